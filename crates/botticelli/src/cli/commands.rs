@@ -45,6 +45,11 @@ pub enum Commands {
         #[arg(long)]
         state_dir: Option<PathBuf>,
 
+        /// Allow raw SQL `where` filters in table references (trusted narratives only)
+        #[cfg(all(feature = "gemini", feature = "database"))]
+        #[arg(long)]
+        allow_raw_filters: bool,
+
         /// Budget multiplier for requests per minute (0.0 < x ≤ 1.0)
         #[arg(long)]
         rpm_multiplier: Option<f64>,
@@ -62,6 +67,10 @@ pub enum Commands {
     Tui {
         /// Name of the table to view
         table: String,
+
+        /// Name recorded as the reviewer of changes (default: $USER)
+        #[arg(long)]
+        reviewer: Option<String>,
     },
 
     /// Content management commands
@@ -111,6 +120,65 @@ pub enum ContentCommands {
         id: i64,
     },
 
+    /// Show the revision history of a content item
+    History {
+        /// Name of the table
+        table: String,
+
+        /// ID of the content item
+        id: i64,
+
+        /// Output format
+        #[arg(long, default_value = "human")]
+        format: OutputFormat,
+    },
+
+    /// Restore a content item to an earlier revision
+    Revert {
+        /// Name of the table
+        table: String,
+
+        /// ID of the content item
+        id: i64,
+
+        /// Revision to restore (see `content history`)
+        revision: i64,
+
+        /// Name recorded as the editor of the restore
+        #[arg(long, default_value = "cli")]
+        editor: String,
+    },
+
+    /// Export every row of a table to a file
+    Export {
+        /// Name of the table
+        table: String,
+
+        /// File to write; JSON Lines and CSV go to stdout when omitted
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+
+        /// Data format (defaults to the output file's extension, else jsonl)
+        #[arg(long)]
+        format: Option<DataFormat>,
+    },
+
+    /// Import rows into a table, creating it if it doesn't exist
+    ///
+    /// Rows whose content is already in the table update its status, tags
+    /// and rating instead of being inserted again.
+    Import {
+        /// Name of the table
+        table: String,
+
+        /// File to read
+        input: PathBuf,
+
+        /// Data format (defaults to the input file's extension)
+        #[arg(long)]
+        format: Option<DataFormat>,
+    },
+
     /// Get the most recently generated table
     Last {
         /// Output format
@@ -140,3 +208,14 @@ pub enum OutputFormat {
     /// Table name only (for scripting)
     TableNameOnly,
 }
+
+/// File formats for content export and import
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum DataFormat {
+    /// One JSON object per line
+    Jsonl,
+    /// Comma-separated values with a header row
+    Csv,
+    /// Apache Parquet (needs the `parquet` feature)
+    Parquet,
+}
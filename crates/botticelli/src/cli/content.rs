@@ -1,7 +1,8 @@
 //! Content management command handlers.
 
-use super::commands::{ContentCommands, OutputFormat};
+use super::commands::{ContentCommands, DataFormat, OutputFormat};
 use botticelli::BotticelliResult;
+use std::path::Path;
 
 /// Handle content management commands.
 pub async fn handle_content_command(cmd: ContentCommands) -> BotticelliResult<()> {
@@ -15,6 +16,27 @@ pub async fn handle_content_command(cmd: ContentCommands) -> BotticelliResult<()
 
         ContentCommands::Show { table, id } => show_content(&table, id).await,
 
+        ContentCommands::History { table, id, format } => content_history(&table, id, format).await,
+
+        ContentCommands::Revert {
+            table,
+            id,
+            revision,
+            editor,
+        } => revert_content(&table, id, revision, &editor).await,
+
+        ContentCommands::Export {
+            table,
+            output,
+            format,
+        } => export_table(&table, output.as_deref(), format).await,
+
+        ContentCommands::Import {
+            table,
+            input,
+            format,
+        } => import_table(&table, &input, format).await,
+
         ContentCommands::Last { format } => last_generation(format).await,
 
         ContentCommands::Generations { status, limit } => {
@@ -31,10 +53,10 @@ async fn list_content(
     limit: i64,
     format: OutputFormat,
 ) -> BotticelliResult<()> {
-    use botticelli::establish_connection;
+    use botticelli::DatabaseConnection;
     use botticelli::list_content as db_list_content;
 
-    let mut conn = establish_connection()?;
+    let mut conn = DatabaseConnection::from_env()?;
     let content = db_list_content(&mut conn, table, status, limit as usize)?;
 
     match format {
@@ -76,9 +98,9 @@ async fn list_content(
 /// Show a specific content item.
 #[cfg(feature = "database")]
 async fn show_content(table: &str, id: i64) -> BotticelliResult<()> {
-    use botticelli::{establish_connection, get_content_by_id};
+    use botticelli::{DatabaseConnection, get_content_by_id};
 
-    let mut conn = establish_connection()?;
+    let mut conn = DatabaseConnection::from_env()?;
     let content = get_content_by_id(&mut conn, table, id)?;
 
     let json = serde_json::to_string_pretty(&content)
@@ -94,15 +116,210 @@ async fn show_content(_table: &str, _id: i64) -> BotticelliResult<()> {
     std::process::exit(1);
 }
 
+/// Show the revision history of a content item.
+#[cfg(feature = "database")]
+async fn content_history(table: &str, id: i64, format: OutputFormat) -> BotticelliResult<()> {
+    use botticelli::{DatabaseConnection, content_revisions};
+
+    let mut conn = DatabaseConnection::from_env()?;
+    let revisions = content_revisions(&mut conn, table, id)?;
+
+    match format {
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&revisions)
+                .map_err(|e| botticelli::JsonError::new(e.to_string()))?;
+            println!("{}", json);
+        }
+        OutputFormat::Human | OutputFormat::TableNameOnly => {
+            if revisions.is_empty() {
+                println!("No edits recorded for {} #{}", table, id);
+                return Ok(());
+            }
+
+            for revision in &revisions {
+                println!(
+                    "Revision {} by {} at {}",
+                    revision.revision(),
+                    revision.editor(),
+                    revision.created_at().format("%Y-%m-%d %H:%M")
+                );
+                if *revision.revision() == 1 {
+                    println!("  (original output)");
+                }
+                if let Some(diff) = revision.diff().as_object() {
+                    for (field, change) in diff {
+                        println!("  {}: {} → {}", field, change["old"], change["new"]);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(not(feature = "database"))]
+async fn content_history(_table: &str, _id: i64, _format: OutputFormat) -> BotticelliResult<()> {
+    eprintln!("Error: Database feature not enabled. Rebuild with --features database");
+    std::process::exit(1);
+}
+
+/// Restore a content item to an earlier revision.
+#[cfg(feature = "database")]
+async fn revert_content(table: &str, id: i64, revision: i64, editor: &str) -> BotticelliResult<()> {
+    use botticelli::DatabaseConnection;
+
+    let mut conn = DatabaseConnection::from_env()?;
+
+    match botticelli::revert_content(&mut conn, table, id, revision, editor)? {
+        Some(new_revision) => println!(
+            "Restored {} #{} to revision {} (recorded as revision {})",
+            table, id, revision, new_revision
+        ),
+        None => println!("{} #{} already matches revision {}", table, id, revision),
+    }
+
+    Ok(())
+}
+
+#[cfg(not(feature = "database"))]
+async fn revert_content(
+    _table: &str,
+    _id: i64,
+    _revision: i64,
+    _editor: &str,
+) -> BotticelliResult<()> {
+    eprintln!("Error: Database feature not enabled. Rebuild with --features database");
+    std::process::exit(1);
+}
+
+/// Export a table to a file, or stdout.
+#[cfg(feature = "database")]
+async fn export_table(
+    table: &str,
+    output: Option<&Path>,
+    format: Option<DataFormat>,
+) -> BotticelliResult<()> {
+    use botticelli::{ContentFormat, DatabaseConnection, export_content};
+
+    let format = resolve_format(format, output).unwrap_or(ContentFormat::Jsonl);
+    let mut conn = DatabaseConnection::from_env()?;
+
+    match output {
+        Some(path) => {
+            let file = std::fs::File::create(path).map_err(|e| {
+                botticelli::BackendError::new(format!("Failed to create {}: {}", path.display(), e))
+            })?;
+            let rows = export_content(&mut conn, table, format, std::io::BufWriter::new(file))?;
+            println!(
+                "Exported {} rows from '{}' to {} ({})",
+                rows,
+                table,
+                path.display(),
+                format
+            );
+        }
+        None if format == ContentFormat::Parquet => {
+            eprintln!("Error: Parquet exports need --output");
+            std::process::exit(1);
+        }
+        None => {
+            export_content(
+                &mut conn,
+                table,
+                format,
+                std::io::BufWriter::new(std::io::stdout()),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(not(feature = "database"))]
+async fn export_table(
+    _table: &str,
+    _output: Option<&Path>,
+    _format: Option<DataFormat>,
+) -> BotticelliResult<()> {
+    eprintln!("Error: Database feature not enabled. Rebuild with --features database");
+    std::process::exit(1);
+}
+
+/// Import a file into a table, creating the table if needed.
+#[cfg(feature = "database")]
+async fn import_table(
+    table: &str,
+    input: &Path,
+    format: Option<DataFormat>,
+) -> BotticelliResult<()> {
+    use botticelli::{DatabaseConnection, import_content};
+
+    let Some(format) = resolve_format(format, Some(input)) else {
+        eprintln!(
+            "Error: Can't tell the format of {}; pass --format",
+            input.display()
+        );
+        std::process::exit(1);
+    };
+    let file = std::fs::File::open(input).map_err(|e| {
+        botticelli::BackendError::new(format!("Failed to open {}: {}", input.display(), e))
+    })?;
+
+    let mut conn = DatabaseConnection::from_env()?;
+    let summary = import_content(&mut conn, table, format, std::io::BufReader::new(file))?;
+
+    if *summary.created_table() {
+        println!("Created table '{}'", table);
+    }
+    println!(
+        "Imported {} into '{}': {} inserted, {} updated, {} unchanged",
+        input.display(),
+        table,
+        summary.inserted(),
+        summary.updated(),
+        summary.unchanged()
+    );
+
+    Ok(())
+}
+
+#[cfg(not(feature = "database"))]
+async fn import_table(
+    _table: &str,
+    _input: &Path,
+    _format: Option<DataFormat>,
+) -> BotticelliResult<()> {
+    eprintln!("Error: Database feature not enabled. Rebuild with --features database");
+    std::process::exit(1);
+}
+
+/// The format asked for, or else the one the file's extension names.
+#[cfg(feature = "database")]
+fn resolve_format(
+    format: Option<DataFormat>,
+    path: Option<&Path>,
+) -> Option<botticelli::ContentFormat> {
+    use botticelli::ContentFormat;
+
+    match format {
+        Some(DataFormat::Jsonl) => Some(ContentFormat::Jsonl),
+        Some(DataFormat::Csv) => Some(ContentFormat::Csv),
+        Some(DataFormat::Parquet) => Some(ContentFormat::Parquet),
+        None => path
+            .and_then(|path| path.extension())
+            .and_then(|extension| extension.to_str())
+            .and_then(ContentFormat::from_extension),
+    }
+}
+
 /// Get the last successful generation.
 #[cfg(feature = "database")]
 async fn last_generation(format: OutputFormat) -> BotticelliResult<()> {
-    use botticelli::{
-        ContentGenerationRepository, PostgresContentGenerationRepository, establish_connection,
-    };
+    use botticelli::{ContentConnection, DatabaseConnection};
 
-    let mut conn = establish_connection()?;
-    let mut repo = PostgresContentGenerationRepository::new(&mut conn);
+    let mut conn = DatabaseConnection::from_env()?;
+    let mut repo = conn.generations();
 
     match repo.get_last_successful()? {
         Some(generation) => match format {
@@ -145,12 +362,10 @@ async fn last_generation(_format: OutputFormat) -> BotticelliResult<()> {
 /// List all content generations.
 #[cfg(feature = "database")]
 async fn list_generations(status: Option<&str>, limit: i64) -> BotticelliResult<()> {
-    use botticelli::{
-        ContentGenerationRepository, PostgresContentGenerationRepository, establish_connection,
-    };
+    use botticelli::{ContentConnection, DatabaseConnection};
 
-    let mut conn = establish_connection()?;
-    let mut repo = PostgresContentGenerationRepository::new(&mut conn);
+    let mut conn = DatabaseConnection::from_env()?;
+    let mut repo = conn.generations();
 
     let generations = repo.list_generations(status.map(String::from), limit)?;
 
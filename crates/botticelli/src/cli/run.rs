@@ -57,6 +57,8 @@ pub struct ExecutionOptions {
     process_discord: bool,
     #[cfg(feature = "database")]
     state_dir: Option<PathBuf>,
+    #[cfg(feature = "database")]
+    allow_raw_filters: bool,
 }
 
 #[cfg(feature = "gemini")]
@@ -86,6 +88,14 @@ impl ExecutionOptions {
     pub fn state_dir(&self) -> Option<&Path> {
         self.state_dir.as_deref()
     }
+
+    /// Whether table references may use raw SQL `where` filters.
+    ///
+    /// Available with the `database` feature.
+    #[cfg(feature = "database")]
+    pub fn allow_raw_filters(&self) -> bool {
+        self.allow_raw_filters
+    }
 }
 
 /// Builder for execution options.
@@ -101,6 +111,8 @@ pub struct ExecutionOptionsBuilder {
     process_discord: bool,
     #[cfg(feature = "database")]
     state_dir: Option<PathBuf>,
+    #[cfg(feature = "database")]
+    allow_raw_filters: bool,
 }
 
 #[cfg(feature = "gemini")]
@@ -129,6 +141,17 @@ impl ExecutionOptionsBuilder {
         self
     }
 
+    /// Set whether table references may use raw SQL `where` filters.
+    ///
+    /// Only for narratives written by a trusted operator.
+    ///
+    /// Available with the `database` feature.
+    #[cfg(feature = "database")]
+    pub fn allow_raw_filters(mut self, allow_raw_filters: bool) -> Self {
+        self.allow_raw_filters = allow_raw_filters;
+        self
+    }
+
     /// Build the execution options.
     pub fn build(self) -> ExecutionOptions {
         ExecutionOptions {
@@ -138,6 +161,8 @@ impl ExecutionOptionsBuilder {
             process_discord: self.process_discord,
             #[cfg(feature = "database")]
             state_dir: self.state_dir,
+            #[cfg(feature = "database")]
+            allow_raw_filters: self.allow_raw_filters,
         }
     }
 }
@@ -169,7 +194,7 @@ pub async fn run_narrative(
     // Use MultiNarrative if a name is provided (enables composition), otherwise single Narrative
     #[cfg(feature = "database")]
     let narrative: Box<dyn botticelli::NarrativeProvider> = {
-        let mut conn = botticelli::establish_connection()?;
+        let mut conn = botticelli::DatabaseConnection::from_env()?;
 
         if let Some(name) = source.name() {
             // Load as MultiNarrative for composition support
@@ -337,28 +362,19 @@ pub async fn run_narrative(
         {
             use botticelli::ProcessorRegistry;
             use botticelli_database::{
-                DatabaseTableQueryRegistry, TableQueryExecutor, create_pool,
+                DatabaseTableQueryRegistry, TableQueryExecutor, create_database_pool, database_url,
             };
-            use botticelli_narrative::{ContentGenerationProcessor, StorageActor};
-            use std::sync::{Arc, Mutex};
+            use botticelli_narrative::ContentGenerationProcessor;
 
-            // Create database connection for table queries
-            let table_conn = botticelli::establish_connection()?;
-            let table_executor = TableQueryExecutor::new(Arc::new(Mutex::new(table_conn)));
+            // One pool serves table queries, database commands and the storage actor
+            let pool = create_database_pool(&database_url()?)?;
+            let table_executor =
+                TableQueryExecutor::new(pool.clone()).with_raw_filters(options.allow_raw_filters());
             let table_registry = DatabaseTableQueryRegistry::new(table_executor);
 
             // Start storage actor with Ractor
             tracing::info!("Starting storage actor");
-            let pool = create_pool()?;
-
-            let actor = StorageActor::new(pool.clone());
-            let (actor_ref, _handle) =
-                ractor::Actor::spawn(None, actor, pool).await.map_err(|e| {
-                    botticelli_error::BackendError::new(format!(
-                        "Failed to spawn storage actor: {}",
-                        e
-                    ))
-                })?;
+            let actor_ref = spawn_storage_actor(&pool).await?;
 
             tracing::info!("Storage actor started");
 
@@ -383,7 +399,7 @@ pub async fn run_narrative(
                 let mut bot_registry = BotCommandRegistryImpl::new();
 
                 // Always register database executor
-                let database_executor = DatabaseCommandExecutor::new();
+                let database_executor = DatabaseCommandExecutor::new(pool.clone());
                 bot_registry.register(database_executor);
                 tracing::info!("Database command executor registered");
 
@@ -391,11 +407,9 @@ pub async fn run_narrative(
                 #[cfg(feature = "discord")]
                 if options.process_discord() {
                     use botticelli_social::DiscordCommandExecutor;
-                    use std::env;
-
-                    if let Ok(token) = env::var("DISCORD_TOKEN") {
+                    if let Ok(token) = botticelli_secrets::get_secret("discord_token") {
                         tracing::info!("Configuring Discord bot executor");
-                        let discord_executor = DiscordCommandExecutor::new(token);
+                        let discord_executor = DiscordCommandExecutor::new(token.into_inner());
                         bot_registry.register(discord_executor);
                         tracing::info!("Discord bot executor registered");
                     } else {
@@ -479,19 +493,17 @@ pub async fn run_narrative(
     if options.save() {
         #[cfg(feature = "database")]
         {
-            use botticelli::{
-                NarrativeRepository, PostgresNarrativeRepository, establish_connection,
-            };
+            use botticelli::{NarrativeRepository, PostgresNarrativeRepository, create_pool};
             use botticelli_storage::FileSystemStorage;
             use std::sync::Arc;
 
-            let conn = establish_connection()?;
+            let pool = create_pool()?;
             let storage_dir = dirs::data_dir()
                 .expect("Could not determine data directory")
                 .join("botticelli")
                 .join("storage");
             let storage = Arc::new(FileSystemStorage::new(storage_dir)?);
-            let repo = PostgresNarrativeRepository::new(conn, storage);
+            let repo = PostgresNarrativeRepository::new(pool, storage);
 
             let exec_id = repo.save_execution(&execution).await?;
             tracing::info!(execution_id = exec_id, "Execution saved to database");
@@ -522,6 +534,21 @@ pub async fn run_narrative(
     Ok(())
 }
 
+/// Spawn a storage actor on a database pool.
+#[cfg(all(feature = "gemini", feature = "database"))]
+async fn spawn_storage_actor(
+    pool: &botticelli_database::DatabasePool,
+) -> BotticelliResult<ractor::ActorRef<botticelli_narrative::StorageMessage>> {
+    let actor = botticelli_narrative::StorageActor::new(pool.clone());
+    let (actor_ref, _handle) = ractor::Actor::spawn(None, actor, pool.clone())
+        .await
+        .map_err(|e| {
+            botticelli_error::BackendError::new(format!("Failed to spawn storage actor: {}", e))
+        })?;
+
+    Ok(actor_ref)
+}
+
 #[cfg(not(feature = "gemini"))]
 pub async fn run_narrative() -> BotticelliResult<()> {
     eprintln!("Error: Gemini feature not enabled. Rebuild with --features gemini");
@@ -8,7 +8,6 @@ use botticelli_narrative::{
     ContentGenerationProcessor, NarrativeExecutor, ProcessorRegistry, StorageActor,
 };
 use std::path::PathBuf;
-use std::sync::{Arc, Mutex};
 use tracing::info;
 
 /// Handle the `server` command
@@ -27,9 +26,8 @@ pub async fn handle_server_command(
     // Create Gemini client
     let client = GeminiClient::new()?;
 
-    // Create database connection for table queries
-    let table_conn = botticelli::establish_connection()?;
-    let table_executor = TableQueryExecutor::new(Arc::new(Mutex::new(table_conn)));
+    // Table queries share the pool
+    let table_executor = TableQueryExecutor::new(pool.clone());
     let table_registry = DatabaseTableQueryRegistry::new(table_executor);
 
     // Start storage actor with Ractor
@@ -3,20 +3,25 @@
 use botticelli::BotticelliResult;
 
 /// Launch the terminal user interface for a table.
+///
+/// Changes are recorded under `reviewer`, or the login name if not given.
 #[cfg(all(feature = "tui", feature = "database"))]
-pub async fn launch_tui(table: &str) -> BotticelliResult<()> {
+pub async fn launch_tui(table: &str, reviewer: Option<&str>) -> BotticelliResult<()> {
     use botticelli_tui::{DatabaseBackend, run_tui};
 
     tracing::info!(table = %table, "Launching TUI");
 
     let mut backend = DatabaseBackend::new()?;
+    if let Some(reviewer) = reviewer {
+        backend = backend.with_editor(reviewer);
+    }
     run_tui(&mut backend, table.to_string())?;
 
     Ok(())
 }
 
 #[cfg(not(all(feature = "tui", feature = "database")))]
-pub async fn launch_tui(_table: &str) -> BotticelliResult<()> {
+pub async fn launch_tui(_table: &str, _reviewer: Option<&str>) -> BotticelliResult<()> {
     eprintln!("Error: TUI and database features not enabled. Rebuild with --features tui,database");
     std::process::exit(1);
 }
@@ -45,6 +45,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         tracing_subscriber::fmt()
             .with_max_level(log_level)
+            .with_writer(botticelli_secrets::RedactingMakeWriter::new(
+                std::io::stdout,
+            ))
             .with_target(false)
             .init();
     }
@@ -59,6 +62,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             process_discord,
             #[cfg(all(feature = "gemini", feature = "database"))]
             state_dir,
+            #[cfg(all(feature = "gemini", feature = "database"))]
+            allow_raw_filters,
             rpm_multiplier,
             tpm_multiplier,
             rpd_multiplier,
@@ -74,7 +79,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     let builder = ExecutionOptions::builder().save(save);
                     #[cfg(feature = "discord")]
                     let builder = builder.process_discord(process_discord);
-                    builder.state_dir(state_dir).build()
+                    builder
+                        .state_dir(state_dir)
+                        .allow_raw_filters(allow_raw_filters)
+                        .build()
                 };
 
                 #[cfg(not(feature = "database"))]
@@ -124,8 +132,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         }
 
-        Commands::Tui { table } => {
-            launch_tui(&table).await?;
+        Commands::Tui { table, reviewer } => {
+            launch_tui(&table, reviewer.as_deref()).await?;
         }
 
         Commands::Content(content_cmd) => {
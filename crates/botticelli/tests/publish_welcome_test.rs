@@ -11,13 +11,10 @@
 
 use botticelli::{
     BotCommandRegistryImpl, DatabaseTableQueryRegistry, DiscordCommandExecutor, GeminiClient,
-    Narrative, NarrativeExecutor, TableQueryExecutor, establish_connection,
+    Narrative, NarrativeExecutor, TableQueryExecutor, create_pool,
 };
 use dotenvy::dotenv;
-use std::{
-    env,
-    sync::{Arc, Mutex},
-};
+use std::env;
 
 #[tokio::test]
 #[cfg_attr(not(feature = "api"), ignore)]
@@ -29,9 +26,8 @@ async fn test_publish_welcome() {
     let _gemini_key = env::var("GEMINI_API_KEY").expect("GEMINI_API_KEY must be set");
     let discord_token = env::var("DISCORD_TOKEN").expect("DISCORD_TOKEN must be set");
 
-    // Set up database connection
-    let conn = establish_connection().expect("Failed to connect to database");
-    let conn = Arc::new(Mutex::new(conn));
+    // Set up database connection pool
+    let pool = create_pool().expect("Failed to connect to database");
 
     // Get narratives directory (tests run from workspace root)
     let narratives_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -50,7 +46,7 @@ async fn test_publish_welcome() {
     bot_registry.register(discord_executor);
 
     // Create table query registry
-    let table_executor = TableQueryExecutor::new(conn.clone());
+    let table_executor = TableQueryExecutor::new(pool);
     let table_registry = DatabaseTableQueryRegistry::new(table_executor);
 
     // Create executor with bot and table support
@@ -7,13 +7,18 @@
 
 use botticelli::{
     ActConfig, DatabaseTableQueryRegistry, Input, NarrativeExecutor, NarrativeMetadata,
-    NarrativeProvider, Output, TableFormat, TableQueryExecutor,
+    NarrativeProvider, Output, TableFormat, TableQueryExecutor, WhereClause,
 };
 use diesel::prelude::*;
-use std::{
-    env,
-    sync::{Arc, Mutex},
-};
+use diesel::r2d2::{ConnectionManager, Pool};
+use std::env;
+
+/// Pool with a single connection, so temp tables stay visible to the executor.
+fn single_connection_pool(database_url: &str) -> Pool<ConnectionManager<PgConnection>> {
+    Pool::builder()
+        .max_size(1)
+        .build_unchecked(ConnectionManager::new(database_url))
+}
 
 /// Test narrative provider that queries a table.
 struct TableReferenceNarrative {
@@ -145,7 +150,8 @@ async fn test_table_reference_query() -> botticelli::BotticelliResult<()> {
     dotenvy::dotenv().ok();
     let database_url = env::var("DATABASE_URL")
         .map_err(|_| ConfigError::new("DATABASE_URL environment variable not set"))?;
-    let mut conn = PgConnection::establish(&database_url).map_err(|e| {
+    let pool = single_connection_pool(&database_url);
+    let mut conn = pool.get().map_err(|e| {
         DatabaseError::new(DatabaseErrorKind::Connection(format!(
             "Failed to establish connection: {}",
             e
@@ -186,8 +192,9 @@ async fn test_table_reference_query() -> botticelli::BotticelliResult<()> {
         )))
     })?;
 
-    // Use the same connection for the query executor so it can see the temp table
-    let query_executor = TableQueryExecutor::new(Arc::new(Mutex::new(conn)));
+    // Return the connection so the executor reuses it and sees the temp table
+    drop(conn);
+    let query_executor = TableQueryExecutor::new(pool);
     let table_registry = DatabaseTableQueryRegistry::new(query_executor);
 
     // Create narrative
@@ -240,7 +247,8 @@ async fn test_table_reference_with_filter() -> botticelli::BotticelliResult<()>
     dotenvy::dotenv().ok();
     let database_url = env::var("DATABASE_URL")
         .map_err(|_| ConfigError::new("DATABASE_URL environment variable not set"))?;
-    let mut conn = PgConnection::establish(&database_url).map_err(|e| {
+    let pool = single_connection_pool(&database_url);
+    let mut conn = pool.get().map_err(|e| {
         DatabaseError::new(DatabaseErrorKind::Connection(format!(
             "Failed to establish connection: {}",
             e
@@ -271,8 +279,9 @@ async fn test_table_reference_with_filter() -> botticelli::BotticelliResult<()>
     .execute(&mut conn)
     .expect("Failed to insert test data");
 
-    // Use the same connection for the query executor so it can see the temp table
-    let query_executor = TableQueryExecutor::new(Arc::new(Mutex::new(conn)));
+    // Return the connection so the executor reuses it and sees the temp table
+    drop(conn);
+    let query_executor = TableQueryExecutor::new(pool);
     let table_registry = DatabaseTableQueryRegistry::new(query_executor);
 
     // Create narrative with WHERE clause
@@ -288,7 +297,7 @@ async fn test_table_reference_with_filter() -> botticelli::BotticelliResult<()>
     let table_input = Input::Table {
         table_name: "test_orders".to_string(),
         columns: Some(vec!["customer".to_string(), "total".to_string()]),
-        where_clause: Some("status = 'completed'".to_string()),
+        where_clause: Some(WhereClause::Sql("status = 'completed'".to_string())),
         limit: Some(10),
         offset: None,
         order_by: Some("total DESC".to_string()),
@@ -368,7 +377,8 @@ async fn test_table_reference_format_csv() -> botticelli::BotticelliResult<()> {
     dotenvy::dotenv().ok();
     let database_url = env::var("DATABASE_URL")
         .map_err(|_| ConfigError::new("DATABASE_URL environment variable not set"))?;
-    let mut conn = PgConnection::establish(&database_url).map_err(|e| {
+    let pool = single_connection_pool(&database_url);
+    let mut conn = pool.get().map_err(|e| {
         DatabaseError::new(DatabaseErrorKind::Connection(format!(
             "Failed to establish connection: {}",
             e
@@ -407,8 +417,9 @@ async fn test_table_reference_format_csv() -> botticelli::BotticelliResult<()> {
         )))
     })?;
 
-    // Use the same connection for the query executor so it can see the temp table
-    let query_executor = TableQueryExecutor::new(Arc::new(Mutex::new(conn)));
+    // Return the connection so the executor reuses it and sees the temp table
+    drop(conn);
+    let query_executor = TableQueryExecutor::new(pool);
     let table_registry = DatabaseTableQueryRegistry::new(query_executor);
 
     // Create narrative with CSV format
@@ -4,11 +4,12 @@ use crate::{
     ActorConfig, ActorError, ActorErrorKind, ActorResult, KnowledgeTable, Platform, SkillContext,
     SkillContextBuilder, SkillOutput, SkillRegistry,
 };
-use diesel::PgConnection;
-use diesel::r2d2::{ConnectionManager, Pool};
+use botticelli_database::{ContentConnection, DatabasePool, with_pooled_connection};
+use botticelli_security::ContentModerator;
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::Instrument;
 
 /// Execution result from running an actor.
 #[derive(Debug, Clone, derive_builder::Builder)]
@@ -23,6 +24,9 @@ pub struct ExecutionResult {
     /// Skipped skills.
     #[builder(default)]
     pub skipped: Vec<String>,
+    /// Skill whose `stop_if` rule ended the pipeline early.
+    #[builder(default)]
+    pub stopped_by: Option<String>,
 }
 
 /// Core actor that orchestrates skills and knowledge.
@@ -45,11 +49,14 @@ impl Actor {
     /// Execute the actor workflow.
     ///
     /// Loads knowledge from configured tables, executes skills in order,
-    /// and handles errors according to execution configuration.
+    /// and handles errors according to execution configuration. Each skill
+    /// sees the output of the skills before it; `inputs_from` and `stop_if`
+    /// in the skill configuration wire and short-circuit the pipeline.
     ///
     /// # Arguments
     ///
-    /// * `pool` - Database connection pool for knowledge queries and skill execution
+    /// * `pool` - Connection pool for knowledge queries and skill execution,
+    ///   on PostgreSQL or SQLite
     ///
     /// # Returns
     ///
@@ -77,43 +84,66 @@ impl Actor {
             knowledge_tables = self.config.knowledge().len(),
         )
     )]
-    pub async fn execute(
+    pub async fn execute(&self, pool: &DatabasePool) -> ActorResult<ExecutionResult> {
+        self.execute_with_variables(pool, HashMap::new()).await
+    }
+
+    /// Execute the actor workflow with template variables.
+    ///
+    /// Behaves like [`Actor::execute`], but passes `variables` to every skill
+    /// context so narratives can reference them (for example the `event:*`
+    /// variables of an event trigger).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Actor::execute`].
+    #[tracing::instrument(
+        skip(self, pool, variables),
+        fields(actor_name = %self.config.name(), variable_count = variables.len())
+    )]
+    pub async fn execute_with_variables(
         &self,
-        pool: &Pool<ConnectionManager<PgConnection>>,
+        pool: &DatabasePool,
+        variables: HashMap<String, String>,
     ) -> ActorResult<ExecutionResult> {
         tracing::info!("Starting actor execution");
 
-        // Get connection from pool for knowledge loading
-        let mut conn = pool.get().map_err(|e| {
-            ActorError::new(ActorErrorKind::DatabaseFailed(format!(
-                "Failed to get database connection: {}",
-                e
-            )))
-        })?;
-
         // Load knowledge from configured tables
         let knowledge_span = tracing::debug_span!(
             "load_knowledge",
             table_count = self.config.knowledge().len()
         );
-        let knowledge = {
-            let _enter = knowledge_span.enter();
-            self.load_knowledge(&mut conn)?
+        let knowledge = if self.config.knowledge().is_empty() {
+            HashMap::new()
+        } else {
+            let tables = self.config.knowledge().clone();
+            let stop_on_missing = *self.config.execution().stop_on_unrecoverable();
+            with_pooled_connection(pool, move |conn| {
+                Self::load_knowledge(conn, &tables, stop_on_missing)
+            })
+            .instrument(knowledge_span)
+            .await?
         };
 
+        // Output data of completed skills, visible to the skills after them
+        let mut outputs: HashMap<String, JsonValue> = HashMap::new();
+
         let mut result = ExecutionResultBuilder::default()
             .build()
             .expect("ExecutionResult with valid defaults");
 
         // Execute each configured skill
-        for skill_name in self.config.skills() {
+        let skills = self.config.skills();
+        for (position, skill_name) in skills.iter().enumerate() {
             let skill_span = tracing::info_span!("execute_skill", skill = %skill_name);
             let _enter = skill_span.enter();
 
             tracing::debug!("Preparing skill execution");
 
+            let skill_config = self.config.skill_configs().get(skill_name);
+
             // Check if skill is enabled in configuration
-            if let Some(skill_config) = self.config.skill_configs().get(skill_name)
+            if let Some(skill_config) = skill_config
                 && !skill_config.enabled()
             {
                 tracing::info!(skill = %skill_name, "Skill disabled, skipping");
@@ -121,12 +151,30 @@ impl Actor {
                 continue;
             }
 
+            let inputs_from = skill_config
+                .map(|c| c.inputs_from().clone())
+                .unwrap_or_default();
+
+            // Skip skills whose declared sources all failed or were skipped
+            if !inputs_from.is_empty() && !inputs_from.iter().any(|s| outputs.contains_key(s)) {
+                tracing::info!(
+                    skill = %skill_name,
+                    inputs_from = ?inputs_from,
+                    "No input available, skipping"
+                );
+                result.skipped.push(skill_name.clone());
+                continue;
+            }
+
             // Build skill context
             let context = SkillContextBuilder::default()
                 .knowledge(knowledge.clone())
                 .config(self.extract_skill_config(skill_name))
                 .platform(Arc::clone(&self.platform))
                 .db_pool(pool.clone())
+                .variables(variables.clone())
+                .outputs(outputs.clone())
+                .inputs_from(inputs_from)
                 .build()
                 .expect("SkillContext with valid fields");
 
@@ -134,7 +182,24 @@ impl Actor {
             match self.execute_skill_with_retry(skill_name, &context).await {
                 Ok(output) => {
                     tracing::info!(skill = %skill_name, "Skill executed successfully");
+                    outputs.insert(skill_name.clone(), output.data().clone());
+
+                    let stop = skill_config
+                        .and_then(|c| c.stop_if().as_ref())
+                        .is_some_and(|condition| condition.matches(output.data()));
                     result.succeeded.push(output);
+
+                    if stop {
+                        let remaining = &skills[position + 1..];
+                        tracing::info!(
+                            skill = %skill_name,
+                            remaining = remaining.len(),
+                            "Stop condition met, ending pipeline"
+                        );
+                        result.skipped.extend(remaining.iter().cloned());
+                        result.stopped_by = Some(skill_name.clone());
+                        break;
+                    }
                 }
                 Err(error) => {
                     tracing::error!(
@@ -165,6 +230,7 @@ impl Actor {
             succeeded = result.succeeded.len(),
             failed = result.failed.len(),
             skipped = result.skipped.len(),
+            stopped_by = ?result.stopped_by,
             "Actor execution completed"
         );
 
@@ -172,25 +238,23 @@ impl Actor {
     }
 
     /// Load knowledge from configured tables.
-    #[tracing::instrument(skip(self, conn))]
-    fn load_knowledge(
-        &self,
-        conn: &mut PgConnection,
+    #[tracing::instrument(skip(conn))]
+    fn load_knowledge<C: ContentConnection>(
+        conn: &mut C,
+        tables: &[String],
+        stop_on_missing: bool,
     ) -> ActorResult<HashMap<String, Vec<JsonValue>>> {
-        tracing::debug!(
-            table_count = self.config.knowledge().len(),
-            "Loading knowledge tables"
-        );
+        tracing::debug!(table_count = tables.len(), "Loading knowledge tables");
 
         let mut knowledge = HashMap::new();
 
-        for table_name in self.config.knowledge() {
+        for table_name in tables {
             let table = KnowledgeTable::new(table_name);
 
             // Check if table exists
             if !table.exists(conn) {
                 tracing::warn!(table = %table_name, "Knowledge table does not exist");
-                if *self.config.execution().stop_on_unrecoverable() {
+                if stop_on_missing {
                     return Err(ActorError::new(ActorErrorKind::KnowledgeTableNotFound(
                         table_name.clone(),
                     )));
@@ -296,6 +360,7 @@ pub struct ActorBuilder {
     config: Option<ActorConfig>,
     skills: Option<SkillRegistry>,
     platform: Option<Arc<dyn Platform>>,
+    moderator: Option<Arc<dyn ContentModerator>>,
 }
 
 impl ActorBuilder {
@@ -317,6 +382,15 @@ impl ActorBuilder {
         self
     }
 
+    /// Set a moderation stage that runs before every platform post.
+    ///
+    /// The platform is wrapped in a [`ModeratedPlatform`](crate::ModeratedPlatform)
+    /// at build time.
+    pub fn moderator(mut self, moderator: Arc<dyn ContentModerator>) -> Self {
+        self.moderator = Some(moderator);
+        self
+    }
+
     /// Build the actor.
     ///
     /// # Returns
@@ -341,6 +415,11 @@ impl ActorBuilder {
             ))
         })?;
 
+        let platform: Arc<dyn Platform> = match self.moderator {
+            Some(moderator) => Arc::new(crate::ModeratedPlatform::new(platform, moderator)),
+            None => platform,
+        };
+
         Ok(Actor {
             config,
             skills,
@@ -3,24 +3,30 @@
 //! This binary runs actor servers that execute scheduled tasks for social media
 //! platforms like Discord, posting content based on narratives and knowledge tables.
 
-use botticelli_actor::ActorServerConfig;
 #[cfg(feature = "discord")]
 use botticelli_actor::{
-    Actor, ActorConfig, ActorExecutionTracker, DatabaseExecutionResult, DatabaseStatePersistence,
-    NarrativeExecutionSkill, ScheduleConfig, SkillRegistry,
+    Actor, ActorError, ActorErrorKind, ActorExecutionTracker, ActorInstanceConfig, ActorResult,
+    ActorStatusBuilder, ControlHandle, ControlRequest, CrossPostPlatform, CrossPoster,
+    DatabaseCrossPostPersistence, DatabaseExecutionResult, DatabaseStatePersistence, EventTrigger,
+    LeaseStatus, NarrativeExecutionSkill, SecureDiscordExecutor, SkillRegistry, TaskLeaseManager,
+    TriggerOutcome, create_control_router,
 };
+use botticelli_actor::{ActorConfig, ActorServerConfig, ControlClient, ScheduleConfig};
 #[cfg(feature = "discord")]
-use botticelli_database::create_pool;
+use botticelli_database::{DatabasePool, create_database_pool, create_pool, database_url};
 #[cfg(feature = "discord")]
-use botticelli_server::ActorServer;
+use botticelli_interface::PlatformEvent;
+#[cfg(feature = "discord")]
+use botticelli_security::{ContentModerator, DiscordValidator};
 #[cfg(feature = "discord")]
-use botticelli_server::Schedule;
+use botticelli_server::ActorServer;
 #[cfg(all(feature = "discord", feature = "metrics"))]
 use botticelli_server::ServerMetrics;
-use clap::Parser;
+use botticelli_server::{ConstrainedSchedule, Schedule};
+use clap::{Parser, Subcommand};
 #[cfg(feature = "discord")]
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 #[cfg(feature = "discord")]
 use std::sync::Arc;
 use tracing::info;
@@ -32,12 +38,51 @@ use tracing_subscriber::EnvFilter;
 #[cfg(feature = "discord")]
 use botticelli_actor::{DiscordActorServer, DiscordPlatform};
 
+#[cfg(feature = "discord")]
+use botticelli_social::{
+    BotCommandRegistryImpl, BotticelliBot, DiscordCommandExecutor, DiscordEventPublisher,
+    NarrativeSlashCommandRunner,
+};
+
 #[cfg(feature = "discord")]
 use serenity::http::Http;
 
+#[cfg(feature = "discord")]
+use tokio::sync::{broadcast, mpsc};
+
 #[cfg(feature = "discord")]
 use chrono::{DateTime, Utc};
 
+/// Days of upcoming runs shown by `--dry-run`.
+const PREVIEW_DAYS: i64 = 7;
+
+/// Upcoming runs logged per actor by `--dry-run`.
+const MAX_PREVIEW_LINES: usize = 50;
+
+/// How often the security config file is checked for changes.
+#[cfg(feature = "discord")]
+const SECURITY_RELOAD_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Actor with its schedule, last successful run and execution tracker.
+#[cfg(feature = "discord")]
+type ActorEntry = (
+    Actor,
+    ConstrainedSchedule<ScheduleConfig>,
+    Option<DateTime<Utc>>,
+    Option<ActorExecutionTracker<DatabaseStatePersistence>>,
+);
+
+/// Shared resources actors are built and run with.
+#[cfg(feature = "discord")]
+struct ActorServices {
+    /// Connection pool for actor execution
+    db_pool: DatabasePool,
+    /// Moderation stage from `[security.moderation]`, run before every post
+    moderator: Option<Arc<dyn ContentModerator>>,
+    /// Discord bot commands checked against `[security.discord]`, hot-reloaded
+    discord_executor: Option<SecureDiscordExecutor>,
+}
+
 /// Command-line arguments for the actor server.
 #[derive(Parser, Debug)]
 #[command(name = "actor-server")]
@@ -57,9 +102,75 @@ struct Args {
     #[cfg(feature = "discord")]
     discord_token: Option<String>,
 
-    /// Dry run mode (don't actually execute actors)
+    /// Dry run mode (validate configuration and preview the next week of
+    /// scheduled runs without executing actors)
     #[arg(long)]
     dry_run: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+/// Subcommands of the actor server.
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Control a running server through its HTTP API
+    Ctl(CtlArgs),
+}
+
+/// Arguments of `actor-server ctl`.
+#[derive(clap::Args, Debug)]
+struct CtlArgs {
+    /// Control API URL (defaults to `[server.control] bind` in the config file)
+    #[arg(long, env = "ACTOR_SERVER_URL")]
+    url: Option<String>,
+
+    /// Control API token (defaults to `[server.control] token` in the config file)
+    #[arg(long, env = "ACTOR_SERVER_TOKEN")]
+    token: Option<String>,
+
+    #[command(subcommand)]
+    action: CtlAction,
+}
+
+/// Actions available through `actor-server ctl`.
+#[derive(Subcommand, Debug)]
+enum CtlAction {
+    /// List loaded actors
+    List,
+    /// Pause an actor
+    Pause {
+        /// Actor name
+        actor: String,
+    },
+    /// Resume a paused actor and reset its failure count
+    Resume {
+        /// Actor name
+        actor: String,
+    },
+    /// Run an actor now and wait for it to finish
+    Trigger {
+        /// Actor name
+        actor: String,
+    },
+    /// Show recent executions of an actor
+    History {
+        /// Actor name
+        actor: String,
+        /// Number of executions to show
+        #[arg(long, default_value_t = 20)]
+        limit: i64,
+    },
+    /// Show an actor's circuit-breaker state
+    Circuit {
+        /// Actor name
+        actor: String,
+    },
+    /// Re-read the server config and rebuild one actor from its config file
+    Reload {
+        /// Actor name
+        actor: String,
+    },
 }
 
 #[tokio::main]
@@ -91,6 +202,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let args = Args::parse();
+
+    if let Some(Command::Ctl(ctl)) = args.command {
+        return run_ctl(ctl, &args.config).await;
+    }
+
     info!("Starting Botticelli Actor Server");
     info!(config_file = ?args.config, "Loading configuration");
 
@@ -104,14 +220,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     if args.dry_run {
         info!("DRY RUN MODE - No actions will be executed");
-        // Just validate configuration and exit
+        // Validate configuration, preview schedules and exit
         for actor_instance in &server_config.actors {
+            let actor_config = ActorConfig::from_file(&actor_instance.config_file)?;
+            let schedule = actor_instance.build_schedule(actor_config.config().timezone())?;
             info!(
                 actor = %actor_instance.name,
                 config = %actor_instance.config_file,
                 enabled = actor_instance.enabled,
+                triggers = actor_instance.triggers.len(),
+                timezone = %schedule.timezone(),
                 "Actor configuration validated"
             );
+            preview_schedule(&actor_instance.name, &schedule);
         }
         info!("Configuration validation complete");
         return Ok(());
@@ -119,14 +240,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     #[cfg(feature = "discord")]
     {
-        // Type alias for actor tracking
-        type ActorEntry = (
-            Actor,
-            ScheduleConfig,
-            Option<DateTime<Utc>>,
-            Option<ActorExecutionTracker<DatabaseStatePersistence>>,
-        );
-
         // Set up database state persistence if DATABASE_URL is set
         let persistence = if args.database_url.is_some() || std::env::var("DATABASE_URL").is_ok() {
             info!("Database state persistence enabled");
@@ -146,27 +259,93 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             None
         };
 
+        // Coordinate with other replicas through task leases if configured
+        let leases = match &server_config.server.leasing {
+            Some(leasing) => {
+                let Some(persistence) = &persistence else {
+                    return Err("[server.leasing] requires database state persistence".into());
+                };
+                let leases = TaskLeaseManager::new(
+                    persistence,
+                    leasing.replica_id(),
+                    std::time::Duration::from_secs(leasing.lease_seconds),
+                );
+                leases.spawn_heartbeat();
+                info!(
+                    replica = leases.owner(),
+                    lease_seconds = leasing.lease_seconds,
+                    "Task leasing enabled"
+                );
+                Some(leases)
+            }
+            None => None,
+        };
+
         // Initialize Discord server
-        let discord_token = args
-            .discord_token
-            .or_else(|| std::env::var("DISCORD_TOKEN").ok())
-            .ok_or("DISCORD_TOKEN not provided")?;
+        let discord_token = match args.discord_token {
+            Some(token) => botticelli_secrets::resolve_secret_reference(&token)?,
+            None => botticelli_secrets::get_secret("discord_token")
+                .map_err(|_| "DISCORD_TOKEN not provided")?
+                .into_inner(),
+        };
 
         // Create Discord HTTP client
         let http = Arc::new(Http::new(&discord_token));
 
         // Create database connection pool for actor execution
         info!("Creating database connection pool for actor execution");
-        let db_pool = create_pool()?;
+        let db_pool = create_database_pool(&database_url()?)?;
         info!("Database connection pool created");
 
+        // Moderate every post if the security config asks for it
+        let security = server_config.load_security()?;
+        let moderator = match &security {
+            Some(security) if security.moderation().is_some() => {
+                let driver = Arc::new(botticelli_models::GeminiClient::new_with_config(None)?);
+                info!("Moderation enabled for all actor posts");
+                security.moderator(driver)
+            }
+            _ => None,
+        };
+
+        // Run narrative Discord commands through the security pipeline and
+        // re-apply the security file whenever it changes
+        let discord_executor = match (&security, &server_config.server.security_config) {
+            (Some(security), Some(path)) => {
+                let mut executor = SecureDiscordExecutor::from_config(
+                    DiscordCommandExecutor::with_http_client(http.clone()),
+                    DiscordValidator::new(),
+                    security,
+                    "actor-server".to_string(),
+                )?;
+                if let Some(moderator) = &moderator {
+                    executor = executor.with_moderator(moderator.clone());
+                }
+                executor.watch_config(path, SECURITY_RELOAD_INTERVAL);
+                info!(path = %path, "Watching security config for changes");
+                Some(executor)
+            }
+            _ => None,
+        };
+        let services = ActorServices {
+            db_pool,
+            moderator,
+            discord_executor,
+        };
+
         // Initialize server with state file path
         let state_path = PathBuf::from(".actor_server_state.json");
         let mut server = DiscordActorServer::new(http.clone(), state_path);
+        if let Some(moderator) = &services.moderator {
+            server = server.with_moderator(moderator.clone());
+        }
 
         // Track actors, their schedules, last run time, and execution trackers
         let mut actors: HashMap<String, ActorEntry> = HashMap::new();
 
+        // Event triggers per actor
+        let mut actor_triggers: HashMap<String, Vec<EventTrigger>> = HashMap::new();
+
         // Initialize metrics for the server (if enabled)
         #[cfg(feature = "metrics")]
         let metrics = {
@@ -192,40 +371,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "Loading actor"
             );
 
-            // Load actor configuration
-            let actor_config = ActorConfig::from_file(&actor_instance.config_file)?;
-
-            // Create platform (Discord if channel_id provided, NoOp otherwise)
-            let platform: Arc<dyn botticelli_actor::Platform> =
-                if let Some(channel_id) = &actor_instance.channel_id {
-                    info!(
-                        actor = %actor_instance.name,
-                        channel_id = %channel_id,
-                        "Creating Discord platform for actor"
-                    );
-                    {
-                        let _ = discord_token; // Token validation happens elsewhere
-                        Arc::new(DiscordPlatform::new(channel_id)?)
-                    }
-                } else {
-                    info!(
-                        actor = %actor_instance.name,
-                        "No channel_id specified, using NoOpPlatform (actor will not post)"
-                    );
-                    Arc::new(botticelli_actor::NoOpPlatform::new())
-                };
-
-            // Create skill registry and register narrative execution skill
-            let mut registry = SkillRegistry::new();
-            registry.register(Arc::new(NarrativeExecutionSkill::new()));
-
-            // Build actor with platform and skills
-            let actor = Actor::builder()
-                .config(actor_config)
-                .skills(registry)
-                .platform(platform)
-                .build()?;
-
+            let (actor, schedule) = build_actor(actor_instance, &services).await?;
             info!(actor = %actor_instance.name, "Actor created successfully");
 
             // Load previous state from database if available
@@ -257,42 +403,109 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
 
             // Create execution tracker if persistence is enabled
-            let tracker = persistence.as_ref().map(|p| {
-                ActorExecutionTracker::new(
-                    p.clone(),
-                    actor_instance.name.clone(),
-                    actor_instance.name.clone(),
-                )
-            });
+            let tracker = persistence
+                .as_ref()
+                .map(|p| create_tracker(p, &actor_instance.name));
 
             // Store actor with schedule, last run, and tracker
             actors.insert(
                 actor_instance.name.clone(),
-                (
-                    actor,
-                    actor_instance.schedule.clone(),
-                    loaded_last_run,
-                    tracker,
-                ),
+                (actor, schedule.clone(), loaded_last_run, tracker),
             );
 
-            match &actor_instance.schedule {
-                ScheduleConfig::Interval { seconds } => {
-                    info!(
-                        actor = %actor_instance.name,
-                        interval_seconds = seconds,
-                        "Scheduled with interval"
-                    );
+            match schedule.schedule() {
+                ScheduleConfig::Triggered => {
+                    info!(actor = %actor_instance.name, "Runs on event triggers only");
                 }
-                ScheduleConfig::Immediate => {
+                _ => {
                     info!(
                         actor = %actor_instance.name,
-                        "Scheduled for immediate execution"
+                        schedule = ?schedule.schedule(),
+                        timezone = %schedule.timezone(),
+                        next_run = ?schedule.check(loaded_last_run).next_run,
+                        "Scheduled"
                     );
                 }
             }
+
+            if !actor_instance.triggers.is_empty() {
+                info!(
+                    actor = %actor_instance.name,
+                    triggers = actor_instance.triggers.len(),
+                    "Registered event triggers"
+                );
+                actor_triggers.insert(actor_instance.name.clone(), actor_instance.triggers.clone());
+            }
+        }
+
+        // Answer narrative-backed slash commands if configured
+        let slash_commands = if server_config.server.slash_commands.is_some() {
+            let driver = Arc::new(botticelli_models::GeminiClient::new_with_config(None)?);
+            let mut runner = NarrativeSlashCommandRunner::new(driver);
+            if let Some(executor) = &services.discord_executor {
+                let mut bot_registry = BotCommandRegistryImpl::new();
+                bot_registry.register(executor.clone().with_narrative_id("slash_commands"));
+                runner = runner.with_bot_registry(Arc::new(bot_registry));
+            }
+            server_config.slash_command_dispatcher(security.as_ref(), Arc::new(runner))?
+        } else {
+            None
+        };
+
+        // Connect to the Discord gateway if any actor listens for events or
+        // slash commands are configured
+        let publisher = (!actor_triggers.is_empty()).then(DiscordEventPublisher::default);
+        let mut events = publisher.as_ref().map(DiscordEventPublisher::subscribe);
+        if publisher.is_some() || slash_commands.is_some() {
+            if let Some(dispatcher) = &slash_commands {
+                info!(
+                    commands = dispatcher.registry().len(),
+                    "Slash commands will be registered on connect"
+                );
+            }
+            // The bot mirrors guilds into Diesel-mapped tables, which need PostgreSQL
+            let mut bot = BotticelliBot::new_with_options(
+                discord_token.clone(),
+                create_pool()?,
+                publisher,
+                slash_commands,
+            )
+            .await?;
+            tokio::spawn(async move {
+                if let Err(e) = bot.start().await {
+                    error!(error = %e, "Discord gateway connection failed");
+                }
+            });
+            if events.is_some() {
+                info!(
+                    actors = actor_triggers.len(),
+                    "Listening for Discord events"
+                );
+            }
         }
 
+        // Start the control API if configured
+        let mut control = match &server_config.server.control {
+            Some(control_config) => {
+                let token = botticelli_secrets::resolve_secret_reference(&control_config.token)?;
+                if token.is_empty() {
+                    return Err("[server.control] token must not be empty".into());
+                }
+
+                let (handle, receiver) = ControlHandle::channel(16);
+                let app = create_control_router(handle, token);
+                let listener = tokio::net::TcpListener::bind(&control_config.bind).await?;
+                tokio::spawn(async move {
+                    if let Err(e) = axum::serve(listener, app).await {
+                        error!(error = ?e, "Control API server error");
+                    }
+                });
+                info!(bind = %control_config.bind, "Control API listening");
+                Some(receiver)
+            }
+            None => None,
+        };
+
         // Set up graceful shutdown signal handler
         let shutdown_flag = Arc::new(tokio::sync::Notify::new());
         let shutdown_flag_clone = shutdown_flag.clone();
@@ -334,24 +547,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                     // Check each actor's schedule
                     for (name, (actor, schedule, last_run, tracker)) in actors.iter_mut() {
-                        // Check circuit breaker if tracker available
-                        if let Some(tracker) = tracker.as_ref() {
-                            match tracker.should_execute().await {
-                                Ok(should_run) => {
-                                    if !should_run {
-                                        debug!(actor = %name, "Task paused by circuit breaker, skipping");
-                                        continue;
-                                    }
-                                }
-                                Err(e) => {
-                                    warn!(
-                                        actor = %name,
-                                        error = ?e,
-                                        "Failed to check circuit breaker state, skipping"
-                                    );
-                                    continue;
-                                }
-                            }
+                        if !lease_allows(name, leases.as_ref(), persistence.as_ref(), last_run).await {
+                            continue;
+                        }
+                        if !circuit_allows(name, tracker.as_ref()).await {
+                            continue;
                         }
 
                         let check = schedule.check(*last_run);
@@ -359,98 +559,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         if check.should_run {
                             info!(actor = %name, "Executing scheduled actor");
 
-                            // Start execution history if tracker available
-                            let exec_id = if let Some(tracker) = tracker.as_ref() {
-                                match tracker.start_execution().await {
-                                    Ok(id) => {
-                                        debug!(actor = %name, exec_id = id, "Started execution record");
-                                        Some(id)
-                                    }
-                                    Err(e) => {
-                                        warn!(
-                                            actor = %name,
-                                            error = ?e,
-                                            "Failed to start execution record"
-                                        );
-                                        None
-                                    }
-                                }
-                            } else {
-                                None
-                            };
-
-                            // Execute the actor with database pool
-                            let start_time = std::time::Instant::now();
-                            match actor.execute(&db_pool).await {
-                                Ok(result) => {
-                                    let duration = start_time.elapsed().as_secs_f64();
-                                    info!(
-                                        actor = %name,
-                                        skills_succeeded = result.succeeded.len(),
-                                        skills_failed = result.failed.len(),
-                                        skills_skipped = result.skipped.len(),
-                                        duration_secs = duration,
-                                        "Actor executed successfully"
-                                    );
-                                    *last_run = Some(Utc::now());
-
-                                    // Record metrics (if enabled)
-                                    #[cfg(feature = "metrics")]
-                                    metrics.bots.record_execution(name, duration);
-
-                                    // Record success if tracker available
-                                    if let Some(exec_id) = exec_id
-                                        && let Some(tracker) = tracker.as_ref() {
-                                        let db_result = DatabaseExecutionResult {
-                                            skills_succeeded: result.succeeded.len() as i32,
-                                            skills_failed: result.failed.len() as i32,
-                                            skills_skipped: result.skipped.len() as i32,
-                                            metadata: serde_json::json!({}),
-                                        };
-
-                                        if let Err(e) =
-                                            tracker.record_success(exec_id, db_result).await
-                                        {
-                                            warn!(
-                                                actor = %name,
-                                                error = ?e,
-                                                "Failed to record success"
-                                            );
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    error!(actor = %name, error = ?e, "Actor execution failed");
-
-                                    // Record failure metric (if enabled)
-                                    #[cfg(feature = "metrics")]
-                                    metrics.bots.record_failure(name);
-
-                                    // Record failure if tracker available
-                                    if let Some(exec_id) = exec_id
-                                        && let Some(tracker) = tracker.as_ref() {
-                                        match tracker
-                                            .record_failure(exec_id, &e.to_string())
-                                            .await
-                                        {
-                                            Ok(should_pause) => {
-                                                if should_pause {
-                                                    warn!(
-                                                        actor = %name,
-                                                        "Circuit breaker triggered, task paused"
-                                                    );
-                                                }
-                                            }
-                                            Err(e) => {
-                                                warn!(
-                                                    actor = %name,
-                                                    error = ?e,
-                                                    "Failed to record failure"
-                                                );
-                                            }
-                                        }
-                                    }
-                                }
+                            let succeeded = execute_actor(
+                                name,
+                                actor,
+                                tracker.as_ref(),
+                                &services.db_pool,
+                                HashMap::new(),
+                                #[cfg(feature = "metrics")]
+                                &metrics,
+                            )
+                            .await;
+                            if succeeded {
+                                *last_run = Some(Utc::now());
                             }
 
                             if let Some(next) = check.next_run {
@@ -459,6 +579,60 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         }
                     }
                 }
+                event = next_event(&mut events) => {
+                    let Some(event) = event else {
+                        warn!("Discord event stream closed");
+                        events = None;
+                        continue;
+                    };
+
+                    for (name, triggers) in &actor_triggers {
+                        let Some(variables) = triggers.iter().find_map(|t| t.match_event(&event))
+                        else {
+                            continue;
+                        };
+                        let Some((actor, _, last_run, tracker)) = actors.get_mut(name) else {
+                            continue;
+                        };
+                        if !lease_allows(name, leases.as_ref(), persistence.as_ref(), last_run).await {
+                            continue;
+                        }
+                        if !circuit_allows(name, tracker.as_ref()).await {
+                            continue;
+                        }
+
+                        info!(actor = %name, event = %event.kind(), "Executing event-triggered actor");
+                        execute_actor(
+                            name,
+                            actor,
+                            tracker.as_ref(),
+                            &services.db_pool,
+                            variables,
+                            #[cfg(feature = "metrics")]
+                            &metrics,
+                        )
+                        .await;
+                    }
+                }
+                request = next_control(&mut control) => {
+                    let Some(request) = request else {
+                        warn!("Control API channel closed");
+                        control = None;
+                        continue;
+                    };
+
+                    handle_control(
+                        request,
+                        &mut actors,
+                        &mut actor_triggers,
+                        persistence.as_ref(),
+                        &args.config,
+                        &services,
+                        #[cfg(feature = "metrics")]
+                        &metrics,
+                    )
+                    .await;
+                }
                 _ = shutdown_flag.notified() => {
                     info!("Shutdown signal received, stopping gracefully...");
                     break;
@@ -470,6 +644,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         if let Some(ref persistence) = persistence {
             info!("Saving final task state to database");
             for (name, (_, _, last_run, _)) in &actors {
+                // Another replica owns this actor's state
+                if let Some(leases) = &leases
+                    && !leases.held().contains(name)
+                {
+                    continue;
+                }
                 if let Some(last_run_time) = last_run {
                     match persistence.load_task_state(name).await {
                         Ok(Some(mut state)) => {
@@ -499,6 +679,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         }
 
+        // Hand leases over to the other replicas right away
+        if let Some(leases) = &leases {
+            if let Err(e) = leases.release_all().await {
+                warn!(error = ?e, "Failed to release task leases");
+            } else {
+                info!("Released task leases");
+            }
+        }
+
         // Graceful shutdown
         server
             .stop()
@@ -515,3 +704,586 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Err("Discord feature required".into())
     }
 }
+
+/// Log when an actor would run over the next [`PREVIEW_DAYS`] days.
+fn preview_schedule(name: &str, schedule: &ConstrainedSchedule<ScheduleConfig>) {
+    if schedule.check(None).should_run {
+        info!(actor = %name, "Runs at startup if it has never run");
+    }
+
+    let now = chrono::Utc::now();
+    let runs = schedule.preview(now, now + chrono::Duration::days(PREVIEW_DAYS));
+    info!(actor = %name, runs = runs.len(), days = PREVIEW_DAYS, "Upcoming runs");
+    for run in runs.iter().take(MAX_PREVIEW_LINES) {
+        info!(actor = %name, at = %run.with_timezone(&schedule.timezone()), "Scheduled run");
+    }
+    if runs.len() > MAX_PREVIEW_LINES {
+        info!(actor = %name, more = runs.len() - MAX_PREVIEW_LINES, "More runs not shown");
+    }
+}
+
+/// Build an actor and its schedule from its instance configuration.
+///
+/// Loads the actor's config file, creates its platform (cross-post
+/// destinations, Discord if `channel_id` is set, NoOp otherwise) and
+/// registers its skills. Narrative Discord commands use the server's secure
+/// executor and posts go through its moderation stage, if any. The schedule uses the actor's `timezone` setting unless
+/// `schedule_rules` names one.
+#[cfg(feature = "discord")]
+async fn build_actor(
+    actor_instance: &ActorInstanceConfig,
+    services: &ActorServices,
+) -> Result<(Actor, ConstrainedSchedule<ScheduleConfig>), Box<dyn std::error::Error>> {
+    // Load actor configuration
+    let actor_config = ActorConfig::from_file(&actor_instance.config_file)?;
+    let schedule = actor_instance.build_schedule(actor_config.config().timezone())?;
+
+    // Create platform (cross-post destinations, Discord if channel_id
+    // provided, NoOp otherwise)
+    let platform: Arc<dyn botticelli_actor::Platform> = if !actor_instance.destinations.is_empty() {
+        info!(
+            actor = %actor_instance.name,
+            destinations = actor_instance.destinations.len(),
+            "Creating cross-post platform for actor"
+        );
+        let destinations = actor_instance
+            .destinations
+            .iter()
+            .map(|destination| destination.build())
+            .collect::<Result<Vec<_>, _>>()?;
+        let poster = CrossPoster::new(destinations)?.with_persistence(Arc::new(
+            DatabaseCrossPostPersistence::new(services.db_pool.clone(), &actor_instance.name),
+        ));
+        Arc::new(CrossPostPlatform::new(poster))
+    } else if let Some(channel_id) = &actor_instance.channel_id {
+        info!(
+            actor = %actor_instance.name,
+            channel_id = %channel_id,
+            "Creating Discord platform for actor"
+        );
+        Arc::new(DiscordPlatform::new(channel_id)?)
+    } else {
+        info!(
+            actor = %actor_instance.name,
+            "No channel_id specified, using NoOpPlatform (actor will not post)"
+        );
+        Arc::new(botticelli_actor::NoOpPlatform::new())
+    };
+
+    // Create skill registry and register narrative execution skill
+    let mut registry = SkillRegistry::new();
+    let mut narrative_skill = NarrativeExecutionSkill::new();
+    if let Some(executor) = &services.discord_executor {
+        narrative_skill = narrative_skill.with_discord_executor(executor.clone());
+    }
+    registry.register(Arc::new(narrative_skill));
+
+    // Load WASM skill plugins from the configured directory
+    if let Some(plugins) = actor_config.plugins() {
+        #[cfg(feature = "wasm-plugins")]
+        {
+            let names = registry.load_plugins(plugins).await?;
+            info!(actor = %actor_instance.name, plugins = ?names, "Loaded skill plugins");
+        }
+        #[cfg(not(feature = "wasm-plugins"))]
+        warn!(
+            actor = %actor_instance.name,
+            directory = %plugins.directory().display(),
+            "Skill plugins configured but the wasm-plugins feature is disabled"
+        );
+    }
+
+    // Build actor with platform and skills
+    let mut builder = Actor::builder()
+        .config(actor_config)
+        .skills(registry)
+        .platform(platform);
+    if let Some(moderator) = &services.moderator {
+        builder = builder.moderator(moderator.clone());
+    }
+    let actor = builder.build()?;
+    Ok((actor, schedule))
+}
+
+/// Create the execution tracker for an actor.
+#[cfg(feature = "discord")]
+fn create_tracker(
+    persistence: &Arc<DatabaseStatePersistence>,
+    name: &str,
+) -> ActorExecutionTracker<DatabaseStatePersistence> {
+    ActorExecutionTracker::new(persistence.clone(), name.to_string(), name.to_string())
+}
+
+/// Wait for the next control request, or forever if the control API is off.
+#[cfg(feature = "discord")]
+async fn next_control(
+    requests: &mut Option<mpsc::Receiver<ControlRequest>>,
+) -> Option<ControlRequest> {
+    match requests {
+        Some(receiver) => receiver.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Answer a control API request.
+#[cfg(feature = "discord")]
+async fn handle_control(
+    request: ControlRequest,
+    actors: &mut HashMap<String, ActorEntry>,
+    actor_triggers: &mut HashMap<String, Vec<EventTrigger>>,
+    persistence: Option<&Arc<DatabaseStatePersistence>>,
+    config_path: &Path,
+    services: &ActorServices,
+    #[cfg(feature = "metrics")] metrics: &ServerMetrics,
+) {
+    match request {
+        ControlRequest::ListActors { reply } => {
+            let mut statuses = Vec::with_capacity(actors.len());
+            for (name, (_, schedule, last_run, tracker)) in actors.iter() {
+                let circuit = match tracker {
+                    Some(tracker) => match tracker.circuit_state().await {
+                        Ok(circuit) => Some(circuit),
+                        Err(e) => {
+                            warn!(actor = %name, error = ?e, "Failed to load circuit state");
+                            None
+                        }
+                    },
+                    None => None,
+                };
+                let status = ActorStatusBuilder::default()
+                    .name(name.clone())
+                    .schedule(schedule.schedule().clone())
+                    .next_run(schedule.check(*last_run).next_run)
+                    .last_run(*last_run)
+                    .circuit(circuit)
+                    .build()
+                    .map_err(|e| ActorError::new(ActorErrorKind::Control(e.to_string())));
+                match status {
+                    Ok(status) => statuses.push(status),
+                    Err(e) => {
+                        let _ = reply.send(Err(e));
+                        return;
+                    }
+                }
+            }
+            statuses.sort_by(|a, b| a.name().cmp(b.name()));
+            let _ = reply.send(Ok(statuses));
+        }
+        ControlRequest::Pause { actor, reply } => {
+            let result = match control_tracker(actors, &actor) {
+                Ok(tracker) => tracker.pause().await.map_err(persistence_error),
+                Err(e) => Err(e),
+            };
+            if result.is_ok() {
+                info!(actor = %actor, "Actor paused via control API");
+            }
+            let _ = reply.send(result);
+        }
+        ControlRequest::Resume { actor, reply } => {
+            let result = match control_tracker(actors, &actor) {
+                Ok(tracker) => tracker.resume().await.map_err(persistence_error),
+                Err(e) => Err(e),
+            };
+            if result.is_ok() {
+                info!(actor = %actor, "Actor resumed via control API");
+            }
+            let _ = reply.send(result);
+        }
+        ControlRequest::Trigger { actor, reply } => {
+            let Some((entry_actor, _, last_run, tracker)) = actors.get_mut(&actor) else {
+                let _ = reply.send(Err(actor_not_found(&actor)));
+                return;
+            };
+
+            info!(actor = %actor, "Executing actor via control API");
+            let succeeded = execute_actor(
+                &actor,
+                entry_actor,
+                tracker.as_ref(),
+                &services.db_pool,
+                HashMap::new(),
+                #[cfg(feature = "metrics")]
+                metrics,
+            )
+            .await;
+            if succeeded {
+                *last_run = Some(Utc::now());
+            }
+            let _ = reply.send(Ok(TriggerOutcome::new(actor, succeeded)));
+        }
+        ControlRequest::History {
+            actor,
+            limit,
+            reply,
+        } => {
+            let result = match control_tracker(actors, &actor) {
+                Ok(tracker) => tracker.history(limit).await.map_err(persistence_error),
+                Err(e) => Err(e),
+            };
+            let _ = reply.send(result);
+        }
+        ControlRequest::Circuit { actor, reply } => {
+            let result = match control_tracker(actors, &actor) {
+                Ok(tracker) => tracker.circuit_state().await.map_err(persistence_error),
+                Err(e) => Err(e),
+            };
+            let _ = reply.send(result);
+        }
+        ControlRequest::Reload { actor, reply } => {
+            let result = reload_actor(
+                &actor,
+                actors,
+                actor_triggers,
+                persistence,
+                config_path,
+                services,
+            )
+            .await;
+            if result.is_ok() {
+                info!(actor = %actor, "Actor reloaded via control API");
+            }
+            let _ = reply.send(result);
+        }
+    }
+}
+
+/// Re-read the server configuration and rebuild one actor.
+///
+/// The actor keeps its last run and execution history. An actor added to
+/// the configuration since startup is loaded as well.
+#[cfg(feature = "discord")]
+async fn reload_actor(
+    name: &str,
+    actors: &mut HashMap<String, ActorEntry>,
+    actor_triggers: &mut HashMap<String, Vec<EventTrigger>>,
+    persistence: Option<&Arc<DatabaseStatePersistence>>,
+    config_path: &Path,
+    services: &ActorServices,
+) -> ActorResult<()> {
+    let server_config = ActorServerConfig::from_file(config_path)
+        .map_err(|e| ActorError::new(ActorErrorKind::InvalidConfiguration(e.to_string())))?;
+    let instance = server_config
+        .actor(name)
+        .ok_or_else(|| actor_not_found(name))?;
+    if !instance.enabled {
+        return Err(ActorError::new(ActorErrorKind::InvalidConfiguration(
+            format!("Actor '{}' is disabled in the server configuration", name),
+        )));
+    }
+
+    let (actor, schedule) = build_actor(instance, services)
+        .await
+        .map_err(|e| ActorError::new(ActorErrorKind::InvalidConfiguration(e.to_string())))?;
+
+    match actors.get_mut(name) {
+        Some(entry) => {
+            entry.0 = actor;
+            entry.1 = schedule;
+        }
+        None => {
+            let tracker = persistence.map(|p| create_tracker(p, name));
+            actors.insert(name.to_string(), (actor, schedule, None, tracker));
+        }
+    }
+
+    if instance.triggers.is_empty() {
+        actor_triggers.remove(name);
+    } else {
+        actor_triggers.insert(name.to_string(), instance.triggers.clone());
+    }
+
+    Ok(())
+}
+
+/// Execution tracker of a loaded actor, for control requests that need state persistence.
+#[cfg(feature = "discord")]
+fn control_tracker<'a>(
+    actors: &'a HashMap<String, ActorEntry>,
+    name: &str,
+) -> ActorResult<&'a ActorExecutionTracker<DatabaseStatePersistence>> {
+    let (_, _, _, tracker) = actors.get(name).ok_or_else(|| actor_not_found(name))?;
+    tracker.as_ref().ok_or_else(|| {
+        ActorError::new(ActorErrorKind::ResourceUnavailable(
+            "State persistence is disabled (DATABASE_URL not set)".to_string(),
+        ))
+    })
+}
+
+#[cfg(feature = "discord")]
+#[track_caller]
+fn actor_not_found(name: &str) -> ActorError {
+    ActorError::new(ActorErrorKind::ActorNotFound(name.to_string()))
+}
+
+#[cfg(feature = "discord")]
+#[track_caller]
+fn persistence_error(e: Box<dyn std::error::Error + Send + Sync>) -> ActorError {
+    ActorError::new(ActorErrorKind::Database(e.to_string()))
+}
+
+/// Run `actor-server ctl` against a running server.
+///
+/// The URL and token fall back to `[server.control]` in the config file, so
+/// on the server's own host `actor-server ctl list` needs no arguments.
+async fn run_ctl(ctl: CtlArgs, config_path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let control = match (&ctl.url, &ctl.token) {
+        (Some(_), Some(_)) => None,
+        _ => ActorServerConfig::from_file(config_path)
+            .ok()
+            .and_then(|config| config.server.control),
+    };
+
+    let url = match ctl.url {
+        Some(url) => url,
+        None => format!(
+            "http://{}",
+            control
+                .as_ref()
+                .map(|c| c.bind.as_str())
+                .ok_or("No control URL: pass --url or set [server.control] in the config file")?
+        ),
+    };
+    let token = match ctl.token.or_else(|| control.map(|c| c.token)) {
+        Some(token) => botticelli_secrets::resolve_secret_reference(&token)?,
+        None => {
+            return Err(
+                "No control token: pass --token or set [server.control] in the config file".into(),
+            );
+        }
+    };
+
+    let client = ControlClient::new(url, token);
+    let output = match ctl.action {
+        CtlAction::List => serde_json::to_value(client.list_actors().await?)?,
+        CtlAction::Pause { actor } => {
+            client.pause(&actor).await?;
+            serde_json::json!({ "actor": actor, "paused": true })
+        }
+        CtlAction::Resume { actor } => {
+            client.resume(&actor).await?;
+            serde_json::json!({ "actor": actor, "paused": false })
+        }
+        CtlAction::Trigger { actor } => serde_json::to_value(client.trigger(&actor).await?)?,
+        CtlAction::History { actor, limit } => {
+            serde_json::to_value(client.history(&actor, limit).await?)?
+        }
+        CtlAction::Circuit { actor } => serde_json::to_value(client.circuit(&actor).await?)?,
+        CtlAction::Reload { actor } => {
+            client.reload(&actor).await?;
+            serde_json::json!({ "actor": actor, "reloaded": true })
+        }
+    };
+
+    println!("{}", serde_json::to_string_pretty(&output)?);
+    Ok(())
+}
+
+/// Wait for the next platform event, or forever if no events are subscribed.
+///
+/// Lagging behind the gateway drops the oldest events; `None` means the
+/// publisher has gone away.
+#[cfg(feature = "discord")]
+async fn next_event(
+    events: &mut Option<broadcast::Receiver<PlatformEvent>>,
+) -> Option<PlatformEvent> {
+    let Some(receiver) = events else {
+        return std::future::pending().await;
+    };
+
+    loop {
+        match receiver.recv().await {
+            Ok(event) => return Some(event),
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!(skipped, "Event consumer lagging, dropped events");
+            }
+            Err(broadcast::error::RecvError::Closed) => return None,
+        }
+    }
+}
+
+/// Check whether this replica holds the actor's lease, taking it if free.
+///
+/// Without leasing every actor runs here. On taking over a lease the last
+/// run is reloaded from the database, so an actor that just ran on another
+/// replica isn't run again.
+#[cfg(feature = "discord")]
+async fn lease_allows(
+    name: &str,
+    leases: Option<&TaskLeaseManager>,
+    persistence: Option<&Arc<DatabaseStatePersistence>>,
+    last_run: &mut Option<DateTime<Utc>>,
+) -> bool {
+    let Some(leases) = leases else {
+        return true;
+    };
+
+    match leases.try_acquire(name).await {
+        Ok(LeaseStatus::Renewed) => true,
+        Ok(LeaseStatus::Acquired) => {
+            let Some(persistence) = persistence else {
+                return true;
+            };
+            match persistence.load_task_state(name).await {
+                Ok(state) => {
+                    *last_run = state
+                        .and_then(|s| s.last_run)
+                        .map(|dt| DateTime::from_naive_utc_and_offset(dt, Utc));
+                    info!(actor = %name, last_run = ?last_run, "Took over actor lease");
+                    true
+                }
+                Err(e) => {
+                    warn!(
+                        actor = %name,
+                        error = ?e,
+                        "Failed to load state after taking lease, skipping"
+                    );
+                    false
+                }
+            }
+        }
+        Ok(LeaseStatus::HeldBy(owner)) => {
+            debug!(actor = %name, owner = %owner, "Actor leased by another replica, skipping");
+            false
+        }
+        Err(e) => {
+            warn!(
+                actor = %name,
+                error = ?e,
+                "Failed to check actor lease, skipping"
+            );
+            false
+        }
+    }
+}
+
+/// Check whether the circuit breaker allows the actor to run.
+#[cfg(feature = "discord")]
+async fn circuit_allows(
+    name: &str,
+    tracker: Option<&ActorExecutionTracker<DatabaseStatePersistence>>,
+) -> bool {
+    let Some(tracker) = tracker else {
+        return true;
+    };
+
+    match tracker.should_execute().await {
+        Ok(true) => true,
+        Ok(false) => {
+            debug!(actor = %name, "Task paused by circuit breaker, skipping");
+            false
+        }
+        Err(e) => {
+            warn!(
+                actor = %name,
+                error = ?e,
+                "Failed to check circuit breaker state, skipping"
+            );
+            false
+        }
+    }
+}
+
+/// Execute an actor and record the outcome, returning whether it succeeded.
+#[cfg(feature = "discord")]
+async fn execute_actor(
+    name: &str,
+    actor: &Actor,
+    tracker: Option<&ActorExecutionTracker<DatabaseStatePersistence>>,
+    db_pool: &DatabasePool,
+    variables: HashMap<String, String>,
+    #[cfg(feature = "metrics")] metrics: &ServerMetrics,
+) -> bool {
+    // Start execution history if tracker available
+    let exec_id = if let Some(tracker) = tracker {
+        match tracker.start_execution().await {
+            Ok(id) => {
+                debug!(actor = %name, exec_id = id, "Started execution record");
+                Some(id)
+            }
+            Err(e) => {
+                warn!(
+                    actor = %name,
+                    error = ?e,
+                    "Failed to start execution record"
+                );
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // Execute the actor with database pool
+    let start_time = std::time::Instant::now();
+    match actor.execute_with_variables(db_pool, variables).await {
+        Ok(result) => {
+            let duration = start_time.elapsed().as_secs_f64();
+            info!(
+                actor = %name,
+                skills_succeeded = result.succeeded.len(),
+                skills_failed = result.failed.len(),
+                skills_skipped = result.skipped.len(),
+                duration_secs = duration,
+                "Actor executed successfully"
+            );
+
+            // Record metrics (if enabled)
+            #[cfg(feature = "metrics")]
+            metrics.bots.record_execution(name, duration);
+
+            // Record success if tracker available
+            if let Some(exec_id) = exec_id
+                && let Some(tracker) = tracker
+            {
+                let db_result = DatabaseExecutionResult {
+                    skills_succeeded: result.succeeded.len() as i32,
+                    skills_failed: result.failed.len() as i32,
+                    skills_skipped: result.skipped.len() as i32,
+                    metadata: serde_json::json!({}),
+                };
+
+                if let Err(e) = tracker.record_success(exec_id, db_result).await {
+                    warn!(
+                        actor = %name,
+                        error = ?e,
+                        "Failed to record success"
+                    );
+                }
+            }
+
+            true
+        }
+        Err(e) => {
+            error!(actor = %name, error = ?e, "Actor execution failed");
+
+            // Record failure metric (if enabled)
+            #[cfg(feature = "metrics")]
+            metrics.bots.record_failure(name);
+
+            // Record failure if tracker available
+            if let Some(exec_id) = exec_id
+                && let Some(tracker) = tracker
+            {
+                match tracker.record_failure(exec_id, &e.to_string()).await {
+                    Ok(should_pause) => {
+                        if should_pause {
+                            warn!(
+                                actor = %name,
+                                "Circuit breaker triggered, task paused"
+                            );
+                        }
+                    }
+                    Err(e) => {
+                        warn!(
+                            actor = %name,
+                            error = ?e,
+                            "Failed to record failure"
+                        );
+                    }
+                }
+            }
+
+            false
+        }
+    }
+}
@@ -2,7 +2,8 @@
 
 use crate::{ActorError, ActorErrorKind, ActorResult};
 use derive_getters::Getters;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -109,6 +110,71 @@ impl Default for ExecutionConfig {
     }
 }
 
+/// WASM skill plugin configuration (`[actor.plugins]`).
+///
+/// Every `.wasm` component in `directory` is loaded as a skill when the
+/// `wasm-plugins` feature is enabled. Plugins only reach the outside world
+/// through host functions, which these settings restrict.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, derive_builder::Builder)]
+#[builder(setter(into))]
+pub struct PluginConfig {
+    /// Directory containing skill components.
+    directory: PathBuf,
+
+    /// Hosts plugins may call through the `http` host function.
+    #[builder(default)]
+    #[serde(default)]
+    allowed_hosts: Vec<String>,
+
+    /// Whether plugins may post to the actor's platform.
+    #[builder(default)]
+    #[serde(default)]
+    allow_post: bool,
+
+    /// Fuel budget per execution (roughly one unit per instruction).
+    #[builder(default = "default_plugin_fuel()")]
+    #[serde(default = "default_plugin_fuel")]
+    fuel: u64,
+
+    /// Maximum linear memory per plugin instance, in megabytes.
+    #[builder(default = "default_plugin_max_memory_mb()")]
+    #[serde(default = "default_plugin_max_memory_mb")]
+    max_memory_mb: usize,
+
+    /// Wall-clock limit per execution, in seconds.
+    #[builder(default = "default_plugin_timeout_secs()")]
+    #[serde(default = "default_plugin_timeout_secs")]
+    timeout_secs: u64,
+
+    /// Largest response body the `http` host function reads, in kilobytes.
+    #[builder(default = "default_plugin_max_response_kb()")]
+    #[serde(default = "default_plugin_max_response_kb")]
+    max_response_kb: usize,
+}
+
+fn default_plugin_fuel() -> u64 {
+    1_000_000_000
+}
+
+fn default_plugin_max_memory_mb() -> usize {
+    64
+}
+
+fn default_plugin_timeout_secs() -> u64 {
+    30
+}
+
+fn default_plugin_max_response_kb() -> usize {
+    1024
+}
+
+impl PluginConfig {
+    /// Get a builder for plugin configuration.
+    pub fn builder() -> PluginConfigBuilder {
+        PluginConfigBuilder::default()
+    }
+}
+
 /// Actor settings with sensible defaults.
 #[derive(Debug, Clone, Serialize, Deserialize, Getters, derive_builder::Builder)]
 pub struct ActorSettings {
@@ -169,6 +235,19 @@ pub struct SkillConfig {
     #[serde(default = "default_skill_enabled")]
     enabled: bool,
 
+    /// Earlier skills whose output this skill consumes.
+    ///
+    /// Accepts a single name (`inputs_from = "content_selection"`) or a list.
+    /// The skill is skipped when none of its sources produced output.
+    #[builder(default)]
+    #[serde(default, deserialize_with = "one_or_many")]
+    inputs_from: Vec<String>,
+
+    /// Stop the pipeline after this skill when its output matches.
+    #[builder(default, setter(strip_option))]
+    #[serde(default)]
+    stop_if: Option<StopCondition>,
+
     /// Skill-specific settings.
     #[builder(default)]
     #[serde(default)]
@@ -180,6 +259,80 @@ fn default_skill_enabled() -> bool {
     true
 }
 
+fn one_or_many<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(name) => vec![name],
+        OneOrMany::Many(names) => names,
+    })
+}
+
+/// Short-circuit rule evaluated against a skill's output data.
+///
+/// # Example
+///
+/// ```toml
+/// [skills.duplicate_check]
+/// inputs_from = "content_selection"
+/// stop_if = { pointer = "/candidates", empty = true }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Getters, derive_builder::Builder)]
+#[builder(setter(into))]
+pub struct StopCondition {
+    /// JSON pointer into the output data (e.g. `"/is_duplicate"`).
+    pointer: String,
+
+    /// Stop when the value equals this.
+    #[builder(default, setter(strip_option))]
+    #[serde(default)]
+    equals: Option<JsonValue>,
+
+    /// Stop when the value is empty or missing (`true`) or present and non-empty (`false`).
+    #[builder(default, setter(strip_option))]
+    #[serde(default)]
+    empty: Option<bool>,
+}
+
+impl StopCondition {
+    /// Check whether `data` triggers the stop.
+    ///
+    /// With neither `equals` nor `empty` set, stops when the value is truthy
+    /// (present, not `null`, `false`, `0` or empty).
+    pub fn matches(&self, data: &JsonValue) -> bool {
+        let value = data.pointer(&self.pointer);
+
+        if let Some(expected) = &self.equals {
+            return value == Some(expected);
+        }
+
+        let is_empty = match value {
+            None | Some(JsonValue::Null) => true,
+            Some(JsonValue::String(s)) => s.is_empty(),
+            Some(JsonValue::Array(a)) => a.is_empty(),
+            Some(JsonValue::Object(o)) => o.is_empty(),
+            Some(_) => false,
+        };
+
+        match self.empty {
+            Some(empty) => is_empty == empty,
+            None => {
+                !is_empty
+                    && value != Some(&JsonValue::Bool(false))
+                    && value.and_then(JsonValue::as_f64) != Some(0.0)
+            }
+        }
+    }
+}
+
 impl Default for SkillConfig {
     fn default() -> Self {
         SkillConfigBuilder::default()
@@ -223,6 +376,11 @@ pub struct ActorConfig {
     #[builder(default)]
     #[serde(default)]
     skill_configs: HashMap<String, SkillConfig>,
+
+    /// WASM skill plugins.
+    #[builder(default)]
+    #[serde(default)]
+    plugins: Option<PluginConfig>,
 }
 
 impl ActorConfig {
@@ -287,6 +445,7 @@ impl ActorConfig {
             cache: config.actor.cache.unwrap_or_default(),
             execution: config.actor.execution.unwrap_or_default(),
             skill_configs: config.skills.unwrap_or_default(),
+            plugins: config.actor.plugins,
         })
     }
 
@@ -327,12 +486,34 @@ impl ActorConfig {
             ));
         }
 
-        for skill in &self.skills {
-            if let Some(skill_config) = self.skill_configs.get(skill)
-                && !skill_config.enabled()
-            {
+        for (position, skill) in self.skills.iter().enumerate() {
+            let Some(skill_config) = self.skill_configs.get(skill) else {
+                continue;
+            };
+
+            if !skill_config.enabled() {
                 warnings.push(format!("Skill '{}' is configured but disabled", skill));
             }
+
+            for source in skill_config.inputs_from() {
+                if !self.skills[..position].contains(source) {
+                    warnings.push(format!(
+                        "Skill '{}' takes inputs from '{}', which does not run before it",
+                        skill, source
+                    ));
+                }
+            }
+
+            if let Some(stop_if) = skill_config.stop_if()
+                && !stop_if.pointer().is_empty()
+                && !stop_if.pointer().starts_with('/')
+            {
+                warnings.push(format!(
+                    "Skill '{}' stop_if pointer '{}' should start with '/'",
+                    skill,
+                    stop_if.pointer()
+                ));
+            }
         }
 
         // Validate retry attempts range
@@ -399,4 +580,6 @@ struct ActorSection {
     cache: Option<ActorCacheConfig>,
     #[serde(default)]
     execution: Option<ExecutionConfig>,
+    #[serde(default)]
+    plugins: Option<PluginConfig>,
 }
@@ -0,0 +1,201 @@
+//! Authenticated HTTP API over a [`ControlHandle`].
+
+use super::ControlHandle;
+use crate::{ActorError, ActorErrorKind};
+use axum::{
+    Json, Router,
+    extract::{Path, Query, Request, State},
+    http::{HeaderMap, StatusCode, header},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use serde::Deserialize;
+use serde_json::json;
+use std::sync::Arc;
+use tracing::{instrument, warn};
+
+/// Executions returned by the history endpoint when no limit is given.
+const DEFAULT_HISTORY_LIMIT: i64 = 20;
+
+/// Control API state.
+#[derive(Clone)]
+struct ControlState {
+    handle: ControlHandle,
+    token: Arc<str>,
+}
+
+/// Query parameters of the history endpoint.
+#[derive(Debug, Deserialize)]
+struct HistoryQuery {
+    limit: Option<i64>,
+}
+
+/// Creates the control API router.
+///
+/// Every route except `/health` requires an `Authorization: Bearer <token>`
+/// header matching `token`.
+///
+/// | Method | Path | Action |
+/// |--------|------|--------|
+/// | GET | `/actors` | List actors |
+/// | POST | `/actors/:name/pause` | Pause an actor |
+/// | POST | `/actors/:name/resume` | Resume an actor |
+/// | POST | `/actors/:name/trigger` | Run an actor now |
+/// | GET | `/actors/:name/history?limit=N` | Recent executions |
+/// | GET | `/actors/:name/circuit` | Circuit-breaker state |
+/// | POST | `/actors/:name/reload` | Reload the actor's config file |
+pub fn create_control_router(handle: ControlHandle, token: impl Into<String>) -> Router {
+    let state = ControlState {
+        handle,
+        token: Arc::from(token.into()),
+    };
+
+    let protected = Router::new()
+        .route("/actors", get(list_actors))
+        .route("/actors/:name/pause", post(pause_actor))
+        .route("/actors/:name/resume", post(resume_actor))
+        .route("/actors/:name/trigger", post(trigger_actor))
+        .route("/actors/:name/history", get(actor_history))
+        .route("/actors/:name/circuit", get(actor_circuit))
+        .route("/actors/:name/reload", post(reload_actor))
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_token));
+
+    Router::new()
+        .route("/health", get(health_check))
+        .merge(protected)
+        .with_state(state)
+}
+
+/// Reject requests without the control token.
+async fn require_token(
+    State(state): State<ControlState>,
+    headers: HeaderMap,
+    request: Request,
+    next: Next,
+) -> Response {
+    let presented = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match presented {
+        Some(token) if tokens_match(token, &state.token) => next.run(request).await,
+        _ => {
+            warn!(path = %request.uri().path(), "Rejected unauthenticated control request");
+            error_response(ActorError::new(ActorErrorKind::AuthenticationFailed(
+                "Missing or invalid control token".to_string(),
+            )))
+        }
+    }
+}
+
+/// Compare tokens without short-circuiting on the first differing byte.
+fn tokens_match(presented: &str, expected: &str) -> bool {
+    presented.len() == expected.len()
+        && presented
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
+/// Map an actor error to a status code and JSON body.
+fn error_response(error: ActorError) -> Response {
+    let status = match &error.kind {
+        ActorErrorKind::ActorNotFound(_) => StatusCode::NOT_FOUND,
+        ActorErrorKind::AuthenticationFailed(_) => StatusCode::UNAUTHORIZED,
+        ActorErrorKind::ResourceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+        ActorErrorKind::InvalidConfiguration(_)
+        | ActorErrorKind::TomlParse(_)
+        | ActorErrorKind::FileIo { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    (status, Json(json!({ "error": error.kind.to_string() }))).into_response()
+}
+
+/// Health check endpoint.
+#[instrument(skip_all)]
+async fn health_check() -> impl IntoResponse {
+    (StatusCode::OK, Json(json!({ "status": "ok" })))
+}
+
+/// List loaded actors.
+#[instrument(skip(state))]
+async fn list_actors(State(state): State<ControlState>) -> Response {
+    match state.handle.list_actors().await {
+        Ok(actors) => (StatusCode::OK, Json(actors)).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Pause an actor.
+#[instrument(skip(state))]
+async fn pause_actor(State(state): State<ControlState>, Path(name): Path<String>) -> Response {
+    match state.handle.pause(&name).await {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(json!({ "actor": name, "paused": true })),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Resume an actor.
+#[instrument(skip(state))]
+async fn resume_actor(State(state): State<ControlState>, Path(name): Path<String>) -> Response {
+    match state.handle.resume(&name).await {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(json!({ "actor": name, "paused": false })),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Run an actor now.
+#[instrument(skip(state))]
+async fn trigger_actor(State(state): State<ControlState>, Path(name): Path<String>) -> Response {
+    match state.handle.trigger(&name).await {
+        Ok(outcome) => (StatusCode::OK, Json(outcome)).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Recent executions of an actor.
+#[instrument(skip(state))]
+async fn actor_history(
+    State(state): State<ControlState>,
+    Path(name): Path<String>,
+    Query(query): Query<HistoryQuery>,
+) -> Response {
+    let limit = query.limit.unwrap_or(DEFAULT_HISTORY_LIMIT).max(1);
+    match state.handle.history(&name, limit).await {
+        Ok(history) => (StatusCode::OK, Json(history)).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Circuit-breaker state of an actor.
+#[instrument(skip(state))]
+async fn actor_circuit(State(state): State<ControlState>, Path(name): Path<String>) -> Response {
+    match state.handle.circuit(&name).await {
+        Ok(circuit) => (StatusCode::OK, Json(circuit)).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Reload an actor's configuration file.
+#[instrument(skip(state))]
+async fn reload_actor(State(state): State<ControlState>, Path(name): Path<String>) -> Response {
+    match state.handle.reload(&name).await {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(json!({ "actor": name, "reloaded": true })),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
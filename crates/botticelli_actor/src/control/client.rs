@@ -0,0 +1,130 @@
+//! Client for the control API, used by `actor-server ctl`.
+
+use super::{ActorStatus, CircuitState, ExecutionRecord, TriggerOutcome};
+use crate::{ActorError, ActorErrorKind, ActorResult};
+use reqwest::{Method, StatusCode};
+use serde::de::DeserializeOwned;
+use serde_json::Value as JsonValue;
+
+/// HTTP client for a running actor server's control API.
+#[derive(Debug, Clone)]
+pub struct ControlClient {
+    base_url: String,
+    token: String,
+    http: reqwest::Client,
+}
+
+impl ControlClient {
+    /// Create a client for the API at `base_url` (e.g. `http://127.0.0.1:8950`).
+    pub fn new(base_url: impl Into<String>, token: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            token: token.into(),
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// List actors loaded by the server.
+    pub async fn list_actors(&self) -> ActorResult<Vec<ActorStatus>> {
+        self.send(Method::GET, "/actors".to_string()).await
+    }
+
+    /// Pause an actor.
+    pub async fn pause(&self, actor: &str) -> ActorResult<()> {
+        self.send::<JsonValue>(Method::POST, actor_path(actor, "pause"))
+            .await
+            .map(|_| ())
+    }
+
+    /// Resume an actor.
+    pub async fn resume(&self, actor: &str) -> ActorResult<()> {
+        self.send::<JsonValue>(Method::POST, actor_path(actor, "resume"))
+            .await
+            .map(|_| ())
+    }
+
+    /// Run an actor now and wait for it to finish.
+    pub async fn trigger(&self, actor: &str) -> ActorResult<TriggerOutcome> {
+        self.send(Method::POST, actor_path(actor, "trigger")).await
+    }
+
+    /// Recent executions of an actor, newest first.
+    pub async fn history(&self, actor: &str, limit: i64) -> ActorResult<Vec<ExecutionRecord>> {
+        let path = format!("{}?limit={}", actor_path(actor, "history"), limit);
+        self.send(Method::GET, path).await
+    }
+
+    /// Circuit-breaker state of an actor.
+    pub async fn circuit(&self, actor: &str) -> ActorResult<CircuitState> {
+        self.send(Method::GET, actor_path(actor, "circuit")).await
+    }
+
+    /// Reload an actor's configuration file.
+    pub async fn reload(&self, actor: &str) -> ActorResult<()> {
+        self.send::<JsonValue>(Method::POST, actor_path(actor, "reload"))
+            .await
+            .map(|_| ())
+    }
+
+    async fn send<T: DeserializeOwned>(&self, method: Method, path: String) -> ActorResult<T> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .http
+            .request(method, &url)
+            .bearer_auth(&self.token)
+            .send()
+            .await
+            .map_err(|e| {
+                ActorError::new(ActorErrorKind::ResourceUnavailable(format!(
+                    "Control API request to {} failed: {}",
+                    url, e
+                )))
+            })?;
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        if !status.is_success() {
+            return Err(status_error(status, &body));
+        }
+
+        Ok(serde_json::from_str(&body)?)
+    }
+}
+
+/// Path of an actor-scoped endpoint.
+fn actor_path(actor: &str, action: &str) -> String {
+    format!("/actors/{}/{}", encode_segment(actor), action)
+}
+
+/// Percent-encode a path segment.
+fn encode_segment(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Map an error response back to the actor error kind the server reported.
+#[track_caller]
+fn status_error(status: StatusCode, body: &str) -> ActorError {
+    let message = serde_json::from_str::<JsonValue>(body)
+        .ok()
+        .and_then(|v| v.get("error").and_then(|e| e.as_str()).map(str::to_string))
+        .unwrap_or_else(|| format!("{}: {}", status, body.trim()));
+
+    let kind = match status {
+        StatusCode::NOT_FOUND => ActorErrorKind::ActorNotFound(message),
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            ActorErrorKind::AuthenticationFailed(message)
+        }
+        StatusCode::SERVICE_UNAVAILABLE => ActorErrorKind::ResourceUnavailable(message),
+        StatusCode::UNPROCESSABLE_ENTITY => ActorErrorKind::InvalidConfiguration(message),
+        _ => ActorErrorKind::Control(message),
+    };
+    ActorError::new(kind)
+}
@@ -0,0 +1,238 @@
+//! Requests from the control API to the running server.
+
+use crate::{ActorError, ActorErrorKind, ActorResult, ScheduleConfig};
+use botticelli_database::ActorServerExecutionRow;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, oneshot};
+
+/// Circuit-breaker state of an actor.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Getters, derive_builder::Builder)]
+pub struct CircuitState {
+    /// Whether the actor is paused (manually or by the circuit breaker).
+    #[builder(default)]
+    paused: bool,
+    /// Failures since the last success.
+    #[builder(default)]
+    consecutive_failures: i32,
+    /// Failures that trip the breaker.
+    max_consecutive_failures: i32,
+}
+
+/// Status of one actor loaded by the server.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, derive_builder::Builder)]
+#[builder(setter(into))]
+pub struct ActorStatus {
+    /// Actor name from the server configuration.
+    name: String,
+    /// Timer schedule.
+    schedule: ScheduleConfig,
+    /// Last successful run.
+    #[builder(default)]
+    last_run: Option<DateTime<Utc>>,
+    /// Next scheduled run, if the schedule has one.
+    #[builder(default)]
+    next_run: Option<DateTime<Utc>>,
+    /// Circuit-breaker state, if state persistence is enabled.
+    #[builder(default)]
+    circuit: Option<CircuitState>,
+}
+
+/// One recorded execution of an actor.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Getters)]
+pub struct ExecutionRecord {
+    /// Execution ID.
+    id: i64,
+    /// Start time.
+    started_at: NaiveDateTime,
+    /// Completion time, if finished.
+    completed_at: Option<NaiveDateTime>,
+    /// Whether the execution succeeded, if finished.
+    success: Option<bool>,
+    /// Error message of a failed execution.
+    error_message: Option<String>,
+    /// Skills that succeeded.
+    skills_succeeded: Option<i32>,
+    /// Skills that failed.
+    skills_failed: Option<i32>,
+    /// Skills that were skipped.
+    skills_skipped: Option<i32>,
+}
+
+impl From<ActorServerExecutionRow> for ExecutionRecord {
+    fn from(row: ActorServerExecutionRow) -> Self {
+        Self {
+            id: row.id,
+            started_at: row.started_at,
+            completed_at: row.completed_at,
+            success: row.success,
+            error_message: row.error_message,
+            skills_succeeded: row.skills_succeeded,
+            skills_failed: row.skills_failed,
+            skills_skipped: row.skills_skipped,
+        }
+    }
+}
+
+/// Result of running an actor on demand.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Getters)]
+pub struct TriggerOutcome {
+    /// Actor that ran.
+    actor: String,
+    /// Whether the run succeeded.
+    succeeded: bool,
+}
+
+impl TriggerOutcome {
+    /// Create a trigger outcome.
+    pub fn new(actor: impl Into<String>, succeeded: bool) -> Self {
+        Self {
+            actor: actor.into(),
+            succeeded,
+        }
+    }
+}
+
+/// Reply channel for a control request.
+pub type ControlReply<T> = oneshot::Sender<ActorResult<T>>;
+
+/// Request handled by the server's main loop.
+///
+/// Each request carries a reply channel; the loop answers once the action
+/// is done, so the caller sees the outcome of the actual change.
+#[derive(Debug)]
+pub enum ControlRequest {
+    /// List loaded actors.
+    ListActors {
+        /// Reply channel.
+        reply: ControlReply<Vec<ActorStatus>>,
+    },
+    /// Pause an actor's scheduled and triggered runs.
+    Pause {
+        /// Actor name.
+        actor: String,
+        /// Reply channel.
+        reply: ControlReply<()>,
+    },
+    /// Resume a paused actor.
+    Resume {
+        /// Actor name.
+        actor: String,
+        /// Reply channel.
+        reply: ControlReply<()>,
+    },
+    /// Run an actor now, outside its schedule.
+    Trigger {
+        /// Actor name.
+        actor: String,
+        /// Reply channel.
+        reply: ControlReply<TriggerOutcome>,
+    },
+    /// Recent executions of an actor, newest first.
+    History {
+        /// Actor name.
+        actor: String,
+        /// Maximum number of executions.
+        limit: i64,
+        /// Reply channel.
+        reply: ControlReply<Vec<ExecutionRecord>>,
+    },
+    /// Circuit-breaker state of an actor.
+    Circuit {
+        /// Actor name.
+        actor: String,
+        /// Reply channel.
+        reply: ControlReply<CircuitState>,
+    },
+    /// Re-read an actor's configuration file and rebuild it.
+    Reload {
+        /// Actor name.
+        actor: String,
+        /// Reply channel.
+        reply: ControlReply<()>,
+    },
+}
+
+/// Sending side of the control channel, shared by the API handlers.
+#[derive(Debug, Clone)]
+pub struct ControlHandle {
+    sender: mpsc::Sender<ControlRequest>,
+}
+
+impl ControlHandle {
+    /// Create a handle and the receiver the server loop reads requests from.
+    pub fn channel(buffer: usize) -> (Self, mpsc::Receiver<ControlRequest>) {
+        let (sender, receiver) = mpsc::channel(buffer);
+        (Self { sender }, receiver)
+    }
+
+    /// List loaded actors.
+    pub async fn list_actors(&self) -> ActorResult<Vec<ActorStatus>> {
+        self.request(|reply| ControlRequest::ListActors { reply })
+            .await
+    }
+
+    /// Pause an actor.
+    pub async fn pause(&self, actor: &str) -> ActorResult<()> {
+        let actor = actor.to_string();
+        self.request(|reply| ControlRequest::Pause { actor, reply })
+            .await
+    }
+
+    /// Resume an actor.
+    pub async fn resume(&self, actor: &str) -> ActorResult<()> {
+        let actor = actor.to_string();
+        self.request(|reply| ControlRequest::Resume { actor, reply })
+            .await
+    }
+
+    /// Run an actor now.
+    pub async fn trigger(&self, actor: &str) -> ActorResult<TriggerOutcome> {
+        let actor = actor.to_string();
+        self.request(|reply| ControlRequest::Trigger { actor, reply })
+            .await
+    }
+
+    /// Recent executions of an actor.
+    pub async fn history(&self, actor: &str, limit: i64) -> ActorResult<Vec<ExecutionRecord>> {
+        let actor = actor.to_string();
+        self.request(|reply| ControlRequest::History {
+            actor,
+            limit,
+            reply,
+        })
+        .await
+    }
+
+    /// Circuit-breaker state of an actor.
+    pub async fn circuit(&self, actor: &str) -> ActorResult<CircuitState> {
+        let actor = actor.to_string();
+        self.request(|reply| ControlRequest::Circuit { actor, reply })
+            .await
+    }
+
+    /// Reload an actor's configuration.
+    pub async fn reload(&self, actor: &str) -> ActorResult<()> {
+        let actor = actor.to_string();
+        self.request(|reply| ControlRequest::Reload { actor, reply })
+            .await
+    }
+
+    async fn request<T>(
+        &self,
+        build: impl FnOnce(ControlReply<T>) -> ControlRequest,
+    ) -> ActorResult<T> {
+        let (reply, response) = oneshot::channel();
+        self.sender.send(build(reply)).await.map_err(|_| {
+            ActorError::new(ActorErrorKind::ResourceUnavailable(
+                "Actor server is shutting down".to_string(),
+            ))
+        })?;
+        response.await.map_err(|_| {
+            ActorError::new(ActorErrorKind::ResourceUnavailable(
+                "Actor server dropped the request".to_string(),
+            ))
+        })?
+    }
+}
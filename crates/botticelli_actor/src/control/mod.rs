@@ -0,0 +1,23 @@
+//! HTTP control plane for a running actor server.
+//!
+//! The server's main loop owns its actors, so the API doesn't touch them
+//! directly: handlers send a [`ControlRequest`] through a [`ControlHandle`]
+//! and the loop answers once the action is done. [`ControlClient`] is the
+//! matching client used by `actor-server ctl`.
+//!
+//! ```toml
+//! [server.control]
+//! bind = "127.0.0.1:8950"
+//! token = "secret:control_token"
+//! ```
+
+mod api;
+mod client;
+mod handle;
+
+pub use api::create_control_router;
+pub use client::ControlClient;
+pub use handle::{
+    ActorStatus, ActorStatusBuilder, CircuitState, CircuitStateBuilder, ControlHandle,
+    ControlReply, ControlRequest, ExecutionRecord, TriggerOutcome,
+};
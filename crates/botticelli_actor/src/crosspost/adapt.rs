@@ -0,0 +1,393 @@
+//! Per-destination content adaptation.
+
+use crate::{Content, MediaType, PlatformCapability, PlatformMessage};
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Ellipsis appended to truncated text.
+const ELLIPSIS: char = '…';
+
+/// Markup dialect a destination renders.
+///
+/// Content is assumed to be written in Markdown; other dialects are
+/// converted from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MarkdownDialect {
+    /// Markdown is passed through unchanged (Discord, most webhooks).
+    #[default]
+    Markdown,
+    /// Markup is stripped; links become `text (url)` (Mastodon, Bluesky, Telegram).
+    Plain,
+    /// Markup is converted to HTML tags (Telegram `HTML` parse mode, Matrix).
+    Html,
+}
+
+/// How content is adapted for one destination.
+///
+/// # Example
+///
+/// ```toml
+/// max_length = 500
+/// markdown = "plain"
+/// max_hashtags = 3
+/// extra_hashtags = ["#art"]
+/// mentions = { botticelli = "@botticelli@mastodon.social" }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Getters, derive_builder::Builder)]
+#[builder(setter(into), default)]
+#[serde(default)]
+pub struct AdaptationRules {
+    /// Maximum text length in characters (`None` for unlimited).
+    ///
+    /// Ignored for platforms with [`PlatformCapability::Threads`] when
+    /// `allow_threads` is set, since they split long text themselves.
+    max_length: Option<usize>,
+
+    /// Let threading platforms split long text instead of truncating.
+    allow_threads: bool,
+
+    /// Markup dialect the destination renders.
+    markdown: MarkdownDialect,
+
+    /// Mention rewrites: `@name` in content becomes the mapped value.
+    mentions: HashMap<String, String>,
+
+    /// Drop the `@` from mentions with no rewrite so they don't notify the
+    /// wrong account on this platform.
+    plain_unmapped_mentions: bool,
+
+    /// Maximum hashtags kept from the content (`None` keeps all).
+    max_hashtags: Option<usize>,
+
+    /// Hashtags appended when not already present and they fit.
+    extra_hashtags: Vec<String>,
+
+    /// Maximum media attachments (`None` for unlimited).
+    max_media: Option<usize>,
+}
+
+impl Default for AdaptationRules {
+    fn default() -> Self {
+        Self {
+            max_length: None,
+            allow_threads: true,
+            markdown: MarkdownDialect::default(),
+            mentions: HashMap::new(),
+            plain_unmapped_mentions: false,
+            max_hashtags: None,
+            extra_hashtags: Vec::new(),
+            max_media: None,
+        }
+    }
+}
+
+impl AdaptationRules {
+    /// Get a builder for adaptation rules.
+    pub fn builder() -> AdaptationRulesBuilder {
+        AdaptationRulesBuilder::default()
+    }
+
+    /// Default rules for a platform name as returned by
+    /// [`Platform::platform_name`](crate::Platform::platform_name).
+    ///
+    /// Unknown platforms get no length or media limits and Markdown passthrough.
+    pub fn for_platform(platform_name: &str) -> Self {
+        let (max_length, markdown, max_media) = match platform_name {
+            "discord" => (Some(2000), MarkdownDialect::Markdown, Some(10)),
+            "mastodon" => (Some(500), MarkdownDialect::Plain, Some(4)),
+            "bluesky" => (Some(300), MarkdownDialect::Plain, Some(4)),
+            "telegram" => (Some(4096), MarkdownDialect::Plain, Some(10)),
+            _ => (None, MarkdownDialect::Markdown, None),
+        };
+        Self {
+            max_length,
+            markdown,
+            // Handles resolve per instance/PDS, so a stray @name may notify a stranger
+            plain_unmapped_mentions: matches!(platform_name, "mastodon" | "bluesky"),
+            max_media,
+            ..Self::default()
+        }
+    }
+
+    /// Adapt content into a message for a platform with `capabilities`.
+    ///
+    /// Steps run in order: mention rewriting, markup conversion, hashtag
+    /// limits, length limits, then media filtering by capability.
+    #[tracing::instrument(skip_all, fields(markdown = ?self.markdown))]
+    pub fn adapt(&self, content: &Content, capabilities: &[PlatformCapability]) -> PlatformMessage {
+        let text = content.text().as_deref().unwrap_or("");
+        let text = rewrite_mentions(text, &self.mentions, self.plain_unmapped_mentions);
+        let text = convert_markdown(&text, self.markdown);
+        let text = limit_hashtags(&text, self.max_hashtags);
+
+        let threads = self.allow_threads && capabilities.contains(&PlatformCapability::Threads);
+        let max_length = self.max_length.filter(|_| !threads);
+        let text = append_hashtags(text.trim(), &self.extra_hashtags, max_length);
+        let text = match max_length {
+            Some(max) => truncate(&text, max),
+            None => text,
+        };
+
+        let media_urls: Vec<String> = content
+            .media()
+            .iter()
+            .filter(|media| match media.media_type() {
+                MediaType::Image => capabilities.contains(&PlatformCapability::Images),
+                MediaType::Video => capabilities.contains(&PlatformCapability::Videos),
+                MediaType::Audio => false,
+            })
+            .take(self.max_media.unwrap_or(usize::MAX))
+            .map(|media| media.url().clone())
+            .collect();
+
+        tracing::debug!(
+            length = text.chars().count(),
+            media = media_urls.len(),
+            dropped_media = content.media().len() - media_urls.len(),
+            "Adapted content"
+        );
+
+        PlatformMessage {
+            text,
+            media_urls,
+            content_id: None,
+        }
+    }
+}
+
+/// Start of a `@mention` or `#hashtag` token: start of text or after a
+/// character that cannot be part of a word or address.
+fn at_token_start(text: &str, index: usize) -> bool {
+    text[..index]
+        .chars()
+        .next_back()
+        .is_none_or(|c| !(c.is_alphanumeric() || c == '_' || c == '@' || c == '#' || c == '/'))
+}
+
+/// Length in bytes of the token name starting at `rest`.
+fn token_len(rest: &str, extra: &[char]) -> usize {
+    let end = rest
+        .char_indices()
+        .find(|(_, c)| !(c.is_alphanumeric() || *c == '_' || extra.contains(c)))
+        .map_or(rest.len(), |(i, _)| i);
+    // Trailing punctuation ends a sentence, not the name
+    rest[..end].trim_end_matches(extra).len()
+}
+
+fn rewrite_mentions(
+    text: &str,
+    mentions: &HashMap<String, String>,
+    plain_unmapped: bool,
+) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for (index, _) in text.match_indices('@') {
+        if index < last || !at_token_start(text, index) {
+            continue;
+        }
+        let name_len = token_len(&text[index + 1..], &['.', '-']);
+        if name_len == 0 {
+            continue;
+        }
+        let name = &text[index + 1..index + 1 + name_len];
+        out.push_str(&text[last..index]);
+        match mentions.get(name) {
+            Some(replacement) => out.push_str(replacement),
+            None if plain_unmapped => out.push_str(name),
+            None => {
+                out.push('@');
+                out.push_str(name);
+            }
+        }
+        last = index + 1 + name_len;
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+/// Convert Markdown emphasis, code and links to the target dialect.
+///
+/// Handles `**bold**`, `__bold__`, `*italic*`, `~~strike~~`, `` `code` ``
+/// and `[text](url)`; anything else is left as written.
+fn convert_markdown(text: &str, dialect: MarkdownDialect) -> String {
+    if dialect == MarkdownDialect::Markdown {
+        return text.to_string();
+    }
+    let html = dialect == MarkdownDialect::Html;
+
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        // Inline code is copied verbatim (escaped for HTML)
+        if c == '`'
+            && let Some(end) = rest[1..].find('`')
+        {
+            let code = &rest[1..1 + end];
+            if html {
+                out.push_str("<code>");
+                out.push_str(&escape_html(code));
+                out.push_str("</code>");
+            } else {
+                out.push_str(code);
+            }
+            rest = &rest[end + 2..];
+            continue;
+        }
+
+        if c == '['
+            && let Some((label, url, consumed)) = parse_link(rest)
+        {
+            if html {
+                out.push_str(&format!(
+                    "<a href=\"{}\">{}</a>",
+                    escape_html(url),
+                    convert_markdown(label, dialect)
+                ));
+            } else if label == url {
+                out.push_str(url);
+            } else {
+                out.push_str(&format!("{} ({})", convert_markdown(label, dialect), url));
+            }
+            rest = &rest[consumed..];
+            continue;
+        }
+
+        let emphasis =
+            [("**", "b"), ("__", "b"), ("~~", "s"), ("*", "i")]
+                .into_iter()
+                .find_map(|(marker, tag)| {
+                    let inner = rest.strip_prefix(marker)?;
+                    let end = inner.find(marker)?;
+                    let body = &inner[..end];
+                    (!body.is_empty() && !body.starts_with(' ') && !body.ends_with(' '))
+                        .then_some((tag, body, marker.len() * 2 + end))
+                });
+        if let Some((tag, body, consumed)) = emphasis {
+            let body = convert_markdown(body, dialect);
+            if html {
+                out.push_str(&format!("<{tag}>{body}</{tag}>"));
+            } else {
+                out.push_str(&body);
+            }
+            rest = &rest[consumed..];
+            continue;
+        }
+
+        match c {
+            '<' if html => out.push_str("&lt;"),
+            '>' if html => out.push_str("&gt;"),
+            '&' if html => out.push_str("&amp;"),
+            _ => out.push(c),
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+    out
+}
+
+/// Parse `[label](url)` at the start of `text`, returning bytes consumed.
+fn parse_link(text: &str) -> Option<(&str, &str, usize)> {
+    let close = text.find("](")?;
+    let label = &text[1..close];
+    if label.contains('[') || label.contains('\n') {
+        return None;
+    }
+    let after = &text[close + 2..];
+    let end = after.find(')')?;
+    let url = &after[..end];
+    if url.is_empty() || url.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((label, url, close + 2 + end + 1))
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Hashtags in `text`, in order, including the `#`.
+fn hashtags(text: &str) -> Vec<(usize, usize)> {
+    text.match_indices('#')
+        .filter(|(index, _)| at_token_start(text, *index))
+        .filter_map(|(index, _)| {
+            let len = token_len(&text[index + 1..], &[]);
+            (len > 0).then_some((index, index + 1 + len))
+        })
+        .collect()
+}
+
+fn limit_hashtags(text: &str, max: Option<usize>) -> String {
+    let Some(max) = max else {
+        return text.to_string();
+    };
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for (start, end) in hashtags(text).into_iter().skip(max) {
+        // Drop the tag and one adjoining space
+        let start = if text[..start].ends_with(' ') && !text[end..].starts_with(' ') {
+            start - 1
+        } else {
+            start
+        };
+        let end = if text[end..].starts_with(' ') {
+            end + 1
+        } else {
+            end
+        };
+        if start >= last {
+            out.push_str(&text[last..start]);
+            last = end;
+        }
+    }
+    out.push_str(&text[last..]);
+    out.trim_end().to_string()
+}
+
+fn append_hashtags(text: &str, extra: &[String], max_length: Option<usize>) -> String {
+    let present: Vec<String> = hashtags(text)
+        .into_iter()
+        .map(|(start, end)| text[start..end].to_lowercase())
+        .collect();
+    let missing: Vec<String> = extra
+        .iter()
+        .map(|tag| {
+            if tag.starts_with('#') {
+                tag.clone()
+            } else {
+                format!("#{}", tag)
+            }
+        })
+        .filter(|tag| !present.contains(&tag.to_lowercase()))
+        .collect();
+    if missing.is_empty() {
+        return text.to_string();
+    }
+
+    let separator = if text.is_empty() { "" } else { "\n\n" };
+    let appended = format!("{}{}{}", text, separator, missing.join(" "));
+    match max_length {
+        Some(max) if appended.chars().count() > max => text.to_string(),
+        _ => appended,
+    }
+}
+
+/// Truncate to `max` characters at a word boundary, ending with an ellipsis.
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    if max == 0 {
+        return String::new();
+    }
+    let cut: String = text.chars().take(max - 1).collect();
+    let at_boundary = text.chars().nth(max - 1).is_some_and(char::is_whitespace);
+    let cut = match cut.rfind(char::is_whitespace) {
+        Some(index) if !at_boundary && index > cut.len() / 2 => &cut[..index],
+        _ => cut.as_str(),
+    };
+    format!("{}{}", cut.trim_end(), ELLIPSIS)
+}
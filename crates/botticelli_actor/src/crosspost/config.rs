@@ -0,0 +1,122 @@
+//! Cross-post destinations in `actor_server.toml`.
+
+use super::{AdaptationRules, CrossPostDestination};
+use crate::{
+    ActorResult, BlueskyPlatform, MastodonPlatform, Platform, TelegramPlatform, WebhookPlatform,
+};
+use botticelli_social::WebhookConfig;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Configuration for one cross-post destination.
+///
+/// Credentials come from the secret provider, as for single-platform actors.
+///
+/// # Example
+///
+/// ```toml
+/// [[actors.destinations]]
+/// name = "mastodon"
+/// platform = "mastodon"
+/// base_url = "https://mastodon.social"
+/// media_dir = "media"
+///
+/// [actors.destinations.adaptation]
+/// max_hashtags = 3
+/// extra_hashtags = ["#art"]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DestinationConfig {
+    /// Destination name, used in the delivery ledger and post metadata.
+    pub name: String,
+    /// Platform and its connection settings.
+    #[serde(flatten)]
+    pub platform: DestinationPlatform,
+    /// Adaptation rules (defaults to the platform's rules).
+    #[serde(default)]
+    pub adaptation: Option<AdaptationRules>,
+    /// Directory local media files may be attached from (Mastodon and
+    /// Bluesky); only http(s) media URLs are accepted when unset.
+    #[serde(default)]
+    pub media_dir: Option<PathBuf>,
+}
+
+/// Platform-specific destination settings, selected by `platform`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "platform", rename_all = "lowercase")]
+pub enum DestinationPlatform {
+    /// Discord channel (requires the `discord` feature).
+    Discord {
+        /// Channel ID.
+        channel_id: String,
+    },
+    /// Mastodon account (`mastodon_access_token` secret).
+    Mastodon {
+        /// Instance URL.
+        base_url: String,
+    },
+    /// Bluesky account (`bluesky_identifier` and `bluesky_app_password` secrets).
+    Bluesky,
+    /// Telegram chat or channel (`telegram_bot_token` secret).
+    Telegram {
+        /// Chat ID or `@channelname`.
+        chat_id: String,
+    },
+    /// Generic webhook.
+    Webhook {
+        /// Webhook destination.
+        webhook: Box<WebhookConfig>,
+    },
+}
+
+impl DestinationConfig {
+    /// Create the platform and destination.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if secrets are missing or settings are invalid.
+    #[tracing::instrument(skip(self), fields(name = %self.name))]
+    pub fn build(&self) -> ActorResult<CrossPostDestination> {
+        let platform: Arc<dyn Platform> = match &self.platform {
+            #[cfg(feature = "discord")]
+            DestinationPlatform::Discord { channel_id } => {
+                Arc::new(crate::DiscordPlatform::new(channel_id)?)
+            }
+            #[cfg(not(feature = "discord"))]
+            DestinationPlatform::Discord { .. } => {
+                return Err(crate::ActorError::new(
+                    crate::ActorErrorKind::InvalidConfiguration(format!(
+                        "Destination '{}' needs the discord feature",
+                        self.name
+                    )),
+                ));
+            }
+            DestinationPlatform::Mastodon { base_url } => {
+                let mut platform = MastodonPlatform::from_secrets(base_url)?;
+                if let Some(media_dir) = &self.media_dir {
+                    platform = platform.with_media_dir(media_dir);
+                }
+                Arc::new(platform)
+            }
+            DestinationPlatform::Bluesky => {
+                let mut platform = BlueskyPlatform::from_secrets()?;
+                if let Some(media_dir) = &self.media_dir {
+                    platform = platform.with_media_dir(media_dir);
+                }
+                Arc::new(platform)
+            }
+            DestinationPlatform::Telegram { chat_id } => {
+                Arc::new(TelegramPlatform::from_secrets(chat_id)?)
+            }
+            DestinationPlatform::Webhook { webhook } => {
+                Arc::new(WebhookPlatform::new((**webhook).clone())?)
+            }
+        };
+
+        Ok(match &self.adaptation {
+            Some(rules) => CrossPostDestination::with_rules(&self.name, platform, rules.clone()),
+            None => CrossPostDestination::new(&self.name, platform),
+        })
+    }
+}
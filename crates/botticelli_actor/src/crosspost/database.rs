@@ -0,0 +1,111 @@
+//! Database storage for the cross-post ledger.
+
+use super::{CrossPostLedger, CrossPostRecord};
+use async_trait::async_trait;
+use botticelli_database::{
+    ActorCrossPostRecordRow, DatabaseConnection, DatabasePool, DatabaseResult,
+    with_pooled_connection,
+};
+use botticelli_server::{ActorServerResult, StatePersistence};
+use chrono::Utc;
+use tracing::{debug, instrument};
+
+/// Cross-post ledger stored in the `actor_crosspost_records` table.
+///
+/// Each actor's records live in their own rows, keyed by content row ID,
+/// in the same database as `actor_server_state`. Saving writes the
+/// ledger's records and deletes the actor's rows that are no longer in it,
+/// so records pruned from the ledger are removed from the table too.
+#[derive(Debug, Clone)]
+pub struct DatabaseCrossPostPersistence {
+    pool: DatabasePool,
+    actor_name: String,
+}
+
+impl DatabaseCrossPostPersistence {
+    /// Create ledger storage for one actor.
+    pub fn new(pool: DatabasePool, actor_name: impl Into<String>) -> Self {
+        Self {
+            pool,
+            actor_name: actor_name.into(),
+        }
+    }
+
+    /// Name of the actor whose records are stored.
+    pub fn actor_name(&self) -> &str {
+        &self.actor_name
+    }
+
+    /// Run a blocking database operation on the pool.
+    async fn with_connection<T, F>(&self, operation: F) -> ActorServerResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut DatabaseConnection, &str) -> DatabaseResult<T> + Send + 'static,
+    {
+        let actor_name = self.actor_name.clone();
+
+        with_pooled_connection(&self.pool, move |conn| -> ActorServerResult<T> {
+            operation(conn, &actor_name)
+                .map_err(|e| format!("Cross-post ledger query failed: {}", e).into())
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl StatePersistence for DatabaseCrossPostPersistence {
+    type State = CrossPostLedger;
+
+    #[instrument(skip(self, state), fields(actor = %self.actor_name, records = state.records().len()))]
+    async fn save_state(&self, state: &Self::State) -> ActorServerResult<()> {
+        let now = Utc::now();
+        let rows = state
+            .records()
+            .values()
+            .map(|record| {
+                Ok(ActorCrossPostRecordRow {
+                    actor_name: self.actor_name.clone(),
+                    content_id: record.content_id().clone(),
+                    deliveries: serde_json::to_value(record.deliveries())?,
+                    updated_at: record.last_updated().unwrap_or(now).naive_utc(),
+                })
+            })
+            .collect::<Result<Vec<_>, serde_json::Error>>()?;
+
+        let pruned = self
+            .with_connection(move |conn, actor_name| {
+                botticelli_database::save_crosspost_records(conn, actor_name, &rows)
+            })
+            .await?;
+
+        debug!(pruned, "Saved cross-post ledger");
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(actor = %self.actor_name))]
+    async fn load_state(&self) -> ActorServerResult<Option<Self::State>> {
+        let rows = self
+            .with_connection(botticelli_database::list_crosspost_records)
+            .await?;
+
+        if rows.is_empty() {
+            return Ok(None);
+        }
+
+        let mut ledger = CrossPostLedger::default();
+        for row in rows {
+            let mut record = CrossPostRecord::new(row.content_id);
+            record.set_deliveries(serde_json::from_value(row.deliveries)?);
+            ledger.insert(record);
+        }
+        debug!(records = ledger.records().len(), "Loaded cross-post ledger");
+        Ok(Some(ledger))
+    }
+
+    #[instrument(skip(self), fields(actor = %self.actor_name))]
+    async fn clear_state(&self) -> ActorServerResult<()> {
+        self.with_connection(botticelli_database::delete_crosspost_records)
+            .await?;
+        Ok(())
+    }
+}
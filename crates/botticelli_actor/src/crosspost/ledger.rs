@@ -0,0 +1,176 @@
+//! Per-destination delivery tracking.
+
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Delivery state of one content item on one destination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeliveryStatus {
+    /// Not attempted yet.
+    #[default]
+    Pending,
+    /// Posted successfully; never posted again.
+    Posted,
+    /// Last attempt failed; retried on the next post.
+    Failed,
+}
+
+/// Delivery record for one destination.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, Getters)]
+pub struct DestinationDelivery {
+    /// Current status.
+    status: DeliveryStatus,
+    /// Platform post ID once posted.
+    post_id: Option<String>,
+    /// Metadata returned by the platform once posted.
+    #[serde(default)]
+    metadata: HashMap<String, String>,
+    /// Number of attempts made.
+    attempts: u32,
+    /// Error from the last failed attempt.
+    last_error: Option<String>,
+    /// Time of the last attempt.
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl DestinationDelivery {
+    /// Record a successful post.
+    pub fn mark_posted(&mut self, metadata: HashMap<String, String>) {
+        self.status = DeliveryStatus::Posted;
+        self.post_id = ["post_id", "message_id", "id", "uri"]
+            .iter()
+            .find_map(|key| metadata.get(*key).cloned());
+        self.metadata = metadata;
+        self.attempts += 1;
+        self.last_error = None;
+        self.updated_at = Some(Utc::now());
+    }
+
+    /// Record a failed attempt.
+    pub fn mark_failed(&mut self, error: impl Into<String>) {
+        self.status = DeliveryStatus::Failed;
+        self.attempts += 1;
+        self.last_error = Some(error.into());
+        self.updated_at = Some(Utc::now());
+    }
+
+    /// Whether this destination has been posted to.
+    pub fn is_posted(&self) -> bool {
+        self.status == DeliveryStatus::Posted
+    }
+}
+
+/// Deliveries of one content item across all destinations.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, Getters)]
+pub struct CrossPostRecord {
+    /// Content identifier (e.g. the approved content row ID).
+    content_id: String,
+    /// Delivery per destination name.
+    deliveries: HashMap<String, DestinationDelivery>,
+}
+
+impl CrossPostRecord {
+    /// Create an empty record for a content item.
+    pub fn new(content_id: impl Into<String>) -> Self {
+        Self {
+            content_id: content_id.into(),
+            deliveries: HashMap::new(),
+        }
+    }
+
+    /// Get the delivery for a destination, creating a pending one.
+    pub fn delivery_mut(&mut self, destination: &str) -> &mut DestinationDelivery {
+        self.deliveries.entry(destination.to_string()).or_default()
+    }
+
+    /// Replace all deliveries (e.g. when loading a stored record).
+    pub fn set_deliveries(&mut self, deliveries: HashMap<String, DestinationDelivery>) {
+        self.deliveries = deliveries;
+    }
+
+    /// Get the delivery for a destination.
+    pub fn delivery(&self, destination: &str) -> Option<&DestinationDelivery> {
+        self.deliveries.get(destination)
+    }
+
+    /// Whether every destination in `destinations` has been posted to.
+    pub fn is_complete<'a>(&self, destinations: impl IntoIterator<Item = &'a str>) -> bool {
+        destinations.into_iter().all(|name| {
+            self.delivery(name)
+                .is_some_and(DestinationDelivery::is_posted)
+        })
+    }
+
+    /// Time of the most recent delivery attempt on any destination.
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.deliveries.values().filter_map(|d| d.updated_at).max()
+    }
+
+    /// Names of destinations whose last attempt failed, sorted.
+    pub fn failed(&self) -> Vec<String> {
+        let mut failed: Vec<String> = self
+            .deliveries
+            .iter()
+            .filter(|(_, d)| d.status == DeliveryStatus::Failed)
+            .map(|(name, _)| name.clone())
+            .collect();
+        failed.sort();
+        failed
+    }
+}
+
+/// All cross-post records, keyed by content ID.
+///
+/// This is the state saved through [`StatePersistence`](botticelli_server::StatePersistence),
+/// e.g. with [`DatabaseCrossPostPersistence`](crate::DatabaseCrossPostPersistence)
+/// or [`JsonStatePersistence`](crate::JsonStatePersistence).
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, Getters)]
+pub struct CrossPostLedger {
+    /// Records by content ID.
+    records: HashMap<String, CrossPostRecord>,
+}
+
+impl CrossPostLedger {
+    /// Get the record for a content item.
+    pub fn record(&self, content_id: &str) -> Option<&CrossPostRecord> {
+        self.records.get(content_id)
+    }
+
+    /// Get the record for a content item, creating an empty one.
+    pub fn record_mut(&mut self, content_id: &str) -> &mut CrossPostRecord {
+        self.records
+            .entry(content_id.to_string())
+            .or_insert_with(|| CrossPostRecord::new(content_id))
+    }
+
+    /// Add or replace a content item's record.
+    pub fn insert(&mut self, record: CrossPostRecord) {
+        self.records.insert(record.content_id.clone(), record);
+    }
+
+    /// Remove a content item's record (e.g. after the content row is archived).
+    pub fn remove(&mut self, content_id: &str) -> Option<CrossPostRecord> {
+        self.records.remove(content_id)
+    }
+
+    /// Remove records posted to every destination whose last delivery was
+    /// before `cutoff`, returning how many were removed.
+    ///
+    /// Incomplete records are kept so their failed destinations can still
+    /// be retried.
+    pub fn prune_completed<'a>(
+        &mut self,
+        destinations: impl IntoIterator<Item = &'a str> + Clone,
+        cutoff: DateTime<Utc>,
+    ) -> usize {
+        let before = self.records.len();
+        self.records.retain(|_, record| {
+            !(record.is_complete(destinations.clone())
+                && record.last_updated().is_some_and(|at| at < cutoff))
+        });
+        before - self.records.len()
+    }
+}
@@ -0,0 +1,40 @@
+//! Cross-posting one content item to several platforms.
+//!
+//! Each destination adapts the content to its platform (length limits,
+//! mention syntax, markup dialect, hashtags, media the platform supports)
+//! before posting. Delivery is tracked per destination so a retry after a
+//! partial failure doesn't double-post where it already succeeded.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use botticelli_actor::{
+//!     CrossPostDestination, CrossPostPlatform, CrossPoster, DatabaseCrossPostPersistence,
+//! };
+//! use std::sync::Arc;
+//!
+//! let poster = CrossPoster::new(vec![
+//!     CrossPostDestination::new("mastodon", Arc::new(mastodon)),
+//!     CrossPostDestination::new("bluesky", Arc::new(bluesky)),
+//! ])?
+//! .with_persistence(Arc::new(DatabaseCrossPostPersistence::new(pool, "my_actor")));
+//!
+//! let record = poster.post("content-42", &content).await?;
+//! // Or let an actor post through it:
+//! let platform = CrossPostPlatform::new(poster);
+//! ```
+
+mod adapt;
+mod config;
+mod database;
+mod ledger;
+mod poster;
+
+pub use adapt::{AdaptationRules, AdaptationRulesBuilder, MarkdownDialect};
+pub use config::{DestinationConfig, DestinationPlatform};
+pub use database::DatabaseCrossPostPersistence;
+pub use ledger::{CrossPostLedger, CrossPostRecord, DeliveryStatus, DestinationDelivery};
+pub use poster::{
+    CrossPostDestination, CrossPostPersistence, CrossPostPlatform, CrossPoster,
+    DEFAULT_RETENTION_DAYS,
+};
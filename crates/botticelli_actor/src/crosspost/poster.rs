@@ -0,0 +1,382 @@
+//! Fan-out posting to multiple destinations.
+
+use super::{AdaptationRules, CrossPostLedger, CrossPostRecord};
+use crate::platforms::http::mime_from_extension;
+use crate::{
+    ActorError, ActorErrorKind, ActorResult, Content, ContentBuilder, MediaAttachmentBuilder,
+    MediaType, Platform, PlatformCapability, PlatformMessage, PlatformMetadata,
+};
+use async_trait::async_trait;
+use botticelli_server::StatePersistence;
+use chrono::{Duration, Utc};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Days completed records stay in the ledger by default.
+pub const DEFAULT_RETENTION_DAYS: i64 = 30;
+
+/// Persistence backend for the cross-post ledger.
+pub type CrossPostPersistence = Arc<dyn StatePersistence<State = CrossPostLedger>>;
+
+/// One named destination with its adaptation rules.
+#[derive(Clone)]
+pub struct CrossPostDestination {
+    name: String,
+    platform: Arc<dyn Platform>,
+    rules: AdaptationRules,
+}
+
+impl CrossPostDestination {
+    /// Create a destination using the platform's default rules.
+    pub fn new(name: impl Into<String>, platform: Arc<dyn Platform>) -> Self {
+        let rules = AdaptationRules::for_platform(platform.platform_name());
+        Self::with_rules(name, platform, rules)
+    }
+
+    /// Create a destination with explicit rules.
+    pub fn with_rules(
+        name: impl Into<String>,
+        platform: Arc<dyn Platform>,
+        rules: AdaptationRules,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            platform,
+            rules,
+        }
+    }
+
+    /// Destination name (unique within a cross-poster).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Platform posted to.
+    pub fn platform(&self) -> &Arc<dyn Platform> {
+        &self.platform
+    }
+
+    /// Adaptation rules.
+    pub fn rules(&self) -> &AdaptationRules {
+        &self.rules
+    }
+
+    /// Adapt content for this destination.
+    pub fn adapt(&self, content: &Content) -> PlatformMessage {
+        self.rules.adapt(content, &self.platform.capabilities())
+    }
+}
+
+impl std::fmt::Debug for CrossPostDestination {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CrossPostDestination")
+            .field("name", &self.name)
+            .field("platform", &self.platform.platform_name())
+            .field("rules", &self.rules)
+            .finish()
+    }
+}
+
+/// Posts one content item to several destinations, at most once each.
+///
+/// Every attempt is recorded in a [`CrossPostLedger`]. Destinations already
+/// posted for a content ID are skipped, so retrying after a partial failure
+/// only reposts where it failed. With a persistence backend the ledger is
+/// saved after every destination and survives restarts. Records posted
+/// everywhere are pruned once they are older than the retention period.
+pub struct CrossPoster {
+    destinations: Vec<CrossPostDestination>,
+    persistence: Option<CrossPostPersistence>,
+    ledger: Mutex<Option<CrossPostLedger>>,
+    retention: Duration,
+}
+
+impl CrossPoster {
+    /// Create a cross-poster with an in-memory ledger.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if there are no destinations or two share a name.
+    pub fn new(destinations: Vec<CrossPostDestination>) -> ActorResult<Self> {
+        if destinations.is_empty() {
+            return Err(ActorError::new(ActorErrorKind::InvalidConfiguration(
+                "Cross-posting requires at least one destination".to_string(),
+            )));
+        }
+        for (i, destination) in destinations.iter().enumerate() {
+            if destinations[..i]
+                .iter()
+                .any(|other| other.name == destination.name)
+            {
+                return Err(ActorError::new(ActorErrorKind::InvalidConfiguration(
+                    format!("Duplicate cross-post destination '{}'", destination.name),
+                )));
+            }
+        }
+        Ok(Self {
+            destinations,
+            persistence: None,
+            ledger: Mutex::new(None),
+            retention: Duration::days(DEFAULT_RETENTION_DAYS),
+        })
+    }
+
+    /// Keep completed records for `retention` before pruning them.
+    pub fn with_retention(mut self, retention: Duration) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// Persist the ledger through a state persistence backend.
+    pub fn with_persistence(mut self, persistence: CrossPostPersistence) -> Self {
+        self.persistence = Some(persistence);
+        self
+    }
+
+    /// Get the destinations.
+    pub fn destinations(&self) -> &[CrossPostDestination] {
+        &self.destinations
+    }
+
+    /// Get the delivery record for a content item.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the ledger cannot be loaded.
+    pub async fn record(&self, content_id: &str) -> ActorResult<Option<CrossPostRecord>> {
+        let mut ledger = self.ledger.lock().await;
+        Ok(self.loaded(&mut ledger).await?.record(content_id).cloned())
+    }
+
+    /// Post content to every destination not yet posted for `content_id`.
+    ///
+    /// All pending destinations are attempted even if one fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first destination error (its kind decides whether the
+    /// caller retries), or a resource error if the ledger cannot be saved.
+    #[tracing::instrument(skip(self, content), fields(destinations = self.destinations.len()))]
+    pub async fn post(&self, content_id: &str, content: &Content) -> ActorResult<CrossPostRecord> {
+        // Held for the whole fan-out so concurrent posts of one item can't race
+        let mut guard = self.ledger.lock().await;
+        let ledger = self.loaded(&mut guard).await?;
+
+        let pruned = ledger.prune_completed(
+            self.destinations.iter().map(|d| d.name.as_str()),
+            Utc::now() - self.retention,
+        );
+        if pruned > 0 {
+            tracing::debug!(pruned, "Pruned completed cross-post records");
+        }
+
+        let mut first_error = None;
+        let mut save_error = None;
+        for destination in &self.destinations {
+            let delivery = ledger
+                .record_mut(content_id)
+                .delivery_mut(&destination.name);
+            if delivery.is_posted() {
+                tracing::debug!(destination = %destination.name, "Already posted, skipping");
+                continue;
+            }
+
+            let mut message = destination.adapt(content);
+            message.content_id = Some(content_id.to_string());
+            match destination.platform.post(&message).await {
+                Ok(metadata) => {
+                    tracing::info!(destination = %destination.name, "Cross-posted content");
+                    delivery.mark_posted(metadata);
+                }
+                Err(error) => {
+                    tracing::warn!(
+                        destination = %destination.name,
+                        error = %error,
+                        recoverable = error.is_recoverable(),
+                        "Cross-post failed"
+                    );
+                    delivery.mark_failed(error.kind.to_string());
+                    first_error.get_or_insert(error);
+                }
+            }
+
+            if let Err(e) = self.save(ledger).await {
+                tracing::error!(error = %e, "Failed to save cross-post ledger");
+                save_error.get_or_insert(e);
+            }
+        }
+
+        let record = ledger.record_mut(content_id).clone();
+        match (first_error, save_error) {
+            (Some(error), _) | (None, Some(error)) => Err(error),
+            (None, None) => Ok(record),
+        }
+    }
+
+    /// Load the ledger on first use.
+    async fn loaded<'a>(
+        &self,
+        ledger: &'a mut Option<CrossPostLedger>,
+    ) -> ActorResult<&'a mut CrossPostLedger> {
+        if ledger.is_none() {
+            let loaded = match &self.persistence {
+                Some(persistence) => persistence.load_state().await.map_err(|e| {
+                    ActorError::new(ActorErrorKind::ResourceUnavailable(format!(
+                        "Failed to load cross-post ledger: {}",
+                        e
+                    )))
+                })?,
+                None => None,
+            };
+            *ledger = Some(loaded.unwrap_or_default());
+        }
+        Ok(ledger.get_or_insert_default())
+    }
+
+    async fn save(&self, ledger: &CrossPostLedger) -> ActorResult<()> {
+        let Some(persistence) = &self.persistence else {
+            return Ok(());
+        };
+        persistence.save_state(ledger).await.map_err(|e| {
+            ActorError::new(ActorErrorKind::ResourceUnavailable(format!(
+                "Failed to save cross-post ledger: {}",
+                e
+            )))
+        })
+    }
+}
+
+impl std::fmt::Debug for CrossPoster {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CrossPoster")
+            .field("destinations", &self.destinations)
+            .field("persistent", &self.persistence.is_some())
+            .finish()
+    }
+}
+
+/// [`Platform`] that fans each post out through a [`CrossPoster`].
+///
+/// Lets an [`Actor`](crate::Actor) post to several platforms without
+/// changing its skills. Deliveries are keyed by the message's content row
+/// ID, so an actor retrying the same row only reposts to destinations that
+/// failed. Messages without a row ID fall back to a SHA-256 of the message.
+///
+/// Metadata keys are prefixed with the destination name
+/// (`mastodon.post_id`, `discord.message_id`, ...); `destinations` lists
+/// the destination names.
+#[derive(Debug, Clone)]
+pub struct CrossPostPlatform {
+    poster: Arc<CrossPoster>,
+}
+
+impl CrossPostPlatform {
+    /// Wrap a cross-poster as a platform.
+    pub fn new(poster: CrossPoster) -> Self {
+        Self {
+            poster: Arc::new(poster),
+        }
+    }
+
+    /// Get the underlying cross-poster.
+    pub fn poster(&self) -> &Arc<CrossPoster> {
+        &self.poster
+    }
+
+    /// Content ID for a message: its content row ID, or a hex SHA-256 of
+    /// its text and media URLs when it has none.
+    pub fn content_id(message: &PlatformMessage) -> String {
+        if let Some(content_id) = &message.content_id {
+            return content_id.clone();
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(message.text.as_bytes());
+        for url in &message.media_urls {
+            hasher.update([0]);
+            hasher.update(url.as_bytes());
+        }
+        hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+}
+
+/// Content from a platform message, guessing media types from extensions.
+fn message_content(message: &PlatformMessage) -> ActorResult<Content> {
+    let media = message
+        .media_urls
+        .iter()
+        .map(|url| {
+            let path = url.split(['?', '#']).next().unwrap_or(url);
+            let media_type = match mime_from_extension(Path::new(path)) {
+                Some(mime) if mime.starts_with("video/") => MediaType::Video,
+                Some(mime) if mime.starts_with("audio/") => MediaType::Audio,
+                _ => MediaType::Image,
+            };
+            MediaAttachmentBuilder::default()
+                .url(url.clone())
+                .media_type(media_type)
+                .build()
+                .map_err(|e| ActorError::new(ActorErrorKind::ValidationFailed(e.to_string())))
+        })
+        .collect::<ActorResult<Vec<_>>>()?;
+
+    ContentBuilder::default()
+        .text(Some(message.text.clone()))
+        .media(media)
+        .build()
+        .map_err(|e| ActorError::new(ActorErrorKind::ValidationFailed(e.to_string())))
+}
+
+#[async_trait]
+impl Platform for CrossPostPlatform {
+    #[tracing::instrument(skip(self, message))]
+    async fn post(&self, message: &PlatformMessage) -> ActorResult<PlatformMetadata> {
+        let content_id = Self::content_id(message);
+        let content = message_content(message)?;
+        let record = self.poster.post(&content_id, &content).await?;
+
+        let mut metadata = PlatformMetadata::new();
+        metadata.insert("content_id".to_string(), content_id);
+        let mut names = Vec::new();
+        for destination in self.poster.destinations() {
+            names.push(destination.name().to_string());
+            if let Some(delivery) = record.delivery(destination.name()) {
+                for (key, value) in delivery.metadata() {
+                    metadata.insert(format!("{}.{}", destination.name(), key), value.clone());
+                }
+            }
+        }
+        metadata.insert("destinations".to_string(), names.join(","));
+        Ok(metadata)
+    }
+
+    async fn verify_connection(&self) -> ActorResult<()> {
+        for destination in self.poster.destinations() {
+            destination.platform().verify_connection().await?;
+        }
+        Ok(())
+    }
+
+    /// Capabilities of any destination.
+    fn capabilities(&self) -> Vec<PlatformCapability> {
+        let mut capabilities = Vec::new();
+        for destination in self.poster.destinations() {
+            for capability in destination.platform().capabilities() {
+                if !capabilities.contains(&capability) {
+                    capabilities.push(capability);
+                }
+            }
+        }
+        capabilities
+    }
+
+    fn platform_name(&self) -> &str {
+        "crosspost"
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::{Actor, ActorConfig, Content, ContentPost, ContentPostBuilder, DiscordPlatform};
 use async_trait::async_trait;
+use botticelli_security::ContentModerator;
 use botticelli_server::{
     ActorManager, ActorServer, ActorServerResult, ContentPoster, TaskScheduler,
 };
@@ -288,12 +289,22 @@ impl ActorManager for DiscordActorManager {
 /// Discord content poster
 pub struct DiscordContentPoster {
     http: Arc<Http>,
+    moderator: Option<Arc<dyn ContentModerator>>,
 }
 
 impl DiscordContentPoster {
     /// Create a new Discord content poster
     pub fn new(http: Arc<Http>) -> Self {
-        Self { http }
+        Self {
+            http,
+            moderator: None,
+        }
+    }
+
+    /// Moderate content before every post
+    pub fn with_moderator(mut self, moderator: Arc<dyn ContentModerator>) -> Self {
+        self.moderator = Some(moderator);
+        self
     }
 }
 
@@ -313,6 +324,11 @@ impl ContentPoster for DiscordContentPoster {
 
         // Convert Content to Discord message
         let message_text = content.to_string();
+
+        if let Some(moderator) = &self.moderator {
+            moderator.check("discord", &message_text).await?;
+        }
+
         let message = CreateMessage::new().content(message_text);
 
         // Post to Discord
@@ -365,6 +381,12 @@ impl DiscordActorServer {
         }
     }
 
+    /// Moderate all content posted by this server
+    pub fn with_moderator(mut self, moderator: Arc<dyn ContentModerator>) -> Self {
+        self.poster = self.poster.with_moderator(moderator);
+        self
+    }
+
     /// Get mutable reference to the actor manager
     pub fn manager_mut(&mut self) -> &mut DiscordActorManager {
         &mut self.manager
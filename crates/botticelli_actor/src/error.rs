@@ -74,6 +74,18 @@ pub enum ActorErrorKind {
     /// Narrative execution error.
     #[display("Narrative error: {}", _0)]
     Narrative(String),
+
+    /// WASM skill plugin error.
+    #[display("Plugin error: {}", _0)]
+    Plugin(String),
+
+    /// Actor not loaded by the server.
+    #[display("Actor not found: {}", _0)]
+    ActorNotFound(String),
+
+    /// Control API request failed.
+    #[display("Control error: {}", _0)]
+    Control(String),
 }
 
 impl ActorErrorKind {
@@ -142,3 +154,10 @@ impl From<serde_json::Error> for ActorError {
         Self::new(ActorErrorKind::JsonError(e.to_string()))
     }
 }
+
+impl From<botticelli_error::DatabaseError> for ActorError {
+    #[track_caller]
+    fn from(e: botticelli_error::DatabaseError) -> Self {
+        Self::new(ActorErrorKind::DatabaseFailed(e.to_string()))
+    }
+}
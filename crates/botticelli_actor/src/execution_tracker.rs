@@ -1,11 +1,15 @@
 //! Helper for tracking actor execution with persistence
 
-use crate::DatabaseExecutionResult;
+use crate::{CircuitState, CircuitStateBuilder, DatabaseExecutionResult, ExecutionRecord};
+use botticelli_database::ActorServerStateRow;
 use botticelli_server::{ActorServerResult, StatePersistence};
-use chrono::NaiveDateTime;
+use chrono::{NaiveDateTime, Utc};
 use std::sync::Arc;
 use tracing::{debug, instrument, warn};
 
+/// Failure threshold used when task metadata sets no `max_failures`.
+const DEFAULT_MAX_FAILURES: i32 = 10;
+
 /// Helper for integrating persistence into actor execution.
 ///
 /// Simplifies common patterns like circuit breaking, execution logging,
@@ -116,7 +120,8 @@ impl ActorExecutionTracker<crate::DatabaseStatePersistence> {
 
     /// Record successful execution
     ///
-    /// Updates execution record and resets consecutive failure count.
+    /// Updates execution record, resets consecutive failure count and
+    /// stores the run time as the task's last run.
     #[instrument(skip(self, result), fields(task_id = %self.task_id, exec_id))]
     pub async fn record_success(
         &self,
@@ -125,6 +130,9 @@ impl ActorExecutionTracker<crate::DatabaseStatePersistence> {
     ) -> ActorServerResult<()> {
         debug!("Recording success");
         self.persistence.complete_execution(exec_id, result).await?;
+        self.persistence
+            .update_last_run(&self.task_id, &self.actor_name, Utc::now().naive_utc())
+            .await?;
         self.persistence.record_success(&self.task_id).await?;
         Ok(())
     }
@@ -151,13 +159,7 @@ impl ActorExecutionTracker<crate::DatabaseStatePersistence> {
                 format!("Task {} not found", self.task_id).into()
             })?;
 
-        let max_failures = state
-            .metadata
-            .as_ref()
-            .and_then(|m| m.get("max_failures"))
-            .and_then(|v| v.as_i64())
-            .map(|v| v as i32)
-            .unwrap_or(10);
+        let max_failures = max_failures(&state);
 
         let should_pause = self
             .persistence
@@ -179,6 +181,85 @@ impl ActorExecutionTracker<crate::DatabaseStatePersistence> {
         self.persistence.should_execute(&self.task_id).await
     }
 
+    /// Pause the task
+    ///
+    /// Creates the task state if the task has never run.
+    #[instrument(skip(self), fields(task_id = %self.task_id))]
+    pub async fn pause(&self) -> ActorServerResult<()> {
+        debug!("Pausing task");
+        if self
+            .persistence
+            .load_task_state(&self.task_id)
+            .await?
+            .is_some()
+        {
+            return self.persistence.pause_task(&self.task_id).await;
+        }
+
+        let now = Utc::now().naive_utc();
+        let state = ActorServerStateRow {
+            task_id: self.task_id.clone(),
+            actor_name: self.actor_name.clone(),
+            last_run: None,
+            next_run: now,
+            consecutive_failures: Some(0),
+            is_paused: Some(true),
+            metadata: None,
+            updated_at: now,
+        };
+        self.persistence
+            .save_task_state(&self.task_id, &state)
+            .await
+    }
+
+    /// Resume the task
+    ///
+    /// Also resets the consecutive failure count, so a task paused by the
+    /// circuit breaker gets a full failure budget again.
+    #[instrument(skip(self), fields(task_id = %self.task_id))]
+    pub async fn resume(&self) -> ActorServerResult<()> {
+        debug!("Resuming task");
+        self.persistence.resume_task(&self.task_id).await?;
+        self.persistence.record_success(&self.task_id).await
+    }
+
+    /// Get circuit breaker state
+    ///
+    /// A task that has never run reports a closed breaker with no failures.
+    #[instrument(skip(self), fields(task_id = %self.task_id))]
+    pub async fn circuit_state(&self) -> ActorServerResult<CircuitState> {
+        let state = self.persistence.load_task_state(&self.task_id).await?;
+
+        let circuit = CircuitStateBuilder::default()
+            .paused(state.as_ref().and_then(|s| s.is_paused).unwrap_or(false))
+            .consecutive_failures(
+                state
+                    .as_ref()
+                    .and_then(|s| s.consecutive_failures)
+                    .unwrap_or(0),
+            )
+            .max_consecutive_failures(
+                state
+                    .as_ref()
+                    .map(max_failures)
+                    .unwrap_or(DEFAULT_MAX_FAILURES),
+            )
+            .build()
+            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { e.to_string().into() })?;
+
+        Ok(circuit)
+    }
+
+    /// Get recent executions, newest first
+    #[instrument(skip(self), fields(task_id = %self.task_id, limit))]
+    pub async fn history(&self, limit: i64) -> ActorServerResult<Vec<ExecutionRecord>> {
+        let rows = self
+            .persistence
+            .get_execution_history(&self.task_id, limit)
+            .await?;
+        Ok(rows.into_iter().map(ExecutionRecord::from).collect())
+    }
+
     /// Update next scheduled run time
     #[instrument(skip(self), fields(task_id = %self.task_id, next_run = %next_run))]
     pub async fn update_next_run(&self, next_run: NaiveDateTime) -> ActorServerResult<()> {
@@ -188,3 +269,14 @@ impl ActorExecutionTracker<crate::DatabaseStatePersistence> {
             .await
     }
 }
+
+/// Circuit breaker threshold from task metadata.
+fn max_failures(state: &ActorServerStateRow) -> i32 {
+    state
+        .metadata
+        .as_ref()
+        .and_then(|m| m.get("max_failures"))
+        .and_then(|v| v.as_i64())
+        .map(|v| v as i32)
+        .unwrap_or(DEFAULT_MAX_FAILURES)
+}
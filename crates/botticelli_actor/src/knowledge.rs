@@ -1,14 +1,14 @@
 //! Knowledge table abstraction for actor data access.
 
 use crate::{ActorError, ActorErrorKind, ActorResult};
-use diesel::PgConnection;
-use diesel::prelude::*;
+use botticelli_database::{ColumnInfo, ContentConnection};
 use serde_json::Value as JsonValue;
 
 /// Wrapper for knowledge table access.
 ///
 /// Provides type-safe access to database tables produced by narratives.
-/// Knowledge tables contain structured data that actors consume.
+/// Knowledge tables contain structured data that actors consume. Works on
+/// any [`ContentConnection`], so PostgreSQL and SQLite tables read alike.
 #[derive(Debug, Clone)]
 pub struct KnowledgeTable {
     name: String,
@@ -49,26 +49,13 @@ impl KnowledgeTable {
     /// - Query fails
     /// - JSON parsing fails
     #[tracing::instrument(skip(self, conn), fields(table_name = %self.name))]
-    pub fn query(&self, conn: &mut PgConnection) -> ActorResult<Vec<JsonValue>> {
+    pub fn query<C: ContentConnection>(&self, conn: &mut C) -> ActorResult<Vec<JsonValue>> {
         tracing::debug!("Querying knowledge table");
 
         // Use raw SQL to query dynamic table names
-        let query = format!("SELECT row_to_json(t.*) as data FROM {} as t", self.name);
+        let query = format!("SELECT * FROM {}", self.name);
 
-        tracing::debug!(sql = %query, "Executing query");
-
-        let results: Vec<JsonValue> = diesel::sql_query(&query)
-            .load::<QueryRow>(conn)
-            .map_err(|e| {
-                tracing::error!(error = ?e, "Knowledge table query failed");
-                ActorError::new(ActorErrorKind::KnowledgeTableNotFound(format!(
-                    "{}: {}",
-                    self.name, e
-                )))
-            })?
-            .into_iter()
-            .map(|row| row.data)
-            .collect();
+        let results = self.load(conn, &query)?;
 
         tracing::info!(count = results.len(), "Retrieved rows from knowledge table");
         Ok(results)
@@ -92,32 +79,16 @@ impl KnowledgeTable {
     /// - Query fails
     /// - Invalid WHERE clause
     #[tracing::instrument(skip(self, conn), fields(table_name = %self.name, where_clause))]
-    pub fn query_where(
+    pub fn query_where<C: ContentConnection>(
         &self,
-        conn: &mut PgConnection,
+        conn: &mut C,
         where_clause: &str,
     ) -> ActorResult<Vec<JsonValue>> {
         tracing::debug!("Querying knowledge table with WHERE clause");
 
-        let query = format!(
-            "SELECT row_to_json(t.*) as data FROM {} as t WHERE {}",
-            self.name, where_clause
-        );
+        let query = format!("SELECT * FROM {} WHERE {}", self.name, where_clause);
 
-        tracing::debug!(sql = %query, "Executing query");
-
-        let results: Vec<JsonValue> = diesel::sql_query(&query)
-            .load::<QueryRow>(conn)
-            .map_err(|e| {
-                tracing::error!(error = ?e, "Knowledge table query failed");
-                ActorError::new(ActorErrorKind::KnowledgeTableNotFound(format!(
-                    "{}: {}",
-                    self.name, e
-                )))
-            })?
-            .into_iter()
-            .map(|row| row.data)
-            .collect();
+        let results = self.load(conn, &query)?;
 
         tracing::info!(
             count = results.len(),
@@ -140,23 +111,33 @@ impl KnowledgeTable {
     ///
     /// Returns error if table does not exist or query fails.
     #[tracing::instrument(skip(self, conn), fields(table_name = %self.name))]
-    pub fn count(&self, conn: &mut PgConnection) -> ActorResult<i64> {
+    pub fn count<C: ContentConnection>(&self, conn: &mut C) -> ActorResult<i64> {
         tracing::debug!("Counting rows in knowledge table");
 
-        let query = format!("SELECT COUNT(*) as count FROM {}", self.name);
+        let query = format!("SELECT COUNT(*) AS count FROM {}", self.name);
+        let count_column = ColumnInfo {
+            name: "count".to_string(),
+            data_type: "bigint".to_string(),
+            is_nullable: "NO".to_string(),
+            character_maximum_length: None,
+            column_default: None,
+        };
 
         tracing::debug!(sql = %query, "Executing query");
 
-        let result: CountRow = diesel::sql_query(&query).get_result(conn).map_err(|e| {
-            tracing::error!(error = ?e, "Count query failed");
-            ActorError::new(ActorErrorKind::KnowledgeTableNotFound(format!(
-                "{}: {}",
-                self.name, e
-            )))
-        })?;
+        let count = conn
+            .load_json(&query, &[count_column], &[])
+            .map_err(|e| {
+                tracing::error!(error = ?e, "Count query failed");
+                self.not_found(e)
+            })?
+            .first()
+            .and_then(|row| row.get("count"))
+            .and_then(JsonValue::as_i64)
+            .unwrap_or_default();
 
-        tracing::debug!(count = result.count, "Row count retrieved");
-        Ok(result.count)
+        tracing::debug!(count, "Row count retrieved");
+        Ok(count)
     }
 
     /// Check if table exists.
@@ -169,20 +150,13 @@ impl KnowledgeTable {
     ///
     /// True if table exists, false otherwise.
     #[tracing::instrument(skip(self, conn), fields(table_name = %self.name))]
-    pub fn exists(&self, conn: &mut PgConnection) -> bool {
-        let query = format!(
-            "SELECT EXISTS (SELECT FROM information_schema.tables WHERE table_name = '{}')",
-            self.name
-        );
-
-        tracing::debug!(sql = %query, "Checking table existence");
-
-        let result: Result<ExistsRow, _> = diesel::sql_query(&query).get_result(conn);
+    pub fn exists<C: ContentConnection>(&self, conn: &mut C) -> bool {
+        tracing::debug!("Checking table existence");
 
-        match result {
-            Ok(row) => {
-                tracing::debug!(exists = row.exists, "Table existence checked");
-                row.exists
+        match conn.table_exists(&self.name) {
+            Ok(exists) => {
+                tracing::debug!(exists, "Table existence checked");
+                exists
             }
             Err(e) => {
                 tracing::warn!(error = ?e, "Failed to check table existence");
@@ -190,25 +164,28 @@ impl KnowledgeTable {
             }
         }
     }
-}
 
-/// Row result for JSON queries.
-#[derive(Debug, QueryableByName)]
-struct QueryRow {
-    #[diesel(sql_type = diesel::sql_types::Jsonb)]
-    data: JsonValue,
-}
+    /// Run a SELECT over this table and return rows as JSON objects.
+    fn load<C: ContentConnection>(&self, conn: &mut C, query: &str) -> ActorResult<Vec<JsonValue>> {
+        tracing::debug!(sql = %query, "Executing query");
 
-/// Row result for count queries.
-#[derive(Debug, QueryableByName)]
-struct CountRow {
-    #[diesel(sql_type = diesel::sql_types::BigInt)]
-    count: i64,
-}
+        let columns = conn
+            .table_columns(&self.name)
+            .map_err(|e| self.not_found(e))?;
+        if columns.is_empty() {
+            return Err(self.not_found("table does not exist"));
+        }
 
-/// Row result for existence checks.
-#[derive(Debug, QueryableByName)]
-struct ExistsRow {
-    #[diesel(sql_type = diesel::sql_types::Bool)]
-    exists: bool,
+        conn.load_json(query, &columns, &[]).map_err(|e| {
+            tracing::error!(error = ?e, "Knowledge table query failed");
+            self.not_found(e)
+        })
+    }
+
+    fn not_found(&self, e: impl std::fmt::Display) -> ActorError {
+        ActorError::new(ActorErrorKind::KnowledgeTableNotFound(format!(
+            "{}: {}",
+            self.name, e
+        )))
+    }
 }
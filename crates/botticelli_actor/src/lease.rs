@@ -0,0 +1,214 @@
+//! Task leases for running several actor-server replicas against one database.
+
+use crate::DatabaseStatePersistence;
+use botticelli_database::{ActorServerLeaseRow, DatabasePool, with_pooled_connection};
+use botticelli_server::ActorServerResult;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, instrument, warn};
+
+/// Outcome of trying to take a task lease.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LeaseStatus {
+    /// The lease was free or expired and is now ours.
+    Acquired,
+    /// We already held the lease and extended it.
+    Renewed,
+    /// Another replica holds a live lease.
+    HeldBy(String),
+}
+
+impl LeaseStatus {
+    /// Whether this replica holds the lease.
+    pub fn is_held(&self) -> bool {
+        !matches!(self, Self::HeldBy(_))
+    }
+}
+
+/// Database-backed task leases.
+///
+/// Each replica has a unique owner ID. A replica runs a task only while it
+/// holds the task's lease; leases are extended by [`try_acquire`] on every
+/// check and by the heartbeat from [`spawn_heartbeat`], so a long execution
+/// keeps its lease. When a replica dies its leases expire after the lease
+/// duration and another replica picks the tasks up.
+///
+/// [`try_acquire`]: TaskLeaseManager::try_acquire
+/// [`spawn_heartbeat`]: TaskLeaseManager::spawn_heartbeat
+///
+/// # Example
+///
+/// ```no_run
+/// use botticelli_actor::{DatabaseStatePersistence, TaskLeaseManager};
+/// use std::time::Duration;
+///
+/// # async fn example() -> botticelli_server::ActorServerResult<()> {
+/// let persistence = DatabaseStatePersistence::new()?;
+/// let leases = TaskLeaseManager::new(&persistence, "replica-a", Duration::from_secs(30));
+///
+/// if leases.try_acquire("daily_poster").await?.is_held() {
+///     // Run the task; no other replica will
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TaskLeaseManager {
+    pool: DatabasePool,
+    owner: String,
+    lease_duration: Duration,
+    held: Arc<Mutex<HashSet<String>>>,
+}
+
+impl TaskLeaseManager {
+    /// Create a lease manager for one replica.
+    ///
+    /// # Arguments
+    ///
+    /// * `persistence` - State persistence whose connection pool is shared
+    /// * `owner` - Unique ID of this replica
+    /// * `lease_duration` - How long a lease lives without renewal
+    pub fn new(
+        persistence: &DatabaseStatePersistence,
+        owner: impl Into<String>,
+        lease_duration: Duration,
+    ) -> Self {
+        Self {
+            pool: persistence.pool().clone(),
+            owner: owner.into(),
+            lease_duration,
+            held: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    /// Owner ID of this replica.
+    pub fn owner(&self) -> &str {
+        &self.owner
+    }
+
+    /// How long a lease lives without renewal.
+    pub fn lease_duration(&self) -> Duration {
+        self.lease_duration
+    }
+
+    /// Tasks this replica currently holds leases on.
+    pub fn held(&self) -> Vec<String> {
+        let mut held: Vec<String> = self.held_set().iter().cloned().collect();
+        held.sort();
+        held
+    }
+
+    fn held_set(&self) -> std::sync::MutexGuard<'_, HashSet<String>> {
+        self.held.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Take or extend the lease on a task.
+    #[instrument(skip(self), fields(owner = %self.owner))]
+    pub async fn try_acquire(&self, task_id: &str) -> ActorServerResult<LeaseStatus> {
+        let owner = self.owner.clone();
+        let task = task_id.to_string();
+        let seconds = self.lease_duration.as_secs_f64();
+
+        let lease = with_pooled_connection(&self.pool, move |conn| -> ActorServerResult<_> {
+            botticelli_database::acquire_actor_lease(conn, &task, &owner, seconds)
+                .map_err(|e| format!("Failed to acquire lease: {}", e).into())
+        })
+        .await?;
+        let result = if lease.owner == self.owner {
+            Ok(lease)
+        } else {
+            Err(lease.owner)
+        };
+
+        let mut held = self.held_set();
+        let status = match result {
+            Ok(_) if held.contains(task_id) => LeaseStatus::Renewed,
+            Ok(lease) => {
+                info!(task_id, expires_at = %lease.expires_at, "Lease acquired");
+                held.insert(task_id.to_string());
+                LeaseStatus::Acquired
+            }
+            Err(holder) => {
+                if held.remove(task_id) {
+                    warn!(task_id, holder = %holder, "Lease lost to another replica");
+                } else {
+                    debug!(task_id, holder = %holder, "Lease held by another replica");
+                }
+                LeaseStatus::HeldBy(holder)
+            }
+        };
+
+        Ok(status)
+    }
+
+    /// Give up the lease on a task so another replica can take it at once.
+    #[instrument(skip(self), fields(owner = %self.owner))]
+    pub async fn release(&self, task_id: &str) -> ActorServerResult<()> {
+        self.held_set().remove(task_id);
+        self.delete_leases(Some(task_id.to_string())).await
+    }
+
+    /// Give up every lease held by this replica, e.g. on shutdown.
+    #[instrument(skip(self), fields(owner = %self.owner))]
+    pub async fn release_all(&self) -> ActorServerResult<()> {
+        self.held_set().clear();
+        self.delete_leases(None).await
+    }
+
+    async fn delete_leases(&self, task_id: Option<String>) -> ActorServerResult<()> {
+        let owner = self.owner.clone();
+
+        let released = with_pooled_connection(&self.pool, move |conn| -> ActorServerResult<_> {
+            botticelli_database::release_actor_leases(conn, &owner, task_id.as_deref())
+                .map_err(|e| format!("Failed to release lease: {}", e).into())
+        })
+        .await?;
+
+        info!(owner = %self.owner, released, "Leases released");
+        Ok(())
+    }
+
+    /// Extend every lease this replica holds.
+    ///
+    /// Leases taken over by another replica in the meantime are dropped
+    /// from the held set. Returns the number of leases still held.
+    pub async fn renew_held(&self) -> ActorServerResult<usize> {
+        let mut renewed = 0;
+        for task_id in self.held() {
+            if self.try_acquire(&task_id).await?.is_held() {
+                renewed += 1;
+            }
+        }
+        Ok(renewed)
+    }
+
+    /// Renew held leases in the background at a third of the lease duration.
+    ///
+    /// Keeps leases alive while the main loop is busy executing an actor.
+    pub fn spawn_heartbeat(&self) -> JoinHandle<()> {
+        let leases = self.clone();
+        let period = (self.lease_duration / 3).max(Duration::from_secs(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                if let Err(e) = leases.renew_held().await {
+                    warn!(owner = %leases.owner, error = %e, "Failed to renew leases");
+                }
+            }
+        })
+    }
+
+    /// All current leases, across replicas.
+    pub async fn list_leases(&self) -> ActorServerResult<Vec<ActorServerLeaseRow>> {
+        with_pooled_connection(&self.pool, |conn| -> ActorServerResult<_> {
+            botticelli_database::list_actor_leases(conn)
+                .map_err(|e| format!("Failed to list leases: {}", e).into())
+        })
+        .await
+    }
+}
@@ -1,7 +1,7 @@
 //! Platform-agnostic actor system for social media automation.
 //!
 //! This crate provides the core abstractions for building automated social media
-//! actors that can work across multiple platforms (Discord, Twitter, Bluesky, etc.).
+//! actors that can work across multiple platforms (Discord, Mastodon, Bluesky, Telegram, webhooks, etc.).
 
 #![recursion_limit = "512"]
 //!
@@ -42,13 +42,18 @@
 mod actor;
 mod config;
 mod content;
+mod control;
+mod crosspost;
 #[cfg(feature = "discord")]
 mod discord_server;
 mod error;
 mod execution_tracker;
 mod knowledge;
+mod lease;
 mod platform_trait;
 pub mod platforms;
+#[cfg(feature = "wasm-plugins")]
+mod plugins;
 mod server;
 mod server_config;
 mod skill;
@@ -58,26 +63,45 @@ mod state_persistence;
 pub use actor::{Actor, ActorBuilder, ExecutionResult, ExecutionResultBuilder};
 pub use config::{
     ActorCacheConfig, ActorCacheConfigBuilder, ActorConfig, ActorConfigBuilder, ActorSettings,
-    ActorSettingsBuilder, CacheStrategy, ExecutionConfig, ExecutionConfigBuilder, SkillConfig,
-    SkillConfigBuilder,
+    ActorSettingsBuilder, CacheStrategy, ExecutionConfig, ExecutionConfigBuilder, PluginConfig,
+    PluginConfigBuilder, SkillConfig, SkillConfigBuilder, StopCondition, StopConditionBuilder,
 };
 pub use content::{
     Content, ContentBuilder, ContentPost, ContentPostBuilder, MediaAttachment,
     MediaAttachmentBuilder, MediaType,
 };
+pub use control::{
+    ActorStatus, ActorStatusBuilder, CircuitState, CircuitStateBuilder, ControlClient,
+    ControlHandle, ControlReply, ControlRequest, ExecutionRecord, TriggerOutcome,
+    create_control_router,
+};
+pub use crosspost::{
+    AdaptationRules, AdaptationRulesBuilder, CrossPostDestination, CrossPostLedger,
+    CrossPostPersistence, CrossPostPlatform, CrossPostRecord, CrossPoster, DEFAULT_RETENTION_DAYS,
+    DatabaseCrossPostPersistence, DeliveryStatus, DestinationConfig, DestinationDelivery,
+    DestinationPlatform, MarkdownDialect,
+};
 pub use error::{ActorError, ActorErrorKind, ActorResult};
 pub use execution_tracker::ActorExecutionTracker;
 pub use knowledge::KnowledgeTable;
+pub use lease::{LeaseStatus, TaskLeaseManager};
 pub use platform_trait::{Platform, PlatformCapability, PlatformMessage, PlatformMetadata};
+#[cfg(feature = "wasm-plugins")]
+pub use plugins::WasmSkill;
 pub use server::{
     BasicActorServer, GenericActorManager, GenericContentPoster, JsonStatePersistence,
     SimpleTaskScheduler,
 };
-pub use server_config::{ActorInstanceConfig, ActorServerConfig, ScheduleConfig, ServerSettings};
+pub use server_config::{
+    ActorInstanceConfig, ActorServerConfig, ControlConfig, EventTrigger, LeasingConfig,
+    ScheduleConfig, ServerSettings, SlashCommandsConfig,
+};
 pub use skill::{
     Skill, SkillContext, SkillContextBuilder, SkillInfo, SkillInfoBuilder, SkillOutput,
     SkillOutputBuilder, SkillRegistry, SkillResult,
 };
+#[cfg(feature = "discord")]
+pub use skills::SecureDiscordExecutor;
 pub use skills::{
     ContentFormatterSkill, ContentSchedulingSkill, ContentSelectionSkill, DuplicateCheckSkill,
     NarrativeExecutionSkill, RateLimitingSkill,
@@ -90,7 +114,10 @@ pub use discord_server::{
     DiscordServerState, DiscordTaskScheduler,
 };
 
-pub use platforms::NoOpPlatform;
+pub use platforms::{
+    BlueskyPlatform, MastodonPlatform, MastodonPlatformBuilder, MastodonVisibility,
+    ModeratedPlatform, NoOpPlatform, TelegramPlatform, WebhookPlatform,
+};
 
 #[cfg(feature = "discord")]
 pub use platforms::{DiscordPlatform, DiscordPlatformBuilder};
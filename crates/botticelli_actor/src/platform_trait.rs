@@ -5,12 +5,14 @@ use async_trait::async_trait;
 use std::collections::HashMap;
 
 /// Platform-agnostic message to post.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct PlatformMessage {
     /// Text content
     pub text: String,
     /// Media URLs to attach
     pub media_urls: Vec<String>,
+    /// ID of the content row the message was built from, if any
+    pub content_id: Option<String>,
 }
 
 /// Metadata returned after posting.
@@ -29,6 +31,10 @@ pub enum PlatformCapability {
     Links,
     /// Supports scheduled posts
     Scheduling,
+    /// Supports content warnings (spoiler text) on posts
+    ContentWarnings,
+    /// Supports reply chains for posts over the length limit
+    Threads,
 }
 
 /// Trait for social media platform implementations.
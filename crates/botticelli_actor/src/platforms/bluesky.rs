@@ -0,0 +1,225 @@
+//! Bluesky platform implementation.
+//!
+//! Posts through the AT Protocol using [`BlueskyClient`] from
+//! `botticelli_social`. Links, mentions and hashtags become rich-text facets,
+//! and text over the 300-grapheme limit is posted as a reply chain.
+
+use super::http::{fetch_media_in, mime_from_extension};
+use super::thread::split_into_thread;
+use crate::{
+    ActorError, ActorErrorKind, ActorResult, Platform, PlatformCapability, PlatformMessage,
+    PlatformMetadata,
+};
+use async_trait::async_trait;
+use botticelli_social::{
+    BLUESKY_MAX_GRAPHEMES, BLUESKY_MAX_IMAGES, BlueskyClient, BlueskyError, BlueskyErrorKind,
+    ImageUpload, NewPost, PostRef, ReplyRef, grapheme_len,
+};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Bluesky platform implementation.
+///
+/// Authenticates with an app password; the session is created on first use
+/// and refreshed automatically.
+#[derive(Debug, Clone)]
+pub struct BlueskyPlatform {
+    client: Arc<BlueskyClient>,
+    http: reqwest::Client,
+    media_dir: Option<PathBuf>,
+}
+
+impl BlueskyPlatform {
+    /// Create a Bluesky platform for an account.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the service URL is invalid or credentials are empty.
+    #[tracing::instrument(skip(app_password), fields(service, identifier))]
+    pub fn new(
+        service: impl Into<String>,
+        identifier: impl Into<String>,
+        app_password: impl Into<String>,
+    ) -> ActorResult<Self> {
+        let client = BlueskyClient::new(service, identifier, app_password).map_err(actor_error)?;
+        Ok(Self::with_client(Arc::new(client)))
+    }
+
+    /// Create a Bluesky platform using the `bluesky_identifier` and
+    /// `bluesky_app_password` secrets.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a secret cannot be resolved.
+    pub fn from_secrets() -> ActorResult<Self> {
+        let client = BlueskyClient::from_secrets().map_err(|e| {
+            ActorError::new(ActorErrorKind::AuthenticationFailed(format!(
+                "Bluesky credentials unavailable: {}",
+                e
+            )))
+        })?;
+        Ok(Self::with_client(Arc::new(client)))
+    }
+
+    /// Create a platform sharing an existing client (e.g. with a command executor).
+    pub fn with_client(client: Arc<BlueskyClient>) -> Self {
+        Self {
+            client,
+            http: reqwest::Client::new(),
+            media_dir: None,
+        }
+    }
+
+    /// Allow local image files from a directory.
+    pub fn with_media_dir(mut self, media_dir: impl Into<PathBuf>) -> Self {
+        self.media_dir = Some(media_dir.into());
+        self
+    }
+
+    /// Get the underlying client.
+    pub fn client(&self) -> &Arc<BlueskyClient> {
+        &self.client
+    }
+
+    /// Split text into posts that fit the 300-grapheme limit.
+    pub fn thread_parts(&self, text: &str) -> Vec<String> {
+        split_into_thread(text, BLUESKY_MAX_GRAPHEMES, grapheme_len)
+    }
+
+    /// Load one image for upload.
+    async fn load_image(&self, url: &str) -> ActorResult<ImageUpload> {
+        let media = fetch_media_in(&self.http, "Bluesky", url, self.media_dir.as_deref()).await?;
+        // Servers often send a generic content type, so fall back to the extension
+        let mime_type = media
+            .mime_type
+            .filter(|mime| mime.starts_with("image/"))
+            .or_else(|| mime_from_extension(Path::new(&media.file_name)).map(str::to_string))
+            .filter(|mime| mime.starts_with("image/"))
+            .ok_or_else(|| {
+                ActorError::new(ActorErrorKind::ValidationFailed(format!(
+                    "Bluesky only supports image attachments: {}",
+                    url
+                )))
+            })?;
+
+        Ok(ImageUpload {
+            bytes: media.bytes,
+            mime_type,
+            alt: String::new(),
+        })
+    }
+}
+
+/// Map a Bluesky client error to the matching actor error kind.
+#[track_caller]
+fn actor_error(error: BlueskyError) -> ActorError {
+    let message = error.to_string();
+    let kind = match error.kind() {
+        BlueskyErrorKind::Http(_) => ActorErrorKind::PlatformTemporary(message),
+        BlueskyErrorKind::AuthenticationFailed(_) => ActorErrorKind::AuthenticationFailed(message),
+        BlueskyErrorKind::RateLimited(retry_after) => {
+            ActorErrorKind::RateLimitExceeded(*retry_after)
+        }
+        BlueskyErrorKind::Xrpc { status, .. } if *status >= 500 => {
+            ActorErrorKind::PlatformTemporary(message)
+        }
+        BlueskyErrorKind::Xrpc { .. } => ActorErrorKind::PlatformPermanent(message),
+        BlueskyErrorKind::PostTooLong(_) | BlueskyErrorKind::InvalidInput(_) => {
+            ActorErrorKind::ValidationFailed(message)
+        }
+        BlueskyErrorKind::ConfigurationError(_) => ActorErrorKind::InvalidConfiguration(message),
+    };
+    ActorError::new(kind)
+}
+
+#[async_trait]
+impl Platform for BlueskyPlatform {
+    #[tracing::instrument(skip(self, message), fields(service = %self.client.service()))]
+    async fn post(&self, message: &PlatformMessage) -> ActorResult<PlatformMetadata> {
+        tracing::debug!("Posting to Bluesky");
+
+        if message.text.trim().is_empty() && message.media_urls.is_empty() {
+            return Err(ActorError::new(ActorErrorKind::ValidationFailed(
+                "Message must have text or media".to_string(),
+            )));
+        }
+
+        if message.media_urls.len() > BLUESKY_MAX_IMAGES {
+            return Err(ActorError::new(ActorErrorKind::ValidationFailed(format!(
+                "Too many images ({}, max {})",
+                message.media_urls.len(),
+                BLUESKY_MAX_IMAGES
+            ))));
+        }
+
+        let mut images = Vec::with_capacity(message.media_urls.len());
+        for url in &message.media_urls {
+            images.push(self.load_image(url).await?);
+        }
+
+        // Images go on the first post; the rest of the thread replies to it
+        let mut images = Some(images);
+        let mut posts: Vec<PostRef> = Vec::new();
+        for part in self.thread_parts(&message.text) {
+            let reply = posts.first().map(|root| ReplyRef {
+                root: root.clone(),
+                parent: posts.last().cloned().unwrap_or_else(|| root.clone()),
+            });
+            let post = NewPost {
+                text: part,
+                images: images.take().unwrap_or_default(),
+                reply,
+                langs: Vec::new(),
+            };
+            posts.push(self.client.create_post(&post).await.map_err(actor_error)?);
+        }
+
+        let first = posts.first().ok_or_else(|| {
+            ActorError::new(ActorErrorKind::PlatformPermanent(
+                "Bluesky returned no post".to_string(),
+            ))
+        })?;
+
+        tracing::info!(
+            uri = %first.uri,
+            thread_length = posts.len(),
+            image_count = message.media_urls.len(),
+            "Posted to Bluesky"
+        );
+
+        let mut metadata = PlatformMetadata::new();
+        metadata.insert("uri".to_string(), first.uri.clone());
+        metadata.insert("cid".to_string(), first.cid.clone());
+        metadata.insert(
+            "thread_uris".to_string(),
+            posts
+                .iter()
+                .map(|p| p.uri.as_str())
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+
+        Ok(metadata)
+    }
+
+    #[tracing::instrument(skip(self), fields(service = %self.client.service()))]
+    async fn verify_connection(&self) -> ActorResult<()> {
+        tracing::debug!("Verifying Bluesky session");
+        let session = self.client.session().await.map_err(actor_error)?;
+        tracing::info!(handle = %session.handle, "Bluesky connection verified");
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Vec<PlatformCapability> {
+        vec![
+            PlatformCapability::Text,
+            PlatformCapability::Images,
+            PlatformCapability::Links,
+            PlatformCapability::Threads,
+        ]
+    }
+
+    fn platform_name(&self) -> &str {
+        "bluesky"
+    }
+}
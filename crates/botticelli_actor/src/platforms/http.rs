@@ -0,0 +1,180 @@
+//! HTTP helpers shared by REST-based platforms.
+
+use crate::{ActorError, ActorErrorKind, ActorResult};
+use reqwest::{Response, StatusCode};
+use std::path::{Path, PathBuf};
+
+/// Fallback delay when a rate-limited response carries no `Retry-After`.
+const DEFAULT_RETRY_AFTER_SECS: u64 = 60;
+
+/// Media file downloaded or read for upload.
+#[derive(Debug, Clone)]
+pub(crate) struct MediaFile {
+    /// Raw file contents.
+    pub bytes: Vec<u8>,
+    /// File name sent with the upload.
+    pub file_name: String,
+    /// MIME type, if known.
+    pub mime_type: Option<String>,
+}
+
+/// Map a request failure (no response) to an actor error.
+#[track_caller]
+pub(crate) fn request_error(platform: &str, error: reqwest::Error) -> ActorError {
+    ActorError::new(ActorErrorKind::PlatformTemporary(format!(
+        "{} request failed: {}",
+        platform, error
+    )))
+}
+
+/// Turn a non-success response into an actor error.
+///
+/// Authentication failures and client errors are permanent; rate limits and
+/// server errors are recoverable so the actor retries them.
+pub(crate) async fn check_response(platform: &str, response: Response) -> ActorResult<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let retry_after = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok());
+    let body = response.text().await.unwrap_or_default();
+
+    Err(status_error(platform, status, retry_after, &body))
+}
+
+/// Map an HTTP status to the matching actor error kind.
+#[track_caller]
+pub(crate) fn status_error(
+    platform: &str,
+    status: StatusCode,
+    retry_after: Option<u64>,
+    body: &str,
+) -> ActorError {
+    let message = format!("{} returned {}: {}", platform, status, body.trim());
+    let kind = match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            ActorErrorKind::AuthenticationFailed(message)
+        }
+        StatusCode::TOO_MANY_REQUESTS => {
+            ActorErrorKind::RateLimitExceeded(retry_after.unwrap_or(DEFAULT_RETRY_AFTER_SECS))
+        }
+        StatusCode::UNPROCESSABLE_ENTITY => ActorErrorKind::ValidationFailed(message),
+        s if s.is_server_error() || s == StatusCode::REQUEST_TIMEOUT => {
+            ActorErrorKind::PlatformTemporary(message)
+        }
+        _ => ActorErrorKind::PlatformPermanent(message),
+    };
+    ActorError::new(kind)
+}
+
+/// Load media for upload from an `http(s)://` URL, or a local file.
+///
+/// Local paths and `file://` URLs are only read when `media_dir` is set, and
+/// only if the file resolves (after following symlinks and `..`) to a path
+/// inside that directory.
+pub(crate) async fn fetch_media_in(
+    client: &reqwest::Client,
+    platform: &str,
+    url: &str,
+    media_dir: Option<&Path>,
+) -> ActorResult<MediaFile> {
+    if url.starts_with("http://") || url.starts_with("https://") {
+        let response = client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| request_error(platform, e))?;
+        let response = check_response(platform, response).await?;
+        let mime_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| request_error(platform, e))?;
+
+        return Ok(MediaFile {
+            bytes: bytes.to_vec(),
+            file_name: file_name(url),
+            mime_type,
+        });
+    }
+
+    let Some(media_dir) = media_dir else {
+        return Err(ActorError::new(ActorErrorKind::ValidationFailed(format!(
+            "{} media must be an http(s) URL unless a media directory is configured: {}",
+            platform, url
+        ))));
+    };
+    let path = local_media_path(media_dir, url).await?;
+    let bytes = tokio::fs::read(&path).await.map_err(|e| {
+        ActorError::new(ActorErrorKind::FileIo {
+            path: path.clone(),
+            message: format!("Failed to read media: {}", e),
+        })
+    })?;
+
+    Ok(MediaFile {
+        bytes,
+        file_name: file_name(&path.to_string_lossy()),
+        mime_type: mime_from_extension(&path).map(str::to_string),
+    })
+}
+
+/// Resolve a local media reference, rejecting files outside `media_dir`.
+///
+/// Relative paths are taken relative to `media_dir`.
+async fn local_media_path(media_dir: &Path, url: &str) -> ActorResult<PathBuf> {
+    let canonicalize = |path: PathBuf| async move {
+        tokio::fs::canonicalize(&path).await.map_err(|e| {
+            ActorError::new(ActorErrorKind::FileIo {
+                path,
+                message: format!("Failed to resolve media path: {}", e),
+            })
+        })
+    };
+
+    let root = canonicalize(media_dir.to_path_buf()).await?;
+    let path = canonicalize(media_dir.join(url.strip_prefix("file://").unwrap_or(url))).await?;
+    if !path.starts_with(&root) {
+        return Err(ActorError::new(ActorErrorKind::ValidationFailed(format!(
+            "Media file is outside the media directory {}: {}",
+            media_dir.display(),
+            url
+        ))));
+    }
+    Ok(path)
+}
+
+/// Last path segment of a URL or path, without query string.
+fn file_name(url: &str) -> String {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    path.rsplit('/')
+        .find(|s| !s.is_empty())
+        .unwrap_or("media")
+        .to_string()
+}
+
+/// Guess a MIME type for common media extensions.
+pub(crate) fn mime_from_extension(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    Some(match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "mov" => "video/quicktime",
+        "mp3" => "audio/mpeg",
+        "ogg" => "audio/ogg",
+        _ => return None,
+    })
+}
@@ -0,0 +1,355 @@
+//! Mastodon platform implementation.
+//!
+//! Posts statuses through the Mastodon REST API (`/api/v1/statuses`) with
+//! media uploads (`/api/v2/media`), content warnings and visibility. Text over
+//! the instance character limit is posted as a reply chain.
+
+use super::http::{check_response, fetch_media_in, request_error};
+use super::thread::split_into_thread;
+use crate::{
+    ActorError, ActorErrorKind, ActorResult, Platform, PlatformCapability, PlatformMessage,
+    PlatformMetadata,
+};
+use async_trait::async_trait;
+use reqwest::multipart::{Form, Part};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Default Mastodon status length in characters.
+pub const MASTODON_DEFAULT_MAX_CHARACTERS: usize = 500;
+
+/// Mastodon maximum number of media attachments per status.
+const MASTODON_MAX_ATTACHMENTS: usize = 4;
+
+/// Secret holding the Mastodon access token.
+const MASTODON_TOKEN_SECRET: &str = "mastodon_access_token";
+
+/// Who can see a status.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MastodonVisibility {
+    /// Visible to everyone and shown in public timelines
+    #[default]
+    Public,
+    /// Visible to everyone but hidden from public timelines
+    Unlisted,
+    /// Visible to followers only
+    Private,
+    /// Visible to mentioned users only
+    Direct,
+}
+
+/// Mastodon platform implementation.
+///
+/// Authenticates with an application access token that has the
+/// `write:statuses` and `write:media` scopes.
+#[derive(Clone, derive_builder::Builder)]
+#[builder(setter(into), build_fn(validate = "Self::validate"))]
+pub struct MastodonPlatform {
+    /// Instance base URL (e.g. `https://mastodon.social`).
+    base_url: String,
+    /// Access token for the posting account.
+    access_token: String,
+    /// Visibility of posted statuses.
+    #[builder(default)]
+    visibility: MastodonVisibility,
+    /// Content warning (spoiler text) shown before the status body.
+    #[builder(default, setter(strip_option))]
+    content_warning: Option<String>,
+    /// Mark attached media as sensitive.
+    #[builder(default)]
+    sensitive: bool,
+    /// Instance character limit per status.
+    #[builder(default = "MASTODON_DEFAULT_MAX_CHARACTERS")]
+    max_characters: usize,
+    /// Directory local media files may be read from; URLs only when unset.
+    #[builder(default, setter(strip_option))]
+    media_dir: Option<PathBuf>,
+    /// HTTP client.
+    #[builder(setter(skip), default = "reqwest::Client::new()")]
+    client: reqwest::Client,
+}
+
+impl std::fmt::Debug for MastodonPlatform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MastodonPlatform")
+            .field("base_url", &self.base_url)
+            .field("visibility", &self.visibility)
+            .field("content_warning", &self.content_warning)
+            .field("sensitive", &self.sensitive)
+            .field("max_characters", &self.max_characters)
+            .field("media_dir", &self.media_dir)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MastodonPlatformBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match &self.base_url {
+            Some(url) if url.starts_with("http://") || url.starts_with("https://") => {}
+            _ => return Err("Mastodon base_url must be an http(s) URL".to_string()),
+        }
+        if self.access_token.as_ref().is_none_or(|t| t.is_empty()) {
+            return Err("Mastodon access_token cannot be empty".to_string());
+        }
+        if self.max_characters == Some(0) {
+            return Err("Mastodon max_characters must be positive".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Status as returned by the Mastodon API (fields we use).
+#[derive(Debug, Deserialize)]
+struct MastodonStatus {
+    id: String,
+    #[serde(default)]
+    url: Option<String>,
+}
+
+/// Media attachment as returned by the Mastodon API (fields we use).
+#[derive(Debug, Deserialize)]
+struct MastodonMedia {
+    id: String,
+}
+
+/// Request body for creating a status.
+#[derive(Debug, Serialize)]
+struct NewStatus<'a> {
+    status: &'a str,
+    visibility: MastodonVisibility,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spoiler_text: Option<&'a str>,
+    sensitive: bool,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    media_ids: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    in_reply_to_id: Option<&'a str>,
+}
+
+impl MastodonPlatform {
+    /// Create a Mastodon platform for an instance and access token.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the URL is not http(s) or the token is empty.
+    #[tracing::instrument(skip(access_token), fields(base_url))]
+    pub fn new(base_url: impl Into<String>, access_token: impl Into<String>) -> ActorResult<Self> {
+        MastodonPlatformBuilder::default()
+            .base_url(base_url)
+            .access_token(access_token)
+            .build()
+            .map_err(|e| ActorError::new(ActorErrorKind::InvalidConfiguration(e.to_string())))
+    }
+
+    /// Create a Mastodon platform using the `mastodon_access_token` secret.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the secret cannot be resolved or the URL is invalid.
+    pub fn from_secrets(base_url: impl Into<String>) -> ActorResult<Self> {
+        let token = botticelli_secrets::get_secret(MASTODON_TOKEN_SECRET).map_err(|e| {
+            ActorError::new(ActorErrorKind::AuthenticationFailed(format!(
+                "Mastodon access token unavailable: {}",
+                e
+            )))
+        })?;
+        Self::new(base_url, token.into_inner())
+    }
+
+    /// Get a builder for configuring visibility, content warnings and limits.
+    pub fn builder() -> MastodonPlatformBuilder {
+        MastodonPlatformBuilder::default()
+    }
+
+    /// Allow local media files from a directory.
+    pub fn with_media_dir(mut self, media_dir: impl Into<PathBuf>) -> Self {
+        self.media_dir = Some(media_dir.into());
+        self
+    }
+
+    /// Get the instance base URL.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Get the configured visibility.
+    pub fn visibility(&self) -> MastodonVisibility {
+        self.visibility
+    }
+
+    /// Get the configured content warning.
+    pub fn content_warning(&self) -> Option<&str> {
+        self.content_warning.as_deref()
+    }
+
+    /// Get the character limit per status.
+    pub fn max_characters(&self) -> usize {
+        self.max_characters
+    }
+
+    /// Split text into statuses that fit the instance character limit.
+    pub fn thread_parts(&self, text: &str) -> Vec<String> {
+        split_into_thread(text, self.max_characters, |s| s.chars().count())
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!("{}{}", self.base_url.trim_end_matches('/'), path)
+    }
+
+    /// Upload one media attachment and return its id.
+    #[tracing::instrument(skip(self))]
+    async fn upload_media(&self, url: &str) -> ActorResult<String> {
+        let media =
+            fetch_media_in(&self.client, "Mastodon", url, self.media_dir.as_deref()).await?;
+
+        let mut part = Part::bytes(media.bytes).file_name(media.file_name);
+        if let Some(mime) = &media.mime_type {
+            part = part.mime_str(mime).map_err(|e| {
+                ActorError::new(ActorErrorKind::ValidationFailed(format!(
+                    "Invalid media type '{}': {}",
+                    mime, e
+                )))
+            })?;
+        }
+
+        let response = self
+            .client
+            .post(self.endpoint("/api/v2/media"))
+            .bearer_auth(&self.access_token)
+            .multipart(Form::new().part("file", part))
+            .send()
+            .await
+            .map_err(|e| request_error("Mastodon", e))?;
+        let uploaded: MastodonMedia = check_response("Mastodon", response)
+            .await?
+            .json()
+            .await
+            .map_err(|e| request_error("Mastodon", e))?;
+
+        tracing::debug!(media_id = %uploaded.id, "Uploaded media");
+        Ok(uploaded.id)
+    }
+
+    /// Create a single status.
+    async fn create_status(&self, status: &NewStatus<'_>) -> ActorResult<MastodonStatus> {
+        let response = self
+            .client
+            .post(self.endpoint("/api/v1/statuses"))
+            .bearer_auth(&self.access_token)
+            .json(status)
+            .send()
+            .await
+            .map_err(|e| request_error("Mastodon", e))?;
+
+        check_response("Mastodon", response)
+            .await?
+            .json()
+            .await
+            .map_err(|e| request_error("Mastodon", e))
+    }
+}
+
+#[async_trait]
+impl Platform for MastodonPlatform {
+    #[tracing::instrument(skip(self, message), fields(base_url = %self.base_url))]
+    async fn post(&self, message: &PlatformMessage) -> ActorResult<PlatformMetadata> {
+        tracing::debug!("Posting status to Mastodon");
+
+        if message.text.trim().is_empty() && message.media_urls.is_empty() {
+            return Err(ActorError::new(ActorErrorKind::ValidationFailed(
+                "Message must have text or media".to_string(),
+            )));
+        }
+
+        if message.media_urls.len() > MASTODON_MAX_ATTACHMENTS {
+            return Err(ActorError::new(ActorErrorKind::ValidationFailed(format!(
+                "Too many media attachments ({}, max {})",
+                message.media_urls.len(),
+                MASTODON_MAX_ATTACHMENTS
+            ))));
+        }
+
+        let mut media_ids = Vec::with_capacity(message.media_urls.len());
+        for url in &message.media_urls {
+            media_ids.push(self.upload_media(url).await?);
+        }
+
+        // Media goes on the first status; the rest of the thread replies to it
+        let parts = self.thread_parts(&message.text);
+        let mut statuses: Vec<MastodonStatus> = Vec::with_capacity(parts.len());
+        for (index, part) in parts.iter().enumerate() {
+            let status = NewStatus {
+                status: part,
+                visibility: self.visibility,
+                spoiler_text: self.content_warning.as_deref(),
+                sensitive: self.sensitive,
+                media_ids: if index == 0 { &media_ids } else { &[] },
+                in_reply_to_id: statuses.last().map(|s| s.id.as_str()),
+            };
+            statuses.push(self.create_status(&status).await?);
+        }
+
+        let first = statuses.first().ok_or_else(|| {
+            ActorError::new(ActorErrorKind::PlatformPermanent(
+                "Mastodon returned no status".to_string(),
+            ))
+        })?;
+
+        tracing::info!(
+            status_id = %first.id,
+            thread_length = statuses.len(),
+            media_count = media_ids.len(),
+            "Posted to Mastodon"
+        );
+
+        let mut metadata = PlatformMetadata::new();
+        metadata.insert("status_id".to_string(), first.id.clone());
+        if let Some(url) = &first.url {
+            metadata.insert("url".to_string(), url.clone());
+        }
+        metadata.insert(
+            "thread_ids".to_string(),
+            statuses
+                .iter()
+                .map(|s| s.id.as_str())
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        metadata.insert("media_ids".to_string(), media_ids.join(","));
+
+        Ok(metadata)
+    }
+
+    #[tracing::instrument(skip(self), fields(base_url = %self.base_url))]
+    async fn verify_connection(&self) -> ActorResult<()> {
+        tracing::debug!("Verifying Mastodon credentials");
+
+        let response = self
+            .client
+            .get(self.endpoint("/api/v1/accounts/verify_credentials"))
+            .bearer_auth(&self.access_token)
+            .send()
+            .await
+            .map_err(|e| request_error("Mastodon", e))?;
+        check_response("Mastodon", response).await?;
+
+        tracing::info!("Mastodon connection verified");
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Vec<PlatformCapability> {
+        vec![
+            PlatformCapability::Text,
+            PlatformCapability::Images,
+            PlatformCapability::Videos,
+            PlatformCapability::Links,
+            PlatformCapability::ContentWarnings,
+            PlatformCapability::Threads,
+        ]
+    }
+
+    fn platform_name(&self) -> &str {
+        "mastodon"
+    }
+}
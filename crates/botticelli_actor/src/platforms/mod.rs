@@ -1,11 +1,26 @@
 //! Platform implementations for social media services.
 
+pub mod bluesky;
+pub(crate) mod http;
+pub mod mastodon;
+pub mod moderated;
 pub mod noop;
+pub mod telegram;
+mod thread;
+pub mod webhook;
 
 #[cfg(feature = "discord")]
 pub mod discord;
 
+pub use bluesky::BlueskyPlatform;
+pub use mastodon::{
+    MASTODON_DEFAULT_MAX_CHARACTERS, MastodonPlatform, MastodonPlatformBuilder, MastodonVisibility,
+};
+pub use moderated::ModeratedPlatform;
 pub use noop::NoOpPlatform;
+pub use telegram::TelegramPlatform;
+pub use thread::split_into_thread;
+pub use webhook::WebhookPlatform;
 
 #[cfg(feature = "discord")]
 pub use discord::{DiscordPlatform, DiscordPlatformBuilder};
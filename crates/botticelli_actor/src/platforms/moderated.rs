@@ -0,0 +1,66 @@
+//! Platform wrapper that runs content moderation before posting.
+
+use crate::{
+    ActorError, ActorErrorKind, ActorResult, Platform, PlatformCapability, PlatformMessage,
+    PlatformMetadata,
+};
+use async_trait::async_trait;
+use botticelli_security::{ContentModerator, SecurityErrorKind};
+use std::sync::Arc;
+
+/// Platform decorator that moderates message text before delegating.
+///
+/// Rejected content surfaces as [`ActorErrorKind::ValidationFailed`], the same
+/// error platforms use for length and attachment limits.
+pub struct ModeratedPlatform {
+    inner: Arc<dyn Platform>,
+    moderator: Arc<dyn ContentModerator>,
+}
+
+impl ModeratedPlatform {
+    /// Wrap a platform with a moderation stage.
+    pub fn new(inner: Arc<dyn Platform>, moderator: Arc<dyn ContentModerator>) -> Self {
+        Self { inner, moderator }
+    }
+
+    /// Get the wrapped platform.
+    pub fn inner(&self) -> &Arc<dyn Platform> {
+        &self.inner
+    }
+}
+
+#[async_trait]
+impl Platform for ModeratedPlatform {
+    #[tracing::instrument(skip(self, message), fields(platform = self.inner.platform_name()))]
+    async fn post(&self, message: &PlatformMessage) -> ActorResult<PlatformMetadata> {
+        tracing::debug!("Moderating message before posting");
+
+        self.moderator
+            .check(self.inner.platform_name(), &message.text)
+            .await
+            .map_err(|e| {
+                tracing::warn!(error = %e, "Moderation rejected message");
+                let kind = match e.kind() {
+                    SecurityErrorKind::ContentViolation { reason } => {
+                        ActorErrorKind::ValidationFailed(format!("Moderation: {}", reason))
+                    }
+                    other => ActorErrorKind::ResourceUnavailable(other.to_string()),
+                };
+                ActorError::new(kind)
+            })?;
+
+        self.inner.post(message).await
+    }
+
+    async fn verify_connection(&self) -> ActorResult<()> {
+        self.inner.verify_connection().await
+    }
+
+    fn capabilities(&self) -> Vec<PlatformCapability> {
+        self.inner.capabilities()
+    }
+
+    fn platform_name(&self) -> &str {
+        self.inner.platform_name()
+    }
+}
@@ -0,0 +1,206 @@
+//! Telegram platform implementation.
+//!
+//! Posts to a chat or channel through the Telegram Bot API using
+//! [`TelegramClient`] from `botticelli_social`. Text over the 4096-character
+//! limit is sent as a chain of replies; media is sent after the text, with
+//! the text as the caption when a single attachment allows it.
+
+use super::thread::split_into_thread;
+use crate::{
+    ActorError, ActorErrorKind, ActorResult, Platform, PlatformCapability, PlatformMessage,
+    PlatformMetadata,
+};
+use async_trait::async_trait;
+use botticelli_social::{
+    TELEGRAM_MAX_CAPTION_LENGTH, TELEGRAM_MAX_MESSAGE_LENGTH, TelegramClient, TelegramError,
+    TelegramErrorKind, TelegramMediaKind, TelegramMessage, TelegramSendOptions, chat_id_value,
+};
+use std::sync::Arc;
+
+/// Telegram platform implementation.
+///
+/// The bot must be a member of the target chat, and an administrator with
+/// posting rights for channels.
+#[derive(Debug, Clone)]
+pub struct TelegramPlatform {
+    client: Arc<TelegramClient>,
+    chat_id: String,
+}
+
+impl TelegramPlatform {
+    /// Create a Telegram platform for a bot token and chat.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the token is empty or the chat ID is malformed.
+    #[tracing::instrument(skip_all)]
+    pub fn new(token: impl Into<String>, chat_id: impl Into<String>) -> ActorResult<Self> {
+        let client = TelegramClient::new(token).map_err(actor_error)?;
+        Self::with_client(Arc::new(client), chat_id)
+    }
+
+    /// Create a Telegram platform using the `telegram_bot_token` secret.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the secret cannot be resolved or the chat ID is malformed.
+    pub fn from_secrets(chat_id: impl Into<String>) -> ActorResult<Self> {
+        let client = TelegramClient::from_secrets().map_err(|e| {
+            ActorError::new(ActorErrorKind::AuthenticationFailed(format!(
+                "Telegram bot token unavailable: {}",
+                e
+            )))
+        })?;
+        Self::with_client(Arc::new(client), chat_id)
+    }
+
+    /// Create a platform sharing an existing client (e.g. with a command executor).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the chat ID is malformed.
+    pub fn with_client(
+        client: Arc<TelegramClient>,
+        chat_id: impl Into<String>,
+    ) -> ActorResult<Self> {
+        let chat_id = chat_id.into();
+        chat_id_value(&chat_id).map_err(actor_error)?;
+        Ok(Self { client, chat_id })
+    }
+
+    /// Get the target chat ID.
+    pub fn chat_id(&self) -> &str {
+        &self.chat_id
+    }
+
+    /// Get the underlying client.
+    pub fn client(&self) -> &Arc<TelegramClient> {
+        &self.client
+    }
+
+    /// Split text into messages that fit the Telegram length limit.
+    pub fn thread_parts(&self, text: &str) -> Vec<String> {
+        split_into_thread(text, TELEGRAM_MAX_MESSAGE_LENGTH, |s| s.chars().count())
+    }
+}
+
+/// Map a Telegram client error to the matching actor error kind.
+#[track_caller]
+fn actor_error(error: TelegramError) -> ActorError {
+    let message = error.to_string();
+    let kind = match error.kind() {
+        TelegramErrorKind::Http(_) => ActorErrorKind::PlatformTemporary(message),
+        TelegramErrorKind::Unauthorized(_) => ActorErrorKind::AuthenticationFailed(message),
+        TelegramErrorKind::RateLimited(retry_after) => {
+            ActorErrorKind::RateLimitExceeded(*retry_after)
+        }
+        TelegramErrorKind::Api { code, .. } if *code >= 500 => {
+            ActorErrorKind::PlatformTemporary(message)
+        }
+        TelegramErrorKind::Api { .. }
+        | TelegramErrorKind::ChatMigrated(_)
+        | TelegramErrorKind::DatabaseError(_) => ActorErrorKind::PlatformPermanent(message),
+        TelegramErrorKind::InvalidInput(_) => ActorErrorKind::ValidationFailed(message),
+        TelegramErrorKind::ConfigurationError(_) => ActorErrorKind::InvalidConfiguration(message),
+    };
+    ActorError::new(kind)
+}
+
+#[async_trait]
+impl Platform for TelegramPlatform {
+    #[tracing::instrument(skip(self, message), fields(chat_id = %self.chat_id))]
+    async fn post(&self, message: &PlatformMessage) -> ActorResult<PlatformMetadata> {
+        tracing::debug!("Posting to Telegram");
+
+        let text = message.text.trim();
+        if text.is_empty() && message.media_urls.is_empty() {
+            return Err(ActorError::new(ActorErrorKind::ValidationFailed(
+                "Message must have text or media".to_string(),
+            )));
+        }
+
+        // A single attachment carries short text as its caption
+        let caption_text = message.media_urls.len() == 1
+            && !text.is_empty()
+            && text.chars().count() <= TELEGRAM_MAX_CAPTION_LENGTH;
+
+        let mut sent: Vec<TelegramMessage> = Vec::new();
+        if !text.is_empty() && !caption_text {
+            for part in self.thread_parts(text) {
+                let options = TelegramSendOptions {
+                    reply_to_message_id: sent.last().map(|m| m.message_id),
+                    ..TelegramSendOptions::default()
+                };
+                let posted = self
+                    .client
+                    .send_message(&self.chat_id, &part, &options)
+                    .await
+                    .map_err(actor_error)?;
+                sent.push(posted);
+            }
+        }
+
+        for url in &message.media_urls {
+            let caption = caption_text.then_some(text);
+            let posted = self
+                .client
+                .send_media(
+                    &self.chat_id,
+                    TelegramMediaKind::from_extension(url),
+                    url,
+                    caption,
+                    &TelegramSendOptions::default(),
+                )
+                .await
+                .map_err(actor_error)?;
+            sent.push(posted);
+        }
+
+        let first = sent.first().ok_or_else(|| {
+            ActorError::new(ActorErrorKind::PlatformPermanent(
+                "Telegram returned no message".to_string(),
+            ))
+        })?;
+
+        tracing::info!(
+            message_id = first.message_id,
+            message_count = sent.len(),
+            "Posted to Telegram"
+        );
+
+        let mut metadata = PlatformMetadata::new();
+        metadata.insert("message_id".to_string(), first.message_id.to_string());
+        metadata.insert("chat_id".to_string(), first.chat.id.to_string());
+        metadata.insert(
+            "message_ids".to_string(),
+            sent.iter()
+                .map(|m| m.message_id.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+
+        Ok(metadata)
+    }
+
+    #[tracing::instrument(skip(self), fields(chat_id = %self.chat_id))]
+    async fn verify_connection(&self) -> ActorResult<()> {
+        tracing::debug!("Verifying Telegram bot token");
+        let me = self.client.get_me().await.map_err(actor_error)?;
+        tracing::info!(bot_id = me.id, username = ?me.username, "Telegram connection verified");
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Vec<PlatformCapability> {
+        vec![
+            PlatformCapability::Text,
+            PlatformCapability::Images,
+            PlatformCapability::Videos,
+            PlatformCapability::Links,
+            PlatformCapability::Threads,
+        ]
+    }
+
+    fn platform_name(&self) -> &str {
+        "telegram"
+    }
+}
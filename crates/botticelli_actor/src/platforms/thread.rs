@@ -0,0 +1,76 @@
+//! Splitting long posts into reply chains.
+
+/// Room reserved at the end of each thread part for the ` (n/m)` marker.
+const THREAD_MARKER_RESERVE: usize = 8;
+
+/// Split `text` into posts that each fit within `max_len`.
+///
+/// Text that already fits is returned unchanged as a single post. Otherwise
+/// it is broken at whitespace (words longer than a whole post are split
+/// mid-word) and each part is suffixed with a ` (n/m)` marker. `measure`
+/// returns the length of a string as the platform counts it, e.g. characters
+/// for Mastodon or graphemes for Bluesky.
+pub fn split_into_thread(
+    text: &str,
+    max_len: usize,
+    measure: impl Fn(&str) -> usize,
+) -> Vec<String> {
+    let text = text.trim();
+    if measure(text) <= max_len {
+        return vec![text.to_string()];
+    }
+
+    let limit = max_len.saturating_sub(THREAD_MARKER_RESERVE).max(1);
+    let mut parts = Vec::new();
+    let mut current = String::new();
+
+    for token in text.split_inclusive(char::is_whitespace) {
+        let candidate = format!("{current}{token}");
+        if measure(candidate.trim()) <= limit {
+            current = candidate;
+            continue;
+        }
+
+        if !current.trim().is_empty() {
+            parts.push(current.trim().to_string());
+        }
+
+        // A single word longer than a post has to be split mid-word
+        let mut rest = token.trim_start();
+        while measure(rest.trim_end()) > limit {
+            let split_at = longest_prefix(rest, limit, &measure);
+            parts.push(rest[..split_at].to_string());
+            rest = &rest[split_at..];
+        }
+        current = rest.to_string();
+    }
+
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+
+    let total = parts.len();
+    if total <= 1 {
+        return parts;
+    }
+
+    parts
+        .into_iter()
+        .enumerate()
+        .map(|(i, part)| format!("{} ({}/{})", part, i + 1, total))
+        .collect()
+}
+
+/// Byte index of the longest prefix of `text` that fits within `limit`.
+///
+/// Always returns at least one character so splitting makes progress.
+fn longest_prefix(text: &str, limit: usize, measure: &impl Fn(&str) -> usize) -> usize {
+    let mut best = text.chars().next().map(char::len_utf8).unwrap_or(0);
+    for (index, _) in text.char_indices().skip(1) {
+        if measure(&text[..index]) > limit {
+            break;
+        }
+        best = index;
+    }
+    best
+}
@@ -0,0 +1,120 @@
+//! Generic webhook platform implementation.
+//!
+//! Delivers content to any HTTP endpoint with [`WebhookClient`] from
+//! `botticelli_social`: Slack-compatible incoming webhooks, Matrix hooks, or
+//! internal services. The request body is rendered from the configured JSON
+//! template using the message's `text`, `media_urls`, `media_url` and
+//! `timestamp`.
+
+use crate::{
+    ActorError, ActorErrorKind, ActorResult, Platform, PlatformCapability, PlatformMessage,
+    PlatformMetadata,
+};
+use async_trait::async_trait;
+use botticelli_social::{
+    WebhookClient, WebhookConfig, WebhookError, WebhookErrorKind, message_variables,
+};
+
+/// Generic webhook platform implementation.
+///
+/// Metadata returned from [`Platform::post`] contains `status`, `attempts`
+/// and `response` (the response body), plus one entry per configured
+/// `capture` field.
+#[derive(Debug, Clone)]
+pub struct WebhookPlatform {
+    client: WebhookClient,
+}
+
+impl WebhookPlatform {
+    /// Create a webhook platform from a destination configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the URL, method or a header name is invalid.
+    #[tracing::instrument(skip_all, fields(url = %config.url()))]
+    pub fn new(config: WebhookConfig) -> ActorResult<Self> {
+        let client = WebhookClient::new(config).map_err(actor_error)?;
+        Ok(Self { client })
+    }
+
+    /// Get the destination configuration.
+    pub fn config(&self) -> &WebhookConfig {
+        self.client.config()
+    }
+}
+
+/// Map a webhook error to the matching actor error kind.
+#[track_caller]
+fn actor_error(error: WebhookError) -> ActorError {
+    let message = error.to_string();
+    let kind = match error.kind() {
+        WebhookErrorKind::Http(_) => ActorErrorKind::PlatformTemporary(message),
+        WebhookErrorKind::Status {
+            status: 429,
+            retry_after,
+            ..
+        } => ActorErrorKind::RateLimitExceeded(retry_after.unwrap_or(60)),
+        WebhookErrorKind::Status {
+            status: 401 | 403, ..
+        }
+        | WebhookErrorKind::Secret(_) => ActorErrorKind::AuthenticationFailed(message),
+        WebhookErrorKind::Status { status, .. } if *status == 408 || *status >= 500 => {
+            ActorErrorKind::PlatformTemporary(message)
+        }
+        WebhookErrorKind::Status { .. } => ActorErrorKind::PlatformPermanent(message),
+        WebhookErrorKind::Template(_) => ActorErrorKind::ValidationFailed(message),
+        WebhookErrorKind::ConfigurationError(_) => ActorErrorKind::InvalidConfiguration(message),
+    };
+    ActorError::new(kind)
+}
+
+#[async_trait]
+impl Platform for WebhookPlatform {
+    #[tracing::instrument(skip(self, message), fields(url = %self.client.config().url()))]
+    async fn post(&self, message: &PlatformMessage) -> ActorResult<PlatformMetadata> {
+        tracing::debug!("Posting to webhook");
+
+        let text = message.text.trim();
+        if text.is_empty() && message.media_urls.is_empty() {
+            return Err(ActorError::new(ActorErrorKind::ValidationFailed(
+                "Message must have text or media".to_string(),
+            )));
+        }
+
+        let variables = message_variables(text, &message.media_urls);
+        let response = self.client.send(&variables).await.map_err(actor_error)?;
+
+        tracing::info!(
+            status = response.status(),
+            attempts = response.attempts(),
+            "Posted to webhook"
+        );
+
+        let mut metadata = response.capture(self.client.config().capture());
+        metadata.insert("status".to_string(), response.status().to_string());
+        metadata.insert("attempts".to_string(), response.attempts().to_string());
+        metadata.insert("response".to_string(), response.body().clone());
+
+        Ok(metadata)
+    }
+
+    #[tracing::instrument(skip(self), fields(url = %self.client.config().url()))]
+    async fn verify_connection(&self) -> ActorResult<()> {
+        // Webhooks have no side-effect-free request; the configuration was
+        // validated when the client was built.
+        tracing::debug!("Webhook configuration valid");
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Vec<PlatformCapability> {
+        vec![
+            PlatformCapability::Text,
+            PlatformCapability::Images,
+            PlatformCapability::Links,
+        ]
+    }
+
+    fn platform_name(&self) -> &str {
+        "webhook"
+    }
+}
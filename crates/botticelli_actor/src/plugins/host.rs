@@ -0,0 +1,247 @@
+//! Host functions exposed to skill plugins.
+
+use crate::{Platform, PlatformMessage, PluginConfig};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use wasmtime::StoreLimits;
+use wasmtime::component::Linker;
+
+/// Interface name of the host functions in `wit/skill.wit`.
+const HOST_INTERFACE: &str = "botticelli:skill/host@0.1.0";
+
+/// Per-execution state available to host functions.
+pub(crate) struct PluginHost {
+    plugin: String,
+    knowledge: HashMap<String, Vec<JsonValue>>,
+    platform: Option<Arc<dyn Platform>>,
+    allowed_hosts: Vec<String>,
+    allow_post: bool,
+    http_timeout: Duration,
+    max_response_bytes: usize,
+    http: reqwest::Client,
+    pub(crate) limits: StoreLimits,
+}
+
+impl PluginHost {
+    /// Create host state for one plugin instance.
+    pub(crate) fn new(
+        plugin: impl Into<String>,
+        config: &PluginConfig,
+        http: reqwest::Client,
+        limits: StoreLimits,
+    ) -> Self {
+        Self {
+            plugin: plugin.into(),
+            knowledge: HashMap::new(),
+            platform: None,
+            allowed_hosts: config.allowed_hosts().clone(),
+            allow_post: *config.allow_post(),
+            http_timeout: Duration::from_secs(*config.timeout_secs()),
+            max_response_bytes: config.max_response_kb().saturating_mul(1024),
+            http,
+            limits,
+        }
+    }
+
+    /// Make knowledge tables and the platform available to the plugin.
+    pub(crate) fn with_context(
+        mut self,
+        knowledge: HashMap<String, Vec<JsonValue>>,
+        platform: Arc<dyn Platform>,
+    ) -> Self {
+        self.knowledge = knowledge;
+        self.platform = Some(platform);
+        self
+    }
+
+    fn log(&self, level: &str, message: &str) {
+        let plugin = self.plugin.as_str();
+        match level {
+            "trace" => tracing::trace!(plugin, "{}", message),
+            "debug" => tracing::debug!(plugin, "{}", message),
+            "warn" => tracing::warn!(plugin, "{}", message),
+            "error" => tracing::error!(plugin, "{}", message),
+            _ => tracing::info!(plugin, "{}", message),
+        }
+    }
+
+    fn knowledge(&self, table: &str) -> Result<String, String> {
+        let rows = self
+            .knowledge
+            .get(table)
+            .ok_or_else(|| format!("Knowledge table '{}' is not loaded by this actor", table))?;
+        serde_json::to_string(rows).map_err(|e| e.to_string())
+    }
+
+    fn post_target(&self) -> Result<Arc<dyn Platform>, String> {
+        if !self.allow_post {
+            return Err("Posting is not allowed for plugins (allow_post = false)".to_string());
+        }
+        self.platform
+            .clone()
+            .ok_or_else(|| "No platform available".to_string())
+    }
+
+    fn check_url(&self, url: &str) -> Result<reqwest::Url, String> {
+        let url = reqwest::Url::parse(url).map_err(|e| format!("Invalid URL '{}': {}", url, e))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(format!("URL scheme '{}' is not allowed", url.scheme()));
+        }
+        let host = url.host_str().unwrap_or_default();
+
+        let allowed = self
+            .allowed_hosts
+            .iter()
+            .any(|allowed| match allowed.strip_prefix("*.") {
+                Some(domain) => host.ends_with(&format!(".{}", domain)),
+                None => host == allowed,
+            });
+
+        if allowed {
+            Ok(url)
+        } else {
+            Err(format!("Host '{}' is not in allowed_hosts", host))
+        }
+    }
+
+    /// Parse a plugin message, checking its media URLs against `allowed_hosts`.
+    fn checked_message(&self, message: &str) -> Result<PlatformMessage, String> {
+        let message = parse_message(message)?;
+        for url in &message.media_urls {
+            self.check_url(url)
+                .map_err(|e| format!("Media URL rejected: {}", e))?;
+        }
+        Ok(message)
+    }
+}
+
+/// Parse a JSON message from a plugin into a platform message.
+fn parse_message(message: &str) -> Result<PlatformMessage, String> {
+    let value: JsonValue =
+        serde_json::from_str(message).map_err(|e| format!("Invalid message JSON: {}", e))?;
+
+    let text = value
+        .get("text")
+        .and_then(|t| t.as_str())
+        .ok_or_else(|| "Message needs a 'text' string".to_string())?
+        .to_string();
+    let media_urls = value
+        .get("media_urls")
+        .and_then(|m| m.as_array())
+        .map(|urls| {
+            urls.iter()
+                .filter_map(|u| u.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+    let content_id = value.get("content_id").and_then(|id| match id {
+        JsonValue::String(id) => Some(id.clone()),
+        JsonValue::Number(id) => Some(id.to_string()),
+        _ => None,
+    });
+
+    Ok(PlatformMessage {
+        text,
+        media_urls,
+        content_id,
+    })
+}
+
+/// Read a response body, failing once it grows past `max_bytes`.
+///
+/// The body is read chunk by chunk so an oversized response is dropped
+/// without being buffered in full.
+async fn read_body(response: &mut reqwest::Response, max_bytes: usize) -> Result<Vec<u8>, String> {
+    if response
+        .content_length()
+        .is_some_and(|length| length > max_bytes as u64)
+    {
+        return Err(format!("Response body exceeds {} bytes", max_bytes));
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(|e| e.to_string())? {
+        if body.len() + chunk.len() > max_bytes {
+            return Err(format!("Response body exceeds {} bytes", max_bytes));
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+/// Define the host interface in `linker`.
+pub(crate) fn add_to_linker(linker: &mut Linker<PluginHost>) -> wasmtime::Result<()> {
+    let mut host = linker.instance(HOST_INTERFACE)?;
+
+    host.func_wrap("log", |store, (level, message): (String, String)| {
+        store.data().log(&level, &message);
+        Ok(())
+    })?;
+
+    host.func_wrap("knowledge", |store, (table,): (String,)| {
+        Ok((store.data().knowledge(&table),))
+    })?;
+
+    host.func_wrap_async("post", |store, (message,): (String,)| {
+        let target = store.data().post_target();
+        let message = store.data().checked_message(&message);
+        let plugin = store.data().plugin.clone();
+        Box::new(async move {
+            let result = async {
+                let platform = target?;
+                let message = message?;
+                tracing::info!(
+                    plugin = %plugin,
+                    platform = platform.platform_name(),
+                    "Plugin posting"
+                );
+                let metadata = platform.post(&message).await.map_err(|e| e.to_string())?;
+                serde_json::to_string(&metadata).map_err(|e| e.to_string())
+            }
+            .await;
+            Ok((result,))
+        })
+    })?;
+
+    host.func_wrap_async(
+        "http",
+        |store,
+         (method, url, headers, body): (
+            String,
+            String,
+            Vec<(String, String)>,
+            Option<String>,
+        )| {
+            let url = store.data().check_url(&url);
+            let client = store.data().http.clone();
+            let timeout = store.data().http_timeout;
+            let max_response_bytes = store.data().max_response_bytes;
+            Box::new(async move {
+                let result: Result<(u16, String), String> = async {
+                    let url = url?;
+                    let method = reqwest::Method::from_bytes(method.to_uppercase().as_bytes())
+                        .map_err(|e| format!("Invalid method '{}': {}", method, e))?;
+
+                    let mut request = client.request(method, url).timeout(timeout);
+                    for (name, value) in headers {
+                        request = request.header(name, value);
+                    }
+                    if let Some(body) = body {
+                        request = request.body(body);
+                    }
+
+                    let mut response = request.send().await.map_err(|e| e.to_string())?;
+                    let status = response.status().as_u16();
+                    let body = read_body(&mut response, max_response_bytes).await?;
+                    Ok((status, String::from_utf8_lossy(&body).into_owned()))
+                }
+                .await;
+                Ok((result,))
+            })
+        },
+    )?;
+
+    Ok(())
+}
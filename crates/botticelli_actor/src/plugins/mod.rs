@@ -0,0 +1,21 @@
+//! WASM skill plugins.
+//!
+//! Skills can be shipped as WebAssembly components implementing the
+//! `botticelli:skill` world in `wit/skill.wit`, and loaded at runtime from the
+//! directory named in `[actor.plugins]`:
+//!
+//! ```toml
+//! [actor.plugins]
+//! directory = "plugins"
+//! allowed_hosts = ["api.example.com"]
+//! allow_post = false
+//! ```
+//!
+//! Each execution runs in a fresh instance with a fuel budget, a memory cap
+//! and a wall-clock timeout. Plugins see the skill context as JSON and reach
+//! knowledge tables, the platform and HTTP only through host functions.
+
+mod host;
+mod skill;
+
+pub use skill::WasmSkill;
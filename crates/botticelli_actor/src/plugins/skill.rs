@@ -0,0 +1,289 @@
+//! Skills backed by WebAssembly components.
+
+use super::host::{PluginHost, add_to_linker};
+use crate::{
+    ActorError, ActorErrorKind, ActorResult, PluginConfig, Skill, SkillContext, SkillOutput,
+    SkillOutputBuilder, SkillRegistry, SkillResult,
+};
+use async_trait::async_trait;
+use serde_json::{Value as JsonValue, json};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use wasmtime::component::{Component, Linker};
+use wasmtime::{Config, Engine, Store, StoreLimitsBuilder};
+
+/// Fuel consumed between yields to the async runtime, so timeouts can fire.
+const FUEL_YIELD_INTERVAL: u64 = 10_000;
+
+/// Shared engine for all plugins.
+fn plugin_engine() -> ActorResult<Engine> {
+    static ENGINE: OnceLock<Engine> = OnceLock::new();
+
+    if let Some(engine) = ENGINE.get() {
+        return Ok(engine.clone());
+    }
+
+    let mut config = Config::new();
+    config.async_support(true);
+    config.consume_fuel(true);
+    let engine = Engine::new(&config).map_err(|e| {
+        ActorError::new(ActorErrorKind::Plugin(format!(
+            "Failed to create WASM engine: {:#}",
+            e
+        )))
+    })?;
+
+    Ok(ENGINE.get_or_init(|| engine).clone())
+}
+
+/// Skill implemented by a WebAssembly component.
+///
+/// The component must implement the `botticelli:skill` world from
+/// `wit/skill.wit`. Its name and description are read once at load time;
+/// every execution then runs in a fresh instance.
+pub struct WasmSkill {
+    name: String,
+    description: String,
+    path: PathBuf,
+    engine: Engine,
+    component: Component,
+    linker: Arc<Linker<PluginHost>>,
+    config: PluginConfig,
+    http: reqwest::Client,
+}
+
+impl WasmSkill {
+    /// Load a skill component from a file.
+    ///
+    /// Binary `.wasm` components are expected; `.wat` text is accepted for
+    /// development.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be compiled, does not implement the
+    /// skill world, or fails while reporting its name.
+    #[tracing::instrument(skip(config), fields(path = %path.as_ref().display()))]
+    pub async fn from_file(path: impl AsRef<Path>, config: &PluginConfig) -> ActorResult<Self> {
+        let path = path.as_ref();
+        let plugin_error = |e: wasmtime::Error| {
+            ActorError::new(ActorErrorKind::Plugin(format!(
+                "{}: {:#}",
+                path.display(),
+                e
+            )))
+        };
+
+        let engine = plugin_engine()?;
+        let component = Component::from_file(&engine, path).map_err(plugin_error)?;
+
+        let mut linker = Linker::new(&engine);
+        add_to_linker(&mut linker).map_err(plugin_error)?;
+
+        // Redirects could lead outside `allowed_hosts`, so they are not followed
+        let http = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|e| {
+                ActorError::new(ActorErrorKind::Plugin(format!(
+                    "{}: failed to build HTTP client: {}",
+                    path.display(),
+                    e
+                )))
+            })?;
+
+        let mut skill = Self {
+            name: String::new(),
+            description: String::new(),
+            path: path.to_path_buf(),
+            engine,
+            component,
+            linker: Arc::new(linker),
+            config: config.clone(),
+            http,
+        };
+
+        let (name, description) = skill.describe().await.map_err(plugin_error)?;
+        if name.is_empty() {
+            return Err(ActorError::new(ActorErrorKind::Plugin(format!(
+                "{}: plugin returned an empty name",
+                path.display()
+            ))));
+        }
+
+        tracing::info!(plugin = %name, "Loaded WASM skill");
+        skill.name = name;
+        skill.description = description;
+        Ok(skill)
+    }
+
+    /// Path the component was loaded from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn store(&self, host: PluginHost) -> wasmtime::Result<Store<PluginHost>> {
+        let mut store = Store::new(&self.engine, host);
+        store.limiter(|host| &mut host.limits);
+        store.set_fuel(*self.config.fuel())?;
+        store.fuel_async_yield_interval(Some(FUEL_YIELD_INTERVAL))?;
+        Ok(store)
+    }
+
+    fn host(&self) -> PluginHost {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(self.config.max_memory_mb().saturating_mul(1024 * 1024))
+            .build();
+        PluginHost::new(&self.name, &self.config, self.http.clone(), limits)
+    }
+
+    /// Call the `name` and `description` exports.
+    async fn describe(&self) -> wasmtime::Result<(String, String)> {
+        let mut store = self.store(self.host())?;
+        let instance = self
+            .linker
+            .instantiate_async(&mut store, &self.component)
+            .await?;
+
+        let name_func = instance.get_typed_func::<(), (String,)>(&mut store, "name")?;
+        let (name,) = name_func.call_async(&mut store, ()).await?;
+        name_func.post_return_async(&mut store).await?;
+
+        let description_func =
+            instance.get_typed_func::<(), (String,)>(&mut store, "description")?;
+        let (description,) = description_func.call_async(&mut store, ()).await?;
+        description_func.post_return_async(&mut store).await?;
+
+        Ok((name, description))
+    }
+
+    /// Call the `execute` export in a fresh instance.
+    async fn run(
+        &self,
+        host: PluginHost,
+        context: String,
+    ) -> wasmtime::Result<Result<String, String>> {
+        let mut store = self.store(host)?;
+        let instance = self
+            .linker
+            .instantiate_async(&mut store, &self.component)
+            .await?;
+
+        let execute =
+            instance.get_typed_func::<(&str,), (Result<String, String>,)>(&mut store, "execute")?;
+        let (result,) = execute.call_async(&mut store, (context.as_str(),)).await?;
+        execute.post_return_async(&mut store).await?;
+
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl Skill for WasmSkill {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    #[tracing::instrument(skip(self, context), fields(skill = %self.name))]
+    async fn execute(&self, context: &SkillContext) -> SkillResult<SkillOutput> {
+        tracing::debug!("Executing WASM skill");
+
+        let mut knowledge_tables: Vec<&String> = context.knowledge().keys().collect();
+        knowledge_tables.sort();
+
+        let input = json!({
+            "config": context.config(),
+            "variables": context.variables(),
+            "outputs": context.outputs(),
+            "input": context.input(),
+            "knowledge_tables": knowledge_tables,
+        })
+        .to_string();
+
+        let host = self
+            .host()
+            .with_context(context.knowledge().clone(), Arc::clone(context.platform()));
+        let timeout = Duration::from_secs(*self.config.timeout_secs());
+
+        let result = tokio::time::timeout(timeout, self.run(host, input))
+            .await
+            .map_err(|_| {
+                ActorError::new(ActorErrorKind::Plugin(format!(
+                    "{}: timed out after {}s",
+                    self.name,
+                    timeout.as_secs()
+                )))
+            })?
+            .map_err(|e| {
+                ActorError::new(ActorErrorKind::Plugin(format!("{}: {:#}", self.name, e)))
+            })?
+            .map_err(|e| {
+                ActorError::new(ActorErrorKind::Plugin(format!("{}: {}", self.name, e)))
+            })?;
+
+        let data: JsonValue = serde_json::from_str(&result).map_err(|e| {
+            ActorError::new(ActorErrorKind::JsonError(format!(
+                "{} returned invalid JSON: {}",
+                self.name, e
+            )))
+        })?;
+
+        tracing::info!("WASM skill completed");
+
+        Ok(SkillOutputBuilder::default()
+            .skill_name(self.name.clone())
+            .data(data)
+            .build()
+            .map_err(|e| ActorError::new(ActorErrorKind::Plugin(e)))?)
+    }
+}
+
+impl SkillRegistry {
+    /// Load every skill component in the plugin directory.
+    ///
+    /// Files ending in `.wasm` (or `.wat`) are loaded in name order and
+    /// registered under the name each plugin reports.
+    ///
+    /// # Returns
+    ///
+    /// Names of the registered skills.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the directory cannot be read or any plugin fails to load.
+    #[tracing::instrument(skip(self, config), fields(directory = %config.directory().display()))]
+    pub async fn load_plugins(&mut self, config: &PluginConfig) -> ActorResult<Vec<String>> {
+        let directory = config.directory();
+        let entries = std::fs::read_dir(directory).map_err(|e| {
+            ActorError::new(ActorErrorKind::FileIo {
+                path: directory.clone(),
+                message: e.to_string(),
+            })
+        })?;
+
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| {
+                path.is_file()
+                    && path
+                        .extension()
+                        .is_some_and(|ext| ext == "wasm" || ext == "wat")
+            })
+            .collect();
+        paths.sort();
+
+        let mut names = Vec::with_capacity(paths.len());
+        for path in paths {
+            let skill = WasmSkill::from_file(&path, config).await?;
+            names.push(skill.name().to_string());
+            self.register(Arc::new(skill));
+        }
+
+        tracing::info!(count = names.len(), "Loaded WASM skill plugins");
+        Ok(names)
+    }
+}
@@ -1,8 +1,16 @@
 //! Server configuration for actor-server binary.
 
-use botticelli_server::{Schedule, ScheduleCheck};
-use chrono::{DateTime, Duration, Utc};
+use crate::{ActorError, ActorErrorKind, ActorResult, DestinationConfig};
+use botticelli_error::ServerError;
+use botticelli_interface::{EVENT_VARIABLE_PREFIX, PlatformEvent, PlatformEventKind};
+use botticelli_security::SecurityConfig;
+use botticelli_server::{
+    ConstrainedSchedule, Schedule, ScheduleCheck, ScheduleRules, ScheduleType,
+};
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 
 /// Top-level server configuration loaded from TOML file.
@@ -25,12 +33,91 @@ impl ActorServerConfig {
     ///
     /// # Errors
     ///
-    /// Returns error if file cannot be read or TOML is invalid.
+    /// Returns error if file cannot be read, TOML is invalid or an actor's
+    /// schedule can never run (see [`Schedule::validate`]).
     pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Box<dyn std::error::Error>> {
         let contents = std::fs::read_to_string(path)?;
         let config: Self = toml::from_str(&contents)?;
+        for actor in &config.actors {
+            actor.schedule.validate().map_err(|e| {
+                ActorError::new(ActorErrorKind::InvalidConfiguration(format!(
+                    "Actor '{}' schedule: {}",
+                    actor.name, e.kind
+                )))
+            })?;
+        }
         Ok(config)
     }
+
+    /// Find an actor instance by name.
+    pub fn actor(&self, name: &str) -> Option<&ActorInstanceConfig> {
+        self.actors.iter().find(|actor| actor.name == name)
+    }
+
+    /// Build the slash command dispatcher for `[server.slash_commands]`.
+    ///
+    /// Commands are checked against `[security.discord.permissions]` as
+    /// `slash.<name>`; without a security config every command is denied.
+    /// Returns `None` when slash commands are not configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the narrative directory cannot be read or two
+    /// narratives declare the same command.
+    #[cfg(feature = "discord")]
+    pub fn slash_command_dispatcher(
+        &self,
+        security: Option<&SecurityConfig>,
+        runner: std::sync::Arc<dyn botticelli_social::SlashCommandRunner>,
+    ) -> ActorResult<Option<botticelli_social::SlashCommandDispatcher>> {
+        let Some(slash_commands) = &self.server.slash_commands else {
+            return Ok(None);
+        };
+
+        let mut registry = botticelli_social::SlashCommandRegistry::new();
+        registry
+            .register_dir(&slash_commands.directory)
+            .map_err(|e| {
+                ActorError::new(ActorErrorKind::InvalidConfiguration(format!(
+                    "Slash commands in {}: {}",
+                    slash_commands.directory, e
+                )))
+            })?;
+
+        let permissions = security
+            .and_then(|security| security.platform("discord"))
+            .map(|platform| platform.permissions().clone())
+            .unwrap_or_default();
+        let mut dispatcher = botticelli_social::SlashCommandDispatcher::new(
+            registry,
+            runner,
+            botticelli_security::PermissionChecker::new(permissions),
+        );
+        if let Some(guild_id) = slash_commands.guild_id {
+            dispatcher = dispatcher.with_guild(guild_id);
+        }
+        Ok(Some(dispatcher))
+    }
+
+    /// Load the security configuration named by `[server] security_config`.
+    ///
+    /// Returns `None` when no security file is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is invalid.
+    pub fn load_security(&self) -> ActorResult<Option<SecurityConfig>> {
+        let Some(path) = &self.server.security_config else {
+            return Ok(None);
+        };
+
+        SecurityConfig::from_file(path).map(Some).map_err(|e| {
+            ActorError::new(ActorErrorKind::InvalidConfiguration(format!(
+                "Security config {}: {}",
+                path, e.kind
+            )))
+        })
+    }
 }
 
 /// Server-level settings.
@@ -42,6 +129,66 @@ pub struct ServerSettings {
     /// Circuit breaker configuration
     #[serde(default)]
     pub circuit_breaker: CircuitBreakerConfig,
+    /// HTTP control API; disabled when absent
+    #[serde(default)]
+    pub control: Option<ControlConfig>,
+    /// Task leasing for running several replicas; disabled when absent
+    #[serde(default)]
+    pub leasing: Option<LeasingConfig>,
+    /// Security configuration file (`security.toml` or a file with a
+    /// `[security]` table); moderation is off when absent
+    #[serde(default)]
+    pub security_config: Option<String>,
+    /// Narrative-backed Discord slash commands; disabled when absent
+    #[serde(default)]
+    pub slash_commands: Option<SlashCommandsConfig>,
+}
+
+/// Slash command settings.
+///
+/// Narratives with a `slash_command` table in `directory` are registered
+/// with Discord when the gateway connects.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlashCommandsConfig {
+    /// Directory of narrative TOML files
+    pub directory: String,
+    /// Register commands to this guild only instead of globally
+    #[serde(default)]
+    pub guild_id: Option<u64>,
+}
+
+/// Task leasing settings.
+///
+/// With leasing enabled, replicas sharing a database coordinate through
+/// leases so each actor runs on exactly one of them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeasingConfig {
+    /// Seconds a lease lives without renewal; also the failover delay
+    #[serde(default = "default_lease_seconds")]
+    pub lease_seconds: u64,
+    /// Unique ID of this replica (defaults to hostname and process ID)
+    #[serde(default)]
+    pub replica_id: Option<String>,
+}
+
+impl LeasingConfig {
+    /// Replica ID, falling back to `<hostname>-<pid>`.
+    pub fn replica_id(&self) -> String {
+        self.replica_id.clone().unwrap_or_else(|| {
+            let host = std::env::var("HOSTNAME").unwrap_or_else(|_| "actor-server".to_string());
+            format!("{}-{}", host, std::process::id())
+        })
+    }
+}
+
+/// HTTP control API settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControlConfig {
+    /// Address to listen on
+    #[serde(default = "default_control_bind")]
+    pub bind: String,
+    /// Bearer token required on every request (supports `secret:` references)
+    pub token: String,
 }
 
 /// Circuit breaker configuration for automatic task pause on repeated failures.
@@ -73,6 +220,10 @@ impl Default for ServerSettings {
         Self {
             check_interval_seconds: default_check_interval(),
             circuit_breaker: CircuitBreakerConfig::default(),
+            control: None,
+            leasing: None,
+            security_config: None,
+            slash_commands: None,
         }
     }
 }
@@ -81,6 +232,14 @@ fn default_check_interval() -> u64 {
     60
 }
 
+fn default_control_bind() -> String {
+    "127.0.0.1:8950".to_string()
+}
+
+fn default_lease_seconds() -> u64 {
+    30
+}
+
 fn default_max_failures() -> i32 {
     5
 }
@@ -103,9 +262,19 @@ pub struct ActorInstanceConfig {
     /// Discord channel ID for posting
     #[serde(default)]
     pub channel_id: Option<String>,
+    /// Cross-post destinations; when set, content is posted to all of them
+    /// instead of `channel_id`
+    #[serde(default)]
+    pub destinations: Vec<DestinationConfig>,
     /// Task scheduling configuration
     #[serde(default)]
     pub schedule: ScheduleConfig,
+    /// Timezone, active windows, blackout dates and jitter for the schedule
+    #[serde(default)]
+    pub schedule_rules: ScheduleRules,
+    /// Platform events that run this actor in addition to its schedule
+    #[serde(default)]
+    pub triggers: Vec<EventTrigger>,
     /// Whether this actor is enabled
     #[serde(default = "default_enabled")]
     pub enabled: bool,
@@ -115,6 +284,35 @@ fn default_enabled() -> bool {
     true
 }
 
+impl ActorInstanceConfig {
+    /// Build the actor's schedule with its rules applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `default_timezone` - Timezone used when `schedule_rules` doesn't set
+    ///   one, normally the actor's `timezone` setting
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timezone or an active window is invalid.
+    pub fn build_schedule(
+        &self,
+        default_timezone: &str,
+    ) -> ActorResult<ConstrainedSchedule<ScheduleConfig>> {
+        ConstrainedSchedule::new(
+            self.schedule.clone(),
+            self.schedule_rules.clone(),
+            default_timezone,
+        )
+        .map_err(|e| {
+            ActorError::new(ActorErrorKind::InvalidConfiguration(format!(
+                "Actor '{}' schedule: {}",
+                self.name, e.kind
+            )))
+        })
+    }
+}
+
 /// Task scheduling configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -124,8 +322,52 @@ pub enum ScheduleConfig {
         /// Interval duration in seconds
         seconds: u64,
     },
+    /// Cron expression (7 fields: sec min hour day month weekday year),
+    /// read in the schedule's timezone
+    Cron {
+        /// Cron expression string
+        expression: String,
+    },
+    /// One-time execution at a specific time
+    Once {
+        /// Execution timestamp
+        at: DateTime<Utc>,
+    },
+    /// A fixed number of runs per day, spread evenly over local time
+    TimesPerDay {
+        /// Runs per day
+        times: u32,
+        /// Local time of the first run (default midnight)
+        #[serde(default)]
+        start: Option<NaiveTime>,
+        /// Local time the runs must finish by (default midnight)
+        #[serde(default)]
+        end: Option<NaiveTime>,
+    },
     /// Execute immediately on startup
     Immediate,
+    /// Never run on a timer, only in response to event triggers
+    Triggered,
+}
+
+impl ScheduleConfig {
+    /// The shared [`ScheduleType`] for the calendar-based variants.
+    fn calendar(&self) -> Option<ScheduleType> {
+        match self {
+            ScheduleConfig::Cron { expression } => Some(ScheduleType::Cron {
+                expression: expression.clone(),
+            }),
+            ScheduleConfig::Once { at } => Some(ScheduleType::Once { at: *at }),
+            ScheduleConfig::TimesPerDay { times, start, end } => Some(ScheduleType::TimesPerDay {
+                times: *times,
+                start: *start,
+                end: *end,
+            }),
+            ScheduleConfig::Interval { .. }
+            | ScheduleConfig::Immediate
+            | ScheduleConfig::Triggered => None,
+        }
+    }
 }
 
 impl Default for ScheduleConfig {
@@ -171,16 +413,176 @@ impl Schedule for ScheduleConfig {
                     }
                 }
             }
+            ScheduleConfig::Triggered => ScheduleCheck::new(false, None),
+            ScheduleConfig::Cron { .. }
+            | ScheduleConfig::Once { .. }
+            | ScheduleConfig::TimesPerDay { .. } => self
+                .calendar()
+                .map_or(ScheduleCheck::new(false, None), |calendar| {
+                    calendar.check(last_run)
+                }),
         }
     }
 
     fn next_execution(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.next_execution_in(after, Tz::UTC)
+    }
+
+    fn is_calendar_based(&self) -> bool {
+        self.calendar()
+            .is_some_and(|calendar| calendar.is_calendar_based())
+    }
+
+    fn validate(&self) -> Result<(), ServerError> {
+        self.calendar()
+            .map_or(Ok(()), |calendar| calendar.validate())
+    }
+
+    fn next_execution_in(&self, after: DateTime<Utc>, tz: Tz) -> Option<DateTime<Utc>> {
         match self {
             ScheduleConfig::Interval { seconds } => {
                 let interval = Duration::seconds(*seconds as i64);
                 Some(after + interval)
             }
-            ScheduleConfig::Immediate => None,
+            ScheduleConfig::Cron { .. }
+            | ScheduleConfig::Once { .. }
+            | ScheduleConfig::TimesPerDay { .. } => self
+                .calendar()
+                .and_then(|calendar| calendar.next_execution_in(after, tz)),
+            ScheduleConfig::Immediate | ScheduleConfig::Triggered => None,
         }
     }
 }
+
+/// Platform event that triggers an actor run.
+///
+/// The matched event is passed to the actor's narratives as `${event:...}`
+/// template variables (see [`PlatformEvent::variables`]). Optional filters
+/// that are left unset match any value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum EventTrigger {
+    /// Any message posted in a channel
+    MessageCreated {
+        /// Only messages in this channel
+        #[serde(default)]
+        channel_id: Option<String>,
+    },
+    /// A message containing one of the keywords as a whole word or phrase
+    ///
+    /// `ban` matches "ban him" but not "banana". The matched keyword is available as `${event:keyword}`.
+    KeywordMatched {
+        /// Only messages in this channel
+        #[serde(default)]
+        channel_id: Option<String>,
+        /// Keywords to look for in the message text
+        keywords: Vec<String>,
+        /// Whether keyword matching is case sensitive
+        #[serde(default)]
+        case_sensitive: bool,
+    },
+    /// A member joined a guild
+    MemberJoined {
+        /// Only joins to this guild
+        #[serde(default)]
+        guild_id: Option<String>,
+    },
+    /// A reaction was added to a message
+    ReactionAdded {
+        /// Only reactions in this channel
+        #[serde(default)]
+        channel_id: Option<String>,
+        /// Only this emoji
+        #[serde(default)]
+        emoji: Option<String>,
+    },
+}
+
+impl EventTrigger {
+    /// Match an event against this trigger.
+    ///
+    /// Returns the template variables for the narrative run if the event
+    /// matches, or `None` otherwise.
+    pub fn match_event(&self, event: &PlatformEvent) -> Option<HashMap<String, String>> {
+        let mut variables = event.variables();
+
+        let matched = match self {
+            EventTrigger::MessageCreated { channel_id } => {
+                *event.kind() == PlatformEventKind::MessageCreated
+                    && filter_matches(channel_id, event.channel_id())
+            }
+            EventTrigger::KeywordMatched {
+                channel_id,
+                keywords,
+                case_sensitive,
+            } => {
+                if *event.kind() != PlatformEventKind::MessageCreated
+                    || !filter_matches(channel_id, event.channel_id())
+                {
+                    return None;
+                }
+                let keyword = find_keyword(event.content().as_deref()?, keywords, *case_sensitive)?;
+                variables.insert(format!("{EVENT_VARIABLE_PREFIX}keyword"), keyword.clone());
+                true
+            }
+            EventTrigger::MemberJoined { guild_id } => {
+                *event.kind() == PlatformEventKind::MemberJoined
+                    && filter_matches(guild_id, event.guild_id())
+            }
+            EventTrigger::ReactionAdded { channel_id, emoji } => {
+                *event.kind() == PlatformEventKind::ReactionAdded
+                    && filter_matches(channel_id, event.channel_id())
+                    && filter_matches(emoji, event.emoji())
+            }
+        };
+
+        matched.then_some(variables)
+    }
+}
+
+/// An unset filter matches anything; a set filter requires an equal value.
+fn filter_matches(filter: &Option<String>, value: &Option<String>) -> bool {
+    match filter {
+        None => true,
+        Some(expected) => value.as_deref() == Some(expected.as_str()),
+    }
+}
+
+/// Find the first keyword appearing in `text` as a whole word or phrase.
+fn find_keyword<'a>(
+    text: &str,
+    keywords: &'a [String],
+    case_sensitive: bool,
+) -> Option<&'a String> {
+    if case_sensitive {
+        keywords
+            .iter()
+            .find(|k| !k.is_empty() && contains_word(text, k))
+    } else {
+        let text = text.to_lowercase();
+        keywords
+            .iter()
+            .find(|k| !k.is_empty() && contains_word(&text, &k.to_lowercase()))
+    }
+}
+
+/// Whether `word` occurs in `text` without being part of a longer word.
+///
+/// Only edges of `word` that are word characters need a boundary, so
+/// a keyword like `!help` also matches directly after another word.
+fn contains_word(text: &str, word: &str) -> bool {
+    let needs_start = word.chars().next().is_some_and(is_word_char);
+    let needs_end = word.chars().next_back().is_some_and(is_word_char);
+
+    text.match_indices(word).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + word.len()..].chars().next();
+        let joined_before = needs_start && before.is_some_and(is_word_char);
+        let joined_after = needs_end && after.is_some_and(is_word_char);
+        !joined_before && !joined_after
+    })
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
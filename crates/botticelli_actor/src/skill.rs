@@ -2,8 +2,7 @@
 
 use crate::{ActorError, ActorErrorKind, Platform};
 use async_trait::async_trait;
-use diesel::pg::PgConnection;
-use diesel::r2d2::{ConnectionManager, Pool};
+use botticelli_database::DatabasePool;
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -43,7 +42,35 @@ pub struct SkillContext {
     /// Platform interface.
     platform: Arc<dyn Platform>,
     /// Database connection pool for table operations.
-    db_pool: Pool<ConnectionManager<PgConnection>>,
+    db_pool: DatabasePool,
+    /// Template variables available to narratives (e.g. `event:content`).
+    #[builder(default)]
+    variables: HashMap<String, String>,
+    /// Output data of skills that already ran in this execution, by skill name.
+    #[builder(default)]
+    outputs: HashMap<String, JsonValue>,
+    /// Skills this skill consumes (`inputs_from`), in declaration order.
+    #[builder(default)]
+    inputs_from: Vec<String>,
+}
+
+impl SkillContext {
+    /// Get the output data of an earlier skill.
+    pub fn output(&self, skill_name: &str) -> Option<&JsonValue> {
+        self.outputs.get(skill_name)
+    }
+
+    /// Get the primary input: output data of the first `inputs_from` source that ran.
+    pub fn input(&self) -> Option<&JsonValue> {
+        self.inputs().next().map(|(_, data)| data)
+    }
+
+    /// Iterate over `inputs_from` sources that produced output, in declaration order.
+    pub fn inputs(&self) -> impl Iterator<Item = (&str, &JsonValue)> {
+        self.inputs_from
+            .iter()
+            .filter_map(|name| self.outputs.get(name).map(|data| (name.as_str(), data)))
+    }
 }
 
 /// Information about a skill.
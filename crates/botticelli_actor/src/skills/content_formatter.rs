@@ -65,11 +65,19 @@ impl Skill for ContentFormatterSkill {
             "Content formatter configuration loaded"
         );
 
+        // Prefer candidates from an upstream skill (`inputs_from`)
         let content_rows = context
-            .knowledge()
-            .get("content")
+            .input()
+            .and_then(|input| input.get("candidates"))
+            .and_then(|c| c.as_array())
             .cloned()
-            .unwrap_or_default();
+            .unwrap_or_else(|| {
+                context
+                    .knowledge()
+                    .get("content")
+                    .cloned()
+                    .unwrap_or_default()
+            });
 
         let formatted_count = content_rows.len();
 
@@ -83,14 +83,42 @@ impl Skill for DuplicateCheckSkill {
             "Duplicate check completed"
         );
 
+        let mut data = json!({
+            "posted_content_ids": content_ids,
+            "lookback_days": lookback_days,
+            "similarity_threshold": similarity_threshold,
+            "history_count": post_history.len(),
+        });
+
+        // Filter candidates from an upstream skill (`inputs_from`)
+        if let Some(candidates) = context
+            .input()
+            .and_then(|input| input.get("candidates"))
+            .and_then(|c| c.as_array())
+        {
+            let (duplicates, remaining): (Vec<_>, Vec<_>) =
+                candidates.iter().cloned().partition(|candidate| {
+                    candidate
+                        .get("content_id")
+                        .or_else(|| candidate.get("id"))
+                        .and_then(|v| v.as_i64())
+                        .is_some_and(|id| content_ids.contains(&(id as i32)))
+                });
+
+            tracing::info!(
+                candidates = candidates.len(),
+                duplicates = duplicates.len(),
+                "Filtered upstream candidates"
+            );
+
+            data["is_duplicate"] = json!(!candidates.is_empty() && remaining.is_empty());
+            data["duplicates"] = json!(duplicates);
+            data["candidates"] = json!(remaining);
+        }
+
         Ok(SkillOutputBuilder::default()
             .skill_name(self.name.clone())
-            .data(json!({
-                "posted_content_ids": content_ids,
-                "lookback_days": lookback_days,
-                "similarity_threshold": similarity_threshold,
-                "history_count": post_history.len(),
-            }))
+            .data(data)
             .build()
             .map_err(|e| ActorError::new(ActorErrorKind::Narrative(e)))?)
     }
@@ -11,5 +11,7 @@ pub use content_formatter::ContentFormatterSkill;
 pub use content_selection::ContentSelectionSkill;
 pub use duplicate_check::DuplicateCheckSkill;
 pub use narrative_execution::NarrativeExecutionSkill;
+#[cfg(feature = "discord")]
+pub use narrative_execution::SecureDiscordExecutor;
 pub use rate_limiting::RateLimitingSkill;
 pub use scheduling::ContentSchedulingSkill;
@@ -4,17 +4,27 @@ use crate::{
     ActorError, ActorErrorKind, Skill, SkillContext, SkillOutput, SkillOutputBuilder, SkillResult,
 };
 use async_trait::async_trait;
-use botticelli_database::{DatabaseTableQueryRegistry, TableQueryExecutor, establish_connection};
+use botticelli_database::{
+    DatabaseConnectionManager, DatabaseTableQueryRegistry, TableQueryExecutor,
+};
 use botticelli_models::GeminiClient;
 use botticelli_narrative::{NarrativeExecutor, ProcessorRegistry};
 use ractor::Actor;
 use serde_json::json;
 use std::path::Path;
-use std::sync::{Arc, Mutex};
+
+/// Discord executor running commands through the security pipeline.
+#[cfg(feature = "discord")]
+pub type SecureDiscordExecutor = botticelli_social::SecureBotExecutor<
+    botticelli_social::DiscordCommandExecutor,
+    botticelli_security::DiscordValidator,
+>;
 
 /// Skill for executing narrative workflows.
 pub struct NarrativeExecutionSkill {
     name: String,
+    #[cfg(feature = "discord")]
+    discord_executor: Option<SecureDiscordExecutor>,
 }
 
 impl NarrativeExecutionSkill {
@@ -22,8 +32,36 @@ impl NarrativeExecutionSkill {
     pub fn new() -> Self {
         Self {
             name: "narrative_execution".to_string(),
+            #[cfg(feature = "discord")]
+            discord_executor: None,
         }
     }
+
+    /// Run Discord bot commands through a shared secure executor.
+    ///
+    /// Each run registers a clone tagged with the narrative name, so rate
+    /// limits and configuration reloads apply across runs. Without one, a
+    /// plain executor is created from the `discord_token` secret.
+    #[cfg(feature = "discord")]
+    pub fn with_discord_executor(mut self, executor: SecureDiscordExecutor) -> Self {
+        self.discord_executor = Some(executor);
+        self
+    }
+
+    /// Table query executor for a run.
+    ///
+    /// Raw SQL `where` filters are rejected unless the skill config sets
+    /// `allow_raw_filters = true`, which should only be done for narratives
+    /// written by the operator.
+    pub fn table_executor(context: &SkillContext) -> TableQueryExecutor<DatabaseConnectionManager> {
+        let allow_raw_filters = context
+            .config()
+            .get("allow_raw_filters")
+            .map(|s| s.parse::<bool>().unwrap_or(false))
+            .unwrap_or(false);
+
+        TableQueryExecutor::new(context.db_pool().clone()).with_raw_filters(allow_raw_filters)
+    }
 }
 
 impl Default for NarrativeExecutionSkill {
@@ -120,15 +158,22 @@ impl Skill for NarrativeExecutionSkill {
             let mut bot_registry = BotCommandRegistryImpl::new();
 
             // Always register database executor
-            let database_executor = DatabaseCommandExecutor::new();
+            let database_executor = DatabaseCommandExecutor::new(context.db_pool().clone());
             bot_registry.register(database_executor);
             tracing::debug!("Database command executor registered");
 
-            // Register Discord executor if token is available
-            if let Ok(token) = std::env::var("DISCORD_TOKEN") {
+            // Register the secure Discord executor, or a plain one if a token is available
+            if let Some(secure_executor) = &self.discord_executor {
+                bot_registry.register(
+                    secure_executor
+                        .clone()
+                        .with_narrative_id(narrative_source.name()),
+                );
+                tracing::debug!("Secure Discord bot executor registered");
+            } else if let Ok(token) = botticelli_secrets::get_secret("discord_token") {
                 use botticelli_social::DiscordCommandExecutor;
                 tracing::debug!("Configuring Discord bot executor");
-                let discord_executor = DiscordCommandExecutor::new(token);
+                let discord_executor = DiscordCommandExecutor::new(token.into_inner());
                 bot_registry.register(discord_executor);
                 tracing::debug!("Discord bot executor registered");
             } else {
@@ -144,21 +189,13 @@ impl Skill for NarrativeExecutionSkill {
         // Create table query registry for database table access
         tracing::debug!("Creating table query registry");
 
-        // Establish a standalone connection for table queries
-        // TODO: Refactor TableQueryExecutor to use connection pool
-        let conn = establish_connection().map_err(|e| {
-            ActorError::new(ActorErrorKind::DatabaseFailed(format!(
-                "Failed to establish database connection for table queries: {}",
-                e
-            )))
-        })?;
-
-        let table_executor = TableQueryExecutor::new(Arc::new(Mutex::new(conn)));
+        let table_executor = Self::table_executor(context);
         let table_registry = DatabaseTableQueryRegistry::new(table_executor);
 
         // Create executor with the client, processors, table registry, and bot registry
         let mut executor = NarrativeExecutor::with_processors(client, registry)
-            .with_table_registry(Box::new(table_registry));
+            .with_table_registry(Box::new(table_registry))
+            .with_variables(context.variables().clone());
         tracing::debug!("Table query registry configured");
 
         if let Some(bot_reg) = bot_registry {
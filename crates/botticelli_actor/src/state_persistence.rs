@@ -2,13 +2,13 @@
 
 use async_trait::async_trait;
 use botticelli_database::{
-    ActorServerExecutionRow, ActorServerStateRow, NewActorServerExecutionBuilder,
-    NewActorServerStateBuilder,
+    ActorServerExecutionRow, ActorServerStateRow, ActorStateChange, ActorStateFilter, BackendKind,
+    DatabaseConnection, DatabaseConnectionManager, DatabasePool, DatabaseResult,
+    with_pooled_connection,
 };
 use botticelli_server::{ActorServerResult, StatePersistence};
 use chrono::{NaiveDateTime, Utc};
-use diesel::prelude::*;
-use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::r2d2::Pool;
 use tracing::{debug, info, instrument};
 
 /// Database execution result for logging.
@@ -24,16 +24,16 @@ pub struct DatabaseExecutionResult {
     pub metadata: serde_json::Value,
 }
 
-/// Database-backed state persistence using PostgreSQL with connection pooling.
+/// Database-backed state persistence with connection pooling.
 ///
 /// Stores actor server state in the `actor_server_state` table for
-/// recovery after server restarts. Uses r2d2 connection pooling for
-/// efficient concurrent database access.
+/// recovery after server restarts. Works on PostgreSQL and, with the
+/// `sqlite` feature, on a SQLite file holding the content the actors post.
 ///
 /// Note: Requires DATABASE_URL environment variable to be set.
 #[derive(Debug, Clone)]
 pub struct DatabaseStatePersistence {
-    pool: Pool<ConnectionManager<PgConnection>>,
+    pool: DatabasePool,
 }
 
 impl DatabaseStatePersistence {
@@ -73,13 +73,14 @@ impl DatabaseStatePersistence {
     ///     .expect("Failed to create persistence");
     /// ```
     pub fn with_pool_size(pool_size: u32) -> ActorServerResult<Self> {
-        let database_url = std::env::var("DATABASE_URL").map_err(
-            |_| -> Box<dyn std::error::Error + Send + Sync> {
-                "DATABASE_URL environment variable not set".into()
-            },
-        )?;
+        let database_url = botticelli_database::database_url()?;
 
-        let manager = ConnectionManager::<PgConnection>::new(database_url);
+        // Pooled SQLite connections skip migrations, so migrate up front
+        if BackendKind::from_url(&database_url) == BackendKind::Sqlite {
+            DatabaseConnection::establish(&database_url)?;
+        }
+
+        let manager = DatabaseConnectionManager::new(database_url);
         let pool = Pool::builder().max_size(pool_size).build(manager).map_err(
             |e| -> Box<dyn std::error::Error + Send + Sync> {
                 format!("Failed to create connection pool: {}", e).into()
@@ -97,6 +98,67 @@ impl DatabaseStatePersistence {
 
         Ok(Self { pool })
     }
+
+    /// Connection pool shared with other database-backed helpers.
+    pub(crate) fn pool(&self) -> &DatabasePool {
+        &self.pool
+    }
+
+    /// Run a blocking query on a pooled connection, prefixing errors with
+    /// what failed.
+    async fn run<T, F>(&self, failure: &'static str, query: F) -> ActorServerResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut DatabaseConnection) -> DatabaseResult<T> + Send + 'static,
+    {
+        with_pooled_connection(&self.pool, move |conn| {
+            query(conn).map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
+                format!("{}: {}", failure, e).into()
+            })
+        })
+        .await
+    }
+
+    async fn list_states(
+        &self,
+        failure: &'static str,
+        filter: ActorStateFilter<'static>,
+    ) -> ActorServerResult<Vec<ActorServerStateRow>> {
+        self.run(failure, move |conn| {
+            botticelli_database::list_actor_states(conn, filter)
+        })
+        .await
+    }
+
+    async fn load_task(
+        &self,
+        failure: &'static str,
+        task_id: &str,
+    ) -> ActorServerResult<Option<ActorServerStateRow>> {
+        let task_id = task_id.to_string();
+        self.run(failure, move |conn| {
+            Ok(
+                botticelli_database::list_actor_states(conn, ActorStateFilter::Task(&task_id))?
+                    .into_iter()
+                    .next(),
+            )
+        })
+        .await
+    }
+
+    async fn update_task(
+        &self,
+        failure: &'static str,
+        task_id: &str,
+        change: ActorStateChange,
+    ) -> ActorServerResult<()> {
+        let task_id = task_id.to_string();
+        self.run(failure, move |conn| {
+            botticelli_database::update_actor_state(conn, &task_id, change)
+        })
+        .await?;
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -107,121 +169,41 @@ impl StatePersistence for DatabaseStatePersistence {
     async fn save_state(&self, state: &Self::State) -> ActorServerResult<()> {
         debug!("Saving actor server state to database");
 
-        // Run blocking database operation in dedicated thread pool
         let state = state.clone();
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<()> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            // Use INSERT ... ON CONFLICT to upsert state
-            diesel::insert_into(botticelli_database::schema::actor_server_state::table)
-                .values(
-                    &NewActorServerStateBuilder::default()
-                        .task_id(&state.task_id)
-                        .actor_name(&state.actor_name)
-                        .last_run(state.last_run)
-                        .next_run(state.next_run)
-                        .consecutive_failures(state.consecutive_failures.unwrap_or(0))
-                        .is_paused(state.is_paused.unwrap_or(false))
-                        .metadata(state.metadata.clone().unwrap_or_default())
-                        .build()
-                        .expect("NewActorServerState with valid fields"),
-                )
-                .on_conflict(botticelli_database::schema::actor_server_state::task_id)
-                .do_update()
-                .set((
-                    botticelli_database::schema::actor_server_state::last_run.eq(&state.last_run),
-                    botticelli_database::schema::actor_server_state::next_run.eq(&state.next_run),
-                    botticelli_database::schema::actor_server_state::consecutive_failures
-                        .eq(&state.consecutive_failures),
-                    botticelli_database::schema::actor_server_state::is_paused.eq(&state.is_paused),
-                    botticelli_database::schema::actor_server_state::metadata.eq(&state.metadata),
-                    botticelli_database::schema::actor_server_state::updated_at
-                        .eq(diesel::dsl::now),
-                ))
-                .execute(&mut conn)
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to save state: {}", e).into()
-                })?;
-
-            info!("Actor server state saved to database");
-            Ok(())
+        self.run("Failed to save state", move |conn| {
+            botticelli_database::save_actor_state(conn, &state)
         })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        .await?;
+
+        info!("Actor server state saved to database");
+        Ok(())
     }
 
     #[instrument(skip(self))]
     async fn load_state(&self) -> ActorServerResult<Option<Self::State>> {
         debug!("Loading actor server state from database");
 
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<Option<ActorServerStateRow>> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            // Load all state rows (for now, just get the first one)
-            let states = botticelli_database::schema::actor_server_state::table
-                .load::<ActorServerStateRow>(&mut conn)
-                .optional()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to load state: {}", e).into()
-                })?;
-
-            if let Some(states_vec) = states {
-                if !states_vec.is_empty() {
-                    info!(count = states_vec.len(), "Loaded actor server states");
-                    Ok(Some(states_vec.into_iter().next().unwrap()))
-                } else {
-                    Ok(None)
-                }
-            } else {
-                Ok(None)
-            }
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        // Load all state rows (for now, just get the first one)
+        let states = self
+            .list_states("Failed to load state", ActorStateFilter::All)
+            .await?;
+        if !states.is_empty() {
+            info!(count = states.len(), "Loaded actor server states");
+        }
+        Ok(states.into_iter().next())
     }
 
     #[instrument(skip(self))]
     async fn clear_state(&self) -> ActorServerResult<()> {
         debug!("Clearing all actor server state from database");
 
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<()> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            diesel::delete(botticelli_database::schema::actor_server_state::table)
-                .execute(&mut conn)
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to clear state: {}", e).into()
-                })?;
-
-            info!("Cleared all actor server state");
-            Ok(())
+        self.run("Failed to clear state", |conn| {
+            botticelli_database::delete_actor_states(conn, None)
         })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        .await?;
+
+        info!("Cleared all actor server state");
+        Ok(())
     }
 }
 
@@ -235,55 +217,15 @@ impl DatabaseStatePersistence {
     ) -> ActorServerResult<()> {
         debug!(task_id, "Saving task state to database");
 
-        let state = state.clone();
-        let task_id = task_id.to_string();
-
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<()> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            diesel::insert_into(botticelli_database::schema::actor_server_state::table)
-                .values(
-                    &NewActorServerStateBuilder::default()
-                        .task_id(&task_id)
-                        .actor_name(&state.actor_name)
-                        .last_run(state.last_run)
-                        .next_run(state.next_run)
-                        .consecutive_failures(state.consecutive_failures.unwrap_or(0))
-                        .is_paused(state.is_paused.unwrap_or(false))
-                        .metadata(state.metadata.clone().unwrap_or_default())
-                        .build()
-                        .expect("NewActorServerState with valid fields"),
-                )
-                .on_conflict(botticelli_database::schema::actor_server_state::task_id)
-                .do_update()
-                .set((
-                    botticelli_database::schema::actor_server_state::last_run.eq(&state.last_run),
-                    botticelli_database::schema::actor_server_state::next_run.eq(&state.next_run),
-                    botticelli_database::schema::actor_server_state::consecutive_failures
-                        .eq(&state.consecutive_failures),
-                    botticelli_database::schema::actor_server_state::is_paused.eq(&state.is_paused),
-                    botticelli_database::schema::actor_server_state::metadata.eq(&state.metadata),
-                    botticelli_database::schema::actor_server_state::updated_at
-                        .eq(diesel::dsl::now),
-                ))
-                .execute(&mut conn)
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to save task state: {}", e).into()
-                })?;
-
-            info!(task_id, "Task state saved to database");
-            Ok(())
+        let mut state = state.clone();
+        state.task_id = task_id.to_string();
+        self.run("Failed to save task state", move |conn| {
+            botticelli_database::save_actor_state(conn, &state)
         })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        .await?;
+
+        info!(task_id, "Task state saved to database");
+        Ok(())
     }
 
     /// Load state for a specific task.
@@ -294,37 +236,14 @@ impl DatabaseStatePersistence {
     ) -> ActorServerResult<Option<ActorServerStateRow>> {
         debug!(task_id, "Loading task state from database");
 
-        let task_id = task_id.to_string();
-
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<Option<ActorServerStateRow>> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            let state = botticelli_database::schema::actor_server_state::table
-                .filter(botticelli_database::schema::actor_server_state::task_id.eq(&task_id))
-                .first::<ActorServerStateRow>(&mut conn)
-                .optional()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to load task state: {}", e).into()
-                })?;
-
-            if state.is_some() {
-                info!(task_id, "Task state loaded from database");
-            } else {
-                debug!(task_id, "No state found for task");
-            }
+        let state = self.load_task("Failed to load task state", task_id).await?;
+        if state.is_some() {
+            info!(task_id, "Task state loaded from database");
+        } else {
+            debug!(task_id, "No state found for task");
+        }
 
-            Ok(state)
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        Ok(state)
     }
 
     /// Delete state for a specific task.
@@ -332,33 +251,14 @@ impl DatabaseStatePersistence {
     pub async fn delete_task_state(&self, task_id: &str) -> ActorServerResult<()> {
         debug!(task_id, "Deleting task state from database");
 
-        let task_id = task_id.to_string();
-
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<()> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            diesel::delete(
-                botticelli_database::schema::actor_server_state::table
-                    .filter(botticelli_database::schema::actor_server_state::task_id.eq(&task_id)),
-            )
-            .execute(&mut conn)
-            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                format!("Failed to delete task state: {}", e).into()
-            })?;
-
-            info!(task_id, "Task state deleted from database");
-            Ok(())
+        let task = task_id.to_string();
+        self.run("Failed to delete task state", move |conn| {
+            botticelli_database::delete_actor_states(conn, Some(&task))
         })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        .await?;
+
+        info!(task_id, "Task state deleted from database");
+        Ok(())
     }
 
     /// List all tasks in the database.
@@ -366,28 +266,12 @@ impl DatabaseStatePersistence {
     pub async fn list_all_tasks(&self) -> ActorServerResult<Vec<ActorServerStateRow>> {
         debug!("Listing all tasks from database");
 
-        let pool = self.pool.clone();
+        let tasks = self
+            .list_states("Failed to list tasks", ActorStateFilter::All)
+            .await?;
 
-        tokio::task::spawn_blocking(move || -> ActorServerResult<Vec<ActorServerStateRow>> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            let tasks = botticelli_database::schema::actor_server_state::table
-                .load::<ActorServerStateRow>(&mut conn)
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to list tasks: {}", e).into()
-                })?;
-
-            info!(count = tasks.len(), "Listed all tasks from database");
-            Ok(tasks)
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        info!(count = tasks.len(), "Listed all tasks from database");
+        Ok(tasks)
     }
 
     /// List all tasks for a specific actor.
@@ -398,31 +282,15 @@ impl DatabaseStatePersistence {
     ) -> ActorServerResult<Vec<ActorServerStateRow>> {
         debug!(actor_name, "Listing tasks for actor");
 
-        let actor_name = actor_name.to_string();
-
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<Vec<ActorServerStateRow>> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            let tasks = botticelli_database::schema::actor_server_state::table
-                .filter(botticelli_database::schema::actor_server_state::actor_name.eq(&actor_name))
-                .load::<ActorServerStateRow>(&mut conn)
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to list tasks by actor: {}", e).into()
-                })?;
+        let actor = actor_name.to_string();
+        let tasks = self
+            .run("Failed to list tasks by actor", move |conn| {
+                botticelli_database::list_actor_states(conn, ActorStateFilter::Actor(&actor))
+            })
+            .await?;
 
-            info!(actor_name, count = tasks.len(), "Listed tasks for actor");
-            Ok(tasks)
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        info!(actor_name, count = tasks.len(), "Listed tasks for actor");
+        Ok(tasks)
     }
 
     /// List all active (non-paused) tasks.
@@ -430,33 +298,12 @@ impl DatabaseStatePersistence {
     pub async fn list_active_tasks(&self) -> ActorServerResult<Vec<ActorServerStateRow>> {
         debug!("Listing active tasks");
 
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<Vec<ActorServerStateRow>> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            let tasks = botticelli_database::schema::actor_server_state::table
-                .filter(
-                    botticelli_database::schema::actor_server_state::is_paused
-                        .eq(false)
-                        .or(botticelli_database::schema::actor_server_state::is_paused.is_null()),
-                )
-                .load::<ActorServerStateRow>(&mut conn)
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to list active tasks: {}", e).into()
-                })?;
+        let tasks = self
+            .list_states("Failed to list active tasks", ActorStateFilter::Active)
+            .await?;
 
-            info!(count = tasks.len(), "Listed active tasks");
-            Ok(tasks)
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        info!(count = tasks.len(), "Listed active tasks");
+        Ok(tasks)
     }
 
     /// List all paused tasks.
@@ -464,29 +311,12 @@ impl DatabaseStatePersistence {
     pub async fn list_paused_tasks(&self) -> ActorServerResult<Vec<ActorServerStateRow>> {
         debug!("Listing paused tasks");
 
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<Vec<ActorServerStateRow>> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            let tasks = botticelli_database::schema::actor_server_state::table
-                .filter(botticelli_database::schema::actor_server_state::is_paused.eq(true))
-                .load::<ActorServerStateRow>(&mut conn)
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to list paused tasks: {}", e).into()
-                })?;
+        let tasks = self
+            .list_states("Failed to list paused tasks", ActorStateFilter::Paused)
+            .await?;
 
-            info!(count = tasks.len(), "Listed paused tasks");
-            Ok(tasks)
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        info!(count = tasks.len(), "Listed paused tasks");
+        Ok(tasks)
     }
 
     /// Pause a specific task.
@@ -494,37 +324,15 @@ impl DatabaseStatePersistence {
     pub async fn pause_task(&self, task_id: &str) -> ActorServerResult<()> {
         debug!(task_id, "Pausing task");
 
-        let task_id = task_id.to_string();
+        self.update_task(
+            "Failed to pause task",
+            task_id,
+            ActorStateChange::Paused(true),
+        )
+        .await?;
 
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<()> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            diesel::update(
-                botticelli_database::schema::actor_server_state::table
-                    .filter(botticelli_database::schema::actor_server_state::task_id.eq(&task_id)),
-            )
-            .set((
-                botticelli_database::schema::actor_server_state::is_paused.eq(true),
-                botticelli_database::schema::actor_server_state::updated_at.eq(diesel::dsl::now),
-            ))
-            .execute(&mut conn)
-            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                format!("Failed to pause task: {}", e).into()
-            })?;
-
-            info!(task_id, "Task paused");
-            Ok(())
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        info!(task_id, "Task paused");
+        Ok(())
     }
 
     /// Resume a specific task.
@@ -532,37 +340,15 @@ impl DatabaseStatePersistence {
     pub async fn resume_task(&self, task_id: &str) -> ActorServerResult<()> {
         debug!(task_id, "Resuming task");
 
-        let task_id = task_id.to_string();
+        self.update_task(
+            "Failed to resume task",
+            task_id,
+            ActorStateChange::Paused(false),
+        )
+        .await?;
 
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<()> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            diesel::update(
-                botticelli_database::schema::actor_server_state::table
-                    .filter(botticelli_database::schema::actor_server_state::task_id.eq(&task_id)),
-            )
-            .set((
-                botticelli_database::schema::actor_server_state::is_paused.eq(false),
-                botticelli_database::schema::actor_server_state::updated_at.eq(diesel::dsl::now),
-            ))
-            .execute(&mut conn)
-            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                format!("Failed to resume task: {}", e).into()
-            })?;
-
-            info!(task_id, "Task resumed");
-            Ok(())
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        info!(task_id, "Task resumed");
+        Ok(())
     }
 
     /// Update the next run time for a specific task.
@@ -574,37 +360,39 @@ impl DatabaseStatePersistence {
     ) -> ActorServerResult<()> {
         debug!(task_id, next_run = %next_run, "Updating next run time");
 
-        let task_id = task_id.to_string();
+        self.update_task(
+            "Failed to update next run time",
+            task_id,
+            ActorStateChange::NextRun(next_run),
+        )
+        .await?;
 
-        let pool = self.pool.clone();
+        info!(task_id, next_run = %next_run, "Next run time updated");
+        Ok(())
+    }
 
-        tokio::task::spawn_blocking(move || -> ActorServerResult<()> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
+    /// Record the last successful run of a task.
+    ///
+    /// Creates the task state if the task has no state yet, so a replica
+    /// taking over the task sees when it last ran.
+    #[instrument(skip(self), fields(task_id, last_run = %last_run))]
+    pub async fn update_last_run(
+        &self,
+        task_id: &str,
+        actor_name: &str,
+        last_run: NaiveDateTime,
+    ) -> ActorServerResult<()> {
+        debug!(task_id, last_run = %last_run, "Updating last run time");
 
-            diesel::update(
-                botticelli_database::schema::actor_server_state::table
-                    .filter(botticelli_database::schema::actor_server_state::task_id.eq(&task_id)),
-            )
-            .set((
-                botticelli_database::schema::actor_server_state::next_run.eq(&next_run),
-                botticelli_database::schema::actor_server_state::updated_at.eq(diesel::dsl::now),
-            ))
-            .execute(&mut conn)
-            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                format!("Failed to update next run time: {}", e).into()
-            })?;
-
-            info!(task_id, next_run = %next_run, "Next run time updated");
-            Ok(())
+        let task = task_id.to_string();
+        let actor_name = actor_name.to_string();
+        self.run("Failed to update last run time", move |conn| {
+            botticelli_database::record_actor_last_run(conn, &task, &actor_name, last_run)
         })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        .await?;
+
+        info!(task_id, last_run = %last_run, "Last run time updated");
+        Ok(())
     }
 
     /// Start a new execution and return the execution ID.
@@ -612,51 +400,16 @@ impl DatabaseStatePersistence {
     pub async fn start_execution(&self, task_id: &str, actor_name: &str) -> ActorServerResult<i64> {
         debug!(task_id, actor_name, "Starting execution");
 
-        let task_id = task_id.to_string();
-        let actor_name = actor_name.to_string();
-
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<i64> {
-            use diesel::Connection;
+        let task = task_id.to_string();
+        let actor = actor_name.to_string();
+        let id = self
+            .run("Failed to start execution", move |conn| {
+                botticelli_database::start_actor_execution(conn, &task, &actor)
+            })
+            .await?;
 
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            let execution = NewActorServerExecutionBuilder::default()
-                .task_id(task_id.clone())
-                .actor_name(actor_name.clone())
-                .started_at(Utc::now().naive_utc())
-                .build()
-                .expect("NewActorServerExecution with valid fields");
-
-            // Use explicit transaction to ensure commit
-            let id =
-                conn.transaction::<i64, Box<dyn std::error::Error + Send + Sync>, _>(|conn| {
-                    let id = diesel::insert_into(
-                        botticelli_database::schema::actor_server_executions::table,
-                    )
-                    .values(&execution)
-                    .returning(botticelli_database::schema::actor_server_executions::id)
-                    .get_result::<i64>(conn)
-                    .map_err(
-                        |e| -> Box<dyn std::error::Error + Send + Sync> {
-                            format!("Failed to start execution: {}", e).into()
-                        },
-                    )?;
-                    Ok(id)
-                })?;
-
-            info!(task_id, actor_name, execution_id = id, "Execution started");
-            Ok(id)
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        info!(task_id, actor_name, execution_id = id, "Execution started");
+        Ok(id)
     }
 
     /// Complete an execution with a result.
@@ -668,44 +421,20 @@ impl DatabaseStatePersistence {
     ) -> ActorServerResult<()> {
         debug!(execution_id, "Completing execution");
 
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<()> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            diesel::update(
-                botticelli_database::schema::actor_server_executions::table.filter(
-                    botticelli_database::schema::actor_server_executions::id.eq(execution_id),
-                ),
+        self.run("Failed to complete execution", move |conn| {
+            botticelli_database::complete_actor_execution(
+                conn,
+                execution_id,
+                result.skills_succeeded,
+                result.skills_failed,
+                result.skills_skipped,
+                &result.metadata,
             )
-            .set((
-                botticelli_database::schema::actor_server_executions::completed_at
-                    .eq(Utc::now().naive_utc()),
-                botticelli_database::schema::actor_server_executions::success.eq(true),
-                botticelli_database::schema::actor_server_executions::skills_succeeded
-                    .eq(result.skills_succeeded),
-                botticelli_database::schema::actor_server_executions::skills_failed
-                    .eq(result.skills_failed),
-                botticelli_database::schema::actor_server_executions::skills_skipped
-                    .eq(result.skills_skipped),
-                botticelli_database::schema::actor_server_executions::metadata.eq(&result.metadata),
-            ))
-            .execute(&mut conn)
-            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                format!("Failed to complete execution: {}", e).into()
-            })?;
-
-            info!(execution_id, "Execution completed successfully");
-            Ok(())
         })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        .await?;
+
+        info!(execution_id, "Execution completed successfully");
+        Ok(())
     }
 
     /// Mark an execution as failed with an error message.
@@ -713,40 +442,14 @@ impl DatabaseStatePersistence {
     pub async fn fail_execution(&self, execution_id: i64, error: &str) -> ActorServerResult<()> {
         debug!(execution_id, error, "Failing execution");
 
-        let error = error.to_string();
-
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<()> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            diesel::update(
-                botticelli_database::schema::actor_server_executions::table.filter(
-                    botticelli_database::schema::actor_server_executions::id.eq(execution_id),
-                ),
-            )
-            .set((
-                botticelli_database::schema::actor_server_executions::completed_at
-                    .eq(Utc::now().naive_utc()),
-                botticelli_database::schema::actor_server_executions::success.eq(false),
-                botticelli_database::schema::actor_server_executions::error_message.eq(&error),
-            ))
-            .execute(&mut conn)
-            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                format!("Failed to fail execution: {}", e).into()
-            })?;
-
-            info!(execution_id, error, "Execution marked as failed");
-            Ok(())
+        let message = error.to_string();
+        self.run("Failed to fail execution", move |conn| {
+            botticelli_database::fail_actor_execution(conn, execution_id, &message)
         })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        .await?;
+
+        info!(execution_id, error, "Execution marked as failed");
+        Ok(())
     }
 
     /// Get execution history for a specific task.
@@ -758,37 +461,19 @@ impl DatabaseStatePersistence {
     ) -> ActorServerResult<Vec<ActorServerExecutionRow>> {
         debug!(task_id, limit, "Getting execution history");
 
-        let task_id = task_id.to_string();
-
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<Vec<ActorServerExecutionRow>> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            let executions = botticelli_database::schema::actor_server_executions::table
-                .filter(botticelli_database::schema::actor_server_executions::task_id.eq(&task_id))
-                .order(botticelli_database::schema::actor_server_executions::started_at.desc())
-                .limit(limit)
-                .load::<ActorServerExecutionRow>(&mut conn)
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get execution history: {}", e).into()
-                })?;
-
-            info!(
-                task_id,
-                count = executions.len(),
-                "Retrieved execution history"
-            );
-            Ok(executions)
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        let task = task_id.to_string();
+        let executions = self
+            .run("Failed to get execution history", move |conn| {
+                botticelli_database::list_actor_executions(conn, Some(&task), false, limit)
+            })
+            .await?;
+
+        info!(
+            task_id,
+            count = executions.len(),
+            "Retrieved execution history"
+        );
+        Ok(executions)
     }
 
     /// Get failed executions for a specific task.
@@ -800,38 +485,19 @@ impl DatabaseStatePersistence {
     ) -> ActorServerResult<Vec<ActorServerExecutionRow>> {
         debug!(task_id, limit, "Getting failed executions");
 
-        let task_id = task_id.to_string();
-
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<Vec<ActorServerExecutionRow>> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            let executions = botticelli_database::schema::actor_server_executions::table
-                .filter(botticelli_database::schema::actor_server_executions::task_id.eq(&task_id))
-                .filter(botticelli_database::schema::actor_server_executions::success.eq(false))
-                .order(botticelli_database::schema::actor_server_executions::started_at.desc())
-                .limit(limit)
-                .load::<ActorServerExecutionRow>(&mut conn)
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get failed executions: {}", e).into()
-                })?;
-
-            info!(
-                task_id,
-                count = executions.len(),
-                "Retrieved failed executions"
-            );
-            Ok(executions)
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        let task = task_id.to_string();
+        let executions = self
+            .run("Failed to get failed executions", move |conn| {
+                botticelli_database::list_actor_executions(conn, Some(&task), true, limit)
+            })
+            .await?;
+
+        info!(
+            task_id,
+            count = executions.len(),
+            "Retrieved failed executions"
+        );
+        Ok(executions)
     }
 
     /// Get recent executions across all tasks.
@@ -842,30 +508,14 @@ impl DatabaseStatePersistence {
     ) -> ActorServerResult<Vec<ActorServerExecutionRow>> {
         debug!(limit, "Getting recent executions");
 
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<Vec<ActorServerExecutionRow>> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            let executions = botticelli_database::schema::actor_server_executions::table
-                .order(botticelli_database::schema::actor_server_executions::started_at.desc())
-                .limit(limit)
-                .load::<ActorServerExecutionRow>(&mut conn)
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get recent executions: {}", e).into()
-                })?;
+        let executions = self
+            .run("Failed to get recent executions", move |conn| {
+                botticelli_database::list_actor_executions(conn, None, false, limit)
+            })
+            .await?;
 
-            info!(count = executions.len(), "Retrieved recent executions");
-            Ok(executions)
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        info!(count = executions.len(), "Retrieved recent executions");
+        Ok(executions)
     }
 
     /// Prune old executions older than the specified number of days.
@@ -875,35 +525,15 @@ impl DatabaseStatePersistence {
     pub async fn prune_old_executions(&self, older_than_days: i32) -> ActorServerResult<usize> {
         debug!(older_than_days, "Pruning old executions");
 
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<usize> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            let cutoff =
-                Utc::now().naive_utc() - chrono::Duration::days(i64::from(older_than_days));
-
-            let deleted = diesel::delete(
-                botticelli_database::schema::actor_server_executions::table.filter(
-                    botticelli_database::schema::actor_server_executions::started_at.lt(cutoff),
-                ),
-            )
-            .execute(&mut conn)
-            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                format!("Failed to prune old executions: {}", e).into()
-            })?;
+        let cutoff = Utc::now().naive_utc() - chrono::Duration::days(i64::from(older_than_days));
+        let deleted = self
+            .run("Failed to prune old executions", move |conn| {
+                botticelli_database::prune_actor_executions(conn, cutoff)
+            })
+            .await?;
 
-            info!(deleted, older_than_days, "Pruned old executions");
-            Ok(deleted)
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        info!(deleted, older_than_days, "Pruned old executions");
+        Ok(deleted)
     }
 
     /// Record a failure for a task and increment consecutive failure counter.
@@ -922,88 +552,37 @@ impl DatabaseStatePersistence {
     ) -> ActorServerResult<bool> {
         debug!(task_id, max_failures, "Recording task failure");
 
-        let task_id = task_id.to_string();
-
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<bool> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            // Get current failure count
-            let current_failures = botticelli_database::schema::actor_server_state::table
-                .filter(botticelli_database::schema::actor_server_state::task_id.eq(&task_id))
-                .select(botticelli_database::schema::actor_server_state::consecutive_failures)
-                .first::<Option<i32>>(&mut conn)
-                .optional()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get failure count: {}", e).into()
-                })?
-                .flatten()
-                .unwrap_or(0);
-
-            // Increment failure counter
-            diesel::update(
-                botticelli_database::schema::actor_server_state::table
-                    .filter(botticelli_database::schema::actor_server_state::task_id.eq(&task_id)),
+        self.update_task(
+            "Failed to record failure",
+            task_id,
+            ActorStateChange::IncrementFailures,
+        )
+        .await?;
+
+        // Check current failure count
+        let threshold_exceeded = self
+            .load_task("Failed to check failure count", task_id)
+            .await?
+            .and_then(|s| s.consecutive_failures)
+            .map(|count| count >= max_failures)
+            .unwrap_or(false);
+
+        if threshold_exceeded {
+            info!(
+                task_id,
+                max_failures, "Task failure threshold exceeded, pausing task"
+            );
+            self.update_task(
+                "Failed to pause task",
+                task_id,
+                ActorStateChange::Paused(true),
             )
-            .set((
-                botticelli_database::schema::actor_server_state::consecutive_failures
-                    .eq(current_failures + 1),
-                botticelli_database::schema::actor_server_state::updated_at.eq(diesel::dsl::now),
-            ))
-            .execute(&mut conn)
-            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                format!("Failed to record failure: {}", e).into()
-            })?;
-
-            // Check current failure count
-            let state = botticelli_database::schema::actor_server_state::table
-                .filter(botticelli_database::schema::actor_server_state::task_id.eq(&task_id))
-                .first::<ActorServerStateRow>(&mut conn)
-                .optional()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to check failure count: {}", e).into()
-                })?;
-
-            let threshold_exceeded = state
-                .and_then(|s| s.consecutive_failures)
-                .map(|count| count >= max_failures)
-                .unwrap_or(false);
-
-            if threshold_exceeded {
-                info!(
-                    task_id,
-                    max_failures, "Task failure threshold exceeded, pausing task"
-                );
-                // Pause the task
-                diesel::update(
-                    botticelli_database::schema::actor_server_state::table.filter(
-                        botticelli_database::schema::actor_server_state::task_id.eq(&task_id),
-                    ),
-                )
-                .set((
-                    botticelli_database::schema::actor_server_state::is_paused.eq(true),
-                    botticelli_database::schema::actor_server_state::updated_at
-                        .eq(diesel::dsl::now),
-                ))
-                .execute(&mut conn)
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to pause task: {}", e).into()
-                })?;
-            } else {
-                debug!(task_id, "Task failure recorded");
-            }
+            .await?;
+        } else {
+            debug!(task_id, "Task failure recorded");
+        }
 
-            Ok(threshold_exceeded)
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        Ok(threshold_exceeded)
     }
 
     /// Record a success for a task and reset consecutive failure counter.
@@ -1011,37 +590,15 @@ impl DatabaseStatePersistence {
     pub async fn record_success(&self, task_id: &str) -> ActorServerResult<()> {
         debug!(task_id, "Recording task success");
 
-        let task_id = task_id.to_string();
-
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<()> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
+        self.update_task(
+            "Failed to record success",
+            task_id,
+            ActorStateChange::ResetFailures,
+        )
+        .await?;
 
-            diesel::update(
-                botticelli_database::schema::actor_server_state::table
-                    .filter(botticelli_database::schema::actor_server_state::task_id.eq(&task_id)),
-            )
-            .set((
-                botticelli_database::schema::actor_server_state::consecutive_failures.eq(0),
-                botticelli_database::schema::actor_server_state::updated_at.eq(diesel::dsl::now),
-            ))
-            .execute(&mut conn)
-            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                format!("Failed to record success: {}", e).into()
-            })?;
-
-            info!(task_id, "Task success recorded, failure counter reset");
-            Ok(())
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        info!(task_id, "Task success recorded, failure counter reset");
+        Ok(())
     }
 
     /// Check if a task should execute based on pause state.
@@ -1051,41 +608,19 @@ impl DatabaseStatePersistence {
     pub async fn should_execute(&self, task_id: &str) -> ActorServerResult<bool> {
         debug!(task_id, "Checking if task should execute");
 
-        let task_id = task_id.to_string();
-
-        let pool = self.pool.clone();
-
-        tokio::task::spawn_blocking(move || -> ActorServerResult<bool> {
-            let mut conn = pool
-                .get()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to get connection from pool: {}", e).into()
-                })?;
-
-            let state = botticelli_database::schema::actor_server_state::table
-                .filter(botticelli_database::schema::actor_server_state::task_id.eq(&task_id))
-                .first::<ActorServerStateRow>(&mut conn)
-                .optional()
-                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                    format!("Failed to check task state: {}", e).into()
-                })?;
-
-            let should_run = state
-                .and_then(|s| s.is_paused)
-                .map(|paused| !paused)
-                .unwrap_or(true);
-
-            if should_run {
-                debug!(task_id, "Task should execute");
-            } else {
-                debug!(task_id, "Task is paused, skipping execution");
-            }
+        let should_run = self
+            .load_task("Failed to check task state", task_id)
+            .await?
+            .and_then(|s| s.is_paused)
+            .map(|paused| !paused)
+            .unwrap_or(true);
+
+        if should_run {
+            debug!(task_id, "Task should execute");
+        } else {
+            debug!(task_id, "Task is paused, skipping execution");
+        }
 
-            Ok(should_run)
-        })
-        .await
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-            format!("Task join error: {}", e).into()
-        })?
+        Ok(should_run)
     }
 }
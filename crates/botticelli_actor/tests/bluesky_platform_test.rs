@@ -0,0 +1,142 @@
+//! Tests for the Bluesky platform against a local mock XRPC server.
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use botticelli_actor::{
+    ActorErrorKind, BlueskyPlatform, Platform, PlatformCapability, PlatformMessage,
+};
+use serde_json::{Value, json};
+use std::sync::{Arc, Mutex};
+
+const DID: &str = "did:plc:bot";
+
+type Shared = Arc<Mutex<Vec<Value>>>;
+
+async fn create_session(Json(body): Json<Value>) -> Result<Json<Value>, StatusCode> {
+    if body["password"] != "app-password" {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+    Ok(Json(json!({
+        "accessJwt": "access",
+        "refreshJwt": "refresh",
+        "did": DID,
+        "handle": "bot.test",
+    })))
+}
+
+async fn create_record(State(state): State<Shared>, Json(body): Json<Value>) -> Json<Value> {
+    let mut records = state.lock().unwrap();
+    records.push(body["record"].clone());
+    let n = records.len();
+    Json(json!({
+        "uri": format!("at://{DID}/app.bsky.feed.post/{n}"),
+        "cid": format!("cid{n}"),
+    }))
+}
+
+async fn upload_blob() -> Json<Value> {
+    Json(json!({ "blob": { "$type": "blob", "ref": { "$link": "bafy" }, "size": 8 } }))
+}
+
+/// Start a mock PDS, returning its base URL and created records.
+async fn mock_server() -> (String, Shared) {
+    let state = Shared::default();
+    let app = Router::new()
+        .route(
+            "/xrpc/com.atproto.server.createSession",
+            post(create_session),
+        )
+        .route("/xrpc/com.atproto.repo.createRecord", post(create_record))
+        .route("/xrpc/com.atproto.repo.uploadBlob", post(upload_blob))
+        .route("/images/cat.png", get(|| async { vec![0u8; 8] }))
+        .with_state(state.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    (format!("http://{addr}"), state)
+}
+
+fn message(text: &str, media_urls: Vec<String>) -> PlatformMessage {
+    PlatformMessage {
+        text: text.to_string(),
+        media_urls,
+        content_id: None,
+    }
+}
+
+#[test]
+fn test_capabilities() {
+    let platform = BlueskyPlatform::new("https://bsky.social", "bot.test", "pw").unwrap();
+    assert_eq!(platform.platform_name(), "bluesky");
+    assert!(
+        platform
+            .capabilities()
+            .contains(&PlatformCapability::Threads)
+    );
+    assert!(
+        !platform
+            .capabilities()
+            .contains(&PlatformCapability::Videos)
+    );
+
+    let err = BlueskyPlatform::new("bsky.social", "bot.test", "pw").unwrap_err();
+    assert!(matches!(err.kind, ActorErrorKind::InvalidConfiguration(_)));
+}
+
+#[tokio::test]
+async fn test_post_with_image() {
+    let (service, records) = mock_server().await;
+    let platform = BlueskyPlatform::new(&service, "bot.test", "app-password").unwrap();
+
+    let metadata = platform
+        .post(&message(
+            "Hello sky",
+            vec![format!("{service}/images/cat.png")],
+        ))
+        .await
+        .expect("post should succeed");
+
+    assert_eq!(metadata["uri"], format!("at://{DID}/app.bsky.feed.post/1"));
+    assert_eq!(metadata["cid"], "cid1");
+
+    let records = records.lock().unwrap();
+    assert_eq!(records[0]["text"], "Hello sky");
+    assert_eq!(records[0]["embed"]["images"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_long_post_becomes_reply_chain() {
+    let (service, records) = mock_server().await;
+    let platform = BlueskyPlatform::new(&service, "bot.test", "app-password").unwrap();
+
+    let text = "word ".repeat(100);
+    let metadata = platform.post(&message(&text, vec![])).await.unwrap();
+
+    let records = records.lock().unwrap();
+    assert!(records.len() > 1);
+    assert_eq!(metadata["thread_uris"].split(',').count(), records.len());
+    assert!(records[0].get("reply").is_none());
+    for (index, record) in records.iter().enumerate().skip(1) {
+        assert_eq!(record["reply"]["root"]["cid"], "cid1");
+        assert_eq!(record["reply"]["parent"]["cid"], format!("cid{index}"));
+        assert!(record["text"].as_str().unwrap().chars().count() <= 300);
+    }
+}
+
+#[tokio::test]
+async fn test_rejects_too_many_images_and_bad_login() {
+    let (service, _) = mock_server().await;
+
+    let platform = BlueskyPlatform::new(&service, "bot.test", "app-password").unwrap();
+    let media = (0..5).map(|i| format!("{service}/{i}.png")).collect();
+    let err = platform.post(&message("hi", media)).await.unwrap_err();
+    assert!(matches!(err.kind, ActorErrorKind::ValidationFailed(_)));
+
+    let bad = BlueskyPlatform::new(&service, "bot.test", "wrong").unwrap();
+    let err = bad.verify_connection().await.unwrap_err();
+    assert!(matches!(err.kind, ActorErrorKind::AuthenticationFailed(_)));
+}
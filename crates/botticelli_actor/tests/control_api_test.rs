@@ -0,0 +1,175 @@
+//! Tests for the actor server control API and client.
+
+use botticelli_actor::{
+    ActorError, ActorErrorKind, ActorStatusBuilder, CircuitStateBuilder, ControlClient,
+    ControlHandle, ControlRequest, ExecutionRecord, ScheduleConfig, TriggerOutcome,
+    create_control_router,
+};
+use botticelli_database::ActorServerExecutionRow;
+use chrono::Utc;
+use std::collections::HashSet;
+use tokio::sync::mpsc;
+
+const TOKEN: &str = "test-control-token";
+
+/// Answer control requests for a single actor named `poster`, the way the
+/// server loop would.
+async fn serve_requests(mut requests: mpsc::Receiver<ControlRequest>) {
+    let mut paused = HashSet::new();
+    let not_found = |actor: &str| ActorError::new(ActorErrorKind::ActorNotFound(actor.to_string()));
+
+    while let Some(request) = requests.recv().await {
+        match request {
+            ControlRequest::ListActors { reply } => {
+                let status = ActorStatusBuilder::default()
+                    .name("poster")
+                    .schedule(ScheduleConfig::Interval { seconds: 60 })
+                    .build()
+                    .expect("Valid status");
+                let _ = reply.send(Ok(vec![status]));
+            }
+            ControlRequest::Pause { actor, reply } => {
+                let result = if actor == "poster" {
+                    paused.insert(actor);
+                    Ok(())
+                } else {
+                    Err(not_found(&actor))
+                };
+                let _ = reply.send(result);
+            }
+            ControlRequest::Resume { actor, reply } => {
+                paused.remove(&actor);
+                let _ = reply.send(Ok(()));
+            }
+            ControlRequest::Trigger { actor, reply } => {
+                let _ = reply.send(Ok(TriggerOutcome::new(actor, true)));
+            }
+            ControlRequest::History {
+                actor,
+                limit,
+                reply,
+            } => {
+                let rows = (1..=limit)
+                    .map(|id| ActorServerExecutionRow {
+                        id,
+                        task_id: actor.clone(),
+                        actor_name: actor.clone(),
+                        started_at: Utc::now().naive_utc(),
+                        completed_at: None,
+                        success: Some(true),
+                        error_message: None,
+                        skills_succeeded: Some(1),
+                        skills_failed: Some(0),
+                        skills_skipped: Some(0),
+                        metadata: None,
+                        created_at: Utc::now().naive_utc(),
+                    })
+                    .map(ExecutionRecord::from)
+                    .collect();
+                let _ = reply.send(Ok(rows));
+            }
+            ControlRequest::Circuit { actor, reply } => {
+                let circuit = CircuitStateBuilder::default()
+                    .paused(paused.contains(&actor))
+                    .consecutive_failures(2)
+                    .max_consecutive_failures(5)
+                    .build()
+                    .expect("Valid circuit state");
+                let _ = reply.send(Ok(circuit));
+            }
+            ControlRequest::Reload { reply, .. } => {
+                let _ = reply.send(Err(ActorError::new(ActorErrorKind::InvalidConfiguration(
+                    "missing config_file".to_string(),
+                ))));
+            }
+        }
+    }
+}
+
+/// Start the control API on a random port and return its URL.
+async fn start_api() -> String {
+    let (handle, requests) = ControlHandle::channel(8);
+    tokio::spawn(serve_requests(requests));
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Bind listener");
+    let addr = listener.local_addr().expect("Local address");
+    tokio::spawn(async move {
+        axum::serve(listener, create_control_router(handle, TOKEN))
+            .await
+            .expect("Serve control API");
+    });
+
+    format!("http://{}", addr)
+}
+
+#[tokio::test]
+async fn test_client_round_trip() {
+    let client = ControlClient::new(start_api().await, TOKEN);
+
+    let actors = client.list_actors().await.expect("List actors");
+    assert_eq!(actors.len(), 1);
+    assert_eq!(actors[0].name(), "poster");
+
+    client.pause("poster").await.expect("Pause");
+    let circuit = client.circuit("poster").await.expect("Circuit");
+    assert!(circuit.paused());
+    assert_eq!(*circuit.consecutive_failures(), 2);
+
+    client.resume("poster").await.expect("Resume");
+    assert!(!client.circuit("poster").await.expect("Circuit").paused());
+
+    let outcome = client.trigger("poster").await.expect("Trigger");
+    assert_eq!(outcome, TriggerOutcome::new("poster", true));
+
+    let history = client.history("poster", 3).await.expect("History");
+    assert_eq!(history.len(), 3);
+    assert_eq!(*history[0].success(), Some(true));
+}
+
+#[tokio::test]
+async fn test_requests_require_token() {
+    let url = start_api().await;
+
+    let error = ControlClient::new(&url, "wrong-token")
+        .list_actors()
+        .await
+        .expect_err("Wrong token");
+    assert!(matches!(
+        error.kind,
+        ActorErrorKind::AuthenticationFailed(_)
+    ));
+
+    let response = reqwest::get(format!("{}/actors", url))
+        .await
+        .expect("Request");
+    assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    let health = reqwest::get(format!("{}/health", url))
+        .await
+        .expect("Request");
+    assert!(health.status().is_success());
+}
+
+#[tokio::test]
+async fn test_errors_map_to_kinds() {
+    let client = ControlClient::new(start_api().await, TOKEN);
+
+    let error = client.pause("missing").await.expect_err("Unknown actor");
+    assert!(matches!(error.kind, ActorErrorKind::ActorNotFound(_)));
+
+    let error = client.reload("poster").await.expect_err("Bad config");
+    assert!(
+        matches!(&error.kind, ActorErrorKind::InvalidConfiguration(msg) if msg.contains("missing config_file"))
+    );
+}
+
+#[tokio::test]
+async fn test_handle_reports_stopped_server() {
+    let (handle, requests) = ControlHandle::channel(1);
+    drop(requests);
+
+    let error = handle.list_actors().await.expect_err("Server gone");
+    assert!(matches!(error.kind, ActorErrorKind::ResourceUnavailable(_)));
+}
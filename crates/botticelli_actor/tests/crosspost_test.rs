@@ -0,0 +1,473 @@
+//! Tests for cross-post adaptation, fan-out and delivery tracking.
+
+use async_trait::async_trait;
+use botticelli_actor::{
+    ActorError, ActorErrorKind, ActorResult, ActorServerConfig, AdaptationRules, Content,
+    ContentBuilder, CrossPostDestination, CrossPostLedger, CrossPostPlatform, CrossPoster,
+    DeliveryStatus, DestinationPlatform, JsonStatePersistence, MarkdownDialect,
+    MediaAttachmentBuilder, MediaType, Platform, PlatformCapability, PlatformMessage,
+    PlatformMetadata,
+};
+use chrono::{Duration, Utc};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Platform that records posts and fails its first `failures` attempts.
+struct MockPlatform {
+    name: &'static str,
+    capabilities: Vec<PlatformCapability>,
+    failures: AtomicUsize,
+    posts: Mutex<Vec<PlatformMessage>>,
+}
+
+impl MockPlatform {
+    fn new(name: &'static str, capabilities: Vec<PlatformCapability>) -> Arc<Self> {
+        Self::failing(name, capabilities, 0)
+    }
+
+    fn failing(
+        name: &'static str,
+        capabilities: Vec<PlatformCapability>,
+        failures: usize,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            name,
+            capabilities,
+            failures: AtomicUsize::new(failures),
+            posts: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn posts(&self) -> Vec<PlatformMessage> {
+        self.posts.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Platform for MockPlatform {
+    async fn post(&self, message: &PlatformMessage) -> ActorResult<PlatformMetadata> {
+        if self
+            .failures
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+        {
+            return Err(ActorError::new(ActorErrorKind::PlatformTemporary(
+                "unavailable".to_string(),
+            )));
+        }
+        let mut posts = self.posts.lock().unwrap();
+        posts.push(message.clone());
+        Ok(HashMap::from([(
+            "post_id".to_string(),
+            format!("{}-{}", self.name, posts.len()),
+        )]))
+    }
+
+    async fn verify_connection(&self) -> ActorResult<()> {
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Vec<PlatformCapability> {
+        self.capabilities.clone()
+    }
+
+    fn platform_name(&self) -> &str {
+        self.name
+    }
+}
+
+fn content(text: &str, media: &[(&str, MediaType)]) -> Content {
+    ContentBuilder::default()
+        .text(Some(text.to_string()))
+        .media(
+            media
+                .iter()
+                .map(|(url, media_type)| {
+                    MediaAttachmentBuilder::default()
+                        .url(url.to_string())
+                        .media_type(*media_type)
+                        .build()
+                        .unwrap()
+                })
+                .collect::<Vec<_>>(),
+        )
+        .build()
+        .unwrap()
+}
+
+const TEXT_ONLY: &[PlatformCapability] = &[PlatformCapability::Text];
+
+#[test]
+fn test_adapt_markdown_dialects() {
+    let source = content(
+        "**New** *painting*: see [the gallery](https://example.com/g) & `code <x>`",
+        &[],
+    );
+
+    let markdown = AdaptationRules::default().adapt(&source, TEXT_ONLY);
+    assert_eq!(markdown.text, source.text().clone().unwrap());
+
+    let plain = AdaptationRules::builder()
+        .markdown(MarkdownDialect::Plain)
+        .build()
+        .unwrap()
+        .adapt(&source, TEXT_ONLY);
+    assert_eq!(
+        plain.text,
+        "New painting: see the gallery (https://example.com/g) & code <x>"
+    );
+
+    let html = AdaptationRules::builder()
+        .markdown(MarkdownDialect::Html)
+        .build()
+        .unwrap()
+        .adapt(&source, TEXT_ONLY);
+    assert_eq!(
+        html.text,
+        "<b>New</b> <i>painting</i>: see <a href=\"https://example.com/g\">the gallery</a> \
+         &amp; <code>code &lt;x&gt;</code>"
+    );
+}
+
+#[test]
+fn test_adapt_mentions_and_hashtags() {
+    let source = content("Thanks @alice and @bob! #art #renaissance #florence", &[]);
+    let rules = AdaptationRules::builder()
+        .mentions(HashMap::from([(
+            "alice".to_string(),
+            "<@1234>".to_string(),
+        )]))
+        .plain_unmapped_mentions(true)
+        .max_hashtags(Some(2))
+        .extra_hashtags(vec!["Art".to_string(), "botticelli".to_string()])
+        .build()
+        .unwrap();
+
+    let message = rules.adapt(&source, TEXT_ONLY);
+    assert_eq!(
+        message.text,
+        "Thanks <@1234> and bob! #art #renaissance\n\n#botticelli"
+    );
+
+    // Email addresses and URL fragments are not mentions or hashtags
+    let source = content("Mail me@example.com or see https://x.org/#top", &[]);
+    assert_eq!(
+        rules.adapt(&source, TEXT_ONLY).text,
+        "Mail me@example.com or see https://x.org/#top\n\n#Art #botticelli"
+    );
+}
+
+#[test]
+fn test_adapt_length_limits() {
+    let source = content("The Birth of Venus is a painting by Sandro Botticelli", &[]);
+    let rules = AdaptationRules::builder()
+        .max_length(Some(24))
+        .extra_hashtags(vec!["#art".to_string()])
+        .build()
+        .unwrap();
+
+    let truncated = rules.adapt(&source, TEXT_ONLY);
+    assert_eq!(truncated.text, "The Birth of Venus is a…");
+    assert!(truncated.text.chars().count() <= 24);
+
+    // Threading platforms split long text themselves
+    let threaded = rules.adapt(
+        &source,
+        &[PlatformCapability::Text, PlatformCapability::Threads],
+    );
+    assert_eq!(
+        threaded.text,
+        "The Birth of Venus is a painting by Sandro Botticelli\n\n#art"
+    );
+}
+
+#[test]
+fn test_adapt_media_by_capability() {
+    let source = content(
+        "Gallery",
+        &[
+            ("https://example.com/a.png", MediaType::Image),
+            ("https://example.com/b.mp4", MediaType::Video),
+            ("https://example.com/c.png", MediaType::Image),
+            ("https://example.com/d.mp3", MediaType::Audio),
+        ],
+    );
+
+    let images_only = AdaptationRules::default().adapt(
+        &source,
+        &[PlatformCapability::Text, PlatformCapability::Images],
+    );
+    assert_eq!(
+        images_only.media_urls,
+        vec!["https://example.com/a.png", "https://example.com/c.png"]
+    );
+
+    let limited = AdaptationRules::builder()
+        .max_media(Some(2))
+        .build()
+        .unwrap()
+        .adapt(
+            &source,
+            &[
+                PlatformCapability::Text,
+                PlatformCapability::Images,
+                PlatformCapability::Videos,
+            ],
+        );
+    assert_eq!(
+        limited.media_urls,
+        vec!["https://example.com/a.png", "https://example.com/b.mp4"]
+    );
+}
+
+#[test]
+fn test_platform_default_rules() {
+    let bluesky = AdaptationRules::for_platform("bluesky");
+    assert_eq!(*bluesky.max_length(), Some(300));
+    assert_eq!(*bluesky.markdown(), MarkdownDialect::Plain);
+    assert_eq!(*bluesky.max_media(), Some(4));
+
+    let webhook = AdaptationRules::for_platform("webhook");
+    assert_eq!(*webhook.max_length(), None);
+    assert_eq!(*webhook.markdown(), MarkdownDialect::Markdown);
+}
+
+#[tokio::test]
+async fn test_retry_does_not_double_post() {
+    let discord = MockPlatform::new("discord", TEXT_ONLY.to_vec());
+    let mastodon = MockPlatform::failing("mastodon", TEXT_ONLY.to_vec(), 1);
+    let poster = CrossPoster::new(vec![
+        CrossPostDestination::new("discord", discord.clone()),
+        CrossPostDestination::new("mastodon", mastodon.clone()),
+    ])
+    .unwrap();
+    let item = content("**Hello** world", &[]);
+
+    let err = poster.post("content-1", &item).await.unwrap_err();
+    assert!(matches!(err.kind, ActorErrorKind::PlatformTemporary(_)));
+
+    let record = poster.record("content-1").await.unwrap().unwrap();
+    assert_eq!(
+        *record.delivery("discord").unwrap().status(),
+        DeliveryStatus::Posted
+    );
+    assert_eq!(record.failed(), vec!["mastodon".to_string()]);
+
+    let record = poster.post("content-1", &item).await.unwrap();
+    assert!(record.is_complete(["discord", "mastodon"]));
+    assert_eq!(*record.delivery("mastodon").unwrap().attempts(), 2);
+    assert_eq!(
+        record.delivery("mastodon").unwrap().post_id().as_deref(),
+        Some("mastodon-1")
+    );
+
+    // Each platform got the content exactly once, adapted to its dialect
+    assert_eq!(discord.posts().len(), 1);
+    assert_eq!(discord.posts()[0].text, "**Hello** world");
+    assert_eq!(mastodon.posts().len(), 1);
+    assert_eq!(mastodon.posts()[0].text, "Hello world");
+
+    // Posting again is a no-op
+    poster.post("content-1", &item).await.unwrap();
+    assert_eq!(discord.posts().len(), 1);
+}
+
+#[tokio::test]
+async fn test_ledger_survives_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("crosspost.json");
+    let item = content("Persisted", &[]);
+
+    let first = MockPlatform::new("telegram", TEXT_ONLY.to_vec());
+    let failing = MockPlatform::failing("bluesky", TEXT_ONLY.to_vec(), 1);
+    let poster = CrossPoster::new(vec![
+        CrossPostDestination::new("telegram", first.clone()),
+        CrossPostDestination::new("bluesky", failing.clone()),
+    ])
+    .unwrap()
+    .with_persistence(Arc::new(JsonStatePersistence::<CrossPostLedger>::new(
+        &path,
+    )));
+    assert!(poster.post("content-2", &item).await.is_err());
+
+    // A new poster (e.g. after a restart) only retries the failed destination
+    let poster = CrossPoster::new(vec![
+        CrossPostDestination::new("telegram", first.clone()),
+        CrossPostDestination::new("bluesky", failing.clone()),
+    ])
+    .unwrap()
+    .with_persistence(Arc::new(JsonStatePersistence::<CrossPostLedger>::new(
+        &path,
+    )));
+    poster.post("content-2", &item).await.unwrap();
+
+    assert_eq!(first.posts().len(), 1);
+    assert_eq!(failing.posts().len(), 1);
+}
+
+#[tokio::test]
+async fn test_crosspost_platform() {
+    let a = MockPlatform::new("discord", vec![PlatformCapability::Text]);
+    let b = MockPlatform::new(
+        "mastodon",
+        vec![PlatformCapability::Text, PlatformCapability::Images],
+    );
+    let platform = CrossPostPlatform::new(
+        CrossPoster::new(vec![
+            CrossPostDestination::new("discord", a.clone()),
+            CrossPostDestination::new("mastodon", b.clone()),
+        ])
+        .unwrap(),
+    );
+    assert_eq!(platform.platform_name(), "crosspost");
+    assert!(
+        platform
+            .capabilities()
+            .contains(&PlatformCapability::Images)
+    );
+
+    let message = PlatformMessage {
+        text: "Hello".to_string(),
+        media_urls: vec!["https://example.com/venus.png".to_string()],
+        content_id: None,
+    };
+    let metadata = platform.post(&message).await.unwrap();
+    assert_eq!(metadata["destinations"], "discord,mastodon");
+    assert_eq!(metadata["discord.post_id"], "discord-1");
+    assert_eq!(metadata["mastodon.post_id"], "mastodon-1");
+    assert_eq!(
+        metadata["content_id"],
+        CrossPostPlatform::content_id(&message)
+    );
+
+    // Media is only sent where the platform supports it
+    assert!(a.posts()[0].media_urls.is_empty());
+    assert_eq!(b.posts()[0].media_urls, message.media_urls);
+
+    platform.post(&message).await.unwrap();
+    assert_eq!(a.posts().len(), 1);
+}
+
+#[tokio::test]
+async fn test_crosspost_platform_keys_by_content_id() {
+    let discord = MockPlatform::new("discord", TEXT_ONLY.to_vec());
+    let platform = CrossPostPlatform::new(
+        CrossPoster::new(vec![CrossPostDestination::new("discord", discord.clone())]).unwrap(),
+    );
+
+    let message = PlatformMessage {
+        text: "Same text".to_string(),
+        media_urls: vec![],
+        content_id: Some("17".to_string()),
+    };
+    let metadata = platform.post(&message).await.unwrap();
+    assert_eq!(metadata["content_id"], "17");
+    assert_eq!(discord.posts()[0].content_id.as_deref(), Some("17"));
+
+    // A different row with identical text is still posted
+    let other = PlatformMessage {
+        content_id: Some("18".to_string()),
+        ..message.clone()
+    };
+    platform.post(&other).await.unwrap();
+    assert_eq!(discord.posts().len(), 2);
+
+    // Re-posting the same row is not
+    platform.post(&message).await.unwrap();
+    assert_eq!(discord.posts().len(), 2);
+}
+
+#[tokio::test]
+async fn test_prune_completed_records() {
+    let discord = MockPlatform::new("discord", TEXT_ONLY.to_vec());
+    let mastodon = MockPlatform::failing("mastodon", TEXT_ONLY.to_vec(), 1);
+    let poster = CrossPoster::new(vec![
+        CrossPostDestination::new("discord", discord.clone()),
+        CrossPostDestination::new("mastodon", mastodon.clone()),
+    ])
+    .unwrap();
+    assert!(poster.post("failed", &content("One", &[])).await.is_err());
+    poster.post("done", &content("Two", &[])).await.unwrap();
+
+    let mut ledger = CrossPostLedger::default();
+    ledger.insert(poster.record("failed").await.unwrap().unwrap());
+    ledger.insert(poster.record("done").await.unwrap().unwrap());
+    let destinations = ["discord", "mastodon"];
+
+    // Recent records are kept
+    let pruned = ledger.prune_completed(destinations, Utc::now() - Duration::days(1));
+    assert_eq!(pruned, 0);
+
+    // Old complete records are removed; incomplete ones stay for retry
+    let pruned = ledger.prune_completed(destinations, Utc::now() + Duration::seconds(1));
+    assert_eq!(pruned, 1);
+    assert!(ledger.record("done").is_none());
+    assert!(ledger.record("failed").is_some());
+}
+
+#[test]
+fn test_cross_poster_rejects_bad_destinations() {
+    let err = CrossPoster::new(vec![]).unwrap_err();
+    assert!(matches!(err.kind, ActorErrorKind::InvalidConfiguration(_)));
+
+    let platform = MockPlatform::new("discord", TEXT_ONLY.to_vec());
+    let err = CrossPoster::new(vec![
+        CrossPostDestination::new("main", platform.clone()),
+        CrossPostDestination::new("main", platform),
+    ])
+    .unwrap_err();
+    assert!(matches!(err.kind, ActorErrorKind::InvalidConfiguration(_)));
+}
+
+#[test]
+fn test_parse_destinations() {
+    let toml = r#"
+[[actors]]
+name = "crossposter"
+config_file = "actors/crossposter.toml"
+
+[[actors.destinations]]
+name = "discord"
+platform = "discord"
+channel_id = "123456"
+
+[[actors.destinations]]
+name = "mastodon"
+platform = "mastodon"
+base_url = "https://mastodon.social"
+
+[actors.destinations.adaptation]
+max_length = 400
+max_hashtags = 2
+
+[[actors.destinations]]
+name = "slack"
+platform = "webhook"
+
+[actors.destinations.webhook]
+url = "https://hooks.slack.com/services/T000/B000/XXXX"
+"#;
+
+    let config: ActorServerConfig = toml::from_str(toml).expect("Valid TOML");
+    let destinations = &config.actors[0].destinations;
+    assert_eq!(destinations.len(), 3);
+    assert!(matches!(
+        &destinations[0].platform,
+        DestinationPlatform::Discord { channel_id } if channel_id == "123456"
+    ));
+    let rules = destinations[1].adaptation.as_ref().unwrap();
+    assert_eq!(*rules.max_length(), Some(400));
+    assert_eq!(*rules.max_hashtags(), Some(2));
+    assert!(*rules.allow_threads());
+    assert!(matches!(
+        &destinations[2].platform,
+        DestinationPlatform::Webhook { webhook } if webhook.url().starts_with("https://hooks.slack.com")
+    ));
+
+    // Webhook destinations need no secrets, so they build offline
+    assert_eq!(
+        destinations[2].build().unwrap().platform().platform_name(),
+        "webhook"
+    );
+}
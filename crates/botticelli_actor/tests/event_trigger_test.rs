@@ -0,0 +1,200 @@
+//! Tests for event trigger configuration and matching.
+
+use botticelli_actor::{ActorServerConfig, EventTrigger, ScheduleConfig};
+use botticelli_interface::{PlatformEvent, PlatformEventBuilder, PlatformEventKind};
+use botticelli_server::Schedule;
+
+fn message(channel_id: &str, content: &str) -> PlatformEvent {
+    PlatformEventBuilder::default()
+        .platform("discord")
+        .kind(PlatformEventKind::MessageCreated)
+        .guild_id("1")
+        .channel_id(channel_id)
+        .user_id("42")
+        .user_name("alice")
+        .message_id("99")
+        .content(content)
+        .build()
+        .expect("valid event")
+}
+
+fn member_joined(guild_id: &str) -> PlatformEvent {
+    PlatformEventBuilder::default()
+        .platform("discord")
+        .kind(PlatformEventKind::MemberJoined)
+        .guild_id(guild_id)
+        .user_id("42")
+        .user_name("alice")
+        .build()
+        .expect("valid event")
+}
+
+#[test]
+fn test_parse_triggers() {
+    let toml = r#"
+[[actors]]
+name = "welcomer"
+config_file = "welcome.toml"
+
+[actors.schedule]
+type = "Triggered"
+
+[[actors.triggers]]
+type = "MemberJoined"
+guild_id = "1"
+
+[[actors.triggers]]
+type = "KeywordMatched"
+channel_id = "10"
+keywords = ["spam", "scam"]
+"#;
+
+    let config: ActorServerConfig = toml::from_str(toml).expect("Valid TOML");
+    let actor = &config.actors[0];
+
+    assert!(matches!(actor.schedule, ScheduleConfig::Triggered));
+    assert_eq!(
+        actor.triggers,
+        vec![
+            EventTrigger::MemberJoined {
+                guild_id: Some("1".to_string())
+            },
+            EventTrigger::KeywordMatched {
+                channel_id: Some("10".to_string()),
+                keywords: vec!["spam".to_string(), "scam".to_string()],
+                case_sensitive: false,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_triggers_default_to_empty() {
+    let toml = r#"
+[[actors]]
+name = "poster"
+config_file = "poster.toml"
+"#;
+
+    let config: ActorServerConfig = toml::from_str(toml).expect("Valid TOML");
+    assert!(config.actors[0].triggers.is_empty());
+}
+
+#[test]
+fn test_triggered_schedule_never_runs() {
+    let schedule = ScheduleConfig::Triggered;
+    assert!(!schedule.check(None).should_run);
+    assert!(schedule.next_execution(chrono::Utc::now()).is_none());
+}
+
+#[test]
+fn test_event_variables() {
+    let variables = message("10", "hello").variables();
+
+    assert_eq!(variables["event:type"], "message_created");
+    assert_eq!(variables["event:platform"], "discord");
+    assert_eq!(variables["event:channel_id"], "10");
+    assert_eq!(variables["event:user_name"], "alice");
+    assert_eq!(variables["event:content"], "hello");
+    assert!(!variables.contains_key("event:emoji"));
+}
+
+#[test]
+fn test_message_trigger_filters_channel() {
+    let trigger = EventTrigger::MessageCreated {
+        channel_id: Some("10".to_string()),
+    };
+
+    assert!(trigger.match_event(&message("10", "hi")).is_some());
+    assert!(trigger.match_event(&message("11", "hi")).is_none());
+    assert!(trigger.match_event(&member_joined("1")).is_none());
+}
+
+#[test]
+fn test_member_joined_trigger() {
+    let any_guild = EventTrigger::MemberJoined { guild_id: None };
+    let one_guild = EventTrigger::MemberJoined {
+        guild_id: Some("1".to_string()),
+    };
+
+    assert!(any_guild.match_event(&member_joined("2")).is_some());
+    assert!(one_guild.match_event(&member_joined("1")).is_some());
+    assert!(one_guild.match_event(&member_joined("2")).is_none());
+}
+
+#[test]
+fn test_keyword_trigger_exposes_keyword() {
+    let trigger = EventTrigger::KeywordMatched {
+        channel_id: None,
+        keywords: vec!["scam".to_string()],
+        case_sensitive: false,
+    };
+
+    let variables = trigger
+        .match_event(&message("10", "Free nitro, totally not a SCAM"))
+        .expect("keyword should match");
+    assert_eq!(variables["event:keyword"], "scam");
+    assert_eq!(variables["event:message_id"], "99");
+
+    assert!(trigger.match_event(&message("10", "hello")).is_none());
+}
+
+#[test]
+fn test_keyword_trigger_case_sensitive() {
+    let trigger = EventTrigger::KeywordMatched {
+        channel_id: None,
+        keywords: vec!["SCAM".to_string()],
+        case_sensitive: true,
+    };
+
+    assert!(trigger.match_event(&message("10", "a SCAM")).is_some());
+    assert!(trigger.match_event(&message("10", "a scam")).is_none());
+}
+
+#[test]
+fn test_keyword_trigger_matches_whole_words() {
+    let trigger = EventTrigger::KeywordMatched {
+        channel_id: None,
+        keywords: vec![
+            "ban".to_string(),
+            "free nitro".to_string(),
+            "!help".to_string(),
+        ],
+        case_sensitive: false,
+    };
+    let keyword = |content: &str| {
+        trigger
+            .match_event(&message("10", content))
+            .map(|variables| variables["event:keyword"].clone())
+    };
+
+    assert_eq!(keyword("please BAN them").as_deref(), Some("ban"));
+    assert_eq!(keyword("ban!").as_deref(), Some("ban"));
+    assert_eq!(keyword("Get free nitro now").as_deref(), Some("free nitro"));
+    assert_eq!(keyword("pls!help").as_deref(), Some("!help"));
+    assert!(keyword("I ate a banana").is_none());
+    assert!(keyword("urban legends").is_none());
+    assert!(keyword("ban_list").is_none());
+    assert!(keyword("freenitro").is_none());
+}
+
+#[test]
+fn test_reaction_trigger_filters_emoji() {
+    let trigger = EventTrigger::ReactionAdded {
+        channel_id: None,
+        emoji: Some("🚩".to_string()),
+    };
+    let reaction = |emoji: &str| {
+        PlatformEventBuilder::default()
+            .platform("discord")
+            .kind(PlatformEventKind::ReactionAdded)
+            .channel_id("10")
+            .message_id("99")
+            .emoji(emoji)
+            .build()
+            .expect("valid event")
+    };
+
+    assert!(trigger.match_event(&reaction("🚩")).is_some());
+    assert!(trigger.match_event(&reaction("👍")).is_none());
+}
@@ -0,0 +1,315 @@
+//! Tests for the Mastodon platform against a local mock Mastodon REST server.
+
+use axum::extract::{Multipart, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use botticelli_actor::platforms::split_into_thread;
+use botticelli_actor::{
+    ActorErrorKind, MastodonPlatform, MastodonVisibility, Platform, PlatformCapability,
+    PlatformMessage,
+};
+use serde_json::{Value, json};
+use std::sync::{Arc, Mutex};
+
+const TOKEN: &str = "test-token";
+
+/// Requests observed by the mock server.
+#[derive(Default)]
+struct Recorded {
+    statuses: Vec<Value>,
+    uploads: Vec<(String, usize)>,
+}
+
+type Shared = Arc<Mutex<Recorded>>;
+
+fn authorized(headers: &HeaderMap) -> bool {
+    headers
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v == format!("Bearer {TOKEN}"))
+}
+
+async fn create_status(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Json(body): Json<Value>,
+) -> Result<Json<Value>, StatusCode> {
+    if !authorized(&headers) {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+    let mut recorded = state.lock().unwrap();
+    recorded.statuses.push(body);
+    let id = recorded.statuses.len().to_string();
+    Ok(Json(json!({
+        "id": id,
+        "url": format!("https://mastodon.test/@bot/{id}"),
+    })))
+}
+
+async fn upload_media(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    mut multipart: Multipart,
+) -> Result<Json<Value>, StatusCode> {
+    if !authorized(&headers) {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+    let field = multipart
+        .next_field()
+        .await
+        .map_err(|_| StatusCode::BAD_REQUEST)?
+        .ok_or(StatusCode::BAD_REQUEST)?;
+    let name = field.file_name().unwrap_or_default().to_string();
+    let bytes = field.bytes().await.map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    let mut recorded = state.lock().unwrap();
+    recorded.uploads.push((name, bytes.len()));
+    Ok(Json(
+        json!({ "id": format!("media-{}", recorded.uploads.len()) }),
+    ))
+}
+
+async fn verify_credentials(headers: HeaderMap) -> StatusCode {
+    if authorized(&headers) {
+        StatusCode::OK
+    } else {
+        StatusCode::UNAUTHORIZED
+    }
+}
+
+async fn rate_limited() -> (StatusCode, [(&'static str, &'static str); 1]) {
+    (StatusCode::TOO_MANY_REQUESTS, [("retry-after", "30")])
+}
+
+/// Start a mock Mastodon instance, returning its base URL and recorded requests.
+async fn mock_server() -> (String, Shared) {
+    let state = Shared::default();
+    let app = Router::new()
+        .route("/api/v1/statuses", post(create_status))
+        .route("/api/v2/media", post(upload_media))
+        .route(
+            "/api/v1/accounts/verify_credentials",
+            get(verify_credentials),
+        )
+        .route("/images/cat.png", get(|| async { vec![0u8; 64] }))
+        .route("/limited/api/v1/statuses", post(rate_limited))
+        .with_state(state.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    (format!("http://{addr}"), state)
+}
+
+fn message(text: &str, media_urls: Vec<String>) -> PlatformMessage {
+    PlatformMessage {
+        text: text.to_string(),
+        media_urls,
+        content_id: None,
+    }
+}
+
+#[test]
+fn test_invalid_configuration() {
+    assert!(MastodonPlatform::new("mastodon.social", TOKEN).is_err());
+    assert!(MastodonPlatform::new("https://mastodon.social", "").is_err());
+}
+
+#[test]
+fn test_capabilities() {
+    let platform = MastodonPlatform::new("https://mastodon.social", TOKEN).unwrap();
+    let caps = platform.capabilities();
+
+    assert_eq!(platform.platform_name(), "mastodon");
+    assert!(caps.contains(&PlatformCapability::Images));
+    assert!(caps.contains(&PlatformCapability::ContentWarnings));
+    assert!(caps.contains(&PlatformCapability::Threads));
+}
+
+#[test]
+fn test_split_into_thread() {
+    let short = split_into_thread("hello world", 20, |s| s.chars().count());
+    assert_eq!(short, vec!["hello world"]);
+
+    let text = "one two three four five six seven eight nine ten";
+    let parts = split_into_thread(text, 20, |s| s.chars().count());
+    assert!(parts.len() > 1);
+    assert!(parts.iter().all(|p| p.chars().count() <= 20));
+    assert!(parts[0].ends_with(&format!("(1/{})", parts.len())));
+
+    let long_word = "x".repeat(50);
+    let parts = split_into_thread(&long_word, 20, |s| s.chars().count());
+    assert!(parts.iter().all(|p| p.chars().count() <= 20));
+}
+
+#[tokio::test]
+async fn test_post_status_with_options() {
+    let (base_url, state) = mock_server().await;
+    let platform = MastodonPlatform::builder()
+        .base_url(base_url)
+        .access_token(TOKEN)
+        .visibility(MastodonVisibility::Unlisted)
+        .content_warning("spoilers")
+        .build()
+        .unwrap();
+
+    let metadata = platform
+        .post(&message("Hello fediverse!", vec![]))
+        .await
+        .expect("post should succeed");
+
+    assert_eq!(metadata["status_id"], "1");
+    assert_eq!(metadata["url"], "https://mastodon.test/@bot/1");
+
+    let recorded = state.lock().unwrap();
+    let status = &recorded.statuses[0];
+    assert_eq!(status["status"], "Hello fediverse!");
+    assert_eq!(status["visibility"], "unlisted");
+    assert_eq!(status["spoiler_text"], "spoilers");
+    assert!(status.get("in_reply_to_id").is_none());
+}
+
+#[tokio::test]
+async fn test_post_uploads_media() {
+    let (base_url, state) = mock_server().await;
+    let media_dir = tempfile::tempdir().unwrap();
+    let platform = MastodonPlatform::new(&base_url, TOKEN)
+        .unwrap()
+        .with_media_dir(media_dir.path());
+
+    let file = media_dir.path().join("local.png");
+    std::fs::write(&file, [1u8; 16]).unwrap();
+
+    let metadata = platform
+        .post(&message(
+            "Look at this",
+            vec![
+                format!("{base_url}/images/cat.png"),
+                file.to_string_lossy().to_string(),
+            ],
+        ))
+        .await
+        .expect("post should succeed");
+
+    assert_eq!(metadata["media_ids"], "media-1,media-2");
+
+    let recorded = state.lock().unwrap();
+    assert_eq!(recorded.uploads[0], ("cat.png".to_string(), 64));
+    assert_eq!(recorded.uploads[1].1, 16);
+    assert_eq!(
+        recorded.statuses[0]["media_ids"],
+        json!(["media-1", "media-2"])
+    );
+}
+
+#[tokio::test]
+async fn test_local_media_restricted_to_media_dir() {
+    let (base_url, state) = mock_server().await;
+    let root = tempfile::tempdir().unwrap();
+    let media_dir = root.path().join("media");
+    std::fs::create_dir(&media_dir).unwrap();
+    std::fs::write(media_dir.join("ok.png"), [1u8; 4]).unwrap();
+    let secret = root.path().join("secret.png");
+    std::fs::write(&secret, [2u8; 4]).unwrap();
+
+    // Without a media directory only http(s) URLs are accepted
+    let platform = MastodonPlatform::new(&base_url, TOKEN).unwrap();
+    for url in [
+        secret.to_string_lossy().to_string(),
+        format!("file://{}", secret.display()),
+    ] {
+        let err = platform.post(&message("hi", vec![url])).await.unwrap_err();
+        assert!(matches!(err.kind, ActorErrorKind::ValidationFailed(_)));
+    }
+
+    // With one, files must resolve to a path inside it
+    let platform = platform.with_media_dir(&media_dir);
+    for url in [
+        secret.to_string_lossy().to_string(),
+        "../secret.png".to_string(),
+        format!("file://{}/../secret.png", media_dir.display()),
+    ] {
+        let err = platform.post(&message("hi", vec![url])).await.unwrap_err();
+        assert!(matches!(err.kind, ActorErrorKind::ValidationFailed(_)));
+    }
+    assert!(state.lock().unwrap().uploads.is_empty());
+
+    platform
+        .post(&message("hi", vec!["ok.png".to_string()]))
+        .await
+        .expect("relative path inside the media directory");
+    assert_eq!(state.lock().unwrap().uploads[0], ("ok.png".to_string(), 4));
+}
+
+#[test]
+fn test_debug_redacts_access_token() {
+    let platform = MastodonPlatform::new("https://mastodon.test", TOKEN).unwrap();
+    let debug = format!("{:?}", platform);
+    assert!(debug.contains("mastodon.test"));
+    assert!(!debug.contains(TOKEN));
+}
+
+#[tokio::test]
+async fn test_long_post_becomes_thread() {
+    let (base_url, state) = mock_server().await;
+    let platform = MastodonPlatform::builder()
+        .base_url(base_url)
+        .access_token(TOKEN)
+        .max_characters(40usize)
+        .build()
+        .unwrap();
+
+    let text = "This generated post is far too long to fit in a single status on this instance";
+    let metadata = platform.post(&message(text, vec![])).await.unwrap();
+
+    let recorded = state.lock().unwrap();
+    assert!(recorded.statuses.len() > 1);
+    assert_eq!(
+        metadata["thread_ids"].split(',').count(),
+        recorded.statuses.len()
+    );
+    for (index, status) in recorded.statuses.iter().enumerate().skip(1) {
+        assert_eq!(status["in_reply_to_id"], index.to_string());
+    }
+    assert!(
+        recorded
+            .statuses
+            .iter()
+            .all(|s| s["status"].as_str().unwrap().chars().count() <= 40)
+    );
+}
+
+#[tokio::test]
+async fn test_rejects_empty_and_too_much_media() {
+    let platform = MastodonPlatform::new("https://mastodon.test", TOKEN).unwrap();
+
+    assert!(platform.post(&message("  ", vec![])).await.is_err());
+
+    let media = (0..5).map(|i| format!("https://x.test/{i}.png")).collect();
+    let err = platform.post(&message("hi", media)).await.unwrap_err();
+    assert!(matches!(err.kind, ActorErrorKind::ValidationFailed(_)));
+}
+
+#[tokio::test]
+async fn test_verify_connection() {
+    let (base_url, _) = mock_server().await;
+
+    let good = MastodonPlatform::new(&base_url, TOKEN).unwrap();
+    assert!(good.verify_connection().await.is_ok());
+
+    let bad = MastodonPlatform::new(&base_url, "wrong").unwrap();
+    let err = bad.verify_connection().await.unwrap_err();
+    assert!(matches!(err.kind, ActorErrorKind::AuthenticationFailed(_)));
+}
+
+#[tokio::test]
+async fn test_rate_limit_is_recoverable() {
+    let (base_url, _) = mock_server().await;
+    let platform = MastodonPlatform::new(format!("{base_url}/limited"), TOKEN).unwrap();
+
+    let err = platform.post(&message("hi", vec![])).await.unwrap_err();
+    assert_eq!(err.kind, ActorErrorKind::RateLimitExceeded(30));
+    assert!(err.is_recoverable());
+}
@@ -0,0 +1,226 @@
+//! Tests for the moderation platform wrapper.
+
+use async_trait::async_trait;
+use botticelli_actor::{
+    Actor, ActorConfigBuilder, ActorResult, ActorServerConfig, ExecutionConfigBuilder,
+    ModeratedPlatform, NoOpPlatform, Platform, PlatformCapability, PlatformMessage,
+    PlatformMetadata, Skill, SkillContext, SkillOutput, SkillOutputBuilder, SkillRegistry,
+    SkillResult,
+};
+use botticelli_core::{GenerateRequest, GenerateResponse, Output};
+use botticelli_database::DatabaseConnectionManager;
+use botticelli_error::BotticelliResult;
+use botticelli_interface::BotticelliDriver;
+use botticelli_rate_limit::RateLimitConfig;
+use botticelli_security::{ContentModerator, ContentViolation, SecurityResult};
+use diesel::r2d2::Pool;
+use std::sync::{Arc, Mutex};
+
+/// Moderator that rejects any text containing a banned word.
+struct WordModerator;
+
+#[async_trait]
+impl ContentModerator for WordModerator {
+    async fn moderate(
+        &self,
+        _platform: &str,
+        content: &str,
+    ) -> SecurityResult<Vec<ContentViolation>> {
+        if content.contains("forbidden") {
+            Ok(vec![ContentViolation::new(
+                "moderation:spam".to_string(),
+                "Contains banned word".to_string(),
+            )])
+        } else {
+            Ok(vec![])
+        }
+    }
+}
+
+fn platform() -> ModeratedPlatform {
+    ModeratedPlatform::new(Arc::new(NoOpPlatform::new()), Arc::new(WordModerator))
+}
+
+#[tokio::test]
+async fn test_moderated_platform_allows_clean_post() {
+    let message = PlatformMessage {
+        text: "A perfectly nice post".to_string(),
+        media_urls: vec![],
+        content_id: None,
+    };
+
+    assert!(platform().post(&message).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_moderated_platform_rejects_flagged_post() {
+    let message = PlatformMessage {
+        text: "This is forbidden".to_string(),
+        media_urls: vec![],
+        content_id: None,
+    };
+
+    let err = platform().post(&message).await.unwrap_err();
+    assert!(err.is_recoverable());
+    assert!(err.to_string().contains("Moderation"));
+}
+
+#[test]
+fn test_moderated_platform_delegates_name() {
+    assert_eq!(platform().platform_name(), "noop");
+}
+
+/// Classifier that flags every post as harassment.
+struct FlaggingDriver;
+
+#[async_trait]
+impl BotticelliDriver for FlaggingDriver {
+    async fn generate(&self, _req: &GenerateRequest) -> BotticelliResult<GenerateResponse> {
+        Ok(GenerateResponse {
+            outputs: vec![Output::Text(
+                r#"{"categories": ["harassment"], "severity": 8, "rationale": "Insults"}"#
+                    .to_string(),
+            )],
+        })
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "flagging"
+    }
+
+    fn model_name(&self) -> &str {
+        "flagging-model"
+    }
+
+    fn rate_limits(&self) -> &RateLimitConfig {
+        static RATE_LIMIT: std::sync::OnceLock<RateLimitConfig> = std::sync::OnceLock::new();
+        RATE_LIMIT.get_or_init(|| RateLimitConfig {
+            requests_per_minute: u64::MAX,
+            tokens_per_minute: u64::MAX,
+            requests_per_day: u64::MAX,
+            tokens_per_day: u64::MAX,
+        })
+    }
+}
+
+/// Platform that records the posts that reach it.
+#[derive(Default)]
+struct RecordingPlatform {
+    posts: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl Platform for RecordingPlatform {
+    async fn post(&self, message: &PlatformMessage) -> ActorResult<PlatformMetadata> {
+        self.posts.lock().unwrap().push(message.text.clone());
+        NoOpPlatform::new().post(message).await
+    }
+
+    async fn verify_connection(&self) -> ActorResult<()> {
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Vec<PlatformCapability> {
+        vec![]
+    }
+
+    fn platform_name(&self) -> &str {
+        "recording"
+    }
+}
+
+/// Skill that posts a fixed message through the actor's platform.
+struct PostingSkill;
+
+#[async_trait]
+impl Skill for PostingSkill {
+    fn name(&self) -> &str {
+        "poster"
+    }
+
+    fn description(&self) -> &str {
+        "Posts a fixed message"
+    }
+
+    async fn execute(&self, context: &SkillContext) -> SkillResult<SkillOutput> {
+        let message = PlatformMessage {
+            text: "You are awful".to_string(),
+            media_urls: vec![],
+            content_id: None,
+        };
+        context.platform().post(&message).await?;
+
+        Ok(SkillOutputBuilder::default()
+            .skill_name("poster".to_string())
+            .data(serde_json::json!({"posted": true}))
+            .build()
+            .expect("Valid output"))
+    }
+}
+
+#[tokio::test]
+async fn test_server_security_config_blocks_flagged_post() {
+    let dir = tempfile::tempdir().unwrap();
+    let security_path = dir.path().join("security.toml");
+    std::fs::write(
+        &security_path,
+        "[security.moderation]\nguidelines = \"Be kind\"\n",
+    )
+    .unwrap();
+    let server_path = dir.path().join("actor_server.toml");
+    std::fs::write(
+        &server_path,
+        format!(
+            "[server]\nsecurity_config = \"{}\"\n",
+            security_path.display()
+        ),
+    )
+    .unwrap();
+
+    let server_config = ActorServerConfig::from_file(&server_path).unwrap();
+    let moderator = server_config
+        .load_security()
+        .unwrap()
+        .expect("security config is set")
+        .moderator(Arc::new(FlaggingDriver))
+        .expect("moderation is configured");
+
+    let mut registry = SkillRegistry::new();
+    registry.register(Arc::new(PostingSkill));
+    let config = ActorConfigBuilder::default()
+        .name("poster".to_string())
+        .description("Posts things".to_string())
+        .knowledge(vec![])
+        .skills(vec!["poster".to_string()])
+        .execution(
+            ExecutionConfigBuilder::default()
+                .max_retries(0)
+                .stop_on_unrecoverable(false)
+                .build()
+                .expect("Valid execution config"),
+        )
+        .build()
+        .expect("Valid actor config");
+    let platform = Arc::new(RecordingPlatform::default());
+    let actor = Actor::builder()
+        .config(config)
+        .skills(registry)
+        .platform(platform.clone())
+        .moderator(moderator)
+        .build()
+        .unwrap();
+
+    let pool = Pool::builder().build_unchecked(DatabaseConnectionManager::new("postgres://unused"));
+    let result = actor.execute(&pool).await.unwrap();
+
+    assert!(result.succeeded.is_empty());
+    assert_eq!(result.failed.len(), 1);
+    assert!(result.failed[0].1.to_string().contains("Moderation"));
+    assert!(platform.posts.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_server_without_security_config_has_no_moderation() {
+    let config: ActorServerConfig = toml::from_str("[server]\n").unwrap();
+    assert!(config.load_security().unwrap().is_none());
+}
@@ -32,6 +32,7 @@ async fn test_discord_platform_post_validation() {
     let message = PlatformMessage {
         text: String::new(),
         media_urls: vec![],
+        content_id: None,
     };
 
     let result = platform.post(&message).await;
@@ -48,6 +49,7 @@ async fn test_discord_platform_text_limit() {
     let message = PlatformMessage {
         text: long_text,
         media_urls: vec![],
+        content_id: None,
     };
 
     let result = platform.post(&message).await;
@@ -62,6 +64,7 @@ async fn test_discord_platform_valid_post() {
     let message = PlatformMessage {
         text: "Hello Discord!".to_string(),
         media_urls: vec![],
+        content_id: None,
     };
 
     let result = platform.post(&message).await;
@@ -0,0 +1,271 @@
+//! Tests for timezone-aware schedules, active windows, blackouts and jitter.
+
+use botticelli_server::{
+    ActiveWindow, ConstrainedSchedule, Schedule, ScheduleCheck, ScheduleRules, ScheduleType,
+};
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+
+fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+        .single()
+        .expect("Valid time")
+}
+
+fn local(tz: Tz, year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+    tz.with_ymd_and_hms(year, month, day, hour, minute, 0)
+        .single()
+        .expect("Valid local time")
+        .with_timezone(&Utc)
+}
+
+fn time(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).expect("Valid time")
+}
+
+fn business_hours() -> ActiveWindow {
+    ActiveWindow::new(
+        vec![
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ],
+        time(9, 0),
+        time(17, 0),
+    )
+}
+
+#[test]
+fn test_cron_follows_local_time_across_dst() {
+    let tz = chrono_tz::America::New_York;
+    let rules = ScheduleRules {
+        timezone: Some("America/New_York".to_string()),
+        ..Default::default()
+    };
+    let schedule = ConstrainedSchedule::new(
+        ScheduleType::Cron {
+            expression: "0 0 9 * * * *".to_string(),
+        },
+        rules,
+        "UTC",
+    )
+    .expect("Valid schedule");
+
+    // US clocks go forward on 2025-03-09
+    let runs = schedule.preview(utc(2025, 3, 7, 0, 0), utc(2025, 3, 11, 0, 0));
+    assert_eq!(
+        runs,
+        vec![
+            local(tz, 2025, 3, 7, 9, 0),
+            local(tz, 2025, 3, 8, 9, 0),
+            local(tz, 2025, 3, 9, 9, 0),
+            local(tz, 2025, 3, 10, 9, 0),
+        ]
+    );
+    assert_eq!(runs[0], utc(2025, 3, 7, 14, 0));
+    assert_eq!(runs[3], utc(2025, 3, 10, 13, 0));
+}
+
+#[test]
+fn test_times_per_day_spread_evenly() {
+    let schedule = ScheduleType::TimesPerDay {
+        times: 4,
+        start: Some(time(9, 0)),
+        end: Some(time(17, 0)),
+    };
+
+    let runs = schedule.preview(utc(2025, 6, 2, 10, 0), utc(2025, 6, 3, 10, 0));
+    assert_eq!(
+        runs,
+        vec![
+            utc(2025, 6, 2, 11, 0),
+            utc(2025, 6, 2, 13, 0),
+            utc(2025, 6, 2, 15, 0),
+            utc(2025, 6, 3, 9, 0),
+        ]
+    );
+
+    let whole_day = ScheduleType::TimesPerDay {
+        times: 3,
+        start: None,
+        end: None,
+    };
+    assert_eq!(
+        whole_day.next_execution(utc(2025, 6, 2, 17, 0)),
+        Some(utc(2025, 6, 3, 0, 0))
+    );
+    assert_eq!(
+        ScheduleType::TimesPerDay {
+            times: 0,
+            start: None,
+            end: None
+        }
+        .next_execution(utc(2025, 6, 2, 0, 0)),
+        None
+    );
+}
+
+#[test]
+fn test_times_per_day_must_fit_window() {
+    let times_per_day = |times, end| ScheduleType::TimesPerDay {
+        times,
+        start: Some(time(9, 0)),
+        end,
+    };
+
+    assert!(times_per_day(0, None).validate().is_err());
+    assert!(
+        ConstrainedSchedule::new(times_per_day(0, None), ScheduleRules::default(), "UTC").is_err()
+    );
+    // One run per minute of a two-minute window fits, a third run doesn't
+    assert!(times_per_day(2, Some(time(9, 2))).validate().is_ok());
+    assert!(times_per_day(3, Some(time(9, 2))).validate().is_err());
+    assert!(times_per_day(u32::MAX, None).validate().is_err());
+    assert_eq!(
+        times_per_day(u32::MAX, None).next_execution(utc(2025, 6, 2, 0, 0)),
+        None
+    );
+}
+
+#[test]
+fn test_active_window_moves_runs_to_next_opening() {
+    let tz = chrono_tz::Europe::Berlin;
+    let rules = ScheduleRules {
+        timezone: Some("Europe/Berlin".to_string()),
+        active_windows: vec![business_hours()],
+        ..Default::default()
+    };
+    let schedule = ConstrainedSchedule::new(ScheduleType::Interval { seconds: 3600 }, rules, "UTC")
+        .expect("Valid schedule");
+
+    // Friday 2025-06-06, last hour of the window
+    assert!(schedule.allows(local(tz, 2025, 6, 6, 16, 30)));
+    assert!(!schedule.allows(local(tz, 2025, 6, 6, 17, 0)));
+    assert_eq!(
+        schedule.next_execution(local(tz, 2025, 6, 6, 16, 30)),
+        Some(local(tz, 2025, 6, 9, 9, 0))
+    );
+
+    // A week of hourly runs is 8 per weekday
+    let monday = local(tz, 2025, 6, 2, 0, 0);
+    let runs = schedule.preview(monday, monday + Duration::days(7));
+    assert_eq!(runs.len(), 40);
+    assert!(runs.iter().all(|run| schedule.allows(*run)));
+}
+
+#[test]
+fn test_overnight_window() {
+    let window = ActiveWindow::new(vec![Weekday::Fri], time(22, 0), time(2, 0));
+    let at = |day: u32, hour: u32| {
+        NaiveDate::from_ymd_opt(2025, 6, day)
+            .expect("Valid date")
+            .and_time(time(hour, 0))
+    };
+
+    assert!(window.contains(at(6, 23))); // Friday night
+    assert!(window.contains(at(7, 1))); // Early Saturday
+    assert!(!window.contains(at(6, 1))); // Early Friday
+    assert!(!window.contains(at(7, 23))); // Saturday night
+}
+
+#[test]
+fn test_blackout_dates_are_skipped() {
+    let rules = ScheduleRules {
+        blackout_dates: vec![NaiveDate::from_ymd_opt(2025, 12, 25).expect("Valid date")],
+        ..Default::default()
+    };
+    let schedule = ConstrainedSchedule::new(
+        ScheduleType::Cron {
+            expression: "0 0 9 * * * *".to_string(),
+        },
+        rules,
+        "UTC",
+    )
+    .expect("Valid schedule");
+
+    let runs = schedule.preview(utc(2025, 12, 24, 12, 0), utc(2025, 12, 27, 12, 0));
+    assert_eq!(runs, vec![utc(2025, 12, 26, 9, 0), utc(2025, 12, 27, 9, 0)]);
+}
+
+#[test]
+fn test_jitter_is_bounded_and_stable() {
+    let rules = ScheduleRules {
+        jitter_seconds: 900,
+        ..Default::default()
+    };
+    let schedule = ConstrainedSchedule::new(
+        ScheduleType::Cron {
+            expression: "0 0 * * * * *".to_string(),
+        },
+        rules,
+        "UTC",
+    )
+    .expect("Valid schedule");
+
+    let start = utc(2025, 6, 2, 0, 30);
+    let runs = schedule.preview(start, start + Duration::days(1));
+    assert_eq!(runs, schedule.preview(start, start + Duration::days(1)));
+    assert!(
+        runs.iter()
+            .any(|run| run.format("%M:%S").to_string() != "00:00")
+    );
+    for run in &runs {
+        let slot = run
+            .date_naive()
+            .and_time(time(chrono::Timelike::hour(run), 0))
+            .and_utc();
+        assert!(*run - slot <= Duration::seconds(900));
+    }
+}
+
+#[test]
+fn test_invalid_rules_rejected() {
+    let bad_timezone = ScheduleRules {
+        timezone: Some("Not/AZone".to_string()),
+        ..Default::default()
+    };
+    assert!(ConstrainedSchedule::new(ScheduleType::Immediate, bad_timezone, "UTC").is_err());
+    assert!(
+        ConstrainedSchedule::new(ScheduleType::Immediate, ScheduleRules::default(), "Nowhere")
+            .is_err()
+    );
+
+    let empty_window = ScheduleRules {
+        active_windows: vec![ActiveWindow::new(vec![], time(9, 0), time(9, 0))],
+        ..Default::default()
+    };
+    assert!(ConstrainedSchedule::new(ScheduleType::Immediate, empty_window, "UTC").is_err());
+}
+
+#[test]
+fn test_immediate_waits_out_blackout() {
+    let today = Utc::now().date_naive();
+    let tomorrow = today.succ_opt().expect("Valid date");
+    let rules = ScheduleRules {
+        blackout_dates: vec![today, tomorrow],
+        ..Default::default()
+    };
+    let schedule =
+        ConstrainedSchedule::new(ScheduleType::Immediate, rules, "UTC").expect("Valid schedule");
+
+    let check = schedule.check(None);
+    assert!(!check.should_run);
+    assert_eq!(
+        check.next_run,
+        Some(
+            tomorrow
+                .succ_opt()
+                .expect("Valid date")
+                .and_time(NaiveTime::MIN)
+                .and_utc()
+        )
+    );
+
+    // Once it has run, an immediate schedule is done
+    assert_eq!(
+        schedule.check(Some(Utc::now())),
+        ScheduleCheck::new(false, None)
+    );
+}
@@ -64,3 +64,231 @@ type = "Immediate"
     let config: ActorServerConfig = toml::from_str(toml).expect("Valid TOML");
     matches!(config.actors[0].schedule, ScheduleConfig::Immediate);
 }
+
+#[test]
+fn test_control_config() {
+    let toml = r#"
+[server.control]
+token = "secret:control_token"
+
+[[actors]]
+name = "poster"
+config_file = "poster.toml"
+"#;
+
+    let config: ActorServerConfig = toml::from_str(toml).expect("Valid TOML");
+    let control = config.server.control.as_ref().expect("Control configured");
+    assert_eq!(control.bind, "127.0.0.1:8950");
+    assert_eq!(control.token, "secret:control_token");
+    assert!(config.actor("poster").is_some());
+    assert!(config.actor("missing").is_none());
+
+    let config: ActorServerConfig = toml::from_str("").expect("Valid TOML");
+    assert!(config.server.control.is_none());
+}
+
+#[test]
+fn test_leasing_config() {
+    let toml = r#"
+[server.leasing]
+replica_id = "replica-a"
+"#;
+
+    let config: ActorServerConfig = toml::from_str(toml).expect("Valid TOML");
+    let leasing = config.server.leasing.as_ref().expect("Leasing configured");
+    assert_eq!(leasing.lease_seconds, 30);
+    assert_eq!(leasing.replica_id(), "replica-a");
+
+    let leasing = toml::from_str::<ActorServerConfig>("[server.leasing]\nlease_seconds = 10")
+        .expect("Valid TOML")
+        .server
+        .leasing
+        .expect("Leasing configured");
+    assert_eq!(leasing.lease_seconds, 10);
+    assert!(
+        leasing
+            .replica_id()
+            .ends_with(&format!("-{}", std::process::id()))
+    );
+}
+
+#[test]
+fn test_schedule_rules_config() {
+    let toml = r#"
+[[actors]]
+name = "poster"
+config_file = "poster.toml"
+
+[actors.schedule]
+type = "TimesPerDay"
+times = 4
+start = "09:00"
+end = "17:00"
+
+[actors.schedule_rules]
+timezone = "Europe/Berlin"
+blackout_dates = ["2025-12-25"]
+jitter_seconds = 600
+
+[[actors.schedule_rules.active_windows]]
+days = ["Mon", "Tue", "Wed", "Thu", "Fri"]
+start = "09:00"
+end = "17:00"
+"#;
+
+    let config: ActorServerConfig = toml::from_str(toml).expect("Valid TOML");
+    let actor = config.actor("poster").expect("Actor configured");
+    assert!(matches!(
+        actor.schedule,
+        ScheduleConfig::TimesPerDay {
+            times: 4,
+            start: Some(_),
+            end: Some(_)
+        }
+    ));
+    assert_eq!(actor.schedule_rules.active_windows[0].days.len(), 5);
+    assert_eq!(actor.schedule_rules.jitter_seconds, 600);
+
+    let schedule = actor.build_schedule("UTC").expect("Valid schedule");
+    assert_eq!(schedule.timezone(), chrono_tz::Europe::Berlin);
+
+    let mut invalid = actor.clone();
+    invalid.schedule_rules.timezone = Some("Mars/Olympus_Mons".to_string());
+    assert!(invalid.build_schedule("UTC").is_err());
+}
+
+#[test]
+fn test_times_per_day_rejected_on_load() {
+    let dir = tempfile::tempdir().expect("Temp dir");
+    let path = dir.path().join("actor_server.toml");
+    let config = |times: u32| {
+        format!(
+            "[[actors]]\nname = \"poster\"\nconfig_file = \"poster.toml\"\n\n\
+             [actors.schedule]\ntype = \"TimesPerDay\"\ntimes = {}\n",
+            times
+        )
+    };
+
+    std::fs::write(&path, config(4)).expect("Write config");
+    assert!(ActorServerConfig::from_file(&path).is_ok());
+
+    for times in [0, 100_000] {
+        std::fs::write(&path, config(times)).expect("Write config");
+        let err = ActorServerConfig::from_file(&path).expect_err("Invalid times");
+        assert!(err.to_string().contains("TimesPerDay"), "{}", err);
+    }
+}
+
+#[test]
+fn test_slash_commands_config() {
+    let toml = r#"
+[server.slash_commands]
+directory = "narratives/commands"
+guild_id = 1234
+"#;
+
+    let config: ActorServerConfig = toml::from_str(toml).expect("Valid TOML");
+    let slash_commands = config
+        .server
+        .slash_commands
+        .as_ref()
+        .expect("Slash commands configured");
+    assert_eq!(slash_commands.directory, "narratives/commands");
+    assert_eq!(slash_commands.guild_id, Some(1234));
+
+    let config: ActorServerConfig = toml::from_str("").expect("Valid TOML");
+    assert!(config.server.slash_commands.is_none());
+}
+
+#[cfg(feature = "discord")]
+mod slash_commands {
+    use async_trait::async_trait;
+    use botticelli_actor::ActorServerConfig;
+    use botticelli_security::SecurityConfig;
+    use botticelli_social::{
+        DiscordError, SlashCommandInvocation, SlashCommandRoute, SlashCommandRunner,
+    };
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    const COMMANDS: &str = r#"
+[narrative.ping]
+description = "Reply to a ping"
+toc = ["reply"]
+
+[narrative.ping.slash_command]
+description = "Ping the bot"
+
+[acts]
+reply = "Say pong"
+"#;
+
+    /// Runner that replies with the narrative name.
+    struct NameRunner;
+
+    #[async_trait]
+    impl SlashCommandRunner for NameRunner {
+        async fn run(
+            &self,
+            route: &SlashCommandRoute,
+            _variables: HashMap<String, String>,
+        ) -> Result<String, DiscordError> {
+            Ok(route.narrative_name().to_string())
+        }
+    }
+
+    fn server_config(dir: &std::path::Path) -> ActorServerConfig {
+        toml::from_str(&format!(
+            "[server.slash_commands]\ndirectory = {:?}\n",
+            dir.display().to_string()
+        ))
+        .expect("Valid TOML")
+    }
+
+    fn ping() -> SlashCommandInvocation {
+        SlashCommandInvocation::new("ping", 7, 100, Some(1), HashMap::new())
+    }
+
+    #[tokio::test]
+    async fn test_slash_command_dispatcher_uses_discord_permissions() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("commands.toml"), COMMANDS).unwrap();
+        let config = server_config(dir.path());
+        let security = SecurityConfig::from_toml_str(
+            "[security.discord.permissions]\nallowed_commands = [\"slash.ping\"]\n",
+        )
+        .unwrap();
+
+        let dispatcher = config
+            .slash_command_dispatcher(Some(&security), Arc::new(NameRunner))
+            .unwrap()
+            .expect("Slash commands configured");
+        assert_eq!(dispatcher.registry().len(), 1);
+        assert_eq!(dispatcher.dispatch(&ping()).await.unwrap(), "ping");
+
+        // Without a security config every command is denied
+        let dispatcher = config
+            .slash_command_dispatcher(None, Arc::new(NameRunner))
+            .unwrap()
+            .expect("Slash commands configured");
+        assert!(dispatcher.dispatch(&ping()).await.is_err());
+    }
+
+    #[test]
+    fn test_slash_command_dispatcher_disabled_or_missing_directory() {
+        let config: ActorServerConfig = toml::from_str("").expect("Valid TOML");
+        assert!(
+            config
+                .slash_command_dispatcher(None, Arc::new(NameRunner))
+                .unwrap()
+                .is_none()
+        );
+
+        let config = server_config(std::path::Path::new("/nonexistent/commands"));
+        assert!(
+            config
+                .slash_command_dispatcher(None, Arc::new(NameRunner))
+                .is_err()
+        );
+    }
+}
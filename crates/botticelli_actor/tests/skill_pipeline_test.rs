@@ -0,0 +1,353 @@
+//! Tests for inter-skill data flow in the actor pipeline.
+
+use async_trait::async_trait;
+use botticelli_actor::{
+    Actor, ActorConfigBuilder, ActorError, ActorErrorKind, ExecutionConfigBuilder,
+    NarrativeExecutionSkill, NoOpPlatform, Skill, SkillConfig, SkillContext, SkillOutput,
+    SkillOutputBuilder, SkillRegistry, SkillResult, StopCondition, StopConditionBuilder,
+};
+use botticelli_database::{DatabaseConnectionManager, DatabasePool};
+use diesel::r2d2::Pool;
+use serde_json::{Value as JsonValue, json};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Skill name, primary input and earlier output names seen by each execution.
+type Seen = Arc<Mutex<Vec<(String, Option<JsonValue>, Vec<String>)>>>;
+
+/// Skill returning fixed data and recording the context it saw.
+struct RecordingSkill {
+    name: String,
+    data: JsonValue,
+    fail: bool,
+    seen: Seen,
+}
+
+#[async_trait]
+impl Skill for RecordingSkill {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        "Records its context"
+    }
+
+    async fn execute(&self, context: &SkillContext) -> SkillResult<SkillOutput> {
+        let mut earlier: Vec<String> = context.outputs().keys().cloned().collect();
+        earlier.sort();
+        self.seen
+            .lock()
+            .unwrap()
+            .push((self.name.clone(), context.input().cloned(), earlier));
+
+        if self.fail {
+            return Err(ActorError::new(ActorErrorKind::PlatformPermanent(
+                "gone".to_string(),
+            )));
+        }
+
+        Ok(SkillOutputBuilder::default()
+            .skill_name(self.name.clone())
+            .data(self.data.clone())
+            .build()
+            .expect("Valid output"))
+    }
+}
+
+fn lazy_pool() -> DatabasePool {
+    Pool::builder().build_unchecked(DatabaseConnectionManager::new("postgres://unused"))
+}
+
+fn skill_config(toml_src: &str) -> SkillConfig {
+    toml::from_str(toml_src).expect("Valid skill config")
+}
+
+fn actor(
+    skills: &[(&str, JsonValue, bool)],
+    configs: HashMap<String, SkillConfig>,
+    seen: &Seen,
+) -> Actor {
+    let mut registry = SkillRegistry::new();
+    for (name, data, fail) in skills {
+        registry.register(Arc::new(RecordingSkill {
+            name: name.to_string(),
+            data: data.clone(),
+            fail: *fail,
+            seen: Arc::clone(seen),
+        }));
+    }
+
+    let config = ActorConfigBuilder::default()
+        .name("pipeline".to_string())
+        .description("Pipeline test".to_string())
+        .knowledge(vec![])
+        .skills(skills.iter().map(|(n, _, _)| n.to_string()).collect())
+        .skill_configs(configs)
+        .execution(
+            ExecutionConfigBuilder::default()
+                .stop_on_unrecoverable(false)
+                .build()
+                .expect("Valid execution config"),
+        )
+        .build()
+        .expect("Valid actor config");
+
+    Actor::builder()
+        .config(config)
+        .skills(registry)
+        .platform(Arc::new(NoOpPlatform::new()))
+        .build()
+        .expect("Valid actor")
+}
+
+#[test]
+fn test_inputs_from_accepts_string_or_list() {
+    let single = skill_config(r#"inputs_from = "content_selection""#);
+    assert_eq!(single.inputs_from(), &vec!["content_selection".to_string()]);
+    assert!(single.settings().is_empty());
+
+    let many = skill_config(
+        r#"
+        inputs_from = ["a", "b"]
+        lookback_days = 7
+        "#,
+    );
+    assert_eq!(many.inputs_from(), &vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(many.settings().get("lookback_days"), Some(&json!(7)));
+}
+
+#[test]
+fn test_stop_condition_matching() {
+    let data = json!({"is_duplicate": true, "candidates": [], "count": 0, "label": "x"});
+
+    let parse = |src: &str| -> StopCondition {
+        skill_config(&format!("stop_if = {src}"))
+            .stop_if()
+            .clone()
+            .expect("stop_if")
+    };
+
+    assert!(parse(r#"{ pointer = "/is_duplicate", equals = true }"#).matches(&data));
+    assert!(!parse(r#"{ pointer = "/is_duplicate", equals = false }"#).matches(&data));
+    assert!(parse(r#"{ pointer = "/candidates", empty = true }"#).matches(&data));
+    assert!(parse(r#"{ pointer = "/missing", empty = true }"#).matches(&data));
+    assert!(parse(r#"{ pointer = "/label", empty = false }"#).matches(&data));
+    assert!(parse(r#"{ pointer = "/is_duplicate" }"#).matches(&data));
+    assert!(!parse(r#"{ pointer = "/count" }"#).matches(&data));
+    assert!(!parse(r#"{ pointer = "/missing" }"#).matches(&data));
+
+    let built = StopConditionBuilder::default()
+        .pointer("/label")
+        .equals(json!("x"))
+        .build()
+        .expect("Valid condition");
+    assert!(built.matches(&data));
+}
+
+#[test]
+fn test_validate_warns_on_unknown_or_later_inputs() {
+    let mut configs = HashMap::new();
+    configs.insert(
+        "first".to_string(),
+        skill_config(r#"inputs_from = "second""#),
+    );
+    configs.insert(
+        "second".to_string(),
+        skill_config(
+            r#"
+            inputs_from = "first"
+            stop_if = { pointer = "done" }
+            "#,
+        ),
+    );
+
+    let config = ActorConfigBuilder::default()
+        .name("pipeline".to_string())
+        .description("Pipeline test".to_string())
+        .knowledge(vec!["content".to_string()])
+        .skills(vec!["first".to_string(), "second".to_string()])
+        .skill_configs(configs)
+        .build()
+        .expect("Valid actor config");
+
+    let warnings = config.validate();
+    assert!(
+        warnings
+            .iter()
+            .any(|w| w.contains("'first' takes inputs from 'second'"))
+    );
+    assert!(!warnings.iter().any(|w| w.contains("'second' takes inputs")));
+    assert!(warnings.iter().any(|w| w.contains("should start with '/'")));
+}
+
+#[tokio::test]
+async fn test_outputs_flow_to_later_skills() {
+    let seen = Seen::default();
+    let mut configs = HashMap::new();
+    configs.insert(
+        "check".to_string(),
+        skill_config(r#"inputs_from = "select""#),
+    );
+
+    let actor = actor(
+        &[
+            ("select", json!({"candidates": [1, 2]}), false),
+            ("check", json!({"candidates": [2]}), false),
+            ("format", json!({"formatted": 1}), false),
+        ],
+        configs,
+        &seen,
+    );
+
+    let result = actor.execute(&lazy_pool()).await.expect("Execution");
+    assert_eq!(result.succeeded.len(), 3);
+    assert!(result.stopped_by.is_none());
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen[0], ("select".to_string(), None, vec![]));
+    assert_eq!(
+        seen[1],
+        (
+            "check".to_string(),
+            Some(json!({"candidates": [1, 2]})),
+            vec!["select".to_string()]
+        )
+    );
+    // No inputs_from: no primary input, but all earlier outputs are visible
+    assert_eq!(
+        seen[2],
+        (
+            "format".to_string(),
+            None,
+            vec!["check".to_string(), "select".to_string()]
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_stop_if_skips_remaining_skills() {
+    let seen = Seen::default();
+    let mut configs = HashMap::new();
+    configs.insert(
+        "check".to_string(),
+        skill_config(
+            r#"
+            inputs_from = "select"
+            stop_if = { pointer = "/candidates", empty = true }
+            "#,
+        ),
+    );
+
+    let actor = actor(
+        &[
+            ("select", json!({"candidates": [1]}), false),
+            ("check", json!({"candidates": []}), false),
+            ("format", json!({}), false),
+            ("post", json!({}), false),
+        ],
+        configs,
+        &seen,
+    );
+
+    let result = actor.execute(&lazy_pool()).await.expect("Execution");
+    assert_eq!(result.succeeded.len(), 2);
+    assert_eq!(result.stopped_by.as_deref(), Some("check"));
+    assert_eq!(
+        result.skipped,
+        vec!["format".to_string(), "post".to_string()]
+    );
+    assert_eq!(seen.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_skill_skipped_when_inputs_unavailable() {
+    let seen = Seen::default();
+    let mut configs = HashMap::new();
+    configs.insert(
+        "check".to_string(),
+        skill_config(r#"inputs_from = "select""#),
+    );
+
+    let actor = actor(
+        &[
+            ("select", json!({}), true),
+            ("check", json!({}), false),
+            ("other", json!({}), false),
+        ],
+        configs,
+        &seen,
+    );
+
+    let result = actor.execute(&lazy_pool()).await.expect("Execution");
+    assert_eq!(result.failed.len(), 1);
+    assert_eq!(result.skipped, vec!["check".to_string()]);
+    assert_eq!(result.succeeded.len(), 1);
+    assert_eq!(*result.succeeded[0].skill_name(), "other");
+}
+
+/// Skill reporting whether its narrative table executor accepts raw filters.
+struct RawFilterProbe {
+    seen: Arc<Mutex<Vec<bool>>>,
+}
+
+#[async_trait]
+impl Skill for RawFilterProbe {
+    fn name(&self) -> &str {
+        "narrative_execution"
+    }
+
+    fn description(&self) -> &str {
+        "Reports the raw filter setting"
+    }
+
+    async fn execute(&self, context: &SkillContext) -> SkillResult<SkillOutput> {
+        let executor = NarrativeExecutionSkill::table_executor(context);
+        self.seen
+            .lock()
+            .unwrap()
+            .push(*executor.allow_raw_filters());
+        Ok(SkillOutputBuilder::default()
+            .skill_name(self.name())
+            .data(json!({}))
+            .build()
+            .expect("Valid output"))
+    }
+}
+
+#[tokio::test]
+async fn test_raw_filters_need_skill_opt_in() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+
+    for settings in [
+        "",
+        "allow_raw_filters = \"yes\"",
+        "allow_raw_filters = true",
+    ] {
+        let mut registry = SkillRegistry::new();
+        registry.register(Arc::new(RawFilterProbe {
+            seen: Arc::clone(&seen),
+        }));
+        let config = ActorConfigBuilder::default()
+            .name("narrator".to_string())
+            .description("Raw filter test".to_string())
+            .knowledge(vec![])
+            .skills(vec!["narrative_execution".to_string()])
+            .skill_configs(HashMap::from([(
+                "narrative_execution".to_string(),
+                skill_config(settings),
+            )]))
+            .build()
+            .expect("Valid actor config");
+        let actor = Actor::builder()
+            .config(config)
+            .skills(registry)
+            .platform(Arc::new(NoOpPlatform::new()))
+            .build()
+            .expect("Valid actor");
+
+        actor.execute(&lazy_pool()).await.expect("Execution");
+    }
+
+    assert_eq!(*seen.lock().unwrap(), vec![false, false, true]);
+}
@@ -0,0 +1,263 @@
+//! Tests that an actor runs generate → curate → post from one SQLite file.
+
+#![cfg(feature = "sqlite")]
+
+use async_trait::async_trait;
+use botticelli_actor::{
+    Actor, ActorConfigBuilder, ActorResult, CrossPostDestination, CrossPostPlatform, CrossPoster,
+    DatabaseCrossPostPersistence, DatabaseExecutionResult, DatabaseStatePersistence,
+    ExecutionConfigBuilder, LeaseStatus, Platform, PlatformCapability, PlatformMessage,
+    PlatformMetadata, Skill, SkillContext, SkillOutput, SkillOutputBuilder, SkillRegistry,
+    SkillResult, TaskLeaseManager,
+};
+use botticelli_database::{
+    ContentStatus, DatabaseConnection, DatabasePool, create_content_table, create_database_pool,
+    insert_content, transition_content_status,
+};
+use botticelli_server::StatePersistence;
+use serde_json::{Map, json};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+/// Point `DATABASE_URL` at one SQLite file shared by every test here.
+fn database_url() -> &'static str {
+    static URL: OnceLock<String> = OnceLock::new();
+    URL.get_or_init(|| {
+        let dir = tempfile::tempdir().unwrap().keep();
+        let url = dir.join("botticelli.db").display().to_string();
+        // SAFETY: set once, before any test reads the environment
+        unsafe { std::env::set_var("DATABASE_URL", &url) };
+        url
+    })
+}
+
+/// Platform that records the posts that reach it.
+#[derive(Default)]
+struct RecordingPlatform {
+    posts: Mutex<Vec<PlatformMessage>>,
+}
+
+#[async_trait]
+impl Platform for RecordingPlatform {
+    async fn post(&self, message: &PlatformMessage) -> ActorResult<PlatformMetadata> {
+        let mut posts = self.posts.lock().unwrap();
+        posts.push(message.clone());
+        Ok(PlatformMetadata::from([(
+            "post_id".to_string(),
+            posts.len().to_string(),
+        )]))
+    }
+
+    async fn verify_connection(&self) -> ActorResult<()> {
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Vec<PlatformCapability> {
+        vec![PlatformCapability::Text]
+    }
+
+    fn platform_name(&self) -> &str {
+        "recording"
+    }
+}
+
+/// Skill that posts every approved guild idea from the actor's knowledge.
+struct ApprovedIdeasSkill;
+
+#[async_trait]
+impl Skill for ApprovedIdeasSkill {
+    fn name(&self) -> &str {
+        "approved_ideas"
+    }
+
+    fn description(&self) -> &str {
+        "Posts approved guild ideas"
+    }
+
+    async fn execute(&self, context: &SkillContext) -> SkillResult<SkillOutput> {
+        let mut posted = Vec::new();
+        let ideas = context.knowledge().get("guild_ideas").cloned();
+        for idea in ideas.unwrap_or_default() {
+            if idea["review_status"] != "approved" {
+                continue;
+            }
+            let message = PlatformMessage {
+                text: idea["name"].as_str().unwrap_or_default().to_string(),
+                media_urls: vec![],
+                content_id: Some(idea["id"].to_string()),
+            };
+            context.platform().post(&message).await?;
+            posted.push(idea["id"].clone());
+        }
+
+        Ok(SkillOutputBuilder::default()
+            .skill_name("approved_ideas".to_string())
+            .data(json!({ "posted": posted }))
+            .build()
+            .expect("Valid output"))
+    }
+}
+
+/// Generate two guild ideas and approve the second.
+fn generate_and_curate(url: &str) -> i64 {
+    let mut conn = DatabaseConnection::establish(url).unwrap();
+    create_content_table(&mut conn, "guild_ideas", "discord_guilds", None, None).unwrap();
+
+    let idea = |name: &str| {
+        let mut values = Map::new();
+        values.insert("name".to_string(), json!(name));
+        values.insert("owner_id".to_string(), json!(42));
+        values
+    };
+    insert_content(&mut conn, "guild_ideas", &idea("Lore Lounge"), "writer").unwrap();
+    let approved =
+        insert_content(&mut conn, "guild_ideas", &idea("Speedrun Den"), "writer").unwrap();
+    transition_content_status(
+        &mut conn,
+        "guild_ideas",
+        approved,
+        ContentStatus::Approved,
+        "curator",
+        None,
+    )
+    .unwrap();
+    approved
+}
+
+fn poster(pool: &DatabasePool, platform: Arc<RecordingPlatform>) -> Actor {
+    let crosspost = CrossPoster::new(vec![CrossPostDestination::new("recording", platform)])
+        .unwrap()
+        .with_persistence(Arc::new(DatabaseCrossPostPersistence::new(
+            pool.clone(),
+            "poster",
+        )));
+
+    let mut registry = SkillRegistry::new();
+    registry.register(Arc::new(ApprovedIdeasSkill));
+    let config = ActorConfigBuilder::default()
+        .name("poster".to_string())
+        .description("Posts approved ideas".to_string())
+        .knowledge(vec!["guild_ideas".to_string()])
+        .skills(vec!["approved_ideas".to_string()])
+        .execution(
+            ExecutionConfigBuilder::default()
+                .max_retries(0)
+                .build()
+                .expect("Valid execution config"),
+        )
+        .build()
+        .expect("Valid actor config");
+
+    Actor::builder()
+        .config(config)
+        .skills(registry)
+        .platform(Arc::new(CrossPostPlatform::new(crosspost)))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_generate_curate_post() {
+    let url = database_url();
+    let approved = generate_and_curate(url);
+    let pool = create_database_pool(url).unwrap();
+
+    let platform = Arc::new(RecordingPlatform::default());
+    let result = poster(&pool, platform.clone())
+        .execute(&pool)
+        .await
+        .unwrap();
+    assert_eq!(result.succeeded.len(), 1);
+    let posts = platform.posts.lock().unwrap().clone();
+    assert_eq!(posts.len(), 1);
+    assert_eq!(posts[0].text, "Speedrun Den");
+
+    // The ledger survives in the database, so a fresh actor doesn't repost
+    let ledger = DatabaseCrossPostPersistence::new(pool.clone(), "poster")
+        .load_state()
+        .await
+        .unwrap()
+        .expect("ledger was saved");
+    let record = ledger.record(&approved.to_string()).expect("idea recorded");
+    assert!(record.delivery("recording").unwrap().is_posted());
+
+    let platform = Arc::new(RecordingPlatform::default());
+    let result = poster(&pool, platform.clone())
+        .execute(&pool)
+        .await
+        .unwrap();
+    assert_eq!(result.succeeded.len(), 1);
+    assert!(platform.posts.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_task_state_and_history() {
+    database_url();
+    let persistence = DatabaseStatePersistence::new().unwrap();
+
+    let execution = persistence
+        .start_execution("sqlite_daily", "poster")
+        .await
+        .unwrap();
+    persistence
+        .complete_execution(
+            execution,
+            DatabaseExecutionResult {
+                skills_succeeded: 1,
+                skills_failed: 0,
+                skills_skipped: 0,
+                metadata: json!({}),
+            },
+        )
+        .await
+        .unwrap();
+    persistence
+        .update_last_run("sqlite_daily", "poster", chrono::Utc::now().naive_utc())
+        .await
+        .unwrap();
+
+    let state = persistence
+        .load_task_state("sqlite_daily")
+        .await
+        .unwrap()
+        .expect("state saved on first run");
+    assert!(state.last_run.is_some());
+    assert!(persistence.should_execute("sqlite_daily").await.unwrap());
+
+    persistence.pause_task("sqlite_daily").await.unwrap();
+    assert!(!persistence.should_execute("sqlite_daily").await.unwrap());
+
+    let history = persistence
+        .get_execution_history("sqlite_daily", 10)
+        .await
+        .unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].success, Some(true));
+}
+
+#[tokio::test]
+async fn test_leases() {
+    database_url();
+    let persistence = DatabaseStatePersistence::with_pool_size(2).unwrap();
+    let a = TaskLeaseManager::new(&persistence, "replica-a", Duration::from_secs(30));
+    let b = TaskLeaseManager::new(&persistence, "replica-b", Duration::from_secs(30));
+
+    assert_eq!(
+        a.try_acquire("sqlite_lease").await.unwrap(),
+        LeaseStatus::Acquired
+    );
+    assert_eq!(
+        b.try_acquire("sqlite_lease").await.unwrap(),
+        LeaseStatus::HeldBy("replica-a".to_string())
+    );
+    assert_eq!(
+        a.try_acquire("sqlite_lease").await.unwrap(),
+        LeaseStatus::Renewed
+    );
+
+    a.release("sqlite_lease").await.unwrap();
+    assert_eq!(
+        b.try_acquire("sqlite_lease").await.unwrap(),
+        LeaseStatus::Acquired
+    );
+}
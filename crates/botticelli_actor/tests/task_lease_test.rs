@@ -0,0 +1,115 @@
+//! Tests for task leasing between actor-server replicas.
+
+use botticelli_actor::{DatabaseStatePersistence, LeaseStatus, TaskLeaseManager};
+use botticelli_database::establish_connection;
+use chrono::Utc;
+use diesel::prelude::*;
+use std::time::Duration;
+
+fn unique_task(name: &str) -> String {
+    format!(
+        "test-lease-{}-{}-{}",
+        name,
+        Utc::now().timestamp_millis(),
+        std::process::id()
+    )
+}
+
+fn cleanup_lease(task_id: &str) {
+    let mut conn = establish_connection().expect("Database connection");
+    diesel::delete(
+        botticelli_database::schema::actor_server_leases::table
+            .filter(botticelli_database::schema::actor_server_leases::task_id.eq(task_id)),
+    )
+    .execute(&mut conn)
+    .ok();
+}
+
+fn replicas(lease_duration: Duration) -> (TaskLeaseManager, TaskLeaseManager) {
+    dotenvy::dotenv().ok();
+    let persistence =
+        DatabaseStatePersistence::with_pool_size(2).expect("Failed to create persistence");
+    (
+        TaskLeaseManager::new(&persistence, "replica-a", lease_duration),
+        TaskLeaseManager::new(&persistence, "replica-b", lease_duration),
+    )
+}
+
+#[tokio::test]
+async fn test_lease_is_exclusive() {
+    let (a, b) = replicas(Duration::from_secs(30));
+    let task_id = unique_task("exclusive");
+
+    assert_eq!(
+        a.try_acquire(&task_id).await.expect("Acquire"),
+        LeaseStatus::Acquired
+    );
+    assert_eq!(
+        b.try_acquire(&task_id).await.expect("Acquire"),
+        LeaseStatus::HeldBy("replica-a".to_string())
+    );
+    assert_eq!(
+        a.try_acquire(&task_id).await.expect("Renew"),
+        LeaseStatus::Renewed
+    );
+    assert_eq!(a.held(), vec![task_id.clone()]);
+    assert!(b.held().is_empty());
+
+    cleanup_lease(&task_id);
+}
+
+#[tokio::test]
+async fn test_expired_lease_fails_over() {
+    let (a, b) = replicas(Duration::from_secs(1));
+    let task_id = unique_task("failover");
+
+    assert!(a.try_acquire(&task_id).await.expect("Acquire").is_held());
+
+    // Replica A stops renewing, as if it had died
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+
+    assert_eq!(
+        b.try_acquire(&task_id).await.expect("Take over"),
+        LeaseStatus::Acquired
+    );
+    assert_eq!(
+        a.try_acquire(&task_id).await.expect("Check"),
+        LeaseStatus::HeldBy("replica-b".to_string())
+    );
+    assert!(a.held().is_empty());
+
+    cleanup_lease(&task_id);
+}
+
+#[tokio::test]
+async fn test_release_hands_over_immediately() {
+    let (a, b) = replicas(Duration::from_secs(30));
+    let first = unique_task("release-one");
+    let second = unique_task("release-all");
+
+    assert!(a.try_acquire(&first).await.expect("Acquire").is_held());
+    assert!(a.try_acquire(&second).await.expect("Acquire").is_held());
+
+    a.release(&first).await.expect("Release");
+    assert_eq!(
+        b.try_acquire(&first).await.expect("Acquire"),
+        LeaseStatus::Acquired
+    );
+
+    a.release_all().await.expect("Release all");
+    assert!(a.held().is_empty());
+    assert_eq!(
+        b.try_acquire(&second).await.expect("Acquire"),
+        LeaseStatus::Acquired
+    );
+
+    // Releasing only affects our own leases
+    a.release_all().await.expect("Release all");
+    assert_eq!(
+        b.try_acquire(&first).await.expect("Renew"),
+        LeaseStatus::Renewed
+    );
+
+    cleanup_lease(&first);
+    cleanup_lease(&second);
+}
@@ -0,0 +1,129 @@
+//! Tests for the Telegram platform against a local mock Bot API.
+
+use axum::extract::{Path, State};
+use axum::routing::post;
+use axum::{Json, Router};
+use botticelli_actor::{
+    ActorErrorKind, Platform, PlatformCapability, PlatformMessage, TelegramPlatform,
+};
+use botticelli_social::TelegramClient;
+use serde_json::{Value, json};
+use std::sync::{Arc, Mutex};
+
+const TOKEN: &str = "123:abc";
+
+type Shared = Arc<Mutex<Vec<(String, Value)>>>;
+
+async fn bot_api(
+    State(state): State<Shared>,
+    Path((token, method)): Path<(String, String)>,
+    Json(body): Json<Value>,
+) -> Json<Value> {
+    if token != format!("bot{TOKEN}") {
+        return Json(json!({ "ok": false, "error_code": 401, "description": "Unauthorized" }));
+    }
+    let mut calls = state.lock().unwrap();
+    calls.push((method.clone(), body));
+    let id = calls.len();
+    Json(json!({
+        "ok": true,
+        "result": match method.as_str() {
+            "getMe" => json!({ "id": 42, "is_bot": true, "first_name": "Bot" }),
+            _ => json!({
+                "message_id": id,
+                "date": 1_735_689_600,
+                "chat": { "id": -100, "type": "channel" },
+            }),
+        }
+    }))
+}
+
+/// Start a mock Bot API server, returning a client for it and recorded calls.
+async fn mock_client(token: &str) -> (Arc<TelegramClient>, Shared) {
+    let state = Shared::default();
+    let app = Router::new()
+        .route("/:token/:method", post(bot_api))
+        .with_state(state.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    let client = TelegramClient::with_api_url(format!("http://{addr}"), token).unwrap();
+    (Arc::new(client), state)
+}
+
+fn message(text: &str, media_urls: Vec<String>) -> PlatformMessage {
+    PlatformMessage {
+        text: text.to_string(),
+        media_urls,
+        content_id: None,
+    }
+}
+
+#[test]
+fn test_configuration() {
+    let platform = TelegramPlatform::new(TOKEN, "@news").unwrap();
+    assert_eq!(platform.platform_name(), "telegram");
+    assert_eq!(platform.chat_id(), "@news");
+    assert!(
+        platform
+            .capabilities()
+            .contains(&PlatformCapability::Threads)
+    );
+
+    let err = TelegramPlatform::new(TOKEN, "news").unwrap_err();
+    assert!(matches!(err.kind, ActorErrorKind::ValidationFailed(_)));
+    assert!(TelegramPlatform::new("", "@news").is_err());
+}
+
+#[tokio::test]
+async fn test_post_with_caption() {
+    let (client, calls) = mock_client(TOKEN).await;
+    let platform = TelegramPlatform::with_client(client, "-100").unwrap();
+
+    let metadata = platform
+        .post(&message(
+            "Look at this",
+            vec!["https://example.com/cat.png".to_string()],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(metadata["message_id"], "1");
+    assert_eq!(metadata["chat_id"], "-100");
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].0, "sendPhoto");
+    assert_eq!(calls[0].1["caption"], "Look at this");
+}
+
+#[tokio::test]
+async fn test_long_post_becomes_reply_chain() {
+    let (client, calls) = mock_client(TOKEN).await;
+    let platform = TelegramPlatform::with_client(client, "-100").unwrap();
+
+    let metadata = platform
+        .post(&message(&"word ".repeat(1000), vec![]))
+        .await
+        .unwrap();
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.len(), 2);
+    assert!(calls[0].1.get("reply_parameters").is_none());
+    assert_eq!(calls[1].1["reply_parameters"]["message_id"], 1);
+    assert_eq!(metadata["message_ids"], "1,2");
+}
+
+#[tokio::test]
+async fn test_verify_connection() {
+    let (client, _) = mock_client(TOKEN).await;
+    let platform = TelegramPlatform::with_client(client, "-100").unwrap();
+    assert!(platform.verify_connection().await.is_ok());
+
+    let (client, _) = mock_client("wrong").await;
+    let platform = TelegramPlatform::with_client(client, "-100").unwrap();
+    let err = platform.verify_connection().await.unwrap_err();
+    assert!(matches!(err.kind, ActorErrorKind::AuthenticationFailed(_)));
+}
@@ -0,0 +1,432 @@
+//! Tests for WASM skill plugins.
+
+#![cfg(feature = "wasm-plugins")]
+
+use botticelli_actor::{
+    ActorErrorKind, NoOpPlatform, PluginConfig, Skill, SkillContext, SkillContextBuilder,
+    SkillRegistry, WasmSkill,
+};
+use botticelli_database::{DatabaseConnectionManager, DatabasePool};
+use serde_json::{Value as JsonValue, json};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Build a skill component in WAT.
+///
+/// The component imports `knowledge`, `post` and `http` from the host interface and
+/// exports the skill world; `execute` runs `body` with the context at
+/// `$ptr`/`$len` and must return a pointer to a `result<string, string>`.
+/// Memory holds the name at 16, `"content"` at 48, `"boom"` at 64, `"GET"` at 80,
+/// [`HTTPS_MEDIA`] at 256 and [`FILE_MEDIA`] at 512.
+fn component(name: &str, body: &str) -> String {
+    format!(
+        r#"
+(component
+  (import "botticelli:skill/host@0.1.0" (instance $host
+    (export "knowledge" (func (param "table" string) (result (result string (error string)))))
+    (export "post" (func (param "message" string) (result (result string (error string)))))
+    (export "http" (func (param "method" string) (param "url" string)
+      (param "headers" (list (tuple string string))) (param "body" (option string))
+      (result (result (tuple u16 string) (error string)))))))
+  (core module $libc
+    (memory (export "memory") 1)
+    (global $heap (mut i32) (i32.const 4096))
+    (func (export "cabi_realloc") (param i32 i32 i32 i32) (result i32)
+      (local $ptr i32)
+      (local.set $ptr
+        (i32.and
+          (i32.add (global.get $heap) (i32.sub (local.get 2) (i32.const 1)))
+          (i32.xor (i32.sub (local.get 2) (i32.const 1)) (i32.const -1))))
+      (global.set $heap (i32.add (local.get $ptr) (local.get 3)))
+      (local.get $ptr)))
+  (core instance $libc (instantiate $libc))
+  (core func $knowledge (canon lower (func $host "knowledge")
+    (memory $libc "memory") (realloc (func $libc "cabi_realloc"))))
+  (core func $post (canon lower (func $host "post")
+    (memory $libc "memory") (realloc (func $libc "cabi_realloc"))))
+  (core func $http (canon lower (func $host "http")
+    (memory $libc "memory") (realloc (func $libc "cabi_realloc"))))
+  (core module $skill
+    (import "libc" "memory" (memory 1))
+    (import "host" "knowledge" (func $knowledge (param i32 i32 i32)))
+    (import "host" "post" (func $post (param i32 i32 i32)))
+    (import "host" "http"
+      (func $http (param i32 i32 i32 i32 i32 i32 i32 i32 i32 i32)))
+    (data (i32.const 16) "{name}")
+    (data (i32.const 48) "content")
+    (data (i32.const 64) "boom")
+    (data (i32.const 80) "GET")
+    (data (i32.const 256) "{https_media}")
+    (data (i32.const 512) "{file_media}")
+    (func (export "name") (result i32)
+      (i32.store (i32.const 96) (i32.const 16))
+      (i32.store (i32.const 100) (i32.const {len}))
+      (i32.const 96))
+    (func (export "execute") (param $ptr i32) (param $len i32) (result i32)
+      {body}))
+  (core instance $skill (instantiate $skill
+    (with "libc" (instance $libc))
+    (with "host" (instance
+      (export "knowledge" (func $knowledge))
+      (export "post" (func $post))
+      (export "http" (func $http))))))
+  (func (export "name") (result string)
+    (canon lift (core func $skill "name") (memory $libc "memory")))
+  (func (export "description") (result string)
+    (canon lift (core func $skill "name") (memory $libc "memory")))
+  (func (export "execute") (param "context" string) (result (result string (error string)))
+    (canon lift (core func $skill "execute")
+      (memory $libc "memory") (realloc (func $libc "cabi_realloc"))))
+)
+"#,
+        name = name,
+        len = name.len(),
+        body = body,
+        https_media = HTTPS_MEDIA.replace('"', "\\\""),
+        file_media = FILE_MEDIA.replace('"', "\\\""),
+    )
+}
+
+/// Message with media on `cdn.example.com`.
+const HTTPS_MEDIA: &str = r#"{"text":"hi","media_urls":["https://cdn.example.com/venus.png"]}"#;
+
+/// Message with a local media file.
+const FILE_MEDIA: &str = r#"{"text":"hi","media_urls":["file:///etc/passwd"]}"#;
+
+/// Post the message at `offset` and return the host's answer.
+fn post(offset: usize, message: &str) -> String {
+    format!(
+        "
+      (call $post (i32.const {offset}) (i32.const {len}) (i32.const 128))
+      (i32.const 128)",
+        offset = offset,
+        len = message.len(),
+    )
+}
+
+/// GET `url` and return the response body.
+///
+/// The URL is written to memory at 1024 before the call; the body string is
+/// moved into place when the request succeeds.
+fn http_get(url: &str) -> String {
+    let store_url: String = url
+        .bytes()
+        .enumerate()
+        .map(|(i, b)| format!("(i32.store8 (i32.const {}) (i32.const {}))\n", 1024 + i, b))
+        .collect();
+    format!(
+        "
+      {store_url}
+      (call $http (i32.const 80) (i32.const 3) (i32.const 1024) (i32.const {len})
+        (i32.const 0) (i32.const 0) (i32.const 0) (i32.const 0) (i32.const 0) (i32.const 128))
+      (if (i32.eqz (i32.load8_u (i32.const 128)))
+        (then
+          (i32.store (i32.const 132) (i32.load (i32.const 136)))
+          (i32.store (i32.const 136) (i32.load (i32.const 140)))))
+      (i32.const 128)",
+        store_url = store_url,
+        len = url.len(),
+    )
+}
+
+/// Return the context unchanged.
+const ECHO: &str = "
+      (i32.store8 (i32.const 128) (i32.const 0))
+      (i32.store (i32.const 132) (local.get $ptr))
+      (i32.store (i32.const 136) (local.get $len))
+      (i32.const 128)";
+
+/// Return the host's answer for the `content` knowledge table.
+const KNOWLEDGE: &str = "
+      (call $knowledge (i32.const 48) (i32.const 7) (i32.const 128))
+      (i32.const 128)";
+
+/// Return an error.
+const FAIL: &str = "
+      (i32.store8 (i32.const 128) (i32.const 1))
+      (i32.store (i32.const 132) (i32.const 64))
+      (i32.store (i32.const 136) (i32.const 4))
+      (i32.const 128)";
+
+/// Return a string that is not JSON.
+const NOT_JSON: &str = "
+      (i32.store8 (i32.const 128) (i32.const 0))
+      (i32.store (i32.const 132) (i32.const 64))
+      (i32.store (i32.const 136) (i32.const 4))
+      (i32.const 128)";
+
+/// Never return.
+const SPIN: &str = "
+      (loop $forever (br $forever))
+      (i32.const 0)";
+
+fn write_plugin(dir: &Path, file: &str, name: &str, body: &str) -> PathBuf {
+    let path = dir.join(file);
+    std::fs::write(&path, component(name, body)).expect("Write plugin");
+    path
+}
+
+fn plugin_config(dir: &Path) -> PluginConfig {
+    PluginConfig::builder()
+        .directory(dir)
+        .build()
+        .expect("Valid plugin config")
+}
+
+fn context(knowledge: HashMap<String, Vec<JsonValue>>) -> SkillContext {
+    let mut config = HashMap::new();
+    config.insert("mode".to_string(), "test".to_string());
+    let mut outputs = HashMap::new();
+    outputs.insert("select".to_string(), json!({"candidates": [1]}));
+
+    SkillContextBuilder::default()
+        .knowledge(knowledge)
+        .config(config)
+        .platform(Arc::new(NoOpPlatform::new()) as Arc<dyn botticelli_actor::Platform>)
+        .db_pool(
+            DatabasePool::builder()
+                .build_unchecked(DatabaseConnectionManager::new("postgres://unused")),
+        )
+        .outputs(outputs)
+        .inputs_from(vec!["select".to_string()])
+        .build()
+        .expect("Valid context")
+}
+
+#[tokio::test]
+async fn test_plugin_receives_context_as_json() {
+    let dir = tempfile::tempdir().expect("Temp dir");
+    let path = write_plugin(dir.path(), "echo.wat", "echo", ECHO);
+
+    let skill = WasmSkill::from_file(&path, &plugin_config(dir.path()))
+        .await
+        .expect("Load plugin");
+    assert_eq!(skill.name(), "echo");
+    assert_eq!(skill.description(), "echo");
+
+    let mut knowledge = HashMap::new();
+    knowledge.insert("content".to_string(), vec![json!({"id": 1})]);
+
+    let output = skill.execute(&context(knowledge)).await.expect("Execute");
+    assert_eq!(output.skill_name(), "echo");
+    let data = output.data();
+    assert_eq!(data["config"]["mode"], "test");
+    assert_eq!(data["input"], json!({"candidates": [1]}));
+    assert_eq!(data["knowledge_tables"], json!(["content"]));
+    // Rows are only available through the knowledge host function
+    assert!(data.get("knowledge").is_none());
+}
+
+#[tokio::test]
+async fn test_plugin_reads_loaded_knowledge_only() {
+    let dir = tempfile::tempdir().expect("Temp dir");
+    let path = write_plugin(dir.path(), "reader.wat", "reader", KNOWLEDGE);
+    let skill = WasmSkill::from_file(&path, &plugin_config(dir.path()))
+        .await
+        .expect("Load plugin");
+
+    let mut knowledge = HashMap::new();
+    knowledge.insert(
+        "content".to_string(),
+        vec![json!({"id": 1}), json!({"id": 2})],
+    );
+    let output = skill.execute(&context(knowledge)).await.expect("Execute");
+    assert_eq!(output.data(), &json!([{"id": 1}, {"id": 2}]));
+
+    let error = skill
+        .execute(&context(HashMap::new()))
+        .await
+        .expect_err("Table not loaded");
+    assert!(matches!(&error.kind, ActorErrorKind::Plugin(msg) if msg.contains("not loaded")));
+}
+
+#[tokio::test]
+async fn test_plugin_errors_are_reported() {
+    let dir = tempfile::tempdir().expect("Temp dir");
+    let config = plugin_config(dir.path());
+
+    let failing = WasmSkill::from_file(write_plugin(dir.path(), "f.wat", "failing", FAIL), &config)
+        .await
+        .expect("Load plugin");
+    let error = failing
+        .execute(&context(HashMap::new()))
+        .await
+        .expect_err("Plugin error");
+    assert_eq!(
+        error.kind,
+        ActorErrorKind::Plugin("failing: boom".to_string())
+    );
+    assert!(!error.is_recoverable());
+
+    let garbled = WasmSkill::from_file(
+        write_plugin(dir.path(), "g.wat", "garbled", NOT_JSON),
+        &config,
+    )
+    .await
+    .expect("Load plugin");
+    let error = garbled
+        .execute(&context(HashMap::new()))
+        .await
+        .expect_err("Invalid JSON");
+    assert!(matches!(error.kind, ActorErrorKind::JsonError(_)));
+}
+
+#[tokio::test]
+async fn test_plugin_media_urls_limited_to_allowed_hosts() {
+    let dir = tempfile::tempdir().expect("Temp dir");
+    let config = |hosts: &[&str]| {
+        PluginConfig::builder()
+            .directory(dir.path())
+            .allowed_hosts(hosts.iter().map(|h| h.to_string()).collect::<Vec<_>>())
+            .allow_post(true)
+            .build()
+            .expect("Valid plugin config")
+    };
+    let https = write_plugin(dir.path(), "https.wat", "https", &post(256, HTTPS_MEDIA));
+    let file = write_plugin(dir.path(), "file.wat", "file", &post(512, FILE_MEDIA));
+
+    let skill = WasmSkill::from_file(&https, &config(&["cdn.example.com"]))
+        .await
+        .expect("Load plugin");
+    skill
+        .execute(&context(HashMap::new()))
+        .await
+        .expect("Allowed media host");
+
+    let skill = WasmSkill::from_file(&https, &config(&["api.example.com"]))
+        .await
+        .expect("Load plugin");
+    let error = skill
+        .execute(&context(HashMap::new()))
+        .await
+        .expect_err("Media host not allowed");
+    assert!(
+        matches!(&error.kind, ActorErrorKind::Plugin(msg) if msg.contains("Media URL rejected"))
+    );
+
+    // Local files are never posted, whatever the allowed hosts
+    let skill = WasmSkill::from_file(&file, &config(&["*.example.com", ""]))
+        .await
+        .expect("Load plugin");
+    let error = skill
+        .execute(&context(HashMap::new()))
+        .await
+        .expect_err("Local media");
+    assert!(matches!(&error.kind, ActorErrorKind::Plugin(msg) if msg.contains("scheme 'file'")));
+}
+
+#[tokio::test]
+async fn test_plugin_http_response_size_is_capped() {
+    let app = axum::Router::new()
+        .route("/small", axum::routing::get(|| async { r#"{"ok":true}"# }))
+        .route(
+            "/large",
+            axum::routing::get(|| async { format!(r#"{{"pad":"{}"}}"#, "x".repeat(4096)) }),
+        );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    let dir = tempfile::tempdir().expect("Temp dir");
+    let config = PluginConfig::builder()
+        .directory(dir.path())
+        .allowed_hosts(vec!["127.0.0.1".to_string()])
+        .max_response_kb(1usize)
+        .build()
+        .expect("Valid plugin config");
+
+    let small = write_plugin(
+        dir.path(),
+        "small.wat",
+        "small",
+        &http_get(&format!("http://{}/small", addr)),
+    );
+    let skill = WasmSkill::from_file(&small, &config)
+        .await
+        .expect("Load plugin");
+    let output = skill
+        .execute(&context(HashMap::new()))
+        .await
+        .expect("Small response");
+    assert_eq!(output.data(), &json!({"ok": true}));
+
+    let large = write_plugin(
+        dir.path(),
+        "large.wat",
+        "large",
+        &http_get(&format!("http://{}/large", addr)),
+    );
+    let skill = WasmSkill::from_file(&large, &config)
+        .await
+        .expect("Load plugin");
+    let error = skill
+        .execute(&context(HashMap::new()))
+        .await
+        .expect_err("Response too large");
+    assert!(
+        matches!(&error.kind, ActorErrorKind::Plugin(msg) if msg.contains("exceeds 1024 bytes"))
+    );
+}
+
+#[tokio::test]
+async fn test_plugin_fuel_limit_stops_runaway_loop() {
+    let dir = tempfile::tempdir().expect("Temp dir");
+    let path = write_plugin(dir.path(), "spin.wat", "spin", SPIN);
+    let config = PluginConfig::builder()
+        .directory(dir.path())
+        .fuel(100_000u64)
+        .build()
+        .expect("Valid plugin config");
+
+    let skill = WasmSkill::from_file(&path, &config)
+        .await
+        .expect("Load plugin");
+    let error = skill
+        .execute(&context(HashMap::new()))
+        .await
+        .expect_err("Out of fuel");
+    assert!(matches!(error.kind, ActorErrorKind::Plugin(_)));
+}
+
+#[tokio::test]
+async fn test_registry_loads_plugin_directory() {
+    let dir = tempfile::tempdir().expect("Temp dir");
+    write_plugin(dir.path(), "b.wat", "second", ECHO);
+    write_plugin(dir.path(), "a.wat", "first", ECHO);
+    std::fs::write(dir.path().join("README.md"), "not a plugin").expect("Write file");
+
+    let mut registry = SkillRegistry::new();
+    let names = registry
+        .load_plugins(&plugin_config(dir.path()))
+        .await
+        .expect("Load plugins");
+
+    assert_eq!(names, vec!["first".to_string(), "second".to_string()]);
+    assert_eq!(registry.len(), 2);
+    assert!(registry.get("first").is_some());
+}
+
+#[tokio::test]
+async fn test_invalid_plugin_fails_to_load() {
+    let dir = tempfile::tempdir().expect("Temp dir");
+    let path = dir.path().join("broken.wasm");
+    std::fs::write(&path, b"\0asm garbage").expect("Write file");
+
+    let error = WasmSkill::from_file(&path, &plugin_config(dir.path()))
+        .await
+        .err()
+        .expect("Load should fail");
+    assert!(matches!(&error.kind, ActorErrorKind::Plugin(msg) if msg.contains("broken.wasm")));
+
+    let missing = tempfile::tempdir()
+        .expect("Temp dir")
+        .path()
+        .join("missing");
+    let mut registry = SkillRegistry::new();
+    let error = registry
+        .load_plugins(&plugin_config(&missing))
+        .await
+        .expect_err("Missing directory");
+    assert!(matches!(error.kind, ActorErrorKind::FileIo { .. }));
+}
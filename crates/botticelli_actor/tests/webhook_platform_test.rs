@@ -0,0 +1,121 @@
+//! Tests for the webhook platform against a local mock endpoint.
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::post;
+use axum::{Json, Router};
+use botticelli_actor::{
+    ActorErrorKind, Platform, PlatformCapability, PlatformMessage, WebhookPlatform,
+};
+use botticelli_social::WebhookConfig;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+type Shared = Arc<Mutex<Vec<Value>>>;
+
+/// Answers `/ok` with a Slack-like body and `/status/:code` with that status.
+async fn hook(
+    State(state): State<Shared>,
+    Path(path): Path<String>,
+    Json(body): Json<Value>,
+) -> (StatusCode, Json<Value>) {
+    state.lock().unwrap().push(body);
+    let status = path
+        .strip_prefix("status/")
+        .and_then(|code| code.parse().ok())
+        .and_then(|code| StatusCode::from_u16(code).ok())
+        .unwrap_or(StatusCode::OK);
+    (
+        status,
+        Json(json!({ "ok": true, "ts": "1735689600.000100" })),
+    )
+}
+
+/// Start a mock endpoint, returning its base URL and recorded bodies.
+async fn mock_server() -> (String, Shared) {
+    let state = Shared::default();
+    let app = Router::new()
+        .route("/*path", post(hook))
+        .with_state(state.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    (format!("http://{addr}"), state)
+}
+
+fn config(url: String) -> WebhookConfig {
+    WebhookConfig::builder()
+        .url(url)
+        .max_retries(1u32)
+        .retry_backoff_ms(1u64)
+        .body(json!({ "text": "{{text}}", "images": "{{media_urls}}" }))
+        .capture(HashMap::from([("ts".to_string(), "/ts".to_string())]))
+        .build()
+        .unwrap()
+}
+
+fn message(text: &str, media_urls: Vec<String>) -> PlatformMessage {
+    PlatformMessage {
+        text: text.to_string(),
+        media_urls,
+        content_id: None,
+    }
+}
+
+#[test]
+fn test_configuration() {
+    let platform = WebhookPlatform::new(config("https://example.com/hook".to_string())).unwrap();
+    assert_eq!(platform.platform_name(), "webhook");
+    assert!(platform.capabilities().contains(&PlatformCapability::Text));
+
+    let err = WebhookPlatform::new(config("example.com/hook".to_string())).unwrap_err();
+    assert!(matches!(err.kind, ActorErrorKind::InvalidConfiguration(_)));
+}
+
+#[tokio::test]
+async fn test_post_renders_template_and_captures() {
+    let (base, bodies) = mock_server().await;
+    let platform = WebhookPlatform::new(config(format!("{base}/ok"))).unwrap();
+
+    let metadata = platform
+        .post(&message(
+            "New painting",
+            vec!["https://example.com/venus.png".to_string()],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(metadata["status"], "200");
+    assert_eq!(metadata["attempts"], "1");
+    assert_eq!(metadata["ts"], "1735689600.000100");
+
+    let bodies = bodies.lock().unwrap();
+    assert_eq!(
+        bodies[0],
+        json!({ "text": "New painting", "images": ["https://example.com/venus.png"] })
+    );
+}
+
+#[tokio::test]
+async fn test_post_maps_errors() {
+    let (base, bodies) = mock_server().await;
+
+    let platform = WebhookPlatform::new(config(format!("{base}/status/503"))).unwrap();
+    let err = platform.post(&message("hi", vec![])).await.unwrap_err();
+    assert!(matches!(err.kind, ActorErrorKind::PlatformTemporary(_)));
+    assert_eq!(bodies.lock().unwrap().len(), 2);
+
+    let platform = WebhookPlatform::new(config(format!("{base}/status/403"))).unwrap();
+    let err = platform.post(&message("hi", vec![])).await.unwrap_err();
+    assert!(matches!(err.kind, ActorErrorKind::AuthenticationFailed(_)));
+
+    let platform = WebhookPlatform::new(config(format!("{base}/status/422"))).unwrap();
+    let err = platform.post(&message("hi", vec![])).await.unwrap_err();
+    assert!(matches!(err.kind, ActorErrorKind::PlatformPermanent(_)));
+
+    let err = platform.post(&message("  ", vec![])).await.unwrap_err();
+    assert!(matches!(err.kind, ActorErrorKind::ValidationFailed(_)));
+}
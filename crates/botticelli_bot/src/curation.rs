@@ -1,5 +1,10 @@
 use crate::config::CurationConfig;
 use crate::metrics::BotMetrics;
+use botticelli_database::{
+    ContentStatus, LEGACY_PENDING_STATUS, list_content, transition_content_status,
+    with_pooled_connection,
+};
+use botticelli_error::BotticelliError;
 use botticelli_interface::BotticelliDriver;
 use botticelli_narrative::NarrativeExecutor;
 use derive_getters::Getters;
@@ -10,6 +15,12 @@ use std::time::Instant;
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, instrument};
 
+/// Table the curation narrative stores selected posts in.
+pub(crate) const APPROVED_POSTS_TABLE: &str = "approved_discord_posts";
+
+/// Actor name recorded in the content status history.
+const CURATION_ACTOR: &str = "curation_bot";
+
 /// Message types for curation bot.
 #[derive(Debug)]
 pub enum CurationMessage {
@@ -93,7 +104,11 @@ impl<D: BotticelliDriver> CurationBot<D> {
                     )
                     .await?;
 
-                info!(batch_size = *self.config.batch_size(), "Curated batch");
+                let approved = self.approve_curated_content().await?;
+                info!(
+                    batch_size = *self.config.batch_size(),
+                    approved, "Curated batch"
+                );
             }
 
             Ok::<(), Box<dyn std::error::Error>>(())
@@ -120,6 +135,48 @@ impl<D: BotticelliDriver> CurationBot<D> {
         }
     }
 
+    /// Approves posts the curation narrative just stored.
+    ///
+    /// The narrative writes its picks to [`APPROVED_POSTS_TABLE`], where they
+    /// start out as generated (or as `'pending'` in tables created before the
+    /// content lifecycle); this records the approval in their history.
+    async fn approve_curated_content(&self) -> Result<usize, Box<dyn std::error::Error>> {
+        let reason = format!(
+            "Selected by curation narrative '{}'",
+            self.config.narrative_name()
+        );
+        let limit = *self.config.batch_size();
+
+        let approved = with_pooled_connection(&self.database, move |conn| {
+            let mut approved = 0;
+            for status in [ContentStatus::Generated.as_str(), LEGACY_PENDING_STATUS] {
+                loop {
+                    let curated = list_content(conn, APPROVED_POSTS_TABLE, Some(status), limit)?;
+                    if curated.is_empty() {
+                        break;
+                    }
+
+                    for id in curated.iter().filter_map(|row| row.get("id")?.as_i64()) {
+                        transition_content_status(
+                            conn,
+                            APPROVED_POSTS_TABLE,
+                            id,
+                            ContentStatus::Approved,
+                            CURATION_ACTOR,
+                            Some(&reason),
+                        )?;
+                        approved += 1;
+                    }
+                }
+            }
+
+            Ok::<_, BotticelliError>(approved)
+        })
+        .await?;
+
+        Ok(approved)
+    }
+
     async fn check_pending_count(&self) -> Result<usize, Box<dyn std::error::Error>> {
         // Check if potential_discord_posts table has any content
         let mut conn = self.database.get()?;
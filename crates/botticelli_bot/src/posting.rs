@@ -1,5 +1,10 @@
 use crate::config::PostingConfig;
+use crate::curation::APPROVED_POSTS_TABLE;
 use crate::metrics::BotMetrics;
+use botticelli_database::{
+    ContentStatus, list_content, transition_content_status, with_pooled_connection,
+};
+use botticelli_error::BotticelliError;
 use botticelli_interface::BotticelliDriver;
 use botticelli_narrative::NarrativeExecutor;
 use derive_getters::Getters;
@@ -11,6 +16,12 @@ use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, instrument};
 
+/// Actor name recorded in the content status history.
+const POSTING_ACTOR: &str = "posting_bot";
+
+/// Most scheduled posts returned to the queue after one failed run.
+const MAX_RELEASED_POSTS: usize = 100;
+
 /// Message types for posting bot.
 #[derive(Debug)]
 pub enum PostingMessage {
@@ -111,6 +122,9 @@ impl<D: BotticelliDriver> PostingBot<D> {
                     error = ?e,
                     "Posting failed"
                 );
+                if let Err(release) = self.release_scheduled_content(&e.to_string()).await {
+                    error!(error = ?release, "Failed to return scheduled content to approved");
+                }
                 Err(e.into())
             }
         }
@@ -123,15 +137,53 @@ impl<D: BotticelliDriver> PostingBot<D> {
         use diesel::prelude::*;
         use diesel::sql_types::BigInt;
 
-        let count: i64 = diesel::select(sql::<BigInt>(
-            "COUNT(*) FROM approved_discord_posts WHERE posted_at IS NULL",
-        ))
+        let count: i64 = diesel::select(sql::<BigInt>(&format!(
+            "COUNT(*) FROM {} WHERE review_status = '{}'",
+            APPROVED_POSTS_TABLE,
+            ContentStatus::Approved
+        )))
         .get_result(&mut conn)
         .unwrap_or(0);
 
         Ok(count > 0)
     }
 
+    /// Returns posts a failed run left scheduled to the approved queue.
+    ///
+    /// The posting narrative schedules a post before sending it; when the run
+    /// fails part-way, this records the failure so the post is retried.
+    async fn release_scheduled_content(
+        &self,
+        error: &str,
+    ) -> Result<usize, Box<dyn std::error::Error>> {
+        let reason = format!("Posting failed: {}", error);
+
+        let released = with_pooled_connection(&self.database, move |conn| {
+            let scheduled = list_content(
+                conn,
+                APPROVED_POSTS_TABLE,
+                Some(ContentStatus::Scheduled.as_str()),
+                MAX_RELEASED_POSTS,
+            )?;
+
+            for id in scheduled.iter().filter_map(|row| row.get("id")?.as_i64()) {
+                transition_content_status(
+                    conn,
+                    APPROVED_POSTS_TABLE,
+                    id,
+                    ContentStatus::Approved,
+                    POSTING_ACTOR,
+                    Some(&reason),
+                )?;
+            }
+
+            Ok::<_, BotticelliError>(scheduled.len())
+        })
+        .await?;
+
+        Ok(released)
+    }
+
     /// Calculates next post time with jitter.
     pub fn calculate_next_post_time(&self) -> Duration {
         let base = Duration::from_secs(*self.config.base_interval_hours() * 3600);
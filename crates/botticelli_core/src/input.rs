@@ -1,6 +1,6 @@
 //! Input types for LLM requests.
 
-use crate::MediaSource;
+use crate::{MediaSource, WhereClause};
 use serde::{Deserialize, Serialize};
 
 /// Controls how an input is retained in conversation history.
@@ -146,8 +146,8 @@ pub enum Input {
         table_name: String,
         /// Specific columns to select (default: all)
         columns: Option<Vec<String>>,
-        /// WHERE clause for filtering
-        where_clause: Option<String>,
+        /// Row filter, structured or raw SQL
+        where_clause: Option<WhereClause>,
         /// Maximum number of rows
         limit: Option<u32>,
         /// Offset for pagination
@@ -13,6 +13,7 @@ mod observability;
 mod output;
 mod request;
 mod role;
+mod table_filter;
 
 pub use budget::{BudgetConfig, BudgetConfigBuilder};
 pub use input::{HistoryRetention, Input, TableFormat};
@@ -25,3 +26,4 @@ pub use observability::{
 pub use output::{Output, ToolCall, ToolCallBuilder};
 pub use request::{GenerateRequest, GenerateRequestBuilder, GenerateResponse};
 pub use role::Role;
+pub use table_filter::{FilterCondition, FilterOperators, FilterValue, TableFilter, WhereClause};
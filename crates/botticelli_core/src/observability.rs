@@ -1,3 +1,4 @@
+use botticelli_secrets::RedactingMakeWriter;
 use opentelemetry::{KeyValue, global, trace::TracerProvider};
 use opentelemetry_sdk::{Resource, metrics::SdkMeterProvider, trace::SdkTracerProvider};
 use opentelemetry_stdout::SpanExporter;
@@ -199,11 +200,13 @@ pub fn init_observability_with_config(
     let fmt_layer = if config.json_logs {
         tracing_subscriber::fmt::layer()
             .json()
+            .with_writer(RedactingMakeWriter::new(std::io::stdout))
             .with_target(true)
             .with_level(true)
             .boxed()
     } else {
         tracing_subscriber::fmt::layer()
+            .with_writer(RedactingMakeWriter::new(std::io::stdout))
             .with_target(true)
             .with_level(true)
             .boxed()
@@ -0,0 +1,173 @@
+//! Structured row filters for table inputs.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Row filter of a table input.
+///
+/// In narrative TOML, `where` is either a structured table, which is
+/// compiled to bound SQL parameters, or a raw SQL condition for trusted
+/// narratives.
+///
+/// ```toml
+/// where = { status = "approved", rating = { gte = 4 }, tags = { contains = "lore" } }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum WhereClause {
+    /// Raw SQL condition, without the `WHERE` keyword
+    Sql(String),
+    /// Structured conditions on columns
+    Filter(TableFilter),
+}
+
+impl From<TableFilter> for WhereClause {
+    fn from(filter: TableFilter) -> Self {
+        Self::Filter(filter)
+    }
+}
+
+/// Conditions on columns, all of which must hold.
+///
+/// # Example
+///
+/// ```
+/// use botticelli_core::{FilterCondition, FilterOperators, FilterValue, TableFilter};
+///
+/// let filter = TableFilter::new()
+///     .with("status", FilterCondition::Equals("approved".into()))
+///     .with(
+///         "rating",
+///         FilterCondition::Operators(Box::new(FilterOperators {
+///             gte: Some(FilterValue::Integer(4)),
+///             ..Default::default()
+///         })),
+///     );
+/// assert_eq!(filter.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TableFilter(BTreeMap<String, FilterCondition>);
+
+impl TableFilter {
+    /// Create an empty filter, which matches every row.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a condition on a column, replacing any earlier one.
+    pub fn with(mut self, column: impl Into<String>, condition: FilterCondition) -> Self {
+        self.0.insert(column.into(), condition);
+        self
+    }
+
+    /// Conditions by column name, in column order.
+    pub fn conditions(&self) -> impl Iterator<Item = (&str, &FilterCondition)> {
+        self.0
+            .iter()
+            .map(|(column, condition)| (column.as_str(), condition))
+    }
+
+    /// Number of filtered columns.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the filter has no conditions.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Condition on one column.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FilterCondition {
+    /// Column equals the value: `status = "approved"`
+    Equals(FilterValue),
+    /// Column satisfies every given operator: `rating = { gte = 4 }`
+    Operators(Box<FilterOperators>),
+}
+
+/// Comparison operators on one column; all that are set must hold.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FilterOperators {
+    /// Equal to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<FilterValue>,
+    /// Not equal to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ne: Option<FilterValue>,
+    /// Greater than
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gt: Option<FilterValue>,
+    /// Greater than or equal to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gte: Option<FilterValue>,
+    /// Less than
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lt: Option<FilterValue>,
+    /// Less than or equal to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lte: Option<FilterValue>,
+    /// One of the values
+    #[serde(rename = "in", default, skip_serializing_if = "Option::is_none")]
+    pub one_of: Option<Vec<FilterValue>>,
+    /// None of the values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_in: Option<Vec<FilterValue>>,
+    /// Array or JSON column holds the value, or text column contains it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains: Option<FilterValue>,
+    /// Text column matches a SQL `LIKE` pattern
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like: Option<String>,
+    /// Column is (`true`) or is not (`false`) NULL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_null: Option<bool>,
+}
+
+/// Literal value in a filter condition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FilterValue {
+    /// Boolean
+    Bool(bool),
+    /// Integer
+    Integer(i64),
+    /// Floating-point number
+    Float(f64),
+    /// Text; also used for dates and timestamps
+    Text(String),
+}
+
+impl From<&str> for FilterValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<String> for FilterValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<i64> for FilterValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<f64> for FilterValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<bool> for FilterValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
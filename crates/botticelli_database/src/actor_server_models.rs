@@ -85,6 +85,38 @@ pub struct ActorServerExecutionRow {
     pub created_at: NaiveDateTime,
 }
 
+/// Database row for actor_server_leases table.
+#[derive(Debug, Clone, Queryable, QueryableByName, Identifiable, Selectable)]
+#[diesel(table_name = crate::schema::actor_server_leases)]
+#[diesel(primary_key(task_id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ActorServerLeaseRow {
+    /// Leased task identifier
+    pub task_id: String,
+    /// Replica holding the lease
+    pub owner: String,
+    /// When the current owner acquired the lease
+    pub acquired_at: NaiveDateTime,
+    /// When the lease lapses unless renewed
+    pub expires_at: NaiveDateTime,
+}
+
+/// Database row for actor_crosspost_records table.
+#[derive(Debug, Clone, Queryable, Insertable, Identifiable, Selectable)]
+#[diesel(table_name = crate::schema::actor_crosspost_records)]
+#[diesel(primary_key(actor_name, content_id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ActorCrossPostRecordRow {
+    /// Actor that posted the content
+    pub actor_name: String,
+    /// Content row identifier
+    pub content_id: String,
+    /// Delivery per destination name, as JSON
+    pub deliveries: serde_json::Value,
+    /// Time of the most recent delivery attempt
+    pub updated_at: NaiveDateTime,
+}
+
 /// Insertable struct for actor_server_executions table with builder pattern.
 #[derive(Debug, Clone, Insertable, Getters, Builder)]
 #[diesel(table_name = crate::schema::actor_server_executions)]
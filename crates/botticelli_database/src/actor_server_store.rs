@@ -0,0 +1,750 @@
+//! Actor server state, execution history, leases and cross-post records.
+//!
+//! The actor server keeps its bookkeeping in the same database as the
+//! content it posts, so these queries are written against
+//! [`ContentConnection`] and run unchanged on PostgreSQL and SQLite.
+//! Timestamps are bound as UTC text; on PostgreSQL they are cast to
+//! `timestamptz`, on SQLite they are stored as `YYYY-MM-DD HH:MM:SS.ffffff`
+//! so they sort and compare as text.
+
+use crate::content_management::parse_timestamp;
+use crate::narrative_history::{optional_text, text};
+use crate::schema_reflection::reflect_table_schema;
+use crate::{
+    ActorCrossPostRecordRow, ActorServerExecutionRow, ActorServerLeaseRow, ActorServerStateRow,
+    ContentConnection, DatabaseResult, SqlDialect,
+};
+use botticelli_core::FilterValue;
+use botticelli_error::{DatabaseError, DatabaseErrorKind};
+use chrono::{NaiveDateTime, Utc};
+use serde_json::Value as JsonValue;
+use tracing::instrument;
+
+/// Which actor server task states to list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActorStateFilter<'a> {
+    /// Every task
+    All,
+    /// One task by ID
+    Task(&'a str),
+    /// Tasks of one actor
+    Actor(&'a str),
+    /// Tasks that aren't paused
+    Active,
+    /// Paused tasks
+    Paused,
+}
+
+/// A change to one actor server task's state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActorStateChange {
+    /// Pause or resume the task
+    Paused(bool),
+    /// Reschedule the task
+    NextRun(NaiveDateTime),
+    /// Count one more failed run
+    IncrementFailures,
+    /// Reset the failure count after a successful run
+    ResetFailures,
+}
+
+/// Take the lease if it is free, expired or already ours, in one statement.
+///
+/// The `WHERE` on the conflict branch leaves a live lease held by another
+/// replica untouched. Lease times use the database clock so replicas with
+/// skewed clocks agree on expiry.
+const ACQUIRE_LEASE_POSTGRES: &str = "
+INSERT INTO actor_server_leases (task_id, owner, acquired_at, expires_at)
+VALUES ($1, $2, NOW(), NOW() + make_interval(secs => $3))
+ON CONFLICT (task_id) DO UPDATE
+SET owner = EXCLUDED.owner,
+    acquired_at = CASE
+        WHEN actor_server_leases.owner = EXCLUDED.owner THEN actor_server_leases.acquired_at
+        ELSE EXCLUDED.acquired_at
+    END,
+    expires_at = EXCLUDED.expires_at
+WHERE actor_server_leases.owner = EXCLUDED.owner
+   OR actor_server_leases.expires_at < NOW()";
+
+/// [`ACQUIRE_LEASE_POSTGRES`] for SQLite, with millisecond text timestamps.
+const ACQUIRE_LEASE_SQLITE: &str = "
+INSERT INTO actor_server_leases (task_id, owner, acquired_at, expires_at)
+VALUES (?1, ?2, strftime('%Y-%m-%d %H:%M:%f', 'now'),
+        strftime('%Y-%m-%d %H:%M:%f', 'now', ?3 || ' seconds'))
+ON CONFLICT (task_id) DO UPDATE
+SET owner = excluded.owner,
+    acquired_at = CASE
+        WHEN actor_server_leases.owner = excluded.owner THEN actor_server_leases.acquired_at
+        ELSE excluded.acquired_at
+    END,
+    expires_at = excluded.expires_at
+WHERE actor_server_leases.owner = excluded.owner
+   OR actor_server_leases.expires_at < strftime('%Y-%m-%d %H:%M:%f', 'now')";
+
+/// Placeholder for a timestamp bound with [`timestamp_value`].
+fn timestamp_param(dialect: SqlDialect, n: usize) -> String {
+    match dialect {
+        SqlDialect::Postgres => format!(
+            "(CAST({} AS timestamp) AT TIME ZONE 'UTC')",
+            dialect.placeholder(n)
+        ),
+        SqlDialect::Sqlite => dialect.placeholder(n),
+    }
+}
+
+/// Bind value for a UTC timestamp.
+fn timestamp_value(ts: NaiveDateTime) -> FilterValue {
+    FilterValue::Text(ts.format("%Y-%m-%d %H:%M:%S%.6f").to_string())
+}
+
+/// Placeholder for a JSON document bound as text.
+fn json_param(dialect: SqlDialect, n: usize) -> String {
+    match dialect {
+        SqlDialect::Postgres => format!("CAST({} AS jsonb)", dialect.placeholder(n)),
+        SqlDialect::Sqlite => dialect.placeholder(n),
+    }
+}
+
+fn timestamp(row: &JsonValue, field: &str) -> DatabaseResult<NaiveDateTime> {
+    Ok(parse_timestamp(&text(row, field)?)?.naive_utc())
+}
+
+fn optional_timestamp(row: &JsonValue, field: &str) -> DatabaseResult<Option<NaiveDateTime>> {
+    optional_text(row, field)
+        .map(|ts| parse_timestamp(&ts).map(|ts| ts.naive_utc()))
+        .transpose()
+}
+
+fn optional_i32(row: &JsonValue, field: &str) -> Option<i32> {
+    row.get(field)
+        .and_then(|v| v.as_i64())
+        .and_then(|v| i32::try_from(v).ok())
+}
+
+fn optional_json(row: &JsonValue, field: &str) -> Option<JsonValue> {
+    row.get(field).filter(|v| !v.is_null()).cloned()
+}
+
+/// Load rows of an actor server table as JSON.
+fn load_rows<C: ContentConnection>(
+    conn: &mut C,
+    table: &str,
+    select: &str,
+    params: &[FilterValue],
+) -> DatabaseResult<Vec<JsonValue>> {
+    let columns = reflect_table_schema(conn, table)?.columns;
+    conn.load_json(select, &columns, params)
+}
+
+fn state_row(row: &JsonValue) -> DatabaseResult<ActorServerStateRow> {
+    Ok(ActorServerStateRow {
+        task_id: text(row, "task_id")?,
+        actor_name: text(row, "actor_name")?,
+        last_run: optional_timestamp(row, "last_run")?,
+        next_run: timestamp(row, "next_run")?,
+        consecutive_failures: optional_i32(row, "consecutive_failures"),
+        is_paused: row.get("is_paused").and_then(|v| v.as_bool()),
+        metadata: optional_json(row, "metadata"),
+        updated_at: timestamp(row, "updated_at")?,
+    })
+}
+
+fn execution_row(row: &JsonValue) -> DatabaseResult<ActorServerExecutionRow> {
+    Ok(ActorServerExecutionRow {
+        id: row.get("id").and_then(|v| v.as_i64()).ok_or_else(|| {
+            DatabaseError::new(DatabaseErrorKind::Query("Row is missing 'id'".to_string()))
+        })?,
+        task_id: text(row, "task_id")?,
+        actor_name: text(row, "actor_name")?,
+        started_at: timestamp(row, "started_at")?,
+        completed_at: optional_timestamp(row, "completed_at")?,
+        success: row.get("success").and_then(|v| v.as_bool()),
+        error_message: optional_text(row, "error_message"),
+        skills_succeeded: optional_i32(row, "skills_succeeded"),
+        skills_failed: optional_i32(row, "skills_failed"),
+        skills_skipped: optional_i32(row, "skills_skipped"),
+        metadata: optional_json(row, "metadata"),
+        created_at: timestamp(row, "created_at")?,
+    })
+}
+
+fn lease_row(row: &JsonValue) -> DatabaseResult<ActorServerLeaseRow> {
+    Ok(ActorServerLeaseRow {
+        task_id: text(row, "task_id")?,
+        owner: text(row, "owner")?,
+        acquired_at: timestamp(row, "acquired_at")?,
+        expires_at: timestamp(row, "expires_at")?,
+    })
+}
+
+fn crosspost_row(row: &JsonValue) -> DatabaseResult<ActorCrossPostRecordRow> {
+    Ok(ActorCrossPostRecordRow {
+        actor_name: text(row, "actor_name")?,
+        content_id: text(row, "content_id")?,
+        deliveries: optional_json(row, "deliveries").unwrap_or_default(),
+        updated_at: timestamp(row, "updated_at")?,
+    })
+}
+
+/// Insert or replace the state of one actor server task.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+#[instrument(name = "actor_server_store.save_actor_state", skip(conn, state), fields(task_id = %state.task_id))]
+pub fn save_actor_state<C: ContentConnection>(
+    conn: &mut C,
+    state: &ActorServerStateRow,
+) -> DatabaseResult<()> {
+    let dialect = conn.dialect();
+    let upsert = format!(
+        "INSERT INTO actor_server_state
+             (task_id, actor_name, last_run, next_run, consecutive_failures, is_paused, metadata)
+         VALUES ({}, {}, {}, {}, {}, {}, {})
+         ON CONFLICT (task_id) DO UPDATE
+         SET last_run = excluded.last_run,
+             next_run = excluded.next_run,
+             consecutive_failures = excluded.consecutive_failures,
+             is_paused = excluded.is_paused,
+             metadata = excluded.metadata,
+             updated_at = {}",
+        dialect.placeholder(1),
+        dialect.placeholder(2),
+        match state.last_run {
+            Some(_) => timestamp_param(dialect, 3),
+            None => "NULL".to_string(),
+        },
+        timestamp_param(dialect, 4),
+        dialect.placeholder(5),
+        dialect.placeholder(6),
+        json_param(dialect, 7),
+        dialect.now(),
+    );
+
+    conn.execute_sql(
+        &upsert,
+        &[
+            FilterValue::Text(state.task_id.clone()),
+            FilterValue::Text(state.actor_name.clone()),
+            // Unused when last_run is NULL, but keeps the numbering fixed
+            timestamp_value(state.last_run.unwrap_or(state.next_run)),
+            timestamp_value(state.next_run),
+            FilterValue::Integer(i64::from(state.consecutive_failures.unwrap_or(0))),
+            FilterValue::Bool(state.is_paused.unwrap_or(false)),
+            FilterValue::Text(
+                state
+                    .metadata
+                    .clone()
+                    .unwrap_or_else(|| JsonValue::Object(Default::default()))
+                    .to_string(),
+            ),
+        ],
+    )?;
+    Ok(())
+}
+
+/// List actor server task states.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+#[instrument(name = "actor_server_store.list_actor_states", skip(conn))]
+pub fn list_actor_states<C: ContentConnection>(
+    conn: &mut C,
+    filter: ActorStateFilter<'_>,
+) -> DatabaseResult<Vec<ActorServerStateRow>> {
+    let dialect = conn.dialect();
+    let (condition, params) = match filter {
+        ActorStateFilter::All => (String::new(), vec![]),
+        ActorStateFilter::Task(task_id) => (
+            format!(" WHERE task_id = {}", dialect.placeholder(1)),
+            vec![FilterValue::Text(task_id.to_string())],
+        ),
+        ActorStateFilter::Actor(actor_name) => (
+            format!(" WHERE actor_name = {}", dialect.placeholder(1)),
+            vec![FilterValue::Text(actor_name.to_string())],
+        ),
+        ActorStateFilter::Active => (
+            " WHERE is_paused IS NULL OR NOT is_paused".to_string(),
+            vec![],
+        ),
+        ActorStateFilter::Paused => (" WHERE is_paused".to_string(), vec![]),
+    };
+
+    let select = format!(
+        "SELECT * FROM actor_server_state{} ORDER BY task_id",
+        condition
+    );
+    load_rows(conn, "actor_server_state", &select, &params)?
+        .iter()
+        .map(state_row)
+        .collect()
+}
+
+/// Delete the state of one task, or of every task.
+///
+/// Returns the number of states deleted.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+#[instrument(name = "actor_server_store.delete_actor_states", skip(conn))]
+pub fn delete_actor_states<C: ContentConnection>(
+    conn: &mut C,
+    task_id: Option<&str>,
+) -> DatabaseResult<usize> {
+    match task_id {
+        Some(task_id) => {
+            let delete = format!(
+                "DELETE FROM actor_server_state WHERE task_id = {}",
+                conn.dialect().placeholder(1)
+            );
+            conn.execute_sql(&delete, &[FilterValue::Text(task_id.to_string())])
+        }
+        None => conn.execute_sql("DELETE FROM actor_server_state", &[]),
+    }
+}
+
+/// Apply a change to one task's state.
+///
+/// Returns the number of states changed, 0 if the task has no state.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+#[instrument(name = "actor_server_store.update_actor_state", skip(conn))]
+pub fn update_actor_state<C: ContentConnection>(
+    conn: &mut C,
+    task_id: &str,
+    change: ActorStateChange,
+) -> DatabaseResult<usize> {
+    let dialect = conn.dialect();
+    let (assignment, value) = match change {
+        ActorStateChange::Paused(paused) => (
+            format!("is_paused = {}", dialect.placeholder(2)),
+            Some(FilterValue::Bool(paused)),
+        ),
+        ActorStateChange::NextRun(next_run) => (
+            format!("next_run = {}", timestamp_param(dialect, 2)),
+            Some(timestamp_value(next_run)),
+        ),
+        ActorStateChange::IncrementFailures => (
+            "consecutive_failures = COALESCE(consecutive_failures, 0) + 1".to_string(),
+            None,
+        ),
+        ActorStateChange::ResetFailures => ("consecutive_failures = 0".to_string(), None),
+    };
+
+    let update = format!(
+        "UPDATE actor_server_state SET {}, updated_at = {} WHERE task_id = {}",
+        assignment,
+        dialect.now(),
+        dialect.placeholder(1)
+    );
+    let mut params = vec![FilterValue::Text(task_id.to_string())];
+    params.extend(value);
+    conn.execute_sql(&update, &params)
+}
+
+/// Record the last successful run of a task.
+///
+/// Creates the task state if the task has none yet, due at `last_run`.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+#[instrument(name = "actor_server_store.record_actor_last_run", skip(conn))]
+pub fn record_actor_last_run<C: ContentConnection>(
+    conn: &mut C,
+    task_id: &str,
+    actor_name: &str,
+    last_run: NaiveDateTime,
+) -> DatabaseResult<()> {
+    let dialect = conn.dialect();
+    let upsert = format!(
+        "INSERT INTO actor_server_state (task_id, actor_name, last_run, next_run)
+         VALUES ({}, {}, {ts}, {ts})
+         ON CONFLICT (task_id) DO UPDATE
+         SET last_run = excluded.last_run, updated_at = {}",
+        dialect.placeholder(1),
+        dialect.placeholder(2),
+        dialect.now(),
+        ts = timestamp_param(dialect, 3),
+    );
+
+    conn.execute_sql(
+        &upsert,
+        &[
+            FilterValue::Text(task_id.to_string()),
+            FilterValue::Text(actor_name.to_string()),
+            timestamp_value(last_run),
+        ],
+    )?;
+    Ok(())
+}
+
+/// Record the start of an actor execution and return its ID.
+///
+/// # Errors
+///
+/// Returns an error if the insert fails.
+#[instrument(name = "actor_server_store.start_actor_execution", skip(conn))]
+pub fn start_actor_execution<C: ContentConnection>(
+    conn: &mut C,
+    task_id: &str,
+    actor_name: &str,
+) -> DatabaseResult<i64> {
+    let dialect = conn.dialect();
+    let insert = format!(
+        "INSERT INTO actor_server_executions (task_id, actor_name, started_at)
+         VALUES ({}, {}, {}) RETURNING id",
+        dialect.placeholder(1),
+        dialect.placeholder(2),
+        timestamp_param(dialect, 3),
+    );
+
+    conn.insert_returning_id(
+        &insert,
+        &[
+            FilterValue::Text(task_id.to_string()),
+            FilterValue::Text(actor_name.to_string()),
+            timestamp_value(Utc::now().naive_utc()),
+        ],
+    )
+}
+
+/// Mark an execution as successful, with its skill counts and metadata.
+///
+/// # Errors
+///
+/// Returns an error if the update fails.
+#[instrument(
+    name = "actor_server_store.complete_actor_execution",
+    skip(conn, metadata)
+)]
+pub fn complete_actor_execution<C: ContentConnection>(
+    conn: &mut C,
+    execution_id: i64,
+    skills_succeeded: i32,
+    skills_failed: i32,
+    skills_skipped: i32,
+    metadata: &JsonValue,
+) -> DatabaseResult<()> {
+    let dialect = conn.dialect();
+    let update = format!(
+        "UPDATE actor_server_executions
+         SET completed_at = {}, success = {}, skills_succeeded = {}, skills_failed = {},
+             skills_skipped = {}, metadata = {}
+         WHERE id = {}",
+        timestamp_param(dialect, 2),
+        dialect.placeholder(3),
+        dialect.placeholder(4),
+        dialect.placeholder(5),
+        dialect.placeholder(6),
+        json_param(dialect, 7),
+        dialect.placeholder(1),
+    );
+
+    conn.execute_sql(
+        &update,
+        &[
+            FilterValue::Integer(execution_id),
+            timestamp_value(Utc::now().naive_utc()),
+            FilterValue::Bool(true),
+            FilterValue::Integer(i64::from(skills_succeeded)),
+            FilterValue::Integer(i64::from(skills_failed)),
+            FilterValue::Integer(i64::from(skills_skipped)),
+            FilterValue::Text(metadata.to_string()),
+        ],
+    )?;
+    Ok(())
+}
+
+/// Mark an execution as failed with an error message.
+///
+/// # Errors
+///
+/// Returns an error if the update fails.
+#[instrument(name = "actor_server_store.fail_actor_execution", skip(conn))]
+pub fn fail_actor_execution<C: ContentConnection>(
+    conn: &mut C,
+    execution_id: i64,
+    error: &str,
+) -> DatabaseResult<()> {
+    let dialect = conn.dialect();
+    let update = format!(
+        "UPDATE actor_server_executions
+         SET completed_at = {}, success = {}, error_message = {}
+         WHERE id = {}",
+        timestamp_param(dialect, 2),
+        dialect.placeholder(3),
+        dialect.placeholder(4),
+        dialect.placeholder(1),
+    );
+
+    conn.execute_sql(
+        &update,
+        &[
+            FilterValue::Integer(execution_id),
+            timestamp_value(Utc::now().naive_utc()),
+            FilterValue::Bool(false),
+            FilterValue::Text(error.to_string()),
+        ],
+    )?;
+    Ok(())
+}
+
+/// List executions, most recently started first.
+///
+/// Narrow the list to one task with `task_id` and to failed executions
+/// with `failed_only`.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+#[instrument(name = "actor_server_store.list_actor_executions", skip(conn))]
+pub fn list_actor_executions<C: ContentConnection>(
+    conn: &mut C,
+    task_id: Option<&str>,
+    failed_only: bool,
+    limit: i64,
+) -> DatabaseResult<Vec<ActorServerExecutionRow>> {
+    let dialect = conn.dialect();
+    let mut conditions = Vec::new();
+    let mut params = Vec::new();
+    if let Some(task_id) = task_id {
+        params.push(FilterValue::Text(task_id.to_string()));
+        conditions.push(format!("task_id = {}", dialect.placeholder(params.len())));
+    }
+    if failed_only {
+        conditions.push("NOT success".to_string());
+    }
+    params.push(FilterValue::Integer(limit));
+
+    let select = format!(
+        "SELECT * FROM actor_server_executions{} ORDER BY started_at DESC, id DESC LIMIT {}",
+        if conditions.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", conditions.join(" AND "))
+        },
+        dialect.placeholder(params.len()),
+    );
+    load_rows(conn, "actor_server_executions", &select, &params)?
+        .iter()
+        .map(execution_row)
+        .collect()
+}
+
+/// Delete executions started before `cutoff`.
+///
+/// Returns the number of executions deleted.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+#[instrument(name = "actor_server_store.prune_actor_executions", skip(conn))]
+pub fn prune_actor_executions<C: ContentConnection>(
+    conn: &mut C,
+    cutoff: NaiveDateTime,
+) -> DatabaseResult<usize> {
+    let delete = format!(
+        "DELETE FROM actor_server_executions WHERE started_at < {}",
+        timestamp_param(conn.dialect(), 1)
+    );
+    conn.execute_sql(&delete, &[timestamp_value(cutoff)])
+}
+
+/// Take or extend the lease on a task for `owner`.
+///
+/// The lease is taken if it is free, expired or already held by `owner`,
+/// and otherwise left alone. Returns the lease as it stands afterwards;
+/// `owner` holds it if the returned owner matches.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+#[instrument(name = "actor_server_store.acquire_actor_lease", skip(conn))]
+pub fn acquire_actor_lease<C: ContentConnection>(
+    conn: &mut C,
+    task_id: &str,
+    owner: &str,
+    lease_seconds: f64,
+) -> DatabaseResult<ActorServerLeaseRow> {
+    let dialect = conn.dialect();
+    let acquire = match dialect {
+        SqlDialect::Postgres => ACQUIRE_LEASE_POSTGRES,
+        SqlDialect::Sqlite => ACQUIRE_LEASE_SQLITE,
+    };
+    conn.execute_sql(
+        acquire,
+        &[
+            FilterValue::Text(task_id.to_string()),
+            FilterValue::Text(owner.to_string()),
+            FilterValue::Float(lease_seconds),
+        ],
+    )?;
+
+    let select = format!(
+        "SELECT * FROM actor_server_leases WHERE task_id = {}",
+        dialect.placeholder(1)
+    );
+    let rows = load_rows(
+        conn,
+        "actor_server_leases",
+        &select,
+        &[FilterValue::Text(task_id.to_string())],
+    )?;
+    rows.first()
+        .map(lease_row)
+        .unwrap_or_else(|| Err(DatabaseError::new(DatabaseErrorKind::NotFound)))
+}
+
+/// Delete the leases `owner` holds, on one task or on all of them.
+///
+/// Returns the number of leases released.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+#[instrument(name = "actor_server_store.release_actor_leases", skip(conn))]
+pub fn release_actor_leases<C: ContentConnection>(
+    conn: &mut C,
+    owner: &str,
+    task_id: Option<&str>,
+) -> DatabaseResult<usize> {
+    let dialect = conn.dialect();
+    let mut delete = format!(
+        "DELETE FROM actor_server_leases WHERE owner = {}",
+        dialect.placeholder(1)
+    );
+    let mut params = vec![FilterValue::Text(owner.to_string())];
+    if let Some(task_id) = task_id {
+        delete.push_str(&format!(" AND task_id = {}", dialect.placeholder(2)));
+        params.push(FilterValue::Text(task_id.to_string()));
+    }
+    conn.execute_sql(&delete, &params)
+}
+
+/// List every lease, across replicas.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+#[instrument(name = "actor_server_store.list_actor_leases", skip(conn))]
+pub fn list_actor_leases<C: ContentConnection>(
+    conn: &mut C,
+) -> DatabaseResult<Vec<ActorServerLeaseRow>> {
+    load_rows(
+        conn,
+        "actor_server_leases",
+        "SELECT * FROM actor_server_leases ORDER BY task_id",
+        &[],
+    )?
+    .iter()
+    .map(lease_row)
+    .collect()
+}
+
+/// Replace an actor's cross-post records with `records`.
+///
+/// Writes every record and deletes the actor's records not among them, in
+/// one transaction. Returns the number of records deleted.
+///
+/// # Errors
+///
+/// Returns an error if a query fails; nothing is changed then.
+#[instrument(name = "actor_server_store.save_crosspost_records", skip(conn, records), fields(records = records.len()))]
+pub fn save_crosspost_records<C: ContentConnection>(
+    conn: &mut C,
+    actor_name: &str,
+    records: &[ActorCrossPostRecordRow],
+) -> DatabaseResult<usize> {
+    let dialect = conn.dialect();
+    conn.in_transaction(|conn| {
+        let mut params = vec![FilterValue::Text(actor_name.to_string())];
+        let mut delete = format!(
+            "DELETE FROM actor_crosspost_records WHERE actor_name = {}",
+            dialect.placeholder(1)
+        );
+        if !records.is_empty() {
+            let keep: Vec<String> = records
+                .iter()
+                .map(|record| {
+                    params.push(FilterValue::Text(record.content_id.clone()));
+                    dialect.placeholder(params.len())
+                })
+                .collect();
+            delete.push_str(&format!(" AND content_id NOT IN ({})", keep.join(", ")));
+        }
+        let pruned = conn.execute_sql(&delete, &params)?;
+
+        let upsert = format!(
+            "INSERT INTO actor_crosspost_records (actor_name, content_id, deliveries, updated_at)
+             VALUES ({}, {}, {}, {})
+             ON CONFLICT (actor_name, content_id) DO UPDATE
+             SET deliveries = excluded.deliveries, updated_at = excluded.updated_at",
+            dialect.placeholder(1),
+            dialect.placeholder(2),
+            json_param(dialect, 3),
+            timestamp_param(dialect, 4),
+        );
+        for record in records {
+            conn.execute_sql(
+                &upsert,
+                &[
+                    FilterValue::Text(actor_name.to_string()),
+                    FilterValue::Text(record.content_id.clone()),
+                    FilterValue::Text(record.deliveries.to_string()),
+                    timestamp_value(record.updated_at),
+                ],
+            )?;
+        }
+
+        Ok(pruned)
+    })
+}
+
+/// List an actor's cross-post records.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+#[instrument(name = "actor_server_store.list_crosspost_records", skip(conn))]
+pub fn list_crosspost_records<C: ContentConnection>(
+    conn: &mut C,
+    actor_name: &str,
+) -> DatabaseResult<Vec<ActorCrossPostRecordRow>> {
+    let select = format!(
+        "SELECT * FROM actor_crosspost_records WHERE actor_name = {} ORDER BY content_id",
+        conn.dialect().placeholder(1)
+    );
+    load_rows(
+        conn,
+        "actor_crosspost_records",
+        &select,
+        &[FilterValue::Text(actor_name.to_string())],
+    )?
+    .iter()
+    .map(crosspost_row)
+    .collect()
+}
+
+/// Delete all of an actor's cross-post records.
+///
+/// Returns the number of records deleted.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+#[instrument(name = "actor_server_store.delete_crosspost_records", skip(conn))]
+pub fn delete_crosspost_records<C: ContentConnection>(
+    conn: &mut C,
+    actor_name: &str,
+) -> DatabaseResult<usize> {
+    let delete = format!(
+        "DELETE FROM actor_crosspost_records WHERE actor_name = {}",
+        conn.dialect().placeholder(1)
+    );
+    conn.execute_sql(&delete, &[FilterValue::Text(actor_name.to_string())])
+}
@@ -0,0 +1,100 @@
+//! Inspect and pause actor server tasks.
+//!
+//! Reads and writes the same `actor_server_state` rows as the actor
+//! server's `DatabaseStatePersistence`, so a task paused here is skipped by
+//! a running server at its next scheduling check.
+
+use crate::content_management::parse_timestamp;
+use crate::narrative_history::{integer, optional_text, text};
+use crate::schema_reflection::reflect_table_schema;
+use crate::{ContentConnection, DatabaseResult};
+use botticelli_core::FilterValue;
+use botticelli_error::{BotticelliResult, DatabaseError, DatabaseErrorKind};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+/// Scheduling state of one actor server task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, derive_getters::Getters)]
+pub struct ActorTask {
+    /// Task ID
+    task_id: String,
+    /// Actor that runs the task
+    actor_name: String,
+    /// When the task last ran
+    last_run: Option<DateTime<Utc>>,
+    /// When the task is next due
+    next_run: DateTime<Utc>,
+    /// Failed runs since the last success
+    consecutive_failures: i64,
+    /// Whether the task is paused
+    is_paused: bool,
+}
+
+/// List every actor server task, soonest due first.
+///
+/// # Errors
+///
+/// Returns `TableNotFound` if the actor server tables haven't been migrated.
+#[instrument(name = "actor_tasks.list_actor_tasks", skip(conn))]
+pub fn list_actor_tasks<C: ContentConnection>(conn: &mut C) -> BotticelliResult<Vec<ActorTask>> {
+    let columns = reflect_table_schema(conn, "actor_server_state")?.columns;
+    let rows = conn.load_json(
+        "SELECT * FROM actor_server_state ORDER BY next_run ASC, task_id ASC",
+        &columns,
+        &[],
+    )?;
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            Ok(ActorTask {
+                task_id: text(row, "task_id")?,
+                actor_name: text(row, "actor_name")?,
+                last_run: optional_text(row, "last_run")
+                    .map(|ts| parse_timestamp(&ts))
+                    .transpose()?,
+                next_run: parse_timestamp(&text(row, "next_run")?)?,
+                consecutive_failures: integer(row, "consecutive_failures"),
+                is_paused: row
+                    .get("is_paused")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or_default(),
+            })
+        })
+        .collect::<DatabaseResult<_>>()?)
+}
+
+/// Pause or resume an actor server task.
+///
+/// # Errors
+///
+/// Returns `NotFound` if no task has this ID.
+#[instrument(name = "actor_tasks.set_actor_task_paused", skip(conn))]
+pub fn set_actor_task_paused<C: ContentConnection>(
+    conn: &mut C,
+    task_id: &str,
+    paused: bool,
+) -> BotticelliResult<()> {
+    let dialect = conn.dialect();
+    let update = format!(
+        "UPDATE actor_server_state SET is_paused = {}, updated_at = {} WHERE task_id = {}",
+        dialect.placeholder(1),
+        dialect.now(),
+        dialect.placeholder(2)
+    );
+
+    let updated = conn.execute_sql(
+        &update,
+        &[
+            FilterValue::Bool(paused),
+            FilterValue::Text(task_id.to_string()),
+        ],
+    )?;
+    if updated == 0 {
+        return Err(DatabaseError::new(DatabaseErrorKind::NotFound).into());
+    }
+
+    tracing::info!(task_id, paused, "Actor task pause state changed");
+    Ok(())
+}
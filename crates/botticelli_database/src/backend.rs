@@ -0,0 +1,485 @@
+//! Database backends for dynamically named content tables.
+//!
+//! Content tables are created at runtime from templates or inferred schemas,
+//! so they are queried with SQL built on the fly rather than Diesel's DSL.
+//! [`ContentConnection`] collects the few operations that differ between
+//! backends (reflection, JSON rows, transactions), so content management,
+//! table queries and storage work the same on PostgreSQL and SQLite.
+
+use crate::{
+    ColumnInfo, ContentGenerationRepository, DatabaseError, DatabaseErrorKind, DatabaseResult,
+    PostgresContentGenerationRepository,
+};
+use botticelli_core::FilterValue;
+use diesel::backend::Backend;
+use diesel::connection::{Connection, TransactionManager};
+use diesel::pg::{Pg, PgConnection};
+use diesel::prelude::*;
+use diesel::query_builder::{BoxedSqlQuery, SqlQuery};
+use diesel::r2d2::{ConnectionManager, PooledConnection, R2D2Connection};
+use diesel::serialize::ToSql;
+use diesel::sql_types::{BigInt, Bool, Double, HasSqlType, Text};
+use serde_json::Value as JsonValue;
+use tracing::instrument;
+
+#[cfg(feature = "sqlite")]
+use diesel::sqlite::SqliteConnection;
+
+/// SQL dialect spoken by a connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SqlDialect {
+    /// PostgreSQL
+    Postgres,
+    /// SQLite (3.35 or later)
+    Sqlite,
+}
+
+impl SqlDialect {
+    /// Placeholder for the `n`-th bound parameter, counting from 1.
+    pub fn placeholder(&self, n: usize) -> String {
+        match self {
+            Self::Postgres => format!("${}", n),
+            Self::Sqlite => format!("?{}", n),
+        }
+    }
+
+    /// Expression for the current timestamp.
+    pub fn now(&self) -> &'static str {
+        match self {
+            Self::Postgres => "NOW()",
+            Self::Sqlite => "CURRENT_TIMESTAMP",
+        }
+    }
+
+    /// Column type for text arrays; SQLite stores them as JSON.
+    pub fn text_array_type(&self) -> &'static str {
+        match self {
+            Self::Postgres => "TEXT[]",
+            Self::Sqlite => "JSONB",
+        }
+    }
+
+    /// Literal for a text array.
+    pub fn text_array_literal(&self, items: &[String]) -> String {
+        match self {
+            Self::Postgres => {
+                let items: Vec<String> = items.iter().map(|item| quote_literal(item)).collect();
+                format!("ARRAY[{}]::TEXT[]", items.join(", "))
+            }
+            Self::Sqlite => quote_literal(&JsonValue::from(items.to_vec()).to_string()),
+        }
+    }
+
+    /// Literal for a JSON document.
+    pub fn json_literal(&self, value: &JsonValue) -> String {
+        match self {
+            Self::Postgres => format!("{}::jsonb", quote_literal(&value.to_string())),
+            Self::Sqlite => quote_literal(&value.to_string()),
+        }
+    }
+}
+
+/// Quote a string as an SQL literal.
+pub(crate) fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Quote an identifier.
+pub(crate) fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+/// A connection that can query dynamically named content tables.
+///
+/// Implemented for [`PgConnection`], `SqliteConnection` (with the `sqlite`
+/// feature), [`DatabaseConnection`] and pooled connections of those.
+pub trait ContentConnection {
+    /// SQL dialect of the connection.
+    fn dialect(&self) -> SqlDialect;
+
+    /// Whether a table exists.
+    fn table_exists(&mut self, table_name: &str) -> DatabaseResult<bool>;
+
+    /// Columns of a table, in declaration order.
+    ///
+    /// Types use PostgreSQL's `information_schema` names (`text`, `bigint`,
+    /// `jsonb`, ...) on every backend. Returns an empty list for unknown
+    /// tables.
+    fn table_columns(&mut self, table_name: &str) -> DatabaseResult<Vec<ColumnInfo>>;
+
+    /// Execute a statement with bound parameters, returning affected rows.
+    fn execute_sql(&mut self, sql: &str, params: &[FilterValue]) -> DatabaseResult<usize>;
+
+    /// Run a query and return each row as a JSON object.
+    ///
+    /// `columns` lists the columns `select` returns; backends without a
+    /// row-to-JSON function use it to build the objects.
+    fn load_json(
+        &mut self,
+        select: &str,
+        columns: &[ColumnInfo],
+        params: &[FilterValue],
+    ) -> DatabaseResult<Vec<JsonValue>>;
+
+    /// Run an `INSERT ... RETURNING id` with bound parameters and return the
+    /// new ID.
+    fn insert_returning_id(&mut self, insert: &str, params: &[FilterValue]) -> DatabaseResult<i64>;
+
+    /// Begin a transaction.
+    fn begin_transaction(&mut self) -> DatabaseResult<()>;
+
+    /// Commit the current transaction.
+    fn commit_transaction(&mut self) -> DatabaseResult<()>;
+
+    /// Roll back the current transaction.
+    fn rollback_transaction(&mut self) -> DatabaseResult<()>;
+
+    /// Repository for content generation tracking on this connection.
+    fn generations(&mut self) -> Box<dyn ContentGenerationRepository + '_>;
+
+    /// Run `f` in a transaction, rolling back if it fails.
+    fn in_transaction<T, F>(&mut self, f: F) -> DatabaseResult<T>
+    where
+        Self: Sized,
+        F: FnOnce(&mut Self) -> DatabaseResult<T>,
+    {
+        self.begin_transaction()?;
+        match f(self) {
+            Ok(value) => {
+                self.commit_transaction()?;
+                Ok(value)
+            }
+            Err(e) => {
+                let _ = self.rollback_transaction();
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Bind filter values to a raw query, in order.
+pub(crate) fn bind_params<'f, DB>(
+    query: SqlQuery,
+    params: &[FilterValue],
+) -> BoxedSqlQuery<'f, DB, SqlQuery>
+where
+    DB: Backend
+        + HasSqlType<Bool>
+        + HasSqlType<BigInt>
+        + HasSqlType<Double>
+        + HasSqlType<Text>
+        + 'static,
+    bool: ToSql<Bool, DB>,
+    i64: ToSql<BigInt, DB>,
+    f64: ToSql<Double, DB>,
+    String: ToSql<Text, DB>,
+{
+    params
+        .iter()
+        .fold(query.into_boxed(), |query, value| match value {
+            FilterValue::Bool(v) => query.bind::<Bool, _>(*v),
+            FilterValue::Integer(v) => query.bind::<BigInt, _>(*v),
+            FilterValue::Float(v) => query.bind::<Double, _>(*v),
+            FilterValue::Text(v) => query.bind::<Text, _>(v.clone()),
+        })
+}
+
+#[track_caller]
+pub(crate) fn query_error(e: impl std::fmt::Display) -> DatabaseError {
+    DatabaseError::new(DatabaseErrorKind::Query(e.to_string()))
+}
+
+/// Row holding a JSON document.
+#[derive(QueryableByName)]
+struct JsonRow {
+    #[diesel(sql_type = diesel::sql_types::Json)]
+    json: JsonValue,
+}
+
+/// Row holding an inserted ID.
+#[derive(QueryableByName)]
+pub(crate) struct IdRow {
+    #[diesel(sql_type = BigInt)]
+    pub(crate) id: i64,
+}
+
+/// Row holding an existence check.
+#[derive(QueryableByName)]
+struct ExistsRow {
+    #[diesel(sql_type = Bool)]
+    exists: bool,
+}
+
+impl ContentConnection for PgConnection {
+    fn dialect(&self) -> SqlDialect {
+        SqlDialect::Postgres
+    }
+
+    #[instrument(name = "backend.postgres.table_exists", skip(self))]
+    fn table_exists(&mut self, table_name: &str) -> DatabaseResult<bool> {
+        let row: ExistsRow = diesel::sql_query(
+            "SELECT EXISTS (
+                SELECT 1
+                FROM information_schema.tables
+                WHERE table_schema = 'public'
+                  AND table_name = $1
+            ) AS exists",
+        )
+        .bind::<Text, _>(table_name)
+        .get_result(self)
+        .map_err(|e| query_error(format!("Failed to check table existence: {}", e)))?;
+
+        Ok(row.exists)
+    }
+
+    #[instrument(name = "backend.postgres.table_columns", skip(self))]
+    fn table_columns(&mut self, table_name: &str) -> DatabaseResult<Vec<ColumnInfo>> {
+        diesel::sql_query(
+            "SELECT
+                column_name AS name,
+                CASE
+                    WHEN data_type = 'ARRAY' THEN udt_name
+                    ELSE data_type
+                END AS data_type,
+                is_nullable,
+                character_maximum_length,
+                column_default
+            FROM information_schema.columns
+            WHERE table_schema = 'public'
+              AND table_name = $1
+            ORDER BY ordinal_position",
+        )
+        .bind::<Text, _>(table_name)
+        .load(self)
+        .map_err(|e| {
+            query_error(format!(
+                "Failed to query schema for table '{}': {}",
+                table_name, e
+            ))
+        })
+    }
+
+    fn execute_sql(&mut self, sql: &str, params: &[FilterValue]) -> DatabaseResult<usize> {
+        bind_params::<Pg>(diesel::sql_query(sql), params)
+            .execute(self)
+            .map_err(query_error)
+    }
+
+    fn load_json(
+        &mut self,
+        select: &str,
+        _columns: &[ColumnInfo],
+        params: &[FilterValue],
+    ) -> DatabaseResult<Vec<JsonValue>> {
+        let query = format!("SELECT row_to_json(t) AS json FROM ({}) t", select);
+        let rows: Vec<JsonRow> = bind_params::<Pg>(diesel::sql_query(query), params)
+            .load(self)
+            .map_err(query_error)?;
+        Ok(rows.into_iter().map(|row| row.json).collect())
+    }
+
+    fn insert_returning_id(&mut self, insert: &str, params: &[FilterValue]) -> DatabaseResult<i64> {
+        let row: IdRow = bind_params::<Pg>(diesel::sql_query(insert), params)
+            .get_result(self)
+            .map_err(query_error)?;
+        Ok(row.id)
+    }
+
+    fn begin_transaction(&mut self) -> DatabaseResult<()> {
+        <Self as Connection>::TransactionManager::begin_transaction(self).map_err(query_error)
+    }
+
+    fn commit_transaction(&mut self) -> DatabaseResult<()> {
+        <Self as Connection>::TransactionManager::commit_transaction(self).map_err(query_error)
+    }
+
+    fn rollback_transaction(&mut self) -> DatabaseResult<()> {
+        <Self as Connection>::TransactionManager::rollback_transaction(self).map_err(query_error)
+    }
+
+    fn generations(&mut self) -> Box<dyn ContentGenerationRepository + '_> {
+        Box::new(PostgresContentGenerationRepository::new(self))
+    }
+}
+
+impl<C> ContentConnection for PooledConnection<ConnectionManager<C>>
+where
+    C: ContentConnection + R2D2Connection + 'static,
+{
+    fn dialect(&self) -> SqlDialect {
+        (**self).dialect()
+    }
+
+    fn table_exists(&mut self, table_name: &str) -> DatabaseResult<bool> {
+        (**self).table_exists(table_name)
+    }
+
+    fn table_columns(&mut self, table_name: &str) -> DatabaseResult<Vec<ColumnInfo>> {
+        (**self).table_columns(table_name)
+    }
+
+    fn execute_sql(&mut self, sql: &str, params: &[FilterValue]) -> DatabaseResult<usize> {
+        (**self).execute_sql(sql, params)
+    }
+
+    fn load_json(
+        &mut self,
+        select: &str,
+        columns: &[ColumnInfo],
+        params: &[FilterValue],
+    ) -> DatabaseResult<Vec<JsonValue>> {
+        (**self).load_json(select, columns, params)
+    }
+
+    fn insert_returning_id(&mut self, insert: &str, params: &[FilterValue]) -> DatabaseResult<i64> {
+        (**self).insert_returning_id(insert, params)
+    }
+
+    fn begin_transaction(&mut self) -> DatabaseResult<()> {
+        (**self).begin_transaction()
+    }
+
+    fn commit_transaction(&mut self) -> DatabaseResult<()> {
+        (**self).commit_transaction()
+    }
+
+    fn rollback_transaction(&mut self) -> DatabaseResult<()> {
+        (**self).rollback_transaction()
+    }
+
+    fn generations(&mut self) -> Box<dyn ContentGenerationRepository + '_> {
+        (**self).generations()
+    }
+}
+
+/// Which backend a database URL points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BackendKind {
+    /// PostgreSQL server
+    Postgres,
+    /// SQLite database file
+    Sqlite,
+}
+
+impl BackendKind {
+    /// Detect the backend from a database URL.
+    ///
+    /// `sqlite:` URLs, `:memory:` and paths ending in `.db`, `.sqlite` or
+    /// `.sqlite3` are SQLite; anything else is PostgreSQL.
+    pub fn from_url(url: &str) -> Self {
+        let is_sqlite = url.starts_with("sqlite:")
+            || url == ":memory:"
+            || [".db", ".sqlite", ".sqlite3"]
+                .iter()
+                .any(|suffix| url.ends_with(suffix));
+        if is_sqlite {
+            Self::Sqlite
+        } else {
+            Self::Postgres
+        }
+    }
+}
+
+/// A connection to whichever backend `DATABASE_URL` names.
+///
+/// Lets tools that only touch content tables (listing, review, table
+/// queries) run against PostgreSQL or a single SQLite file unchanged.
+pub enum DatabaseConnection {
+    /// PostgreSQL connection
+    Postgres(PgConnection),
+    /// SQLite connection
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteConnection),
+}
+
+impl DatabaseConnection {
+    /// Connect to the database at `url`.
+    ///
+    /// SQLite databases are created if missing and migrated to the latest
+    /// schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns a connection error if connecting fails, or if `url` names a
+    /// SQLite database and the `sqlite` feature is disabled.
+    #[instrument(name = "database.connect", skip(url))]
+    pub fn establish(url: &str) -> DatabaseResult<Self> {
+        match BackendKind::from_url(url) {
+            BackendKind::Postgres => Ok(Self::Postgres(PgConnection::establish(url)?)),
+            #[cfg(feature = "sqlite")]
+            BackendKind::Sqlite => Ok(Self::Sqlite(crate::establish_sqlite_connection(url)?)),
+            #[cfg(not(feature = "sqlite"))]
+            BackendKind::Sqlite => Err(DatabaseError::new(DatabaseErrorKind::Connection(
+                "SQLite database URL given, but the sqlite feature is not enabled".to_string(),
+            ))),
+        }
+    }
+
+    /// Connect to the database named by `DATABASE_URL`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a connection error if `DATABASE_URL` is unset or connecting
+    /// fails.
+    pub fn from_env() -> DatabaseResult<Self> {
+        Self::establish(&crate::database_url()?)
+    }
+}
+
+/// Forward a call to the wrapped connection.
+macro_rules! dispatch {
+    ($self:ident, $conn:ident => $call:expr) => {
+        match $self {
+            DatabaseConnection::Postgres($conn) => $call,
+            #[cfg(feature = "sqlite")]
+            DatabaseConnection::Sqlite($conn) => $call,
+        }
+    };
+}
+
+impl ContentConnection for DatabaseConnection {
+    fn dialect(&self) -> SqlDialect {
+        dispatch!(self, conn => conn.dialect())
+    }
+
+    fn table_exists(&mut self, table_name: &str) -> DatabaseResult<bool> {
+        dispatch!(self, conn => conn.table_exists(table_name))
+    }
+
+    fn table_columns(&mut self, table_name: &str) -> DatabaseResult<Vec<ColumnInfo>> {
+        dispatch!(self, conn => conn.table_columns(table_name))
+    }
+
+    fn execute_sql(&mut self, sql: &str, params: &[FilterValue]) -> DatabaseResult<usize> {
+        dispatch!(self, conn => conn.execute_sql(sql, params))
+    }
+
+    fn load_json(
+        &mut self,
+        select: &str,
+        columns: &[ColumnInfo],
+        params: &[FilterValue],
+    ) -> DatabaseResult<Vec<JsonValue>> {
+        dispatch!(self, conn => conn.load_json(select, columns, params))
+    }
+
+    fn insert_returning_id(&mut self, insert: &str, params: &[FilterValue]) -> DatabaseResult<i64> {
+        dispatch!(self, conn => conn.insert_returning_id(insert, params))
+    }
+
+    fn begin_transaction(&mut self) -> DatabaseResult<()> {
+        dispatch!(self, conn => ContentConnection::begin_transaction(conn))
+    }
+
+    fn commit_transaction(&mut self) -> DatabaseResult<()> {
+        dispatch!(self, conn => ContentConnection::commit_transaction(conn))
+    }
+
+    fn rollback_transaction(&mut self) -> DatabaseResult<()> {
+        dispatch!(self, conn => ContentConnection::rollback_transaction(conn))
+    }
+
+    fn generations(&mut self) -> Box<dyn ContentGenerationRepository + '_> {
+        dispatch!(self, conn => conn.generations())
+    }
+}
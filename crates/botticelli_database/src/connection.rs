@@ -14,16 +14,11 @@ use tracing::instrument;
 /// # Errors
 ///
 /// Returns an error if:
-/// - `DATABASE_URL` environment variable is not set
+/// - `DATABASE_URL` environment variable is not set or names a SQLite database
 /// - Connection to the database fails
 #[instrument(name = "database.establish_connection")]
 pub fn establish_connection() -> DatabaseResult<PgConnection> {
-    let database_url = std::env::var("DATABASE_URL").map_err(|_| {
-        tracing::error!("DATABASE_URL environment variable not set");
-        DatabaseError::new(DatabaseErrorKind::Connection(
-            "DATABASE_URL environment variable not set".to_string(),
-        ))
-    })?;
+    let database_url = postgres_database_url()?;
 
     tracing::debug!("Connecting to PostgreSQL database");
     PgConnection::establish(&database_url).map_err(|e| {
@@ -39,16 +34,11 @@ pub fn establish_connection() -> DatabaseResult<PgConnection> {
 /// # Errors
 ///
 /// Returns an error if:
-/// - `DATABASE_URL` environment variable is not set
+/// - `DATABASE_URL` environment variable is not set or names a SQLite database
 /// - Pool creation fails
 #[instrument(name = "database.create_pool")]
 pub fn create_pool() -> DatabaseResult<Pool<ConnectionManager<PgConnection>>> {
-    let database_url = std::env::var("DATABASE_URL").map_err(|_| {
-        tracing::error!("DATABASE_URL environment variable not set");
-        DatabaseError::new(DatabaseErrorKind::Connection(
-            "DATABASE_URL environment variable not set".to_string(),
-        ))
-    })?;
+    let database_url = postgres_database_url()?;
 
     tracing::debug!("Creating PostgreSQL connection pool");
     let manager = ConnectionManager::<PgConnection>::new(database_url);
@@ -58,3 +48,41 @@ pub fn create_pool() -> DatabaseResult<Pool<ConnectionManager<PgConnection>>> {
         DatabaseError::new(DatabaseErrorKind::Connection(e.to_string()))
     })
 }
+
+/// Read the database URL from the `DATABASE_URL` environment variable.
+///
+/// # Errors
+///
+/// Returns a connection error if `DATABASE_URL` is not set.
+pub fn database_url() -> DatabaseResult<String> {
+    std::env::var("DATABASE_URL").map_err(|_| {
+        tracing::error!("DATABASE_URL environment variable not set");
+        DatabaseError::new(DatabaseErrorKind::Connection(
+            "DATABASE_URL environment variable not set".to_string(),
+        ))
+    })
+}
+
+/// Read a PostgreSQL URL from the `DATABASE_URL` environment variable.
+///
+/// The SQLite backend covers content and actor server tables, but not the
+/// Diesel-mapped ones: execution history (`--save`) and the Discord guild
+/// mirror behind the bot server and the actor server's gateway bot. Those
+/// reject SQLite URLs up front instead of failing on the first query.
+///
+/// # Errors
+///
+/// Returns a connection error if `DATABASE_URL` is not set or names a
+/// SQLite database.
+pub fn postgres_database_url() -> DatabaseResult<String> {
+    let database_url = database_url()?;
+    if crate::BackendKind::from_url(&database_url) == crate::BackendKind::Sqlite {
+        tracing::error!("DATABASE_URL names a SQLite database where PostgreSQL is required");
+        return Err(DatabaseError::new(DatabaseErrorKind::Connection(
+            "DATABASE_URL names a SQLite database, but this needs PostgreSQL; \
+             SQLite doesn't support execution history or the Discord gateway bot"
+                .to_string(),
+        )));
+    }
+    Ok(database_url)
+}
@@ -52,3 +52,54 @@ pub struct UpdateContentGenerationRow {
     pub status: Option<String>,
     pub error_message: Option<String>,
 }
+
+/// Row for content_generations read through raw SQL on SQLite.
+///
+/// SQLite stores timestamps as text, so the Diesel schema's `Timestamptz`
+/// columns are read as `TimestamptzSqlite` instead.
+#[cfg(feature = "sqlite")]
+#[derive(Debug, QueryableByName)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub(crate) struct SqliteContentGenerationRow {
+    #[diesel(sql_type = diesel::sql_types::Integer)]
+    id: i32,
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    table_name: String,
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    narrative_file: String,
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    narrative_name: String,
+    #[diesel(sql_type = diesel::sql_types::TimestamptzSqlite)]
+    generated_at: DateTime<Utc>,
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::TimestamptzSqlite>)]
+    completed_at: Option<DateTime<Utc>>,
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Integer>)]
+    row_count: Option<i32>,
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Integer>)]
+    generation_duration_ms: Option<i32>,
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    status: String,
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Text>)]
+    error_message: Option<String>,
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Text>)]
+    created_by: Option<String>,
+}
+
+#[cfg(feature = "sqlite")]
+impl From<SqliteContentGenerationRow> for ContentGenerationRow {
+    fn from(row: SqliteContentGenerationRow) -> Self {
+        Self {
+            id: row.id,
+            table_name: row.table_name,
+            narrative_file: row.narrative_file,
+            narrative_name: row.narrative_name,
+            generated_at: row.generated_at,
+            completed_at: row.completed_at,
+            row_count: row.row_count,
+            generation_duration_ms: row.generation_duration_ms,
+            status: row.status,
+            error_message: row.error_message,
+            created_by: row.created_by,
+        }
+    }
+}
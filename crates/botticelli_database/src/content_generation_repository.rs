@@ -112,8 +112,8 @@ pub trait ContentGenerationRepository {
 
 /// PostgreSQL implementation of ContentGenerationRepository.
 ///
-/// Uses a mutable reference to PgConnection. For concurrent access,
-/// consider wrapping the repository in Arc<Mutex> or using a connection pool.
+/// Uses a mutable reference to PgConnection. From async code, borrow a
+/// pooled connection inside [`crate::with_pooled_connection`].
 pub struct PostgresContentGenerationRepository<'a> {
     conn: &'a mut PgConnection,
 }
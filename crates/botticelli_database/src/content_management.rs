@@ -3,10 +3,14 @@
 //! Provides functions for querying, updating, and managing content
 //! in dynamically created generation tables.
 
-use crate::schema_reflection::reflect_table_schema;
+use crate::backend::quote_identifier;
+use crate::content_revisions::with_revision;
+use crate::schema_reflection::{ColumnInfo, reflect_table_schema};
+use crate::{CompiledFilter, ContentConnection, DatabaseResult, SqlDialect};
+use botticelli_core::{FilterValue, TableFilter};
 use botticelli_error::{BotticelliResult, DatabaseError, DatabaseErrorKind};
-use diesel::pg::PgConnection;
-use diesel::prelude::*;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use tracing::instrument;
 
@@ -19,21 +23,19 @@ use tracing::instrument;
 ///
 /// * `conn` - Database connection
 /// * `table_name` - Name of the content table
-/// * `status_filter` - Optional review status filter ("pending", "approved", "rejected")
+/// * `status_filter` - Optional review status filter, e.g. "approved"
 /// * `limit` - Maximum number of results to return
 ///
 /// # Returns
 ///
 /// Vector of JSON objects representing table rows
 #[instrument(name = "content_management.list_content", skip(conn), fields(table = %table_name, limit = %limit))]
-pub fn list_content(
-    conn: &mut PgConnection,
+pub fn list_content<C: ContentConnection>(
+    conn: &mut C,
     table_name: &str,
     status_filter: Option<&str>,
     limit: usize,
 ) -> BotticelliResult<Vec<JsonValue>> {
-    use crate::schema_reflection::reflect_table_schema;
-
     // Reflect table schema to check for metadata columns
     let schema = reflect_table_schema(conn, table_name)?;
     let columns: std::collections::HashSet<_> =
@@ -44,15 +46,17 @@ pub fn list_content(
     let has_id = columns.contains("id");
 
     // Build query dynamically
-    let mut query = format!(
-        "SELECT row_to_json(t) FROM (SELECT * FROM {} WHERE 1=1",
-        table_name
-    );
+    let mut query = format!("SELECT * FROM {} WHERE 1=1", table_name);
+    let mut params = Vec::new();
 
     // Only filter by review_status if the column exists
     if let Some(status) = status_filter {
         if has_review_status {
-            query.push_str(&format!(" AND review_status = '{}'", status));
+            query.push_str(&format!(
+                " AND review_status = {}",
+                conn.dialect().placeholder(1)
+            ));
+            params.push(FilterValue::Text(status.to_string()));
         } else {
             tracing::warn!(
                 table = %table_name,
@@ -70,23 +74,123 @@ pub fn list_content(
     // If neither exists, no ORDER BY clause (database default ordering)
 
     query.push_str(&format!(" LIMIT {}", limit));
-    query.push_str(") t");
 
     tracing::debug!(sql = %query, "Listing content");
 
-    // Execute query and collect results
-    let results: Vec<String> = diesel::sql_query(&query)
-        .load::<StringRow>(conn)
-        .map_err(|e| DatabaseError::new(DatabaseErrorKind::Query(e.to_string())))?
-        .into_iter()
-        .map(|row| row.row_to_json)
-        .collect();
+    Ok(conn.load_json(&query, &schema.columns, &params)?)
+}
+
+/// A filtered, sorted page of a content table, for [`query_content`].
+#[derive(Debug, Clone, PartialEq, derive_builder::Builder, derive_getters::Getters)]
+#[builder(setter(into, strip_option))]
+pub struct ContentQuery {
+    /// Structured conditions on columns
+    #[builder(default)]
+    filter: TableFilter,
+    /// Text to look for, case-insensitively, in text, array and JSON columns
+    #[builder(default)]
+    search: Option<String>,
+    /// Column to sort by; newest first when unset
+    #[builder(default)]
+    sort_by: Option<String>,
+    /// Sort `sort_by` in descending order
+    #[builder(default)]
+    descending: bool,
+    /// Rows to skip
+    #[builder(default)]
+    offset: i64,
+    /// Maximum rows to return
+    #[builder(default = "100")]
+    limit: i64,
+}
+
+/// One page of content from [`query_content`].
+#[derive(Debug, Clone, PartialEq, derive_getters::Getters)]
+pub struct ContentPage {
+    /// Rows on this page
+    rows: Vec<JsonValue>,
+    /// Rows matching the query across all pages
+    total: i64,
+}
+
+/// Query a page of content with filters, text search and sorting.
+///
+/// Filter and search values are bound as parameters. Rows with equal sort
+/// keys are ordered by descending `id` so pages don't overlap.
+///
+/// # Errors
+///
+/// Returns an error if the filter or sort column doesn't fit the table.
+#[instrument(name = "content_management.query_content", skip(conn, query), fields(table = %table_name, offset = %query.offset, limit = %query.limit))]
+pub fn query_content<C: ContentConnection>(
+    conn: &mut C,
+    table_name: &str,
+    query: &ContentQuery,
+) -> BotticelliResult<ContentPage> {
+    let schema = reflect_table_schema(conn, table_name)?;
+    let has_column = |name: &str| schema.columns.iter().any(|c| c.name == name);
 
-    // Parse JSON strings
-    let json_results: Result<Vec<JsonValue>, _> =
-        results.iter().map(|s| serde_json::from_str(s)).collect();
+    let mut condition = CompiledFilter::compile(&query.filter, &schema, conn.dialect())?;
+    if let Some(term) = query.search.as_deref().filter(|t| !t.is_empty()) {
+        condition = condition.and_search(&schema.columns, term);
+    }
+
+    let mut order = Vec::new();
+    match &query.sort_by {
+        Some(column) if has_column(column) => {
+            let direction = if query.descending { "DESC" } else { "ASC" };
+            order.push(format!("{} {}", quote_identifier(column), direction));
+        }
+        Some(column) => {
+            return Err(DatabaseError::new(DatabaseErrorKind::InvalidQuery(format!(
+                "Cannot sort '{}' by unknown column '{}'",
+                table_name, column
+            )))
+            .into());
+        }
+        None if has_column("generated_at") => order.push("generated_at DESC".to_string()),
+        None => {}
+    }
+    if has_column("id") && query.sort_by.as_deref() != Some("id") {
+        order.push("id DESC".to_string());
+    }
+    let order = if order.is_empty() {
+        String::new()
+    } else {
+        format!(" ORDER BY {}", order.join(", "))
+    };
+
+    let select = format!(
+        "SELECT * FROM {} WHERE {}{} LIMIT {} OFFSET {}",
+        table_name,
+        condition.sql(),
+        order,
+        query.limit,
+        query.offset
+    );
+    let count = format!(
+        "SELECT COUNT(*) AS total FROM {} WHERE {}",
+        table_name,
+        condition.sql()
+    );
 
-    json_results.map_err(|e| DatabaseError::new(DatabaseErrorKind::Query(e.to_string())).into())
+    tracing::debug!(sql = %select, "Querying content");
+
+    let total_column = ColumnInfo {
+        name: "total".to_string(),
+        data_type: "bigint".to_string(),
+        is_nullable: "NO".to_string(),
+        character_maximum_length: None,
+        column_default: None,
+    };
+    let total = conn
+        .load_json(&count, &[total_column], condition.params())?
+        .first()
+        .and_then(|row| row.get("total")?.as_i64())
+        .unwrap_or_default();
+    let rows = conn.load_json(&select, &schema.columns, condition.params())?;
+
+    Ok(ContentPage { rows, total })
 }
 
 /// Get a specific content item by ID.
@@ -101,25 +205,20 @@ pub fn list_content(
 ///
 /// JSON object representing the row, or error if not found
 #[instrument(name = "content_management.get_content_by_id", skip(conn), fields(table = %table_name, id = %id))]
-pub fn get_content_by_id(
-    conn: &mut PgConnection,
+pub fn get_content_by_id<C: ContentConnection>(
+    conn: &mut C,
     table_name: &str,
     id: i64,
 ) -> BotticelliResult<JsonValue> {
-    let query = format!(
-        "SELECT row_to_json(t) FROM (SELECT * FROM {} WHERE id = {}) t",
-        table_name, id
-    );
+    let schema = reflect_table_schema(conn, table_name)?;
+    let query = format!("SELECT * FROM {} WHERE id = {}", table_name, id);
 
     tracing::debug!(sql = %query, "Getting content by ID");
 
-    let result: String = diesel::sql_query(&query)
-        .get_result::<StringRow>(conn)
-        .map_err(|e| DatabaseError::new(DatabaseErrorKind::Query(e.to_string())))?
-        .row_to_json;
-
-    serde_json::from_str(&result)
-        .map_err(|e| DatabaseError::new(DatabaseErrorKind::Query(e.to_string())).into())
+    conn.load_json(&query, &schema.columns, &[])?
+        .into_iter()
+        .next()
+        .ok_or_else(|| DatabaseError::new(DatabaseErrorKind::NotFound).into())
 }
 
 /// Pull and delete content items in a single transaction (destructive read).
@@ -134,6 +233,8 @@ pub fn get_content_by_id(
 /// * `limit` - Maximum number of items to pull and delete
 /// Update tags and rating for a content item.
 ///
+/// The change is recorded as a content revision by `editor`.
+///
 /// # Arguments
 ///
 /// * `conn` - Database connection
@@ -141,13 +242,15 @@ pub fn get_content_by_id(
 /// * `id` - Content ID
 /// * `tags` - Optional tags to set (replaces existing)
 /// * `rating` - Optional rating (1-5)
+/// * `editor` - Who is making the change
 #[instrument(name = "content_management.update_content_metadata", skip(conn, tags), fields(table = %table_name, id = %id))]
-pub fn update_content_metadata(
-    conn: &mut PgConnection,
+pub fn update_content_metadata<C: ContentConnection>(
+    conn: &mut C,
     table_name: &str,
     id: i64,
     tags: Option<&[String]>,
     rating: Option<i32>,
+    editor: &str,
 ) -> BotticelliResult<()> {
     let mut updates = Vec::new();
 
@@ -155,11 +258,7 @@ pub fn update_content_metadata(
         let tags_sql = if tag_list.is_empty() {
             "NULL".to_string()
         } else {
-            let escaped: Vec<String> = tag_list
-                .iter()
-                .map(|t| format!("'{}'", t.replace('\'', "''")))
-                .collect();
-            format!("ARRAY[{}]", escaped.join(", "))
+            conn.dialect().text_array_literal(tag_list)
         };
         updates.push(format!("tags = {}", tags_sql));
     }
@@ -187,47 +286,409 @@ pub fn update_content_metadata(
 
     tracing::debug!(sql = %query, "Updating content metadata");
 
-    diesel::sql_query(&query)
-        .execute(conn)
-        .map_err(|e| DatabaseError::new(DatabaseErrorKind::Query(e.to_string())))?;
+    with_revision(conn, table_name, id, editor, |conn| {
+        conn.execute_sql(&query, &[])?;
+        Ok(())
+    })?;
 
     Ok(())
 }
 
-/// Update review status for a content item.
+/// Bookkeeping columns every content table carries alongside its template
+/// columns.
+pub const CONTENT_METADATA_COLUMNS: [&str; 7] = [
+    "generated_at",
+    "source_narrative",
+    "source_act",
+    "generation_model",
+    "review_status",
+    "tags",
+    "rating",
+];
+
+/// Lifecycle state of a content item, stored in its `review_status` column.
+///
+/// Content moves generated → pending_review → approved/rejected → scheduled
+/// → posted → archived. [`transition_content_status`] enforces the allowed
+/// moves and records each one in `content_status_history`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentStatus {
+    /// Freshly generated, not yet queued for review
+    Generated,
+    /// Waiting for a reviewer
+    PendingReview,
+    /// Accepted for posting
+    Approved,
+    /// Turned down by a reviewer
+    Rejected,
+    /// Picked up by a poster
+    Scheduled,
+    /// Published
+    Posted,
+    /// Retired; no further transitions
+    Archived,
+}
+
+impl ContentStatus {
+    /// All statuses, in lifecycle order.
+    pub const ALL: [ContentStatus; 7] = [
+        ContentStatus::Generated,
+        ContentStatus::PendingReview,
+        ContentStatus::Approved,
+        ContentStatus::Rejected,
+        ContentStatus::Scheduled,
+        ContentStatus::Posted,
+        ContentStatus::Archived,
+    ];
+
+    /// Value stored in the `review_status` column.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentStatus::Generated => "generated",
+            ContentStatus::PendingReview => "pending_review",
+            ContentStatus::Approved => "approved",
+            ContentStatus::Rejected => "rejected",
+            ContentStatus::Scheduled => "scheduled",
+            ContentStatus::Posted => "posted",
+            ContentStatus::Archived => "archived",
+        }
+    }
+
+    /// Statuses this status may move to.
+    pub fn next_statuses(&self) -> &'static [ContentStatus] {
+        use ContentStatus::*;
+        match self {
+            Generated => &[PendingReview, Approved, Rejected, Archived],
+            PendingReview => &[Approved, Rejected, Archived],
+            Approved => &[PendingReview, Rejected, Scheduled, Archived],
+            Rejected => &[PendingReview, Archived],
+            // A failed post goes back to approved so it can be retried
+            Scheduled => &[Approved, Posted, Archived],
+            Posted => &[Archived],
+            Archived => &[],
+        }
+    }
+
+    /// Whether the lifecycle allows moving from this status to `next`.
+    pub fn can_transition_to(&self, next: ContentStatus) -> bool {
+        self.next_statuses().contains(&next)
+    }
+}
+
+impl std::fmt::Display for ContentStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for ContentStatus {
+    type Err = DatabaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            LEGACY_PENDING_STATUS => Ok(ContentStatus::PendingReview),
+            _ => ContentStatus::ALL
+                .into_iter()
+                .find(|status| status.as_str() == s)
+                .ok_or_else(|| {
+                    DatabaseError::new(DatabaseErrorKind::InvalidQuery(format!(
+                        "Unknown content status '{}'",
+                        s
+                    )))
+                }),
+        }
+    }
+}
+
+/// `review_status` default of tables created before the lifecycle.
+///
+/// Parses as [`ContentStatus::PendingReview`]; queries by status should
+/// include it so legacy rows aren't skipped.
+pub const LEGACY_PENDING_STATUS: &str = "pending";
+
+/// One recorded lifecycle transition from `content_status_history`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, derive_getters::Getters)]
+pub struct ContentStatusChange {
+    /// Content table the item lives in
+    table_name: String,
+    /// ID of the item within its table
+    content_id: i64,
+    /// Status before the change
+    from_status: ContentStatus,
+    /// Status after the change
+    to_status: ContentStatus,
+    /// Who made the change (user, bot or actor name)
+    actor: String,
+    /// Why the change was made
+    reason: Option<String>,
+    /// When the change was made
+    changed_at: DateTime<Utc>,
+}
+
+impl ContentStatusChange {
+    /// Build a change from a `content_status_history` row.
+    fn from_row(row: &JsonValue) -> DatabaseResult<Self> {
+        let text = |field: &str| {
+            row.get(field).and_then(|v| v.as_str()).ok_or_else(|| {
+                DatabaseError::new(DatabaseErrorKind::Query(format!(
+                    "Status history row is missing '{}'",
+                    field
+                )))
+            })
+        };
+
+        Ok(Self {
+            table_name: text("table_name")?.to_string(),
+            content_id: row
+                .get("content_id")
+                .and_then(|v| v.as_i64())
+                .unwrap_or_default(),
+            from_status: text("from_status")?.parse()?,
+            to_status: text("to_status")?.parse()?,
+            actor: text("actor")?.to_string(),
+            reason: row
+                .get("reason")
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            changed_at: parse_timestamp(text("changed_at")?)?,
+        })
+    }
+}
+
+/// Parse a timestamp as PostgreSQL (RFC 3339, or ISO 8601 without a zone
+/// for `timestamp` columns) or SQLite (naive UTC) renders it.
+pub(crate) fn parse_timestamp(value: &str) -> DatabaseResult<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|ts| ts.with_timezone(&Utc))
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f")
+                .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f"))
+                .map(|ts| ts.and_utc())
+        })
+        .map_err(|e| {
+            DatabaseError::new(DatabaseErrorKind::Query(format!(
+                "Invalid timestamp '{}': {}",
+                value, e
+            )))
+        })
+}
+
+/// Read the lifecycle status of a content item.
+///
+/// A NULL `review_status` counts as [`ContentStatus::Generated`].
+///
+/// # Errors
+///
+/// Returns an error if the item doesn't exist, the table has no
+/// `review_status` column, or the stored value isn't a known status.
+#[instrument(name = "content_management.content_status", skip(conn), fields(table = %table_name, id = %id))]
+pub fn content_status<C: ContentConnection>(
+    conn: &mut C,
+    table_name: &str,
+    id: i64,
+) -> BotticelliResult<ContentStatus> {
+    let (_, status) = read_status(conn, table_name, id)?;
+    Ok(status)
+}
+
+/// Read the raw `review_status` of a content item along with its parsed status.
+fn read_status<C: ContentConnection>(
+    conn: &mut C,
+    table_name: &str,
+    id: i64,
+) -> BotticelliResult<(Option<String>, ContentStatus)> {
+    let content = get_content_by_id(conn, table_name, id)?;
+
+    let stored = match content.get("review_status") {
+        None => {
+            return Err(DatabaseError::new(DatabaseErrorKind::InvalidQuery(format!(
+                "Table '{}' has no review_status column",
+                table_name
+            )))
+            .into());
+        }
+        Some(value) => value.as_str().map(str::to_string),
+    };
+    let status = match &stored {
+        Some(status) => status.parse()?,
+        None => ContentStatus::Generated,
+    };
+
+    Ok((stored, status))
+}
+
+/// Move a content item to a new lifecycle status.
+///
+/// Checks the move against [`ContentStatus::can_transition_to`], then
+/// updates `review_status` and appends a `content_status_history` row in one
+/// transaction. Moving an item to the status it already has is a no-op.
 ///
 /// # Arguments
 ///
 /// * `conn` - Database connection
 /// * `table_name` - Name of the content table
 /// * `id` - Content ID
-/// * `status` - New status ("pending", "approved", "rejected")
-#[instrument(name = "content_management.update_review_status", skip(conn), fields(table = %table_name, id = %id, status = %status))]
-pub fn update_review_status(
-    conn: &mut PgConnection,
+/// * `to` - New status
+/// * `actor` - Who is making the change, recorded in the history
+/// * `reason` - Optional explanation, recorded in the history
+///
+/// # Returns
+///
+/// The status the item had before the change
+///
+/// # Errors
+///
+/// Returns an `InvalidTransition` error if the lifecycle forbids the move,
+/// and a query error if another writer changed the status concurrently.
+#[instrument(
+    name = "content_management.transition_content_status",
+    skip(conn, reason),
+    fields(table = %table_name, id = %id, to = %to, actor = %actor)
+)]
+pub fn transition_content_status<C: ContentConnection>(
+    conn: &mut C,
     table_name: &str,
     id: i64,
-    status: &str,
-) -> BotticelliResult<()> {
-    // Validate status
-    if !["pending", "approved", "rejected"].contains(&status) {
-        return Err(DatabaseError::new(DatabaseErrorKind::Query(
-            "Status must be 'pending', 'approved', or 'rejected'".to_string(),
-        ))
+    to: ContentStatus,
+    actor: &str,
+    reason: Option<&str>,
+) -> BotticelliResult<ContentStatus> {
+    let (stored, from) = read_status(conn, table_name, id)?;
+
+    if from == to {
+        tracing::debug!(status = %from, "Content already has requested status");
+        return Ok(from);
+    }
+
+    if !from.can_transition_to(to) {
+        return Err(DatabaseError::new(DatabaseErrorKind::InvalidTransition {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
         .into());
     }
 
+    let dialect = conn.dialect();
+    // Only update if the status is still the one we read, so two writers
+    // can't both apply a transition from the same starting point
+    let (guard, mut params) = match stored {
+        Some(status) => (
+            format!("review_status = {}", dialect.placeholder(3)),
+            vec![FilterValue::Text(status)],
+        ),
+        None => ("review_status IS NULL".to_string(), vec![]),
+    };
+    params.insert(0, FilterValue::Text(to.to_string()));
+    params.insert(1, FilterValue::Integer(id));
+
+    let update = format!(
+        "UPDATE {} SET review_status = {} WHERE id = {} AND {}",
+        table_name,
+        dialect.placeholder(1),
+        dialect.placeholder(2),
+        guard
+    );
+    let mut history = vec![
+        FilterValue::Text(table_name.to_string()),
+        FilterValue::Integer(id),
+        FilterValue::Text(from.to_string()),
+        FilterValue::Text(to.to_string()),
+        FilterValue::Text(actor.to_string()),
+    ];
+    let mut values: Vec<String> = (1..=history.len())
+        .map(|n| dialect.placeholder(n))
+        .collect();
+    match reason {
+        Some(reason) => {
+            history.push(FilterValue::Text(reason.to_string()));
+            values.push(dialect.placeholder(history.len()));
+        }
+        None => values.push("NULL".to_string()),
+    }
+    let insert = format!(
+        "INSERT INTO content_status_history \
+         (table_name, content_id, from_status, to_status, actor, reason) \
+         VALUES ({})",
+        values.join(", ")
+    );
+
+    conn.in_transaction(|conn| {
+        tracing::debug!(sql = %update, "Updating review status");
+        if conn.execute_sql(&update, &params)? == 0 {
+            return Err(DatabaseError::new(DatabaseErrorKind::Query(format!(
+                "Status of {} #{} changed concurrently",
+                table_name, id
+            ))));
+        }
+
+        conn.execute_sql(&insert, &history)?;
+        Ok(())
+    })?;
+
+    tracing::info!(from = %from, to = %to, actor, "Content status changed");
+    Ok(from)
+}
+
+/// List the recorded lifecycle transitions of a content item, oldest first.
+///
+/// # Arguments
+///
+/// * `conn` - Database connection
+/// * `table_name` - Name of the content table
+/// * `id` - Content ID
+#[instrument(name = "content_management.content_status_history", skip(conn), fields(table = %table_name, id = %id))]
+pub fn content_status_history<C: ContentConnection>(
+    conn: &mut C,
+    table_name: &str,
+    id: i64,
+) -> BotticelliResult<Vec<ContentStatusChange>> {
+    let schema = reflect_table_schema(conn, "content_status_history")?;
+    let dialect = conn.dialect();
     let query = format!(
-        "UPDATE {} SET review_status = '{}' WHERE id = {}",
-        table_name, status, id
+        "SELECT * FROM content_status_history \
+         WHERE table_name = {} AND content_id = {} \
+         ORDER BY changed_at ASC, id ASC",
+        dialect.placeholder(1),
+        dialect.placeholder(2)
     );
 
-    tracing::debug!(sql = %query, "Updating review status");
+    let rows = conn.load_json(
+        &query,
+        &schema.columns,
+        &[
+            FilterValue::Text(table_name.to_string()),
+            FilterValue::Integer(id),
+        ],
+    )?;
 
-    diesel::sql_query(&query)
-        .execute(conn)
-        .map_err(|e| DatabaseError::new(DatabaseErrorKind::Query(e.to_string())))?;
+    Ok(rows
+        .iter()
+        .map(ContentStatusChange::from_row)
+        .collect::<DatabaseResult<_>>()?)
+}
 
+/// Update review status for a content item.
+///
+/// Parses `status` as a [`ContentStatus`] and applies it with
+/// [`transition_content_status`], recording `actor` without a reason.
+///
+/// # Arguments
+///
+/// * `conn` - Database connection
+/// * `table_name` - Name of the content table
+/// * `id` - Content ID
+/// * `status` - New status, e.g. "pending_review", "approved", "rejected"
+/// * `actor` - Who is making the change
+#[instrument(name = "content_management.update_review_status", skip(conn), fields(table = %table_name, id = %id, status = %status))]
+pub fn update_review_status<C: ContentConnection>(
+    conn: &mut C,
+    table_name: &str,
+    id: i64,
+    status: &str,
+    actor: &str,
+) -> BotticelliResult<()> {
+    transition_content_status(conn, table_name, id, status.parse()?, actor, None)?;
     Ok(())
 }
 
@@ -239,14 +700,16 @@ pub fn update_review_status(
 /// * `table_name` - Name of the content table
 /// * `id` - Content ID
 #[instrument(name = "content_management.delete_content", skip(conn), fields(table = %table_name, id = %id))]
-pub fn delete_content(conn: &mut PgConnection, table_name: &str, id: i64) -> BotticelliResult<()> {
+pub fn delete_content<C: ContentConnection>(
+    conn: &mut C,
+    table_name: &str,
+    id: i64,
+) -> BotticelliResult<()> {
     let query = format!("DELETE FROM {} WHERE id = {}", table_name, id);
 
     tracing::debug!(sql = %query, "Deleting content");
 
-    diesel::sql_query(&query)
-        .execute(conn)
-        .map_err(|e| DatabaseError::new(DatabaseErrorKind::Query(e.to_string())))?;
+    conn.execute_sql(&query, &[])?;
 
     Ok(())
 }
@@ -266,13 +729,11 @@ pub fn delete_content(conn: &mut PgConnection, table_name: &str, id: i64) -> Bot
 ///
 /// Vector of JSON objects representing the pulled (and deleted) rows
 #[instrument(name = "content_management.pull_and_delete", skip(conn), fields(table = %table_name, limit = %limit))]
-pub fn pull_and_delete(
-    conn: &mut PgConnection,
+pub fn pull_and_delete<C: ContentConnection>(
+    conn: &mut C,
     table_name: &str,
     limit: usize,
 ) -> BotticelliResult<Vec<JsonValue>> {
-    use diesel::connection::TransactionManager;
-
     // Reflect table schema to build appropriate query
     let schema = reflect_table_schema(conn, table_name)?;
     let columns: std::collections::HashSet<_> =
@@ -281,12 +742,8 @@ pub fn pull_and_delete(
     let has_generated_at = columns.contains("generated_at");
     let has_id = columns.contains("id");
 
-    // Start transaction for atomic pull-and-delete
-    <PgConnection as diesel::connection::Connection>::TransactionManager::begin_transaction(conn)
-        .map_err(|e| DatabaseError::new(DatabaseErrorKind::Query(e.to_string())))?;
-
     // Build SELECT query with ORDER BY
-    let mut select_query = format!("SELECT row_to_json(t) FROM (SELECT * FROM {}", table_name);
+    let mut select_query = format!("SELECT * FROM {}", table_name);
 
     // Order by generated_at if it exists, otherwise by id if it exists
     if has_generated_at {
@@ -296,71 +753,42 @@ pub fn pull_and_delete(
     }
 
     select_query.push_str(&format!(" LIMIT {}", limit));
-    select_query.push_str(") t");
 
-    tracing::debug!(sql = %select_query, "Pulling content for destructive read");
+    // Pull and delete atomically
+    let json_results = conn.in_transaction(|conn| {
+        tracing::debug!(sql = %select_query, "Pulling content for destructive read");
+
+        let json_results = conn.load_json(&select_query, &schema.columns, &[])?;
+
+        // Extract IDs for deletion
+        let ids: Vec<i64> = json_results
+            .iter()
+            .filter_map(|obj| obj.get("id").and_then(|v| v.as_i64()))
+            .collect();
+
+        if !ids.is_empty() {
+            let delete_query = format!(
+                "DELETE FROM {} WHERE id IN ({})",
+                table_name,
+                ids.iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
 
-    // Execute SELECT
-    let results: Vec<String> = diesel::sql_query(&select_query)
-        .load::<StringRow>(conn)
-        .map_err(|e| {
-            let _ = <PgConnection as diesel::connection::Connection>::TransactionManager::rollback_transaction(conn);
-            DatabaseError::new(DatabaseErrorKind::Query(e.to_string()))
-        })?
-        .into_iter()
-        .map(|row| row.row_to_json)
-        .collect();
+            tracing::debug!(sql = %delete_query, count = ids.len(), "Deleting pulled content");
 
-    // Parse JSON to extract IDs for deletion
-    let json_results: Vec<JsonValue> = results
-        .iter()
-        .map(|s| serde_json::from_str(s))
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| {
-            let _ = <PgConnection as diesel::connection::Connection>::TransactionManager::rollback_transaction(conn);
-            DatabaseError::new(DatabaseErrorKind::Query(e.to_string()))
-        })?;
+            conn.execute_sql(&delete_query, &[])?;
+        }
+
+        Ok(json_results)
+    })?;
 
     if json_results.is_empty() {
-        // No records to delete, commit empty transaction
-        <PgConnection as diesel::connection::Connection>::TransactionManager::commit_transaction(
-            conn,
-        )
-        .map_err(|e| DatabaseError::new(DatabaseErrorKind::Query(e.to_string())))?;
         tracing::debug!("No content found to pull");
         return Ok(vec![]);
     }
 
-    // Extract IDs for deletion
-    let ids: Vec<i64> = json_results
-        .iter()
-        .filter_map(|obj| obj.get("id").and_then(|v| v.as_i64()))
-        .collect();
-
-    if !ids.is_empty() {
-        let delete_query = format!(
-            "DELETE FROM {} WHERE id IN ({})",
-            table_name,
-            ids.iter()
-                .map(|id| id.to_string())
-                .collect::<Vec<_>>()
-                .join(", ")
-        );
-
-        tracing::debug!(sql = %delete_query, count = ids.len(), "Deleting pulled content");
-
-        diesel::sql_query(&delete_query)
-            .execute(conn)
-            .map_err(|e| {
-                let _ = <PgConnection as diesel::connection::Connection>::TransactionManager::rollback_transaction(conn);
-                DatabaseError::new(DatabaseErrorKind::Query(e.to_string()))
-            })?;
-    }
-
-    // Commit transaction
-    <PgConnection as diesel::connection::Connection>::TransactionManager::commit_transaction(conn)
-        .map_err(|e| DatabaseError::new(DatabaseErrorKind::Query(e.to_string())))?;
-
     tracing::info!(
         count = json_results.len(),
         "Pulled and deleted content items"
@@ -372,6 +800,7 @@ pub fn pull_and_delete(
 ///
 /// Copies a content item from a generation table to a production table,
 /// stripping metadata columns and handling foreign key relationships.
+/// Only [`ContentStatus::Approved`] content can be promoted.
 ///
 /// # Arguments
 ///
@@ -384,8 +813,8 @@ pub fn pull_and_delete(
 ///
 /// The ID of the inserted row in the target table
 #[instrument(name = "content_management.promote_content", skip(conn), fields(source = %source_table, target = %target_table, id = %id))]
-pub fn promote_content(
-    conn: &mut PgConnection,
+pub fn promote_content<C: ContentConnection>(
+    conn: &mut C,
     source_table: &str,
     target_table: &str,
     id: i64,
@@ -397,6 +826,15 @@ pub fn promote_content(
         "Promoting content"
     );
 
+    let status = content_status(conn, source_table, id)?;
+    if status != ContentStatus::Approved {
+        return Err(DatabaseError::new(DatabaseErrorKind::InvalidQuery(format!(
+            "Only approved content can be promoted ({} #{} is {})",
+            source_table, id, status
+        )))
+        .into());
+    }
+
     // Get the source content
     let content = get_content_by_id(conn, source_table, id)?;
 
@@ -404,20 +842,10 @@ pub fn promote_content(
     let target_schema = reflect_table_schema(conn, target_table)?;
 
     // Build column list (exclude metadata columns)
-    let metadata_columns = [
-        "generated_at",
-        "source_narrative",
-        "source_act",
-        "generation_model",
-        "review_status",
-        "tags",
-        "rating",
-    ];
-
     let target_columns: Vec<String> = target_schema
         .columns
         .iter()
-        .filter(|col| col.name != "id" && !metadata_columns.contains(&col.name.as_str()))
+        .filter(|col| col.name != "id" && !CONTENT_METADATA_COLUMNS.contains(&col.name.as_str()))
         .map(|col| col.name.clone())
         .collect();
 
@@ -432,7 +860,7 @@ pub fn promote_content(
     let mut values = Vec::new();
     for col_name in &target_columns {
         if let Some(value) = content.get(col_name) {
-            values.push(json_value_to_sql(value));
+            values.push(json_value_to_sql(conn.dialect(), value));
         } else {
             // Column exists in target but not in source - use NULL
             values.push("NULL".to_string());
@@ -450,10 +878,7 @@ pub fn promote_content(
     tracing::debug!(sql = %insert_sql, "Inserting promoted content");
 
     // Execute and get the new ID
-    let new_id: i64 = diesel::sql_query(&insert_sql)
-        .get_result::<IdRow>(conn)
-        .map_err(|e| DatabaseError::new(DatabaseErrorKind::Query(e.to_string())))?
-        .id;
+    let new_id = conn.insert_returning_id(&insert_sql, &[])?;
 
     tracing::info!(new_id = new_id, "Content promoted successfully");
 
@@ -461,28 +886,12 @@ pub fn promote_content(
 }
 
 /// Helper to convert JSON value to SQL string.
-fn json_value_to_sql(value: &JsonValue) -> String {
+pub(crate) fn json_value_to_sql(dialect: SqlDialect, value: &JsonValue) -> String {
     match value {
         JsonValue::Null => "NULL".to_string(),
         JsonValue::Bool(b) => b.to_string(),
         JsonValue::Number(n) => n.to_string(),
         JsonValue::String(s) => format!("'{}'", s.replace('\'', "''")),
-        JsonValue::Array(_) | JsonValue::Object(_) => {
-            format!("'{}'::jsonb", value.to_string().replace('\'', "''"))
-        }
+        JsonValue::Array(_) | JsonValue::Object(_) => dialect.json_literal(value),
     }
 }
-
-/// Helper struct for deserializing row_to_json results.
-#[derive(QueryableByName)]
-struct StringRow {
-    #[diesel(sql_type = diesel::sql_types::Text)]
-    row_to_json: String,
-}
-
-/// Helper struct for deserializing RETURNING id.
-#[derive(QueryableByName)]
-struct IdRow {
-    #[diesel(sql_type = diesel::sql_types::BigInt)]
-    id: i64,
-}
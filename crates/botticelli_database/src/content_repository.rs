@@ -3,28 +3,43 @@
 //! Provides database-backed implementation of the ContentRepository trait
 //! for managing generated content tables.
 
+use crate::{ContentConnection, with_pooled_connection};
 use async_trait::async_trait;
 use botticelli_error::BotticelliResult;
 use botticelli_interface::ContentRepository;
 use diesel::pg::PgConnection;
-use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::r2d2::{ConnectionManager, ManageConnection, Pool};
 use serde_json::Value as JsonValue;
 
+/// Actor recorded in the status history when none is given.
+const REPOSITORY_ACTOR: &str = "content_repository";
+
 /// Database-backed content repository.
-#[derive(Clone)]
-pub struct DatabaseContentRepository {
-    pool: Pool<ConnectionManager<PgConnection>>,
+pub struct DatabaseContentRepository<M: ManageConnection = ConnectionManager<PgConnection>> {
+    pool: Pool<M>,
+}
+
+impl<M: ManageConnection> Clone for DatabaseContentRepository<M> {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+        }
+    }
 }
 
-impl DatabaseContentRepository {
+impl<M: ManageConnection> DatabaseContentRepository<M> {
     /// Create a new content repository with the given connection pool.
-    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+    pub fn new(pool: Pool<M>) -> Self {
         Self { pool }
     }
 }
 
 #[async_trait]
-impl ContentRepository for DatabaseContentRepository {
+impl<M> ContentRepository for DatabaseContentRepository<M>
+where
+    M: ManageConnection,
+    M::Connection: ContentConnection,
+{
     async fn list_content(
         &self,
         table_name: &str,
@@ -33,27 +48,16 @@ impl ContentRepository for DatabaseContentRepository {
     ) -> BotticelliResult<Vec<JsonValue>> {
         let table_name = table_name.to_string();
         let status_filter = status_filter.map(|s| s.to_string());
-        let pool = self.pool.clone();
 
-        tokio::task::spawn_blocking(move || {
-            let mut conn = pool.get().map_err(|e| {
-                botticelli_error::DatabaseError::new(
-                    botticelli_error::DatabaseErrorKind::Connection(e.to_string()),
-                )
-            })?;
+        with_pooled_connection(&self.pool, move |conn| {
             crate::content_management::list_content(
-                &mut conn,
+                conn,
                 &table_name,
                 status_filter.as_deref(),
                 limit,
             )
         })
         .await
-        .map_err(|e| {
-            botticelli_error::DatabaseError::new(botticelli_error::DatabaseErrorKind::Query(
-                e.to_string(),
-            ))
-        })?
     }
 
     async fn update_review_status(
@@ -61,45 +65,41 @@ impl ContentRepository for DatabaseContentRepository {
         table_name: &str,
         id: i64,
         new_status: &str,
+    ) -> BotticelliResult<()> {
+        self.update_review_status_as(table_name, id, new_status, REPOSITORY_ACTOR)
+            .await
+    }
+
+    async fn update_review_status_as(
+        &self,
+        table_name: &str,
+        id: i64,
+        new_status: &str,
+        actor: &str,
     ) -> BotticelliResult<()> {
         let table_name = table_name.to_string();
         let new_status = new_status.to_string();
-        let pool = self.pool.clone();
+        let actor = actor.to_string();
 
-        tokio::task::spawn_blocking(move || {
-            let mut conn = pool.get().map_err(|e| {
-                botticelli_error::DatabaseError::new(
-                    botticelli_error::DatabaseErrorKind::Connection(e.to_string()),
-                )
-            })?;
-            crate::content_management::update_review_status(&mut conn, &table_name, id, &new_status)
+        with_pooled_connection(&self.pool, move |conn| {
+            crate::content_management::update_review_status(
+                conn,
+                &table_name,
+                id,
+                &new_status,
+                &actor,
+            )
         })
         .await
-        .map_err(|e| {
-            botticelli_error::DatabaseError::new(botticelli_error::DatabaseErrorKind::Query(
-                e.to_string(),
-            ))
-        })?
     }
 
     async fn delete_content(&self, table_name: &str, id: i64) -> BotticelliResult<()> {
         let table_name = table_name.to_string();
-        let pool = self.pool.clone();
 
-        tokio::task::spawn_blocking(move || {
-            let mut conn = pool.get().map_err(|e| {
-                botticelli_error::DatabaseError::new(
-                    botticelli_error::DatabaseErrorKind::Connection(e.to_string()),
-                )
-            })?;
-            crate::content_management::delete_content(&mut conn, &table_name, id)
+        with_pooled_connection(&self.pool, move |conn| {
+            crate::content_management::delete_content(conn, &table_name, id)
         })
         .await
-        .map_err(|e| {
-            botticelli_error::DatabaseError::new(botticelli_error::DatabaseErrorKind::Query(
-                e.to_string(),
-            ))
-        })?
     }
 
     async fn pull_and_delete(
@@ -108,21 +108,10 @@ impl ContentRepository for DatabaseContentRepository {
         limit: usize,
     ) -> BotticelliResult<Vec<JsonValue>> {
         let table_name = table_name.to_string();
-        let pool = self.pool.clone();
 
-        tokio::task::spawn_blocking(move || {
-            let mut conn = pool.get().map_err(|e| {
-                botticelli_error::DatabaseError::new(
-                    botticelli_error::DatabaseErrorKind::Connection(e.to_string()),
-                )
-            })?;
-            crate::content_management::pull_and_delete(&mut conn, &table_name, limit)
+        with_pooled_connection(&self.pool, move |conn| {
+            crate::content_management::pull_and_delete(conn, &table_name, limit)
         })
         .await
-        .map_err(|e| {
-            botticelli_error::DatabaseError::new(botticelli_error::DatabaseErrorKind::Query(
-                e.to_string(),
-            ))
-        })?
     }
 }
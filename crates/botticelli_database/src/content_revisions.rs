@@ -0,0 +1,512 @@
+//! Revision history for content items.
+//!
+//! Every edit to a content row is recorded in `content_revisions` as a full
+//! snapshot plus the fields it changed. The first revision keeps the row as
+//! the model generated it, so published content can always be compared
+//! with the original output.
+
+use crate::content_management::parse_timestamp;
+use crate::schema_reflection::{ColumnInfo, reflect_table_schema};
+use crate::table_filter::CASTABLE_TYPES;
+use crate::{ContentConnection, DatabaseResult, SqlDialect};
+use botticelli_core::FilterValue;
+use botticelli_error::{BotticelliResult, DatabaseError, DatabaseErrorKind};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value as JsonValue, json};
+use tracing::instrument;
+
+/// Columns that edits and reverts never touch.
+///
+/// `review_status` changes go through the content lifecycle instead.
+const UNVERSIONED_COLUMNS: [&str; 2] = ["id", "review_status"];
+
+/// One recorded revision of a content item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, derive_getters::Getters)]
+pub struct ContentRevision {
+    /// Content table the item lives in
+    table_name: String,
+    /// ID of the item within its table
+    content_id: i64,
+    /// Revision number, starting at 1 for the original output
+    revision: i64,
+    /// Full row as of this revision
+    content: JsonValue,
+    /// Who made the edit; the generating model for revision 1
+    editor: String,
+    /// Changed fields, as `{"field": {"old": ..., "new": ...}}`
+    diff: JsonValue,
+    /// When the revision was recorded
+    created_at: DateTime<Utc>,
+}
+
+impl ContentRevision {
+    /// Build a revision from a `content_revisions` row.
+    fn from_row(row: &JsonValue) -> DatabaseResult<Self> {
+        let field = |name: &str| {
+            row.get(name).ok_or_else(|| {
+                DatabaseError::new(DatabaseErrorKind::Query(format!(
+                    "Revision row is missing '{}'",
+                    name
+                )))
+            })
+        };
+        let text = |name: &str| {
+            field(name)?.as_str().map(str::to_string).ok_or_else(|| {
+                DatabaseError::new(DatabaseErrorKind::Query(format!(
+                    "Revision field '{}' is not text",
+                    name
+                )))
+            })
+        };
+
+        Ok(Self {
+            table_name: text("table_name")?,
+            content_id: field("content_id")?.as_i64().unwrap_or_default(),
+            revision: field("revision")?.as_i64().unwrap_or_default(),
+            content: field("content")?.clone(),
+            editor: text("editor")?,
+            diff: field("diff")?.clone(),
+            created_at: parse_timestamp(&text("created_at")?)?,
+        })
+    }
+
+    /// Names of the fields this revision changed.
+    pub fn changed_fields(&self) -> Vec<&str> {
+        self.diff
+            .as_object()
+            .map(|diff| diff.keys().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Apply an edit to a content item and record it as a revision.
+///
+/// Snapshots the row before and after `edit` in one transaction. The first
+/// time an item is edited its current row is stored as revision 1, credited
+/// to its `generation_model` (or "model"). Edits that only touch
+/// unversioned columns record nothing.
+///
+/// # Returns
+///
+/// The new revision number, or `None` if no versioned field changed
+#[instrument(name = "content_revisions.with_revision", skip(conn, edit), fields(table = %table_name, id = %id, editor = %editor))]
+pub fn with_revision<C, F>(
+    conn: &mut C,
+    table_name: &str,
+    id: i64,
+    editor: &str,
+    edit: F,
+) -> BotticelliResult<Option<i64>>
+where
+    C: ContentConnection,
+    F: FnOnce(&mut C) -> DatabaseResult<()>,
+{
+    let content_columns = reflect_table_schema(conn, table_name)?.columns;
+    let revision_columns = reflect_table_schema(conn, "content_revisions")?.columns;
+
+    let revision = conn.in_transaction(|conn| {
+        let before = snapshot(conn, table_name, &content_columns, id)?;
+
+        let latest = match latest_revision(conn, &revision_columns, table_name, id)? {
+            Some(latest) => latest,
+            None => {
+                let model = before
+                    .get("generation_model")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("model");
+                insert_revision(conn, table_name, id, 1, &before, model, &json!({}))?;
+                1
+            }
+        };
+
+        edit(conn)?;
+
+        let after = snapshot(conn, table_name, &content_columns, id)?;
+        let diff = diff_content(&before, &after);
+        if diff.is_empty() {
+            return Ok(None);
+        }
+
+        let revision = latest + 1;
+        insert_revision(
+            conn,
+            table_name,
+            id,
+            revision,
+            &after,
+            editor,
+            &JsonValue::Object(diff),
+        )?;
+        Ok(Some(revision))
+    })?;
+
+    if let Some(revision) = revision {
+        tracing::info!(revision, "Recorded content revision");
+    }
+    Ok(revision)
+}
+
+/// Edit fields of a content item, recording a revision.
+///
+/// # Arguments
+///
+/// * `conn` - Database connection
+/// * `table_name` - Name of the content table
+/// * `id` - Content ID
+/// * `changes` - New values by column name
+/// * `editor` - Who is making the edit
+///
+/// # Errors
+///
+/// Returns an error if a column doesn't exist or is `id` or `review_status`.
+#[instrument(name = "content_revisions.edit_content", skip(conn, changes), fields(table = %table_name, id = %id, editor = %editor))]
+pub fn edit_content<C: ContentConnection>(
+    conn: &mut C,
+    table_name: &str,
+    id: i64,
+    changes: &Map<String, JsonValue>,
+    editor: &str,
+) -> BotticelliResult<Option<i64>> {
+    if changes.is_empty() {
+        return Ok(None);
+    }
+
+    let columns = reflect_table_schema(conn, table_name)?.columns;
+    let dialect = conn.dialect();
+    let mut params = Vec::new();
+
+    let assignments = changes
+        .iter()
+        .map(|(name, value)| {
+            let column = columns
+                .iter()
+                .find(|c| &c.name == name && !UNVERSIONED_COLUMNS.contains(&name.as_str()))
+                .ok_or_else(|| {
+                    DatabaseError::new(DatabaseErrorKind::InvalidQuery(format!(
+                        "Column '{}' of '{}' cannot be edited",
+                        name, table_name
+                    )))
+                })?;
+            Ok(format!(
+                "{} = {}",
+                name,
+                column_value_sql(dialect, column, value, &mut params)
+            ))
+        })
+        .collect::<DatabaseResult<Vec<_>>>()?;
+
+    params.push(FilterValue::Integer(id));
+    let query = format!(
+        "UPDATE {} SET {} WHERE id = {}",
+        table_name,
+        assignments.join(", "),
+        dialect.placeholder(params.len())
+    );
+
+    tracing::debug!(sql = %query, "Editing content");
+
+    with_revision(conn, table_name, id, editor, |conn| {
+        conn.execute_sql(&query, &params)?;
+        Ok(())
+    })
+}
+
+/// Insert a hand-written content item.
+///
+/// Content tables take their `id` from generated output rather than a
+/// sequence, so the new row gets the next id after the table's highest. It
+/// starts the lifecycle as `generated`, and its first revision is credited
+/// to `author` rather than a model.
+///
+/// # Arguments
+///
+/// * `conn` - Database connection
+/// * `table_name` - Name of the content table
+/// * `values` - Column values for the new row
+/// * `author` - Who wrote the item
+///
+/// # Returns
+///
+/// The ID of the inserted row
+///
+/// # Errors
+///
+/// Returns an error if a column doesn't exist or is `id` or `review_status`.
+#[instrument(name = "content_revisions.insert_content", skip(conn, values), fields(table = %table_name, author = %author))]
+pub fn insert_content<C: ContentConnection>(
+    conn: &mut C,
+    table_name: &str,
+    values: &Map<String, JsonValue>,
+    author: &str,
+) -> BotticelliResult<i64> {
+    let columns = reflect_table_schema(conn, table_name)?.columns;
+    let dialect = conn.dialect();
+    let mut params = Vec::new();
+
+    let mut names = vec!["id"];
+    let mut literals = vec![format!(
+        "(SELECT COALESCE(MAX(id), 0) + 1 FROM {})",
+        table_name
+    )];
+    for (name, value) in values {
+        let column = columns
+            .iter()
+            .find(|c| &c.name == name && !UNVERSIONED_COLUMNS.contains(&name.as_str()))
+            .ok_or_else(|| {
+                DatabaseError::new(DatabaseErrorKind::InvalidQuery(format!(
+                    "Column '{}' of '{}' cannot be written",
+                    name, table_name
+                )))
+            })?;
+        names.push(name.as_str());
+        literals.push(column_value_sql(dialect, column, value, &mut params));
+    }
+
+    if values.is_empty() {
+        return Err(DatabaseError::new(DatabaseErrorKind::InvalidQuery(format!(
+            "No values given for new '{}' item",
+            table_name
+        )))
+        .into());
+    }
+
+    let query = format!(
+        "INSERT INTO {} ({}) VALUES ({}) RETURNING id",
+        table_name,
+        names.join(", "),
+        literals.join(", ")
+    );
+
+    tracing::debug!(sql = %query, "Inserting content");
+
+    let id = conn.in_transaction(|conn| {
+        let id = conn.insert_returning_id(&query, &params)?;
+        let row = snapshot(conn, table_name, &columns, id)?;
+        insert_revision(conn, table_name, id, 1, &row, author, &json!({}))?;
+        Ok(id)
+    })?;
+
+    tracing::info!(id, "Inserted content");
+    Ok(id)
+}
+
+/// List the revisions of a content item, oldest first.
+#[instrument(name = "content_revisions.content_revisions", skip(conn), fields(table = %table_name, id = %id))]
+pub fn content_revisions<C: ContentConnection>(
+    conn: &mut C,
+    table_name: &str,
+    id: i64,
+) -> BotticelliResult<Vec<ContentRevision>> {
+    let schema = reflect_table_schema(conn, "content_revisions")?;
+    let dialect = conn.dialect();
+    let query = format!(
+        "SELECT * FROM content_revisions WHERE table_name = {} AND content_id = {} \
+         ORDER BY revision ASC",
+        dialect.placeholder(1),
+        dialect.placeholder(2)
+    );
+    let params = [
+        FilterValue::Text(table_name.to_string()),
+        FilterValue::Integer(id),
+    ];
+
+    Ok(conn
+        .load_json(&query, &schema.columns, &params)?
+        .iter()
+        .map(ContentRevision::from_row)
+        .collect::<DatabaseResult<_>>()?)
+}
+
+/// Restore a content item to an earlier revision.
+///
+/// Only fields that differ from the current row are written, and the
+/// restore itself is recorded as a new revision by `editor`.
+///
+/// # Returns
+///
+/// The new revision number, or `None` if the item already matches
+#[instrument(name = "content_revisions.revert_content", skip(conn), fields(table = %table_name, id = %id, revision = %revision, editor = %editor))]
+pub fn revert_content<C: ContentConnection>(
+    conn: &mut C,
+    table_name: &str,
+    id: i64,
+    revision: i64,
+    editor: &str,
+) -> BotticelliResult<Option<i64>> {
+    let target = content_revisions(conn, table_name, id)?
+        .into_iter()
+        .find(|r| r.revision == revision)
+        .ok_or_else(|| DatabaseError::new(DatabaseErrorKind::NotFound))?;
+
+    let columns = reflect_table_schema(conn, table_name)?.columns;
+    let current = snapshot(conn, table_name, &columns, id)?;
+
+    let changes: Map<String, JsonValue> = diff_content(&current, &target.content)
+        .into_iter()
+        // Columns added after the revision was taken are left alone
+        .filter(|(name, _)| target.content.get(name).is_some())
+        .filter(|(name, _)| columns.iter().any(|c| &c.name == name))
+        .map(|(name, change)| (name, change["new"].clone()))
+        .collect();
+
+    edit_content(conn, table_name, id, &changes, editor)
+}
+
+/// Field-level differences between two snapshots of a row.
+fn diff_content(before: &JsonValue, after: &JsonValue) -> Map<String, JsonValue> {
+    let empty = Map::new();
+    let before = before.as_object().unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+
+    let mut names: Vec<&String> = before.keys().chain(after.keys()).collect();
+    names.sort();
+    names.dedup();
+
+    names
+        .into_iter()
+        .filter(|name| !UNVERSIONED_COLUMNS.contains(&name.as_str()))
+        .filter(|name| before.get(*name) != after.get(*name))
+        .map(|name| {
+            let old = before.get(name).cloned().unwrap_or(JsonValue::Null);
+            let new = after.get(name).cloned().unwrap_or(JsonValue::Null);
+            (name.clone(), json!({ "old": old, "new": new }))
+        })
+        .collect()
+}
+
+/// Current row of a content item.
+fn snapshot<C: ContentConnection>(
+    conn: &mut C,
+    table_name: &str,
+    columns: &[ColumnInfo],
+    id: i64,
+) -> DatabaseResult<JsonValue> {
+    let query = format!(
+        "SELECT * FROM {} WHERE id = {}",
+        table_name,
+        conn.dialect().placeholder(1)
+    );
+    conn.load_json(&query, columns, &[FilterValue::Integer(id)])?
+        .into_iter()
+        .next()
+        .ok_or_else(|| DatabaseError::new(DatabaseErrorKind::NotFound))
+}
+
+/// Highest revision number recorded for a content item.
+fn latest_revision<C: ContentConnection>(
+    conn: &mut C,
+    revision_columns: &[ColumnInfo],
+    table_name: &str,
+    id: i64,
+) -> DatabaseResult<Option<i64>> {
+    let columns: Vec<ColumnInfo> = revision_columns
+        .iter()
+        .filter(|c| c.name == "revision")
+        .cloned()
+        .collect();
+    let dialect = conn.dialect();
+    let query = format!(
+        "SELECT revision FROM content_revisions WHERE table_name = {} AND content_id = {} \
+         ORDER BY revision DESC LIMIT 1",
+        dialect.placeholder(1),
+        dialect.placeholder(2)
+    );
+    let params = [
+        FilterValue::Text(table_name.to_string()),
+        FilterValue::Integer(id),
+    ];
+
+    Ok(conn
+        .load_json(&query, &columns, &params)?
+        .first()
+        .and_then(|row| row.get("revision")?.as_i64()))
+}
+
+/// Append a row to `content_revisions`.
+fn insert_revision<C: ContentConnection>(
+    conn: &mut C,
+    table_name: &str,
+    id: i64,
+    revision: i64,
+    content: &JsonValue,
+    editor: &str,
+    diff: &JsonValue,
+) -> DatabaseResult<()> {
+    let dialect = conn.dialect();
+    let json_param = |n| match dialect {
+        SqlDialect::Postgres => format!("CAST({} AS jsonb)", dialect.placeholder(n)),
+        SqlDialect::Sqlite => dialect.placeholder(n),
+    };
+    let query = format!(
+        "INSERT INTO content_revisions (table_name, content_id, revision, content, editor, diff) \
+         VALUES ({}, {}, {}, {}, {}, {})",
+        dialect.placeholder(1),
+        dialect.placeholder(2),
+        dialect.placeholder(3),
+        json_param(4),
+        dialect.placeholder(5),
+        json_param(6)
+    );
+    let params = [
+        FilterValue::Text(table_name.to_string()),
+        FilterValue::Integer(id),
+        FilterValue::Integer(revision),
+        FilterValue::Text(content.to_string()),
+        FilterValue::Text(editor.to_string()),
+        FilterValue::Text(diff.to_string()),
+    ];
+
+    conn.execute_sql(&query, &params)?;
+    Ok(())
+}
+
+/// SQL expression writing `value` to `column`, binding it into `params`.
+///
+/// Postgres reports array columns by element type (e.g. `_text`); arrays
+/// are bound as JSON and unpacked into the column's array type. Text bound
+/// to other typed columns is cast, since Postgres won't assign `text` to a
+/// timestamp or number. SQLite stores arrays as JSON and needs no casts.
+pub(crate) fn column_value_sql(
+    dialect: SqlDialect,
+    column: &ColumnInfo,
+    value: &JsonValue,
+    params: &mut Vec<FilterValue>,
+) -> String {
+    let data_type = column.data_type.as_str();
+    let param = match value {
+        JsonValue::Null => return "NULL".to_string(),
+        JsonValue::Bool(b) => FilterValue::Bool(*b),
+        JsonValue::Number(n) => match n.as_i64() {
+            Some(n) => FilterValue::Integer(n),
+            None => FilterValue::Float(n.as_f64().unwrap_or_default()),
+        },
+        JsonValue::String(s) if !matches!(data_type, "json" | "jsonb") => {
+            FilterValue::Text(s.clone())
+        }
+        other => FilterValue::Text(other.to_string()),
+    };
+    let is_text = matches!(param, FilterValue::Text(_));
+    params.push(param);
+    let placeholder = dialect.placeholder(params.len());
+
+    if dialect == SqlDialect::Sqlite {
+        return placeholder;
+    }
+    match (data_type, value) {
+        ("json" | "jsonb", _) => format!("CAST({} AS jsonb)", placeholder),
+        (array, JsonValue::Array(_)) if array.starts_with('_') => format!(
+            "CAST(ARRAY(SELECT jsonb_array_elements_text(CAST({} AS jsonb))) AS {}[])",
+            placeholder,
+            &array[1..]
+        ),
+        // Anything else bound to an array column is taken as an array literal
+        (array, _) if array.starts_with('_') => {
+            format!("CAST({} AS {}[])", placeholder, &array[1..])
+        }
+        (scalar, _) if is_text && CASTABLE_TYPES.contains(&scalar) => {
+            format!("CAST({} AS {})", placeholder, scalar)
+        }
+        _ => placeholder,
+    }
+}
@@ -0,0 +1,668 @@
+//! Export and import of content tables in portable formats.
+//!
+//! Exports write every row of a table as JSON Lines, CSV or Parquet. Imports
+//! read those files back, creating the table from the rows' inferred schema
+//! when it doesn't exist, and upsert by content hash: a row whose content
+//! columns match an existing row only updates its curation metadata, so the
+//! same file can be imported repeatedly without duplicating content.
+
+use crate::content_management::{CONTENT_METADATA_COLUMNS, ContentStatus, parse_timestamp};
+use crate::content_revisions::column_value_sql;
+use crate::schema_inference::{ColumnDefinition, create_inferred_table, infer_schema};
+use crate::schema_reflection::{ColumnInfo, reflect_table_schema};
+use crate::{ContentConnection, DatabaseResult};
+use botticelli_core::FilterValue;
+use botticelli_error::{BotticelliResult, DatabaseError, DatabaseErrorKind};
+use serde_json::{Map, Value as JsonValue};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use tracing::instrument;
+
+/// Metadata columns an import updates on rows whose content already exists.
+const CURATION_COLUMNS: [&str; 3] = ["review_status", "tags", "rating"];
+
+/// File format for exporting and importing content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContentFormat {
+    /// One JSON object per line
+    Jsonl,
+    /// Comma-separated values with a header row
+    Csv,
+    /// Apache Parquet (needs the `parquet` feature)
+    Parquet,
+}
+
+impl ContentFormat {
+    /// Format named by a file extension (`jsonl`, `ndjson`, `csv`, `parquet`).
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "jsonl" | "ndjson" => Some(Self::Jsonl),
+            "csv" => Some(Self::Csv),
+            "parquet" => Some(Self::Parquet),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ContentFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Jsonl => "jsonl",
+            Self::Csv => "csv",
+            Self::Parquet => "parquet",
+        })
+    }
+}
+
+/// What an import changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, derive_getters::Getters)]
+pub struct ImportSummary {
+    /// Whether the table was created from the imported rows
+    created_table: bool,
+    /// Rows whose content wasn't in the table
+    inserted: usize,
+    /// Existing rows whose status, tags or rating changed
+    updated: usize,
+    /// Rows already in the table as imported
+    unchanged: usize,
+}
+
+/// Write every row of a content table to `writer`.
+///
+/// Rows are written in `id` order when the table has an `id` column.
+///
+/// # Returns
+///
+/// The number of rows written
+///
+/// # Errors
+///
+/// Returns an error if the table doesn't exist, the rows can't be written,
+/// or Parquet is requested without the `parquet` feature.
+#[instrument(name = "content_transfer.export_content", skip(conn, writer), fields(table = %table_name, format = %format))]
+pub fn export_content<C: ContentConnection, W: Write + Send>(
+    conn: &mut C,
+    table_name: &str,
+    format: ContentFormat,
+    writer: W,
+) -> BotticelliResult<usize> {
+    let columns = reflect_table_schema(conn, table_name)?.columns;
+    let order = if columns.iter().any(|c| c.name == "id") {
+        " ORDER BY id"
+    } else {
+        ""
+    };
+    let rows = conn.load_json(
+        &format!("SELECT * FROM {}{}", table_name, order),
+        &columns,
+        &[],
+    )?;
+
+    match format {
+        ContentFormat::Jsonl => write_jsonl(&rows, writer)?,
+        ContentFormat::Csv => write_csv(&columns, &rows, writer)?,
+        ContentFormat::Parquet => write_parquet(&columns, &rows, writer)?,
+    }
+
+    tracing::info!(rows = rows.len(), "Exported content");
+    Ok(rows.len())
+}
+
+/// Import rows into a content table, creating it if it doesn't exist.
+///
+/// A missing table is created with columns inferred from the rows plus the
+/// standard content metadata columns. Each row is matched to existing rows
+/// by a hash of its content columns (everything but `id` and metadata):
+/// new content is inserted with the next free `id`, and matching rows take
+/// the imported `review_status`, `tags` and `rating`. Statuses are copied
+/// as they are, without lifecycle checks. Columns the table doesn't have
+/// are ignored. The import runs in one transaction.
+///
+/// # Errors
+///
+/// Returns an error if the data can't be parsed, has no rows to create a
+/// missing table from, or a row can't be written.
+#[instrument(name = "content_transfer.import_content", skip(conn, reader), fields(table = %table_name, format = %format))]
+pub fn import_content<C: ContentConnection, R: Read>(
+    conn: &mut C,
+    table_name: &str,
+    format: ContentFormat,
+    mut reader: R,
+) -> BotticelliResult<ImportSummary> {
+    let mut data = Vec::new();
+    reader
+        .read_to_end(&mut data)
+        .map_err(|e| serialization_error(format!("Failed to read import data: {}", e)))?;
+
+    let records = match format {
+        ContentFormat::Jsonl => read_jsonl(&data)?,
+        ContentFormat::Csv => read_csv(&data)?,
+        ContentFormat::Parquet => read_parquet(data)?,
+    };
+
+    let summary = conn.in_transaction(|conn| {
+        let mut summary = ImportSummary::default();
+        if !conn.table_exists(table_name)? {
+            create_table_for(conn, table_name, &records, format == ContentFormat::Csv)?;
+            summary.created_table = true;
+        }
+
+        let columns = reflect_table_schema(conn, table_name)?.columns;
+        upsert_records(conn, table_name, &columns, records, &mut summary)?;
+        Ok(summary)
+    })?;
+
+    tracing::info!(
+        created_table = summary.created_table,
+        inserted = summary.inserted,
+        updated = summary.updated,
+        unchanged = summary.unchanged,
+        "Imported content"
+    );
+    Ok(summary)
+}
+
+/// Create a table with columns inferred from the imported rows.
+///
+/// Types come from the values that aren't null, and every column but `id`
+/// accepts nulls. CSV cells are all text, so their types are guessed from how they
+/// read.
+fn create_table_for<C: ContentConnection>(
+    conn: &mut C,
+    table_name: &str,
+    records: &[Map<String, JsonValue>],
+    guess_types: bool,
+) -> DatabaseResult<()> {
+    let sample: Vec<JsonValue> = records
+        .iter()
+        .map(|record| {
+            let fields = record
+                .iter()
+                .filter(|(name, value)| {
+                    !value.is_null() && !CONTENT_METADATA_COLUMNS.contains(&name.as_str())
+                })
+                .map(|(name, value)| {
+                    let value = match value {
+                        JsonValue::String(text) if guess_types => guess_value(text),
+                        _ => value.clone(),
+                    };
+                    (name.clone(), value)
+                });
+            JsonValue::Object(fields.collect())
+        })
+        .collect();
+
+    let mut schema = infer_schema(&JsonValue::Array(sample)).map_err(|e| {
+        DatabaseError::new(DatabaseErrorKind::SchemaInference(format!(
+            "Cannot create '{}' from the imported rows: {}",
+            table_name, e.kind
+        )))
+    })?;
+    for name in records.iter().flat_map(|record| record.keys()) {
+        if !CONTENT_METADATA_COLUMNS.contains(&name.as_str()) && !schema.has_field(name) {
+            schema
+                .fields
+                .insert(name.clone(), ColumnDefinition::new("TEXT", true));
+        }
+    }
+    for (name, column) in schema.fields.iter_mut() {
+        column.nullable = name != "id";
+    }
+    if let Some(name) = schema.fields.keys().find(|name| !is_identifier(name)) {
+        return Err(DatabaseError::new(DatabaseErrorKind::SchemaInference(
+            format!("Column name '{}' is not a valid identifier", name),
+        )));
+    }
+
+    create_inferred_table(conn, table_name, &schema, None, Some("Imported content"))
+}
+
+/// Insert new content and update the curation metadata of matching rows.
+fn upsert_records<C: ContentConnection>(
+    conn: &mut C,
+    table_name: &str,
+    columns: &[ColumnInfo],
+    records: Vec<Map<String, JsonValue>>,
+    summary: &mut ImportSummary,
+) -> DatabaseResult<()> {
+    let dialect = conn.dialect();
+    let has_id = columns.iter().any(|c| c.name == "id");
+    let existing = conn.load_json(&format!("SELECT * FROM {}", table_name), columns, &[])?;
+    let mut known: HashMap<String, Map<String, JsonValue>> = existing
+        .into_iter()
+        .filter_map(|row| match row {
+            JsonValue::Object(row) => Some((content_hash(columns, &row), row)),
+            _ => None,
+        })
+        .collect();
+
+    for record in records {
+        let record: Map<String, JsonValue> = columns
+            .iter()
+            .filter_map(|column| {
+                record
+                    .get(&column.name)
+                    .map(|value| (column.name.clone(), coerce_value(column, value)))
+            })
+            .collect();
+        if let Some(status) = record.get("review_status").and_then(|v| v.as_str()) {
+            status.parse::<ContentStatus>()?;
+        }
+
+        let hash = content_hash(columns, &record);
+        let mut params = Vec::new();
+        if let Some(row) = known.get(&hash) {
+            let changes: Vec<String> = columns
+                .iter()
+                .filter(|column| CURATION_COLUMNS.contains(&column.name.as_str()))
+                .filter_map(|column| {
+                    let value = record.get(&column.name)?;
+                    (row.get(&column.name) != Some(value)).then(|| {
+                        format!(
+                            "{} = {}",
+                            column.name,
+                            column_value_sql(dialect, column, value, &mut params)
+                        )
+                    })
+                })
+                .collect();
+
+            match row.get("id").and_then(|id| id.as_i64()) {
+                Some(id) if !changes.is_empty() => {
+                    params.push(FilterValue::Integer(id));
+                    conn.execute_sql(
+                        &format!(
+                            "UPDATE {} SET {} WHERE id = {}",
+                            table_name,
+                            changes.join(", "),
+                            dialect.placeholder(params.len())
+                        ),
+                        &params,
+                    )?;
+                    summary.updated += 1;
+                }
+                _ => summary.unchanged += 1,
+            }
+            continue;
+        }
+
+        let mut names = Vec::new();
+        let mut literals = Vec::new();
+        if has_id {
+            names.push("id");
+            literals.push(format!(
+                "(SELECT COALESCE(MAX(id), 0) + 1 FROM {})",
+                table_name
+            ));
+        }
+        for column in columns.iter().filter(|c| c.name != "id") {
+            if let Some(value) = record.get(&column.name) {
+                names.push(column.name.as_str());
+                literals.push(column_value_sql(dialect, column, value, &mut params));
+            }
+        }
+        if names.is_empty() {
+            continue;
+        }
+
+        conn.execute_sql(
+            &format!(
+                "INSERT INTO {} ({}) VALUES ({})",
+                table_name,
+                names.join(", "),
+                literals.join(", ")
+            ),
+            &params,
+        )?;
+        summary.inserted += 1;
+        known.insert(hash, record);
+    }
+
+    Ok(())
+}
+
+/// Hash of a row's content columns: everything but `id` and metadata.
+///
+/// Timestamps are normalized so rows read back from PostgreSQL and SQLite
+/// hash the same.
+fn content_hash(columns: &[ColumnInfo], row: &Map<String, JsonValue>) -> String {
+    let content: Map<String, JsonValue> = columns
+        .iter()
+        .filter(|c| c.name != "id" && !CONTENT_METADATA_COLUMNS.contains(&c.name.as_str()))
+        .map(|column| {
+            let value = row.get(&column.name).cloned().unwrap_or(JsonValue::Null);
+            let value = match (&value, column.data_type.starts_with("timestamp")) {
+                (JsonValue::String(text), true) => parse_timestamp(text)
+                    .map(|ts| JsonValue::String(ts.to_rfc3339()))
+                    .unwrap_or(value),
+                _ => value,
+            };
+            (column.name.clone(), value)
+        })
+        .collect();
+
+    let mut hasher = Sha256::new();
+    hasher.update(JsonValue::Object(content).to_string());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Convert an imported value to the type of the column it's written to.
+///
+/// CSV cells, and JSON documents Parquet stores as text, arrive as strings;
+/// numbers and booleans written to text columns become strings.
+fn coerce_value(column: &ColumnInfo, value: &JsonValue) -> JsonValue {
+    let is_text = matches!(
+        column.data_type.as_str(),
+        "text" | "character varying" | "character"
+    );
+    let text = match value {
+        JsonValue::String(text) => text,
+        JsonValue::Number(_) | JsonValue::Bool(_) if is_text => {
+            return JsonValue::String(value.to_string());
+        }
+        _ => return value.clone(),
+    };
+    let coerced = match column.data_type.as_str() {
+        "integer" | "bigint" | "smallint" => text.parse::<i64>().ok().map(JsonValue::from),
+        "real" | "double precision" | "numeric" => text.parse::<f64>().ok().map(JsonValue::from),
+        "boolean" => match text.to_ascii_lowercase().as_str() {
+            "true" | "t" | "1" => Some(JsonValue::Bool(true)),
+            "false" | "f" | "0" => Some(JsonValue::Bool(false)),
+            _ => None,
+        },
+        data_type if is_json_type(data_type) => serde_json::from_str(text).ok(),
+        _ => None,
+    };
+    coerced.unwrap_or_else(|| value.clone())
+}
+
+/// Whether a column type's values travel as JSON text in CSV and Parquet.
+///
+/// PostgreSQL reports array columns by element type, e.g. `_text`.
+fn is_json_type(data_type: &str) -> bool {
+    matches!(data_type, "json" | "jsonb") || data_type.starts_with('_')
+}
+
+/// The value a CSV cell most likely holds, for inferring column types.
+fn guess_value(text: &str) -> JsonValue {
+    if let Ok(number) = text.parse::<i64>() {
+        return JsonValue::from(number);
+    }
+    if let Ok(number) = text.parse::<f64>()
+        && number.is_finite()
+    {
+        return JsonValue::from(number);
+    }
+    match text {
+        "true" => return JsonValue::Bool(true),
+        "false" => return JsonValue::Bool(false),
+        _ => {}
+    }
+    if text.starts_with(['[', '{'])
+        && let Ok(value) = serde_json::from_str(text)
+    {
+        return value;
+    }
+    JsonValue::String(text.to_string())
+}
+
+/// Whether a name can be used as a column without quoting.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn serialization_error(message: String) -> DatabaseError {
+    DatabaseError::new(DatabaseErrorKind::Serialization(message))
+}
+
+fn write_jsonl<W: Write>(rows: &[JsonValue], mut writer: W) -> DatabaseResult<()> {
+    for row in rows {
+        serde_json::to_writer(&mut writer, row)
+            .map_err(|e| serialization_error(format!("Failed to write JSON: {}", e)))?;
+        writer
+            .write_all(b"\n")
+            .map_err(|e| serialization_error(format!("Failed to write JSON: {}", e)))?;
+    }
+    writer
+        .flush()
+        .map_err(|e| serialization_error(format!("Failed to write JSON: {}", e)))
+}
+
+fn read_jsonl(data: &[u8]) -> DatabaseResult<Vec<Map<String, JsonValue>>> {
+    serde_json::Deserializer::from_slice(data)
+        .into_iter::<Map<String, JsonValue>>()
+        .enumerate()
+        .map(|(index, record)| {
+            record.map_err(|e| {
+                serialization_error(format!("Row {} is not a JSON object: {}", index + 1, e))
+            })
+        })
+        .collect()
+}
+
+/// Write rows as CSV; arrays and objects are written as JSON text.
+fn write_csv<W: Write>(
+    columns: &[ColumnInfo],
+    rows: &[JsonValue],
+    writer: W,
+) -> DatabaseResult<()> {
+    let csv_error = |e: csv::Error| serialization_error(format!("Failed to write CSV: {}", e));
+    let mut writer = csv::Writer::from_writer(writer);
+
+    writer
+        .write_record(columns.iter().map(|c| c.name.as_str()))
+        .map_err(csv_error)?;
+    for row in rows {
+        writer
+            .write_record(columns.iter().map(|column| match row.get(&column.name) {
+                None | Some(JsonValue::Null) => String::new(),
+                Some(JsonValue::String(text)) => text.clone(),
+                Some(value) => value.to_string(),
+            }))
+            .map_err(csv_error)?;
+    }
+
+    writer
+        .flush()
+        .map_err(|e| serialization_error(format!("Failed to write CSV: {}", e)))
+}
+
+/// Read CSV rows as text; empty cells are null.
+fn read_csv(data: &[u8]) -> DatabaseResult<Vec<Map<String, JsonValue>>> {
+    let csv_error = |e: csv::Error| serialization_error(format!("Failed to read CSV: {}", e));
+    let mut reader = csv::Reader::from_reader(data);
+    let headers = reader.headers().map_err(csv_error)?.clone();
+
+    reader
+        .records()
+        .map(|record| {
+            let record = record.map_err(csv_error)?;
+            Ok(headers
+                .iter()
+                .zip(record.iter())
+                .map(|(name, cell)| {
+                    let value = if cell.is_empty() {
+                        JsonValue::Null
+                    } else {
+                        JsonValue::String(cell.to_string())
+                    };
+                    (name.to_string(), value)
+                })
+                .collect())
+        })
+        .collect()
+}
+
+/// Write rows as a Parquet file.
+///
+/// Numbers and booleans keep their types; everything else is stored as text
+/// with the column's database type in the field metadata, so JSON columns
+/// are parsed again on import.
+#[cfg(feature = "parquet")]
+fn write_parquet<W: Write + Send>(
+    columns: &[ColumnInfo],
+    rows: &[JsonValue],
+    writer: W,
+) -> DatabaseResult<()> {
+    use arrow_array::{ArrayRef, BooleanArray, Float64Array, Int64Array, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+    use std::sync::Arc;
+
+    let parquet_error =
+        |e: &dyn std::fmt::Display| serialization_error(format!("Failed to write Parquet: {}", e));
+
+    let mut fields = Vec::new();
+    let mut arrays: Vec<ArrayRef> = Vec::new();
+    for column in columns {
+        let values = rows.iter().map(|row| row.get(&column.name));
+        let (data_type, array): (DataType, ArrayRef) = match column.data_type.as_str() {
+            "integer" | "bigint" | "smallint" => (
+                DataType::Int64,
+                Arc::new(
+                    values
+                        .map(|v| v.and_then(|v| v.as_i64()))
+                        .collect::<Int64Array>(),
+                ),
+            ),
+            "real" | "double precision" | "numeric" => (
+                DataType::Float64,
+                Arc::new(
+                    values
+                        .map(|v| v.and_then(|v| v.as_f64()))
+                        .collect::<Float64Array>(),
+                ),
+            ),
+            "boolean" => (
+                DataType::Boolean,
+                Arc::new(
+                    values
+                        .map(|v| v.and_then(|v| v.as_bool()))
+                        .collect::<BooleanArray>(),
+                ),
+            ),
+            _ => (
+                DataType::Utf8,
+                Arc::new(
+                    values
+                        .map(|v| match v {
+                            None | Some(JsonValue::Null) => None,
+                            Some(JsonValue::String(text)) => Some(text.clone()),
+                            Some(value) => Some(value.to_string()),
+                        })
+                        .collect::<StringArray>(),
+                ),
+            ),
+        };
+        fields.push(
+            Field::new(&column.name, data_type, true).with_metadata(HashMap::from([(
+                PARQUET_TYPE_KEY.to_string(),
+                column.data_type.clone(),
+            )])),
+        );
+        arrays.push(array);
+    }
+
+    let schema = Arc::new(Schema::new(fields));
+    let batch = RecordBatch::try_new(schema.clone(), arrays).map_err(|e| parquet_error(&e))?;
+    let mut writer = parquet::arrow::ArrowWriter::try_new(writer, schema, None)
+        .map_err(|e| parquet_error(&e))?;
+    writer.write(&batch).map_err(|e| parquet_error(&e))?;
+    writer.close().map_err(|e| parquet_error(&e))?;
+    Ok(())
+}
+
+/// Read the rows of a Parquet file.
+///
+/// Text columns written with a JSON database type are parsed back into
+/// JSON; other column types must be integers, floats, booleans or text.
+#[cfg(feature = "parquet")]
+fn read_parquet(data: Vec<u8>) -> DatabaseResult<Vec<Map<String, JsonValue>>> {
+    use arrow_array::Array;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Float32Type, Float64Type, Int32Type, Int64Type};
+    use arrow_schema::DataType;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    let parquet_error =
+        |e: &dyn std::fmt::Display| serialization_error(format!("Failed to read Parquet: {}", e));
+
+    let reader = ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::from(data))
+        .and_then(|builder| builder.build())
+        .map_err(|e| parquet_error(&e))?;
+
+    let mut records = Vec::new();
+    for batch in reader {
+        let batch = batch.map_err(|e| parquet_error(&e))?;
+        let mut rows = vec![Map::new(); batch.num_rows()];
+        for (field, array) in batch.schema().fields().iter().zip(batch.columns()) {
+            let is_json = field
+                .metadata()
+                .get(PARQUET_TYPE_KEY)
+                .is_some_and(|t| is_json_type(t));
+            for (index, row) in rows.iter_mut().enumerate() {
+                let value = if array.is_null(index) {
+                    JsonValue::Null
+                } else {
+                    match array.data_type() {
+                        DataType::Int64 => array.as_primitive::<Int64Type>().value(index).into(),
+                        DataType::Int32 => array.as_primitive::<Int32Type>().value(index).into(),
+                        DataType::Float64 => {
+                            array.as_primitive::<Float64Type>().value(index).into()
+                        }
+                        DataType::Float32 => {
+                            array.as_primitive::<Float32Type>().value(index).into()
+                        }
+                        DataType::Boolean => array.as_boolean().value(index).into(),
+                        DataType::Utf8 => {
+                            let text = array.as_string::<i32>().value(index);
+                            if is_json {
+                                serde_json::from_str(text).unwrap_or_else(|_| JsonValue::from(text))
+                            } else {
+                                JsonValue::from(text)
+                            }
+                        }
+                        other => {
+                            return Err(serialization_error(format!(
+                                "Parquet column '{}' has unsupported type {}",
+                                field.name(),
+                                other
+                            )));
+                        }
+                    }
+                };
+                row.insert(field.name().clone(), value);
+            }
+        }
+        records.extend(rows);
+    }
+
+    Ok(records)
+}
+
+/// Field metadata key holding a column's database type in Parquet exports.
+#[cfg(feature = "parquet")]
+const PARQUET_TYPE_KEY: &str = "botticelli:data_type";
+
+#[cfg(not(feature = "parquet"))]
+fn write_parquet<W: Write>(
+    _columns: &[ColumnInfo],
+    _rows: &[JsonValue],
+    _writer: W,
+) -> DatabaseResult<()> {
+    Err(parquet_unavailable())
+}
+
+#[cfg(not(feature = "parquet"))]
+fn read_parquet(_data: Vec<u8>) -> DatabaseResult<Vec<Map<String, JsonValue>>> {
+    Err(parquet_unavailable())
+}
+
+#[cfg(not(feature = "parquet"))]
+fn parquet_unavailable() -> DatabaseError {
+    serialization_error("Parquet support requires the `parquet` feature".to_string())
+}
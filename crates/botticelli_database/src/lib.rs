@@ -1,4 +1,4 @@
-//! PostgreSQL integration for Botticelli.
+//! PostgreSQL and SQLite integration for Botticelli.
 //!
 //! This crate provides database models, schema definitions, and repository
 //! implementations for persisting narratives and content.
@@ -6,18 +6,25 @@
 //! # Features
 //!
 //! - Diesel-based PostgreSQL integration
+//! - Connection pools for async callers
+//! - SQLite backend for content and actor server tables (`sqlite` feature)
 //! - Narrative persistence and retrieval
 //! - Content generation tracking
+//! - Content lifecycle enforcement with status history
+//! - Content revision history with reverts
+//! - Table export and import as JSON Lines, CSV or Parquet (`parquet` feature)
+//! - Narrative execution history and actor task state for dashboards
+//! - Actor server state, leases and cross-post records on either backend
 //! - Schema reflection and inference
 //!
 //! # Example
 //!
 //! ```rust,ignore
-//! use botticelli_database::{establish_connection, NarrativeRepository, PostgresNarrativeRepository};
+//! use botticelli_database::{create_pool, NarrativeRepository, PostgresNarrativeRepository};
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-//! let mut conn = establish_connection()?;
-//! let repo = PostgresNarrativeRepository::new();
+//! let pool = create_pool()?;
+//! let repo = PostgresNarrativeRepository::new(pool, storage);
 //!
 //! // Use repository...
 //! # Ok(())
@@ -25,18 +32,28 @@
 //! ```
 
 mod actor_server_models;
+mod actor_server_store;
+mod actor_tasks;
+mod backend;
 mod connection;
 mod content_generation_models;
 mod content_generation_repository;
 mod content_management;
 mod content_repository;
+mod content_revisions;
+mod content_transfer;
 mod models;
 mod narrative_conversions;
+mod narrative_history;
 mod narrative_models;
 mod narrative_repository;
+mod pool;
 mod schema_docs;
 mod schema_inference;
 mod schema_reflection;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+mod table_filter;
 mod table_query;
 mod table_query_registry;
 
@@ -45,19 +62,43 @@ pub mod schema;
 
 // Re-export actor server state management types
 pub use actor_server_models::{
-    ActorServerExecutionRow, ActorServerStateRow, NewActorServerExecution,
-    NewActorServerExecutionBuilder, NewActorServerState, NewActorServerStateBuilder,
+    ActorCrossPostRecordRow, ActorServerExecutionRow, ActorServerLeaseRow, ActorServerStateRow,
+    NewActorServerExecution, NewActorServerExecutionBuilder, NewActorServerState,
+    NewActorServerStateBuilder,
 };
+pub use actor_server_store::{
+    ActorStateChange, ActorStateFilter, acquire_actor_lease, complete_actor_execution,
+    delete_actor_states, delete_crosspost_records, fail_actor_execution, list_actor_executions,
+    list_actor_leases, list_actor_states, list_crosspost_records, prune_actor_executions,
+    record_actor_last_run, release_actor_leases, save_actor_state, save_crosspost_records,
+    start_actor_execution, update_actor_state,
+};
+pub use actor_tasks::{ActorTask, list_actor_tasks, set_actor_task_paused};
 
-// Re-export connection utilities
-pub use connection::{create_pool, establish_connection};
+// Re-export backend and connection utilities
+pub use backend::{BackendKind, ContentConnection, DatabaseConnection, SqlDialect};
+pub use connection::{create_pool, database_url, establish_connection, postgres_database_url};
+pub use pool::{
+    DatabaseConnectionManager, DatabasePool, PgPool, create_database_pool, with_pooled_connection,
+};
+#[cfg(feature = "sqlite")]
+pub use sqlite::{
+    SQLITE_MIGRATIONS, SqliteContentGenerationRepository, create_sqlite_pool,
+    establish_sqlite_connection, run_sqlite_migrations,
+};
 
 // Re-export content management functions
 pub use content_management::{
-    delete_content, get_content_by_id, list_content, promote_content, update_content_metadata,
-    update_review_status,
+    CONTENT_METADATA_COLUMNS, ContentPage, ContentQuery, ContentQueryBuilder, ContentStatus,
+    ContentStatusChange, LEGACY_PENDING_STATUS, content_status, content_status_history,
+    delete_content, get_content_by_id, list_content, promote_content, query_content,
+    transition_content_status, update_content_metadata, update_review_status,
 };
 pub use content_repository::DatabaseContentRepository;
+pub use content_revisions::{
+    ContentRevision, content_revisions, edit_content, insert_content, revert_content, with_revision,
+};
+pub use content_transfer::{ContentFormat, ImportSummary, export_content, import_content};
 
 // Re-export content generation types
 pub use content_generation_models::{
@@ -71,6 +112,10 @@ pub use content_generation_repository::{
 pub use models::{ModelResponse, NewModelResponse, SerializableModelResponse};
 
 // Re-export narrative types
+pub use narrative_history::{
+    ActInputRecord, ActRecord, ExecutionRecord, ModelUsage, list_narrative_executions, model_usage,
+    narrative_execution_acts,
+};
 pub use narrative_models::{
     ActExecutionRow, ActInputRow, NarrativeExecutionRow, NewActExecutionRow, NewActInputRow,
     NewNarrativeExecutionRow,
@@ -90,6 +135,7 @@ pub use schema_reflection::{
 };
 
 // Re-export table query types
+pub use table_filter::CompiledFilter;
 pub use table_query::{TableQueryExecutor, format_as_csv, format_as_json, format_as_markdown};
 pub use table_query_registry::DatabaseTableQueryRegistry;
 
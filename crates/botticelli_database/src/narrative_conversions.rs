@@ -57,7 +57,7 @@ pub fn act_execution_to_new_row(act: &ActExecution, execution_id: i32) -> NewAct
         model: act.model.clone(),
         temperature: act.temperature,
         max_tokens: act.max_tokens.map(|t| t as i32),
-        response: act.response.clone(),
+        response: botticelli_secrets::redact(&act.response).into_owned(),
     }
 }
 
@@ -72,7 +72,7 @@ pub fn input_to_new_row(
             act_execution_id,
             input_order: order as i32,
             input_type: "text".to_string(),
-            text_content: Some(text.clone()),
+            text_content: Some(botticelli_secrets::redact(text).into_owned()),
             mime_type: None,
             filename: None,
             media_ref_id: None,
@@ -0,0 +1,292 @@
+//! Read recorded narrative executions for dashboards.
+//!
+//! Unlike [`PostgresNarrativeRepository`](crate::PostgresNarrativeRepository),
+//! these functions are synchronous, work on any [`ContentConnection`], and
+//! return summaries rather than full executions with media.
+
+use crate::content_management::parse_timestamp;
+use crate::schema_reflection::{ColumnInfo, reflect_table_schema};
+use crate::{ContentConnection, DatabaseResult, SqlDialect};
+use botticelli_core::FilterValue;
+use botticelli_error::{BotticelliResult, DatabaseError, DatabaseErrorKind};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use tracing::instrument;
+
+/// One row of `narrative_executions`, with its number of acts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, derive_getters::Getters)]
+pub struct ExecutionRecord {
+    /// Execution ID
+    id: i64,
+    /// Name of the narrative that ran
+    narrative_name: String,
+    /// Execution status (running, completed, failed)
+    status: String,
+    /// When the execution started
+    started_at: DateTime<Utc>,
+    /// When the execution finished, if it has
+    completed_at: Option<DateTime<Utc>>,
+    /// Why the execution failed
+    error_message: Option<String>,
+    /// Acts recorded for the execution
+    act_count: i64,
+}
+
+/// One recorded act of an execution, with its inputs and response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, derive_getters::Getters)]
+pub struct ActRecord {
+    /// Position of the act in the narrative, from 0
+    sequence_number: i64,
+    /// Act name
+    act_name: String,
+    /// Model the act ran on, if it overrode the default
+    model: Option<String>,
+    /// Inputs sent to the model, in order
+    inputs: Vec<ActInputRecord>,
+    /// Model response
+    response: String,
+    /// When the act was recorded
+    created_at: DateTime<Utc>,
+}
+
+/// One input of a recorded act.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, derive_getters::Getters)]
+pub struct ActInputRecord {
+    /// Input kind (text, image, audio, ...)
+    input_type: String,
+    /// Text of a text input
+    text_content: Option<String>,
+    /// MIME type of a media input
+    mime_type: Option<String>,
+    /// File name of a document input
+    filename: Option<String>,
+}
+
+/// Requests and estimated tokens recorded for one model.
+///
+/// Tokens are estimated at four characters per token of act inputs and
+/// responses, as the Gemini client does when reserving rate-limit capacity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, derive_getters::Getters)]
+pub struct ModelUsage {
+    /// Model name; `None` for acts that used the client's default model
+    model: Option<String>,
+    /// Acts recorded in the last minute
+    requests_last_minute: i64,
+    /// Acts recorded in the last 24 hours
+    requests_last_day: i64,
+    /// Estimated tokens in the last minute
+    tokens_last_minute: i64,
+    /// Estimated tokens in the last 24 hours
+    tokens_last_day: i64,
+}
+
+/// List the most recent narrative executions, newest first.
+///
+/// # Errors
+///
+/// Returns `TableNotFound` if the narrative tables haven't been migrated.
+#[instrument(name = "narrative_history.list_narrative_executions", skip(conn))]
+pub fn list_narrative_executions<C: ContentConnection>(
+    conn: &mut C,
+    limit: i64,
+) -> BotticelliResult<Vec<ExecutionRecord>> {
+    let mut columns = reflect_table_schema(conn, "narrative_executions")?.columns;
+    columns.push(column("act_count", "bigint"));
+
+    let query = format!(
+        "SELECT e.*, \
+         (SELECT COUNT(*) FROM act_executions a WHERE a.execution_id = e.id) AS act_count \
+         FROM narrative_executions e \
+         ORDER BY e.started_at DESC, e.id DESC LIMIT {}",
+        limit
+    );
+
+    let rows = conn.load_json(&query, &columns, &[])?;
+    Ok(rows
+        .iter()
+        .map(|row| {
+            Ok(ExecutionRecord {
+                id: integer(row, "id"),
+                narrative_name: text(row, "narrative_name")?,
+                status: text(row, "status")?,
+                started_at: parse_timestamp(&text(row, "started_at")?)?,
+                completed_at: optional_text(row, "completed_at")
+                    .map(|ts| parse_timestamp(&ts))
+                    .transpose()?,
+                error_message: optional_text(row, "error_message"),
+                act_count: integer(row, "act_count"),
+            })
+        })
+        .collect::<DatabaseResult<_>>()?)
+}
+
+/// Load the acts of one execution in order, with their inputs.
+///
+/// Media inputs are summarized by type and file name; their content is
+/// not loaded.
+///
+/// # Errors
+///
+/// Returns `NotFound` if the execution doesn't exist.
+#[instrument(name = "narrative_history.narrative_execution_acts", skip(conn))]
+pub fn narrative_execution_acts<C: ContentConnection>(
+    conn: &mut C,
+    execution_id: i64,
+) -> BotticelliResult<Vec<ActRecord>> {
+    let dialect = conn.dialect();
+    let params = [FilterValue::Integer(execution_id)];
+
+    let exists = conn.load_json(
+        &format!(
+            "SELECT COUNT(*) AS total FROM narrative_executions WHERE id = {}",
+            dialect.placeholder(1)
+        ),
+        &[column("total", "bigint")],
+        &params,
+    )?;
+    if exists.first().map(|row| integer(row, "total")) != Some(1) {
+        return Err(DatabaseError::new(DatabaseErrorKind::NotFound).into());
+    }
+
+    let input_columns = [
+        column("act_execution_id", "bigint"),
+        column("input_type", "text"),
+        column("text_content", "text"),
+        column("mime_type", "text"),
+        column("filename", "text"),
+    ];
+    let inputs = conn.load_json(
+        &format!(
+            "SELECT i.act_execution_id, i.input_type, i.text_content, i.mime_type, i.filename \
+             FROM act_inputs i JOIN act_executions a ON a.id = i.act_execution_id \
+             WHERE a.execution_id = {} \
+             ORDER BY i.act_execution_id, i.input_order",
+            dialect.placeholder(1)
+        ),
+        &input_columns,
+        &params,
+    )?;
+    let mut inputs_by_act: HashMap<i64, Vec<ActInputRecord>> = HashMap::new();
+    for row in &inputs {
+        inputs_by_act
+            .entry(integer(row, "act_execution_id"))
+            .or_default()
+            .push(ActInputRecord {
+                input_type: text(row, "input_type")?,
+                text_content: optional_text(row, "text_content"),
+                mime_type: optional_text(row, "mime_type"),
+                filename: optional_text(row, "filename"),
+            });
+    }
+
+    let columns = reflect_table_schema(conn, "act_executions")?.columns;
+    let acts = conn.load_json(
+        &format!(
+            "SELECT * FROM act_executions WHERE execution_id = {} \
+             ORDER BY sequence_number, id",
+            dialect.placeholder(1)
+        ),
+        &columns,
+        &params,
+    )?;
+
+    Ok(acts
+        .iter()
+        .map(|row| {
+            Ok(ActRecord {
+                sequence_number: integer(row, "sequence_number"),
+                act_name: text(row, "act_name")?,
+                model: optional_text(row, "model"),
+                inputs: inputs_by_act
+                    .remove(&integer(row, "id"))
+                    .unwrap_or_default(),
+                response: text(row, "response")?,
+                created_at: parse_timestamp(&text(row, "created_at")?)?,
+            })
+        })
+        .collect::<DatabaseResult<_>>()?)
+}
+
+/// Requests and estimated tokens per model over the last minute and day.
+///
+/// Counts the acts recorded in `act_executions`, so only saved narrative
+/// runs are included. Models with no acts in the last day are omitted.
+///
+/// # Errors
+///
+/// Returns an error if the narrative tables haven't been migrated.
+#[instrument(name = "narrative_history.model_usage", skip(conn))]
+pub fn model_usage<C: ContentConnection>(conn: &mut C) -> BotticelliResult<Vec<ModelUsage>> {
+    let dialect = conn.dialect();
+    let minute = window_start(dialect, 60);
+    let day = window_start(dialect, 86_400);
+
+    let query = format!(
+        "SELECT model, \
+         CAST(SUM(CASE WHEN created_at >= {minute} THEN 1 ELSE 0 END) AS BIGINT) AS requests_last_minute, \
+         CAST(COUNT(*) AS BIGINT) AS requests_last_day, \
+         CAST(SUM(CASE WHEN created_at >= {minute} THEN chars ELSE 0 END) / 4 AS BIGINT) AS tokens_last_minute, \
+         CAST(SUM(chars) / 4 AS BIGINT) AS tokens_last_day \
+         FROM (SELECT a.model, a.created_at, LENGTH(a.response) + COALESCE(\
+         (SELECT SUM(LENGTH(i.text_content)) FROM act_inputs i WHERE i.act_execution_id = a.id), 0) AS chars \
+         FROM act_executions a WHERE a.created_at >= {day}) u \
+         GROUP BY model ORDER BY model"
+    );
+    let columns = [
+        column("model", "text"),
+        column("requests_last_minute", "bigint"),
+        column("requests_last_day", "bigint"),
+        column("tokens_last_minute", "bigint"),
+        column("tokens_last_day", "bigint"),
+    ];
+
+    let rows = conn.load_json(&query, &columns, &[])?;
+    Ok(rows
+        .iter()
+        .map(|row| ModelUsage {
+            model: optional_text(row, "model"),
+            requests_last_minute: integer(row, "requests_last_minute"),
+            requests_last_day: integer(row, "requests_last_day"),
+            tokens_last_minute: integer(row, "tokens_last_minute"),
+            tokens_last_day: integer(row, "tokens_last_day"),
+        })
+        .collect())
+}
+
+/// Expression for the time `seconds` ago, in the database's clock.
+fn window_start(dialect: SqlDialect, seconds: i64) -> String {
+    match dialect {
+        SqlDialect::Postgres => format!("NOW() - INTERVAL '{} seconds'", seconds),
+        SqlDialect::Sqlite => format!("datetime('now', '-{} seconds')", seconds),
+    }
+}
+
+/// Description of a computed column, for [`ContentConnection::load_json`].
+pub(crate) fn column(name: &str, data_type: &str) -> ColumnInfo {
+    ColumnInfo {
+        name: name.to_string(),
+        data_type: data_type.to_string(),
+        is_nullable: "YES".to_string(),
+        character_maximum_length: None,
+        column_default: None,
+    }
+}
+
+pub(crate) fn integer(row: &JsonValue, field: &str) -> i64 {
+    row.get(field).and_then(|v| v.as_i64()).unwrap_or_default()
+}
+
+pub(crate) fn optional_text(row: &JsonValue, field: &str) -> Option<String> {
+    row.get(field).and_then(|v| v.as_str()).map(str::to_string)
+}
+
+pub(crate) fn text(row: &JsonValue, field: &str) -> DatabaseResult<String> {
+    optional_text(row, field).ok_or_else(|| {
+        DatabaseError::new(DatabaseErrorKind::Query(format!(
+            "Row is missing '{}'",
+            field
+        )))
+    })
+}
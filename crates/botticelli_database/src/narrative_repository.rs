@@ -5,7 +5,7 @@ use crate::narrative_conversions::{
     rows_to_narrative_execution, status_to_string, string_to_status,
 };
 use crate::schema::{act_executions, act_inputs, narrative_executions};
-use crate::{ActExecutionRow, ActInputRow, NarrativeExecutionRow};
+use crate::{ActExecutionRow, ActInputRow, NarrativeExecutionRow, PgPool, with_pooled_connection};
 
 use botticelli_error::{BackendError, BotticelliError, BotticelliResult};
 use botticelli_interface::{
@@ -17,7 +17,6 @@ use chrono::Utc;
 use diesel::pg::PgConnection;
 use diesel::prelude::*;
 use std::sync::Arc;
-use tokio::sync::Mutex;
 
 /// PostgreSQL implementation of NarrativeRepository using Diesel ORM.
 ///
@@ -26,26 +25,24 @@ use tokio::sync::Mutex;
 ///
 /// # Example
 /// ```no_run
-/// use botticelli_database::{PostgresNarrativeRepository, establish_connection};
+/// use botticelli_database::{PostgresNarrativeRepository, create_pool};
 /// use botticelli_interface::NarrativeRepository;
 /// use botticelli_storage::FileSystemStorage;
 /// use std::sync::Arc;
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-///     let mut conn = establish_connection()?;
+///     let pool = create_pool()?;
 ///     let storage = Arc::new(FileSystemStorage::new("/var/botticelli/media")?);
-///     let repo = PostgresNarrativeRepository::new(conn, storage);
+///     let repo = PostgresNarrativeRepository::new(pool, storage);
 ///     // Use repo.save_execution(), load_execution(), etc.
 ///     Ok(())
 /// }
 /// ```
+#[derive(Clone)]
 pub struct PostgresNarrativeRepository {
-    /// Database connection wrapped in Arc<Mutex> for async safety.
-    ///
-    /// Note: This is a simple implementation. For production use, consider using
-    /// a connection pool like r2d2 or deadpool.
-    conn: Arc<Mutex<PgConnection>>,
+    /// Connection pool; each operation checks out its own connection
+    pool: PgPool,
     /// Media storage backend for binary content
     storage: Arc<dyn botticelli_storage::MediaStorage>,
 }
@@ -54,78 +51,21 @@ impl PostgresNarrativeRepository {
     /// Create a new PostgreSQL narrative repository.
     ///
     /// # Arguments
-    /// * `conn` - A PostgreSQL connection
+    /// * `pool` - A PostgreSQL connection pool
     /// * `storage` - Media storage backend
-    ///
-    /// # Note
-    /// The connection is wrapped in Arc<Mutex> to allow async access.
-    /// For better performance with concurrent access, consider using a connection pool.
-    pub fn new(conn: PgConnection, storage: Arc<dyn botticelli_storage::MediaStorage>) -> Self {
-        Self {
-            conn: Arc::new(Mutex::new(conn)),
-            storage,
-        }
+    pub fn new(pool: PgPool, storage: Arc<dyn botticelli_storage::MediaStorage>) -> Self {
+        Self { pool, storage }
     }
 
-    /// Create a repository from an Arc<Mutex<PgConnection>> (for sharing connections).
-    pub fn from_arc(
-        conn: Arc<Mutex<PgConnection>>,
-        storage: Arc<dyn botticelli_storage::MediaStorage>,
-    ) -> Self {
-        Self { conn, storage }
-    }
-}
-
-#[async_trait]
-impl NarrativeRepository for PostgresNarrativeRepository {
-    async fn save_execution(&self, execution: &NarrativeExecution) -> BotticelliResult<i32> {
-        let mut conn = self.conn.lock().await;
-
-        // Use a transaction for atomicity
-        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
-            // Insert narrative_execution
-            let new_execution = execution_to_new_row(execution, ExecutionStatus::Completed);
-            let execution_row: NarrativeExecutionRow =
-                diesel::insert_into(narrative_executions::table)
-                    .values(&new_execution)
-                    .get_result(conn)?;
-
-            let execution_id = execution_row.id;
-
-            // Insert all acts
-            for act in &execution.act_executions {
-                let new_act = act_execution_to_new_row(act, execution_id);
-                let act_row: ActExecutionRow = diesel::insert_into(act_executions::table)
-                    .values(&new_act)
-                    .get_result(conn)?;
-
-                // Insert all inputs for this act
-                for (order, input) in act.inputs.iter().enumerate() {
-                    let new_input = match input_to_new_row(input, act_row.id, order) {
-                        Ok(row) => row,
-                        Err(_) => return Err(diesel::result::Error::RollbackTransaction),
-                    };
-                    diesel::insert_into(act_inputs::table)
-                        .values(&new_input)
-                        .execute(conn)?;
-                }
-            }
-
-            Ok(execution_id)
-        });
-
-        result.map_err(|e| {
-            BotticelliError::from(BackendError::new(format!("Transaction failed: {}", e)))
-        })
-    }
-
-    async fn load_execution(&self, id: i32) -> BotticelliResult<NarrativeExecution> {
-        let mut conn = self.conn.lock().await;
-
+    /// Load an execution with its acts and inputs on a checked-out connection.
+    fn load_execution_with(
+        conn: &mut PgConnection,
+        id: i32,
+    ) -> BotticelliResult<NarrativeExecution> {
         // Load the narrative execution
         let execution_row: NarrativeExecutionRow = narrative_executions::table
             .find(id)
-            .first(&mut *conn)
+            .first(conn)
             .map_err(|e| {
                 BotticelliError::from(BackendError::new(format!(
                     "Failed to load narrative execution {}: {}",
@@ -136,7 +76,7 @@ impl NarrativeRepository for PostgresNarrativeRepository {
         // Load all acts for this execution
         let act_rows: Vec<ActExecutionRow> = ActExecutionRow::belonging_to(&execution_row)
             .order(act_executions::sequence_number.asc())
-            .load(&mut *conn)
+            .load(conn)
             .map_err(|e| {
                 BotticelliError::from(BackendError::new(format!(
                     "Failed to load act executions: {}",
@@ -146,7 +86,7 @@ impl NarrativeRepository for PostgresNarrativeRepository {
 
         // Load all inputs for all acts
         let input_rows: Vec<ActInputRow> = ActInputRow::belonging_to(&act_rows)
-            .load(&mut *conn)
+            .load(conn)
             .map_err(|e| {
                 BotticelliError::from(BackendError::new(format!(
                     "Failed to load act inputs: {}",
@@ -179,109 +119,168 @@ impl NarrativeRepository for PostgresNarrativeRepository {
             act_executions,
         ))
     }
+}
+
+#[async_trait]
+impl NarrativeRepository for PostgresNarrativeRepository {
+    async fn save_execution(&self, execution: &NarrativeExecution) -> BotticelliResult<i32> {
+        let execution = execution.clone();
+
+        // Use a transaction for atomicity
+        with_pooled_connection(&self.pool, move |conn| {
+            conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                // Insert narrative_execution
+                let new_execution = execution_to_new_row(&execution, ExecutionStatus::Completed);
+                let execution_row: NarrativeExecutionRow =
+                    diesel::insert_into(narrative_executions::table)
+                        .values(&new_execution)
+                        .get_result(conn)?;
+
+                let execution_id = execution_row.id;
+
+                // Insert all acts
+                for act in &execution.act_executions {
+                    let new_act = act_execution_to_new_row(act, execution_id);
+                    let act_row: ActExecutionRow = diesel::insert_into(act_executions::table)
+                        .values(&new_act)
+                        .get_result(conn)?;
+
+                    // Insert all inputs for this act
+                    for (order, input) in act.inputs.iter().enumerate() {
+                        let new_input = match input_to_new_row(input, act_row.id, order) {
+                            Ok(row) => row,
+                            Err(_) => return Err(diesel::result::Error::RollbackTransaction),
+                        };
+                        diesel::insert_into(act_inputs::table)
+                            .values(&new_input)
+                            .execute(conn)?;
+                    }
+                }
+
+                Ok(execution_id)
+            })
+            .map_err(|e| {
+                BotticelliError::from(BackendError::new(format!("Transaction failed: {}", e)))
+            })
+        })
+        .await
+    }
+
+    async fn load_execution(&self, id: i32) -> BotticelliResult<NarrativeExecution> {
+        with_pooled_connection(&self.pool, move |conn| Self::load_execution_with(conn, id)).await
+    }
 
     async fn list_executions(
         &self,
         filter: &ExecutionFilter,
     ) -> BotticelliResult<Vec<ExecutionSummary>> {
-        let mut conn = self.conn.lock().await;
-
-        let mut query = narrative_executions::table.into_boxed();
+        let filter = filter.clone();
+        with_pooled_connection(&self.pool, move |conn| {
+            let mut query = narrative_executions::table.into_boxed();
 
-        // Apply filters
-        if let Some(ref name) = filter.narrative_name {
-            query = query.filter(narrative_executions::narrative_name.eq(name));
-        }
+            // Apply filters
+            if let Some(ref name) = filter.narrative_name {
+                query = query.filter(narrative_executions::narrative_name.eq(name));
+            }
 
-        if let Some(ref status) = filter.status {
-            query = query.filter(narrative_executions::status.eq(status_to_string(*status)));
-        }
+            if let Some(ref status) = filter.status {
+                query = query.filter(narrative_executions::status.eq(status_to_string(*status)));
+            }
 
-        // Note: Date filtering removed - ExecutionFilter in interface doesn't have these fields
-        // Original code filtered by started_after and started_before
+            // Note: Date filtering removed - ExecutionFilter in interface doesn't have these fields
+            // Original code filtered by started_after and started_before
 
-        // Order by started_at descending (most recent first)
-        query = query.order(narrative_executions::started_at.desc());
+            // Order by started_at descending (most recent first)
+            query = query.order(narrative_executions::started_at.desc());
 
-        // Apply offset and limit
-        if let Some(offset) = filter.offset {
-            query = query.offset(offset as i64);
-        }
+            // Apply offset and limit
+            if let Some(offset) = filter.offset {
+                query = query.offset(offset as i64);
+            }
 
-        if let Some(limit) = filter.limit {
-            query = query.limit(limit as i64);
-        }
+            if let Some(limit) = filter.limit {
+                query = query.limit(limit as i64);
+            }
 
-        let execution_rows: Vec<NarrativeExecutionRow> = query.load(&mut *conn).map_err(|e| {
-            BotticelliError::from(BackendError::new(format!(
-                "Failed to list executions: {}",
-                e
-            )))
-        })?;
-
-        // Count acts for each execution
-        let mut summaries = Vec::new();
-        for row in execution_rows {
-            let act_count: i64 = act_executions::table
-                .filter(act_executions::execution_id.eq(row.id))
-                .count()
-                .get_result(&mut *conn)
-                .map_err(|e| {
-                    BotticelliError::from(BackendError::new(format!("Failed to count acts: {}", e)))
-                })?;
+            let execution_rows: Vec<NarrativeExecutionRow> = query.load(conn).map_err(|e| {
+                BotticelliError::from(BackendError::new(format!(
+                    "Failed to list executions: {}",
+                    e
+                )))
+            })?;
 
-            summaries.push(ExecutionSummary {
-                id: row.id,
-                narrative_name: row.narrative_name,
-                narrative_description: row.narrative_description,
-                status: string_to_status(&row.status)?,
-                // Note: started_at and completed_at removed from ExecutionSummary in interface
-                act_count: act_count as usize,
-                error_message: row.error_message,
-            });
-        }
+            // Count acts for each execution
+            let mut summaries = Vec::new();
+            for row in execution_rows {
+                let act_count: i64 = act_executions::table
+                    .filter(act_executions::execution_id.eq(row.id))
+                    .count()
+                    .get_result(conn)
+                    .map_err(|e| {
+                        BotticelliError::from(BackendError::new(format!(
+                            "Failed to count acts: {}",
+                            e
+                        )))
+                    })?;
+
+                summaries.push(ExecutionSummary {
+                    id: row.id,
+                    narrative_name: row.narrative_name,
+                    narrative_description: row.narrative_description,
+                    status: string_to_status(&row.status)?,
+                    // Note: started_at and completed_at removed from ExecutionSummary in interface
+                    act_count: act_count as usize,
+                    error_message: row.error_message,
+                });
+            }
 
-        Ok(summaries)
+            Ok(summaries)
+        })
+        .await
     }
 
     async fn update_status(&self, id: i32, status: ExecutionStatus) -> BotticelliResult<()> {
-        let mut conn = self.conn.lock().await;
-
-        let status_str = status_to_string(status);
-        let completed_at = match status {
-            ExecutionStatus::Completed | ExecutionStatus::Failed => Some(Utc::now().naive_utc()),
-            ExecutionStatus::Running => None,
-        };
+        with_pooled_connection(&self.pool, move |conn| {
+            let status_str = status_to_string(status);
+            let completed_at = match status {
+                ExecutionStatus::Completed | ExecutionStatus::Failed => {
+                    Some(Utc::now().naive_utc())
+                }
+                ExecutionStatus::Running => None,
+            };
 
-        diesel::update(narrative_executions::table.find(id))
-            .set((
-                narrative_executions::status.eq(status_str),
-                narrative_executions::completed_at.eq(completed_at),
-            ))
-            .execute(&mut *conn)
-            .map_err(|e| {
-                BotticelliError::from(BackendError::new(format!(
-                    "Failed to update execution status: {}",
-                    e
-                )))
-            })?;
+            diesel::update(narrative_executions::table.find(id))
+                .set((
+                    narrative_executions::status.eq(status_str),
+                    narrative_executions::completed_at.eq(completed_at),
+                ))
+                .execute(conn)
+                .map_err(|e| {
+                    BotticelliError::from(BackendError::new(format!(
+                        "Failed to update execution status: {}",
+                        e
+                    )))
+                })?;
 
-        Ok(())
+            Ok(())
+        })
+        .await
     }
 
     async fn delete_execution(&self, id: i32) -> BotticelliResult<()> {
-        let mut conn = self.conn.lock().await;
-
-        diesel::delete(narrative_executions::table.find(id))
-            .execute(&mut *conn)
-            .map_err(|e| {
-                BotticelliError::from(BackendError::new(format!(
-                    "Failed to delete execution: {}",
-                    e
-                )))
-            })?;
+        with_pooled_connection(&self.pool, move |conn| {
+            diesel::delete(narrative_executions::table.find(id))
+                .execute(conn)
+                .map_err(|e| {
+                    BotticelliError::from(BackendError::new(format!(
+                        "Failed to delete execution: {}",
+                        e
+                    )))
+                })?;
 
-        Ok(())
+            Ok(())
+        })
+        .await
     }
 
     // Media storage methods
@@ -312,7 +311,6 @@ impl NarrativeRepository for PostgresNarrativeRepository {
         let reference = self.storage.store(data, metadata).await?;
 
         // Save reference to database
-        let mut conn = self.conn.lock().await;
 
         #[derive(Insertable)]
         #[diesel(table_name = media_references)]
@@ -342,15 +340,18 @@ impl NarrativeRepository for PostgresNarrativeRepository {
             duration_seconds: metadata.duration_seconds,
         };
 
-        diesel::insert_into(media_references::table)
-            .values(&new_row)
-            .execute(&mut *conn)
-            .map_err(|e| {
-                BotticelliError::from(BackendError::new(format!(
-                    "Failed to save media reference: {}",
-                    e
-                )))
-            })?;
+        with_pooled_connection(&self.pool, move |conn| {
+            diesel::insert_into(media_references::table)
+                .values(&new_row)
+                .execute(conn)
+                .map_err(|e| {
+                    BotticelliError::from(BackendError::new(format!(
+                        "Failed to save media reference: {}",
+                        e
+                    )))
+                })
+        })
+        .await?;
 
         tracing::info!(
             id = %reference.id,
@@ -376,28 +377,30 @@ impl NarrativeRepository for PostgresNarrativeRepository {
     ) -> BotticelliResult<Option<botticelli_storage::MediaReference>> {
         use crate::schema::media_references;
 
-        let mut conn = self.conn.lock().await;
-
+        let content_hash = content_hash.to_string();
         let result: Option<(uuid::Uuid, String, String, i64, String, String, String)> =
-            media_references::table
-                .select((
-                    media_references::id,
-                    media_references::media_type,
-                    media_references::mime_type,
-                    media_references::size_bytes,
-                    media_references::content_hash,
-                    media_references::storage_backend,
-                    media_references::storage_path,
-                ))
-                .filter(media_references::content_hash.eq(content_hash))
-                .first(&mut *conn)
-                .optional()
-                .map_err(|e| {
-                    BotticelliError::from(BackendError::new(format!(
-                        "Failed to query media by hash: {}",
-                        e
-                    )))
-                })?;
+            with_pooled_connection(&self.pool, move |conn| {
+                media_references::table
+                    .select((
+                        media_references::id,
+                        media_references::media_type,
+                        media_references::mime_type,
+                        media_references::size_bytes,
+                        media_references::content_hash,
+                        media_references::storage_backend,
+                        media_references::storage_path,
+                    ))
+                    .filter(media_references::content_hash.eq(content_hash))
+                    .first(conn)
+                    .optional()
+                    .map_err(|e| {
+                        BotticelliError::from(BackendError::new(format!(
+                            "Failed to query media by hash: {}",
+                            e
+                        )))
+                    })
+            })
+            .await?;
 
         Ok(result.map(
             |(id, media_type_str, mime_type, size_bytes, hash, backend, path)| {
@@ -0,0 +1,133 @@
+//! Connection pools for async callers.
+//!
+//! Diesel is synchronous, so async code never holds a connection across an
+//! `.await`. Instead it checks one out of an r2d2 pool on tokio's blocking
+//! thread pool with [`with_pooled_connection`]. Concurrent narratives and
+//! actors each get their own connection rather than queueing on one mutex.
+
+use crate::{BackendKind, DatabaseConnection, DatabaseResult};
+use botticelli_error::{DatabaseError, DatabaseErrorKind};
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, ManageConnection, Pool, R2D2Connection};
+use tracing::{error, instrument};
+
+/// Pool of PostgreSQL connections.
+pub type PgPool = Pool<ConnectionManager<PgConnection>>;
+
+/// Pool of connections to whichever backend a database URL names.
+pub type DatabasePool = Pool<DatabaseConnectionManager>;
+
+/// Maximum connections per pool.
+const MAX_POOL_SIZE: u32 = 10;
+
+/// Run blocking database work on a pooled connection.
+///
+/// Checks a connection out of `pool` and runs `f` on tokio's blocking thread
+/// pool, so the async runtime keeps serving other tasks meanwhile.
+///
+/// # Errors
+///
+/// Returns a connection error if no connection can be checked out or the
+/// blocking task panics, and otherwise whatever `f` returns.
+pub async fn with_pooled_connection<M, T, E, F>(pool: &Pool<M>, f: F) -> Result<T, E>
+where
+    M: ManageConnection,
+    F: FnOnce(&mut M::Connection) -> Result<T, E> + Send + 'static,
+    T: Send + 'static,
+    E: From<DatabaseError> + Send + 'static,
+{
+    let pool = pool.clone();
+    tokio::task::spawn_blocking(move || {
+        let mut conn = pool.get().map_err(|e| {
+            error!(error = %e, "Failed to get connection from pool");
+            DatabaseError::new(DatabaseErrorKind::Connection(e.to_string()))
+        })?;
+        f(&mut conn)
+    })
+    .await
+    .map_err(|e| {
+        DatabaseError::new(DatabaseErrorKind::Connection(format!(
+            "Database task failed: {}",
+            e
+        )))
+    })?
+}
+
+/// r2d2 connection manager for [`DatabaseConnection`]s.
+///
+/// Opens PostgreSQL or SQLite connections depending on the URL, as
+/// [`BackendKind::from_url`] decides.
+#[derive(Debug, Clone)]
+pub struct DatabaseConnectionManager {
+    url: String,
+}
+
+impl DatabaseConnectionManager {
+    /// Create a manager for the database at `url`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into() }
+    }
+}
+
+impl ManageConnection for DatabaseConnectionManager {
+    type Connection = DatabaseConnection;
+    type Error = DatabaseError;
+
+    fn connect(&self) -> Result<Self::Connection, Self::Error> {
+        match BackendKind::from_url(&self.url) {
+            BackendKind::Postgres => Ok(DatabaseConnection::Postgres(PgConnection::establish(
+                &self.url,
+            )?)),
+            // Pooled SQLite connections skip migrations; the pool ran them
+            #[cfg(feature = "sqlite")]
+            BackendKind::Sqlite => Ok(DatabaseConnection::Sqlite(
+                crate::sqlite::open_sqlite_connection(&self.url)?,
+            )),
+            #[cfg(not(feature = "sqlite"))]
+            BackendKind::Sqlite => DatabaseConnection::establish(&self.url),
+        }
+    }
+
+    fn is_valid(&self, conn: &mut Self::Connection) -> Result<(), Self::Error> {
+        let result = match conn {
+            DatabaseConnection::Postgres(conn) => conn.ping(),
+            #[cfg(feature = "sqlite")]
+            DatabaseConnection::Sqlite(conn) => conn.ping(),
+        };
+        result.map_err(|e| DatabaseError::new(DatabaseErrorKind::Connection(e.to_string())))
+    }
+
+    fn has_broken(&self, conn: &mut Self::Connection) -> bool {
+        match conn {
+            DatabaseConnection::Postgres(conn) => conn.is_broken(),
+            #[cfg(feature = "sqlite")]
+            DatabaseConnection::Sqlite(conn) => conn.is_broken(),
+        }
+    }
+}
+
+/// Create a connection pool for the database at `url`.
+///
+/// SQLite databases are created if missing and migrated once, before the
+/// pool opens its connections. `:memory:` gives every pooled connection its
+/// own empty database, so use a file for anything shared.
+///
+/// # Errors
+///
+/// Returns a connection error if the pool can't be created, or if `url`
+/// names a SQLite database and the `sqlite` feature is disabled.
+#[instrument(name = "database.create_database_pool", skip(url))]
+pub fn create_database_pool(url: &str) -> DatabaseResult<DatabasePool> {
+    if BackendKind::from_url(url) == BackendKind::Sqlite {
+        DatabaseConnection::establish(url)?;
+    }
+
+    Pool::builder()
+        .max_size(MAX_POOL_SIZE)
+        .build(DatabaseConnectionManager::new(url))
+        .map_err(|e| {
+            error!(error = %e, "Failed to create connection pool");
+            DatabaseError::new(DatabaseErrorKind::Connection(e.to_string()))
+        })
+}
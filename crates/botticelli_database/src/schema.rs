@@ -34,6 +34,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    actor_crosspost_records (actor_name, content_id) {
+        #[max_length = 255]
+        actor_name -> Varchar,
+        #[max_length = 255]
+        content_id -> Varchar,
+        deliveries -> Jsonb,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     actor_preferences (id) {
         id -> Int4,
@@ -72,6 +83,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    actor_server_leases (task_id) {
+        #[max_length = 255]
+        task_id -> Varchar,
+        #[max_length = 255]
+        owner -> Varchar,
+        acquired_at -> Timestamptz,
+        expires_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     actor_server_state (task_id) {
         #[max_length = 255]
@@ -137,6 +159,32 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    content_revisions (id) {
+        id -> Int4,
+        table_name -> Text,
+        content_id -> Int8,
+        revision -> Int4,
+        content -> Jsonb,
+        editor -> Text,
+        diff -> Jsonb,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    content_status_history (id) {
+        id -> Int4,
+        table_name -> Text,
+        content_id -> Int8,
+        from_status -> Text,
+        to_status -> Text,
+        actor -> Text,
+        reason -> Nullable<Text>,
+        changed_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::DiscordChannelType;
@@ -367,6 +415,71 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    telegram_chats (id) {
+        id -> Int8,
+        #[max_length = 16]
+        chat_type -> Varchar,
+        #[max_length = 255]
+        title -> Nullable<Varchar>,
+        #[max_length = 64]
+        username -> Nullable<Varchar>,
+        #[max_length = 64]
+        first_name -> Nullable<Varchar>,
+        #[max_length = 64]
+        last_name -> Nullable<Varchar>,
+        is_forum -> Nullable<Bool>,
+        first_seen -> Timestamp,
+        last_seen -> Timestamp,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    telegram_messages (chat_id, message_id) {
+        chat_id -> Int8,
+        message_id -> Int8,
+        from_user_id -> Nullable<Int8>,
+        text -> Nullable<Text>,
+        caption -> Nullable<Text>,
+        #[max_length = 16]
+        media_type -> Nullable<Varchar>,
+        reply_to_message_id -> Nullable<Int8>,
+        sent_at -> Timestamp,
+        edited_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    telegram_update_offsets (bot_id) {
+        bot_id -> Int8,
+        next_update_id -> Int8,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    telegram_users (id) {
+        id -> Int8,
+        is_bot -> Bool,
+        #[max_length = 64]
+        first_name -> Varchar,
+        #[max_length = 64]
+        last_name -> Nullable<Varchar>,
+        #[max_length = 64]
+        username -> Nullable<Varchar>,
+        #[max_length = 16]
+        language_code -> Nullable<Varchar>,
+        first_seen -> Timestamp,
+        last_seen -> Timestamp,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
 diesel::joinable!(act_executions -> narrative_executions (execution_id));
 diesel::joinable!(act_inputs -> act_executions (act_execution_id));
 diesel::joinable!(act_inputs -> media_references (media_ref_id));
@@ -376,16 +489,22 @@ diesel::joinable!(discord_guild_members -> discord_users (user_id));
 diesel::joinable!(discord_member_roles -> discord_roles (role_id));
 diesel::joinable!(discord_roles -> discord_guilds (guild_id));
 diesel::joinable!(post_history -> content (content_id));
+diesel::joinable!(telegram_messages -> telegram_chats (chat_id));
+diesel::joinable!(telegram_messages -> telegram_users (from_user_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     act_executions,
     act_inputs,
+    actor_crosspost_records,
     actor_preferences,
     actor_server_executions,
+    actor_server_leases,
     actor_server_state,
     content,
     content_generation_tables,
     content_generations,
+    content_revisions,
+    content_status_history,
     discord_channels,
     discord_guild_members,
     discord_guilds,
@@ -396,4 +515,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     model_responses,
     narrative_executions,
     post_history,
+    telegram_chats,
+    telegram_messages,
+    telegram_update_offsets,
+    telegram_users,
 );
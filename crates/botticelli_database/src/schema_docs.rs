@@ -3,9 +3,8 @@
 //! This module automatically generates LLM-friendly schema documentation
 //! from database table structures, eliminating boilerplate in narrative files.
 
-use crate::DatabaseResult;
 use crate::schema_reflection::{ColumnInfo, TableSchema, reflect_table_schema};
-use diesel::pg::PgConnection;
+use crate::{ContentConnection, DatabaseResult};
 use tracing::instrument;
 
 /// Type hints and documentation for common Discord field patterns
@@ -172,8 +171,8 @@ pub const DISCORD_PLATFORM_CONTEXT: &str =
 
 /// Assemble a complete prompt from template schema and user content focus
 #[instrument(name = "schema_docs.assemble_prompt", skip(conn, user_content_focus), fields(template = %template))]
-pub fn assemble_prompt(
-    conn: &mut PgConnection,
+pub fn assemble_prompt<C: ContentConnection>(
+    conn: &mut C,
     template: &str,
     user_content_focus: &str,
 ) -> DatabaseResult<String> {
@@ -3,7 +3,7 @@
 //! This module provides automatic schema inference from LLM-generated JSON,
 //! allowing content generation without explicit template definitions.
 
-use crate::DatabaseResult;
+use crate::{ContentConnection, DatabaseResult};
 use botticelli_error::{DatabaseError, DatabaseErrorKind};
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
@@ -233,7 +233,7 @@ pub fn infer_schema(json: &JsonValue) -> DatabaseResult<InferredSchema> {
 
 /// Create a table with inferred schema from JSON structure
 ///
-/// This function creates a PostgreSQL table based on an inferred schema,
+/// This function creates a table based on an inferred schema,
 /// adding standard metadata columns for content generation tracking.
 ///
 /// # Arguments
@@ -248,14 +248,14 @@ pub fn infer_schema(json: &JsonValue) -> DatabaseResult<InferredSchema> {
 ///
 /// Returns `Ok(())` if the table was created successfully, or an error if creation failed.
 #[instrument(name = "schema_inference.create_inferred_table", skip(conn, schema), fields(table = %table_name, field_count = schema.field_count()))]
-pub fn create_inferred_table(
-    conn: &mut diesel::pg::PgConnection,
+pub fn create_inferred_table<C: ContentConnection>(
+    conn: &mut C,
     table_name: &str,
     schema: &InferredSchema,
     narrative_name: Option<&str>,
     description: Option<&str>,
 ) -> DatabaseResult<()> {
-    use diesel::prelude::*;
+    let dialect = conn.dialect();
 
     // Build column definitions
     let mut columns = Vec::new();
@@ -267,16 +267,19 @@ pub fn create_inferred_table(
 
     // Add metadata columns (same as template-based tables)
     // Only add if not already present in schema
-    columns.push("generated_at TIMESTAMP NOT NULL DEFAULT NOW()".to_string());
+    columns.push(format!(
+        "generated_at TIMESTAMP NOT NULL DEFAULT {}",
+        dialect.now()
+    ));
     columns.push("source_narrative TEXT".to_string());
     columns.push("source_act TEXT".to_string());
     columns.push("generation_model TEXT".to_string());
 
     if !schema.fields.contains_key("review_status") {
-        columns.push("review_status TEXT DEFAULT 'pending'".to_string());
+        columns.push("review_status TEXT DEFAULT 'generated'".to_string());
     }
     if !schema.fields.contains_key("tags") {
-        columns.push("tags TEXT[]".to_string());
+        columns.push(format!("tags {}", dialect.text_array_type()));
     }
     if !schema.fields.contains_key("rating") {
         columns.push("rating INTEGER".to_string());
@@ -290,7 +293,7 @@ pub fn create_inferred_table(
 
     tracing::debug!(sql = %create_sql, "Creating inferred table");
 
-    diesel::sql_query(&create_sql).execute(conn)?;
+    conn.execute_sql(&create_sql, &[])?;
 
     tracing::info!(
         table = table_name,
@@ -316,7 +319,7 @@ pub fn create_inferred_table(
         description_value,
     );
 
-    diesel::sql_query(&insert_metadata).execute(conn)?;
+    conn.execute_sql(&insert_metadata, &[])?;
 
     Ok(())
 }
@@ -1,7 +1,8 @@
 //! Schema reflection and dynamic table management for content generation.
 //!
 //! This module provides functionality to:
-//! - Query PostgreSQL information_schema to inspect table structures
+//! - Inspect table structures (information_schema on PostgreSQL,
+//!   `PRAGMA table_info` on SQLite)
 //! - Create new tables based on existing Discord table templates
 //! - Add metadata columns for content generation tracking
 //!
@@ -9,9 +10,9 @@
 
 #![allow(dead_code)] // Phase 1: Infrastructure only, will be used in Phase 2
 
-use crate::DatabaseResult;
+use crate::backend::quote_literal;
+use crate::{ContentConnection, DatabaseResult, SqlDialect};
 use botticelli_error::{DatabaseError, DatabaseErrorKind};
-use diesel::pg::PgConnection;
 use diesel::prelude::*;
 use tracing::instrument;
 
@@ -44,37 +45,13 @@ pub struct TableSchema {
     pub columns: Vec<ColumnInfo>,
 }
 
-/// Query information_schema to get column information for a table
+/// Get column information for a table
 #[instrument(name = "schema_reflection.reflect_table_schema", skip(conn), fields(table = %table_name))]
-pub fn reflect_table_schema(
-    conn: &mut PgConnection,
+pub fn reflect_table_schema<C: ContentConnection>(
+    conn: &mut C,
     table_name: &str,
 ) -> DatabaseResult<TableSchema> {
-    let query = format!(
-        r#"
-        SELECT 
-            column_name as name,
-            CASE 
-                WHEN data_type = 'ARRAY' THEN udt_name
-                ELSE data_type
-            END as data_type,
-            is_nullable,
-            character_maximum_length,
-            column_default
-        FROM information_schema.columns
-        WHERE table_schema = 'public'
-          AND table_name = '{}'
-        ORDER BY ordinal_position
-        "#,
-        table_name
-    );
-
-    let results: Vec<ColumnInfo> = diesel::sql_query(&query).load(conn).map_err(|e| {
-        DatabaseError::new(DatabaseErrorKind::Query(format!(
-            "Failed to query schema for table '{}': {}",
-            table_name, e
-        )))
-    })?;
+    let results = conn.table_columns(table_name)?;
 
     if results.is_empty() {
         return Err(DatabaseError::new(DatabaseErrorKind::TableNotFound(
@@ -88,8 +65,17 @@ pub fn reflect_table_schema(
     })
 }
 
-/// Generate CREATE TABLE SQL from a table schema
+/// Generate PostgreSQL CREATE TABLE SQL from a table schema
 pub fn generate_create_table_sql(target_table_name: &str, source_schema: &TableSchema) -> String {
+    create_table_sql(SqlDialect::Postgres, target_table_name, source_schema)
+}
+
+/// Generate CREATE TABLE SQL for a dialect from a table schema
+fn create_table_sql(
+    dialect: SqlDialect,
+    target_table_name: &str,
+    source_schema: &TableSchema,
+) -> String {
     let mut sql = format!("CREATE TABLE {} (\n", target_table_name);
 
     let column_defs: Vec<String> = source_schema
@@ -106,7 +92,7 @@ pub fn generate_create_table_sql(target_table_name: &str, source_schema: &TableS
                     def.push_str(" VARCHAR");
                 }
             } else {
-                def.push_str(&format!(" {}", map_data_type(&col.data_type)));
+                def.push_str(&format!(" {}", map_data_type(dialect, &col.data_type)));
             }
 
             // Make foreign keys nullable in content generation tables
@@ -131,12 +117,15 @@ pub fn generate_create_table_sql(target_table_name: &str, source_schema: &TableS
 
     // Add content generation metadata columns
     sql.push_str(",\n\n    -- Content generation metadata\n");
-    sql.push_str("    generated_at TIMESTAMP NOT NULL DEFAULT NOW(),\n");
+    sql.push_str(&format!(
+        "    generated_at TIMESTAMP NOT NULL DEFAULT {},\n",
+        dialect.now()
+    ));
     sql.push_str("    source_narrative TEXT,\n");
     sql.push_str("    source_act TEXT,\n");
     sql.push_str("    generation_model TEXT,\n");
-    sql.push_str("    review_status TEXT DEFAULT 'pending',\n");
-    sql.push_str("    tags TEXT[],\n");
+    sql.push_str("    review_status TEXT DEFAULT 'generated',\n");
+    sql.push_str(&format!("    tags {},\n", dialect.text_array_type()));
     sql.push_str("    rating INTEGER");
 
     sql.push_str("\n)");
@@ -144,7 +133,10 @@ pub fn generate_create_table_sql(target_table_name: &str, source_schema: &TableS
 }
 
 /// Map PostgreSQL data types to Diesel-compatible types
-fn map_data_type(pg_type: &str) -> &'static str {
+fn map_data_type(dialect: SqlDialect, pg_type: &str) -> &'static str {
+    if dialect == SqlDialect::Sqlite && (pg_type == "ARRAY" || pg_type.starts_with('_')) {
+        return "JSONB";
+    }
     match pg_type {
         "bigint" => "BIGINT",
         "integer" => "INTEGER",
@@ -163,42 +155,16 @@ fn map_data_type(pg_type: &str) -> &'static str {
     }
 }
 
-/// Result struct for table existence check
-#[derive(Debug, QueryableByName)]
-struct TableExistsResult {
-    #[diesel(sql_type = diesel::sql_types::Bool)]
-    exists: bool,
-}
-
 /// Check if a table exists in the database
 #[instrument(name = "schema_reflection.table_exists", skip(conn), fields(table = %table_name))]
-pub fn table_exists(conn: &mut PgConnection, table_name: &str) -> DatabaseResult<bool> {
-    let query = format!(
-        r#"
-        SELECT EXISTS (
-            SELECT 1
-            FROM information_schema.tables
-            WHERE table_schema = 'public'
-              AND table_name = '{}'
-        ) as exists
-        "#,
-        table_name
-    );
-
-    let result: TableExistsResult = diesel::sql_query(&query).get_result(conn).map_err(|e| {
-        DatabaseError::new(DatabaseErrorKind::Query(format!(
-            "Failed to check table existence: {}",
-            e
-        )))
-    })?;
-
-    Ok(result.exists)
+pub fn table_exists<C: ContentConnection>(conn: &mut C, table_name: &str) -> DatabaseResult<bool> {
+    conn.table_exists(table_name)
 }
 
 /// Create a content generation table based on a template
 #[instrument(name = "schema_reflection.create_content_table", skip(conn), fields(table = %table_name, template = %template_source))]
-pub fn create_content_table(
-    conn: &mut PgConnection,
+pub fn create_content_table<C: ContentConnection>(
+    conn: &mut C,
     table_name: &str,
     template_source: &str,
     narrative_file: Option<&str>,
@@ -217,10 +183,10 @@ pub fn create_content_table(
     let source_schema = reflect_table_schema(conn, template_source)?;
 
     // Generate CREATE TABLE SQL
-    let create_sql = generate_create_table_sql(table_name, &source_schema);
+    let create_sql = create_table_sql(conn.dialect(), table_name, &source_schema);
 
     // Execute CREATE TABLE
-    diesel::sql_query(&create_sql).execute(conn).map_err(|e| {
+    conn.execute_sql(&create_sql, &[]).map_err(|e| {
         DatabaseError::new(DatabaseErrorKind::Query(format!(
             "Failed to create table '{}': {}",
             table_name, e
@@ -236,14 +202,14 @@ pub fn create_content_table(
         table_name,
         template_source,
         narrative_file
-            .map(|f| format!("'{}'", f))
+            .map(quote_literal)
             .unwrap_or_else(|| "NULL".to_string()),
         description
-            .map(|d| format!("'{}'", d))
+            .map(quote_literal)
             .unwrap_or_else(|| "NULL".to_string())
     );
 
-    diesel::sql_query(&insert_sql).execute(conn).map_err(|e| {
+    conn.execute_sql(&insert_sql, &[]).map_err(|e| {
         DatabaseError::new(DatabaseErrorKind::Query(format!(
             "Failed to insert metadata for table '{}': {}",
             table_name, e
@@ -0,0 +1,473 @@
+//! SQLite backend.
+//!
+//! Runs the content pipeline against a single database file, with no
+//! server to set up. The schema comes from `migrations_sqlite/`, which
+//! mirrors the PostgreSQL migrations; Diesel's `schema.rs` stays
+//! PostgreSQL-only, so queries here use raw SQL.
+
+use crate::backend::{IdRow, bind_params, query_error, quote_identifier};
+use crate::content_generation_models::SqliteContentGenerationRow;
+use crate::{
+    ColumnInfo, ContentConnection, ContentGenerationRepository, ContentGenerationRow,
+    DatabaseResult, NewContentGenerationRow, SqlDialect, UpdateContentGenerationRow,
+};
+use botticelli_core::FilterValue;
+use botticelli_error::{DatabaseError, DatabaseErrorKind};
+use diesel::connection::{Connection, SimpleConnection, TransactionManager};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, CustomizeConnection, Pool};
+use diesel::sql_types::{BigInt, Integer, Nullable, Text, TimestamptzSqlite};
+use diesel::sqlite::{Sqlite, SqliteConnection};
+use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
+use serde_json::Value as JsonValue;
+use tracing::{debug, error, instrument};
+
+/// Schema migrations for SQLite databases.
+pub const SQLITE_MIGRATIONS: EmbeddedMigrations = embed_migrations!("../../migrations_sqlite");
+
+/// Pragmas applied to every SQLite connection.
+const CONNECTION_PRAGMAS: &str = "PRAGMA foreign_keys = ON; PRAGMA busy_timeout = 5000;";
+
+/// Strip a `sqlite:` or `sqlite://` prefix from a database URL.
+fn sqlite_path(url: &str) -> &str {
+    url.strip_prefix("sqlite://")
+        .or_else(|| url.strip_prefix("sqlite:"))
+        .unwrap_or(url)
+}
+
+/// Apply pending SQLite migrations.
+///
+/// # Errors
+///
+/// Returns a migration error if any migration fails.
+#[instrument(name = "database.sqlite.run_migrations", skip(conn))]
+pub fn run_sqlite_migrations(conn: &mut SqliteConnection) -> DatabaseResult<()> {
+    let applied = conn
+        .run_pending_migrations(SQLITE_MIGRATIONS)
+        .map_err(|e| {
+            error!(error = %e, "Failed to run SQLite migrations");
+            DatabaseError::new(DatabaseErrorKind::Migration(e.to_string()))
+        })?;
+    debug!(count = applied.len(), "Applied SQLite migrations");
+    Ok(())
+}
+
+/// Open a SQLite database and bring its schema up to date.
+///
+/// Accepts a file path, `:memory:`, or a `sqlite:` URL. The file is created
+/// if it does not exist.
+///
+/// # Errors
+///
+/// Returns an error if the database can't be opened or migrated.
+#[instrument(name = "database.sqlite.establish_connection")]
+pub fn establish_sqlite_connection(url: &str) -> DatabaseResult<SqliteConnection> {
+    let mut conn = open_sqlite_connection(url)?;
+    run_sqlite_migrations(&mut conn)?;
+    Ok(conn)
+}
+
+/// Open a SQLite database with [`CONNECTION_PRAGMAS`] applied, without
+/// migrating it.
+pub(crate) fn open_sqlite_connection(url: &str) -> DatabaseResult<SqliteConnection> {
+    debug!("Opening SQLite database");
+    let mut conn = SqliteConnection::establish(sqlite_path(url)).map_err(|e| {
+        error!(error = %e, "Failed to open SQLite database");
+        DatabaseError::new(DatabaseErrorKind::Connection(e.to_string()))
+    })?;
+    conn.batch_execute(CONNECTION_PRAGMAS)?;
+    Ok(conn)
+}
+
+/// Applies [`CONNECTION_PRAGMAS`] to pooled connections.
+#[derive(Debug, Clone, Copy)]
+struct SqlitePragmas;
+
+impl CustomizeConnection<SqliteConnection, diesel::r2d2::Error> for SqlitePragmas {
+    fn on_acquire(&self, conn: &mut SqliteConnection) -> Result<(), diesel::r2d2::Error> {
+        conn.batch_execute(CONNECTION_PRAGMAS)
+            .map_err(diesel::r2d2::Error::QueryError)
+    }
+}
+
+/// Create a connection pool for a SQLite database, migrating it first.
+///
+/// `:memory:` gives every pooled connection its own empty database, so
+/// use a file for anything shared between connections.
+///
+/// # Errors
+///
+/// Returns an error if the database can't be opened or migrated.
+#[instrument(name = "database.sqlite.create_pool")]
+pub fn create_sqlite_pool(url: &str) -> DatabaseResult<Pool<ConnectionManager<SqliteConnection>>> {
+    establish_sqlite_connection(url)?;
+
+    let manager = ConnectionManager::<SqliteConnection>::new(sqlite_path(url));
+    Pool::builder()
+        .max_size(10)
+        .connection_customizer(Box::new(SqlitePragmas))
+        .build(manager)
+        .map_err(|e| {
+            error!(error = %e, "Failed to create SQLite connection pool");
+            DatabaseError::new(DatabaseErrorKind::Connection(e.to_string()))
+        })
+}
+
+/// Column as reported by `PRAGMA table_info`.
+#[derive(QueryableByName)]
+struct PragmaColumn {
+    #[diesel(sql_type = Text)]
+    name: String,
+    #[diesel(sql_type = Text)]
+    declared_type: String,
+    #[diesel(sql_type = Integer)]
+    not_null: i32,
+    #[diesel(sql_type = Nullable<Text>)]
+    dflt_value: Option<String>,
+    #[diesel(sql_type = Integer)]
+    pk: i32,
+}
+
+impl From<PragmaColumn> for ColumnInfo {
+    fn from(column: PragmaColumn) -> Self {
+        let (data_type, character_maximum_length) = postgres_type_name(&column.declared_type);
+        Self {
+            name: column.name,
+            data_type: data_type.to_string(),
+            is_nullable: if column.not_null != 0 || column.pk != 0 {
+                "NO"
+            } else {
+                "YES"
+            }
+            .to_string(),
+            character_maximum_length,
+            column_default: column.dflt_value,
+        }
+    }
+}
+
+/// Map a declared SQLite column type to its `information_schema` name.
+///
+/// SQLite keeps whatever type a column was declared with, so the schema
+/// written in PostgreSQL terms maps back cleanly. Returns the type and any
+/// length given for character types.
+fn postgres_type_name(declared: &str) -> (&'static str, Option<i32>) {
+    let declared = declared.trim().to_ascii_uppercase();
+    let (base, length) = match declared.split_once('(') {
+        Some((base, rest)) => (
+            base.trim(),
+            rest.trim_end_matches(')').trim().parse::<i32>().ok(),
+        ),
+        None => (declared.as_str(), None),
+    };
+
+    let data_type = match base {
+        "INTEGER" | "INT" | "INT4" => "integer",
+        "BIGINT" | "INT8" => "bigint",
+        "SMALLINT" | "INT2" => "smallint",
+        "BOOLEAN" | "BOOL" => "boolean",
+        "VARCHAR" | "CHARACTER VARYING" => return ("character varying", length),
+        "CHAR" | "CHARACTER" => return ("character", length),
+        "TIMESTAMPTZ" | "TIMESTAMP WITH TIME ZONE" => "timestamp with time zone",
+        "TIMESTAMP" | "DATETIME" => "timestamp without time zone",
+        "DATE" => "date",
+        "JSON" | "JSONB" => "jsonb",
+        "REAL" | "FLOAT" => "real",
+        "DOUBLE" | "DOUBLE PRECISION" => "double precision",
+        "NUMERIC" | "DECIMAL" => "numeric",
+        "UUID" => "uuid",
+        "BLOB" => "bytea",
+        _ => "text",
+    };
+    (data_type, None)
+}
+
+/// Row holding a JSON document as text.
+#[derive(QueryableByName)]
+struct JsonTextRow {
+    #[diesel(sql_type = Text)]
+    json: String,
+}
+
+/// Row holding a count.
+#[derive(QueryableByName)]
+struct CountRow {
+    #[diesel(sql_type = BigInt)]
+    count: i64,
+}
+
+/// Expression giving a column's value as JSON, for `json_object`.
+fn json_expression(column: &ColumnInfo) -> String {
+    let name = format!("t.{}", quote_identifier(&column.name));
+    match column.data_type.as_str() {
+        "json" | "jsonb" => format!("json({})", name),
+        // Booleans are stored as 0/1
+        "boolean" => format!(
+            "CASE WHEN {name} IS NULL THEN NULL WHEN {name} THEN json('true') ELSE json('false') END"
+        ),
+        _ => name,
+    }
+}
+
+impl ContentConnection for SqliteConnection {
+    fn dialect(&self) -> SqlDialect {
+        SqlDialect::Sqlite
+    }
+
+    #[instrument(name = "backend.sqlite.table_exists", skip(self))]
+    fn table_exists(&mut self, table_name: &str) -> DatabaseResult<bool> {
+        let row: CountRow = diesel::sql_query(
+            "SELECT COUNT(*) AS count FROM sqlite_master WHERE type = 'table' AND name = ?1",
+        )
+        .bind::<Text, _>(table_name)
+        .get_result(self)
+        .map_err(|e| query_error(format!("Failed to check table existence: {}", e)))?;
+
+        Ok(row.count > 0)
+    }
+
+    #[instrument(name = "backend.sqlite.table_columns", skip(self))]
+    fn table_columns(&mut self, table_name: &str) -> DatabaseResult<Vec<ColumnInfo>> {
+        let columns: Vec<PragmaColumn> = diesel::sql_query(
+            "SELECT name, type AS declared_type, \"notnull\" AS not_null, dflt_value, pk
+             FROM pragma_table_info(?1)
+             ORDER BY cid",
+        )
+        .bind::<Text, _>(table_name)
+        .load(self)
+        .map_err(|e| {
+            query_error(format!(
+                "Failed to query schema for table '{}': {}",
+                table_name, e
+            ))
+        })?;
+
+        Ok(columns.into_iter().map(ColumnInfo::from).collect())
+    }
+
+    fn execute_sql(&mut self, sql: &str, params: &[FilterValue]) -> DatabaseResult<usize> {
+        bind_params::<Sqlite>(diesel::sql_query(sql), params)
+            .execute(self)
+            .map_err(query_error)
+    }
+
+    fn load_json(
+        &mut self,
+        select: &str,
+        columns: &[ColumnInfo],
+        params: &[FilterValue],
+    ) -> DatabaseResult<Vec<JsonValue>> {
+        let fields: Vec<String> = columns
+            .iter()
+            .map(|column| {
+                format!(
+                    "'{}', {}",
+                    column.name.replace('\'', "''"),
+                    json_expression(column)
+                )
+            })
+            .collect();
+        let query = format!(
+            "SELECT json_object({}) AS json FROM ({}) t",
+            fields.join(", "),
+            select
+        );
+
+        let rows: Vec<JsonTextRow> = bind_params::<Sqlite>(diesel::sql_query(query), params)
+            .load(self)
+            .map_err(query_error)?;
+
+        rows.iter()
+            .map(|row| serde_json::from_str(&row.json).map_err(query_error))
+            .collect()
+    }
+
+    fn insert_returning_id(&mut self, insert: &str, params: &[FilterValue]) -> DatabaseResult<i64> {
+        let row: IdRow = bind_params::<Sqlite>(diesel::sql_query(insert), params)
+            .get_result(self)
+            .map_err(query_error)?;
+        Ok(row.id)
+    }
+
+    fn begin_transaction(&mut self) -> DatabaseResult<()> {
+        <Self as Connection>::TransactionManager::begin_transaction(self).map_err(query_error)
+    }
+
+    fn commit_transaction(&mut self) -> DatabaseResult<()> {
+        <Self as Connection>::TransactionManager::commit_transaction(self).map_err(query_error)
+    }
+
+    fn rollback_transaction(&mut self) -> DatabaseResult<()> {
+        <Self as Connection>::TransactionManager::rollback_transaction(self).map_err(query_error)
+    }
+
+    fn generations(&mut self) -> Box<dyn ContentGenerationRepository + '_> {
+        Box::new(SqliteContentGenerationRepository::new(self))
+    }
+}
+
+/// SQLite implementation of ContentGenerationRepository.
+pub struct SqliteContentGenerationRepository<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> SqliteContentGenerationRepository<'a> {
+    /// Create a new repository with a mutable connection reference.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    fn load(
+        &mut self,
+        query: diesel::query_builder::BoxedSqlQuery<'_, Sqlite, diesel::query_builder::SqlQuery>,
+    ) -> DatabaseResult<Vec<ContentGenerationRow>> {
+        let rows: Vec<SqliteContentGenerationRow> = query.load(self.conn).map_err(query_error)?;
+        Ok(rows.into_iter().map(ContentGenerationRow::from).collect())
+    }
+}
+
+impl<'a> ContentGenerationRepository for SqliteContentGenerationRepository<'a> {
+    fn start_generation(
+        &mut self,
+        new_gen: NewContentGenerationRow,
+    ) -> DatabaseResult<ContentGenerationRow> {
+        debug!(table = %new_gen.table_name, narrative = ?new_gen.narrative_file, "Starting content generation");
+
+        // Check if generation already exists
+        if let Some(existing) = self.get_by_table_name(&new_gen.table_name)? {
+            debug!(table = %new_gen.table_name, existing_status = %existing.status(), "Generation already exists, returning existing record");
+            return Ok(existing);
+        }
+
+        let query = diesel::sql_query(
+            "INSERT INTO content_generations
+                (table_name, narrative_file, narrative_name, status, created_by)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             RETURNING *",
+        )
+        .into_boxed()
+        .bind::<Text, _>(new_gen.table_name.clone())
+        .bind::<Text, _>(new_gen.narrative_file)
+        .bind::<Text, _>(new_gen.narrative_name)
+        .bind::<Text, _>(new_gen.status)
+        .bind::<Nullable<Text>, _>(new_gen.created_by);
+
+        self.load(query)?.into_iter().next().ok_or_else(|| {
+            error!(table = %new_gen.table_name, "Failed to start content generation");
+            query_error("INSERT returned no row")
+        })
+    }
+
+    fn complete_generation(
+        &mut self,
+        table: &str,
+        update: UpdateContentGenerationRow,
+    ) -> DatabaseResult<ContentGenerationRow> {
+        debug!(table = %table, status = ?update.status, "Completing content generation");
+
+        // Like the AsChangeset derive, only set fields that are present
+        let columns: Vec<&str> = [
+            ("completed_at", update.completed_at.is_some()),
+            ("row_count", update.row_count.is_some()),
+            (
+                "generation_duration_ms",
+                update.generation_duration_ms.is_some(),
+            ),
+            ("status", update.status.is_some()),
+            ("error_message", update.error_message.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(column, present)| present.then_some(column))
+        .collect();
+        if columns.is_empty() {
+            return self
+                .get_by_table_name(table)?
+                .ok_or_else(|| DatabaseError::new(DatabaseErrorKind::NotFound));
+        }
+        // ?1 is the table name
+        let sets: Vec<String> = columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| format!("{} = ?{}", column, i + 2))
+            .collect();
+
+        let mut query = diesel::sql_query(format!(
+            "UPDATE content_generations SET {} WHERE table_name = ?1 RETURNING *",
+            sets.join(", ")
+        ))
+        .into_boxed()
+        .bind::<Text, _>(table.to_string());
+        if let Some(completed_at) = update.completed_at {
+            query = query.bind::<TimestamptzSqlite, _>(completed_at);
+        }
+        if let Some(row_count) = update.row_count {
+            query = query.bind::<Integer, _>(row_count);
+        }
+        if let Some(duration) = update.generation_duration_ms {
+            query = query.bind::<Integer, _>(duration);
+        }
+        if let Some(status) = update.status {
+            query = query.bind::<Text, _>(status);
+        }
+        if let Some(message) = update.error_message {
+            query = query.bind::<Text, _>(message);
+        }
+
+        self.load(query)?.into_iter().next().ok_or_else(|| {
+            error!(table = %table, "Failed to complete content generation");
+            DatabaseError::new(DatabaseErrorKind::NotFound)
+        })
+    }
+
+    fn get_last_successful(&mut self) -> DatabaseResult<Option<ContentGenerationRow>> {
+        let query = diesel::sql_query(
+            "SELECT * FROM content_generations
+             WHERE status = 'success'
+             ORDER BY generated_at DESC, id DESC
+             LIMIT 1",
+        )
+        .into_boxed();
+        Ok(self.load(query)?.into_iter().next())
+    }
+
+    fn list_generations(
+        &mut self,
+        status_filter: Option<String>,
+        limit: i64,
+    ) -> DatabaseResult<Vec<ContentGenerationRow>> {
+        let query = match status_filter {
+            Some(status) => diesel::sql_query(
+                "SELECT * FROM content_generations
+                 WHERE status = ?1
+                 ORDER BY generated_at DESC, id DESC
+                 LIMIT ?2",
+            )
+            .into_boxed()
+            .bind::<Text, _>(status)
+            .bind::<BigInt, _>(limit),
+            None => diesel::sql_query(
+                "SELECT * FROM content_generations
+                 ORDER BY generated_at DESC, id DESC
+                 LIMIT ?1",
+            )
+            .into_boxed()
+            .bind::<BigInt, _>(limit),
+        };
+        self.load(query)
+    }
+
+    fn get_by_table_name(&mut self, table: &str) -> DatabaseResult<Option<ContentGenerationRow>> {
+        let query = diesel::sql_query("SELECT * FROM content_generations WHERE table_name = ?1")
+            .into_boxed()
+            .bind::<Text, _>(table.to_string());
+        Ok(self.load(query)?.into_iter().next())
+    }
+
+    fn delete_generation(&mut self, table: &str) -> DatabaseResult<()> {
+        diesel::sql_query("DELETE FROM content_generations WHERE table_name = ?1")
+            .bind::<Text, _>(table)
+            .execute(self.conn)
+            .map(|_| ())
+            .map_err(query_error)
+    }
+}
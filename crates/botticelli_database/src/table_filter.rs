@@ -0,0 +1,368 @@
+//! Compile structured table filters to parameterized SQL.
+
+use crate::backend::quote_identifier as quote;
+use crate::{
+    ColumnInfo, DatabaseError, DatabaseErrorKind, DatabaseResult, SqlDialect, TableSchema,
+};
+use botticelli_core::{FilterCondition, FilterOperators, FilterValue, TableFilter};
+
+/// Column types a text parameter can be cast to for comparison.
+///
+/// Names come from `information_schema.columns.data_type`; types not listed
+/// here (enums, ranges, ...) are compared as text instead.
+pub(crate) const CASTABLE_TYPES: &[&str] = &[
+    "smallint",
+    "integer",
+    "bigint",
+    "numeric",
+    "real",
+    "double precision",
+    "boolean",
+    "date",
+    "time without time zone",
+    "time with time zone",
+    "timestamp without time zone",
+    "timestamp with time zone",
+    "interval",
+    "uuid",
+];
+
+/// How a column can be filtered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColumnKind {
+    Text,
+    Array,
+    Json,
+    Scalar,
+}
+
+impl ColumnKind {
+    fn of(column: &ColumnInfo) -> Self {
+        match column.data_type.as_str() {
+            "text" | "character varying" | "character" => Self::Text,
+            "json" | "jsonb" => Self::Json,
+            // Array columns are reported by their element type, e.g. `_text`
+            data_type if data_type.starts_with('_') => Self::Array,
+            _ => Self::Scalar,
+        }
+    }
+}
+
+/// A WHERE condition with positional parameters (`$1` or `?1`, ...).
+#[derive(Debug, Clone, PartialEq, derive_getters::Getters)]
+pub struct CompiledFilter {
+    /// SQL condition, without the `WHERE` keyword.
+    sql: String,
+    /// Parameter values, in placeholder order.
+    params: Vec<FilterValue>,
+    /// Dialect the condition is written in.
+    #[getter(skip)]
+    dialect: SqlDialect,
+}
+
+impl CompiledFilter {
+    /// Compile a filter against a table's schema.
+    ///
+    /// Every column must exist in `schema`, and each operator must suit the
+    /// column's type. Values are never written into the SQL; they become
+    /// parameters numbered from 1 in `dialect`'s placeholder style.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidQuery` for unknown columns or unsupported operators.
+    pub fn compile(
+        filter: &TableFilter,
+        schema: &TableSchema,
+        dialect: SqlDialect,
+    ) -> DatabaseResult<Self> {
+        let mut compiler = Self {
+            sql: String::new(),
+            params: Vec::new(),
+            dialect,
+        };
+
+        let mut conditions = Vec::new();
+        for (name, condition) in filter.conditions() {
+            let column = schema
+                .columns
+                .iter()
+                .find(|c| c.name == name)
+                .ok_or_else(|| {
+                    invalid(format!(
+                        "Unknown column '{}' in filter on table '{}'",
+                        name, schema.table_name
+                    ))
+                })?;
+
+            match condition {
+                FilterCondition::Equals(value) => {
+                    conditions.push(compiler.compare(column, "=", value)?);
+                }
+                FilterCondition::Operators(operators) => {
+                    conditions.extend(compiler.operators(column, operators)?);
+                }
+            }
+        }
+
+        compiler.sql = if conditions.is_empty() {
+            "TRUE".to_string()
+        } else {
+            conditions.join(" AND ")
+        };
+        Ok(compiler)
+    }
+
+    /// A raw SQL condition without parameters.
+    pub(crate) fn from_sql(sql: String, dialect: SqlDialect) -> Self {
+        Self {
+            sql,
+            params: Vec::new(),
+            dialect,
+        }
+    }
+
+    /// This condition AND a raw SQL condition.
+    pub(crate) fn and_sql(mut self, sql: &str) -> Self {
+        self.sql = format!("{} AND ({})", self.sql, sql);
+        self
+    }
+
+    /// This condition AND a case-insensitive substring match on any of
+    /// `columns`.
+    ///
+    /// Only text, array and JSON columns are searched; JSON and arrays are
+    /// matched against their text form.
+    pub(crate) fn and_search(mut self, columns: &[ColumnInfo], term: &str) -> Self {
+        let searchable: Vec<&ColumnInfo> = columns
+            .iter()
+            .filter(|c| ColumnKind::of(c) != ColumnKind::Scalar)
+            .collect();
+        if searchable.is_empty() {
+            self.sql = format!("{} AND FALSE", self.sql);
+            return self;
+        }
+
+        // One parameter, referenced by every column's match
+        let param = self.param(FilterValue::Text(term.to_lowercase()));
+        let matches: Vec<String> = searchable
+            .into_iter()
+            .map(|column| {
+                let name = quote(&column.name);
+                match self.dialect {
+                    SqlDialect::Postgres => {
+                        format!("strpos(lower({}::text), {}::text) > 0", name, param)
+                    }
+                    SqlDialect::Sqlite => format!(
+                        "instr(lower(CAST({} AS TEXT)), CAST({} AS TEXT)) > 0",
+                        name, param
+                    ),
+                }
+            })
+            .collect();
+
+        self.sql = format!("{} AND ({})", self.sql, matches.join(" OR "));
+        self
+    }
+
+    fn operators(
+        &mut self,
+        column: &ColumnInfo,
+        operators: &FilterOperators,
+    ) -> DatabaseResult<Vec<String>> {
+        let comparisons = [
+            ("=", &operators.eq),
+            ("<>", &operators.ne),
+            (">", &operators.gt),
+            (">=", &operators.gte),
+            ("<", &operators.lt),
+            ("<=", &operators.lte),
+        ];
+
+        let mut conditions = Vec::new();
+        for (op, value) in comparisons {
+            if let Some(value) = value {
+                conditions.push(self.compare(column, op, value)?);
+            }
+        }
+        if let Some(values) = &operators.one_of {
+            conditions.push(self.membership(column, values, false)?);
+        }
+        if let Some(values) = &operators.not_in {
+            conditions.push(self.membership(column, values, true)?);
+        }
+        if let Some(value) = &operators.contains {
+            conditions.push(self.contains(column, value)?);
+        }
+        if let Some(pattern) = &operators.like {
+            if ColumnKind::of(column) != ColumnKind::Text {
+                return Err(unsupported("like", column));
+            }
+            let param = self.param(FilterValue::Text(pattern.clone()));
+            conditions.push(format!("{} LIKE {}", quote(&column.name), param));
+        }
+        if let Some(is_null) = operators.is_null {
+            let test = if is_null { "IS NULL" } else { "IS NOT NULL" };
+            conditions.push(format!("{} {}", quote(&column.name), test));
+        }
+
+        if conditions.is_empty() {
+            return Err(invalid(format!(
+                "No operators given for column '{}'",
+                column.name
+            )));
+        }
+        Ok(conditions)
+    }
+
+    /// `column <op> value`, with the value cast to the column type if needed.
+    fn compare(
+        &mut self,
+        column: &ColumnInfo,
+        op: &str,
+        value: &FilterValue,
+    ) -> DatabaseResult<String> {
+        let name = quote(&column.name);
+        match ColumnKind::of(column) {
+            ColumnKind::Array => Err(unsupported(op, column)),
+            ColumnKind::Json if op == "=" || op == "<>" => {
+                let param = self.param(value.clone());
+                Ok(match self.dialect {
+                    SqlDialect::Postgres => format!("{}::jsonb {} to_jsonb({})", name, op, param),
+                    SqlDialect::Sqlite => format!("json({}) {} json_quote({})", name, op, param),
+                })
+            }
+            ColumnKind::Json => Err(unsupported(op, column)),
+            ColumnKind::Text => {
+                let param = self.text_param(value.clone());
+                Ok(format!("{} {} {}", name, op, param))
+            }
+            ColumnKind::Scalar => {
+                let (lhs, rhs) = self.typed_operands(column, value);
+                Ok(format!("{} {} {}", lhs, op, rhs))
+            }
+        }
+    }
+
+    /// `column [NOT] IN (...)`.
+    fn membership(
+        &mut self,
+        column: &ColumnInfo,
+        values: &[FilterValue],
+        negated: bool,
+    ) -> DatabaseResult<String> {
+        let kind = ColumnKind::of(column);
+        if matches!(kind, ColumnKind::Array | ColumnKind::Json) {
+            return Err(unsupported(if negated { "not_in" } else { "in" }, column));
+        }
+        if values.is_empty() {
+            return Ok(if negated { "TRUE" } else { "FALSE" }.to_string());
+        }
+
+        // The column side is cast per value type, so one list needs one type
+        let first = std::mem::discriminant(&values[0]);
+        if kind != ColumnKind::Text && values.iter().any(|v| std::mem::discriminant(v) != first) {
+            return Err(invalid(format!(
+                "Operator '{}' on column '{}' needs values of one type",
+                if negated { "not_in" } else { "in" },
+                column.name
+            )));
+        }
+
+        let mut lhs = None;
+        let mut params = Vec::new();
+        for value in values {
+            if kind == ColumnKind::Text {
+                params.push(self.text_param(value.clone()));
+            } else {
+                let (column_expr, param) = self.typed_operands(column, value);
+                lhs.get_or_insert(column_expr);
+                params.push(param);
+            }
+        }
+        let lhs = lhs.unwrap_or_else(|| quote(&column.name));
+
+        let op = if negated { "NOT IN" } else { "IN" };
+        Ok(format!("{} {} ({})", lhs, op, params.join(", ")))
+    }
+
+    /// Array or JSON containment, or substring match on text.
+    fn contains(&mut self, column: &ColumnInfo, value: &FilterValue) -> DatabaseResult<String> {
+        let name = quote(&column.name);
+        match (ColumnKind::of(column), self.dialect) {
+            (ColumnKind::Array, SqlDialect::Postgres) => {
+                let param = self.param(value.clone());
+                Ok(format!("{} = ANY({})", param, name))
+            }
+            (ColumnKind::Json, SqlDialect::Postgres) => {
+                let param = self.param(value.clone());
+                Ok(format!("{}::jsonb @> to_jsonb({})", name, param))
+            }
+            // SQLite stores arrays as JSON, so both search the JSON elements
+            (ColumnKind::Array | ColumnKind::Json, SqlDialect::Sqlite) => {
+                let param = self.param(value.clone());
+                Ok(format!(
+                    "EXISTS (SELECT 1 FROM json_each({}) WHERE value = {})",
+                    name, param
+                ))
+            }
+            (ColumnKind::Text, SqlDialect::Postgres) => {
+                let param = self.text_param(value.clone());
+                Ok(format!("strpos({}, {}) > 0", name, param))
+            }
+            (ColumnKind::Text, SqlDialect::Sqlite) => {
+                let param = self.text_param(value.clone());
+                Ok(format!("instr({}, {}) > 0", name, param))
+            }
+            (ColumnKind::Scalar, _) => Err(unsupported("contains", column)),
+        }
+    }
+
+    /// Operands for comparing a scalar column with a value.
+    ///
+    /// Numbers and booleans bind with their own SQL types. Text values are
+    /// cast to the column type (e.g. for timestamps), or the column is
+    /// compared as text when its type can't be named safely. SQLite
+    /// compares by value affinity, so it needs no casts.
+    fn typed_operands(&mut self, column: &ColumnInfo, value: &FilterValue) -> (String, String) {
+        let name = quote(&column.name);
+        let param = self.param(value.clone());
+        if self.dialect == SqlDialect::Sqlite {
+            return (name, param);
+        }
+        match value {
+            FilterValue::Text(_) if CASTABLE_TYPES.contains(&column.data_type.as_str()) => {
+                (name, format!("CAST({} AS {})", param, column.data_type))
+            }
+            FilterValue::Text(_) => (format!("{}::text", name), param),
+            _ => (name, param),
+        }
+    }
+
+    /// Add a parameter and return its placeholder.
+    fn param(&mut self, value: FilterValue) -> String {
+        self.params.push(value);
+        self.dialect.placeholder(self.params.len())
+    }
+
+    /// Add a parameter compared as text and return its placeholder.
+    fn text_param(&mut self, value: FilterValue) -> String {
+        let param = self.param(value);
+        match self.dialect {
+            SqlDialect::Postgres => format!("{}::text", param),
+            SqlDialect::Sqlite => format!("CAST({} AS TEXT)", param),
+        }
+    }
+}
+
+#[track_caller]
+fn invalid(message: String) -> DatabaseError {
+    DatabaseError::new(DatabaseErrorKind::InvalidQuery(message))
+}
+
+#[track_caller]
+fn unsupported(op: &str, column: &ColumnInfo) -> DatabaseError {
+    invalid(format!(
+        "Operator '{}' is not supported on column '{}' of type {}",
+        op, column.name, column.data_type
+    ))
+}
@@ -1,66 +1,134 @@
 //! Table query execution for narrative table references.
 
-use crate::{DatabaseError, DatabaseErrorKind, DatabaseResult};
-use botticelli_interface::{TableCountView, TableQueryView};
-use diesel::prelude::*;
-use diesel::sql_types::{BigInt, Text};
+use crate::{
+    ColumnInfo, CompiledFilter, ContentConnection, DatabaseError, DatabaseErrorKind,
+    DatabaseResult, SqlDialect, TableSchema, reflect_table_schema, with_pooled_connection,
+};
+use botticelli_interface::{TableCountView, TableQueryView, TableView};
+use diesel::pg::PgConnection;
+use diesel::r2d2::{ConnectionManager, ManageConnection, Pool};
 use serde_json::Value as JsonValue;
-use std::sync::{Arc, Mutex};
 use tracing::{debug, instrument};
 
 /// Executes table queries for narrative table references.
-#[derive(Clone, derive_getters::Getters)]
-pub struct TableQueryExecutor {
-    connection: Arc<Mutex<PgConnection>>,
+///
+/// Each query checks a connection out of the pool on tokio's blocking
+/// thread pool, so concurrent narratives don't wait on each other.
+#[derive(derive_getters::Getters)]
+pub struct TableQueryExecutor<M: ManageConnection = ConnectionManager<PgConnection>> {
+    pool: Pool<M>,
+    /// Whether raw SQL `where` strings are accepted
+    allow_raw_filters: bool,
 }
 
-impl TableQueryExecutor {
+impl<M: ManageConnection> Clone for TableQueryExecutor<M> {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            allow_raw_filters: self.allow_raw_filters,
+        }
+    }
+}
+
+impl<M> TableQueryExecutor<M>
+where
+    M: ManageConnection,
+    M::Connection: ContentConnection,
+{
     /// Creates a new table query executor.
-    pub fn new(connection: Arc<Mutex<PgConnection>>) -> Self {
-        Self { connection }
+    ///
+    /// Raw SQL filters are rejected until enabled with
+    /// [`Self::with_raw_filters`].
+    pub fn new(pool: Pool<M>) -> Self {
+        Self {
+            pool,
+            allow_raw_filters: false,
+        }
+    }
+
+    /// Accept or reject raw SQL filters.
+    ///
+    /// Only enable this for narratives written by a trusted operator; raw
+    /// `where` strings are spliced into the query, while structured filters
+    /// are compiled to bound parameters.
+    pub fn with_raw_filters(mut self, allow: bool) -> Self {
+        self.allow_raw_filters = allow;
+        self
     }
 
     /// Queries a table and returns results as JSON values.
     #[instrument(skip(self), fields(table_name = %view.table_name(), limit = ?view.limit(), offset = ?view.offset()))]
-    pub fn query_table(&self, view: &TableQueryView) -> DatabaseResult<Vec<JsonValue>> {
-        debug!("Querying table");
+    pub async fn query_table(&self, view: &TableQueryView) -> DatabaseResult<Vec<JsonValue>> {
+        let executor = self.clone();
+        let view = view.clone();
+        with_pooled_connection(&self.pool, move |conn| {
+            executor.query_table_with(conn, &view)
+        })
+        .await
+    }
 
-        let mut conn = self
-            .connection
-            .lock()
-            .map_err(|e| DatabaseError::new(DatabaseErrorKind::Connection(e.to_string())))?;
+    /// Queries a table, returns results, and deletes those rows (destructive read).
+    #[instrument(skip(self), fields(table_name = %view.table_name(), limit = ?view.limit(), offset = ?view.offset()))]
+    pub async fn query_and_delete_table(
+        &self,
+        view: &TableQueryView,
+    ) -> DatabaseResult<Vec<JsonValue>> {
+        let view = view.clone();
+        with_pooled_connection(&self.pool, move |conn| {
+            Self::query_and_delete_table_with(conn, &view)
+        })
+        .await
+    }
+
+    /// Gets the count of rows that would be returned by a query.
+    #[instrument(skip(self), fields(table_name = %view.table_name()))]
+    pub async fn count_rows(&self, view: &TableCountView) -> DatabaseResult<i64> {
+        let executor = self.clone();
+        let view = view.clone();
+        with_pooled_connection(&self.pool, move |conn| {
+            executor.count_rows_with(conn, &view)
+        })
+        .await
+    }
+
+    /// Runs [`Self::query_table`] on a checked-out connection.
+    fn query_table_with(
+        &self,
+        conn: &mut M::Connection,
+        view: &TableQueryView,
+    ) -> DatabaseResult<Vec<JsonValue>> {
+        debug!("Querying table");
 
         // Validate table exists
-        if !self.table_exists(&mut conn, view.table_name())? {
+        if !conn.table_exists(view.table_name())? {
             return Err(DatabaseError::new(DatabaseErrorKind::TableNotFound(
                 view.table_name().to_string(),
             )));
         }
 
         // Build SQL query
-        let query = self.build_query(view)?;
+        let schema = reflect_table_schema(conn, view.table_name())?;
+        let condition = self.where_condition(conn.dialect(), &schema, view)?;
+        let query = self.build_query(view, condition.as_ref())?;
 
         debug!(query = %query, "Executing table query");
 
         // Execute query using raw SQL
-        let results = self.execute_raw_query(&mut conn, &query)?;
+        let results = self.execute_raw_query(conn, &schema, view, &query, condition.as_ref())?;
 
         debug!(count = results.len(), "Retrieved rows");
         Ok(results)
     }
 
-    /// Queries a table, returns results, and deletes those rows (destructive read).
-    #[instrument(skip(self), fields(table_name = %view.table_name(), limit = ?view.limit(), offset = ?view.offset()))]
-    pub fn query_and_delete_table(&self, view: &TableQueryView) -> DatabaseResult<Vec<JsonValue>> {
+    /// Runs [`Self::query_and_delete_table`] on a checked-out connection.
+    fn query_and_delete_table_with(
+        conn: &mut M::Connection,
+        view: &TableQueryView,
+    ) -> DatabaseResult<Vec<JsonValue>> {
         debug!("Querying and deleting from table");
 
-        let mut conn = self
-            .connection
-            .lock()
-            .map_err(|e| DatabaseError::new(DatabaseErrorKind::Connection(e.to_string())))?;
-
         // Validate table exists
-        if !self.table_exists(&mut conn, view.table_name())? {
+        if !conn.table_exists(view.table_name())? {
             return Err(DatabaseError::new(DatabaseErrorKind::TableNotFound(
                 view.table_name().to_string(),
             )));
@@ -68,38 +136,49 @@ impl TableQueryExecutor {
 
         // Call pull_and_delete from content_management
         let limit = view.limit().unwrap_or(10) as usize;
-        let results =
-            crate::content_management::pull_and_delete(&mut conn, view.table_name(), limit)
-                .map_err(|e| DatabaseError::new(DatabaseErrorKind::Query(e.to_string())))?;
+        let results = crate::content_management::pull_and_delete(conn, view.table_name(), limit)
+            .map_err(|e| DatabaseError::new(DatabaseErrorKind::Query(e.to_string())))?;
 
         debug!(count = results.len(), "Retrieved and deleted rows");
         Ok(results)
     }
 
-    /// Checks if a table exists in the database.
-    #[instrument(skip(self, conn))]
-    fn table_exists(&self, conn: &mut PgConnection, table_name: &str) -> DatabaseResult<bool> {
-        #[derive(QueryableByName)]
-        struct ExistsResult {
-            #[diesel(sql_type = diesel::sql_types::Bool)]
-            exists: bool,
-        }
-
-        let query = "SELECT EXISTS (
-            SELECT FROM information_schema.tables 
-            WHERE table_name = $1
-        ) as exists";
+    /// Combines a view's structured and raw filters into one condition.
+    ///
+    /// Structured filters are validated against the table's columns.
+    fn where_condition(
+        &self,
+        dialect: SqlDialect,
+        schema: &TableSchema,
+        view: &impl TableView,
+    ) -> DatabaseResult<Option<CompiledFilter>> {
+        let mut condition = match view.conditions() {
+            Some(filter) => Some(CompiledFilter::compile(filter, schema, dialect)?),
+            None => None,
+        };
 
-        let result: ExistsResult = diesel::sql_query(query)
-            .bind::<Text, _>(table_name)
-            .get_result(conn)
-            .map_err(|e| DatabaseError::new(DatabaseErrorKind::Query(e.to_string())))?;
+        if let Some(where_clause) = view.filter() {
+            if !self.allow_raw_filters {
+                return Err(DatabaseError::new(DatabaseErrorKind::InvalidQuery(
+                    "Raw SQL filters are disabled; use a structured filter".into(),
+                )));
+            }
+            let safe_clause = self.sanitize_where_clause(where_clause)?;
+            condition = Some(match condition {
+                Some(compiled) => compiled.and_sql(&safe_clause),
+                None => CompiledFilter::from_sql(safe_clause, dialect),
+            });
+        }
 
-        Ok(result.exists)
+        Ok(condition)
     }
 
     /// Builds a SELECT query from the provided view.
-    fn build_query(&self, view: &TableQueryView) -> DatabaseResult<String> {
+    fn build_query(
+        &self,
+        view: &TableQueryView,
+        condition: Option<&CompiledFilter>,
+    ) -> DatabaseResult<String> {
         let table_name = view.table_name();
 
         // Sanitize table name (alphanumeric and underscores only)
@@ -125,9 +204,8 @@ impl TableQueryExecutor {
 
         let mut query = format!("SELECT {} FROM {}", col_list, table_name);
 
-        if let Some(where_clause) = view.filter() {
-            let safe_clause = self.sanitize_where_clause(where_clause)?;
-            query.push_str(&format!(" WHERE {}", safe_clause));
+        if let Some(condition) = condition {
+            query.push_str(&format!(" WHERE {}", condition.sql()));
         }
 
         if let Some(order) = view.order_by() {
@@ -173,78 +251,87 @@ impl TableQueryExecutor {
     /// Executes a raw SQL query and returns results as JSON.
     fn execute_raw_query(
         &self,
-        conn: &mut PgConnection,
+        conn: &mut M::Connection,
+        schema: &TableSchema,
+        view: &TableQueryView,
         query: &str,
+        condition: Option<&CompiledFilter>,
     ) -> DatabaseResult<Vec<JsonValue>> {
         use tracing::warn;
 
-        // Use diesel's sql_query to execute raw SQL
-        // We'll return the results as JSON strings from PostgreSQL
-        let json_query = format!("SELECT row_to_json(t) as json FROM ({}) t", query);
-
-        #[derive(QueryableByName)]
-        struct JsonRow {
-            #[diesel(sql_type = diesel::sql_types::Json)]
-            json: JsonValue,
-        }
-
-        match diesel::sql_query(&json_query).load::<JsonRow>(conn) {
-            Ok(results) => Ok(results.into_iter().map(|row| row.json).collect()),
-            Err(e) => {
-                let err_msg = e.to_string();
-                // Handle missing column errors gracefully
-                if err_msg.contains("column") && err_msg.contains("does not exist") {
-                    warn!(
-                        error = %err_msg,
-                        query = %query,
-                        "Query references non-existent column - returning empty result set"
-                    );
-                    // Return empty result instead of propagating error
-                    Ok(Vec::new())
-                } else {
-                    Err(DatabaseError::new(DatabaseErrorKind::Query(err_msg)))
+        // Columns the query returns, in select order
+        let columns: Vec<ColumnInfo> = match view.columns() {
+            Some(names) => {
+                let mut columns = Vec::new();
+                for name in names {
+                    match schema.columns.iter().find(|c| &c.name == name) {
+                        Some(column) => columns.push(column.clone()),
+                        None => {
+                            // Handle missing columns gracefully
+                            warn!(
+                                column = %name,
+                                query = %query,
+                                "Query references non-existent column - returning empty result set"
+                            );
+                            return Ok(Vec::new());
+                        }
+                    }
                 }
+                columns
             }
-        }
-    }
+            None => schema.columns.clone(),
+        };
 
-    /// Gets the count of rows that would be returned by a query.
-    #[instrument(skip(self), fields(table_name = %view.table_name()))]
-    pub fn count_rows(&self, view: &TableCountView) -> DatabaseResult<i64> {
-        let mut conn = self
-            .connection
-            .lock()
-            .map_err(|e| DatabaseError::new(DatabaseErrorKind::Connection(e.to_string())))?;
+        let params = condition.map(|c| c.params().as_slice()).unwrap_or_default();
+        conn.load_json(query, &columns, params)
+    }
 
+    /// Runs [`Self::count_rows`] on a checked-out connection.
+    fn count_rows_with(
+        &self,
+        conn: &mut M::Connection,
+        view: &TableCountView,
+    ) -> DatabaseResult<i64> {
         let table_name = view.table_name();
 
         // Validate table exists
-        if !self.table_exists(&mut conn, table_name)? {
+        if !conn.table_exists(table_name)? {
             return Err(DatabaseError::new(DatabaseErrorKind::TableNotFound(
                 table_name.to_string(),
             )));
         }
 
+        let schema = reflect_table_schema(conn, table_name)?;
+        let condition = self.where_condition(conn.dialect(), &schema, view)?;
         let mut query = format!("SELECT COUNT(*) as count FROM {}", table_name);
 
-        if let Some(where_clause) = view.filter() {
-            let safe_clause = self.sanitize_where_clause(where_clause)?;
-            query.push_str(&format!(" WHERE {}", safe_clause));
+        if let Some(condition) = &condition {
+            query.push_str(&format!(" WHERE {}", condition.sql()));
         }
 
         debug!(query = %query, "Counting rows");
 
-        #[derive(QueryableByName)]
-        struct CountResult {
-            #[diesel(sql_type = BigInt)]
-            count: i64,
-        }
-
-        let result: CountResult = diesel::sql_query(&query)
-            .get_result(&mut *conn)
-            .map_err(|e| DatabaseError::new(DatabaseErrorKind::Query(e.to_string())))?;
-
-        Ok(result.count)
+        let count_column = ColumnInfo {
+            name: "count".to_string(),
+            data_type: "bigint".to_string(),
+            is_nullable: "NO".to_string(),
+            character_maximum_length: None,
+            column_default: None,
+        };
+        let params = condition
+            .as_ref()
+            .map(|c| c.params().as_slice())
+            .unwrap_or_default();
+        let rows = conn.load_json(&query, &[count_column], params)?;
+
+        rows.first()
+            .and_then(|row| row.get("count"))
+            .and_then(JsonValue::as_i64)
+            .ok_or_else(|| {
+                DatabaseError::new(DatabaseErrorKind::Query(
+                    "Count query returned no rows".to_string(),
+                ))
+            })
     }
 }
 
@@ -1,30 +1,42 @@
 //! Implementation of TableQueryRegistry for narrative integration.
 
-use crate::{TableQueryExecutor, format_as_csv, format_as_json, format_as_markdown};
+use crate::{
+    ContentConnection, TableQueryExecutor, format_as_csv, format_as_json, format_as_markdown,
+};
 use async_trait::async_trait;
 use botticelli_interface::TableQueryRegistry;
+use diesel::pg::PgConnection;
+use diesel::r2d2::{ConnectionManager, ManageConnection};
 use tracing::{debug, error, instrument};
 
 /// Implementation of TableQueryRegistry using TableQueryExecutor.
-pub struct DatabaseTableQueryRegistry {
-    executor: TableQueryExecutor,
+pub struct DatabaseTableQueryRegistry<M: ManageConnection = ConnectionManager<PgConnection>> {
+    executor: TableQueryExecutor<M>,
 }
 
-impl DatabaseTableQueryRegistry {
+impl<M> DatabaseTableQueryRegistry<M>
+where
+    M: ManageConnection,
+    M::Connection: ContentConnection,
+{
     /// Creates a new table query registry.
-    pub fn new(executor: TableQueryExecutor) -> Self {
+    pub fn new(executor: TableQueryExecutor<M>) -> Self {
         Self { executor }
     }
 }
 
 #[async_trait]
-impl TableQueryRegistry for DatabaseTableQueryRegistry {
+impl<M> TableQueryRegistry for DatabaseTableQueryRegistry<M>
+where
+    M: ManageConnection,
+    M::Connection: ContentConnection,
+{
     #[instrument(
         skip(self, query),
         fields(
             table_name = %query.table_name(),
             columns_count = query.columns().as_ref().map(|c| c.len()),
-            has_where = query.filter().is_some(),
+            has_where = query.filter().is_some() || query.conditions().is_some(),
             limit = query.limit(),
             offset = query.offset(),
             format = %query.format()
@@ -37,7 +49,7 @@ impl TableQueryRegistry for DatabaseTableQueryRegistry {
         debug!("Executing table query");
 
         // Execute query
-        let rows = self.executor.query_table(query).map_err(|e| {
+        let rows = self.executor.query_table(query).await.map_err(|e| {
             error!(error = %e, "Table query execution failed");
             Box::new(e) as Box<dyn std::error::Error + Send + Sync>
         })?;
@@ -64,7 +76,7 @@ impl TableQueryRegistry for DatabaseTableQueryRegistry {
         fields(
             table_name = %query.table_name(),
             columns_count = query.columns().as_ref().map(|c| c.len()),
-            has_where = query.filter().is_some(),
+            has_where = query.filter().is_some() || query.conditions().is_some(),
             limit = query.limit(),
             offset = query.offset(),
             format = %query.format()
@@ -77,10 +89,14 @@ impl TableQueryRegistry for DatabaseTableQueryRegistry {
         debug!("Executing destructive table query");
 
         // Execute query and delete
-        let rows = self.executor.query_and_delete_table(query).map_err(|e| {
-            error!(error = %e, "Destructive table query execution failed");
-            Box::new(e) as Box<dyn std::error::Error + Send + Sync>
-        })?;
+        let rows = self
+            .executor
+            .query_and_delete_table(query)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Destructive table query execution failed");
+                Box::new(e) as Box<dyn std::error::Error + Send + Sync>
+            })?;
 
         debug!(
             row_count = rows.len(),
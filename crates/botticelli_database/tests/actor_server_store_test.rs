@@ -0,0 +1,315 @@
+//! Tests for actor server state, execution history, leases and cross-post
+//! records on both backends.
+//!
+//! The PostgreSQL variants need `DATABASE_URL` pointing at a migrated
+//! PostgreSQL database; run them with `cargo test -p botticelli_database
+//! --test actor_server_store_test -- --ignored`. They work inside a
+//! transaction that is never committed.
+
+use botticelli_database::{
+    ActorCrossPostRecordRow, ActorServerStateRow, ActorStateChange, ActorStateFilter,
+    ContentConnection, acquire_actor_lease, complete_actor_execution, delete_actor_states,
+    delete_crosspost_records, fail_actor_execution, list_actor_executions, list_actor_leases,
+    list_actor_states, list_crosspost_records, prune_actor_executions, record_actor_last_run,
+    release_actor_leases, save_actor_state, save_crosspost_records, start_actor_execution,
+    update_actor_state,
+};
+use chrono::{Duration, NaiveDateTime, Utc};
+use serde_json::json;
+
+fn at(ts: &str) -> NaiveDateTime {
+    NaiveDateTime::parse_from_str(ts, "%Y-%m-%d %H:%M:%S%.f").unwrap()
+}
+
+fn state(task_id: &str, next_run: &str) -> ActorServerStateRow {
+    ActorServerStateRow {
+        task_id: task_id.to_string(),
+        actor_name: "poster".to_string(),
+        last_run: None,
+        next_run: at(next_run),
+        consecutive_failures: None,
+        is_paused: None,
+        metadata: Some(json!({"channel": "lore"})),
+        updated_at: Utc::now().naive_utc(),
+    }
+}
+
+fn check_task_state<C: ContentConnection>(conn: &mut C) {
+    save_actor_state(conn, &state("daily", "2025-11-28 12:00:00.250")).unwrap();
+    save_actor_state(conn, &state("weekly", "2025-12-01 09:00:00")).unwrap();
+
+    let daily = list_actor_states(conn, ActorStateFilter::Task("daily")).unwrap();
+    assert_eq!(daily.len(), 1);
+    assert_eq!(daily[0].next_run, at("2025-11-28 12:00:00.250"));
+    assert_eq!(daily[0].last_run, None);
+    assert_eq!(daily[0].consecutive_failures, Some(0));
+    assert_eq!(daily[0].is_paused, Some(false));
+    assert_eq!(daily[0].metadata, Some(json!({"channel": "lore"})));
+
+    // Saving again replaces the state
+    let mut replaced = state("daily", "2025-11-29 12:00:00");
+    replaced.last_run = Some(at("2025-11-28 12:00:01"));
+    save_actor_state(conn, &replaced).unwrap();
+    let daily = list_actor_states(conn, ActorStateFilter::Task("daily")).unwrap();
+    assert_eq!(daily[0].last_run, Some(at("2025-11-28 12:00:01")));
+    assert_eq!(daily[0].next_run, at("2025-11-29 12:00:00"));
+
+    assert_eq!(
+        update_actor_state(conn, "daily", ActorStateChange::Paused(true)).unwrap(),
+        1
+    );
+    let ids = |rows: Vec<ActorServerStateRow>| -> Vec<String> {
+        rows.into_iter().map(|row| row.task_id).collect()
+    };
+    assert_eq!(
+        ids(list_actor_states(conn, ActorStateFilter::Paused).unwrap()),
+        ["daily"]
+    );
+    assert_eq!(
+        ids(list_actor_states(conn, ActorStateFilter::Active).unwrap()),
+        ["weekly"]
+    );
+    assert_eq!(
+        ids(list_actor_states(conn, ActorStateFilter::Actor("poster")).unwrap()),
+        ["daily", "weekly"]
+    );
+
+    update_actor_state(conn, "weekly", ActorStateChange::IncrementFailures).unwrap();
+    update_actor_state(conn, "weekly", ActorStateChange::IncrementFailures).unwrap();
+    update_actor_state(
+        conn,
+        "weekly",
+        ActorStateChange::NextRun(at("2025-12-08 09:00:00")),
+    )
+    .unwrap();
+    let weekly = list_actor_states(conn, ActorStateFilter::Task("weekly")).unwrap();
+    assert_eq!(weekly[0].consecutive_failures, Some(2));
+    assert_eq!(weekly[0].next_run, at("2025-12-08 09:00:00"));
+    update_actor_state(conn, "weekly", ActorStateChange::ResetFailures).unwrap();
+    let weekly = list_actor_states(conn, ActorStateFilter::Task("weekly")).unwrap();
+    assert_eq!(weekly[0].consecutive_failures, Some(0));
+
+    assert_eq!(
+        update_actor_state(conn, "missing", ActorStateChange::Paused(true)).unwrap(),
+        0
+    );
+
+    // A first successful run creates the state
+    record_actor_last_run(conn, "hourly", "poster", at("2025-11-28 13:00:00")).unwrap();
+    record_actor_last_run(conn, "hourly", "poster", at("2025-11-28 14:00:00")).unwrap();
+    let hourly = list_actor_states(conn, ActorStateFilter::Task("hourly")).unwrap();
+    assert_eq!(hourly[0].last_run, Some(at("2025-11-28 14:00:00")));
+    assert_eq!(hourly[0].next_run, at("2025-11-28 13:00:00"));
+
+    assert_eq!(delete_actor_states(conn, Some("hourly")).unwrap(), 1);
+    assert_eq!(delete_actor_states(conn, None).unwrap(), 2);
+    assert!(
+        list_actor_states(conn, ActorStateFilter::All)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+fn check_executions<C: ContentConnection>(conn: &mut C) {
+    let first = start_actor_execution(conn, "daily", "poster").unwrap();
+    let second = start_actor_execution(conn, "daily", "poster").unwrap();
+    let other = start_actor_execution(conn, "weekly", "poster").unwrap();
+
+    complete_actor_execution(conn, first, 2, 0, 1, &json!({"posted": "42"})).unwrap();
+    fail_actor_execution(conn, second, "Discord returned 503").unwrap();
+
+    let history = list_actor_executions(conn, Some("daily"), false, 10).unwrap();
+    assert_eq!(
+        history.iter().map(|row| row.id).collect::<Vec<_>>(),
+        [second, first]
+    );
+    assert_eq!(history[1].success, Some(true));
+    assert_eq!(history[1].skills_succeeded, Some(2));
+    assert_eq!(history[1].skills_skipped, Some(1));
+    assert_eq!(history[1].metadata, Some(json!({"posted": "42"})));
+    assert!(history[1].completed_at.is_some());
+
+    let failed = list_actor_executions(conn, Some("daily"), true, 10).unwrap();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(
+        failed[0].error_message.as_deref(),
+        Some("Discord returned 503")
+    );
+
+    assert_eq!(
+        list_actor_executions(conn, None, false, 2).unwrap().len(),
+        2
+    );
+    assert_eq!(
+        list_actor_executions(conn, None, false, 10).unwrap()[0].id,
+        other
+    );
+
+    assert_eq!(
+        prune_actor_executions(conn, (Utc::now() - Duration::days(1)).naive_utc()).unwrap(),
+        0
+    );
+    assert_eq!(
+        prune_actor_executions(conn, (Utc::now() + Duration::seconds(1)).naive_utc()).unwrap(),
+        3
+    );
+}
+
+fn check_leases<C: ContentConnection>(conn: &mut C) {
+    let lease = acquire_actor_lease(conn, "daily", "replica-a", 30.0).unwrap();
+    assert_eq!(lease.owner, "replica-a");
+    assert!(lease.expires_at > lease.acquired_at);
+
+    // A live lease stays with its owner
+    let lease = acquire_actor_lease(conn, "daily", "replica-b", 30.0).unwrap();
+    assert_eq!(lease.owner, "replica-a");
+
+    // Renewing keeps the acquisition time and extends expiry
+    let renewed = acquire_actor_lease(conn, "daily", "replica-a", 60.0).unwrap();
+    assert_eq!(renewed.owner, "replica-a");
+    assert_eq!(renewed.acquired_at, lease.acquired_at);
+    assert!(renewed.expires_at > lease.expires_at);
+
+    // An expired lease is taken over
+    acquire_actor_lease(conn, "weekly", "replica-a", -1.0).unwrap();
+    let taken = acquire_actor_lease(conn, "weekly", "replica-b", 30.0).unwrap();
+    assert_eq!(taken.owner, "replica-b");
+
+    let leases = list_actor_leases(conn).unwrap();
+    assert_eq!(
+        leases
+            .iter()
+            .map(|lease| (lease.task_id.as_str(), lease.owner.as_str()))
+            .collect::<Vec<_>>(),
+        [("daily", "replica-a"), ("weekly", "replica-b")]
+    );
+
+    assert_eq!(
+        release_actor_leases(conn, "replica-a", Some("weekly")).unwrap(),
+        0
+    );
+    assert_eq!(release_actor_leases(conn, "replica-b", None).unwrap(), 1);
+    let lease = acquire_actor_lease(conn, "weekly", "replica-a", 30.0).unwrap();
+    assert_eq!(lease.owner, "replica-a");
+}
+
+fn check_crosspost_records<C: ContentConnection>(conn: &mut C) {
+    let record = |content_id: &str, deliveries: serde_json::Value| ActorCrossPostRecordRow {
+        actor_name: "poster".to_string(),
+        content_id: content_id.to_string(),
+        deliveries,
+        updated_at: at("2025-11-28 12:00:00"),
+    };
+
+    let pruned = save_crosspost_records(
+        conn,
+        "poster",
+        &[
+            record("1", json!({"discord": {"status": "delivered"}})),
+            record("2", json!({"bluesky": {"status": "failed"}})),
+        ],
+    )
+    .unwrap();
+    assert_eq!(pruned, 0);
+    save_crosspost_records(conn, "other", &[record("1", json!({}))]).unwrap();
+
+    // Saving replaces the actor's records and prunes the rest
+    let pruned = save_crosspost_records(
+        conn,
+        "poster",
+        &[record("2", json!({"bluesky": {"status": "delivered"}}))],
+    )
+    .unwrap();
+    assert_eq!(pruned, 1);
+
+    let records = list_crosspost_records(conn, "poster").unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].content_id, "2");
+    assert_eq!(
+        records[0].deliveries,
+        json!({"bluesky": {"status": "delivered"}})
+    );
+    assert_eq!(records[0].updated_at, at("2025-11-28 12:00:00"));
+
+    assert_eq!(save_crosspost_records(conn, "poster", &[]).unwrap(), 1);
+    assert!(list_crosspost_records(conn, "poster").unwrap().is_empty());
+    assert_eq!(delete_crosspost_records(conn, "other").unwrap(), 1);
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use super::*;
+    use botticelli_database::establish_sqlite_connection;
+    use diesel::sqlite::SqliteConnection;
+
+    fn connect() -> SqliteConnection {
+        establish_sqlite_connection(":memory:").expect("in-memory database should open")
+    }
+
+    #[test]
+    fn test_task_state() {
+        check_task_state(&mut connect());
+    }
+
+    #[test]
+    fn test_executions() {
+        check_executions(&mut connect());
+    }
+
+    #[test]
+    fn test_leases() {
+        check_leases(&mut connect());
+    }
+
+    #[test]
+    fn test_crosspost_records() {
+        check_crosspost_records(&mut connect());
+    }
+}
+
+mod postgres {
+    use super::*;
+    use botticelli_database::establish_connection;
+    use diesel::Connection;
+    use diesel::pg::PgConnection;
+
+    /// Connect inside an uncommitted transaction, with the tables emptied.
+    fn connect() -> PgConnection {
+        let mut conn = establish_connection().expect("DATABASE_URL should name PostgreSQL");
+        conn.begin_test_transaction().unwrap();
+        for table in [
+            "actor_server_state",
+            "actor_server_executions",
+            "actor_server_leases",
+            "actor_crosspost_records",
+        ] {
+            conn.execute_sql(&format!("DELETE FROM {}", table), &[])
+                .unwrap();
+        }
+        conn
+    }
+
+    #[test]
+    #[ignore = "Requires DATABASE_URL pointing at PostgreSQL"]
+    fn test_task_state() {
+        check_task_state(&mut connect());
+    }
+
+    #[test]
+    #[ignore = "Requires DATABASE_URL pointing at PostgreSQL"]
+    fn test_executions() {
+        check_executions(&mut connect());
+    }
+
+    #[test]
+    #[ignore = "Requires DATABASE_URL pointing at PostgreSQL"]
+    fn test_leases() {
+        check_leases(&mut connect());
+    }
+
+    #[test]
+    #[ignore = "Requires DATABASE_URL pointing at PostgreSQL"]
+    fn test_crosspost_records() {
+        check_crosspost_records(&mut connect());
+    }
+}
@@ -0,0 +1,29 @@
+//! Tests for PostgreSQL-only connection helpers.
+
+use botticelli_database::{create_pool, establish_connection, postgres_database_url};
+
+#[test]
+fn test_postgres_helpers_reject_sqlite_urls() {
+    // Only test in this binary, so nothing else reads DATABASE_URL concurrently
+    unsafe { std::env::set_var("DATABASE_URL", "botticelli.db") };
+
+    let err = postgres_database_url().unwrap_err();
+    assert!(err.to_string().contains("needs PostgreSQL"));
+    assert!(
+        create_pool()
+            .unwrap_err()
+            .to_string()
+            .contains("needs PostgreSQL")
+    );
+    let Err(err) = establish_connection() else {
+        panic!("SQLite URL accepted");
+    };
+    assert!(err.to_string().contains("needs PostgreSQL"));
+    assert!(!std::path::Path::new("botticelli.db").exists());
+
+    unsafe { std::env::set_var("DATABASE_URL", "postgres://localhost/botticelli") };
+    assert_eq!(
+        postgres_database_url().unwrap(),
+        "postgres://localhost/botticelli"
+    );
+}
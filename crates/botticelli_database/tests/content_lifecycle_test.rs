@@ -0,0 +1,148 @@
+//! Tests for the content lifecycle and its status history.
+
+#![cfg(feature = "sqlite")]
+
+use botticelli_database::{
+    ContentConnection, ContentStatus, content_status, content_status_history, create_content_table,
+    establish_sqlite_connection, transition_content_status, update_review_status,
+};
+use botticelli_error::{BotticelliErrorKind, DatabaseErrorKind};
+use diesel::sqlite::SqliteConnection;
+
+/// Open an in-memory database with a `guild_ideas` content table holding one row.
+fn connect() -> SqliteConnection {
+    let mut conn = establish_sqlite_connection(":memory:").expect("in-memory database should open");
+
+    create_content_table(&mut conn, "guild_ideas", "discord_guilds", None, None).unwrap();
+    conn.execute_sql(
+        "INSERT INTO guild_ideas (id, name, owner_id) VALUES (1, 'Lore Lounge', 42)",
+        &[],
+    )
+    .unwrap();
+
+    conn
+}
+
+#[test]
+fn test_lifecycle_rules() {
+    use ContentStatus::*;
+
+    assert!(Generated.can_transition_to(PendingReview));
+    assert!(PendingReview.can_transition_to(Approved));
+    assert!(Approved.can_transition_to(Scheduled));
+    assert!(Scheduled.can_transition_to(Posted));
+    assert!(Scheduled.can_transition_to(Approved));
+    assert!(Posted.can_transition_to(Archived));
+
+    assert!(!Generated.can_transition_to(Posted));
+    assert!(!Rejected.can_transition_to(Scheduled));
+    assert!(!Posted.can_transition_to(Approved));
+    assert!(Archived.next_statuses().is_empty());
+
+    for status in ContentStatus::ALL {
+        assert_eq!(status.to_string().parse::<ContentStatus>().unwrap(), status);
+    }
+    assert_eq!("pending".parse::<ContentStatus>().unwrap(), PendingReview);
+    assert!("published".parse::<ContentStatus>().is_err());
+}
+
+#[test]
+fn test_transitions_record_history() {
+    let mut conn = connect();
+    assert_eq!(
+        content_status(&mut conn, "guild_ideas", 1).unwrap(),
+        ContentStatus::Generated
+    );
+
+    let steps = [
+        (ContentStatus::PendingReview, "generation_bot", None),
+        (
+            ContentStatus::Approved,
+            "alice",
+            Some("Fits the lore channel"),
+        ),
+        (
+            ContentStatus::Scheduled,
+            "posting_bot",
+            Some("Picked as next post"),
+        ),
+        (ContentStatus::Posted, "posting_bot", None),
+    ];
+    for (status, actor, reason) in steps {
+        transition_content_status(&mut conn, "guild_ideas", 1, status, actor, reason).unwrap();
+    }
+
+    assert_eq!(
+        content_status(&mut conn, "guild_ideas", 1).unwrap(),
+        ContentStatus::Posted
+    );
+
+    let history = content_status_history(&mut conn, "guild_ideas", 1).unwrap();
+    assert_eq!(history.len(), 4);
+    assert_eq!(history[0].from_status(), &ContentStatus::Generated);
+    assert_eq!(history[1].actor(), "alice");
+    assert_eq!(
+        history[1].reason().as_deref(),
+        Some("Fits the lore channel")
+    );
+    assert_eq!(history[3].from_status(), &ContentStatus::Scheduled);
+    assert_eq!(history[3].to_status(), &ContentStatus::Posted);
+    assert!(history[3].reason().is_none());
+    assert!(history.iter().all(|change| *change.content_id() == 1));
+}
+
+#[test]
+fn test_invalid_transition_is_rejected() {
+    let mut conn = connect();
+
+    let err = transition_content_status(
+        &mut conn,
+        "guild_ideas",
+        1,
+        ContentStatus::Posted,
+        "posting_bot",
+        None,
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        BotticelliErrorKind::Database(db) if matches!(db.kind, DatabaseErrorKind::InvalidTransition { .. })
+    ));
+
+    assert_eq!(
+        content_status(&mut conn, "guild_ideas", 1).unwrap(),
+        ContentStatus::Generated
+    );
+    assert!(
+        content_status_history(&mut conn, "guild_ideas", 1)
+            .unwrap()
+            .is_empty()
+    );
+
+    assert!(update_review_status(&mut conn, "guild_ideas", 1, "published", "tui").is_err());
+}
+
+#[test]
+fn test_legacy_and_repeated_statuses() {
+    let mut conn = connect();
+
+    // Rows from before the lifecycle still read 'pending'
+    conn.execute_sql(
+        "UPDATE guild_ideas SET review_status = 'pending' WHERE id = 1",
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        content_status(&mut conn, "guild_ideas", 1).unwrap(),
+        ContentStatus::PendingReview
+    );
+
+    update_review_status(&mut conn, "guild_ideas", 1, "approved", "tui").unwrap();
+    // Re-applying the current status changes nothing
+    update_review_status(&mut conn, "guild_ideas", 1, "approved", "tui").unwrap();
+
+    let history = content_status_history(&mut conn, "guild_ideas", 1).unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].from_status(), &ContentStatus::PendingReview);
+    assert_eq!(history[0].actor(), "tui");
+}
@@ -0,0 +1,94 @@
+//! Tests for filtered, searched and paginated content queries.
+
+#![cfg(feature = "sqlite")]
+
+use botticelli_core::{FilterCondition, FilterOperators, FilterValue, TableFilter};
+use botticelli_database::{
+    ContentConnection, ContentQueryBuilder, create_content_table, establish_sqlite_connection,
+    query_content,
+};
+use diesel::sqlite::SqliteConnection;
+
+/// Open an in-memory database with five `guild_ideas` rows.
+fn connect() -> SqliteConnection {
+    let mut conn = establish_sqlite_connection(":memory:").expect("in-memory database should open");
+
+    create_content_table(&mut conn, "guild_ideas", "discord_guilds", None, None).unwrap();
+    conn.execute_sql(
+        r#"INSERT INTO guild_ideas (id, name, owner_id, description, review_status, rating, tags, source_narrative, generated_at) VALUES
+           (1, 'Lore Lounge', 1, 'Stories of the realm', 'approved', 5, '["lore"]', 'guilds', '2025-01-01 09:00:00'),
+           (2, 'Pixel Forge', 1, 'Art and LORE swaps', 'approved', 3, '["art"]', 'guilds', '2025-01-02 09:00:00'),
+           (3, 'Code Cave', 1, 'Rust help', 'generated', NULL, NULL, 'guilds', '2025-01-03 09:00:00'),
+           (4, 'Quest Board', 1, 'Weekly lore quests', 'rejected', 2, '["lore", "quests"]', 'quests', '2025-01-04 09:00:00'),
+           (5, 'Tea Room', 1, 'Chat', 'approved', 4, '["lore"]', 'guilds', '2025-01-05 09:00:00')"#,
+        &[],
+    )
+    .unwrap();
+
+    conn
+}
+
+fn ids(rows: &[serde_json::Value]) -> Vec<i64> {
+    rows.iter().map(|row| row["id"].as_i64().unwrap()).collect()
+}
+
+#[test]
+fn test_search_is_case_insensitive() {
+    let mut conn = connect();
+
+    let query = ContentQueryBuilder::default()
+        .search("lore")
+        .build()
+        .unwrap();
+    let page = query_content(&mut conn, "guild_ideas", &query).unwrap();
+
+    // Newest first by default; tags are searched too
+    assert_eq!(ids(page.rows()), vec![5, 4, 2, 1]);
+    assert_eq!(*page.total(), 4);
+}
+
+#[test]
+fn test_filters_sorting_and_pages() {
+    let mut conn = connect();
+
+    let filter = TableFilter::new()
+        .with("review_status", FilterCondition::Equals("approved".into()))
+        .with(
+            "rating",
+            FilterCondition::Operators(Box::new(FilterOperators {
+                gte: Some(FilterValue::Integer(3)),
+                ..Default::default()
+            })),
+        )
+        .with(
+            "generated_at",
+            FilterCondition::Operators(Box::new(FilterOperators {
+                lte: Some("2025-01-05 00:00:00".into()),
+                ..Default::default()
+            })),
+        );
+    let query = ContentQueryBuilder::default()
+        .filter(filter.clone())
+        .sort_by("rating")
+        .descending(true)
+        .build()
+        .unwrap();
+    let page = query_content(&mut conn, "guild_ideas", &query).unwrap();
+    assert_eq!(ids(page.rows()), vec![1, 2]);
+
+    let second_page = ContentQueryBuilder::default()
+        .sort_by("name")
+        .offset(2)
+        .limit(2)
+        .build()
+        .unwrap();
+    let page = query_content(&mut conn, "guild_ideas", &second_page).unwrap();
+    assert_eq!(ids(page.rows()), vec![2, 4]);
+    assert_eq!(*page.total(), 5);
+
+    let unknown = ContentQueryBuilder::default()
+        .sort_by("popularity")
+        .build()
+        .unwrap();
+    assert!(query_content(&mut conn, "guild_ideas", &unknown).is_err());
+}
@@ -0,0 +1,156 @@
+//! Tests for content revision history and reverts.
+
+#![cfg(feature = "sqlite")]
+
+use botticelli_database::{
+    ContentConnection, ContentStatus, content_revisions, create_content_table, edit_content,
+    establish_sqlite_connection, get_content_by_id, insert_content, revert_content,
+    transition_content_status, update_content_metadata,
+};
+use diesel::sqlite::SqliteConnection;
+use serde_json::json;
+
+/// Open an in-memory database with one model-generated `guild_ideas` row.
+fn connect() -> SqliteConnection {
+    let mut conn = establish_sqlite_connection(":memory:").expect("in-memory database should open");
+
+    create_content_table(&mut conn, "guild_ideas", "discord_guilds", None, None).unwrap();
+    conn.execute_sql(
+        "INSERT INTO guild_ideas (id, name, owner_id, description, generation_model)
+         VALUES (1, 'Lore Lounge', 42, 'A place for lore', 'gemini-2.5-flash')",
+        &[],
+    )
+    .unwrap();
+
+    conn
+}
+
+fn changes(value: serde_json::Value) -> serde_json::Map<String, serde_json::Value> {
+    value.as_object().unwrap().clone()
+}
+
+#[test]
+fn test_edits_keep_original_output() {
+    let mut conn = connect();
+
+    let revision = edit_content(
+        &mut conn,
+        "guild_ideas",
+        1,
+        &changes(json!({"description": "A place for lore and art"})),
+        "alice",
+    )
+    .unwrap();
+    assert_eq!(revision, Some(2));
+
+    update_content_metadata(
+        &mut conn,
+        "guild_ideas",
+        1,
+        Some(&["keeper".to_string()]),
+        Some(4),
+        "bob",
+    )
+    .unwrap();
+
+    let revisions = content_revisions(&mut conn, "guild_ideas", 1).unwrap();
+    assert_eq!(revisions.len(), 3);
+
+    let original = &revisions[0];
+    assert_eq!(*original.revision(), 1);
+    assert_eq!(original.editor(), "gemini-2.5-flash");
+    assert_eq!(original.content()["description"], "A place for lore");
+    assert!(original.changed_fields().is_empty());
+
+    let edit = &revisions[1];
+    assert_eq!(edit.editor(), "alice");
+    assert_eq!(
+        edit.diff()["description"],
+        json!({"old": "A place for lore", "new": "A place for lore and art"})
+    );
+
+    let mut metadata = revisions[2].changed_fields();
+    metadata.sort();
+    assert_eq!(metadata, vec!["rating", "tags"]);
+    assert_eq!(revisions[2].editor(), "bob");
+}
+
+#[test]
+fn test_revert_restores_revision() {
+    let mut conn = connect();
+
+    edit_content(
+        &mut conn,
+        "guild_ideas",
+        1,
+        &changes(json!({"name": "Lore Lounge!!", "description": null})),
+        "curate_and_approve",
+    )
+    .unwrap();
+
+    let revision = revert_content(&mut conn, "guild_ideas", 1, 1, "alice").unwrap();
+    assert_eq!(revision, Some(3));
+
+    let row = get_content_by_id(&mut conn, "guild_ideas", 1).unwrap();
+    assert_eq!(row["name"], "Lore Lounge");
+    assert_eq!(row["description"], "A place for lore");
+
+    // Reverting to the content it already has records nothing
+    assert_eq!(
+        revert_content(&mut conn, "guild_ideas", 1, 1, "alice").unwrap(),
+        None
+    );
+    assert!(revert_content(&mut conn, "guild_ideas", 1, 9, "alice").is_err());
+}
+
+#[test]
+fn test_unversioned_columns() {
+    let mut conn = connect();
+
+    // Status changes have their own history and never create revisions
+    transition_content_status(
+        &mut conn,
+        "guild_ideas",
+        1,
+        ContentStatus::Approved,
+        "alice",
+        None,
+    )
+    .unwrap();
+    assert!(
+        content_revisions(&mut conn, "guild_ideas", 1)
+            .unwrap()
+            .is_empty()
+    );
+
+    for column in ["id", "review_status", "missing"] {
+        let edit = changes(json!({ column: "x" }));
+        assert!(edit_content(&mut conn, "guild_ideas", 1, &edit, "alice").is_err());
+    }
+}
+
+#[test]
+fn test_hand_written_content() {
+    let mut conn = connect();
+
+    let id = insert_content(
+        &mut conn,
+        "guild_ideas",
+        &changes(json!({"name": "Typo Hunters", "owner_id": 7})),
+        "alice",
+    )
+    .unwrap();
+
+    let row = get_content_by_id(&mut conn, "guild_ideas", id).unwrap();
+    assert_eq!(row["name"], "Typo Hunters");
+    assert_eq!(row["review_status"], "generated");
+
+    // The author, not a model, is credited with the original
+    let revisions = content_revisions(&mut conn, "guild_ideas", id).unwrap();
+    assert_eq!(revisions.len(), 1);
+    assert_eq!(revisions[0].editor(), "alice");
+
+    for values in [json!({}), json!({"review_status": "approved"})] {
+        assert!(insert_content(&mut conn, "guild_ideas", &changes(values), "alice").is_err());
+    }
+}
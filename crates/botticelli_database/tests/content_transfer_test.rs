@@ -0,0 +1,219 @@
+//! Tests for exporting and importing content tables.
+
+#![cfg(feature = "sqlite")]
+
+use botticelli_database::{
+    ContentConnection, ContentFormat, ContentStatus, create_content_table,
+    establish_sqlite_connection, export_content, get_content_by_id, import_content, list_content,
+    transition_content_status,
+};
+use diesel::sqlite::SqliteConnection;
+
+/// Open an in-memory database with two curated `guild_ideas` rows.
+fn connect() -> SqliteConnection {
+    let mut conn = establish_sqlite_connection(":memory:").expect("in-memory database should open");
+
+    create_content_table(&mut conn, "guild_ideas", "discord_guilds", None, None).unwrap();
+    conn.execute_sql(
+        r#"INSERT INTO guild_ideas (id, name, owner_id, description, review_status, tags, rating) VALUES
+           (1, 'Lore Lounge', 42, 'A place for lore', 'approved', '["lore","cozy"]', 5),
+           (2, 'Pixel Guild', 7, 'It''s for "pixel" art', 'generated', NULL, NULL)"#,
+        &[],
+    )
+    .unwrap();
+
+    conn
+}
+
+fn export(conn: &mut SqliteConnection, table: &str, format: ContentFormat) -> Vec<u8> {
+    let mut data = Vec::new();
+    export_content(conn, table, format, &mut data).unwrap();
+    data
+}
+
+/// Names, statuses and tags of a table's rows, in `id` order.
+fn summarize(conn: &mut SqliteConnection, table: &str) -> Vec<(i64, String, String, String)> {
+    let mut rows = list_content(conn, table, None, 100).unwrap();
+    rows.sort_by_key(|row| row["id"].as_i64());
+    rows.iter()
+        .map(|row| {
+            (
+                row["id"].as_i64().unwrap(),
+                row["name"].as_str().unwrap().to_string(),
+                row["review_status"].as_str().unwrap().to_string(),
+                row["tags"].to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_jsonl_import_creates_table_and_upserts_by_content() {
+    let mut conn = connect();
+    let data = export(&mut conn, "guild_ideas", ContentFormat::Jsonl);
+    assert_eq!(String::from_utf8_lossy(&data).lines().count(), 2);
+
+    let summary = import_content(&mut conn, "guild_copy", ContentFormat::Jsonl, &data[..]).unwrap();
+    assert!(*summary.created_table());
+    assert_eq!(*summary.inserted(), 2);
+    assert_eq!(
+        summarize(&mut conn, "guild_copy"),
+        summarize(&mut conn, "guild_ideas")
+    );
+    let copy = get_content_by_id(&mut conn, "guild_copy", 2).unwrap();
+    assert_eq!(copy["description"], "It's for \"pixel\" art");
+    assert_eq!(copy["owner_id"], 7);
+
+    // Importing the same rows again changes nothing
+    let summary = import_content(&mut conn, "guild_copy", ContentFormat::Jsonl, &data[..]).unwrap();
+    assert!(!*summary.created_table());
+    assert_eq!((*summary.inserted(), *summary.unchanged()), (0, 2));
+
+    // Curation done in the source is carried over without duplicating rows
+    transition_content_status(
+        &mut conn,
+        "guild_ideas",
+        2,
+        ContentStatus::Rejected,
+        "alice",
+        None,
+    )
+    .unwrap();
+    let data = export(&mut conn, "guild_ideas", ContentFormat::Jsonl);
+    let summary = import_content(&mut conn, "guild_copy", ContentFormat::Jsonl, &data[..]).unwrap();
+    assert_eq!(
+        (
+            *summary.inserted(),
+            *summary.updated(),
+            *summary.unchanged()
+        ),
+        (0, 1, 1)
+    );
+    assert_eq!(summarize(&mut conn, "guild_copy")[1].2, "rejected");
+}
+
+#[test]
+fn test_csv_round_trip_into_existing_and_new_tables() {
+    let mut conn = connect();
+    let data = export(&mut conn, "guild_ideas", ContentFormat::Csv);
+
+    let summary = import_content(&mut conn, "guild_ideas", ContentFormat::Csv, &data[..]).unwrap();
+    assert_eq!((*summary.inserted(), *summary.unchanged()), (0, 2));
+
+    // New content gets the next free id
+    let new_row = "name,owner_id,description,tags\nMeme Vault,9,Memes only,\"[\"\"memes\"\"]\"\n";
+    let summary = import_content(
+        &mut conn,
+        "guild_ideas",
+        ContentFormat::Csv,
+        new_row.as_bytes(),
+    )
+    .unwrap();
+    assert_eq!(*summary.inserted(), 1);
+    let rows = summarize(&mut conn, "guild_ideas");
+    assert_eq!(
+        rows[2],
+        (
+            3,
+            "Meme Vault".to_string(),
+            "generated".to_string(),
+            r#"["memes"]"#.to_string()
+        )
+    );
+
+    // Types of a missing table are guessed from the cells
+    let summary = import_content(&mut conn, "guild_csv", ContentFormat::Csv, &data[..]).unwrap();
+    assert!(*summary.created_table());
+    assert_eq!(
+        summarize(&mut conn, "guild_csv"),
+        summarize(&mut conn, "guild_ideas")[..2]
+    );
+    let owner = conn
+        .table_columns("guild_csv")
+        .unwrap()
+        .into_iter()
+        .find(|column| column.name == "owner_id")
+        .unwrap();
+    assert_eq!(owner.data_type, "bigint");
+}
+
+#[test]
+fn test_sparse_rows_keep_their_types() {
+    let mut conn = connect();
+    let data = r#"{"id": 1, "title": "Hello", "score": 3}
+{"id": 2, "title": "World", "score": null}
+{"id": 3, "title": "Again"}
+"#;
+
+    let summary =
+        import_content(&mut conn, "posts", ContentFormat::Jsonl, data.as_bytes()).unwrap();
+    assert_eq!(*summary.inserted(), 3);
+    let score = conn
+        .table_columns("posts")
+        .unwrap()
+        .into_iter()
+        .find(|column| column.name == "score")
+        .unwrap();
+    assert_eq!(score.data_type, "bigint");
+
+    let summary =
+        import_content(&mut conn, "posts", ContentFormat::Jsonl, data.as_bytes()).unwrap();
+    assert_eq!((*summary.inserted(), *summary.unchanged()), (0, 3));
+}
+
+#[test]
+fn test_import_rejects_bad_rows() {
+    let mut conn = connect();
+
+    let bad_status = r#"{"name": "Odd", "owner_id": 1, "review_status": "published"}"#;
+    assert!(
+        import_content(
+            &mut conn,
+            "guild_ideas",
+            ContentFormat::Jsonl,
+            bad_status.as_bytes()
+        )
+        .is_err()
+    );
+    assert!(
+        import_content(
+            &mut conn,
+            "guild_new",
+            ContentFormat::Jsonl,
+            "[1, 2]".as_bytes()
+        )
+        .is_err()
+    );
+    assert!(
+        import_content(&mut conn, "guild_new", ContentFormat::Jsonl, "".as_bytes()).is_err(),
+        "a missing table can't be created without rows"
+    );
+    assert!(!conn.table_exists("guild_new").unwrap());
+    assert_eq!(summarize(&mut conn, "guild_ideas").len(), 2);
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn test_parquet_round_trip() {
+    let mut conn = connect();
+    let data = export(&mut conn, "guild_ideas", ContentFormat::Parquet);
+    assert!(data.starts_with(b"PAR1"));
+
+    let summary = import_content(
+        &mut conn,
+        "guild_parquet",
+        ContentFormat::Parquet,
+        &data[..],
+    )
+    .unwrap();
+    assert!(*summary.created_table());
+    assert_eq!(*summary.inserted(), 2);
+    assert_eq!(
+        summarize(&mut conn, "guild_parquet"),
+        summarize(&mut conn, "guild_ideas")
+    );
+
+    let summary =
+        import_content(&mut conn, "guild_ideas", ContentFormat::Parquet, &data[..]).unwrap();
+    assert_eq!(*summary.unchanged(), 2);
+}
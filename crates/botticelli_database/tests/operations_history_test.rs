@@ -0,0 +1,123 @@
+//! Tests for the narrative execution history and actor task state read by
+//! the operator console.
+
+#![cfg(feature = "sqlite")]
+
+use botticelli_database::{
+    ContentConnection, establish_sqlite_connection, list_actor_tasks, list_narrative_executions,
+    model_usage, narrative_execution_acts, set_actor_task_paused,
+};
+use diesel::sqlite::SqliteConnection;
+
+/// Open an in-memory database with two recorded executions.
+///
+/// Execution 2 ran two acts on `gemini-2.5-flash` just now; execution 1
+/// ran one act two days ago, outside every usage window.
+fn connect() -> SqliteConnection {
+    let mut conn = establish_sqlite_connection(":memory:").expect("in-memory database should open");
+
+    conn.execute_sql(
+        r#"INSERT INTO narrative_executions (id, narrative_name, status, started_at, completed_at, error_message) VALUES
+           (1, 'guilds', 'failed', datetime('now', '-2 days'), datetime('now', '-2 days'), 'quota exhausted'),
+           (2, 'guilds', 'completed', datetime('now', '-10 seconds'), datetime('now'), NULL)"#,
+        &[],
+    )
+    .unwrap();
+    conn.execute_sql(
+        r#"INSERT INTO act_executions (id, execution_id, act_name, sequence_number, model, response, created_at) VALUES
+           (1, 1, 'draft', 0, NULL, 'old', datetime('now', '-2 days')),
+           (2, 2, 'draft', 0, 'gemini-2.5-flash', '12345678', datetime('now')),
+           (3, 2, 'critique', 1, 'gemini-2.5-flash', '1234', datetime('now'))"#,
+        &[],
+    )
+    .unwrap();
+    conn.execute_sql(
+        r#"INSERT INTO act_inputs (act_execution_id, input_order, input_type, text_content, mime_type, filename) VALUES
+           (2, 0, 'text', 'Write a guild', NULL, NULL),
+           (2, 1, 'image', NULL, 'image/png', 'logo.png'),
+           (3, 0, 'text', 'Critique it', NULL, NULL)"#,
+        &[],
+    )
+    .unwrap();
+
+    conn
+}
+
+#[test]
+fn test_executions_and_acts() {
+    let mut conn = connect();
+
+    let executions = list_narrative_executions(&mut conn, 10).unwrap();
+    assert_eq!(
+        executions.iter().map(|e| *e.id()).collect::<Vec<_>>(),
+        vec![2, 1]
+    );
+    assert_eq!(*executions[0].act_count(), 2);
+    assert_eq!(
+        executions[1].error_message().as_deref(),
+        Some("quota exhausted")
+    );
+
+    let acts = narrative_execution_acts(&mut conn, 2).unwrap();
+    assert_eq!(
+        acts.iter()
+            .map(|a| a.act_name().as_str())
+            .collect::<Vec<_>>(),
+        vec!["draft", "critique"]
+    );
+    assert_eq!(acts[0].inputs().len(), 2);
+    assert_eq!(acts[0].inputs()[1].filename().as_deref(), Some("logo.png"));
+    assert_eq!(
+        acts[1].inputs()[0].text_content().as_deref(),
+        Some("Critique it")
+    );
+
+    assert!(narrative_execution_acts(&mut conn, 99).is_err());
+}
+
+#[test]
+fn test_model_usage_windows() {
+    let mut conn = connect();
+
+    let usage = model_usage(&mut conn).unwrap();
+    assert_eq!(usage.len(), 1, "acts older than a day are left out");
+    let flash = &usage[0];
+    assert_eq!(flash.model().as_deref(), Some("gemini-2.5-flash"));
+    assert_eq!(*flash.requests_last_minute(), 2);
+    assert_eq!(*flash.requests_last_day(), 2);
+    // (8 + 13 + 4 + 11) characters at four per token
+    assert_eq!(*flash.tokens_last_minute(), 9);
+    assert_eq!(*flash.tokens_last_day(), 9);
+}
+
+#[test]
+fn test_pause_and_resume_tasks() {
+    let mut conn = connect();
+    conn.execute_sql(
+        r#"INSERT INTO actor_server_state (task_id, actor_name, last_run, next_run, consecutive_failures, is_paused) VALUES
+           ('post', 'poster', '2025-01-01 09:00:00', '2025-01-01 10:00:00', 2, FALSE),
+           ('curate', 'curator', NULL, '2025-01-01 09:30:00', 0, TRUE)"#,
+        &[],
+    )
+    .unwrap();
+
+    let tasks = list_actor_tasks(&mut conn).unwrap();
+    assert_eq!(
+        tasks
+            .iter()
+            .map(|t| t.task_id().as_str())
+            .collect::<Vec<_>>(),
+        vec!["curate", "post"]
+    );
+    assert!(*tasks[0].is_paused());
+    assert_eq!(*tasks[1].consecutive_failures(), 2);
+    assert!(tasks[1].last_run().is_some());
+
+    set_actor_task_paused(&mut conn, "post", true).unwrap();
+    set_actor_task_paused(&mut conn, "curate", false).unwrap();
+    let tasks = list_actor_tasks(&mut conn).unwrap();
+    assert!(!*tasks[0].is_paused());
+    assert!(*tasks[1].is_paused());
+
+    assert!(set_actor_task_paused(&mut conn, "missing", true).is_err());
+}
@@ -0,0 +1,164 @@
+//! Content editing against PostgreSQL.
+//!
+//! These need `DATABASE_URL` pointing at a migrated PostgreSQL database; run
+//! them with `cargo test -p botticelli_database --test postgres_content_test
+//! -- --ignored`. Each test works inside a transaction that is never
+//! committed, so the database is left as it was.
+
+use botticelli_database::{
+    ContentConnection, ContentFormat, content_revisions, create_content_table, edit_content,
+    establish_connection, export_content, get_content_by_id, import_content, insert_content,
+    revert_content, update_content_metadata,
+};
+use diesel::Connection;
+use diesel::pg::PgConnection;
+use serde_json::json;
+
+/// Connect with an uncommitted transaction and one `pg_guild_ideas` row.
+fn connect() -> PgConnection {
+    let mut conn = establish_connection().expect("DATABASE_URL should name PostgreSQL");
+    conn.begin_test_transaction().unwrap();
+
+    create_content_table(&mut conn, "pg_guild_ideas", "discord_guilds", None, None).unwrap();
+    conn.execute_sql(
+        "INSERT INTO pg_guild_ideas (id, name, owner_id, description, generation_model)
+         VALUES (1, 'Lore Lounge', 42, 'A place for lore', 'gemini-2.5-flash')",
+        &[],
+    )
+    .unwrap();
+
+    conn
+}
+
+fn changes(value: serde_json::Value) -> serde_json::Map<String, serde_json::Value> {
+    value.as_object().unwrap().clone()
+}
+
+#[test]
+#[ignore = "Requires DATABASE_URL pointing at PostgreSQL"]
+fn test_edit_array_and_typed_columns() {
+    let mut conn = connect();
+
+    let revision = edit_content(
+        &mut conn,
+        "pg_guild_ideas",
+        1,
+        &changes(json!({
+            "tags": ["keeper", "it's lore"],
+            "generated_at": "2025-11-28T12:00:00Z",
+            "description": "Robert'); DROP TABLE pg_guild_ideas; --",
+        })),
+        "alice",
+    )
+    .unwrap();
+    assert_eq!(revision, Some(2));
+
+    let row = get_content_by_id(&mut conn, "pg_guild_ideas", 1).unwrap();
+    assert_eq!(row["tags"], json!(["keeper", "it's lore"]));
+    assert_eq!(
+        row["description"],
+        "Robert'); DROP TABLE pg_guild_ideas; --"
+    );
+    assert!(
+        row["generated_at"]
+            .as_str()
+            .unwrap()
+            .starts_with("2025-11-28T12:00:00")
+    );
+
+    let revisions = content_revisions(&mut conn, "pg_guild_ideas", 1).unwrap();
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(
+        revisions[1].content()["tags"],
+        json!(["keeper", "it's lore"])
+    );
+
+    assert_eq!(
+        revert_content(&mut conn, "pg_guild_ideas", 1, 1, "bob").unwrap(),
+        Some(3)
+    );
+    let row = get_content_by_id(&mut conn, "pg_guild_ideas", 1).unwrap();
+    assert_eq!(row["tags"], json!(null));
+    assert_eq!(row["description"], "A place for lore");
+}
+
+#[test]
+#[ignore = "Requires DATABASE_URL pointing at PostgreSQL"]
+fn test_insert_with_tags() {
+    let mut conn = connect();
+
+    let id = insert_content(
+        &mut conn,
+        "pg_guild_ideas",
+        &changes(json!({"name": "Typo Hunters", "owner_id": 7, "tags": ["new"]})),
+        "alice",
+    )
+    .unwrap();
+    assert_eq!(id, 2);
+
+    let row = get_content_by_id(&mut conn, "pg_guild_ideas", id).unwrap();
+    assert_eq!(row["tags"], json!(["new"]));
+    assert_eq!(row["review_status"], "generated");
+
+    let revisions = content_revisions(&mut conn, "pg_guild_ideas", id).unwrap();
+    assert_eq!(revisions.len(), 1);
+    assert_eq!(revisions[0].editor(), "alice");
+}
+
+#[test]
+#[ignore = "Requires DATABASE_URL pointing at PostgreSQL"]
+fn test_round_trip_into_existing_table() {
+    let mut conn = connect();
+    let tags = ["lore".to_string(), "it's cozy".to_string()];
+    update_content_metadata(
+        &mut conn,
+        "pg_guild_ideas",
+        1,
+        Some(&tags),
+        Some(5),
+        "alice",
+    )
+    .unwrap();
+
+    let formats = [
+        ContentFormat::Jsonl,
+        ContentFormat::Csv,
+        #[cfg(feature = "parquet")]
+        ContentFormat::Parquet,
+    ];
+
+    for format in formats {
+        let table = format!("pg_guild_copy_{}", format);
+        create_content_table(&mut conn, &table, "discord_guilds", None, None).unwrap();
+
+        let mut data = Vec::new();
+        export_content(&mut conn, "pg_guild_ideas", format, &mut data).unwrap();
+        let summary = import_content(&mut conn, &table, format, &data[..]).unwrap();
+        assert!(!*summary.created_table(), "{}", format);
+        assert_eq!(*summary.inserted(), 1, "{}", format);
+
+        let copy = get_content_by_id(&mut conn, &table, 1).unwrap();
+        assert_eq!(copy["tags"], json!(["lore", "it's cozy"]), "{}", format);
+        assert_eq!(copy["rating"], 5, "{}", format);
+
+        // Curation from the export overwrites tags changed since
+        update_content_metadata(
+            &mut conn,
+            &table,
+            1,
+            Some(&["stale".to_string()]),
+            None,
+            "bob",
+        )
+        .unwrap();
+        let summary = import_content(&mut conn, &table, format, &data[..]).unwrap();
+        assert_eq!(
+            (*summary.inserted(), *summary.updated()),
+            (0, 1),
+            "{}",
+            format
+        );
+        let copy = get_content_by_id(&mut conn, &table, 1).unwrap();
+        assert_eq!(copy["tags"], json!(["lore", "it's cozy"]), "{}", format);
+    }
+}
@@ -0,0 +1,275 @@
+//! Tests for the SQLite backend, run against in-memory databases.
+
+#![cfg(feature = "sqlite")]
+
+use botticelli_core::{FilterCondition, FilterOperators, TableFilter};
+use botticelli_database::{
+    ContentConnection, DatabaseContentRepository, NewContentGenerationRow, TableQueryExecutor,
+    UpdateContentGenerationRow, content_status_history, create_content_table, create_database_pool,
+    establish_sqlite_connection, get_content_by_id, list_content, promote_content,
+    reflect_table_schema, update_content_metadata, update_review_status,
+};
+use botticelli_interface::{ContentRepository, TableCountViewBuilder, TableQueryViewBuilder};
+use diesel::sqlite::SqliteConnection;
+
+fn connect() -> SqliteConnection {
+    establish_sqlite_connection(":memory:").expect("in-memory database should open")
+}
+
+/// Create a content table from the `discord_guilds` template with two rows.
+fn guild_ideas(conn: &mut impl ContentConnection) {
+    create_content_table(
+        conn,
+        "guild_ideas",
+        "discord_guilds",
+        Some("ideas.toml"),
+        Some("Guild ideas"),
+    )
+    .unwrap();
+
+    conn.execute_sql(
+        "INSERT INTO guild_ideas (id, name, owner_id, features, source_narrative)
+         VALUES (1, 'Lore Lounge', 42, '[\"lore\", \"art\"]', 'ideas'),
+                (2, 'Speedrun Den', 42, '[\"games\"]', 'ideas')",
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_migrations_and_reflection() {
+    let mut conn = connect();
+
+    assert!(conn.table_exists("discord_guilds").unwrap());
+    assert!(!conn.table_exists("missing_table").unwrap());
+
+    let schema = reflect_table_schema(&mut conn, "discord_guilds").unwrap();
+    let column = |name: &str| schema.columns.iter().find(|c| c.name == name).unwrap();
+
+    assert_eq!(column("id").data_type, "bigint");
+    assert_eq!(column("id").is_nullable, "NO");
+    assert_eq!(column("name").data_type, "character varying");
+    assert_eq!(column("name").character_maximum_length, Some(100));
+    assert_eq!(column("features").data_type, "jsonb");
+    assert_eq!(column("description").is_nullable, "YES");
+}
+
+#[test]
+fn test_content_round_trip() {
+    let mut conn = connect();
+    guild_ideas(&mut conn);
+
+    let rows = list_content(&mut conn, "guild_ideas", Some("generated"), 10).unwrap();
+    assert_eq!(rows.len(), 2);
+    assert!(rows.iter().all(|row| row["features"].is_array()));
+
+    update_review_status(&mut conn, "guild_ideas", 1, "approved", "tester").unwrap();
+    update_content_metadata(
+        &mut conn,
+        "guild_ideas",
+        1,
+        Some(&["keeper".to_string()]),
+        Some(5),
+        "tester",
+    )
+    .unwrap();
+
+    let approved = list_content(&mut conn, "guild_ideas", Some("approved"), 10).unwrap();
+    assert_eq!(approved.len(), 1);
+
+    let row = get_content_by_id(&mut conn, "guild_ideas", 1).unwrap();
+    assert_eq!(row["name"], "Lore Lounge");
+    assert_eq!(row["rating"], 5);
+    assert_eq!(row["tags"], serde_json::json!(["keeper"]));
+    assert_eq!(row["features"], serde_json::json!(["lore", "art"]));
+
+    assert!(get_content_by_id(&mut conn, "guild_ideas", 99).is_err());
+}
+
+#[tokio::test]
+async fn test_structured_filters_and_destructive_reads() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_database_pool(&dir.path().join("content.db").display().to_string()).unwrap();
+    {
+        let mut conn = pool.get().unwrap();
+        guild_ideas(&mut *conn);
+        update_review_status(&mut *conn, "guild_ideas", 1, "approved", "tester").unwrap();
+    }
+
+    let executor = TableQueryExecutor::new(pool);
+    let filter = TableFilter::new()
+        .with("review_status", FilterCondition::Equals("approved".into()))
+        .with(
+            "features",
+            FilterCondition::Operators(Box::new(FilterOperators {
+                contains: Some("lore".into()),
+                ..Default::default()
+            })),
+        );
+
+    let view = TableQueryViewBuilder::default()
+        .table_name("guild_ideas")
+        .columns(vec!["id".to_string(), "name".to_string()])
+        .conditions(filter.clone())
+        .build()
+        .unwrap();
+    let rows = executor.query_table(&view).await.unwrap();
+    assert_eq!(
+        rows,
+        vec![serde_json::json!({"id": 1, "name": "Lore Lounge"})]
+    );
+
+    let count = TableCountViewBuilder::default()
+        .table_name("guild_ideas")
+        .conditions(filter)
+        .build()
+        .unwrap();
+    assert_eq!(executor.count_rows(&count).await.unwrap(), 1);
+
+    let pull = TableQueryViewBuilder::default()
+        .table_name("guild_ideas")
+        .limit(1)
+        .build()
+        .unwrap();
+    let pulled = executor.query_and_delete_table(&pull).await.unwrap();
+    assert_eq!(pulled.len(), 1);
+
+    let everything = TableQueryViewBuilder::default()
+        .table_name("guild_ideas")
+        .build()
+        .unwrap();
+    assert_eq!(executor.query_table(&everything).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_raw_filters_need_opt_in() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_database_pool(&dir.path().join("content.db").display().to_string()).unwrap();
+    {
+        let mut conn = pool.get().unwrap();
+        guild_ideas(&mut *conn);
+    }
+
+    let view = TableQueryViewBuilder::default()
+        .table_name("guild_ideas")
+        .filter("id = 2")
+        .build()
+        .unwrap();
+
+    // `botticelli run` and actors build the executor this way unless told otherwise
+    let executor = TableQueryExecutor::new(pool);
+    assert!(!executor.allow_raw_filters());
+    let err = executor.query_table(&view).await.unwrap_err();
+    assert!(err.to_string().contains("Raw SQL filters are disabled"));
+
+    let trusted = executor.with_raw_filters(true);
+    let rows = trusted.query_table(&view).await.unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["name"], "Speedrun Den");
+}
+
+#[test]
+fn test_promote_content() {
+    let mut conn = connect();
+    guild_ideas(&mut conn);
+
+    conn.execute_sql(
+        "CREATE TABLE published_guilds (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            features JSONB
+        )",
+        &[],
+    )
+    .unwrap();
+
+    // Only approved content can be promoted
+    assert!(promote_content(&mut conn, "guild_ideas", "published_guilds", 2).is_err());
+    update_review_status(&mut conn, "guild_ideas", 2, "approved", "tester").unwrap();
+
+    let id = promote_content(&mut conn, "guild_ideas", "published_guilds", 2).unwrap();
+    assert_eq!(id, 1);
+
+    let promoted = get_content_by_id(&mut conn, "published_guilds", id).unwrap();
+    assert_eq!(promoted["name"], "Speedrun Den");
+    assert_eq!(promoted["features"], serde_json::json!(["games"]));
+}
+
+#[test]
+fn test_generation_tracking() {
+    let mut conn = connect();
+    let mut repo = conn.generations();
+
+    let started = repo
+        .start_generation(NewContentGenerationRow {
+            table_name: "guild_ideas".to_string(),
+            narrative_file: "ideas.toml".to_string(),
+            narrative_name: "ideas".to_string(),
+            status: "running".to_string(),
+            created_by: None,
+        })
+        .unwrap();
+    assert_eq!(started.status(), "running");
+    assert!(repo.get_last_successful().unwrap().is_none());
+
+    let completed = repo
+        .complete_generation(
+            "guild_ideas",
+            UpdateContentGenerationRow {
+                completed_at: Some(chrono::Utc::now()),
+                row_count: Some(2),
+                generation_duration_ms: Some(1500),
+                status: Some("success".to_string()),
+                error_message: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(completed.status(), "success");
+    assert_eq!(completed.row_count(), &Some(2));
+    assert!(completed.completed_at().is_some());
+
+    let last = repo.get_last_successful().unwrap().unwrap();
+    assert_eq!(last.table_name(), "guild_ideas");
+    assert_eq!(repo.list_generations(None, 10).unwrap().len(), 1);
+
+    repo.delete_generation("guild_ideas").unwrap();
+    assert!(repo.get_by_table_name("guild_ideas").unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_concurrent_pooled_reviews() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_database_pool(&dir.path().join("content.db").display().to_string()).unwrap();
+    guild_ideas(&mut *pool.get().unwrap());
+
+    // Each review checks out its own pooled connection
+    let repo = DatabaseContentRepository::new(pool.clone());
+    let reviews = (1..=2).map(|id| {
+        let repo = repo.clone();
+        tokio::spawn(async move {
+            if id == 1 {
+                repo.update_review_status_as("guild_ideas", id, "approved", "tester")
+                    .await
+            } else {
+                repo.update_review_status("guild_ideas", id, "approved")
+                    .await
+            }
+        })
+    });
+    for review in reviews {
+        review.await.unwrap().unwrap();
+    }
+
+    let approved = repo
+        .list_content("guild_ideas", Some("approved"), 10)
+        .await
+        .unwrap();
+    assert_eq!(approved.len(), 2);
+
+    // Without an actor, the repository records itself
+    let mut conn = pool.get().unwrap();
+    let history = content_status_history(&mut *conn, "guild_ideas", 1).unwrap();
+    assert_eq!(history[0].actor(), "tester");
+    let history = content_status_history(&mut *conn, "guild_ideas", 2).unwrap();
+    assert_eq!(history[0].actor(), "content_repository");
+}
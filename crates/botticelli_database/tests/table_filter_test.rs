@@ -0,0 +1,244 @@
+//! Tests for compiling structured table filters to parameterized SQL.
+
+use botticelli_core::{FilterCondition, FilterOperators, FilterValue, TableFilter};
+use botticelli_database::{ColumnInfo, CompiledFilter, SqlDialect, TableSchema};
+
+fn column(name: &str, data_type: &str) -> ColumnInfo {
+    ColumnInfo {
+        name: name.to_string(),
+        data_type: data_type.to_string(),
+        is_nullable: "YES".to_string(),
+        character_maximum_length: None,
+        column_default: None,
+    }
+}
+
+fn posts() -> TableSchema {
+    TableSchema {
+        table_name: "posts".to_string(),
+        columns: vec![
+            column("status", "text"),
+            column("rating", "integer"),
+            column("tags", "_text"),
+            column("metadata", "jsonb"),
+            column("created_at", "timestamp with time zone"),
+            column("mood", "USER-DEFINED"),
+        ],
+    }
+}
+
+fn operators(operators: FilterOperators) -> FilterCondition {
+    FilterCondition::Operators(Box::new(operators))
+}
+
+#[test]
+fn test_values_become_parameters() {
+    let filter = TableFilter::new()
+        .with("status", FilterCondition::Equals("approved".into()))
+        .with(
+            "rating",
+            operators(FilterOperators {
+                gte: Some(FilterValue::Integer(4)),
+                ..Default::default()
+            }),
+        )
+        .with(
+            "tags",
+            operators(FilterOperators {
+                contains: Some("lore".into()),
+                ..Default::default()
+            }),
+        );
+
+    let compiled = CompiledFilter::compile(&filter, &posts(), SqlDialect::Postgres).unwrap();
+
+    assert_eq!(
+        compiled.sql(),
+        r#""rating" >= $1 AND "status" = $2::text AND $3 = ANY("tags")"#
+    );
+    assert_eq!(
+        compiled.params(),
+        &vec![
+            FilterValue::Integer(4),
+            FilterValue::Text("approved".to_string()),
+            FilterValue::Text("lore".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_injection_attempt_stays_a_value() {
+    let attack = "x'; DROP TABLE posts; --";
+    let filter = TableFilter::new().with("status", FilterCondition::Equals(attack.into()));
+
+    let compiled = CompiledFilter::compile(&filter, &posts(), SqlDialect::Postgres).unwrap();
+
+    assert!(!compiled.sql().contains("DROP"));
+    assert_eq!(
+        compiled.params(),
+        &vec![FilterValue::Text(attack.to_string())]
+    );
+}
+
+#[test]
+fn test_unknown_column_rejected() {
+    let filter = TableFilter::new().with(
+        "status = 'x' OR 1=1 --",
+        FilterCondition::Equals("approved".into()),
+    );
+
+    let error = CompiledFilter::compile(&filter, &posts(), SqlDialect::Postgres).unwrap_err();
+    assert!(error.to_string().contains("Unknown column"));
+}
+
+#[test]
+fn test_text_values_cast_to_column_type() {
+    let filter = TableFilter::new()
+        .with(
+            "created_at",
+            operators(FilterOperators {
+                gte: Some("2025-01-01".into()),
+                ..Default::default()
+            }),
+        )
+        .with("mood", FilterCondition::Equals("cheerful".into()));
+
+    let compiled = CompiledFilter::compile(&filter, &posts(), SqlDialect::Postgres).unwrap();
+
+    assert_eq!(
+        compiled.sql(),
+        r#""created_at" >= CAST($1 AS timestamp with time zone) AND "mood"::text = $2"#
+    );
+}
+
+#[test]
+fn test_membership_and_null_checks() {
+    let filter = TableFilter::new()
+        .with(
+            "status",
+            operators(FilterOperators {
+                one_of: Some(vec!["pending".into(), "approved".into()]),
+                is_null: Some(false),
+                ..Default::default()
+            }),
+        )
+        .with(
+            "rating",
+            operators(FilterOperators {
+                not_in: Some(Vec::new()),
+                ..Default::default()
+            }),
+        );
+
+    let compiled = CompiledFilter::compile(&filter, &posts(), SqlDialect::Postgres).unwrap();
+
+    assert_eq!(
+        compiled.sql(),
+        r#"TRUE AND "status" IN ($1::text, $2::text) AND "status" IS NOT NULL"#
+    );
+    assert_eq!(compiled.params().len(), 2);
+}
+
+#[test]
+fn test_membership_values_share_one_type() {
+    let in_filter = |values: Vec<FilterValue>| {
+        TableFilter::new().with(
+            "created_at",
+            operators(FilterOperators {
+                one_of: Some(values),
+                ..Default::default()
+            }),
+        )
+    };
+
+    let dates = in_filter(vec!["2025-01-01".into(), "2025-02-01".into()]);
+    let compiled = CompiledFilter::compile(&dates, &posts(), SqlDialect::Postgres).unwrap();
+    assert_eq!(
+        compiled.sql(),
+        r#""created_at" IN (CAST($1 AS timestamp with time zone), CAST($2 AS timestamp with time zone))"#
+    );
+
+    let mixed = in_filter(vec!["2025-01-01".into(), FilterValue::Integer(7)]);
+    assert!(CompiledFilter::compile(&mixed, &posts(), SqlDialect::Postgres).is_err());
+}
+
+#[test]
+fn test_json_and_text_contains() {
+    let filter = TableFilter::new()
+        .with(
+            "metadata",
+            operators(FilterOperators {
+                contains: Some("featured".into()),
+                ..Default::default()
+            }),
+        )
+        .with(
+            "status",
+            operators(FilterOperators {
+                contains: Some("prov".into()),
+                like: Some("app%".to_string()),
+                ..Default::default()
+            }),
+        );
+
+    let compiled = CompiledFilter::compile(&filter, &posts(), SqlDialect::Postgres).unwrap();
+
+    assert_eq!(
+        compiled.sql(),
+        r#""metadata"::jsonb @> to_jsonb($1) AND strpos("status", $2::text) > 0 AND "status" LIKE $3"#
+    );
+}
+
+#[test]
+fn test_unsupported_operators_rejected() {
+    let like_on_number = TableFilter::new().with(
+        "rating",
+        operators(FilterOperators {
+            like: Some("4%".to_string()),
+            ..Default::default()
+        }),
+    );
+    assert!(CompiledFilter::compile(&like_on_number, &posts(), SqlDialect::Postgres).is_err());
+
+    let compare_array = TableFilter::new().with("tags", FilterCondition::Equals("lore".into()));
+    assert!(CompiledFilter::compile(&compare_array, &posts(), SqlDialect::Postgres).is_err());
+
+    let no_operators = TableFilter::new().with("rating", operators(FilterOperators::default()));
+    assert!(CompiledFilter::compile(&no_operators, &posts(), SqlDialect::Postgres).is_err());
+}
+
+#[test]
+fn test_empty_filter_matches_all() {
+    let compiled =
+        CompiledFilter::compile(&TableFilter::new(), &posts(), SqlDialect::Postgres).unwrap();
+    assert_eq!(compiled.sql(), "TRUE");
+    assert!(compiled.params().is_empty());
+}
+
+#[test]
+fn test_sqlite_dialect() {
+    let filter = TableFilter::new()
+        .with("status", FilterCondition::Equals("approved".into()))
+        .with(
+            "tags",
+            operators(FilterOperators {
+                contains: Some("lore".into()),
+                ..Default::default()
+            }),
+        )
+        .with(
+            "created_at",
+            operators(FilterOperators {
+                gte: Some("2025-01-01".into()),
+                ..Default::default()
+            }),
+        );
+
+    let compiled = CompiledFilter::compile(&filter, &posts(), SqlDialect::Sqlite).unwrap();
+
+    assert_eq!(
+        compiled.sql(),
+        r#""created_at" >= ?1 AND "status" = CAST(?2 AS TEXT) AND EXISTS (SELECT 1 FROM json_each("tags") WHERE value = ?3)"#
+    );
+    assert_eq!(compiled.params().len(), 3);
+}
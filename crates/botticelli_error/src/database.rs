@@ -27,6 +27,14 @@ pub enum DatabaseErrorKind {
     /// Invalid query
     #[display("Invalid query: {}", _0)]
     InvalidQuery(String),
+    /// Content lifecycle does not allow this status change
+    #[display("Invalid status transition from '{}' to '{}'", from, to)]
+    InvalidTransition {
+        /// Current status
+        from: String,
+        /// Requested status
+        to: String,
+    },
 }
 
 /// Database error with source location tracking.
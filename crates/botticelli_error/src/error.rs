@@ -6,7 +6,7 @@ use crate::DatabaseError;
 use crate::TuiError;
 use crate::{
     BackendError, BuilderError, ConfigError, GeminiError, HttpError, JsonError, NarrativeError,
-    NotImplementedError, ServerError, StorageError,
+    NotImplementedError, SecretError, ServerError, StorageError,
 };
 
 /// This is the foundation error enum. Additional variants will be added
@@ -61,6 +61,9 @@ pub enum BotticelliErrorKind {
     /// Local inference server error
     #[from(ServerError)]
     Server(ServerError),
+    /// Secret management error
+    #[from(SecretError)]
+    Secret(SecretError),
 }
 
 /// Botticelli error with kind discrimination.
@@ -38,6 +38,7 @@ mod http;
 mod json;
 mod narrative;
 mod not_implemented;
+mod secret;
 mod server;
 mod storage;
 #[cfg(feature = "tui")]
@@ -54,6 +55,7 @@ pub use http::HttpError;
 pub use json::JsonError;
 pub use narrative::{NarrativeError, NarrativeErrorKind};
 pub use not_implemented::NotImplementedError;
+pub use secret::{SecretError, SecretErrorKind, SecretResult};
 pub use server::{ServerError, ServerErrorKind};
 pub use storage::{StorageError, StorageErrorKind};
 #[cfg(feature = "tui")]
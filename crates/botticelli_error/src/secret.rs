@@ -0,0 +1,61 @@
+//! Secret management error types.
+
+/// Kinds of secret management errors.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, derive_more::Display)]
+pub enum SecretErrorKind {
+    /// No provider knows the requested secret
+    #[display("Secret not found: {}", _0)]
+    NotFound(String),
+    /// A config value referenced a secret with an invalid name
+    #[display("Invalid secret reference: {}", _0)]
+    InvalidReference(String),
+    /// Failed to read or write a secret store
+    #[display("Secret store I/O failed: {}", _0)]
+    Io(String),
+    /// Keystore could not be decrypted (wrong passphrase or tampering)
+    #[display("Keystore decryption failed: {}", _0)]
+    Decryption(String),
+    /// Keystore could not be encrypted or serialized
+    #[display("Keystore encryption failed: {}", _0)]
+    Encryption(String),
+    /// Keystore file is malformed
+    #[display("Invalid keystore format: {}", _0)]
+    InvalidFormat(String),
+}
+
+/// Secret management error with location tracking.
+///
+/// # Examples
+///
+/// ```
+/// use botticelli_error::{SecretError, SecretErrorKind};
+///
+/// let err = SecretError::new(SecretErrorKind::NotFound("discord_token".to_string()));
+/// assert!(format!("{}", err).contains("discord_token"));
+/// ```
+#[derive(Debug, Clone, derive_more::Display, derive_more::Error)]
+#[display("Secret Error: {} at line {} in {}", kind, line, file)]
+pub struct SecretError {
+    /// The kind of error that occurred
+    pub kind: SecretErrorKind,
+    /// Line number where error was created
+    pub line: u32,
+    /// File where error was created
+    pub file: &'static str,
+}
+
+impl SecretError {
+    /// Create a new secret error with automatic location tracking.
+    #[track_caller]
+    pub fn new(kind: SecretErrorKind) -> Self {
+        let location = std::panic::Location::caller();
+        Self {
+            kind,
+            line: location.line(),
+            file: location.file(),
+        }
+    }
+}
+
+/// Result type for secret operations.
+pub type SecretResult<T> = Result<T, SecretError>;
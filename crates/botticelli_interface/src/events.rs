@@ -0,0 +1,118 @@
+//! Platform events that can trigger narrative execution.
+//!
+//! Social platform integrations publish [`PlatformEvent`]s as they observe
+//! gateway activity (new messages, member joins, reactions). Consumers such as
+//! the actor server match them against configured triggers and expose the
+//! payload to narratives as `${event:...}` template variables.
+
+use derive_builder::Builder;
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Prefix used for event template variables (`${event:content}`).
+pub const EVENT_VARIABLE_PREFIX: &str = "event:";
+
+/// Kind of platform event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum::EnumIter)]
+#[serde(rename_all = "snake_case")]
+pub enum PlatformEventKind {
+    /// A message was posted in a channel.
+    MessageCreated,
+    /// A member joined a guild/server.
+    MemberJoined,
+    /// A reaction was added to a message.
+    ReactionAdded,
+}
+
+impl PlatformEventKind {
+    /// Stable snake_case name used in templates and logs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MessageCreated => "message_created",
+            Self::MemberJoined => "member_joined",
+            Self::ReactionAdded => "reaction_added",
+        }
+    }
+}
+
+impl std::fmt::Display for PlatformEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An event observed on a social platform.
+///
+/// Identifiers are kept as strings so events from different platforms share
+/// one representation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder, Getters)]
+#[builder(setter(into, strip_option))]
+pub struct PlatformEvent {
+    /// Platform that produced the event (e.g. "discord").
+    platform: String,
+    /// What happened.
+    kind: PlatformEventKind,
+    /// Guild/server the event belongs to.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    guild_id: Option<String>,
+    /// Channel the event happened in.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    channel_id: Option<String>,
+    /// User who caused the event.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    user_id: Option<String>,
+    /// Display name of the user who caused the event.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    user_name: Option<String>,
+    /// Message the event refers to.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    message_id: Option<String>,
+    /// Message text (message events only).
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    /// Emoji of a reaction (reaction events only).
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    emoji: Option<String>,
+}
+
+impl PlatformEvent {
+    /// Template variables describing this event.
+    ///
+    /// Keys are prefixed with [`EVENT_VARIABLE_PREFIX`], e.g. `event:type`,
+    /// `event:channel_id` or `event:content`. Fields that are not set are
+    /// omitted.
+    pub fn variables(&self) -> HashMap<String, String> {
+        let mut variables = HashMap::new();
+        let mut insert = |key: &str, value: &str| {
+            variables.insert(format!("{EVENT_VARIABLE_PREFIX}{key}"), value.to_string());
+        };
+
+        insert("type", self.kind.as_str());
+        insert("platform", &self.platform);
+
+        let optional = [
+            ("guild_id", &self.guild_id),
+            ("channel_id", &self.channel_id),
+            ("user_id", &self.user_id),
+            ("user_name", &self.user_name),
+            ("message_id", &self.message_id),
+            ("content", &self.content),
+            ("emoji", &self.emoji),
+        ];
+        for (key, value) in optional {
+            if let Some(value) = value {
+                insert(key, value);
+            }
+        }
+
+        variables
+    }
+}
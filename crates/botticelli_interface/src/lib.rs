@@ -4,6 +4,7 @@
 //! the Botticelli interface.
 
 mod bot_server;
+mod events;
 mod narrative;
 mod table_query_view;
 mod table_view;
@@ -11,6 +12,10 @@ mod traits;
 mod types;
 
 pub use bot_server::{BotActor, BotResult, BotServer, BotServerConfig, BotState, BotStats};
+pub use events::{
+    EVENT_VARIABLE_PREFIX, PlatformEvent, PlatformEventBuilder, PlatformEventBuilderError,
+    PlatformEventKind,
+};
 pub use narrative::{
     ActExecution, ExecutionFilter, ExecutionStatus, ExecutionSummary, NarrativeExecution,
     NarrativeRepository,
@@ -1,6 +1,7 @@
 //! Concrete table view implementations with builder pattern.
 
 use crate::TableView;
+use botticelli_core::TableFilter;
 use derive_builder::Builder;
 use derive_getters::Getters;
 
@@ -16,10 +17,14 @@ pub struct TableQueryView {
     #[builder(default)]
     columns: Option<Vec<String>>,
 
-    /// WHERE clause for filtering.
+    /// Raw SQL WHERE clause for filtering.
     #[builder(default)]
     filter: Option<String>,
 
+    /// Structured filter, compiled to bound parameters.
+    #[builder(default)]
+    conditions: Option<TableFilter>,
+
     /// ORDER BY clause.
     #[builder(default)]
     order_by: Option<String>,
@@ -46,6 +51,10 @@ impl TableView for TableQueryView {
         self.filter.as_deref()
     }
 
+    fn conditions(&self) -> Option<&TableFilter> {
+        self.conditions.as_ref()
+    }
+
     fn order_by(&self) -> Option<&str> {
         self.order_by.as_deref()
     }
@@ -67,9 +76,13 @@ pub struct TableCountView {
     #[builder(setter(into))]
     table_name: String,
 
-    /// WHERE clause for filtering.
+    /// Raw SQL WHERE clause for filtering.
     #[builder(default)]
     filter: Option<String>,
+
+    /// Structured filter, compiled to bound parameters.
+    #[builder(default)]
+    conditions: Option<TableFilter>,
 }
 
 impl TableView for TableCountView {
@@ -80,4 +93,8 @@ impl TableView for TableCountView {
     fn filter(&self) -> Option<&str> {
         self.filter.as_deref()
     }
+
+    fn conditions(&self) -> Option<&TableFilter> {
+        self.conditions.as_ref()
+    }
 }
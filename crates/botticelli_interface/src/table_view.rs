@@ -1,5 +1,6 @@
 //! Table view trait for database query specifications.
 
+use botticelli_core::TableFilter;
 use serde::{Deserialize, Serialize};
 
 /// Trait for table view specifications that define database queries.
@@ -10,11 +11,16 @@ pub trait TableView: Send + Sync {
     /// The name of the table being queried.
     fn table_name(&self) -> &str;
 
-    /// Optional filter conditions for the query.
+    /// Optional raw SQL filter for the query.
     fn filter(&self) -> Option<&str> {
         None
     }
 
+    /// Optional structured filter for the query.
+    fn conditions(&self) -> Option<&TableFilter> {
+        None
+    }
+
     /// Optional ordering specification (e.g., "created_at DESC").
     fn order_by(&self) -> Option<&str> {
         None
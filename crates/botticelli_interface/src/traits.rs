@@ -343,7 +343,7 @@ pub trait ContentRepository: Send + Sync {
     ///
     /// * `table_name` - Name of the content table
     /// * `id` - ID of the content item
-    /// * `new_status` - New review status ("pending", "approved", "rejected")
+    /// * `new_status` - New lifecycle status, e.g. "approved" or "rejected"
     async fn update_review_status(
         &self,
         table_name: &str,
@@ -351,6 +351,28 @@ pub trait ContentRepository: Send + Sync {
         new_status: &str,
     ) -> BotticelliResult<()>;
 
+    /// Update the review status of a content item, recording who changed it.
+    ///
+    /// The default ignores `actor` and calls [`Self::update_review_status`];
+    /// repositories that keep a status history should override it.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - Name of the content table
+    /// * `id` - ID of the content item
+    /// * `new_status` - New lifecycle status, e.g. "approved" or "rejected"
+    /// * `actor` - Who is making the change, recorded in the status history
+    async fn update_review_status_as(
+        &self,
+        table_name: &str,
+        id: i64,
+        new_status: &str,
+        actor: &str,
+    ) -> BotticelliResult<()> {
+        let _ = actor;
+        self.update_review_status(table_name, id, new_status).await
+    }
+
     /// Delete a content item.
     ///
     /// # Arguments
@@ -44,7 +44,6 @@
 
 use async_trait::async_trait;
 use std::collections::HashMap;
-use std::env;
 use std::sync::{Arc, Mutex};
 use tracing::instrument;
 
@@ -328,7 +327,8 @@ impl GeminiClient {
     fn new_with_tier_config(tier_config: Option<TierConfig>) -> BotticelliResult<Self> {
         // Load .env file if present
 
-        let api_key = env::var("GEMINI_API_KEY")
+        let api_key = botticelli_secrets::get_secret("gemini_api_key")
+            .map(|secret| secret.into_inner())
             .map_err(|_| BotticelliError::from(GeminiError::new(GeminiErrorKind::MissingApiKey)))?;
 
         let base_tier = tier_config.unwrap_or_else(|| {
@@ -366,7 +366,8 @@ impl GeminiClient {
 
     /// Internal constructor that returns Gemini-specific errors.
     fn new_internal(tier: Option<Box<dyn Tier>>) -> GeminiResult<Self> {
-        let api_key = env::var("GEMINI_API_KEY")
+        let api_key = botticelli_secrets::get_secret("gemini_api_key")
+            .map(|secret| secret.into_inner())
             .map_err(|_| GeminiError::new(GeminiErrorKind::MissingApiKey))?;
 
         // Convert Box<dyn Tier> to TierConfig
@@ -31,7 +31,6 @@
 //! ```
 
 use futures_util::{SinkExt, StreamExt};
-use std::env;
 use std::sync::Arc;
 use tokio::net::TcpStream;
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async, tungstenite::Message};
@@ -95,7 +94,8 @@ impl GeminiLiveClient {
     /// ```
     #[instrument(name = "gemini_live_client_new_with_rate_limit")]
     pub fn new_with_rate_limit(max_messages_per_minute: Option<u32>) -> GeminiResult<Self> {
-        let api_key = env::var("GEMINI_API_KEY")
+        let api_key = botticelli_secrets::get_secret("gemini_api_key")
+            .map(|secret| secret.into_inner())
             .map_err(|_| GeminiError::new(GeminiErrorKind::MissingApiKey))?;
 
         let rate_limiter = max_messages_per_minute.map(|rpm| Arc::new(LiveRateLimiter::new(rpm)));
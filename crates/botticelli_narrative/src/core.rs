@@ -1,6 +1,6 @@
 //! Core data structures for narratives.
 
-use crate::{ActConfig, CarouselConfig, NarrativeProvider, toml_parser};
+use crate::{ActConfig, CarouselConfig, NarrativeProvider, SlashCommandConfig, toml_parser};
 use botticelli_error::{NarrativeError, NarrativeErrorKind};
 use std::collections::HashMap;
 use std::path::Path;
@@ -10,9 +10,7 @@ use tracing::debug;
 #[cfg(feature = "database")]
 use botticelli_core::Input;
 #[cfg(feature = "database")]
-use botticelli_database::{assemble_prompt, is_content_focus};
-#[cfg(feature = "database")]
-use diesel::pg::PgConnection;
+use botticelli_database::{ContentConnection, assemble_prompt, is_content_focus};
 
 /// Narrative metadata from the `[narrative]` section.
 #[derive(
@@ -48,6 +46,9 @@ pub struct NarrativeMetadata {
     /// Available with the`budget`feature.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     budget: Option<botticelli_core::BudgetConfig>,
+    /// Optional slash command that runs this narrative
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    slash_command: Option<SlashCommandConfig>,
 }
 
 impl NarrativeMetadata {
@@ -65,6 +66,7 @@ impl NarrativeMetadata {
             temperature: None,
             max_tokens: None,
             budget: None,
+            slash_command: None,
         }
     }
 }
@@ -175,9 +177,9 @@ impl Narrative {
     /// - Prompt assembly fails
     #[cfg(feature = "database")]
     #[tracing::instrument(skip_all, fields(path = %path.as_ref().display()))]
-    pub fn from_file_with_db<P: AsRef<Path>>(
+    pub fn from_file_with_db<P: AsRef<Path>, C: ContentConnection>(
         path: P,
-        conn: &mut PgConnection,
+        conn: &mut C,
     ) -> Result<Self, NarrativeError> {
         let mut narrative = Self::from_file(path)?;
         tracing::debug!(has_template = ?narrative.metadata.template.is_some());
@@ -206,7 +208,10 @@ impl Narrative {
     /// - Prompt assembly fails
     #[cfg(feature = "database")]
     #[tracing::instrument(skip(self, conn), fields(template = ?self.metadata.template, act_count = self.acts.len()))]
-    pub fn assemble_act_prompts(&mut self, conn: &mut PgConnection) -> Result<(), NarrativeError> {
+    pub fn assemble_act_prompts<C: ContentConnection>(
+        &mut self,
+        conn: &mut C,
+    ) -> Result<(), NarrativeError> {
         let template = self
             .metadata
             .template
@@ -330,8 +335,13 @@ impl Narrative {
             temperature: narrative_meta.temperature,
             max_tokens: narrative_meta.max_tokens,
             budget: narrative_meta.budget.clone(),
+            slash_command: narrative_meta.slash_command.clone(),
         };
 
+        if let Some(slash_command) = &metadata.slash_command {
+            slash_command.validate(&metadata.name)?;
+        }
+
         let toc = NarrativeToc {
             order: narrative_toc.order().to_vec(),
         };
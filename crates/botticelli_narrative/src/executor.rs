@@ -33,6 +33,19 @@ pub trait BotCommandRegistry: Send + Sync {
     ) -> Result<JsonValue, Box<dyn std::error::Error + Send + Sync>>;
 }
 
+// Allows a shared registry to be handed to several executors
+#[async_trait::async_trait]
+impl<T: BotCommandRegistry + ?Sized> BotCommandRegistry for std::sync::Arc<T> {
+    async fn execute(
+        &self,
+        platform: &str,
+        command: &str,
+        args: &HashMap<String, JsonValue>,
+    ) -> Result<JsonValue, Box<dyn std::error::Error + Send + Sync>> {
+        (**self).execute(platform, command, args).await
+    }
+}
+
 /// Executes narratives by calling LLM APIs in sequence.
 ///
 /// The executor processes each act in the narrative's table of contents order,
@@ -60,6 +73,7 @@ pub struct NarrativeExecutor<D: BotticelliDriver> {
     bot_registry: Option<Box<dyn BotCommandRegistry>>,
     table_registry: Option<Box<dyn TableQueryRegistry>>,
     state_manager: Option<StateManager>,
+    variables: HashMap<String, String>,
 }
 
 impl<D: BotticelliDriver> NarrativeExecutor<D> {
@@ -71,6 +85,7 @@ impl<D: BotticelliDriver> NarrativeExecutor<D> {
             bot_registry: None,
             table_registry: None,
             state_manager: None,
+            variables: HashMap::new(),
         }
     }
 
@@ -96,6 +111,7 @@ impl<D: BotticelliDriver> NarrativeExecutor<D> {
             bot_registry: None,
             table_registry: None,
             state_manager: None,
+            variables: HashMap::new(),
         }
     }
 
@@ -160,6 +176,30 @@ impl<D: BotticelliDriver> NarrativeExecutor<D> {
         self
     }
 
+    /// Add caller-supplied template variables.
+    ///
+    /// Keys are full template references such as `option:channel`, so a
+    /// variable is used as `${option:channel}` in prompts and bot command
+    /// arguments. Variables take precedence over all other template sources.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let executor = NarrativeExecutor::new(driver).with_variables(HashMap::from([(
+    ///     "option:channel".to_string(),
+    ///     "1234567890".to_string(),
+    /// )]));
+    /// ```
+    pub fn with_variables(mut self, variables: HashMap<String, String>) -> Self {
+        self.variables.extend(variables);
+        self
+    }
+
+    /// Get the caller-supplied template variables.
+    pub fn variables(&self) -> &HashMap<String, String> {
+        &self.variables
+    }
+
     /// Capture and save ID fields from bot command output to state.
     ///
     /// Extracts common ID fields (channel_id, message_id, role_id, etc.) from JSON response
@@ -930,6 +970,7 @@ impl<D: BotticelliDriver> NarrativeExecutor<D> {
                                 act_executions,
                                 current_index,
                                 self.state_manager.as_ref(),
+                                &self.variables,
                             )?;
                         }
                     }
@@ -1036,8 +1077,14 @@ impl<D: BotticelliDriver> NarrativeExecutor<D> {
                     if let Some(cols) = columns.as_ref() {
                         query_builder.columns(cols.clone());
                     }
-                    if let Some(where_str) = where_clause.as_ref() {
-                        query_builder.filter(where_str.clone());
+                    match where_clause {
+                        Some(botticelli_core::WhereClause::Sql(sql)) => {
+                            query_builder.filter(sql.clone());
+                        }
+                        Some(botticelli_core::WhereClause::Filter(filter)) => {
+                            query_builder.conditions(filter.clone());
+                        }
+                        None => {}
                     }
                     if let Some(lim) = limit {
                         query_builder.limit(*lim as i64);
@@ -1191,6 +1238,10 @@ impl<D: BotticelliDriver> NarrativeExecutor<D> {
                     // processed.push(Input::Text(final_output));
                 }
 
+                Input::Text(text) if !self.variables.is_empty() => {
+                    processed.push(Input::Text(substitute_variables(text, &self.variables)));
+                }
+
                 // Pass through all other input types unchanged
                 other => {
                     processed.push(other.clone());
@@ -1224,12 +1275,27 @@ fn extract_text_from_outputs(outputs: &[Output]) -> BotticelliResult<String> {
     }
 }
 
+/// Replace `${key}` placeholders whose key is a caller-supplied variable.
+///
+/// Unlike [`resolve_template`], unknown placeholders are left untouched so that
+/// literal braces in prompt text are never rejected.
+fn substitute_variables(text: &str, variables: &HashMap<String, String>) -> String {
+    let mut result = text.to_string();
+    for (key, value) in variables {
+        result = result.replace(&format!("${{{}}}", key), value);
+    }
+    result
+}
+
 /// Resolve template placeholders in a string using act execution history and state.
 ///
 /// Supports:
 /// - `{{previous}}` - Content from the immediately previous act
 /// - `{{act_name}}` - Content from a specific named act
 /// - `${state:key}` - Value from persistent state
+/// - `${env:VAR}` - Environment variable
+/// - `${secret:name}` - Secret from the configured secret providers
+/// - `${namespace:key}` - Caller-supplied variable (e.g. `${option:channel}`)
 ///
 /// # Errors
 ///
@@ -1243,6 +1309,7 @@ fn resolve_template(
     act_executions: &[ActExecution],
     current_index: usize,
     state_manager: Option<&StateManager>,
+    variables: &HashMap<String, String>,
 ) -> BotticelliResult<String> {
     let mut result = template.to_string();
 
@@ -1269,7 +1336,9 @@ fn resolve_template(
                 )
             })?;
 
-        let replacement = if reference.starts_with("state:") {
+        let replacement = if let Some(value) = variables.get(reference) {
+            value.clone()
+        } else if reference.starts_with("state:") {
             // State reference like "${state:channel_id}" or "${state:discord.channels.create.channel_id}"
             let state_key = reference.strip_prefix("state:").unwrap();
 
@@ -1324,6 +1393,18 @@ fn resolve_template(
                     )),
                 )
             })?
+        } else if let Some(secret_name) = reference.strip_prefix("secret:") {
+            // Secret reference like "${secret:discord_token}"
+            botticelli_secrets::get_secret(secret_name)
+                .map_err(|e| {
+                    botticelli_error::NarrativeError::new(
+                        botticelli_error::NarrativeErrorKind::TemplateError(format!(
+                            "Secret '{}' could not be resolved: {}",
+                            secret_name, e.kind
+                        )),
+                    )
+                })?
+                .into_inner()
         } else if reference == "previous" {
             // Get previous act
             if current_index == 0 {
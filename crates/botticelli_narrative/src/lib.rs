@@ -44,6 +44,7 @@ mod in_memory_repository;
 mod multi_narrative;
 mod processor;
 mod provider;
+mod slash_command;
 mod state;
 mod table_reference;
 mod toml_parser;
@@ -67,6 +68,7 @@ pub use in_memory_repository::InMemoryNarrativeRepository;
 pub use multi_narrative::MultiNarrative;
 pub use processor::{ActProcessor, ProcessorContext, ProcessorRegistry};
 pub use provider::{ActConfig, NarrativeProvider};
+pub use slash_command::{SlashCommandConfig, SlashCommandOption, SlashCommandOptionType};
 pub use state::{NarrativeState, StateManager, StateScope};
 pub use table_reference::TableReference;
 
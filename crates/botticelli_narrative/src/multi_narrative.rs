@@ -10,7 +10,7 @@ use std::path::Path;
 use tracing::{debug, instrument};
 
 #[cfg(feature = "database")]
-use diesel::pg::PgConnection;
+use botticelli_database::ContentConnection;
 
 /// Container for multiple narratives from a single TOML file.
 ///
@@ -57,10 +57,10 @@ impl MultiNarrative {
     /// Returns an error if the file cannot be read, parsed, or schema reflection fails.
     #[cfg(feature = "database")]
     #[instrument(skip_all, fields(path = %path.as_ref().display(), narrative_name))]
-    pub fn from_file_with_db<P: AsRef<Path>>(
+    pub fn from_file_with_db<P: AsRef<Path>, C: ContentConnection>(
         path: P,
         narrative_name: &str,
-        conn: &mut PgConnection,
+        conn: &mut C,
     ) -> Result<Self, NarrativeError> {
         let path = path.as_ref();
         let content = std::fs::read_to_string(path)
@@ -123,11 +123,11 @@ impl MultiNarrative {
     /// Parse all narratives from TOML string with database support.
     #[cfg(feature = "database")]
     #[instrument(skip_all, fields(narrative_name))]
-    fn from_toml_str_with_db(
+    fn from_toml_str_with_db<C: ContentConnection>(
         s: &str,
         source_path: &Path,
         narrative_name: &str,
-        conn: &mut PgConnection,
+        conn: &mut C,
     ) -> Result<Self, NarrativeError> {
         use crate::toml_parser::{TomlNarrativeData, TomlNarrativeFile};
 
@@ -183,6 +183,33 @@ impl MultiNarrative {
     pub fn get_narrative(&self, name: &str) -> Option<&Narrative> {
         self.narratives.get(name)
     }
+
+    /// Iterate over every narrative in the file.
+    pub fn narratives(&self) -> impl Iterator<Item = &Narrative> {
+        self.narratives.values()
+    }
+
+    /// Names of all narratives defined in a TOML file.
+    ///
+    /// Useful for discovering narratives without knowing which one to activate.
+    #[instrument(skip_all, fields(path = %path.as_ref().display()))]
+    pub fn narrative_names<P: AsRef<Path>>(path: P) -> Result<Vec<String>, NarrativeError> {
+        use crate::toml_parser::{TomlNarrativeData, TomlNarrativeFile};
+
+        let content = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| NarrativeError::new(NarrativeErrorKind::FileRead(e.to_string())))?;
+        let toml_file: TomlNarrativeFile = toml::from_str(&content)
+            .map_err(|e| NarrativeError::new(NarrativeErrorKind::TomlParse(e.to_string())))?;
+
+        let mut names: Vec<String> = match &toml_file.narrative_data {
+            TomlNarrativeData::Multi { narrative } => narrative.keys().cloned().collect(),
+            TomlNarrativeData::Single { narrative, .. } => {
+                narrative.iter().map(|n| n.name.clone()).collect()
+            }
+        };
+        names.sort();
+        Ok(names)
+    }
 }
 
 impl NarrativeProvider for MultiNarrative {
@@ -0,0 +1,233 @@
+//! Slash command definitions attached to narratives.
+//!
+//! A narrative can expose itself as a chat slash command by adding a
+//! `slash_command` table next to its metadata:
+//!
+//! ```toml
+//! [narrative]
+//! name = "summarize"
+//! description = "Summarize recent channel activity"
+//!
+//! [narrative.slash_command]
+//! description = "Summarize a channel"
+//!
+//! [[narrative.slash_command.options]]
+//! name = "channel"
+//! description = "Channel to summarize"
+//! type = "channel"
+//! required = true
+//! ```
+//!
+//! When the command is invoked, each option is available to the narrative as
+//! an `${option:<name>}` template variable.
+
+use botticelli_error::{NarrativeError, NarrativeErrorKind};
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Maximum length of a command or option name.
+const MAX_NAME_LEN: usize = 32;
+/// Maximum length of a command or option description.
+const MAX_DESCRIPTION_LEN: usize = 100;
+/// Maximum number of options per command.
+const MAX_OPTIONS: usize = 25;
+
+/// Value type of a slash command option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SlashCommandOptionType {
+    /// Free-form text
+    String,
+    /// Whole number
+    Integer,
+    /// Floating point number
+    Number,
+    /// True/false
+    Boolean,
+    /// Channel picker (value is the channel ID)
+    Channel,
+    /// User picker (value is the user ID)
+    User,
+    /// Role picker (value is the role ID)
+    Role,
+}
+
+/// A single option of a slash command.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Getters)]
+pub struct SlashCommandOption {
+    /// Option name, used as `${option:<name>}` in templates
+    name: String,
+    /// Help text shown by the client
+    description: String,
+    /// Value type
+    #[serde(rename = "type", default = "default_option_type")]
+    kind: SlashCommandOptionType,
+    /// Whether the user must supply a value
+    #[serde(default)]
+    required: bool,
+    /// Fixed set of allowed values (string options only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    choices: Vec<String>,
+}
+
+fn default_option_type() -> SlashCommandOptionType {
+    SlashCommandOptionType::String
+}
+
+impl SlashCommandOption {
+    /// Creates a new option.
+    pub fn new(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        kind: SlashCommandOptionType,
+        required: bool,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            kind,
+            required,
+            choices: Vec::new(),
+        }
+    }
+
+    /// Restricts the option to a fixed set of values.
+    pub fn with_choices(mut self, choices: Vec<String>) -> Self {
+        self.choices = choices;
+        self
+    }
+}
+
+/// Slash command exposed by a narrative.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Getters)]
+pub struct SlashCommandConfig {
+    /// Command name (defaults to the narrative name)
+    #[serde(default)]
+    name: Option<String>,
+    /// Help text shown by the client
+    description: String,
+    /// Command options
+    #[serde(default)]
+    options: Vec<SlashCommandOption>,
+    /// Only show the reply to the invoking user
+    #[serde(default)]
+    ephemeral: bool,
+}
+
+impl SlashCommandConfig {
+    /// Creates a new slash command configuration.
+    pub fn new(description: impl Into<String>) -> Self {
+        Self {
+            name: None,
+            description: description.into(),
+            options: Vec::new(),
+            ephemeral: false,
+        }
+    }
+
+    /// Sets an explicit command name.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Adds an option.
+    pub fn with_option(mut self, option: SlashCommandOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Sets whether replies are only visible to the invoking user.
+    pub fn with_ephemeral(mut self, ephemeral: bool) -> Self {
+        self.ephemeral = ephemeral;
+        self
+    }
+
+    /// Command name, falling back to the narrative name.
+    pub fn command_name<'a>(&'a self, narrative_name: &'a str) -> &'a str {
+        self.name.as_deref().unwrap_or(narrative_name)
+    }
+
+    /// Check the definition against chat platform limits.
+    ///
+    /// Names must be 1-32 characters of lowercase letters, digits, `-` or `_`;
+    /// descriptions 1-100 characters; at most 25 options with unique names, and
+    /// required options must come before optional ones.
+    pub fn validate(&self, narrative_name: &str) -> Result<(), NarrativeError> {
+        let command = self.command_name(narrative_name);
+        validate_name(command, "Command")?;
+        validate_description(&self.description, command)?;
+
+        if self.options.len() > MAX_OPTIONS {
+            return Err(config_error(format!(
+                "Command '{}' has {} options (max {})",
+                command,
+                self.options.len(),
+                MAX_OPTIONS
+            )));
+        }
+
+        let mut seen = HashSet::new();
+        let mut optional_seen = false;
+        for option in &self.options {
+            validate_name(&option.name, "Option")?;
+            validate_description(&option.description, &option.name)?;
+
+            if !seen.insert(option.name.as_str()) {
+                return Err(config_error(format!(
+                    "Command '{}' has duplicate option '{}'",
+                    command, option.name
+                )));
+            }
+            if option.required && optional_seen {
+                return Err(config_error(format!(
+                    "Required option '{}' of command '{}' must come before optional options",
+                    option.name, command
+                )));
+            }
+            optional_seen |= !option.required;
+
+            if !option.choices.is_empty() && option.kind != SlashCommandOptionType::String {
+                return Err(config_error(format!(
+                    "Option '{}' of command '{}' has choices but is not a string option",
+                    option.name, command
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_name(name: &str, what: &str) -> Result<(), NarrativeError> {
+    let valid = !name.is_empty()
+        && name.chars().count() <= MAX_NAME_LEN
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(config_error(format!(
+            "{} name '{}' must be 1-{} lowercase letters, digits, '-' or '_'",
+            what, name, MAX_NAME_LEN
+        )))
+    }
+}
+
+fn validate_description(description: &str, owner: &str) -> Result<(), NarrativeError> {
+    let len = description.chars().count();
+    if len == 0 || len > MAX_DESCRIPTION_LEN {
+        return Err(config_error(format!(
+            "Description of '{}' must be 1-{} characters",
+            owner, MAX_DESCRIPTION_LEN
+        )));
+    }
+    Ok(())
+}
+
+fn config_error(message: String) -> NarrativeError {
+    NarrativeError::new(NarrativeErrorKind::ConfigurationError(message))
+}
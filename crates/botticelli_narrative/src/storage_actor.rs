@@ -6,40 +6,36 @@
 
 use async_trait::async_trait;
 use botticelli_database::{
-    ContentGenerationRepository, NewContentGenerationRow, PostgresContentGenerationRepository,
-    UpdateContentGenerationRow, create_content_table, create_inferred_table, infer_schema,
-    reflect_table_schema,
+    ContentConnection, NewContentGenerationRow, SqlDialect, UpdateContentGenerationRow,
+    create_content_table, create_inferred_table, infer_schema, reflect_table_schema,
+    with_pooled_connection,
 };
 use botticelli_error::BotticelliResult;
 use chrono::Utc;
-use diesel::prelude::*;
-use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::pg::PgConnection;
+use diesel::r2d2::{ConnectionManager, ManageConnection, Pool};
 use ractor::{Actor, ActorProcessingErr, ActorRef, RpcReplyPort};
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 
 /// Storage actor handling all database operations for content generation.
-pub struct StorageActor {
-    pool: Pool<ConnectionManager<PgConnection>>,
+///
+/// Works with any pool of [`ContentConnection`]s: PostgreSQL by default, or
+/// SQLite with the `sqlite` feature. Each message runs on a pooled
+/// connection off the async runtime.
+pub struct StorageActor<M: ManageConnection = ConnectionManager<PgConnection>> {
+    pool: Pool<M>,
 }
 
-impl StorageActor {
+impl<M> StorageActor<M>
+where
+    M: ManageConnection,
+    M::Connection: ContentConnection,
+{
     /// Create a new storage actor with a connection pool.
-    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+    pub fn new(pool: Pool<M>) -> Self {
         Self { pool }
     }
-
-    /// Get a connection from the pool.
-    fn get_conn(
-        &self,
-    ) -> BotticelliResult<diesel::r2d2::PooledConnection<ConnectionManager<PgConnection>>> {
-        Ok(self.pool.get().map_err(|e| {
-            botticelli_error::BackendError::new(format!(
-                "Failed to get connection from pool: {}",
-                e
-            ))
-        })?)
-    }
 }
 
 /// Messages that the StorageActor can handle.
@@ -118,10 +114,14 @@ pub enum StorageMessage {
 pub struct StorageActorState;
 
 #[async_trait]
-impl Actor for StorageActor {
+impl<M> Actor for StorageActor<M>
+where
+    M: ManageConnection,
+    M::Connection: ContentConnection,
+{
     type Msg = StorageMessage;
     type State = StorageActorState;
-    type Arguments = Pool<ConnectionManager<PgConnection>>;
+    type Arguments = Pool<M>;
 
     async fn pre_start(
         &self,
@@ -154,8 +154,10 @@ impl Actor for StorageActor {
                 narrative_name,
                 reply,
             } => {
-                let result =
-                    self.handle_start_generation(table_name, narrative_file, narrative_name);
+                let result = with_pooled_connection(&self.pool, move |conn| {
+                    Self::handle_start_generation(conn, table_name, narrative_file, narrative_name)
+                })
+                .await;
                 let _ = reply.send(result);
             }
             StorageMessage::CreateTableFromTemplate {
@@ -165,12 +167,16 @@ impl Actor for StorageActor {
                 description,
                 reply,
             } => {
-                let result = self.handle_create_from_template(
-                    table_name,
-                    template,
-                    narrative_name,
-                    description,
-                );
+                let result = with_pooled_connection(&self.pool, move |conn| {
+                    Self::handle_create_from_template(
+                        conn,
+                        table_name,
+                        template,
+                        narrative_name,
+                        description,
+                    )
+                })
+                .await;
                 let _ = reply.send(result);
             }
             StorageMessage::CreateTableFromInference {
@@ -180,12 +186,16 @@ impl Actor for StorageActor {
                 description,
                 reply,
             } => {
-                let result = self.handle_create_from_inference(
-                    table_name,
-                    json_sample,
-                    narrative_name,
-                    description,
-                );
+                let result = with_pooled_connection(&self.pool, move |conn| {
+                    Self::handle_create_from_inference(
+                        conn,
+                        table_name,
+                        json_sample,
+                        narrative_name,
+                        description,
+                    )
+                })
+                .await;
                 let _ = reply.send(result);
             }
             StorageMessage::InsertContent {
@@ -196,13 +206,17 @@ impl Actor for StorageActor {
                 model,
                 reply,
             } => {
-                let result = self.handle_insert_content(
-                    table_name,
-                    json_data,
-                    narrative_name,
-                    act_name,
-                    model,
-                );
+                let result = with_pooled_connection(&self.pool, move |conn| {
+                    Self::handle_insert_content(
+                        conn,
+                        table_name,
+                        json_data,
+                        narrative_name,
+                        act_name,
+                        model,
+                    )
+                })
+                .await;
                 let _ = reply.send(result);
             }
             StorageMessage::CompleteGeneration {
@@ -213,13 +227,17 @@ impl Actor for StorageActor {
                 error_message,
                 reply,
             } => {
-                let result = self.handle_complete_generation(
-                    table_name,
-                    row_count,
-                    duration_ms,
-                    status,
-                    error_message,
-                );
+                let result = with_pooled_connection(&self.pool, move |conn| {
+                    Self::handle_complete_generation(
+                        conn,
+                        table_name,
+                        row_count,
+                        duration_ms,
+                        status,
+                        error_message,
+                    )
+                })
+                .await;
                 let _ = reply.send(result);
             }
         }
@@ -227,15 +245,18 @@ impl Actor for StorageActor {
     }
 }
 
-impl StorageActor {
+impl<M> StorageActor<M>
+where
+    M: ManageConnection,
+    M::Connection: ContentConnection,
+{
     fn handle_start_generation(
-        &self,
+        conn: &mut M::Connection,
         table_name: String,
         narrative_file: String,
         narrative_name: String,
     ) -> BotticelliResult<()> {
-        let mut conn = self.get_conn()?;
-        let mut repo = PostgresContentGenerationRepository::new(&mut conn);
+        let mut repo = conn.generations();
 
         let new_gen = NewContentGenerationRow {
             table_name: table_name.clone(),
@@ -259,14 +280,12 @@ impl StorageActor {
     }
 
     fn handle_create_from_template(
-        &self,
+        conn: &mut M::Connection,
         table_name: String,
         template: String,
         narrative_name: Option<String>,
         description: Option<String>,
     ) -> BotticelliResult<()> {
-        let mut conn = self.get_conn()?;
-
         tracing::debug!(
             template = %template,
             table = %table_name,
@@ -274,7 +293,7 @@ impl StorageActor {
         );
 
         create_content_table(
-            &mut conn,
+            conn,
             &table_name,
             &template,
             narrative_name.as_deref(),
@@ -286,14 +305,12 @@ impl StorageActor {
     }
 
     fn handle_create_from_inference(
-        &self,
+        conn: &mut M::Connection,
         table_name: String,
         json_sample: JsonValue,
         narrative_name: Option<String>,
         description: Option<String>,
     ) -> BotticelliResult<()> {
-        let mut conn = self.get_conn()?;
-
         tracing::debug!(table = %table_name, "Inferring schema from JSON");
 
         let schema = infer_schema(&json_sample)?;
@@ -305,7 +322,7 @@ impl StorageActor {
         );
 
         create_inferred_table(
-            &mut conn,
+            conn,
             &table_name,
             &schema,
             narrative_name.as_deref(),
@@ -317,17 +334,17 @@ impl StorageActor {
     }
 
     fn handle_insert_content(
-        &self,
+        conn: &mut M::Connection,
         table_name: String,
         json_data: JsonValue,
         narrative_name: String,
         act_name: String,
         model: Option<String>,
     ) -> BotticelliResult<()> {
-        let mut conn = self.get_conn()?;
+        let dialect = conn.dialect();
 
         // Query schema to get column types and constraints
-        let schema = reflect_table_schema(&mut conn, &table_name)?;
+        let schema = reflect_table_schema(conn, &table_name)?;
         let column_types: std::collections::HashMap<_, _> = schema
             .columns
             .iter()
@@ -355,7 +372,7 @@ impl StorageActor {
         for (key, value) in obj {
             if let Some((col_name, col_type)) = find_column_match(key, &column_types) {
                 columns.push(col_name.to_string());
-                values.push(json_value_to_sql(value, col_type));
+                values.push(json_value_to_sql(dialect, value, col_type));
                 provided_columns.insert(col_name);
             } else {
                 tracing::debug!(
@@ -406,7 +423,7 @@ impl StorageActor {
         }
 
         columns.push("generated_at".to_string());
-        values.push("NOW()".to_string());
+        values.push(dialect.now().to_string());
 
         // Execute INSERT
         let query = format!(
@@ -418,7 +435,7 @@ impl StorageActor {
 
         tracing::debug!(sql = %query, "Executing INSERT");
 
-        diesel::sql_query(&query).execute(&mut conn).map_err(|e| {
+        conn.execute_sql(&query, &[]).map_err(|e| {
             botticelli_error::BackendError::new(format!("Failed to insert content: {}", e))
         })?;
 
@@ -432,15 +449,14 @@ impl StorageActor {
     }
 
     fn handle_complete_generation(
-        &self,
+        conn: &mut M::Connection,
         table_name: String,
         row_count: Option<i32>,
         duration_ms: i32,
         status: String,
         error_message: Option<String>,
     ) -> BotticelliResult<()> {
-        let mut conn = self.get_conn()?;
-        let mut repo = PostgresContentGenerationRepository::new(&mut conn);
+        let mut repo = conn.generations();
 
         let update = UpdateContentGenerationRow {
             completed_at: Some(Utc::now()),
@@ -543,11 +559,23 @@ fn find_column_match<'a>(
 ///
 ///
 /// Converts a JSON value to SQL literal based on column type with best-effort coercion
-fn json_value_to_sql(value: &JsonValue, col_type: &str) -> String {
+fn json_value_to_sql(dialect: SqlDialect, value: &JsonValue, col_type: &str) -> String {
     use serde_json::Value;
 
     let col_type_lower = col_type.to_lowercase();
 
+    // SQLite stores arrays and documents as JSON text
+    if dialect == SqlDialect::Sqlite && matches!(col_type_lower.as_str(), "jsonb" | "json") {
+        return match value {
+            Value::Null => "NULL".to_string(),
+            // Keep strings that already hold JSON; quote the rest
+            Value::String(s) if serde_json::from_str::<Value>(s).is_ok() => {
+                format!("'{}'", s.replace('\'', "''"))
+            }
+            _ => dialect.json_literal(value),
+        };
+    }
+
     // Handle PostgreSQL array types (e.g., _text = text[], _int4 = integer[])
     if col_type_lower.starts_with('_') {
         match value {
@@ -4,7 +4,7 @@
 //! into our domain types (ActConfig, Input, etc.).
 
 use crate::ActConfig;
-use botticelli_core::{HistoryRetention, Input, MediaSource};
+use botticelli_core::{HistoryRetention, Input, MediaSource, WhereClause};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -161,6 +161,9 @@ pub struct TomlNarrative {
     /// Optional budget multipliers
     #[serde(default)]
     pub budget: Option<botticelli_core::BudgetConfig>,
+    /// Optional slash command exposing this narrative
+    #[serde(default)]
+    pub slash_command: Option<crate::SlashCommandConfig>,
 }
 
 /// Intermediate structure for deserializing individual [narratives.name] sections.
@@ -194,6 +197,9 @@ pub struct TomlNarrativeDefinition {
     /// Optional budget multipliers
     #[serde(default)]
     pub budget: Option<botticelli_core::BudgetConfig>,
+    /// Optional slash command exposing this narrative
+    #[serde(default)]
+    pub slash_command: Option<crate::SlashCommandConfig>,
     /// Table of contents for this narrative (just an array of act names)
     pub toc: Vec<String>,
     /// Optional narrative-specific acts (override shared acts)
@@ -237,7 +243,7 @@ pub struct TomlTableDefinition {
     pub table_name: String,
     pub columns: Option<Vec<String>>,
     #[serde(rename = "where")]
-    pub where_clause: Option<String>,
+    pub where_clause: Option<WhereClause>,
     pub limit: Option<u32>,
     pub offset: Option<u32>,
     pub order_by: Option<String>,
@@ -356,7 +362,7 @@ pub struct TomlInput {
     pub table_name: Option<String>,
     pub columns: Option<Vec<String>>,
     #[serde(rename = "where")]
-    pub where_clause: Option<String>,
+    pub where_clause: Option<WhereClause>,
     pub limit: Option<u32>,
     pub offset: Option<u32>,
     pub order_by: Option<String>,
@@ -540,6 +546,7 @@ impl TomlNarrativeFile {
                     temperature: def.temperature,
                     max_tokens: def.max_tokens,
                     budget: def.budget.clone(),
+                    slash_command: def.slash_command.clone(),
                 };
 
                 // Merge shared acts with definition-specific acts
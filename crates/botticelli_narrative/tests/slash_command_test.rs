@@ -0,0 +1,211 @@
+//! Tests for narrative slash command definitions and template variables.
+
+use async_trait::async_trait;
+use botticelli_core::{GenerateRequest, GenerateResponse, Input, Output};
+use botticelli_error::{BotticelliResult, NarrativeErrorKind};
+use botticelli_interface::BotticelliDriver;
+use botticelli_narrative::{MultiNarrative, Narrative, NarrativeExecutor, SlashCommandOptionType};
+use botticelli_rate_limit::RateLimitConfig;
+use std::collections::HashMap;
+
+const SUMMARIZE: &str = r#"
+[narrative]
+name = "summarize"
+description = "Summarize recent channel activity"
+
+[narrative.slash_command]
+description = "Summarize a channel"
+ephemeral = true
+
+[[narrative.slash_command.options]]
+name = "channel"
+description = "Channel to summarize"
+type = "channel"
+required = true
+
+[[narrative.slash_command.options]]
+name = "style"
+description = "Summary style"
+choices = ["brief", "detailed"]
+
+[toc]
+order = ["summarize"]
+
+[acts]
+summarize = "Write a ${option:style} summary of channel ${option:channel}."
+"#;
+
+/// Driver that echoes the prompt text back as its response.
+struct EchoDriver;
+
+#[async_trait]
+impl BotticelliDriver for EchoDriver {
+    async fn generate(&self, req: &GenerateRequest) -> BotticelliResult<GenerateResponse> {
+        let text = req
+            .messages()
+            .iter()
+            .flat_map(|m| m.content().iter())
+            .filter_map(|input| match input {
+                Input::Text(text) => Some(text.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        Ok(GenerateResponse {
+            outputs: vec![Output::Text(text)],
+        })
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "echo"
+    }
+
+    fn model_name(&self) -> &str {
+        "echo-model"
+    }
+
+    fn rate_limits(&self) -> &RateLimitConfig {
+        static RATE_LIMIT: std::sync::OnceLock<RateLimitConfig> = std::sync::OnceLock::new();
+        RATE_LIMIT.get_or_init(|| RateLimitConfig {
+            requests_per_minute: u64::MAX,
+            tokens_per_minute: u64::MAX,
+            requests_per_day: u64::MAX,
+            tokens_per_day: u64::MAX,
+        })
+    }
+}
+
+#[test]
+fn test_slash_command_parsed_from_narrative() {
+    let narrative: Narrative = SUMMARIZE.parse().unwrap();
+    let command = narrative.metadata().slash_command().as_ref().unwrap();
+
+    assert_eq!(command.command_name("summarize"), "summarize");
+    assert!(*command.ephemeral());
+    assert_eq!(command.options().len(), 2);
+    assert_eq!(
+        *command.options()[0].kind(),
+        SlashCommandOptionType::Channel
+    );
+    assert_eq!(*command.options()[1].kind(), SlashCommandOptionType::String);
+    assert_eq!(command.options()[1].choices().len(), 2);
+}
+
+#[test]
+fn test_narrative_without_slash_command() {
+    let narrative: Narrative = r#"
+        [narrative]
+        name = "plain"
+        description = "No command"
+
+        [toc]
+        order = ["one"]
+
+        [acts]
+        one = "Hello"
+    "#
+    .parse()
+    .unwrap();
+
+    assert!(narrative.metadata().slash_command().is_none());
+}
+
+#[test]
+fn test_invalid_command_name_rejected() {
+    let toml = SUMMARIZE.replace(
+        "description = \"Summarize a channel\"",
+        "name = \"Summarize Channel\"\ndescription = \"Summarize a channel\"",
+    );
+
+    let err = toml.parse::<Narrative>().unwrap_err();
+    assert!(matches!(
+        err.kind,
+        NarrativeErrorKind::ConfigurationError(_)
+    ));
+}
+
+#[test]
+fn test_required_option_after_optional_rejected() {
+    let toml = SUMMARIZE.replace("required = true", "required = false")
+        + r#"
+[[narrative.slash_command.options]]
+name = "since"
+description = "Start date"
+required = true
+"#;
+
+    assert!(toml.parse::<Narrative>().is_err());
+}
+
+#[test]
+fn test_multi_narrative_slash_commands_and_names() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("commands.toml");
+    std::fs::write(
+        &path,
+        r#"
+[narrative.weekly]
+description = "Weekly digest"
+toc = ["digest"]
+
+[narrative.weekly.slash_command]
+name = "digest"
+description = "Post the weekly digest"
+
+[narrative.helper]
+toc = ["digest"]
+
+[acts]
+digest = "Write a digest"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        MultiNarrative::narrative_names(&path).unwrap(),
+        vec!["helper".to_string(), "weekly".to_string()]
+    );
+
+    let multi = MultiNarrative::from_file(&path, "weekly").unwrap();
+    let weekly = multi.get_narrative("weekly").unwrap();
+    let command = weekly.metadata().slash_command().as_ref().unwrap();
+    assert_eq!(command.command_name("weekly"), "digest");
+    assert!(
+        multi
+            .get_narrative("helper")
+            .unwrap()
+            .metadata()
+            .slash_command()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_variables_substituted_into_prompts() {
+    let narrative: Narrative = SUMMARIZE.parse().unwrap();
+    let executor = NarrativeExecutor::new(EchoDriver).with_variables(HashMap::from([
+        ("option:channel".to_string(), "1234567890".to_string()),
+        ("option:style".to_string(), "brief".to_string()),
+    ]));
+
+    let execution = executor.execute(&narrative).await.unwrap();
+    assert_eq!(
+        execution.act_executions[0].response,
+        "Write a brief summary of channel 1234567890."
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_placeholders_left_in_prompts() {
+    let narrative: Narrative = SUMMARIZE.parse().unwrap();
+    let executor = NarrativeExecutor::new(EchoDriver).with_variables(HashMap::from([(
+        "option:channel".to_string(),
+        "42".to_string(),
+    )]));
+
+    let execution = executor.execute(&narrative).await.unwrap();
+    assert_eq!(
+        execution.act_executions[0].response,
+        "Write a ${option:style} summary of channel 42."
+    );
+}
@@ -0,0 +1,88 @@
+//! Tests for the storage actor on a SQLite database file.
+
+#![cfg(feature = "sqlite")]
+
+use botticelli_database::{create_sqlite_pool, get_content_by_id, list_content};
+use botticelli_error::BotticelliResult;
+use botticelli_narrative::{StorageActor, StorageMessage};
+use ractor::rpc::CallResult;
+use ractor::{Actor, ActorRef, RpcReplyPort};
+use serde_json::json;
+
+async fn call(
+    actor: &ActorRef<StorageMessage>,
+    message: impl FnOnce(RpcReplyPort<BotticelliResult<()>>) -> StorageMessage,
+) {
+    match actor.call(message, None).await.expect("actor should reply") {
+        CallResult::Success(result) => result.expect("storage operation should succeed"),
+        other => panic!("Unexpected call result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_generate_into_sqlite_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let url = dir.path().join("botticelli.db").display().to_string();
+    let pool = create_sqlite_pool(&url).unwrap();
+
+    let (actor, handle) = Actor::spawn(None, StorageActor::new(pool.clone()), pool.clone())
+        .await
+        .unwrap();
+
+    call(&actor, |reply| StorageMessage::StartGeneration {
+        table_name: "quotes".to_string(),
+        narrative_file: "quotes.toml".to_string(),
+        narrative_name: "quotes".to_string(),
+        reply,
+    })
+    .await;
+
+    let sample = json!({"id": 1, "text": "It's a trap", "featured": true, "topics": ["film"]});
+    call(&actor, |reply| StorageMessage::CreateTableFromInference {
+        table_name: "quotes".to_string(),
+        json_sample: sample.clone(),
+        narrative_name: Some("quotes".to_string()),
+        description: None,
+        reply,
+    })
+    .await;
+
+    call(&actor, |reply| StorageMessage::InsertContent {
+        table_name: "quotes".to_string(),
+        json_data: sample,
+        narrative_name: "quotes".to_string(),
+        act_name: "generate".to_string(),
+        model: Some("test-model".to_string()),
+        reply,
+    })
+    .await;
+
+    call(&actor, |reply| StorageMessage::CompleteGeneration {
+        table_name: "quotes".to_string(),
+        row_count: Some(1),
+        duration_ms: 10,
+        status: "success".to_string(),
+        error_message: None,
+        reply,
+    })
+    .await;
+
+    actor.stop(None);
+    handle.await.unwrap();
+
+    // A fresh connection sees everything the actor wrote
+    let mut conn = pool.get().unwrap();
+    let rows = list_content(&mut conn, "quotes", Some("generated"), 10).unwrap();
+    assert_eq!(rows.len(), 1);
+
+    let row = get_content_by_id(&mut conn, "quotes", 1).unwrap();
+    assert_eq!(row["text"], "It's a trap");
+    assert_eq!(row["featured"], true);
+    assert_eq!(row["topics"], json!(["film"]));
+    assert_eq!(row["generation_model"], "test-model");
+
+    let mut repo = botticelli_database::ContentConnection::generations(&mut conn);
+    let generation = repo.get_last_successful().unwrap().unwrap();
+    assert_eq!(generation.table_name(), "quotes");
+    assert_eq!(generation.row_count(), &Some(1));
+}
@@ -0,0 +1,94 @@
+//! Tests for parsing `where` filters on table references.
+
+use botticelli_core::{FilterCondition, FilterOperators, FilterValue, Input, WhereClause};
+use botticelli_narrative::Narrative;
+use std::str::FromStr;
+
+fn table_where(where_line: &str) -> Option<WhereClause> {
+    let toml = format!(
+        r#"
+[narrative]
+name = "filtered"
+description = "Table filter"
+
+[toc]
+order = ["review"]
+
+[tables.approved]
+table_name = "posts"
+{}
+
+[acts]
+review = ["tables.approved", "Summarize these posts"]
+"#,
+        where_line
+    );
+
+    let narrative = Narrative::from_str(&toml).expect("narrative should parse");
+    let (_, act) = narrative.ordered_acts()[0];
+    match &act.inputs()[0] {
+        Input::Table { where_clause, .. } => where_clause.clone(),
+        other => panic!("Expected table input, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_structured_where_parses() {
+    let where_clause = table_where(
+        r#"where = { status = "approved", rating = { gte = 4 }, tags = { contains = "lore" } }"#,
+    );
+
+    let Some(WhereClause::Filter(filter)) = where_clause else {
+        panic!("Expected structured filter, got {:?}", where_clause);
+    };
+    let conditions: Vec<_> = filter.conditions().collect();
+    assert_eq!(
+        conditions,
+        vec![
+            (
+                "rating",
+                &FilterCondition::Operators(Box::new(FilterOperators {
+                    gte: Some(FilterValue::Integer(4)),
+                    ..Default::default()
+                }))
+            ),
+            ("status", &FilterCondition::Equals("approved".into())),
+            (
+                "tags",
+                &FilterCondition::Operators(Box::new(FilterOperators {
+                    contains: Some("lore".into()),
+                    ..Default::default()
+                }))
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_raw_where_still_parses() {
+    assert_eq!(
+        table_where(r#"where = "status = 'approved'""#),
+        Some(WhereClause::Sql("status = 'approved'".to_string()))
+    );
+}
+
+#[test]
+fn test_unknown_operator_rejected() {
+    let toml = r#"
+[narrative]
+name = "filtered"
+description = "Table filter"
+
+[toc]
+order = ["review"]
+
+[tables.approved]
+table_name = "posts"
+where = { rating = { between = [1, 5] } }
+
+[acts]
+review = ["tables.approved"]
+"#;
+
+    assert!(Narrative::from_str(toml).is_err());
+}
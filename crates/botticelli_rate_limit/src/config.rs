@@ -449,4 +449,38 @@ impl BotticelliConfig {
 
         Some(tier_config)
     }
+
+    /// Get the default-tier limits that apply to a model.
+    ///
+    /// The provider is the one whose default tier lists the model, or else
+    /// the one whose name prefixes it (`gemini-2.0-pro` belongs to
+    /// `gemini`). Limits are resolved as clients resolve them: the default
+    /// tier, then [`TierConfig::for_model`].
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if no provider claims the model.
+    #[instrument(skip(self))]
+    pub fn tier_for_model(&self, model: &str) -> Option<TierConfig> {
+        let mut providers: Vec<&String> = self.providers.keys().collect();
+        providers.sort();
+
+        let provider = providers
+            .iter()
+            .find(|provider| {
+                let config = &self.providers[provider.as_str()];
+                config
+                    .tiers
+                    .get(&config.default_tier)
+                    .is_some_and(|tier| tier.models.contains_key(model))
+            })
+            .or_else(|| {
+                providers
+                    .iter()
+                    .find(|provider| model.starts_with(provider.as_str()))
+            })?;
+
+        self.get_tier(provider, None)
+            .map(|tier| tier.for_model(model))
+    }
 }
@@ -96,3 +96,20 @@ tpm = 999_000
     assert_eq!(tier.rpm, Some(42));
     assert_eq!(tier.tpm, Some(999_000));
 }
+
+#[test]
+fn test_tier_for_model() {
+    let config = BotticelliConfig::load().unwrap();
+
+    // Listed models get their overrides
+    let pro = config.tier_for_model("gemini-2.5-pro").unwrap();
+    assert_eq!(pro.rpm, Some(2));
+    assert_eq!(pro.rpd, Some(50));
+
+    // Unlisted models fall back to the provider named by their prefix
+    let default = config.get_tier("gemini", None).unwrap();
+    let unlisted = config.tier_for_model("gemini-9-ultra").unwrap();
+    assert_eq!(unlisted.rpm, default.rpm);
+
+    assert!(config.tier_for_model("mystery-model").is_none());
+}
@@ -0,0 +1,57 @@
+//! Environment variable secret provider.
+
+use crate::{SecretProvider, SecretString, validate_secret_name};
+use botticelli_error::SecretResult;
+
+/// Reads secrets from environment variables.
+///
+/// A secret name maps to an upper-cased variable with `-` and `.` replaced by
+/// `_`, optionally prefixed: `discord_token` → `DISCORD_TOKEN`, or
+/// `BOTTICELLI_DISCORD_TOKEN` with prefix `BOTTICELLI_`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvSecretProvider {
+    prefix: Option<String>,
+}
+
+impl EnvSecretProvider {
+    /// Create a provider that reads unprefixed variables.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a provider that prepends `prefix` to every variable name.
+    pub fn with_prefix(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: Some(prefix.into()),
+        }
+    }
+
+    /// Environment variable name for a secret.
+    pub fn variable_name(&self, name: &str) -> String {
+        let base: String = name
+            .chars()
+            .map(|c| match c {
+                '-' | '.' => '_',
+                c => c.to_ascii_uppercase(),
+            })
+            .collect();
+        match &self.prefix {
+            Some(prefix) => format!("{}{}", prefix, base),
+            None => base,
+        }
+    }
+}
+
+impl SecretProvider for EnvSecretProvider {
+    fn provider_name(&self) -> &'static str {
+        "env"
+    }
+
+    fn get(&self, name: &str) -> SecretResult<Option<SecretString>> {
+        validate_secret_name(name)?;
+        Ok(std::env::var(self.variable_name(name))
+            .ok()
+            .filter(|value| !value.is_empty())
+            .map(SecretString::new))
+    }
+}
@@ -0,0 +1,63 @@
+//! File-per-secret provider for Docker and Podman secrets.
+
+use crate::{SecretProvider, SecretString, validate_secret_name};
+use botticelli_error::{SecretError, SecretErrorKind, SecretResult};
+use std::path::{Path, PathBuf};
+
+/// Directory where Docker and Podman mount secrets by default.
+pub const DEFAULT_SECRETS_DIR: &str = "/run/secrets";
+
+/// Reads each secret from a file named after it inside a directory.
+///
+/// Trailing newlines are stripped, since `echo token > file` is the usual way
+/// these files get created.
+#[derive(Debug, Clone)]
+pub struct FileSecretProvider {
+    dir: PathBuf,
+}
+
+impl FileSecretProvider {
+    /// Create a provider reading from `dir`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Directory secrets are read from.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+impl Default for FileSecretProvider {
+    fn default() -> Self {
+        Self::new(DEFAULT_SECRETS_DIR)
+    }
+}
+
+impl SecretProvider for FileSecretProvider {
+    fn provider_name(&self) -> &'static str {
+        "file"
+    }
+
+    fn get(&self, name: &str) -> SecretResult<Option<SecretString>> {
+        validate_secret_name(name)?;
+        let path = self.dir.join(name);
+
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => {
+                let value = contents.trim_end_matches(['\r', '\n']);
+                if value.is_empty() {
+                    Ok(None)
+                } else {
+                    Ok(Some(SecretString::new(value)))
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(SecretError::new(SecretErrorKind::Io(format!(
+                "Failed to read {}: {}",
+                path.display(),
+                e
+            )))),
+        }
+    }
+}
@@ -0,0 +1,280 @@
+//! Passphrase-encrypted local keystore.
+//!
+//! The keystore is a JSON file holding one ChaCha20-Poly1305 ciphertext per
+//! secret. The key is derived from a passphrase with Argon2id and a random salt
+//! stored alongside the entries. Each entry is bound to its name through the
+//! AEAD associated data, so ciphertexts cannot be swapped between names.
+
+use crate::{SecretProvider, SecretString, validate_secret_name};
+use argon2::Argon2;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use botticelli_error::{SecretError, SecretErrorKind, SecretResult};
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload, rand_core::RngCore};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tracing::{debug, instrument};
+
+const KEYSTORE_VERSION: u32 = 1;
+const KDF_NAME: &str = "argon2id";
+const SALT_LEN: usize = 16;
+const VERIFIER_AAD: &[u8] = b"botticelli-keystore-verifier";
+const VERIFIER_PLAINTEXT: &[u8] = b"botticelli";
+
+/// Encrypted value stored in the keystore file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EncryptedEntry {
+    nonce: String,
+    ciphertext: String,
+}
+
+/// On-disk keystore layout.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct KeystoreFile {
+    version: u32,
+    kdf: String,
+    salt: String,
+    verifier: EncryptedEntry,
+    #[serde(default)]
+    entries: BTreeMap<String, EncryptedEntry>,
+}
+
+/// Secret provider backed by an encrypted keystore file.
+pub struct KeystoreSecretProvider {
+    path: PathBuf,
+    cipher: ChaCha20Poly1305,
+    salt: Vec<u8>,
+    verifier: EncryptedEntry,
+    entries: BTreeMap<String, EncryptedEntry>,
+}
+
+impl std::fmt::Debug for KeystoreSecretProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeystoreSecretProvider")
+            .field("path", &self.path)
+            .field("entries", &self.entries.keys().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl KeystoreSecretProvider {
+    /// Create a new, empty keystore at `path`.
+    ///
+    /// Nothing is written until [`save`](Self::save) is called.
+    pub fn create(path: impl Into<PathBuf>, passphrase: &str) -> SecretResult<Self> {
+        let mut salt = vec![0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let cipher = derive_cipher(passphrase, &salt)?;
+        let verifier = encrypt(&cipher, VERIFIER_AAD, VERIFIER_PLAINTEXT)?;
+
+        Ok(Self {
+            path: path.into(),
+            cipher,
+            salt,
+            verifier,
+            entries: BTreeMap::new(),
+        })
+    }
+
+    /// Open an existing keystore, failing if the passphrase is wrong.
+    #[instrument(skip(passphrase), fields(path = %path.as_ref().display()))]
+    pub fn open(path: impl AsRef<Path>, passphrase: &str) -> SecretResult<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            SecretError::new(SecretErrorKind::Io(format!(
+                "Failed to read {}: {}",
+                path.display(),
+                e
+            )))
+        })?;
+
+        let file: KeystoreFile = serde_json::from_str(&contents)
+            .map_err(|e| SecretError::new(SecretErrorKind::InvalidFormat(e.to_string())))?;
+
+        if file.version != KEYSTORE_VERSION || file.kdf != KDF_NAME {
+            return Err(SecretError::new(SecretErrorKind::InvalidFormat(format!(
+                "Unsupported keystore version {} ({})",
+                file.version, file.kdf
+            ))));
+        }
+
+        let salt = decode(&file.salt)?;
+        let cipher = derive_cipher(passphrase, &salt)?;
+        decrypt(&cipher, VERIFIER_AAD, &file.verifier).map_err(|_| {
+            SecretError::new(SecretErrorKind::Decryption(
+                "Wrong passphrase or corrupted keystore".to_string(),
+            ))
+        })?;
+
+        debug!(entries = file.entries.len(), "Keystore opened");
+        Ok(Self {
+            path: path.to_path_buf(),
+            cipher,
+            salt,
+            verifier: file.verifier,
+            entries: file.entries,
+        })
+    }
+
+    /// Open the keystore at `path`, creating an empty one if it does not exist.
+    pub fn open_or_create(path: impl AsRef<Path>, passphrase: &str) -> SecretResult<Self> {
+        let path = path.as_ref();
+        if path.exists() {
+            Self::open(path, passphrase)
+        } else {
+            Self::create(path, passphrase)
+        }
+    }
+
+    /// Path of the keystore file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Names of all stored secrets.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    /// Encrypt and store a secret, replacing any previous value.
+    pub fn set(&mut self, name: &str, value: &str) -> SecretResult<()> {
+        validate_secret_name(name)?;
+        let entry = encrypt(&self.cipher, name.as_bytes(), value.as_bytes())?;
+        self.entries.insert(name.to_string(), entry);
+        Ok(())
+    }
+
+    /// Remove a secret, returning whether it existed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.entries.remove(name).is_some()
+    }
+
+    /// Write the keystore to disk.
+    ///
+    /// The file is written to a temporary sibling and renamed into place, and on
+    /// Unix it is created with mode `0600`.
+    #[instrument(skip(self), fields(path = %self.path.display()))]
+    pub fn save(&self) -> SecretResult<()> {
+        let file = KeystoreFile {
+            version: KEYSTORE_VERSION,
+            kdf: KDF_NAME.to_string(),
+            salt: STANDARD.encode(&self.salt),
+            verifier: self.verifier.clone(),
+            entries: self.entries.clone(),
+        };
+        let json = serde_json::to_string_pretty(&file)
+            .map_err(|e| SecretError::new(SecretErrorKind::Encryption(e.to_string())))?;
+
+        let io_err = |e: std::io::Error| {
+            SecretError::new(SecretErrorKind::Io(format!(
+                "Failed to write {}: {}",
+                self.path.display(),
+                e
+            )))
+        };
+
+        let tmp = self.path.with_extension("tmp");
+        write_private(&tmp, json.as_bytes()).map_err(io_err)?;
+        std::fs::rename(&tmp, &self.path).map_err(io_err)?;
+
+        debug!(entries = self.entries.len(), "Keystore saved");
+        Ok(())
+    }
+}
+
+impl SecretProvider for KeystoreSecretProvider {
+    fn provider_name(&self) -> &'static str {
+        "keystore"
+    }
+
+    fn get(&self, name: &str) -> SecretResult<Option<SecretString>> {
+        let Some(entry) = self.entries.get(name) else {
+            return Ok(None);
+        };
+
+        let plaintext = decrypt(&self.cipher, name.as_bytes(), entry)?;
+        let value = String::from_utf8(plaintext).map_err(|_| {
+            SecretError::new(SecretErrorKind::Decryption(format!(
+                "Secret '{}' is not valid UTF-8",
+                name
+            )))
+        })?;
+        Ok(Some(SecretString::new(value)))
+    }
+}
+
+fn derive_cipher(passphrase: &str, salt: &[u8]) -> SecretResult<ChaCha20Poly1305> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| SecretError::new(SecretErrorKind::Encryption(e.to_string())))?;
+    Ok(ChaCha20Poly1305::new(Key::from_slice(&key)))
+}
+
+fn encrypt(
+    cipher: &ChaCha20Poly1305,
+    aad: &[u8],
+    plaintext: &[u8],
+) -> SecretResult<EncryptedEntry> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: plaintext,
+                aad,
+            },
+        )
+        .map_err(|e| SecretError::new(SecretErrorKind::Encryption(e.to_string())))?;
+
+    Ok(EncryptedEntry {
+        nonce: STANDARD.encode(nonce),
+        ciphertext: STANDARD.encode(ciphertext),
+    })
+}
+
+fn decrypt(cipher: &ChaCha20Poly1305, aad: &[u8], entry: &EncryptedEntry) -> SecretResult<Vec<u8>> {
+    let nonce = decode(&entry.nonce)?;
+    if nonce.len() != 12 {
+        return Err(SecretError::new(SecretErrorKind::InvalidFormat(
+            "Nonce must be 12 bytes".to_string(),
+        )));
+    }
+    let ciphertext = decode(&entry.ciphertext)?;
+
+    cipher
+        .decrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: &ciphertext,
+                aad,
+            },
+        )
+        .map_err(|e| SecretError::new(SecretErrorKind::Decryption(e.to_string())))
+}
+
+fn decode(value: &str) -> SecretResult<Vec<u8>> {
+    STANDARD
+        .decode(value)
+        .map_err(|e| SecretError::new(SecretErrorKind::InvalidFormat(e.to_string())))
+}
+
+#[cfg(unix)]
+fn write_private(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    file.write_all(contents)
+}
+
+#[cfg(not(unix))]
+fn write_private(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    std::fs::write(path, contents)
+}
@@ -0,0 +1,54 @@
+//! Secret providers and log redaction for Botticelli.
+//!
+//! Bot tokens and API keys used to be read straight from environment variables.
+//! This crate puts those reads behind a [`SecretProvider`] trait so deployments can
+//! choose where credentials live:
+//!
+//! - **Environment** ([`EnvSecretProvider`]): `discord_token` → `DISCORD_TOKEN`
+//! - **Secret files** ([`FileSecretProvider`]): Docker/Podman secrets mounted at `/run/secrets`
+//! - **Encrypted keystore** ([`KeystoreSecretProvider`]): a local file encrypted with a passphrase
+//!
+//! Configuration values refer to secrets as `secret:name`, which
+//! [`resolve_secret_reference`] replaces with the value from the first provider
+//! that has it. Every secret resolved this way is registered with the redaction
+//! registry, so [`redact`] and [`RedactingMakeWriter`] can scrub it from logs and
+//! persisted execution records.
+//!
+//! # Example
+//!
+//! ```rust
+//! use botticelli_secrets::{EnvSecretProvider, SecretResolver};
+//!
+//! # unsafe { std::env::set_var("EXAMPLE_API_KEY", "abc123xyz") };
+//! let resolver = SecretResolver::new().with_provider(EnvSecretProvider::new());
+//!
+//! let key = resolver.resolve_reference("secret:example_api_key").unwrap();
+//! assert_eq!(key, "abc123xyz");
+//!
+//! // Plain values pass through untouched
+//! assert_eq!(resolver.resolve_reference("literal").unwrap(), "literal");
+//!
+//! // Resolved secrets are scrubbed from text
+//! assert_eq!(botticelli_secrets::redact("key=abc123xyz"), "key=[REDACTED]");
+//! ```
+
+mod env;
+mod file;
+mod keystore;
+mod provider;
+mod redact;
+mod resolver;
+
+pub use env::EnvSecretProvider;
+pub use file::{DEFAULT_SECRETS_DIR, FileSecretProvider};
+pub use keystore::KeystoreSecretProvider;
+pub use provider::{SecretProvider, SecretString, validate_secret_name};
+pub use redact::{
+    MIN_REDACTED_LEN, REDACTED, RedactingMakeWriter, RedactingWriter, redact, redact_json,
+    register_secret,
+};
+pub use resolver::{
+    SECRET_REFERENCE_PREFIX, SecretResolver, default_resolver, get_secret, resolve_secret_reference,
+};
+
+pub use botticelli_error::{SecretError, SecretErrorKind, SecretResult};
@@ -0,0 +1,77 @@
+//! Secret provider trait and secret value wrapper.
+
+use botticelli_error::{SecretError, SecretErrorKind, SecretResult};
+
+/// A secret value that never prints itself.
+///
+/// `Debug` output is redacted so secrets can sit inside structs that derive
+/// `Debug` without leaking into logs. Use [`SecretString::expose`] at the point
+/// where the raw value is actually needed.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretString(String);
+
+impl SecretString {
+    /// Wrap a secret value.
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Access the raw secret value.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Consume the wrapper and return the raw secret value.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl std::fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SecretString([REDACTED])")
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+/// Source of named secrets.
+///
+/// Names are lowercase identifiers such as `discord_token` or `gemini_api_key`;
+/// each provider maps them onto its own storage (environment variable, file,
+/// keystore entry).
+pub trait SecretProvider: Send + Sync {
+    /// Short provider name used in logs (e.g. "env", "file", "keystore").
+    fn provider_name(&self) -> &'static str;
+
+    /// Look up a secret by name.
+    ///
+    /// Returns `Ok(None)` when this provider does not have the secret, so the
+    /// resolver can fall through to the next provider.
+    fn get(&self, name: &str) -> SecretResult<Option<SecretString>>;
+}
+
+/// Check that a secret name is non-empty and only uses `[A-Za-z0-9_.-]`.
+///
+/// Names double as file names for [`FileSecretProvider`](crate::FileSecretProvider),
+/// so path separators and `..` are rejected.
+pub fn validate_secret_name(name: &str) -> SecretResult<()> {
+    let valid = !name.is_empty()
+        && name != "."
+        && name != ".."
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'));
+
+    if valid {
+        Ok(())
+    } else {
+        Err(SecretError::new(SecretErrorKind::InvalidReference(
+            name.to_string(),
+        )))
+    }
+}
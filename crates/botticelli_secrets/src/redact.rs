@@ -0,0 +1,123 @@
+//! Redaction of known secret values from text.
+//!
+//! Secrets resolved through [`SecretResolver`](crate::SecretResolver) are
+//! registered here automatically. [`redact`] replaces every registered value
+//! with [`REDACTED`]; [`RedactingMakeWriter`] applies the same scrubbing to
+//! formatted `tracing` output.
+
+use serde_json::Value as JsonValue;
+use std::borrow::Cow;
+use std::io::{self, Write};
+use std::sync::{LazyLock, RwLock};
+use tracing_subscriber::fmt::MakeWriter;
+
+/// Placeholder written in place of a secret value.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Values shorter than this are not registered.
+///
+/// Redacting very short strings would mangle unrelated text far more often
+/// than it would hide anything sensitive.
+pub const MIN_REDACTED_LEN: usize = 6;
+
+/// Registered secret values, longest first so overlapping values redact fully.
+static SECRETS: LazyLock<RwLock<Vec<String>>> = LazyLock::new(|| RwLock::new(Vec::new()));
+
+/// Register a value to be scrubbed by [`redact`].
+pub fn register_secret(value: &str) {
+    if value.len() < MIN_REDACTED_LEN {
+        return;
+    }
+
+    let mut secrets = SECRETS.write().unwrap_or_else(|e| e.into_inner());
+    if secrets.iter().any(|s| s == value) {
+        return;
+    }
+    secrets.push(value.to_string());
+    secrets.sort_by_key(|s| std::cmp::Reverse(s.len()));
+}
+
+/// Replace every registered secret in `text` with [`REDACTED`].
+pub fn redact(text: &str) -> Cow<'_, str> {
+    let secrets = SECRETS.read().unwrap_or_else(|e| e.into_inner());
+
+    let mut result = Cow::Borrowed(text);
+    for secret in secrets.iter() {
+        if result.contains(secret.as_str()) {
+            result = Cow::Owned(result.replace(secret.as_str(), REDACTED));
+        }
+    }
+    result
+}
+
+/// Redact every string inside a JSON value in place.
+pub fn redact_json(value: &mut JsonValue) {
+    match value {
+        JsonValue::String(s) => {
+            if let Cow::Owned(redacted) = redact(s) {
+                *s = redacted;
+            }
+        }
+        JsonValue::Array(items) => items.iter_mut().for_each(redact_json),
+        JsonValue::Object(map) => map.values_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+/// `MakeWriter` wrapper that redacts secrets from formatted log lines.
+///
+/// # Example
+///
+/// ```rust
+/// use botticelli_secrets::RedactingMakeWriter;
+///
+/// let _subscriber = tracing_subscriber::fmt()
+///     .with_writer(RedactingMakeWriter::new(std::io::stdout))
+///     .finish();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RedactingMakeWriter<M> {
+    inner: M,
+}
+
+impl<M> RedactingMakeWriter<M> {
+    /// Wrap an existing writer factory.
+    pub fn new(inner: M) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for RedactingMakeWriter<M> {
+    type Writer = RedactingWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactingWriter {
+            inner: self.inner.make_writer(),
+        }
+    }
+}
+
+/// Writer produced by [`RedactingMakeWriter`].
+///
+/// `tracing_subscriber` formats each event into a buffer and writes it in one
+/// call, so redacting per `write` sees whole lines.
+#[derive(Debug)]
+pub struct RedactingWriter<W> {
+    inner: W,
+}
+
+impl<W: Write> Write for RedactingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match std::str::from_utf8(buf) {
+            Ok(text) => {
+                self.inner.write_all(redact(text).as_bytes())?;
+                Ok(buf.len())
+            }
+            Err(_) => self.inner.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
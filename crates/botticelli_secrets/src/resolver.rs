@@ -0,0 +1,135 @@
+//! Chained secret lookup and `secret:` reference resolution.
+
+use crate::{
+    DEFAULT_SECRETS_DIR, EnvSecretProvider, FileSecretProvider, KeystoreSecretProvider,
+    SecretProvider, SecretString, register_secret, validate_secret_name,
+};
+use botticelli_error::{SecretError, SecretErrorKind, SecretResult};
+use std::path::Path;
+use std::sync::OnceLock;
+use tracing::{debug, instrument, warn};
+
+/// Prefix marking a config value as a reference to a named secret.
+pub const SECRET_REFERENCE_PREFIX: &str = "secret:";
+
+/// Looks secrets up in an ordered list of providers.
+///
+/// The first provider that returns a value wins. Every value returned is
+/// registered for redaction.
+#[derive(Default)]
+pub struct SecretResolver {
+    providers: Vec<Box<dyn SecretProvider>>,
+}
+
+impl std::fmt::Debug for SecretResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretResolver")
+            .field(
+                "providers",
+                &self
+                    .providers
+                    .iter()
+                    .map(|p| p.provider_name())
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl SecretResolver {
+    /// Create a resolver with no providers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a provider (builder style).
+    pub fn with_provider(mut self, provider: impl SecretProvider + 'static) -> Self {
+        self.providers.push(Box::new(provider));
+        self
+    }
+
+    /// Append a provider.
+    pub fn push(&mut self, provider: Box<dyn SecretProvider>) {
+        self.providers.push(provider);
+    }
+
+    /// Build the resolver used by the CLI and servers.
+    ///
+    /// Providers are consulted in this order:
+    ///
+    /// 1. Secret files in `BOTTICELLI_SECRETS_DIR`, or `/run/secrets` if it exists
+    /// 2. The keystore at `BOTTICELLI_KEYSTORE`, unlocked with `BOTTICELLI_KEYSTORE_PASSPHRASE`
+    /// 3. Environment variables
+    ///
+    /// A keystore that cannot be opened is logged and skipped so a bad
+    /// passphrase does not hide secrets available from other providers.
+    pub fn from_env() -> Self {
+        let mut resolver = Self::new();
+
+        match std::env::var("BOTTICELLI_SECRETS_DIR") {
+            Ok(dir) => resolver.push(Box::new(FileSecretProvider::new(dir))),
+            Err(_) if Path::new(DEFAULT_SECRETS_DIR).is_dir() => {
+                resolver.push(Box::new(FileSecretProvider::default()))
+            }
+            Err(_) => {}
+        }
+
+        if let Ok(path) = std::env::var("BOTTICELLI_KEYSTORE") {
+            let passphrase = std::env::var("BOTTICELLI_KEYSTORE_PASSPHRASE").unwrap_or_default();
+            match KeystoreSecretProvider::open(&path, &passphrase) {
+                Ok(keystore) => resolver.push(Box::new(keystore)),
+                Err(e) => warn!(path = %path, error = %e, "Failed to open keystore, skipping"),
+            }
+        }
+
+        resolver.push(Box::new(EnvSecretProvider::new()));
+        resolver
+    }
+
+    /// Look up a secret, returning `None` if no provider has it.
+    #[instrument(skip(self))]
+    pub fn get_optional(&self, name: &str) -> SecretResult<Option<SecretString>> {
+        validate_secret_name(name)?;
+
+        for provider in &self.providers {
+            if let Some(secret) = provider.get(name)? {
+                debug!(provider = provider.provider_name(), "Secret resolved");
+                register_secret(secret.expose());
+                return Ok(Some(secret));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Look up a secret, failing if no provider has it.
+    pub fn get(&self, name: &str) -> SecretResult<SecretString> {
+        self.get_optional(name)?
+            .ok_or_else(|| SecretError::new(SecretErrorKind::NotFound(name.to_string())))
+    }
+
+    /// Resolve a config value that may be a `secret:name` reference.
+    ///
+    /// Values without the prefix are returned unchanged.
+    pub fn resolve_reference(&self, value: &str) -> SecretResult<String> {
+        match value.strip_prefix(SECRET_REFERENCE_PREFIX) {
+            Some(name) => Ok(self.get(name.trim())?.into_inner()),
+            None => Ok(value.to_string()),
+        }
+    }
+}
+
+/// Process-wide resolver built with [`SecretResolver::from_env`] on first use.
+pub fn default_resolver() -> &'static SecretResolver {
+    static RESOLVER: OnceLock<SecretResolver> = OnceLock::new();
+    RESOLVER.get_or_init(SecretResolver::from_env)
+}
+
+/// Look up a secret with the [`default_resolver`].
+pub fn get_secret(name: &str) -> SecretResult<SecretString> {
+    default_resolver().get(name)
+}
+
+/// Resolve a possible `secret:name` reference with the [`default_resolver`].
+pub fn resolve_secret_reference(value: &str) -> SecretResult<String> {
+    default_resolver().resolve_reference(value)
+}
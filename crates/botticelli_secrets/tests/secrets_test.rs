@@ -0,0 +1,220 @@
+//! Tests for secret providers, resolution and redaction.
+
+use botticelli_secrets::*;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+// ============================================================================
+// Provider Tests
+// ============================================================================
+
+#[test]
+fn test_env_provider_variable_name() {
+    assert_eq!(
+        EnvSecretProvider::new().variable_name("discord_token"),
+        "DISCORD_TOKEN"
+    );
+    assert_eq!(
+        EnvSecretProvider::with_prefix("BOTTICELLI_").variable_name("gemini.api-key"),
+        "BOTTICELLI_GEMINI_API_KEY"
+    );
+}
+
+#[test]
+fn test_env_provider_reads_variable() {
+    unsafe { std::env::set_var("SECRETS_TEST_ENV_TOKEN", "env-token-value") };
+    let provider = EnvSecretProvider::new();
+
+    let secret = provider.get("secrets_test_env_token").unwrap().unwrap();
+    assert_eq!(secret.expose(), "env-token-value");
+    assert!(provider.get("secrets_test_missing").unwrap().is_none());
+}
+
+#[test]
+fn test_file_provider_reads_and_trims() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("discord_token"), "file-token-value\n").unwrap();
+    let provider = FileSecretProvider::new(dir.path());
+
+    let secret = provider.get("discord_token").unwrap().unwrap();
+    assert_eq!(secret.expose(), "file-token-value");
+    assert!(provider.get("missing").unwrap().is_none());
+}
+
+#[test]
+fn test_file_provider_rejects_path_traversal() {
+    let provider = FileSecretProvider::new("/tmp");
+    let err = provider.get("../etc/passwd").unwrap_err();
+    assert!(matches!(err.kind, SecretErrorKind::InvalidReference(_)));
+}
+
+#[test]
+fn test_secret_string_debug_is_redacted() {
+    let secret = SecretString::new("super-secret-value");
+    assert!(!format!("{:?}", secret).contains("super-secret-value"));
+}
+
+// ============================================================================
+// Keystore Tests
+// ============================================================================
+
+#[test]
+fn test_keystore_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("keystore.json");
+
+    let mut keystore = KeystoreSecretProvider::create(&path, "correct horse").unwrap();
+    keystore.set("gemini_api_key", "keystore-api-key").unwrap();
+    keystore.save().unwrap();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(!contents.contains("keystore-api-key"));
+
+    let reopened = KeystoreSecretProvider::open(&path, "correct horse").unwrap();
+    assert_eq!(
+        reopened.get("gemini_api_key").unwrap().unwrap().expose(),
+        "keystore-api-key"
+    );
+    assert_eq!(reopened.names().collect::<Vec<_>>(), vec!["gemini_api_key"]);
+}
+
+#[test]
+fn test_keystore_wrong_passphrase() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("keystore.json");
+    KeystoreSecretProvider::create(&path, "right")
+        .unwrap()
+        .save()
+        .unwrap();
+
+    let err = KeystoreSecretProvider::open(&path, "wrong").unwrap_err();
+    assert!(matches!(err.kind, SecretErrorKind::Decryption(_)));
+}
+
+#[test]
+fn test_keystore_entries_bound_to_names() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("keystore.json");
+
+    let mut keystore = KeystoreSecretProvider::create(&path, "pass").unwrap();
+    keystore.set("first", "first-secret-value").unwrap();
+    keystore.set("second", "second-secret-value").unwrap();
+    keystore.save().unwrap();
+
+    // Swap the ciphertexts between entries on disk
+    let mut json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let first = json["entries"]["first"].clone();
+    json["entries"]["first"] = json["entries"]["second"].clone();
+    json["entries"]["second"] = first;
+    std::fs::write(&path, json.to_string()).unwrap();
+
+    let tampered = KeystoreSecretProvider::open(&path, "pass").unwrap();
+    assert!(tampered.get("first").is_err());
+}
+
+// ============================================================================
+// Resolver Tests
+// ============================================================================
+
+#[test]
+fn test_resolver_uses_first_provider_with_value() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("resolver_order_token"), "from-file-value").unwrap();
+    unsafe { std::env::set_var("RESOLVER_ORDER_TOKEN", "from-env-value") };
+
+    let resolver = SecretResolver::new()
+        .with_provider(FileSecretProvider::new(dir.path()))
+        .with_provider(EnvSecretProvider::new());
+
+    assert_eq!(
+        resolver.get("resolver_order_token").unwrap().expose(),
+        "from-file-value"
+    );
+}
+
+#[test]
+fn test_resolver_resolves_references() {
+    unsafe { std::env::set_var("RESOLVER_REFERENCE_KEY", "reference-value") };
+    let resolver = SecretResolver::new().with_provider(EnvSecretProvider::new());
+
+    assert_eq!(
+        resolver
+            .resolve_reference("secret:resolver_reference_key")
+            .unwrap(),
+        "reference-value"
+    );
+    assert_eq!(resolver.resolve_reference("plain").unwrap(), "plain");
+
+    let err = resolver.resolve_reference("secret:nowhere").unwrap_err();
+    assert!(matches!(err.kind, SecretErrorKind::NotFound(_)));
+}
+
+// ============================================================================
+// Redaction Tests
+// ============================================================================
+
+#[test]
+fn test_resolved_secrets_are_redacted() {
+    unsafe { std::env::set_var("REDACTION_TEST_TOKEN", "redact-me-please") };
+    let resolver = SecretResolver::new().with_provider(EnvSecretProvider::new());
+    resolver.get("redaction_test_token").unwrap();
+
+    assert_eq!(
+        redact("token=redact-me-please;"),
+        format!("token={};", REDACTED)
+    );
+}
+
+#[test]
+fn test_short_values_are_not_registered() {
+    register_secret("abc");
+    assert_eq!(redact("abc def"), "abc def");
+}
+
+#[test]
+fn test_redact_json() {
+    register_secret("json-secret-value");
+    let mut value = serde_json::json!({
+        "headers": {"authorization": "Bearer json-secret-value"},
+        "items": ["json-secret-value", 3]
+    });
+
+    redact_json(&mut value);
+    assert_eq!(value["headers"]["authorization"], "Bearer [REDACTED]");
+    assert_eq!(value["items"][0], REDACTED);
+}
+
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_tracing_output_is_redacted() {
+    register_secret("tracing-secret-value");
+    let buffer = SharedBuffer::default();
+    let writer = buffer.clone();
+
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(RedactingMakeWriter::new(move || writer.clone()))
+        .with_ansi(false)
+        .finish();
+
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info!(token = "tracing-secret-value", "Connecting");
+    });
+
+    let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    assert!(output.contains("Connecting"));
+    assert!(output.contains(REDACTED));
+    assert!(!output.contains("tracing-secret-value"));
+}
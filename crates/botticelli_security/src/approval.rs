@@ -2,7 +2,7 @@
 
 use crate::{SecurityError, SecurityErrorKind, SecurityResult};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::{debug, instrument};
 
@@ -117,6 +117,16 @@ impl ApprovalWorkflow {
         self.requires_approval.insert(command.into(), required);
     }
 
+    /// Replace the set of commands that require approval.
+    ///
+    /// Existing pending actions are kept.
+    pub fn set_required_commands(&mut self, commands: &HashSet<String>) {
+        self.requires_approval = commands
+            .iter()
+            .map(|command| (command.clone(), true))
+            .collect();
+    }
+
     /// Check if a command requires approval.
     pub fn requires_approval(&self, command: &str) -> bool {
         self.requires_approval
@@ -0,0 +1,337 @@
+//! File-based security configuration.
+//!
+//! A single TOML document configures permissions, content filtering, rate limits
+//! and approval requirements for every platform. The document may be a dedicated
+//! `security.toml` or a `[security]` table inside a larger config file:
+//!
+//! ```toml
+//! [security.discord.permissions]
+//! allowed_commands = ["messages.send", "channels.list"]
+//!
+//! [security.discord.content]
+//! max_length = 2000
+//! prohibited_patterns = ["(?i)crypto giveaway"]
+//!
+//! [security.discord.rate_limits."messages.send"]
+//! max_tokens = 10
+//! window_secs = 60
+//!
+//! [security.discord]
+//! require_approval = ["channels.delete"]
+//!
+//! [security.moderation]
+//! guidelines = "Keep it friendly and on topic"
+//!
+//! [security.moderation.platforms.discord]
+//! block_severity = 4
+//! ```
+//!
+//! The optional `moderation` table configures the LLM moderation stage (see
+//! [`ModerationConfig`]); every other table is a platform.
+
+use crate::{
+    ApprovalWorkflow, ContentFilter, ContentFilterConfig, ContentModerator, LlmModerator,
+    ModerationConfig, PermissionChecker, PermissionConfig, RateLimit, RateLimiter, SecurityError,
+    SecurityErrorKind, SecurityResult,
+};
+use botticelli_interface::BotticelliDriver;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+use tracing::{debug, instrument};
+
+/// Security settings for a single platform.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    derive_getters::Getters,
+    derive_setters::Setters,
+    derive_new::new,
+)]
+#[setters(prefix = "with_")]
+pub struct PlatformSecurityConfig {
+    /// Command and resource permissions
+    #[serde(default)]
+    #[new(default)]
+    permissions: PermissionConfig,
+
+    /// Content filter settings
+    #[serde(default)]
+    #[new(default)]
+    content: ContentFilterConfig,
+
+    /// Rate limits keyed by command name
+    #[serde(default)]
+    #[new(default)]
+    rate_limits: HashMap<String, RateLimit>,
+
+    /// Commands that require human approval
+    #[serde(default)]
+    #[new(default)]
+    require_approval: HashSet<String>,
+}
+
+impl PlatformSecurityConfig {
+    /// Check that every section can be turned into a working component.
+    pub fn validate(&self) -> SecurityResult<()> {
+        ContentFilter::new(self.content.clone())?;
+
+        for (command, limit) in &self.rate_limits {
+            if *limit.max_tokens() == 0 || *limit.window_secs() == 0 {
+                return Err(SecurityError::new(SecurityErrorKind::Configuration(
+                    format!(
+                        "Rate limit for '{}' must have non-zero max_tokens and window_secs",
+                        command
+                    ),
+                )));
+            }
+        }
+
+        for command in &self.require_approval {
+            if self.permissions.denied_commands().contains(command) {
+                return Err(SecurityError::new(SecurityErrorKind::Configuration(
+                    format!("Command '{}' requires approval but is also denied", command),
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Build a permission checker from this configuration.
+    pub fn permission_checker(&self) -> PermissionChecker {
+        PermissionChecker::new(self.permissions.clone())
+    }
+
+    /// Build a content filter from this configuration.
+    pub fn content_filter(&self) -> SecurityResult<ContentFilter> {
+        ContentFilter::new(self.content.clone())
+    }
+
+    /// Build a rate limiter from this configuration.
+    pub fn rate_limiter(&self) -> RateLimiter {
+        let mut limiter = RateLimiter::new();
+        limiter.set_limits(self.rate_limits.clone());
+        limiter
+    }
+
+    /// Build an approval workflow from this configuration.
+    pub fn approval_workflow(&self) -> ApprovalWorkflow {
+        let mut workflow = ApprovalWorkflow::new();
+        workflow.set_required_commands(&self.require_approval);
+        workflow
+    }
+}
+
+/// Security configuration for all platforms.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    derive_getters::Getters,
+    derive_setters::Setters,
+    derive_new::new,
+)]
+#[setters(prefix = "with_")]
+pub struct SecurityConfig {
+    /// LLM moderation stage; off when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[new(default)]
+    moderation: Option<ModerationConfig>,
+
+    /// Per-platform settings keyed by platform name (e.g. "discord")
+    #[serde(flatten)]
+    #[new(default)]
+    platforms: HashMap<String, PlatformSecurityConfig>,
+}
+
+impl SecurityConfig {
+    /// Parse and validate configuration from a TOML string.
+    ///
+    /// Accepts either a bare document or one nested under a `[security]` table.
+    pub fn from_toml_str(contents: &str) -> SecurityResult<Self> {
+        let config = Self::parse_toml_str(contents)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Parse configuration without validating it.
+    fn parse_toml_str(contents: &str) -> SecurityResult<Self> {
+        let mut document: toml::Table = toml::from_str(contents).map_err(|e| {
+            SecurityError::new(SecurityErrorKind::Configuration(format!(
+                "Invalid security TOML: {}",
+                e
+            )))
+        })?;
+
+        let section = match document.remove("security") {
+            Some(toml::Value::Table(table)) => table,
+            Some(_) => {
+                return Err(SecurityError::new(SecurityErrorKind::Configuration(
+                    "[security] must be a table".to_string(),
+                )));
+            }
+            None => document,
+        };
+
+        let config: Self = section.try_into().map_err(|e: toml::de::Error| {
+            SecurityError::new(SecurityErrorKind::Configuration(format!(
+                "Invalid security configuration: {}",
+                e
+            )))
+        })?;
+
+        Ok(config)
+    }
+
+    /// Load and validate configuration from a TOML file.
+    #[instrument(skip_all, fields(path = %path.as_ref().display()))]
+    pub fn from_file(path: impl AsRef<Path>) -> SecurityResult<Self> {
+        debug!("Loading security configuration");
+        Self::from_toml_str(&read_config_file(path.as_ref())?)
+    }
+
+    /// Load a replacement for this configuration from a TOML file.
+    ///
+    /// Returns the new configuration together with the list of changes. If the
+    /// new file parses but fails validation, the error message includes the
+    /// diff so operators can see which edit broke it.
+    #[instrument(skip_all, fields(path = %path.as_ref().display()))]
+    pub fn reload_from_file(&self, path: impl AsRef<Path>) -> SecurityResult<(Self, Vec<String>)> {
+        self.reload_from_str(&read_config_file(path.as_ref())?)
+    }
+
+    /// Load a replacement for this configuration from a TOML string.
+    ///
+    /// Behaves like [`SecurityConfig::reload_from_file`] for callers that
+    /// read the file themselves.
+    pub fn reload_from_str(&self, contents: &str) -> SecurityResult<(Self, Vec<String>)> {
+        let updated = Self::parse_toml_str(contents)?;
+        let changes = self.diff(&updated);
+
+        if let Err(e) = updated.validate() {
+            return Err(SecurityError::new(SecurityErrorKind::Configuration(
+                format!(
+                    "Rejected security update: {}\nChanges:\n{}",
+                    e.kind,
+                    changes.join("\n")
+                ),
+            )));
+        }
+
+        debug!(changes = changes.len(), "Security configuration reloaded");
+        Ok((updated, changes))
+    }
+
+    /// Validate every platform section and the moderation settings.
+    pub fn validate(&self) -> SecurityResult<()> {
+        if let Some(moderation) = &self.moderation {
+            moderation.validate().map_err(|e| {
+                SecurityError::new(SecurityErrorKind::Configuration(format!(
+                    "[security.moderation]: {}",
+                    e.kind
+                )))
+            })?;
+        }
+        for (platform, config) in &self.platforms {
+            config.validate().map_err(|e| {
+                SecurityError::new(SecurityErrorKind::Configuration(format!(
+                    "[security.{}]: {}",
+                    platform, e.kind
+                )))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Settings for a platform, if configured.
+    pub fn platform(&self, platform: &str) -> Option<&PlatformSecurityConfig> {
+        self.platforms.get(platform)
+    }
+
+    /// Build the moderation stage configured under `[security.moderation]`.
+    ///
+    /// Returns `None` when moderation is not configured.
+    pub fn moderator<D>(&self, driver: Arc<D>) -> Option<Arc<dyn ContentModerator>>
+    where
+        D: BotticelliDriver + ?Sized + 'static,
+    {
+        self.moderation.as_ref().map(|moderation| {
+            Arc::new(LlmModerator::new(driver, moderation.clone())) as Arc<dyn ContentModerator>
+        })
+    }
+
+    /// Describe what changed between this configuration and `other`.
+    ///
+    /// Each line is `+ path = value` (added), `- path = value` (removed)
+    /// or `~ path: old -> new` (changed). Sets are compared without regard to order.
+    pub fn diff(&self, other: &Self) -> Vec<String> {
+        let before = flatten_config(self);
+        let after = flatten_config(other);
+
+        let mut lines = Vec::new();
+        for (path, old) in &before {
+            match after.get(path) {
+                None => lines.push(format!("- {} = {}", path, old)),
+                Some(new) if new != old => lines.push(format!("~ {}: {} -> {}", path, old, new)),
+                Some(_) => {}
+            }
+        }
+        for (path, new) in &after {
+            if !before.contains_key(path) {
+                lines.push(format!("+ {} = {}", path, new));
+            }
+        }
+        lines.sort_by(|a, b| a[2..].cmp(&b[2..]));
+        lines
+    }
+}
+
+fn read_config_file(path: &Path) -> SecurityResult<String> {
+    std::fs::read_to_string(path).map_err(|e| {
+        SecurityError::new(SecurityErrorKind::Configuration(format!(
+            "Failed to read {}: {}",
+            path.display(),
+            e
+        )))
+    })
+}
+
+/// Flatten a configuration into `dotted.path -> value` pairs.
+fn flatten_config(config: &SecurityConfig) -> BTreeMap<String, String> {
+    let mut out = BTreeMap::new();
+    if let Ok(value) = serde_json::to_value(config) {
+        flatten_value("security", &value, &mut out);
+    }
+    out
+}
+
+fn flatten_value(prefix: &str, value: &JsonValue, out: &mut BTreeMap<String, String>) {
+    match value {
+        JsonValue::Object(map) => {
+            for (key, child) in map {
+                let path = if key.contains('.') {
+                    format!("{}.\"{}\"", prefix, key)
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten_value(&path, child, out);
+            }
+        }
+        JsonValue::Array(items) => {
+            let mut rendered: Vec<String> = items.iter().map(|v| v.to_string()).collect();
+            rendered.sort();
+            out.insert(prefix.to_string(), format!("[{}]", rendered.join(", ")));
+        }
+        other => {
+            out.insert(prefix.to_string(), other.to_string());
+        }
+    }
+}
@@ -76,6 +76,10 @@ pub enum SecurityErrorKind {
         reason: String,
     },
 
+    /// Moderation classifier failed to produce a verdict
+    #[display("Moderation failed: {}", _0)]
+    ModerationFailed(String),
+
     /// Configuration error
     #[display("Configuration error: {}", _0)]
     Configuration(String),
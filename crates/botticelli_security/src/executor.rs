@@ -1,8 +1,8 @@
 //! Secure command executor with multi-layer security pipeline.
 
 use crate::{
-    ApprovalWorkflow, CommandValidator, ContentFilter, PermissionChecker, RateLimiter,
-    SecurityResult,
+    ApprovalWorkflow, CommandValidator, ContentFilter, PermissionChecker, PlatformSecurityConfig,
+    RateLimiter, SecurityResult,
 };
 use std::collections::HashMap;
 use tracing::{debug, info, instrument, warn};
@@ -43,6 +43,38 @@ impl<V: CommandValidator> SecureExecutor<V> {
         }
     }
 
+    /// Create a secure executor from file-based platform configuration.
+    pub fn from_config(validator: V, config: &PlatformSecurityConfig) -> SecurityResult<Self> {
+        config.validate()?;
+        Ok(Self::new(
+            config.permission_checker(),
+            validator,
+            config.content_filter()?,
+            config.rate_limiter(),
+            config.approval_workflow(),
+        ))
+    }
+
+    /// Re-apply platform configuration in place.
+    ///
+    /// The configuration is validated before anything is replaced, so an
+    /// invalid update leaves the executor untouched. Pending approvals and
+    /// rate limit buckets for unchanged limits are preserved.
+    #[instrument(skip_all)]
+    pub fn apply_config(&mut self, config: &PlatformSecurityConfig) -> SecurityResult<()> {
+        config.validate()?;
+        let content_filter = config.content_filter()?;
+
+        self.permission_checker = config.permission_checker();
+        self.content_filter = content_filter;
+        self.rate_limiter.set_limits(config.rate_limits().clone());
+        self.approval_workflow
+            .set_required_commands(config.require_approval());
+
+        info!("Security configuration applied");
+        Ok(())
+    }
+
     /// Execute a command through the security pipeline.
     ///
     /// Returns Ok(()) if the command passes all security checks and is ready to execute.
@@ -10,7 +10,7 @@
 //!
 //! 1. **Permission Layer** - Granular per-narrative command permissions
 //! 2. **Validation Layer** - Input validation and resource checks
-//! 3. **Content Layer** - Content filtering and pattern detection
+//! 3. **Content Layer** - Content filtering, pattern detection, and optional LLM moderation
 //! 4. **Rate Limit Layer** - Token bucket rate limiting
 //! 5. **Approval Layer** - Human-in-the-loop for dangerous operations
 //!
@@ -20,17 +20,24 @@
 #![forbid(unsafe_code)]
 
 mod approval;
+mod config;
 mod content;
 mod error;
 mod executor;
+mod moderation;
 mod permission;
 mod rate_limit;
 mod validation;
 
 pub use approval::{ApprovalDecision, ApprovalWorkflow, PendingAction};
+pub use config::{PlatformSecurityConfig, SecurityConfig};
 pub use content::{ContentFilter, ContentFilterConfig, ContentViolation};
 pub use error::{SecurityError, SecurityErrorKind, SecurityResult};
 pub use executor::SecureExecutor;
+pub use moderation::{
+    ContentModerator, DEFAULT_MODERATION_RUBRIC, LlmModerator, MAX_MODERATION_SEVERITY,
+    ModerationConfig, ModerationThresholds, ModerationVerdict,
+};
 pub use permission::{CommandPermission, PermissionChecker, PermissionConfig, ResourcePermission};
 pub use rate_limit::{RateLimit, RateLimitExceeded, RateLimiter};
 pub use validation::{CommandValidator, DiscordValidator, ValidationError};
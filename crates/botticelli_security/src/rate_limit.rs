@@ -7,13 +7,14 @@ use std::time::{Duration, Instant};
 use tracing::{debug, instrument};
 
 /// Rate limit configuration.
-#[derive(Debug, Clone, Serialize, Deserialize, derive_getters::Getters)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, derive_getters::Getters)]
 pub struct RateLimit {
     /// Maximum tokens (requests) allowed
     max_tokens: u32,
     /// Time window in seconds
     window_secs: u64,
     /// Burst allowance (extra tokens for spikes)
+    #[serde(default)]
     burst: u32,
 }
 
@@ -129,6 +130,21 @@ impl RateLimiter {
         self.limits.insert(operation, limit);
     }
 
+    /// Replace all configured limits.
+    ///
+    /// Buckets for operations whose limit is unchanged keep their current
+    /// token count, so reloading configuration does not reset rate limiting.
+    pub fn set_limits(&mut self, limits: HashMap<String, RateLimit>) {
+        self.buckets
+            .retain(|operation, bucket| limits.get(operation) == Some(&bucket.limit));
+        for (operation, limit) in &limits {
+            self.buckets
+                .entry(operation.clone())
+                .or_insert_with(|| TokenBucket::new(limit.clone()));
+        }
+        self.limits = limits;
+    }
+
     /// Check if an operation can be executed.
     #[instrument(skip(self), fields(operation))]
     pub fn check(&mut self, operation: &str) -> SecurityResult<()> {
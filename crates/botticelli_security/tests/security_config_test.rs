@@ -0,0 +1,240 @@
+//! Tests for file-based security configuration and reload.
+
+use botticelli_security::*;
+use std::collections::HashMap;
+
+const CONFIG: &str = r#"
+[security.discord.permissions]
+allowed_commands = ["messages.send"]
+
+[security.discord.content]
+max_length = 500
+prohibited_patterns = ["(?i)giveaway"]
+
+[security.discord.rate_limits."messages.send"]
+max_tokens = 2
+window_secs = 60
+
+[security.discord]
+require_approval = ["channels.delete"]
+"#;
+
+fn write_config(name: &str, contents: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "botticelli_security_{}_{}.toml",
+        name,
+        std::process::id()
+    ));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn test_security_config_parses_security_table() {
+    let config = SecurityConfig::from_toml_str(CONFIG).unwrap();
+    let discord = config.platform("discord").unwrap();
+
+    assert!(
+        discord
+            .permissions()
+            .allowed_commands()
+            .contains("messages.send")
+    );
+    assert_eq!(*discord.content().max_length(), 500);
+    assert_eq!(*discord.rate_limits()["messages.send"].max_tokens(), 2);
+    assert!(discord.require_approval().contains("channels.delete"));
+    assert!(config.platform("telegram").is_none());
+}
+
+#[test]
+fn test_security_config_parses_bare_document() {
+    let config = SecurityConfig::from_toml_str(
+        r#"
+        [discord.permissions]
+        allow_all_by_default = true
+        "#,
+    )
+    .unwrap();
+
+    assert!(
+        *config
+            .platform("discord")
+            .unwrap()
+            .permissions()
+            .allow_all_by_default()
+    );
+}
+
+#[test]
+fn test_security_config_rejects_invalid_regex() {
+    let result = SecurityConfig::from_toml_str(
+        r#"
+        [security.discord.content]
+        prohibited_patterns = ["(unclosed"]
+        "#,
+    );
+
+    let err = result.unwrap_err();
+    assert!(err.to_string().contains("security.discord"));
+}
+
+#[test]
+fn test_security_config_rejects_zero_rate_limit() {
+    let result = SecurityConfig::from_toml_str(
+        r#"
+        [security.discord.rate_limits."messages.send"]
+        max_tokens = 0
+        window_secs = 60
+        "#,
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_executor_from_config_applies_all_layers() {
+    let config = SecurityConfig::from_toml_str(CONFIG).unwrap();
+    let mut executor =
+        SecureExecutor::from_config(DiscordValidator::new(), config.platform("discord").unwrap())
+            .unwrap();
+
+    let mut params = HashMap::new();
+    params.insert("content".to_string(), "hello".to_string());
+
+    // Permissions: only messages.send is allowed
+    assert!(
+        executor
+            .check_security("n", "channels.list", &params)
+            .is_err()
+    );
+
+    // Content filter: prohibited pattern
+    params.insert("content".to_string(), "Big GIVEAWAY today".to_string());
+    assert!(
+        executor
+            .check_security("n", "messages.send", &params)
+            .is_err()
+    );
+
+    // Rate limit: two sends allowed
+    params.insert("content".to_string(), "hello".to_string());
+    assert!(
+        executor
+            .check_security("n", "messages.send", &params)
+            .is_ok()
+    );
+    assert!(
+        executor
+            .check_security("n", "messages.send", &params)
+            .is_ok()
+    );
+    assert!(
+        executor
+            .check_security("n", "messages.send", &params)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_apply_config_preserves_unchanged_rate_limit_buckets() {
+    let config = SecurityConfig::from_toml_str(CONFIG).unwrap();
+    let discord = config.platform("discord").unwrap();
+    let mut executor = SecureExecutor::from_config(DiscordValidator::new(), discord).unwrap();
+
+    let mut params = HashMap::new();
+    params.insert("content".to_string(), "hello".to_string());
+    executor
+        .check_security("n", "messages.send", &params)
+        .unwrap();
+
+    // Re-applying the same limits must not refill the bucket
+    executor.apply_config(discord).unwrap();
+    assert_eq!(
+        executor.rate_limiter().available_tokens("messages.send"),
+        Some(1)
+    );
+}
+
+#[test]
+fn test_apply_invalid_config_leaves_executor_untouched() {
+    let config = SecurityConfig::from_toml_str(CONFIG).unwrap();
+    let discord = config.platform("discord").unwrap();
+    let mut executor = SecureExecutor::from_config(DiscordValidator::new(), discord).unwrap();
+
+    let broken = discord.clone().with_content(
+        ContentFilterConfig::default().with_prohibited_patterns(vec!["(bad".to_string()]),
+    );
+    assert!(executor.apply_config(&broken).is_err());
+    assert_eq!(*executor.content_filter().config().max_length(), 500);
+}
+
+#[test]
+fn test_security_config_diff() {
+    let before = SecurityConfig::from_toml_str(CONFIG).unwrap();
+    let after =
+        SecurityConfig::from_toml_str(&CONFIG.replace("max_length = 500", "max_length = 800"))
+            .unwrap();
+
+    let changes = before.diff(&after);
+    assert_eq!(
+        changes,
+        vec!["~ security.discord.content.max_length: 500 -> 800".to_string()]
+    );
+    assert!(before.diff(&before).is_empty());
+}
+
+#[test]
+fn test_reload_rejects_invalid_update_with_diff() {
+    let current = SecurityConfig::from_toml_str(CONFIG).unwrap();
+    let path = write_config(
+        "reload_invalid",
+        &CONFIG.replace("max_tokens = 2", "max_tokens = 0"),
+    );
+
+    let err = current.reload_from_file(&path).unwrap_err().to_string();
+    std::fs::remove_file(&path).ok();
+
+    assert!(err.contains("Rejected security update"));
+    assert!(err.contains("~ security.discord.rate_limits.\"messages.send\".max_tokens: 2 -> 0"));
+}
+
+#[test]
+fn test_reload_returns_changes() {
+    let current = SecurityConfig::from_toml_str(CONFIG).unwrap();
+    let path = write_config(
+        "reload_valid",
+        &format!(
+            "{}\n[security.telegram.permissions]\nallow_all_by_default = true\n",
+            CONFIG
+        ),
+    );
+
+    let (updated, changes) = current.reload_from_file(&path).unwrap();
+    std::fs::remove_file(&path).ok();
+
+    assert!(updated.platform("telegram").is_some());
+    assert!(changes.iter().all(|c| c.starts_with("+ security.telegram")));
+}
+
+#[test]
+fn test_security_config_parses_moderation_table() {
+    let config = SecurityConfig::from_toml_str(&format!(
+        "{}\n[security.moderation]\nguidelines = \"Stay on topic\"\nmodel = \"gemini-2.0-flash\"\n\n[security.moderation.platforms.discord]\nblock_severity = 4\n",
+        CONFIG
+    ))
+    .unwrap();
+
+    let moderation = config.moderation().as_ref().unwrap();
+    assert_eq!(moderation.guidelines().as_deref(), Some("Stay on topic"));
+    assert_eq!(moderation.model().as_deref(), Some("gemini-2.0-flash"));
+    assert_eq!(*moderation.thresholds_for("discord").block_severity(), 4);
+    assert!(config.platform("moderation").is_none());
+    assert!(config.platform("discord").is_some());
+}
+
+#[test]
+fn test_security_config_rejects_unreachable_moderation_threshold() {
+    let err = SecurityConfig::from_toml_str("[security.moderation.default]\nblock_severity = 11\n")
+        .unwrap_err();
+    assert!(err.to_string().contains("[security.moderation]"));
+}
@@ -57,6 +57,7 @@ mod metrics;
 mod request;
 mod response;
 mod schedule;
+mod schedule_rules;
 mod traits;
 
 pub use actor_traits::{
@@ -80,4 +81,5 @@ pub use response::{
     ChatCompletionChunk, ChatCompletionResponse, Choice, ChoiceMessage, ChunkChoice, Delta, Usage,
 };
 pub use schedule::{Schedule, ScheduleCheck, ScheduleType};
+pub use schedule_rules::{ActiveWindow, ConstrainedSchedule, ScheduleRules};
 pub use traits::{InferenceServer, ModelManager as ModelManagerTrait, ServerLauncher};
@@ -1,9 +1,14 @@
 //! Task scheduling abstractions for actor servers.
 //!
 //! This module provides trait-based scheduling with support for multiple
-//! schedule types (interval, cron, one-time, immediate).
+//! schedule types (interval, cron, one-time, immediate, times per day).
+//! Calendar-based schedules can be evaluated in any IANA timezone; see
+//! [`ConstrainedSchedule`](crate::ConstrainedSchedule) for active windows,
+//! blackout dates and jitter.
 
-use chrono::{DateTime, Duration, Utc};
+use botticelli_error::{ServerError, ServerErrorKind};
+use chrono::{DateTime, Duration, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
@@ -73,8 +78,57 @@ pub trait Schedule {
     ///
     /// Next execution time, or None if schedule is exhausted
     fn next_execution(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>>;
+
+    /// Calculate the next execution time with calendar fields read in `tz`.
+    ///
+    /// Schedules that don't depend on the calendar (intervals, fixed
+    /// instants) can keep the default, which ignores the timezone.
+    fn next_execution_in(&self, after: DateTime<Utc>, tz: Tz) -> Option<DateTime<Utc>> {
+        let _ = tz;
+        self.next_execution(after)
+    }
+
+    /// Whether runs fall on recurring calendar slots (cron, times per day)
+    /// rather than relative to the last run or at a single instant.
+    ///
+    /// [`ConstrainedSchedule`](crate::ConstrainedSchedule) skips calendar
+    /// slots that its rules don't allow, and defers other runs to the next
+    /// allowed time.
+    fn is_calendar_based(&self) -> bool {
+        false
+    }
+
+    /// Check that the schedule's settings can produce runs.
+    ///
+    /// Called by [`ConstrainedSchedule::new`](crate::ConstrainedSchedule::new);
+    /// the default accepts every schedule.
+    fn validate(&self) -> Result<(), ServerError> {
+        Ok(())
+    }
+
+    /// List the executions after `after` up to and including `until`.
+    ///
+    /// Useful for previewing a schedule before deploying it. At most
+    /// [`MAX_PREVIEW_RUNS`] executions are returned.
+    fn preview(&self, after: DateTime<Utc>, until: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+        let mut runs = Vec::new();
+        let mut cursor = after;
+        while runs.len() < MAX_PREVIEW_RUNS {
+            match self.next_execution(cursor) {
+                Some(next) if next > cursor && next <= until => {
+                    runs.push(next);
+                    cursor = next;
+                }
+                _ => break,
+            }
+        }
+        runs
+    }
 }
 
+/// Upper bound on the executions returned by [`Schedule::preview`].
+pub const MAX_PREVIEW_RUNS: usize = 1000;
+
 /// Types of task schedules supported by the actor server.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(tag = "type")]
@@ -101,6 +155,22 @@ pub enum ScheduleType {
 
     /// Execute immediately on startup
     Immediate,
+
+    /// A fixed number of runs per day, spread evenly
+    ///
+    /// Runs are spread over `start`..`end` in local time (the whole day by
+    /// default), e.g. `times = 4` with `start = "09:00"` and `end = "17:00"`
+    /// runs at 09:00, 11:00, 13:00 and 15:00.
+    TimesPerDay {
+        /// Runs per day
+        times: u32,
+        /// Local time of the first run
+        #[serde(default)]
+        start: Option<NaiveTime>,
+        /// Local time the runs must finish by (exclusive)
+        #[serde(default)]
+        end: Option<NaiveTime>,
+    },
 }
 
 impl Schedule for ScheduleType {
@@ -160,10 +230,46 @@ impl Schedule for ScheduleType {
                 }
                 Err(_) => ScheduleCheck::new(false, None),
             },
+            ScheduleType::TimesPerDay { .. } => {
+                let Some(next) = self.next_execution(last_run.unwrap_or(now)) else {
+                    return ScheduleCheck::new(false, None);
+                };
+                if now >= next {
+                    ScheduleCheck::new(true, self.next_execution(now))
+                } else {
+                    ScheduleCheck::wait_until(next)
+                }
+            }
         }
     }
 
     fn next_execution(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.next_execution_in(after, Tz::UTC)
+    }
+
+    fn is_calendar_based(&self) -> bool {
+        matches!(
+            self,
+            ScheduleType::Cron { .. } | ScheduleType::TimesPerDay { .. }
+        )
+    }
+
+    /// Rejects a times-per-day schedule with no runs, or with more runs than
+    /// there are minutes in its window.
+    #[track_caller]
+    fn validate(&self) -> Result<(), ServerError> {
+        if let ScheduleType::TimesPerDay { times, start, end } = self
+            && times_per_day_step(*times, *start, *end).is_none()
+        {
+            return Err(ServerError::new(ServerErrorKind::Configuration(format!(
+                "TimesPerDay needs at least one run and runs at least a minute apart, got times = {}",
+                times
+            ))));
+        }
+        Ok(())
+    }
+
+    fn next_execution_in(&self, after: DateTime<Utc>, tz: Tz) -> Option<DateTime<Utc>> {
         match self {
             ScheduleType::Immediate => None,
             ScheduleType::Once { at } => {
@@ -175,12 +281,72 @@ impl Schedule for ScheduleType {
             }
             ScheduleType::Interval { seconds } => Some(after + Duration::seconds(*seconds as i64)),
             ScheduleType::Cron { expression } => {
-                if let Ok(schedule) = cron::Schedule::from_str(expression) {
-                    schedule.after(&after).next()
-                } else {
-                    None
-                }
+                let schedule = cron::Schedule::from_str(expression).ok()?;
+                schedule
+                    .after(&after.with_timezone(&tz))
+                    .next()
+                    .map(|next| next.with_timezone(&Utc))
+            }
+            ScheduleType::TimesPerDay { times, start, end } => {
+                times_per_day_after(after, tz, *times, *start, *end)
             }
         }
     }
 }
+
+/// Next of `times` evenly spread daily runs after `after`, in local time.
+fn times_per_day_after(
+    after: DateTime<Utc>,
+    tz: Tz,
+    times: u32,
+    start: Option<NaiveTime>,
+    end: Option<NaiveTime>,
+) -> Option<DateTime<Utc>> {
+    let step = times_per_day_step(times, start, end)?;
+    let start = start.unwrap_or(NaiveTime::MIN);
+
+    // A day's runs can spill into the next day, so yesterday's count too
+    let today = after.with_timezone(&tz).date_naive();
+    (-1..=1)
+        .filter_map(|offset| today.checked_add_signed(Duration::days(offset)))
+        .flat_map(|day| (0..times).map(move |k| day.and_time(start) + step * k as i32))
+        .filter_map(|local| resolve_local(tz, local))
+        .filter(|run| *run > after)
+        .min()
+}
+
+/// Gap between the runs of a times-per-day schedule.
+///
+/// Returns `None` unless there is at least one run and the runs are at least
+/// a minute apart.
+fn times_per_day_step(
+    times: u32,
+    start: Option<NaiveTime>,
+    end: Option<NaiveTime>,
+) -> Option<Duration> {
+    let start = start.unwrap_or(NaiveTime::MIN);
+    let span = match end {
+        Some(end) if end > start => end - start,
+        // The runs span midnight
+        Some(end) => end - start + Duration::days(1),
+        None => Duration::days(1) - (start - NaiveTime::MIN),
+    };
+    if times == 0 || i64::from(times) > span.num_minutes() {
+        return None;
+    }
+    Some(span / times as i32)
+}
+
+/// Convert a local wall-clock time to UTC.
+///
+/// Ambiguous times (DST fall-back) resolve to the earlier instant; times
+/// skipped by a DST jump move forward an hour.
+pub(crate) fn resolve_local(tz: Tz, local: NaiveDateTime) -> Option<DateTime<Utc>> {
+    tz.from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            tz.from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+        })
+        .map(|resolved| resolved.with_timezone(&Utc))
+}
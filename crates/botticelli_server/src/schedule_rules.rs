@@ -0,0 +1,299 @@
+//! Calendar rules layered on top of a schedule.
+//!
+//! [`ScheduleRules`] restrict when a schedule may fire: active windows in
+//! local time, blackout dates and a per-run jitter. [`ConstrainedSchedule`]
+//! applies them to any [`Schedule`] in an IANA timezone, so a "9 AM daily"
+//! cron stays at 9 AM local time across DST changes.
+
+use crate::schedule::{Schedule, ScheduleCheck, resolve_local};
+use botticelli_error::{ServerError, ServerErrorKind};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// How far ahead to look for the next allowed time before giving up.
+const MAX_LOOKAHEAD_DAYS: i64 = 366;
+
+/// How many disallowed calendar slots to skip before giving up.
+const MAX_SKIPPED_SLOTS: usize = 1000;
+
+/// Local hours on given weekdays during which a schedule may run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActiveWindow {
+    /// Weekdays the window opens on; empty means every day
+    #[serde(default)]
+    pub days: Vec<Weekday>,
+    /// Local time the window opens
+    pub start: NaiveTime,
+    /// Local time the window closes (exclusive); earlier than `start` for
+    /// windows that span midnight
+    pub end: NaiveTime,
+}
+
+impl ActiveWindow {
+    /// Create a window open on `days` (every day if empty) from `start` to `end`.
+    pub fn new(days: Vec<Weekday>, start: NaiveTime, end: NaiveTime) -> Self {
+        Self { days, start, end }
+    }
+
+    /// Whether the window opens on this weekday.
+    fn opens_on(&self, day: Weekday) -> bool {
+        self.days.is_empty() || self.days.contains(&day)
+    }
+
+    /// Whether a local time falls inside the window.
+    ///
+    /// A window spanning midnight belongs to the day it opens on, so a
+    /// Friday 22:00-02:00 window covers early Saturday but not early Friday.
+    pub fn contains(&self, local: NaiveDateTime) -> bool {
+        let (day, time) = (local.weekday(), local.time());
+        if self.start < self.end {
+            self.opens_on(day) && self.start <= time && time < self.end
+        } else {
+            (self.opens_on(day) && time >= self.start)
+                || (self.opens_on(day.pred()) && time < self.end)
+        }
+    }
+}
+
+/// Calendar restrictions applied to a schedule.
+///
+/// All fields are optional; the default rules allow every time and add no
+/// jitter.
+///
+/// # Example
+///
+/// ```toml
+/// timezone = "Europe/Berlin"
+/// blackout_dates = ["2025-12-25", "2026-01-01"]
+/// jitter_seconds = 600
+///
+/// [[active_windows]]
+/// days = ["Mon", "Tue", "Wed", "Thu", "Fri"]
+/// start = "09:00:00"
+/// end = "17:00:00"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduleRules {
+    /// IANA timezone for windows, blackout dates and calendar schedules
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// Windows the schedule may run in; empty means any time
+    #[serde(default)]
+    pub active_windows: Vec<ActiveWindow>,
+    /// Local dates on which the schedule never runs
+    #[serde(default)]
+    pub blackout_dates: Vec<NaiveDate>,
+    /// Maximum random delay added to each run
+    #[serde(default)]
+    pub jitter_seconds: u64,
+}
+
+/// A schedule evaluated in a timezone under [`ScheduleRules`].
+///
+/// Calendar slots (cron, times per day) outside the active windows or on a
+/// blackout date are skipped; other runs, such as intervals, are moved to
+/// the next allowed time. Each run is then
+/// delayed by up to `jitter_seconds`; the delay is pseudo-random but fixed
+/// per run, so repeated checks and previews agree on it.
+///
+/// # Example
+///
+/// ```
+/// use botticelli_server::{ConstrainedSchedule, Schedule, ScheduleRules, ScheduleType};
+/// use chrono::Utc;
+///
+/// let rules = ScheduleRules {
+///     timezone: Some("America/New_York".to_string()),
+///     jitter_seconds: 300,
+///     ..Default::default()
+/// };
+/// let schedule = ConstrainedSchedule::new(
+///     ScheduleType::Cron { expression: "0 0 9 * * * *".to_string() },
+///     rules,
+///     "UTC",
+/// )?;
+///
+/// // Runs over the next week, in local time
+/// let now = Utc::now();
+/// for run in schedule.preview(now, now + chrono::Duration::days(7)) {
+///     println!("{}", run.with_timezone(&schedule.timezone()));
+/// }
+/// # Ok::<(), botticelli_error::ServerError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConstrainedSchedule<S> {
+    schedule: S,
+    rules: ScheduleRules,
+    timezone: Tz,
+    created_at: DateTime<Utc>,
+}
+
+impl<S: Schedule> ConstrainedSchedule<S> {
+    /// Apply `rules` to `schedule`.
+    ///
+    /// # Arguments
+    ///
+    /// * `schedule` - Schedule deciding when runs are due
+    /// * `rules` - Calendar restrictions
+    /// * `default_timezone` - IANA timezone used when `rules` doesn't set one
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error for an unknown timezone, an empty
+    /// active window or a schedule that fails [`Schedule::validate`].
+    #[track_caller]
+    pub fn new(
+        schedule: S,
+        rules: ScheduleRules,
+        default_timezone: &str,
+    ) -> Result<Self, ServerError> {
+        let name = rules.timezone.as_deref().unwrap_or(default_timezone);
+        let timezone = name.parse::<Tz>().map_err(|_| {
+            ServerError::new(ServerErrorKind::Configuration(format!(
+                "Unknown timezone '{}'",
+                name
+            )))
+        })?;
+
+        schedule.validate()?;
+
+        if let Some(window) = rules.active_windows.iter().find(|w| w.start == w.end) {
+            return Err(ServerError::new(ServerErrorKind::Configuration(format!(
+                "Active window starts and ends at {}",
+                window.start
+            ))));
+        }
+
+        Ok(Self {
+            schedule,
+            rules,
+            timezone,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// The wrapped schedule.
+    pub fn schedule(&self) -> &S {
+        &self.schedule
+    }
+
+    /// The calendar rules.
+    pub fn rules(&self) -> &ScheduleRules {
+        &self.rules
+    }
+
+    /// Timezone the schedule is evaluated in.
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Whether the rules allow a run at this instant (jitter aside).
+    pub fn allows(&self, at: DateTime<Utc>) -> bool {
+        let local = at.with_timezone(&self.timezone).naive_local();
+        if self.rules.blackout_dates.contains(&local.date()) {
+            return false;
+        }
+        self.rules.active_windows.is_empty()
+            || self.rules.active_windows.iter().any(|w| w.contains(local))
+    }
+
+    /// Earliest instant at or after `after` that the rules allow.
+    ///
+    /// Returns `None` if nothing is allowed within a year.
+    pub fn next_allowed(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if self.allows(after) {
+            return Some(after);
+        }
+
+        // The allowed periods open at local midnight or a window start
+        let today = after.with_timezone(&self.timezone).date_naive();
+        (0..=MAX_LOOKAHEAD_DAYS)
+            .filter_map(|offset| today.checked_add_signed(Duration::days(offset)))
+            .find_map(|day| {
+                let mut openings: Vec<NaiveTime> = std::iter::once(NaiveTime::MIN)
+                    .chain(
+                        self.rules
+                            .active_windows
+                            .iter()
+                            .filter(|w| w.opens_on(day.weekday()))
+                            .map(|w| w.start),
+                    )
+                    .collect();
+                openings.sort();
+                openings
+                    .into_iter()
+                    .filter_map(|time| resolve_local(self.timezone, day.and_time(time)))
+                    .find(|opening| *opening > after && self.allows(*opening))
+            })
+    }
+
+    /// First slot of the wrapped schedule, from `due` on, that the rules allow.
+    fn next_allowed_slot(&self, mut due: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        for _ in 0..MAX_SKIPPED_SLOTS {
+            if self.allows(due) {
+                return Some(due);
+            }
+            // Slots are whole seconds, so this finds one at the opening itself
+            let opening = self.next_allowed(due)?;
+            due = self
+                .schedule
+                .next_execution_in(opening - Duration::seconds(1), self.timezone)?;
+        }
+        None
+    }
+
+    /// Delay added to a run due at `slot`.
+    pub fn jitter(&self, slot: DateTime<Utc>) -> Duration {
+        if self.rules.jitter_seconds == 0 {
+            return Duration::zero();
+        }
+        let seconds = splitmix64(slot.timestamp() as u64) % (self.rules.jitter_seconds + 1);
+        Duration::seconds(seconds as i64)
+    }
+}
+
+impl<S: Schedule> Schedule for ConstrainedSchedule<S> {
+    fn check(&self, last_run: Option<DateTime<Utc>>) -> ScheduleCheck {
+        let now = Utc::now();
+        let due = match last_run {
+            // Schedules that run at once when new still wait for a window
+            None if self.schedule.check(None).should_run => self.next_allowed(now),
+            // Otherwise the first run is the first slot after loading
+            None => self.next_execution(self.created_at),
+            Some(last) => self.next_execution(last),
+        };
+
+        match due {
+            Some(due) if now >= due => ScheduleCheck::new(true, self.next_execution(now)),
+            Some(due) => ScheduleCheck::wait_until(due),
+            None => ScheduleCheck::new(false, None),
+        }
+    }
+
+    fn next_execution(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let due = self.schedule.next_execution_in(after, self.timezone)?;
+        let slot = if self.schedule.is_calendar_based() {
+            self.next_allowed_slot(due)?
+        } else {
+            self.next_allowed(due)?
+        };
+        Some(slot + self.jitter(slot))
+    }
+
+    fn is_calendar_based(&self) -> bool {
+        self.schedule.is_calendar_based()
+    }
+
+    fn validate(&self) -> Result<(), ServerError> {
+        self.schedule.validate()
+    }
+}
+
+/// Mix a 64-bit value into a well-distributed one (SplitMix64 finalizer).
+fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
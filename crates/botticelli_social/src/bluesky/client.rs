@@ -0,0 +1,571 @@
+//! AT Protocol XRPC client for Bluesky.
+
+use super::facets::{FacetFeature, detect_facets, grapheme_len};
+use super::{
+    BLUESKY_MAX_GRAPHEMES, BLUESKY_MAX_IMAGES, BlueskyError, BlueskyErrorKind, BlueskyResult,
+};
+use reqwest::{Method, RequestBuilder, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value as JsonValue, json};
+use tokio::sync::Mutex;
+use tracing::{debug, info, instrument, warn};
+
+/// Default PDS / entryway for bsky.social accounts.
+pub const BLUESKY_DEFAULT_SERVICE: &str = "https://bsky.social";
+
+/// Fallback delay when a rate-limited response carries no reset header.
+const DEFAULT_RETRY_AFTER_SECS: u64 = 60;
+
+/// Authenticated session returned by `com.atproto.server.createSession`.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlueskySession {
+    /// Short-lived access token.
+    pub access_jwt: String,
+    /// Long-lived refresh token.
+    pub refresh_jwt: String,
+    /// Account DID.
+    pub did: String,
+    /// Account handle.
+    pub handle: String,
+}
+
+impl std::fmt::Debug for BlueskySession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlueskySession")
+            .field("did", &self.did)
+            .field("handle", &self.handle)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Strong reference to a record (`com.atproto.repo.strongRef`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PostRef {
+    /// `at://` URI of the record.
+    pub uri: String,
+    /// Content hash of the record.
+    pub cid: String,
+}
+
+/// Reply target for a post.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplyRef {
+    /// First post of the thread.
+    pub root: PostRef,
+    /// Post being replied to.
+    pub parent: PostRef,
+}
+
+/// Image to attach to a post.
+#[derive(Debug, Clone)]
+pub struct ImageUpload {
+    /// Raw image bytes.
+    pub bytes: Vec<u8>,
+    /// MIME type (e.g. "image/png").
+    pub mime_type: String,
+    /// Alt text.
+    pub alt: String,
+}
+
+/// A post to create.
+#[derive(Debug, Clone, Default)]
+pub struct NewPost {
+    /// Post text (at most 300 graphemes).
+    pub text: String,
+    /// Images to embed (at most 4).
+    pub images: Vec<ImageUpload>,
+    /// Thread the post replies to.
+    pub reply: Option<ReplyRef>,
+    /// BCP-47 language tags.
+    pub langs: Vec<String>,
+}
+
+impl NewPost {
+    /// Create a text-only post.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// XRPC error body.
+#[derive(Debug, Default, Deserialize)]
+struct XrpcErrorBody {
+    #[serde(default)]
+    error: String,
+    #[serde(default)]
+    message: String,
+}
+
+/// Client for the Bluesky XRPC API with session management.
+///
+/// Logs in lazily with an app password and refreshes the access token when
+/// it expires.
+pub struct BlueskyClient {
+    service: String,
+    identifier: String,
+    app_password: String,
+    http: reqwest::Client,
+    session: Mutex<Option<BlueskySession>>,
+}
+
+impl std::fmt::Debug for BlueskyClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlueskyClient")
+            .field("service", &self.service)
+            .field("identifier", &self.identifier)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BlueskyClient {
+    /// Create a client for an account.
+    ///
+    /// # Arguments
+    ///
+    /// * `service` - PDS base URL (usually [`BLUESKY_DEFAULT_SERVICE`])
+    /// * `identifier` - Handle or DID
+    /// * `app_password` - App password (not the account password)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the service URL is not http(s) or credentials are empty.
+    pub fn new(
+        service: impl Into<String>,
+        identifier: impl Into<String>,
+        app_password: impl Into<String>,
+    ) -> BlueskyResult<Self> {
+        let service = service.into().trim_end_matches('/').to_string();
+        let identifier = identifier.into();
+        let app_password = app_password.into();
+
+        if !(service.starts_with("http://") || service.starts_with("https://")) {
+            return Err(BlueskyError::new(BlueskyErrorKind::ConfigurationError(
+                format!("Service must be an http(s) URL: {}", service),
+            )));
+        }
+        if identifier.is_empty() || app_password.is_empty() {
+            return Err(BlueskyError::new(BlueskyErrorKind::ConfigurationError(
+                "Identifier and app password are required".to_string(),
+            )));
+        }
+
+        Ok(Self {
+            service,
+            identifier,
+            app_password,
+            http: reqwest::Client::new(),
+            session: Mutex::new(None),
+        })
+    }
+
+    /// Create a client from the `bluesky_identifier` and `bluesky_app_password` secrets.
+    ///
+    /// The optional `bluesky_service` secret overrides the default service.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required secret is missing.
+    pub fn from_secrets() -> BlueskyResult<Self> {
+        let secret = |name: &str| {
+            botticelli_secrets::get_secret(name)
+                .map(|s| s.into_inner())
+                .map_err(|e| BlueskyError::new(BlueskyErrorKind::ConfigurationError(e.to_string())))
+        };
+        let service =
+            secret("bluesky_service").unwrap_or_else(|_| BLUESKY_DEFAULT_SERVICE.to_string());
+        Self::new(
+            service,
+            secret("bluesky_identifier")?,
+            secret("bluesky_app_password")?,
+        )
+    }
+
+    /// Service base URL.
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// Account identifier used to log in.
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Current session, logging in if necessary.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if login fails.
+    pub async fn session(&self) -> BlueskyResult<BlueskySession> {
+        let mut session = self.session.lock().await;
+        if let Some(existing) = session.as_ref() {
+            return Ok(existing.clone());
+        }
+        let created = self.create_session().await?;
+        *session = Some(created.clone());
+        Ok(created)
+    }
+
+    /// Log in with the app password.
+    #[instrument(skip(self), fields(identifier = %self.identifier))]
+    async fn create_session(&self) -> BlueskyResult<BlueskySession> {
+        debug!("Creating Bluesky session");
+        let response = self
+            .http
+            .post(self.xrpc_url("com.atproto.server.createSession"))
+            .json(&json!({
+                "identifier": self.identifier,
+                "password": self.app_password,
+            }))
+            .send()
+            .await?;
+        let session: BlueskySession = check(response).await?.json().await?;
+        info!(did = %session.did, handle = %session.handle, "Bluesky session created");
+        Ok(session)
+    }
+
+    /// Refresh the access token, falling back to a fresh login.
+    #[instrument(skip(self))]
+    async fn refresh_session(&self) -> BlueskyResult<BlueskySession> {
+        let mut session = self.session.lock().await;
+
+        let refreshed = match session.as_ref() {
+            Some(current) => {
+                let response = self
+                    .http
+                    .post(self.xrpc_url("com.atproto.server.refreshSession"))
+                    .bearer_auth(&current.refresh_jwt)
+                    .send()
+                    .await?;
+                match check(response).await {
+                    Ok(response) => response.json::<BlueskySession>().await.ok(),
+                    Err(e) => {
+                        warn!(error = %e, "Bluesky session refresh failed, logging in again");
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+
+        let refreshed = match refreshed {
+            Some(s) => s,
+            None => self.create_session().await?,
+        };
+        *session = Some(refreshed.clone());
+        Ok(refreshed)
+    }
+
+    fn xrpc_url(&self, nsid: &str) -> String {
+        format!("{}/xrpc/{}", self.service, nsid)
+    }
+
+    /// Send an authenticated request, refreshing the session once if the token expired.
+    async fn send_authed(&self, build: impl Fn(&str) -> RequestBuilder) -> BlueskyResult<Response> {
+        let session = self.session().await?;
+        let response = build(&session.access_jwt).send().await?;
+
+        match check(response).await {
+            Err(e) if is_expired_token(&e) => {
+                debug!("Bluesky access token expired, refreshing");
+                let session = self.refresh_session().await?;
+                let response = build(&session.access_jwt).send().await?;
+                check(response).await
+            }
+            result => result,
+        }
+    }
+
+    /// Call an XRPC query (GET).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the service rejects it.
+    pub async fn query(&self, nsid: &str, params: &[(&str, String)]) -> BlueskyResult<JsonValue> {
+        let url = self.xrpc_url(nsid);
+        let response = self
+            .send_authed(|token| {
+                self.http
+                    .request(Method::GET, &url)
+                    .bearer_auth(token)
+                    .query(params)
+            })
+            .await?;
+        Ok(response.json().await?)
+    }
+
+    /// Call an XRPC procedure (POST with JSON body).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the service rejects it.
+    pub async fn procedure(&self, nsid: &str, body: &JsonValue) -> BlueskyResult<JsonValue> {
+        let url = self.xrpc_url(nsid);
+        let response = self
+            .send_authed(|token| self.http.post(&url).bearer_auth(token).json(body))
+            .await?;
+        Ok(response.json().await?)
+    }
+
+    /// Upload a blob and return the blob reference for embedding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the upload fails.
+    #[instrument(skip(self, bytes), fields(size = bytes.len()))]
+    pub async fn upload_blob(&self, bytes: Vec<u8>, mime_type: &str) -> BlueskyResult<JsonValue> {
+        let url = self.xrpc_url("com.atproto.repo.uploadBlob");
+        let response = self
+            .send_authed(|token| {
+                self.http
+                    .post(&url)
+                    .bearer_auth(token)
+                    .header(reqwest::header::CONTENT_TYPE, mime_type)
+                    .body(bytes.clone())
+            })
+            .await?;
+        let body: JsonValue = response.json().await?;
+        body.get("blob").cloned().ok_or_else(|| {
+            BlueskyError::new(BlueskyErrorKind::Http(
+                "uploadBlob response missing blob".to_string(),
+            ))
+        })
+    }
+
+    /// Resolve a handle to a DID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the handle does not exist.
+    pub async fn resolve_handle(&self, handle: &str) -> BlueskyResult<String> {
+        let body = self
+            .query(
+                "com.atproto.identity.resolveHandle",
+                &[("handle", handle.to_string())],
+            )
+            .await?;
+        body.get("did")
+            .and_then(|d| d.as_str())
+            .map(str::to_string)
+            .ok_or_else(|| {
+                BlueskyError::new(BlueskyErrorKind::InvalidInput(format!(
+                    "Could not resolve handle {}",
+                    handle
+                )))
+            })
+    }
+
+    /// Create a post with facets, images and optional reply target.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the post is too long, has too many images, or the
+    /// service rejects it.
+    #[instrument(skip(self, post), fields(graphemes = grapheme_len(&post.text), images = post.images.len()))]
+    pub async fn create_post(&self, post: &NewPost) -> BlueskyResult<PostRef> {
+        let length = grapheme_len(&post.text);
+        if length > BLUESKY_MAX_GRAPHEMES {
+            return Err(BlueskyError::new(BlueskyErrorKind::PostTooLong(length)));
+        }
+        if post.images.len() > BLUESKY_MAX_IMAGES {
+            return Err(BlueskyError::new(BlueskyErrorKind::InvalidInput(format!(
+                "Too many images ({}, max {})",
+                post.images.len(),
+                BLUESKY_MAX_IMAGES
+            ))));
+        }
+
+        let session = self.session().await?;
+
+        let mut record = json!({
+            "$type": "app.bsky.feed.post",
+            "text": post.text,
+            "createdAt": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        });
+
+        let facets = self.facet_records(&post.text).await;
+        if !facets.is_empty() {
+            record["facets"] = JsonValue::Array(facets);
+        }
+
+        if !post.images.is_empty() {
+            let mut images = Vec::with_capacity(post.images.len());
+            for image in &post.images {
+                let blob = self
+                    .upload_blob(image.bytes.clone(), &image.mime_type)
+                    .await?;
+                images.push(json!({ "alt": image.alt, "image": blob }));
+            }
+            record["embed"] = json!({ "$type": "app.bsky.embed.images", "images": images });
+        }
+
+        if let Some(reply) = &post.reply {
+            record["reply"] = serde_json::to_value(reply)
+                .map_err(|e| BlueskyError::new(BlueskyErrorKind::InvalidInput(e.to_string())))?;
+        }
+        if !post.langs.is_empty() {
+            record["langs"] = json!(post.langs);
+        }
+
+        let created = self
+            .procedure(
+                "com.atproto.repo.createRecord",
+                &json!({
+                    "repo": session.did,
+                    "collection": "app.bsky.feed.post",
+                    "record": record,
+                }),
+            )
+            .await?;
+
+        let post_ref: PostRef = serde_json::from_value(created)
+            .map_err(|e| BlueskyError::new(BlueskyErrorKind::Http(e.to_string())))?;
+        info!(uri = %post_ref.uri, "Created Bluesky post");
+        Ok(post_ref)
+    }
+
+    /// Build facet records, resolving mentions to DIDs.
+    ///
+    /// Mentions of handles that cannot be resolved are left as plain text.
+    async fn facet_records(&self, text: &str) -> Vec<JsonValue> {
+        let mut records = Vec::new();
+        for facet in detect_facets(text) {
+            let did = match &facet.feature {
+                FacetFeature::Mention(handle) => match self.resolve_handle(handle).await {
+                    Ok(did) => Some(did),
+                    Err(e) => {
+                        warn!(handle = %handle, error = %e, "Skipping unresolvable mention");
+                        continue;
+                    }
+                },
+                _ => None,
+            };
+            records.extend(facet.to_record(did.as_deref()));
+        }
+        records
+    }
+
+    /// Home timeline of the logged-in account.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn timeline(
+        &self,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> BlueskyResult<JsonValue> {
+        self.query("app.bsky.feed.getTimeline", &page_params(limit, cursor))
+            .await
+    }
+
+    /// Posts by an account.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn author_feed(
+        &self,
+        actor: &str,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> BlueskyResult<JsonValue> {
+        let mut params = page_params(limit, cursor);
+        params.push(("actor", actor.to_string()));
+        self.query("app.bsky.feed.getAuthorFeed", &params).await
+    }
+
+    /// Notifications of the logged-in account.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn notifications(
+        &self,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> BlueskyResult<JsonValue> {
+        self.query(
+            "app.bsky.notification.listNotifications",
+            &page_params(limit, cursor),
+        )
+        .await
+    }
+
+    /// Profile of an account (handle or DID).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn profile(&self, actor: &str) -> BlueskyResult<JsonValue> {
+        self.query("app.bsky.actor.getProfile", &[("actor", actor.to_string())])
+            .await
+    }
+}
+
+fn page_params(limit: Option<u32>, cursor: Option<&str>) -> Vec<(&'static str, String)> {
+    let mut params = Vec::new();
+    if let Some(limit) = limit {
+        params.push(("limit", limit.to_string()));
+    }
+    if let Some(cursor) = cursor {
+        params.push(("cursor", cursor.to_string()));
+    }
+    params
+}
+
+fn is_expired_token(error: &BlueskyError) -> bool {
+    match error.kind() {
+        BlueskyErrorKind::Xrpc { error, .. } => error == "ExpiredToken",
+        BlueskyErrorKind::AuthenticationFailed(_) => true,
+        _ => false,
+    }
+}
+
+/// Turn an unsuccessful XRPC response into an error.
+async fn check(response: Response) -> BlueskyResult<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = retry_after_secs(&response).unwrap_or(DEFAULT_RETRY_AFTER_SECS);
+        return Err(BlueskyError::new(BlueskyErrorKind::RateLimited(
+            retry_after,
+        )));
+    }
+
+    let body: XrpcErrorBody = response.json().await.unwrap_or_default();
+    if status == StatusCode::UNAUTHORIZED {
+        return Err(BlueskyError::new(BlueskyErrorKind::AuthenticationFailed(
+            format!("{}: {}", body.error, body.message),
+        )));
+    }
+
+    Err(BlueskyError::new(BlueskyErrorKind::Xrpc {
+        status: status.as_u16(),
+        error: body.error,
+        message: body.message,
+    }))
+}
+
+/// Seconds until the rate limit resets (`Retry-After` or `RateLimit-Reset`).
+fn retry_after_secs(response: &Response) -> Option<u64> {
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok())
+    };
+
+    header("retry-after").or_else(|| {
+        let reset = header("ratelimit-reset")?;
+        let now = chrono::Utc::now().timestamp().max(0) as u64;
+        Some(reset.saturating_sub(now))
+    })
+}
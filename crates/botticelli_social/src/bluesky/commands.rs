@@ -0,0 +1,592 @@
+//! Bluesky bot command executor.
+
+use super::client::{BlueskyClient, ImageUpload, NewPost, PostRef, ReplyRef};
+use super::facets::grapheme_len;
+use super::{BLUESKY_MAX_GRAPHEMES, BlueskyError, BlueskyErrorKind};
+use crate::{BotCommandError, BotCommandErrorKind, BotCommandExecutor, BotCommandResult};
+use async_trait::async_trait;
+use serde_json::{Value as JsonValue, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{debug, error, info, instrument};
+
+/// Bluesky command executor for narrative-driven Bluesky operations.
+///
+/// Supported commands:
+/// - `posts.create` - Create a post (facets detected automatically)
+/// - `feed.get` - Read the home timeline or an account's posts
+/// - `notifications.list` - List notifications
+/// - `profile.get` - Get an account profile
+///
+/// # Example
+///
+/// ```toml
+/// [bots.crosspost]
+/// platform = "bluesky"
+/// command = "posts.create"
+/// text = "{{select_best}}"
+///
+/// [bots.timeline]
+/// platform = "bluesky"
+/// command = "feed.get"
+/// limit = 20
+/// ```
+#[derive(Debug, Clone)]
+pub struct BlueskyCommandExecutor {
+    client: Arc<BlueskyClient>,
+    http: reqwest::Client,
+}
+
+impl BlueskyCommandExecutor {
+    /// Create an executor around a Bluesky client.
+    pub fn new(client: BlueskyClient) -> Self {
+        Self::with_client(Arc::new(client))
+    }
+
+    /// Create an executor sharing an existing client.
+    pub fn with_client(client: Arc<BlueskyClient>) -> Self {
+        Self {
+            client,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Get the underlying client.
+    pub fn client(&self) -> &Arc<BlueskyClient> {
+        &self.client
+    }
+
+    /// Create a post.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command arguments:
+    ///   - `text` (required): Post text, at most 300 graphemes
+    ///   - `images` (optional): Image URLs or file paths (at most 4)
+    ///   - `alt` (optional): Alt text applied to all images
+    ///   - `reply_to_uri` / `reply_to_cid` (optional): Post to reply to
+    ///   - `langs` (optional): Language tags
+    #[instrument(skip(self, args), fields(command = "posts.create"))]
+    async fn posts_create(&self, args: &HashMap<String, JsonValue>) -> BotCommandResult<JsonValue> {
+        const COMMAND: &str = "posts.create";
+        let text = required_str(args, COMMAND, "text")?;
+
+        let length = grapheme_len(text);
+        if length > BLUESKY_MAX_GRAPHEMES {
+            return Err(BotCommandError::new(BotCommandErrorKind::InvalidArgument {
+                command: COMMAND.to_string(),
+                arg_name: "text".to_string(),
+                reason: format!(
+                    "Text is {} graphemes, Bluesky allows {}",
+                    length, BLUESKY_MAX_GRAPHEMES
+                ),
+            }));
+        }
+
+        let alt = args.get("alt").and_then(|v| v.as_str()).unwrap_or_default();
+        let mut images = Vec::new();
+        for url in string_list(args, "images") {
+            images.push(self.load_image(&url, alt).await?);
+        }
+
+        let reply = match (
+            args.get("reply_to_uri").and_then(|v| v.as_str()),
+            args.get("reply_to_cid").and_then(|v| v.as_str()),
+        ) {
+            (Some(uri), Some(cid)) => {
+                let parent = PostRef {
+                    uri: uri.to_string(),
+                    cid: cid.to_string(),
+                };
+                Some(ReplyRef {
+                    root: parent.clone(),
+                    parent,
+                })
+            }
+            _ => None,
+        };
+
+        let post = NewPost {
+            text: text.to_string(),
+            images,
+            reply,
+            langs: string_list(args, "langs"),
+        };
+
+        debug!(
+            graphemes = length,
+            images = post.images.len(),
+            "Creating Bluesky post"
+        );
+        let created = self
+            .client
+            .create_post(&post)
+            .await
+            .map_err(|e| api_error(COMMAND, e))?;
+
+        Ok(json!({ "uri": created.uri, "cid": created.cid }))
+    }
+
+    /// Read the home timeline, or an account's posts when `actor` is given.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command arguments:
+    ///   - `actor` (optional): Handle or DID whose posts to read
+    ///   - `limit` (optional): Maximum posts (1-100)
+    ///   - `cursor` (optional): Pagination cursor from a previous call
+    #[instrument(skip(self, args), fields(command = "feed.get"))]
+    async fn feed_get(&self, args: &HashMap<String, JsonValue>) -> BotCommandResult<JsonValue> {
+        const COMMAND: &str = "feed.get";
+        let limit = optional_limit(args, COMMAND)?;
+        let cursor = args.get("cursor").and_then(|v| v.as_str());
+
+        let body = match args.get("actor").and_then(|v| v.as_str()) {
+            Some(actor) => self.client.author_feed(actor, limit, cursor).await,
+            None => self.client.timeline(limit, cursor).await,
+        }
+        .map_err(|e| api_error(COMMAND, e))?;
+
+        let posts: Vec<JsonValue> = body
+            .get("feed")
+            .and_then(|f| f.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| item.get("post"))
+                    .map(summarize_post)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(json!({
+            "posts": posts,
+            "cursor": body.get("cursor").cloned().unwrap_or(JsonValue::Null),
+        }))
+    }
+
+    /// List notifications.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command arguments:
+    ///   - `limit` (optional): Maximum notifications (1-100)
+    ///   - `cursor` (optional): Pagination cursor from a previous call
+    #[instrument(skip(self, args), fields(command = "notifications.list"))]
+    async fn notifications_list(
+        &self,
+        args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        const COMMAND: &str = "notifications.list";
+        let limit = optional_limit(args, COMMAND)?;
+        let cursor = args.get("cursor").and_then(|v| v.as_str());
+
+        let body = self
+            .client
+            .notifications(limit, cursor)
+            .await
+            .map_err(|e| api_error(COMMAND, e))?;
+
+        let notifications: Vec<JsonValue> = body
+            .get("notifications")
+            .and_then(|n| n.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .map(|n| {
+                        json!({
+                            "reason": n.get("reason"),
+                            "author": n.pointer("/author/handle"),
+                            "text": n.pointer("/record/text"),
+                            "uri": n.get("uri"),
+                            "is_read": n.get("isRead"),
+                            "indexed_at": n.get("indexedAt"),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(json!({
+            "notifications": notifications,
+            "cursor": body.get("cursor").cloned().unwrap_or(JsonValue::Null),
+        }))
+    }
+
+    /// Get a profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command arguments:
+    ///   - `actor` (optional): Handle or DID (defaults to the logged-in account)
+    #[instrument(skip(self, args), fields(command = "profile.get"))]
+    async fn profile_get(&self, args: &HashMap<String, JsonValue>) -> BotCommandResult<JsonValue> {
+        const COMMAND: &str = "profile.get";
+        let actor = match args.get("actor").and_then(|v| v.as_str()) {
+            Some(actor) => actor.to_string(),
+            None => {
+                self.client
+                    .session()
+                    .await
+                    .map_err(|e| api_error(COMMAND, e))?
+                    .did
+            }
+        };
+
+        let profile = self
+            .client
+            .profile(&actor)
+            .await
+            .map_err(|e| api_error(COMMAND, e))?;
+
+        Ok(json!({
+            "did": profile.get("did"),
+            "handle": profile.get("handle"),
+            "display_name": profile.get("displayName"),
+            "description": profile.get("description"),
+            "followers_count": profile.get("followersCount"),
+            "follows_count": profile.get("followsCount"),
+            "posts_count": profile.get("postsCount"),
+        }))
+    }
+
+    /// Download or read an image for upload.
+    async fn load_image(&self, url: &str, alt: &str) -> BotCommandResult<ImageUpload> {
+        let invalid = |reason: String| {
+            BotCommandError::new(BotCommandErrorKind::InvalidArgument {
+                command: "posts.create".to_string(),
+                arg_name: "images".to_string(),
+                reason,
+            })
+        };
+
+        let (bytes, mime_type) = if url.starts_with("http://") || url.starts_with("https://") {
+            let response = self
+                .http
+                .get(url)
+                .send()
+                .await
+                .and_then(|r| r.error_for_status())
+                .map_err(|e| invalid(format!("Failed to download {}: {}", url, e)))?;
+            let mime = response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            let bytes = response
+                .bytes()
+                .await
+                .map_err(|e| invalid(format!("Failed to download {}: {}", url, e)))?;
+            (bytes.to_vec(), mime)
+        } else {
+            let path = url.strip_prefix("file://").unwrap_or(url);
+            let bytes = tokio::fs::read(path)
+                .await
+                .map_err(|e| invalid(format!("Failed to read {}: {}", path, e)))?;
+            (bytes, None)
+        };
+
+        // Servers often send a generic content type, so fall back to the extension
+        let mime_type = mime_type
+            .filter(|mime| mime.starts_with("image/"))
+            .or_else(|| image_mime_from_extension(url).map(str::to_string))
+            .ok_or_else(|| invalid(format!("Unknown image type for {}", url)))?;
+
+        Ok(ImageUpload {
+            bytes,
+            mime_type,
+            alt: alt.to_string(),
+        })
+    }
+}
+
+/// Compact representation of a post view for LLM consumption.
+fn summarize_post(post: &JsonValue) -> JsonValue {
+    json!({
+        "uri": post.get("uri"),
+        "cid": post.get("cid"),
+        "author": post.pointer("/author/handle"),
+        "text": post.pointer("/record/text"),
+        "created_at": post.pointer("/record/createdAt"),
+        "like_count": post.get("likeCount"),
+        "repost_count": post.get("repostCount"),
+        "reply_count": post.get("replyCount"),
+    })
+}
+
+fn image_mime_from_extension(url: &str) -> Option<&'static str> {
+    let extension = url.rsplit('.').next()?.to_ascii_lowercase();
+    Some(match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        _ => return None,
+    })
+}
+
+fn required_str<'a>(
+    args: &'a HashMap<String, JsonValue>,
+    command: &str,
+    arg_name: &str,
+) -> BotCommandResult<&'a str> {
+    args.get(arg_name).and_then(|v| v.as_str()).ok_or_else(|| {
+        BotCommandError::new(BotCommandErrorKind::MissingArgument {
+            command: command.to_string(),
+            arg_name: arg_name.to_string(),
+        })
+    })
+}
+
+/// Read a list argument given as an array or a single string.
+fn string_list(args: &HashMap<String, JsonValue>, arg_name: &str) -> Vec<String> {
+    match args.get(arg_name) {
+        Some(JsonValue::Array(items)) => items
+            .iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect(),
+        Some(JsonValue::String(s)) if !s.is_empty() => vec![s.clone()],
+        _ => Vec::new(),
+    }
+}
+
+fn optional_limit(
+    args: &HashMap<String, JsonValue>,
+    command: &str,
+) -> BotCommandResult<Option<u32>> {
+    let Some(value) = args.get("limit") else {
+        return Ok(None);
+    };
+    let limit = value
+        .as_u64()
+        .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+        .filter(|l| (1..=100).contains(l))
+        .ok_or_else(|| {
+            BotCommandError::new(BotCommandErrorKind::InvalidArgument {
+                command: command.to_string(),
+                arg_name: "limit".to_string(),
+                reason: "must be between 1 and 100".to_string(),
+            })
+        })?;
+    Ok(Some(limit as u32))
+}
+
+/// Map a client error to the matching bot command error.
+#[track_caller]
+fn api_error(command: &str, error: BlueskyError) -> BotCommandError {
+    error!(command, error = %error, "Bluesky command failed");
+    let kind = match error.kind() {
+        BlueskyErrorKind::AuthenticationFailed(reason) => {
+            BotCommandErrorKind::AuthenticationError {
+                platform: "bluesky".to_string(),
+                reason: reason.clone(),
+            }
+        }
+        BlueskyErrorKind::RateLimited(retry_after) => BotCommandErrorKind::RateLimitExceeded {
+            command: command.to_string(),
+            retry_after: *retry_after,
+        },
+        other => BotCommandErrorKind::ApiError {
+            command: command.to_string(),
+            reason: other.to_string(),
+        },
+    };
+    BotCommandError::new(kind)
+}
+
+#[async_trait]
+impl BotCommandExecutor for BlueskyCommandExecutor {
+    fn platform(&self) -> &str {
+        "bluesky"
+    }
+
+    fn supports_command(&self, command: &str) -> bool {
+        matches!(
+            command,
+            "posts.create" | "feed.get" | "notifications.list" | "profile.get"
+        )
+    }
+
+    fn supported_commands(&self) -> Vec<String> {
+        vec![
+            "posts.create".to_string(),
+            "feed.get".to_string(),
+            "notifications.list".to_string(),
+            "profile.get".to_string(),
+        ]
+    }
+
+    #[instrument(
+        skip(self, args),
+        fields(
+            platform = "bluesky",
+            command = %command,
+            arg_count = args.len()
+        )
+    )]
+    async fn execute(
+        &self,
+        command: &str,
+        args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        info!("Executing Bluesky bot command");
+
+        match command {
+            "posts.create" => self.posts_create(args).await,
+            "feed.get" => self.feed_get(args).await,
+            "notifications.list" => self.notifications_list(args).await,
+            "profile.get" => self.profile_get(args).await,
+            _ => Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+                format!("bluesky.{}", command),
+            ))),
+        }
+    }
+
+    async fn messages_bulk_delete(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "bluesky.messages_bulk_delete".to_string(),
+        )))
+    }
+
+    async fn threads_create(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "bluesky.threads_create".to_string(),
+        )))
+    }
+
+    async fn threads_list(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "bluesky.threads_list".to_string(),
+        )))
+    }
+
+    async fn threads_get(&self, _args: &HashMap<String, JsonValue>) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "bluesky.threads_get".to_string(),
+        )))
+    }
+
+    async fn threads_edit(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "bluesky.threads_edit".to_string(),
+        )))
+    }
+
+    async fn threads_delete(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "bluesky.threads_delete".to_string(),
+        )))
+    }
+
+    async fn threads_join(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "bluesky.threads_join".to_string(),
+        )))
+    }
+
+    async fn threads_leave(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "bluesky.threads_leave".to_string(),
+        )))
+    }
+
+    async fn threads_add_member(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "bluesky.threads_add_member".to_string(),
+        )))
+    }
+
+    async fn threads_remove_member(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "bluesky.threads_remove_member".to_string(),
+        )))
+    }
+
+    async fn reactions_list(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "bluesky.reactions_list".to_string(),
+        )))
+    }
+
+    async fn reactions_clear(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "bluesky.reactions_clear".to_string(),
+        )))
+    }
+
+    async fn reactions_clear_emoji(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "bluesky.reactions_clear_emoji".to_string(),
+        )))
+    }
+
+    fn command_help(&self, command: &str) -> Option<String> {
+        let help = match command {
+            "posts.create" => {
+                "Create a Bluesky post. Links, mentions and hashtags become facets automatically.\n\n\
+                Arguments:\n\
+                - text: Post text (max 300 graphemes)\n\
+                - images (optional): Image URLs or file paths (max 4)\n\
+                - alt (optional): Alt text for the images\n\
+                - reply_to_uri, reply_to_cid (optional): Post to reply to\n\
+                - langs (optional): Language tags"
+            }
+            "feed.get" => {
+                "Read the home timeline, or an account's posts.\n\n\
+                Arguments:\n\
+                - actor (optional): Handle or DID\n\
+                - limit (optional): 1-100\n\
+                - cursor (optional): Pagination cursor"
+            }
+            "notifications.list" => {
+                "List notifications (likes, replies, mentions, follows).\n\n\
+                Arguments:\n\
+                - limit (optional): 1-100\n\
+                - cursor (optional): Pagination cursor"
+            }
+            "profile.get" => {
+                "Get a profile.\n\n\
+                Arguments:\n\
+                - actor (optional): Handle or DID, defaults to the bot account"
+            }
+            _ => return None,
+        };
+        Some(help.to_string())
+    }
+}
@@ -0,0 +1,74 @@
+//! Bluesky-specific error types.
+
+use derive_getters::Getters;
+
+/// Bluesky error variants.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
+pub enum BlueskyErrorKind {
+    /// HTTP request could not be sent or the response could not be read.
+    #[display("HTTP error: {_0}")]
+    Http(String),
+
+    /// Login or token refresh was rejected.
+    #[display("Authentication failed: {_0}")]
+    AuthenticationFailed(String),
+
+    /// The service is rate limiting requests.
+    #[display("Rate limited: retry after {_0}s")]
+    RateLimited(u64),
+
+    /// The XRPC call returned an error.
+    #[display("XRPC error {status} ({error}): {message}")]
+    Xrpc {
+        /// HTTP status code.
+        status: u16,
+        /// AT Protocol error name (e.g. "InvalidRequest").
+        error: String,
+        /// Human-readable message.
+        message: String,
+    },
+
+    /// Post text exceeds the grapheme limit.
+    #[display("Post is {_0} graphemes, limit is {}", super::BLUESKY_MAX_GRAPHEMES)]
+    PostTooLong(usize),
+
+    /// Invalid input (handle, media, arguments).
+    #[display("Invalid input: {_0}")]
+    InvalidInput(String),
+
+    /// Configuration error (missing credentials, invalid service URL).
+    #[display("Configuration error: {_0}")]
+    ConfigurationError(String),
+}
+
+/// Bluesky error with source location tracking.
+#[derive(Debug, Clone, derive_more::Display, derive_more::Error, Getters)]
+#[display("Bluesky Error: {} at line {} in {}", kind, line, file)]
+pub struct BlueskyError {
+    kind: BlueskyErrorKind,
+    line: u32,
+    file: &'static str,
+}
+
+impl BlueskyError {
+    /// Create a new BlueskyError with automatic location tracking.
+    #[track_caller]
+    pub fn new(kind: BlueskyErrorKind) -> Self {
+        let location = std::panic::Location::caller();
+        Self {
+            kind,
+            line: location.line(),
+            file: location.file(),
+        }
+    }
+}
+
+/// Result type for Bluesky operations.
+pub type BlueskyResult<T> = Result<T, BlueskyError>;
+
+impl From<reqwest::Error> for BlueskyError {
+    #[track_caller]
+    fn from(err: reqwest::Error) -> Self {
+        BlueskyError::new(BlueskyErrorKind::Http(err.to_string()))
+    }
+}
@@ -0,0 +1,131 @@
+//! Rich text facets (links, mentions, hashtags) for Bluesky posts.
+//!
+//! Bluesky does not parse post text server-side: clients must annotate links,
+//! mentions and hashtags with facets whose ranges are UTF-8 byte offsets.
+
+use regex::Regex;
+use serde_json::{Value as JsonValue, json};
+use std::sync::LazyLock;
+use unicode_segmentation::UnicodeSegmentation;
+
+static URL_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"https?://[^\s<>"']+"#).expect("valid URL regex"));
+
+static MENTION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?:^|[\s(])(@(?:[a-zA-Z0-9](?:[a-zA-Z0-9-]*[a-zA-Z0-9])?\.)+[a-zA-Z](?:[a-zA-Z0-9-]*[a-zA-Z0-9])?)",
+    )
+    .expect("valid mention regex")
+});
+
+static TAG_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:^|\s)(#[\p{L}\p{N}_]+)").expect("valid tag regex"));
+
+/// Characters stripped from the end of detected URLs.
+const URL_TRAILING_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?', ')', ']', '\''];
+
+/// Count graphemes the way Bluesky enforces its post length limit.
+pub fn grapheme_len(text: &str) -> usize {
+    text.graphemes(true).count()
+}
+
+/// What a facet annotates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FacetFeature {
+    /// A link to a URI.
+    Link(String),
+    /// A mention of a handle (resolved to a DID when posting).
+    Mention(String),
+    /// A hashtag, without the leading `#`.
+    Tag(String),
+}
+
+/// A facet: a byte range of the post text and what it annotates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Facet {
+    /// Start of the range (UTF-8 byte offset, inclusive).
+    pub byte_start: usize,
+    /// End of the range (UTF-8 byte offset, exclusive).
+    pub byte_end: usize,
+    /// Annotated feature.
+    pub feature: FacetFeature,
+}
+
+impl Facet {
+    /// Build the `app.bsky.richtext.facet` record.
+    ///
+    /// Mentions need the DID of the mentioned account; `None` is returned for a
+    /// mention without one.
+    pub fn to_record(&self, mention_did: Option<&str>) -> Option<JsonValue> {
+        let feature = match &self.feature {
+            FacetFeature::Link(uri) => json!({
+                "$type": "app.bsky.richtext.facet#link",
+                "uri": uri,
+            }),
+            FacetFeature::Mention(_) => json!({
+                "$type": "app.bsky.richtext.facet#mention",
+                "did": mention_did?,
+            }),
+            FacetFeature::Tag(tag) => json!({
+                "$type": "app.bsky.richtext.facet#tag",
+                "tag": tag,
+            }),
+        };
+
+        Some(json!({
+            "index": { "byteStart": self.byte_start, "byteEnd": self.byte_end },
+            "features": [feature],
+        }))
+    }
+}
+
+/// Detect links, mentions and hashtags in post text.
+///
+/// Facets are returned in text order.
+pub fn detect_facets(text: &str) -> Vec<Facet> {
+    let mut facets = Vec::new();
+
+    for m in URL_RE.find_iter(text) {
+        let uri = m.as_str().trim_end_matches(URL_TRAILING_PUNCTUATION);
+        facets.push(Facet {
+            byte_start: m.start(),
+            byte_end: m.start() + uri.len(),
+            feature: FacetFeature::Link(uri.to_string()),
+        });
+    }
+
+    for caps in MENTION_RE.captures_iter(text) {
+        let m = caps.get(1).expect("mention group");
+        if overlaps(&facets, m.start(), m.end()) {
+            continue;
+        }
+        facets.push(Facet {
+            byte_start: m.start(),
+            byte_end: m.end(),
+            feature: FacetFeature::Mention(m.as_str()[1..].to_string()),
+        });
+    }
+
+    for caps in TAG_RE.captures_iter(text) {
+        let m = caps.get(1).expect("tag group");
+        let tag = &m.as_str()[1..];
+        // Purely numeric tags ("#1") are not hashtags
+        if tag.chars().all(|c| c.is_ascii_digit()) || overlaps(&facets, m.start(), m.end()) {
+            continue;
+        }
+        facets.push(Facet {
+            byte_start: m.start(),
+            byte_end: m.end(),
+            feature: FacetFeature::Tag(tag.to_string()),
+        });
+    }
+
+    facets.sort_by_key(|f| f.byte_start);
+    facets
+}
+
+fn overlaps(facets: &[Facet], start: usize, end: usize) -> bool {
+    facets
+        .iter()
+        .any(|f| start < f.byte_end && f.byte_start < end)
+}
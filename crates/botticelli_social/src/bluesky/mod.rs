@@ -0,0 +1,23 @@
+//! Bluesky integration over the AT Protocol.
+//!
+//! Provides an XRPC client with app-password session auth, automatic
+//! rich-text facets for links, mentions and hashtags, and a bot command
+//! executor for narratives.
+
+mod client;
+mod commands;
+mod error;
+mod facets;
+
+pub use client::{
+    BLUESKY_DEFAULT_SERVICE, BlueskyClient, BlueskySession, ImageUpload, NewPost, PostRef, ReplyRef,
+};
+pub use commands::BlueskyCommandExecutor;
+pub use error::{BlueskyError, BlueskyErrorKind, BlueskyResult};
+pub use facets::{Facet, FacetFeature, detect_facets, grapheme_len};
+
+/// Maximum post length in graphemes.
+pub const BLUESKY_MAX_GRAPHEMES: usize = 300;
+
+/// Maximum number of images per post.
+pub const BLUESKY_MAX_IMAGES: usize = 4;
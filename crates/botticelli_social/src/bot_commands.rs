@@ -175,6 +175,18 @@ impl BotCommandError {
     }
 }
 
+/// Database errors outside a specific command step, such as no pooled
+/// connection being available or a failed transaction commit.
+#[cfg(feature = "database")]
+impl From<botticelli_error::DatabaseError> for BotCommandError {
+    fn from(e: botticelli_error::DatabaseError) -> Self {
+        BotCommandError::new(BotCommandErrorKind::ApiError {
+            command: "database".to_string(),
+            reason: format!("Database error: {}", e),
+        })
+    }
+}
+
 /// Executes bot commands for a specific platform.
 ///
 /// Implementations handle platform-specific API calls and return structured
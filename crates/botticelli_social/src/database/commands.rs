@@ -2,8 +2,12 @@
 
 use crate::{BotCommandError, BotCommandErrorKind, BotCommandExecutor, BotCommandResult};
 use async_trait::async_trait;
-use botticelli_database::establish_connection;
-use diesel::prelude::*;
+use botticelli_database::{
+    ContentConnection, ContentStatus, transition_content_status, with_pooled_connection,
+    with_revision,
+};
+use diesel::PgConnection;
+use diesel::r2d2::{ConnectionManager, ManageConnection, Pool};
 use serde_json::{Value as JsonValue, json};
 use std::collections::{HashMap, HashSet};
 use tracing::{debug, info, instrument};
@@ -19,6 +23,10 @@ use tracing::{debug, info, instrument};
 /// - Uses parameterized queries via diesel
 /// - Updates validated before execution
 /// - Returns affected row count for verification
+/// - `review_status` changes go through the content lifecycle and are
+///   recorded in the status history
+/// - Other changes are recorded as content revisions
+/// - Rows are updated on a pooled connection, off the async runtime
 ///
 /// # Example
 ///
@@ -27,20 +35,43 @@ use tracing::{debug, info, instrument};
 /// platform = "database"
 /// command = "update_table"
 /// table_name = "approved_discord_posts"
-/// where_clause = "review_status = 'pending'"
+/// where_clause = "review_status = 'scheduled'"
 /// limit = 1
+/// actor = "discord_poster"
 ///
 /// [bots.mark_posted.updates]
 /// review_status = "posted"
 /// posted_at = "NOW()"
 /// ```
-#[derive(Debug, Clone)]
-pub struct DatabaseCommandExecutor {
+pub struct DatabaseCommandExecutor<M: ManageConnection = ConnectionManager<PgConnection>> {
+    /// Connection pool shared with the rest of the runtime.
+    pool: Pool<M>,
     /// Whitelisted table names that can be updated.
     allowed_tables: HashSet<String>,
 }
 
-impl DatabaseCommandExecutor {
+impl<M: ManageConnection> Clone for DatabaseCommandExecutor<M> {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            allowed_tables: self.allowed_tables.clone(),
+        }
+    }
+}
+
+impl<M: ManageConnection> std::fmt::Debug for DatabaseCommandExecutor<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatabaseCommandExecutor")
+            .field("allowed_tables", &self.allowed_tables)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<M> DatabaseCommandExecutor<M>
+where
+    M: ManageConnection,
+    M::Connection: ContentConnection,
+{
     /// Create a new database command executor with default allowed tables.
     ///
     /// Default allowed tables:
@@ -48,23 +79,34 @@ impl DatabaseCommandExecutor {
     /// - potential_discord_posts
     /// - content
     /// - post_history
-    pub fn new() -> Self {
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - Connection pool commands run on
+    pub fn new(pool: Pool<M>) -> Self {
         let mut allowed_tables = HashSet::new();
         allowed_tables.insert("approved_discord_posts".to_string());
         allowed_tables.insert("potential_discord_posts".to_string());
         allowed_tables.insert("content".to_string());
         allowed_tables.insert("post_history".to_string());
 
-        Self { allowed_tables }
+        Self {
+            pool,
+            allowed_tables,
+        }
     }
 
     /// Create a new executor with custom allowed tables.
     ///
     /// # Arguments
     ///
+    /// * `pool` - Connection pool commands run on
     /// * `allowed_tables` - Set of table names that can be updated
-    pub fn with_allowed_tables(allowed_tables: HashSet<String>) -> Self {
-        Self { allowed_tables }
+    pub fn with_allowed_tables(pool: Pool<M>, allowed_tables: HashSet<String>) -> Self {
+        Self {
+            pool,
+            allowed_tables,
+        }
     }
 
     /// Add a table to the whitelist.
@@ -86,6 +128,14 @@ impl DatabaseCommandExecutor {
     ///   - `where_clause` (required): WHERE clause (without WHERE keyword)
     ///   - `updates` (required): Map of column → value updates
     ///   - `limit` (optional): Maximum rows to update
+    ///   - `actor` (optional): Who to record in the status and revision
+    ///     history (default "narrative")
+    ///   - `reason` (optional): Why, recorded for `review_status` changes
+    ///
+    /// Matching rows must have an `id` column. Updating `review_status`
+    /// moves each row through [`transition_content_status`], so a change the
+    /// lifecycle forbids fails the command; other columns are written with
+    /// [`with_revision`] so the previous content is kept.
     ///
     /// # Returns
     ///
@@ -97,6 +147,7 @@ impl DatabaseCommandExecutor {
     /// - Required arguments missing
     /// - Table not whitelisted
     /// - Invalid WHERE clause
+    /// - Invalid `review_status` transition
     /// - Database error
     #[instrument(skip(self), fields(command = "update_table"))]
     async fn update_table(&self, args: &HashMap<String, JsonValue>) -> BotCommandResult<JsonValue> {
@@ -156,6 +207,36 @@ impl DatabaseCommandExecutor {
         // Extract optional limit
         let limit = args.get("limit").and_then(|v| v.as_i64()).map(|l| l as i32);
 
+        let status = updates
+            .get("review_status")
+            .map(|status| {
+                status
+                    .as_str()
+                    .ok_or_else(|| "review_status must be a string".to_string())
+                    .and_then(|s| s.parse::<ContentStatus>().map_err(|e| e.to_string()))
+                    .map_err(|reason| {
+                        BotCommandError::new(BotCommandErrorKind::InvalidArgument {
+                            command: "update_table".to_string(),
+                            arg_name: "updates".to_string(),
+                            reason,
+                        })
+                    })
+            })
+            .transpose()?;
+        let actor = args
+            .get("actor")
+            .and_then(|v| v.as_str())
+            .unwrap_or("narrative");
+        let reason = args.get("reason").and_then(|v| v.as_str());
+
+        let mut others = updates.clone();
+        others.remove("review_status");
+        let set_clause = if others.is_empty() {
+            None
+        } else {
+            Some(Self::set_clause(&others)?)
+        };
+
         debug!(
             table_name = %table_name,
             where_clause = %where_clause,
@@ -164,8 +245,118 @@ impl DatabaseCommandExecutor {
             "Executing update_table command"
         );
 
-        // Build SET clause
-        let set_clause = updates
+        // Rows are updated one at a time so each change is versioned, in one
+        // transaction so a failure part way through changes nothing
+        let mut select = format!("SELECT id FROM {} WHERE {}", table_name, where_clause);
+        if let Some(limit_val) = limit {
+            select.push_str(&format!(" LIMIT {}", limit_val));
+        }
+
+        debug!(select = %select, "Selecting rows to update");
+
+        let table = table_name.to_string();
+        let actor = actor.to_string();
+        let reason = reason.map(str::to_string);
+        let ids = with_pooled_connection(&self.pool, move |conn| {
+            conn.begin_transaction()?;
+            let updated = Self::update_rows(
+                conn,
+                &table,
+                &select,
+                status,
+                set_clause.as_deref(),
+                &actor,
+                reason.as_deref(),
+            );
+            match updated {
+                Ok(ids) => {
+                    conn.commit_transaction()?;
+                    Ok(ids)
+                }
+                Err(e) => {
+                    let _ = conn.rollback_transaction();
+                    Err(e)
+                }
+            }
+        })
+        .await?;
+
+        info!(
+            table_name = %table_name,
+            rows_affected = ids.len(),
+            "Successfully updated table"
+        );
+
+        Ok(json!({
+            "rows_affected": ids.len(),
+            "table_name": table_name,
+        }))
+    }
+
+    /// Update the rows `select` returns, inside the caller's transaction.
+    fn update_rows(
+        conn: &mut M::Connection,
+        table_name: &str,
+        select: &str,
+        status: Option<ContentStatus>,
+        set_clause: Option<&str>,
+        actor: &str,
+        reason: Option<&str>,
+    ) -> BotCommandResult<Vec<i64>> {
+        let update_error = |e: &dyn std::fmt::Display| {
+            BotCommandError::new(BotCommandErrorKind::ApiError {
+                command: "update_table".to_string(),
+                reason: format!("Update failed: {}", e),
+            })
+        };
+
+        let id_column: Vec<_> = conn
+            .table_columns(table_name)
+            .map_err(|e| update_error(&e))?
+            .into_iter()
+            .filter(|column| column.name == "id")
+            .collect();
+        let ids = conn
+            .load_json(select, &id_column, &[])
+            .map_err(|e| update_error(&e))?
+            .iter()
+            .map(|row| {
+                row["id"]
+                    .as_i64()
+                    .ok_or_else(|| update_error(&format!("row has no integer id: {}", row)))
+            })
+            .collect::<BotCommandResult<Vec<i64>>>()?;
+
+        for id in &ids {
+            if let Some(status) = status {
+                transition_content_status(conn, table_name, *id, status, actor, reason).map_err(
+                    |e| {
+                        BotCommandError::new(BotCommandErrorKind::ApiError {
+                            command: "update_table".to_string(),
+                            reason: format!("Status change failed: {}", e),
+                        })
+                    },
+                )?;
+            }
+
+            if let Some(set_clause) = set_clause {
+                let query = format!("UPDATE {} SET {} WHERE id = {}", table_name, set_clause, id);
+                debug!(query = %query, "Executing UPDATE query");
+
+                with_revision(conn, table_name, *id, actor, |conn| {
+                    conn.execute_sql(&query, &[])?;
+                    Ok(())
+                })
+                .map_err(|e| update_error(&e))?;
+            }
+        }
+
+        Ok(ids)
+    }
+
+    /// Builds an UPDATE's SET clause from a column → value map.
+    fn set_clause(updates: &serde_json::Map<String, JsonValue>) -> BotCommandResult<String> {
+        Ok(updates
             .iter()
             .map(|(col, val)| {
                 let val_str = match val {
@@ -191,76 +382,16 @@ impl DatabaseCommandExecutor {
                 Ok(format!("{} = {}", col, val_str))
             })
             .collect::<Result<Vec<_>, _>>()?
-            .join(", ");
-
-        // Build full UPDATE query
-        let query = if let Some(limit_val) = limit {
-            format!(
-                "UPDATE {} SET {} WHERE {} LIMIT {}",
-                table_name, set_clause, where_clause, limit_val
-            )
-        } else {
-            format!(
-                "UPDATE {} SET {} WHERE {}",
-                table_name, set_clause, where_clause
-            )
-        };
-
-        debug!(query = %query, "Executing UPDATE query");
-
-        // Execute query
-        let mut conn = establish_connection().map_err(|e| {
-            BotCommandError::new(BotCommandErrorKind::ApiError {
-                command: "update_table".to_string(),
-                reason: format!("Database connection failed: {}", e),
-            })
-        })?;
-
-        // PostgreSQL doesn't support LIMIT in UPDATE without a subquery
-        // Rewrite query for PostgreSQL compatibility
-        let query = if limit.is_some() {
-            let limit_val = limit.unwrap();
-            format!(
-                "UPDATE {} SET {} WHERE ctid IN (SELECT ctid FROM {} WHERE {} LIMIT {})",
-                table_name, set_clause, table_name, where_clause, limit_val
-            )
-        } else {
-            format!(
-                "UPDATE {} SET {} WHERE {}",
-                table_name, set_clause, where_clause
-            )
-        };
-
-        debug!(query = %query, "Executing PostgreSQL-compatible UPDATE");
-
-        let rows_affected = diesel::sql_query(&query).execute(&mut conn).map_err(|e| {
-            BotCommandError::new(BotCommandErrorKind::ApiError {
-                command: "update_table".to_string(),
-                reason: format!("Update failed: {}", e),
-            })
-        })?;
-
-        info!(
-            table_name = %table_name,
-            rows_affected = rows_affected,
-            "Successfully updated table"
-        );
-
-        Ok(json!({
-            "rows_affected": rows_affected,
-            "table_name": table_name,
-        }))
-    }
-}
-
-impl Default for DatabaseCommandExecutor {
-    fn default() -> Self {
-        Self::new()
+            .join(", "))
     }
 }
 
 #[async_trait]
-impl BotCommandExecutor for DatabaseCommandExecutor {
+impl<M> BotCommandExecutor for DatabaseCommandExecutor<M>
+where
+    M: ManageConnection,
+    M::Connection: ContentConnection,
+{
     fn platform(&self) -> &str {
         "database"
     }
@@ -422,11 +553,14 @@ impl BotCommandExecutor for DatabaseCommandExecutor {
                 - table_name: Name of table to update (must be whitelisted)\n\
                 - where_clause: WHERE clause condition (without WHERE keyword)\n\
                 - updates: Object mapping column names to new values\n\
-                - limit (optional): Maximum number of rows to update\n\n\
+                - limit (optional): Maximum number of rows to update\n\
+                - actor (optional): Who to record in the status and revision history\n\
+                - reason (optional): Why, recorded for review_status changes\n\n\
+                review_status changes must follow the content lifecycle.\n\n\
                 Example:\n\
                 {\n\
                   \"table_name\": \"approved_discord_posts\",\n\
-                  \"where_clause\": \"review_status = 'pending'\",\n\
+                  \"where_clause\": \"review_status = 'scheduled'\",\n\
                   \"updates\": {\"review_status\": \"posted\", \"posted_at\": \"NOW()\"},\n\
                   \"limit\": 1\n\
                 }"
@@ -3,8 +3,11 @@
 //! This module provides the BotticelliBot struct which manages the Discord client
 //! connection, event handling, and database integration.
 
-use crate::{BotticelliHandler, DiscordError, DiscordErrorKind, DiscordRepository};
-use diesel::pg::PgConnection;
+use crate::{
+    BotticelliHandler, DiscordError, DiscordErrorKind, DiscordEventPublisher, DiscordRepository,
+    SlashCommandDispatcher,
+};
+use botticelli_database::PgPool;
 use serenity::Client;
 use std::sync::Arc;
 use tracing::{info, instrument};
@@ -17,14 +20,14 @@ use tracing::{info, instrument};
 /// # Example
 /// ```no_run
 /// use botticelli_social::BotticelliBot;
-/// use botticelli_database::establish_connection;
+/// use botticelli_database::create_pool;
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///     let token = std::env::var("DISCORD_TOKEN")?;
-///     let conn = establish_connection()?;
+///     let pool = create_pool()?;
 ///
-///     let mut bot = BotticelliBot::new(token, conn).await?;
+///     let mut bot = BotticelliBot::new(token, pool).await?;
 ///     bot.start().await?;
 ///     Ok(())
 /// }
@@ -41,23 +44,89 @@ impl BotticelliBot {
     ///
     /// # Arguments
     /// * `token` - Discord bot token from the Discord Developer Portal
-    /// * `conn` - PostgreSQL database connection
+    /// * `pool` - PostgreSQL connection pool
     ///
     /// # Errors
     /// Returns an error if:
     /// - The bot token is invalid
     /// - The Serenity client fails to initialize
     /// - Database connection fails
-    #[instrument(skip(token, conn), fields(token_len = token.len()))]
-    pub async fn new(token: String, conn: PgConnection) -> Result<Self, DiscordError> {
+    #[instrument(skip(token, pool), fields(token_len = token.len()))]
+    pub async fn new(token: String, pool: PgPool) -> Result<Self, DiscordError> {
         info!("Initializing Botticelli Discord bot");
 
-        // Wrap connection in Arc<Mutex> for async access
-        let repository = Arc::new(DiscordRepository::new(conn));
+        let repository = Arc::new(DiscordRepository::new(pool));
 
         // Create event handler
         let handler = BotticelliHandler::new(repository.clone());
+        Self::from_handler(token, repository, handler).await
+    }
+
+    /// Create a bot that also answers narrative-backed slash commands.
+    ///
+    /// # Errors
+    /// Returns an error if the Serenity client fails to initialize.
+    #[instrument(skip(token, pool, dispatcher), fields(token_len = token.len(), commands = dispatcher.registry().len()))]
+    pub async fn new_with_slash_commands(
+        token: String,
+        pool: PgPool,
+        dispatcher: SlashCommandDispatcher,
+    ) -> Result<Self, DiscordError> {
+        info!("Initializing Botticelli Discord bot with slash commands");
+
+        let repository = Arc::new(DiscordRepository::new(pool));
+        let handler =
+            BotticelliHandler::new(repository.clone()).with_slash_commands(Arc::new(dispatcher));
+        Self::from_handler(token, repository, handler).await
+    }
+
+    /// Create a bot that publishes gateway events for event-triggered narratives.
+    ///
+    /// # Errors
+    /// Returns an error if the Serenity client fails to initialize.
+    #[instrument(skip(token, pool, publisher), fields(token_len = token.len()))]
+    pub async fn new_with_events(
+        token: String,
+        pool: PgPool,
+        publisher: DiscordEventPublisher,
+    ) -> Result<Self, DiscordError> {
+        info!("Initializing Botticelli Discord bot with event publishing");
+
+        let repository = Arc::new(DiscordRepository::new(pool));
+        let handler = BotticelliHandler::new(repository.clone()).with_event_publisher(publisher);
+        Self::from_handler(token, repository, handler).await
+    }
+
+    /// Create a bot with any combination of event publishing and slash commands.
+    ///
+    /// # Errors
+    /// Returns an error if the Serenity client fails to initialize.
+    #[instrument(skip_all, fields(token_len = token.len(), events = publisher.is_some(), slash_commands = dispatcher.is_some()))]
+    pub async fn new_with_options(
+        token: String,
+        pool: PgPool,
+        publisher: Option<DiscordEventPublisher>,
+        dispatcher: Option<SlashCommandDispatcher>,
+    ) -> Result<Self, DiscordError> {
+        info!("Initializing Botticelli Discord bot");
+
+        let repository = Arc::new(DiscordRepository::new(pool));
+        let mut handler = BotticelliHandler::new(repository.clone());
+        if let Some(publisher) = publisher {
+            handler = handler.with_event_publisher(publisher);
+        }
+        if let Some(dispatcher) = dispatcher {
+            handler = handler.with_slash_commands(Arc::new(dispatcher));
+        }
+        Self::from_handler(token, repository, handler).await
+    }
 
+    /// Build the Serenity client around an event handler.
+    async fn from_handler(
+        token: String,
+        repository: Arc<DiscordRepository>,
+        handler: BotticelliHandler,
+    ) -> Result<Self, DiscordError> {
         // Get required gateway intents
         let intents = BotticelliHandler::intents();
 
@@ -116,7 +185,7 @@ impl BotticelliBot {
     /// ```rust,ignore
     /// use botticelli_social::{BotticelliBot, DiscordCommandExecutor};
     ///
-    /// let bot = BotticelliBot::new(token, conn).await?;
+    /// let bot = BotticelliBot::new(token, pool).await?;
     /// let executor = DiscordCommandExecutor::with_http_client(bot.http_client());
     /// ```
     pub fn http_client(&self) -> Arc<serenity::http::Http> {
@@ -19,7 +19,7 @@
 //! let executor = DiscordCommandExecutor::new("DISCORD_BOT_TOKEN");
 //!
 //! // Or create from existing bot
-//! let bot = BotticelliBot::new(token, conn).await?;
+//! let bot = BotticelliBot::new(token, pool).await?;
 //! let executor = DiscordCommandExecutor::with_http_client(bot.http_client());
 //!
 //! // Execute command
@@ -48,7 +48,7 @@ use tracing::{debug, error, info, instrument, warn};
 ///
 /// Implements the BotCommandExecutor trait to provide Discord-specific
 /// command handling using Serenity's HTTP client.
-#[derive(Getters, Setters)]
+#[derive(Clone, Getters, Setters)]
 #[setters(prefix = "with_")]
 pub struct DiscordCommandExecutor {
     /// Serenity HTTP client for Discord API calls
@@ -96,7 +96,7 @@ impl DiscordCommandExecutor {
     /// # Example
     ///
     /// ```rust,ignore
-    /// let bot = BotticelliBot::new(token, conn).await?;
+    /// let bot = BotticelliBot::new(token, pool).await?;
     /// let executor = DiscordCommandExecutor::with_http_client(bot.http_client());
     /// ```
     pub fn with_http_client(http: Arc<Http>) -> Self {
@@ -0,0 +1,107 @@
+//! Publishing Discord gateway events to interested consumers.
+//!
+//! [`BotticelliHandler`](crate::BotticelliHandler) converts gateway events into
+//! platform-neutral [`PlatformEvent`]s and broadcasts them through a
+//! [`DiscordEventPublisher`]. The actor server subscribes to run event-triggered
+//! narratives (welcome messages, auto-moderation, ...).
+
+use botticelli_interface::{
+    PlatformEvent, PlatformEventBuilder, PlatformEventBuilderError, PlatformEventKind,
+};
+use serenity::model::channel::{Message, Reaction};
+use serenity::model::guild::Member;
+use tokio::sync::broadcast;
+use tracing::trace;
+
+/// Platform name recorded on published events.
+pub const DISCORD_PLATFORM: &str = "discord";
+
+/// Default number of events buffered per subscriber.
+pub const DEFAULT_EVENT_CAPACITY: usize = 256;
+
+/// Broadcasts Discord events to any number of subscribers.
+///
+/// Slow subscribers that fall more than the channel capacity behind miss the
+/// oldest events rather than blocking the gateway.
+#[derive(Debug, Clone)]
+pub struct DiscordEventPublisher {
+    sender: broadcast::Sender<PlatformEvent>,
+}
+
+impl DiscordEventPublisher {
+    /// Create a publisher buffering up to `capacity` events per subscriber.
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        Self { sender }
+    }
+
+    /// Subscribe to events published from now on.
+    pub fn subscribe(&self) -> broadcast::Receiver<PlatformEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Publish an event, returning how many subscribers received it.
+    pub fn publish(&self, event: PlatformEvent) -> usize {
+        match self.sender.send(event) {
+            Ok(receivers) => receivers,
+            Err(broadcast::error::SendError(event)) => {
+                trace!(kind = %event.kind(), "No subscribers for Discord event");
+                0
+            }
+        }
+    }
+}
+
+impl Default for DiscordEventPublisher {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_CAPACITY)
+    }
+}
+
+/// Build a `message_created` event from a Discord message.
+pub fn message_event(message: &Message) -> Result<PlatformEvent, PlatformEventBuilderError> {
+    let mut builder = PlatformEventBuilder::default();
+    builder
+        .platform(DISCORD_PLATFORM)
+        .kind(PlatformEventKind::MessageCreated)
+        .channel_id(message.channel_id.to_string())
+        .user_id(message.author.id.to_string())
+        .user_name(message.author.name.clone())
+        .message_id(message.id.to_string())
+        .content(message.content.clone());
+    if let Some(guild_id) = message.guild_id {
+        builder.guild_id(guild_id.to_string());
+    }
+    builder.build()
+}
+
+/// Build a `member_joined` event from a new guild member.
+pub fn member_joined_event(member: &Member) -> Result<PlatformEvent, PlatformEventBuilderError> {
+    PlatformEventBuilder::default()
+        .platform(DISCORD_PLATFORM)
+        .kind(PlatformEventKind::MemberJoined)
+        .guild_id(member.guild_id.to_string())
+        .user_id(member.user.id.to_string())
+        .user_name(member.user.name.clone())
+        .build()
+}
+
+/// Build a `reaction_added` event from a message reaction.
+pub fn reaction_added_event(
+    reaction: &Reaction,
+) -> Result<PlatformEvent, PlatformEventBuilderError> {
+    let mut builder = PlatformEventBuilder::default();
+    builder
+        .platform(DISCORD_PLATFORM)
+        .kind(PlatformEventKind::ReactionAdded)
+        .channel_id(reaction.channel_id.to_string())
+        .message_id(reaction.message_id.to_string())
+        .emoji(reaction.emoji.to_string());
+    if let Some(guild_id) = reaction.guild_id {
+        builder.guild_id(guild_id.to_string());
+    }
+    if let Some(user_id) = reaction.user_id {
+        builder.user_id(user_id.to_string());
+    }
+    builder.build()
+}
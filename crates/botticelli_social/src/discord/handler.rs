@@ -3,15 +3,18 @@
 //! This module implements the EventHandler trait to respond to Discord events
 //! and persist data to the database.
 
+use crate::discord::events::{member_joined_event, message_event, reaction_added_event};
 use crate::{
-    ChannelType, DiscordRepository, NewChannel, NewGuildBuilder, NewGuildMember, NewRole, NewUser,
+    ChannelType, DiscordEventPublisher, DiscordRepository, NewChannel, NewGuildBuilder,
+    NewGuildMember, NewRole, NewUser, SlashCommandDispatcher,
 };
+use botticelli_interface::{PlatformEvent, PlatformEventBuilderError};
 use chrono::NaiveDateTime;
-use serenity::all::{GuildId, Ready};
+use serenity::all::{GuildId, Interaction, Ready};
 use serenity::async_trait;
 use serenity::client::{Context, EventHandler};
 use serenity::model::Timestamp;
-use serenity::model::channel::{Channel, GuildChannel};
+use serenity::model::channel::{Channel, GuildChannel, Message, Reaction};
 use serenity::model::gateway::GatewayIntents;
 use serenity::model::guild::{Guild, Member, Role};
 use std::sync::Arc;
@@ -31,12 +34,34 @@ fn timestamp_to_naive(ts: &Timestamp) -> NaiveDateTime {
 pub struct BotticelliHandler {
     /// Repository for database operations
     repository: Arc<DiscordRepository>,
+    /// Slash command routing (None if the bot exposes no commands)
+    slash_commands: Option<Arc<SlashCommandDispatcher>>,
+    /// Event publisher for event-triggered narratives (None if unused)
+    events: Option<DiscordEventPublisher>,
 }
 
 impl BotticelliHandler {
     /// Create a new BotticelliHandler with the given repository.
     pub fn new(repository: Arc<DiscordRepository>) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            slash_commands: None,
+            events: None,
+        }
+    }
+
+    /// Respond to slash commands with the given dispatcher.
+    ///
+    /// Commands are registered with Discord when the bot becomes ready.
+    pub fn with_slash_commands(mut self, dispatcher: Arc<SlashCommandDispatcher>) -> Self {
+        self.slash_commands = Some(dispatcher);
+        self
+    }
+
+    /// Publish message, member join and reaction events to `publisher`.
+    pub fn with_event_publisher(mut self, publisher: DiscordEventPublisher) -> Self {
+        self.events = Some(publisher);
+        self
     }
 
     /// Required gateway intents for the bot.
@@ -46,6 +71,7 @@ impl BotticelliHandler {
         GatewayIntents::GUILDS
             | GatewayIntents::GUILD_MEMBERS
             | GatewayIntents::GUILD_MESSAGES
+            | GatewayIntents::GUILD_MESSAGE_REACTIONS
             | GatewayIntents::MESSAGE_CONTENT
     }
 
@@ -298,12 +324,47 @@ impl BotticelliHandler {
             }
         }
     }
+
+    /// Publish a converted gateway event, logging events that failed to convert.
+    fn publish(
+        events: &DiscordEventPublisher,
+        event: Result<PlatformEvent, PlatformEventBuilderError>,
+    ) {
+        match event {
+            Ok(event) => {
+                events.publish(event);
+            }
+            Err(e) => error!(error = %e, "Failed to build platform event"),
+        }
+    }
+
+    /// Whether a reaction was added by a bot.
+    ///
+    /// Guild reactions carry the member; DM reactions only carry the user ID,
+    /// so the user is fetched. Reactions whose author cannot be determined
+    /// are treated as bot reactions so triggered replies cannot loop.
+    async fn is_bot_reaction(ctx: &Context, reaction: &Reaction) -> bool {
+        if let Some(member) = &reaction.member {
+            return member.user.bot;
+        }
+
+        let Some(user_id) = reaction.user_id else {
+            return true;
+        };
+        match ctx.http.get_user(user_id).await {
+            Ok(user) => user.bot,
+            Err(e) => {
+                warn!(user_id = %user_id, error = %e, "Failed to look up reaction author");
+                true
+            }
+        }
+    }
 }
 
 #[async_trait]
 impl EventHandler for BotticelliHandler {
     /// Called when the bot successfully connects to Discord.
-    async fn ready(&self, _ctx: Context, ready: Ready) {
+    async fn ready(&self, ctx: Context, ready: Ready) {
         info!(
             bot_user = %ready.user.name,
             bot_id = %ready.user.id,
@@ -315,6 +376,50 @@ impl EventHandler for BotticelliHandler {
         for guild in &ready.guilds {
             debug!(guild_id = %guild.id, "Bot is in guild");
         }
+
+        if let Some(dispatcher) = &self.slash_commands
+            && let Err(e) = dispatcher.register_commands(&ctx.http).await
+        {
+            error!(error = %e, "Failed to register slash commands");
+        }
+    }
+
+    /// Called when a user invokes a slash command or other interaction.
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        let Interaction::Command(command) = interaction else {
+            return;
+        };
+
+        match &self.slash_commands {
+            Some(dispatcher) => dispatcher.handle_command(&ctx, &command).await,
+            None => debug!(command = %command.data.name, "Ignoring slash command, none configured"),
+        }
+    }
+
+    /// Called when a message is posted in a channel the bot can see.
+    async fn message(&self, _ctx: Context, message: Message) {
+        // Ignore bots (including ourselves) so triggered replies cannot loop
+        if message.author.bot {
+            return;
+        }
+
+        if let Some(events) = &self.events {
+            debug!(channel_id = %message.channel_id, message_id = %message.id, "Publishing message event");
+            Self::publish(events, message_event(&message));
+        }
+    }
+
+    /// Called when a reaction is added to a message.
+    async fn reaction_add(&self, ctx: Context, reaction: Reaction) {
+        let Some(events) = &self.events else {
+            return;
+        };
+        if Self::is_bot_reaction(&ctx, &reaction).await {
+            return;
+        }
+
+        debug!(channel_id = %reaction.channel_id, message_id = %reaction.message_id, "Publishing reaction event");
+        Self::publish(events, reaction_added_event(&reaction));
     }
 
     /// Called when a guild becomes available or the bot joins a guild.
@@ -390,6 +495,10 @@ impl EventHandler for BotticelliHandler {
             "Member joined guild"
         );
         self.store_member(new_member.guild_id, &new_member).await;
+
+        if let Some(events) = &self.events {
+            Self::publish(events, member_joined_event(&new_member));
+        }
     }
 
     /// Called when a member leaves a guild.
@@ -0,0 +1,588 @@
+//! Slash commands backed by narratives.
+//!
+//! Narratives that declare a `slash_command` table are registered with Discord
+//! as application commands. When a user invokes one, the bot defers the
+//! interaction, runs the narrative with the command options available as
+//! `${option:<name>}` template variables, and edits the deferred reply with
+//! the narrative's final response.
+//!
+//! Every invocation is checked with a [`PermissionChecker`] before the
+//! narrative runs. The command is checked as `slash.<name>`; if the permission
+//! config has `user` or `channel` resource rules, the invoking user, the
+//! invoking channel and any channel options are checked against them too.
+
+use crate::{DiscordError, DiscordErrorKind};
+use async_trait::async_trait;
+use botticelli_error::NarrativeError;
+use botticelli_interface::BotticelliDriver;
+use botticelli_narrative::{
+    BotCommandRegistry, MultiNarrative, NarrativeExecutor, NarrativeProvider, SlashCommandConfig,
+    SlashCommandOptionType,
+};
+use botticelli_security::{PermissionChecker, SecurityResult};
+use derive_getters::Getters;
+use serenity::all::{
+    Command, CommandDataOptionValue, CommandInteraction, CommandOptionType, CreateCommand,
+    CreateCommandOption, CreateInteractionResponse, CreateInteractionResponseMessage,
+    EditInteractionResponse, GuildId,
+};
+use serenity::client::Context;
+use serenity::http::Http;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{debug, error, info, instrument, warn};
+
+/// Maximum length of a Discord message.
+pub const MAX_REPLY_LEN: usize = 2000;
+
+/// A narrative exposed as a slash command.
+#[derive(Debug, Clone, Getters)]
+pub struct SlashCommandRoute {
+    /// Name users type after `/`
+    command_name: String,
+    /// Narrative to execute
+    narrative_name: String,
+    /// TOML file that defines the narrative
+    path: PathBuf,
+    /// Command definition from the narrative
+    config: SlashCommandConfig,
+}
+
+impl SlashCommandRoute {
+    /// Create a route for a narrative's slash command definition.
+    pub fn new(
+        narrative_name: impl Into<String>,
+        path: impl Into<PathBuf>,
+        config: SlashCommandConfig,
+    ) -> Self {
+        let narrative_name = narrative_name.into();
+        let command_name = config.command_name(&narrative_name).to_string();
+        Self {
+            command_name,
+            narrative_name,
+            path: path.into(),
+            config,
+        }
+    }
+
+    /// Build the Discord application command definition.
+    pub fn create_command(&self) -> CreateCommand {
+        let mut command =
+            CreateCommand::new(&self.command_name).description(self.config.description());
+
+        for option in self.config.options() {
+            let mut builder = CreateCommandOption::new(
+                option_type(*option.kind()),
+                option.name(),
+                option.description(),
+            )
+            .required(*option.required());
+
+            for choice in option.choices() {
+                builder = builder.add_string_choice(choice, choice);
+            }
+            command = command.add_option(builder);
+        }
+
+        command
+    }
+}
+
+/// Slash command routes for every narrative in a file that declares one.
+fn load_routes(path: &Path) -> Result<Vec<SlashCommandRoute>, NarrativeError> {
+    let names = MultiNarrative::narrative_names(path)?;
+    let Some(first) = names.first() else {
+        return Ok(Vec::new());
+    };
+    let multi = MultiNarrative::from_file(path, first)?;
+
+    Ok(names
+        .iter()
+        .filter_map(|name| multi.get_narrative(name))
+        .filter_map(|narrative| {
+            narrative
+                .metadata()
+                .slash_command()
+                .as_ref()
+                .map(|config| SlashCommandRoute::new(narrative.name(), path, config.clone()))
+        })
+        .collect())
+}
+
+fn option_type(kind: SlashCommandOptionType) -> CommandOptionType {
+    match kind {
+        SlashCommandOptionType::String => CommandOptionType::String,
+        SlashCommandOptionType::Integer => CommandOptionType::Integer,
+        SlashCommandOptionType::Number => CommandOptionType::Number,
+        SlashCommandOptionType::Boolean => CommandOptionType::Boolean,
+        SlashCommandOptionType::Channel => CommandOptionType::Channel,
+        SlashCommandOptionType::User => CommandOptionType::User,
+        SlashCommandOptionType::Role => CommandOptionType::Role,
+    }
+}
+
+/// Slash commands discovered from narrative files.
+#[derive(Debug, Clone, Default)]
+pub struct SlashCommandRegistry {
+    routes: HashMap<String, SlashCommandRoute>,
+}
+
+impl SlashCommandRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a route, failing if its command name is already taken.
+    pub fn register(&mut self, route: SlashCommandRoute) -> Result<(), DiscordError> {
+        if let Some(existing) = self.routes.get(route.command_name()) {
+            return Err(DiscordError::new(DiscordErrorKind::ConfigurationError(
+                format!(
+                    "Slash command '/{}' is defined by both '{}' and '{}'",
+                    route.command_name, existing.narrative_name, route.narrative_name
+                ),
+            )));
+        }
+
+        debug!(
+            command = %route.command_name,
+            narrative = %route.narrative_name,
+            "Registered slash command"
+        );
+        self.routes.insert(route.command_name.clone(), route);
+        Ok(())
+    }
+
+    /// Register every narrative in a TOML file that declares a slash command.
+    ///
+    /// Returns the number of commands added.
+    #[instrument(skip(self), fields(path = %path.as_ref().display()))]
+    pub fn register_file(&mut self, path: impl AsRef<Path>) -> Result<usize, DiscordError> {
+        let path = path.as_ref();
+        let routes = load_routes(path).map_err(|e| {
+            DiscordError::new(DiscordErrorKind::ConfigurationError(format!(
+                "Failed to load {}: {}",
+                path.display(),
+                e
+            )))
+        })?;
+
+        let added = routes.len();
+        for route in routes {
+            self.register(route)?;
+        }
+        Ok(added)
+    }
+
+    /// Register slash commands from every `.toml` file in a directory.
+    ///
+    /// Files that are not valid narratives are skipped with a warning.
+    #[instrument(skip(self), fields(dir = %dir.as_ref().display()))]
+    pub fn register_dir(&mut self, dir: impl AsRef<Path>) -> Result<usize, DiscordError> {
+        let dir = dir.as_ref();
+        let entries = std::fs::read_dir(dir).map_err(|e| {
+            DiscordError::new(DiscordErrorKind::ConfigurationError(format!(
+                "Failed to read {}: {}",
+                dir.display(),
+                e
+            )))
+        })?;
+
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .collect();
+        paths.sort();
+
+        let mut added = 0;
+        for path in paths {
+            match load_routes(&path) {
+                Ok(routes) => {
+                    for route in routes {
+                        self.register(route)?;
+                        added += 1;
+                    }
+                }
+                Err(e) => warn!(path = %path.display(), error = %e, "Skipping file"),
+            }
+        }
+        Ok(added)
+    }
+
+    /// Look up a route by command name.
+    pub fn get(&self, command_name: &str) -> Option<&SlashCommandRoute> {
+        self.routes.get(command_name)
+    }
+
+    /// All registered routes.
+    pub fn routes(&self) -> impl Iterator<Item = &SlashCommandRoute> {
+        self.routes.values()
+    }
+
+    /// Number of registered commands.
+    pub fn len(&self) -> usize {
+        self.routes.len()
+    }
+
+    /// Whether no commands are registered.
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty()
+    }
+
+    /// Build Discord command definitions for every route, sorted by name.
+    pub fn create_commands(&self) -> Vec<CreateCommand> {
+        let mut routes: Vec<_> = self.routes.values().collect();
+        routes.sort_by(|a, b| a.command_name.cmp(&b.command_name));
+        routes.iter().map(|route| route.create_command()).collect()
+    }
+
+    /// Overwrite the bot's application commands with this registry.
+    ///
+    /// Guild commands update immediately; global commands can take up to an
+    /// hour to propagate, so a guild is preferable during development.
+    #[instrument(skip(self, http), fields(commands = self.routes.len()))]
+    pub async fn register_commands(
+        &self,
+        http: &Http,
+        guild_id: Option<GuildId>,
+    ) -> Result<(), DiscordError> {
+        let commands = self.create_commands();
+        let result = match guild_id {
+            Some(guild_id) => guild_id.set_commands(http, commands).await,
+            None => Command::set_global_commands(http, commands).await,
+        };
+
+        result.map_err(|e| {
+            DiscordError::new(DiscordErrorKind::SerenityError(format!(
+                "Failed to register slash commands: {}",
+                e
+            )))
+        })?;
+
+        info!(guild_id = ?guild_id, "Slash commands registered");
+        Ok(())
+    }
+}
+
+/// A slash command invocation, independent of Serenity types.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct SlashCommandInvocation {
+    /// Invoked command name
+    command_name: String,
+    /// User who invoked the command
+    user_id: u64,
+    /// Channel the command was invoked in
+    channel_id: u64,
+    /// Guild the command was invoked in (None for DMs)
+    guild_id: Option<u64>,
+    /// Option values by name, rendered as strings
+    options: HashMap<String, String>,
+}
+
+impl SlashCommandInvocation {
+    /// Create an invocation.
+    pub fn new(
+        command_name: impl Into<String>,
+        user_id: u64,
+        channel_id: u64,
+        guild_id: Option<u64>,
+        options: HashMap<String, String>,
+    ) -> Self {
+        Self {
+            command_name: command_name.into(),
+            user_id,
+            channel_id,
+            guild_id,
+            options,
+        }
+    }
+
+    /// Extract an invocation from a Discord interaction.
+    pub fn from_interaction(command: &CommandInteraction) -> Self {
+        let options = command
+            .data
+            .options
+            .iter()
+            .filter_map(|option| {
+                option_value_to_string(&option.value).map(|value| (option.name.clone(), value))
+            })
+            .collect();
+
+        Self::new(
+            command.data.name.clone(),
+            command.user.id.get(),
+            command.channel_id.get(),
+            command.guild_id.map(|id| id.get()),
+            options,
+        )
+    }
+
+    /// Template variables for the narrative.
+    ///
+    /// Options become `option:<name>`; invocation context is available as
+    /// `interaction:user_id`, `interaction:channel_id` and `interaction:guild_id`.
+    pub fn variables(&self) -> HashMap<String, String> {
+        let mut variables: HashMap<String, String> = self
+            .options
+            .iter()
+            .map(|(name, value)| (format!("option:{}", name), value.clone()))
+            .collect();
+
+        variables.insert("interaction:user_id".to_string(), self.user_id.to_string());
+        variables.insert(
+            "interaction:channel_id".to_string(),
+            self.channel_id.to_string(),
+        );
+        if let Some(guild_id) = self.guild_id {
+            variables.insert("interaction:guild_id".to_string(), guild_id.to_string());
+        }
+        variables
+    }
+}
+
+fn option_value_to_string(value: &CommandDataOptionValue) -> Option<String> {
+    match value {
+        CommandDataOptionValue::String(s) => Some(s.clone()),
+        CommandDataOptionValue::Integer(i) => Some(i.to_string()),
+        CommandDataOptionValue::Number(n) => Some(n.to_string()),
+        CommandDataOptionValue::Boolean(b) => Some(b.to_string()),
+        CommandDataOptionValue::Channel(id) => Some(id.get().to_string()),
+        CommandDataOptionValue::User(id) => Some(id.get().to_string()),
+        CommandDataOptionValue::Role(id) => Some(id.get().to_string()),
+        CommandDataOptionValue::Mentionable(id) => Some(id.get().to_string()),
+        _ => None,
+    }
+}
+
+/// Executes the narrative behind a slash command.
+#[async_trait]
+pub trait SlashCommandRunner: Send + Sync {
+    /// Run the route's narrative and return the reply text.
+    async fn run(
+        &self,
+        route: &SlashCommandRoute,
+        variables: HashMap<String, String>,
+    ) -> Result<String, DiscordError>;
+}
+
+/// Runs slash command narratives with a [`NarrativeExecutor`].
+pub struct NarrativeSlashCommandRunner<D: BotticelliDriver + ?Sized> {
+    driver: Arc<D>,
+    bot_registry: Option<Arc<dyn BotCommandRegistry>>,
+}
+
+impl<D: BotticelliDriver + ?Sized> NarrativeSlashCommandRunner<D> {
+    /// Create a runner using the given LLM driver.
+    pub fn new(driver: Arc<D>) -> Self {
+        Self {
+            driver,
+            bot_registry: None,
+        }
+    }
+
+    /// Make bot commands available to slash command narratives.
+    pub fn with_bot_registry(mut self, registry: Arc<dyn BotCommandRegistry>) -> Self {
+        self.bot_registry = Some(registry);
+        self
+    }
+}
+
+#[async_trait]
+impl<D: BotticelliDriver + ?Sized + 'static> SlashCommandRunner for NarrativeSlashCommandRunner<D> {
+    #[instrument(skip(self, route, variables), fields(narrative = %route.narrative_name))]
+    async fn run(
+        &self,
+        route: &SlashCommandRoute,
+        variables: HashMap<String, String>,
+    ) -> Result<String, DiscordError> {
+        let mut executor = NarrativeExecutor::new(self.driver.clone()).with_variables(variables);
+        if let Some(registry) = &self.bot_registry {
+            executor = executor.with_bot_registry(Box::new(registry.clone()));
+        }
+
+        let path = route.path.to_string_lossy();
+        let execution = executor
+            .execute_narrative_by_name(&path, &route.narrative_name)
+            .await
+            .map_err(|e| {
+                DiscordError::new(DiscordErrorKind::InteractionFailed(format!(
+                    "Narrative '{}' failed: {}",
+                    route.narrative_name, e
+                )))
+            })?;
+
+        Ok(execution
+            .act_executions
+            .last()
+            .map(|act| act.response.clone())
+            .unwrap_or_default())
+    }
+}
+
+/// Routes slash command interactions to narratives.
+pub struct SlashCommandDispatcher {
+    registry: SlashCommandRegistry,
+    runner: Arc<dyn SlashCommandRunner>,
+    permissions: PermissionChecker,
+    guild_id: Option<GuildId>,
+}
+
+impl SlashCommandDispatcher {
+    /// Create a dispatcher.
+    ///
+    /// Commands are registered globally unless [`with_guild`](Self::with_guild) is used.
+    pub fn new(
+        registry: SlashCommandRegistry,
+        runner: Arc<dyn SlashCommandRunner>,
+        permissions: PermissionChecker,
+    ) -> Self {
+        Self {
+            registry,
+            runner,
+            permissions,
+            guild_id: None,
+        }
+    }
+
+    /// Register commands to a single guild instead of globally.
+    pub fn with_guild(mut self, guild_id: u64) -> Self {
+        self.guild_id = Some(GuildId::new(guild_id));
+        self
+    }
+
+    /// The command registry.
+    pub fn registry(&self) -> &SlashCommandRegistry {
+        &self.registry
+    }
+
+    /// Register all commands with Discord.
+    pub async fn register_commands(&self, http: &Http) -> Result<(), DiscordError> {
+        self.registry.register_commands(http, self.guild_id).await
+    }
+
+    /// Check whether an invocation is permitted.
+    pub fn authorize(&self, invocation: &SlashCommandInvocation) -> SecurityResult<()> {
+        self.permissions
+            .check_command(&format!("slash.{}", invocation.command_name))?;
+
+        let resources = self.permissions.config().resources();
+        if resources.contains_key("user") {
+            self.permissions
+                .check_resource("user", &invocation.user_id.to_string())?;
+        }
+
+        if resources.contains_key("channel") {
+            self.permissions
+                .check_resource("channel", &invocation.channel_id.to_string())?;
+
+            if let Some(route) = self.registry.get(&invocation.command_name) {
+                for option in route.config.options() {
+                    if *option.kind() == SlashCommandOptionType::Channel
+                        && let Some(channel_id) = invocation.options.get(option.name())
+                    {
+                        self.permissions.check_resource("channel", channel_id)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Authorize and run an invocation, returning the reply text.
+    pub async fn dispatch(
+        &self,
+        invocation: &SlashCommandInvocation,
+    ) -> Result<String, DiscordError> {
+        let route = self.registry.get(&invocation.command_name).ok_or_else(|| {
+            DiscordError::new(DiscordErrorKind::InteractionFailed(format!(
+                "Unknown command '/{}'",
+                invocation.command_name
+            )))
+        })?;
+
+        self.authorize(invocation).map_err(|e| {
+            DiscordError::new(DiscordErrorKind::InsufficientPermissions(
+                e.kind.to_string(),
+            ))
+        })?;
+
+        let reply = self.runner.run(route, invocation.variables()).await?;
+        Ok(truncate_reply(&reply))
+    }
+
+    /// Handle a command interaction from the gateway.
+    ///
+    /// Unknown or unauthorized commands get an immediate ephemeral reply.
+    /// Otherwise the interaction is deferred while the narrative runs, and the
+    /// deferred reply is edited with the result.
+    #[instrument(skip_all, fields(command = %command.data.name, user_id = %command.user.id))]
+    pub async fn handle_command(&self, ctx: &Context, command: &CommandInteraction) {
+        let invocation = SlashCommandInvocation::from_interaction(command);
+
+        let Some(route) = self.registry.get(&invocation.command_name) else {
+            warn!("Received unknown slash command");
+            respond_ephemeral(ctx, command, "Unknown command.").await;
+            return;
+        };
+
+        if let Err(e) = self.authorize(&invocation) {
+            info!(error = %e, "Slash command denied");
+            respond_ephemeral(
+                ctx,
+                command,
+                "You don't have permission to use this command.",
+            )
+            .await;
+            return;
+        }
+
+        let deferred = if *route.config.ephemeral() {
+            command.defer_ephemeral(&ctx.http).await
+        } else {
+            command.defer(&ctx.http).await
+        };
+        if let Err(e) = deferred {
+            error!(error = %e, "Failed to defer interaction");
+            return;
+        }
+
+        let reply = match self.runner.run(route, invocation.variables()).await {
+            Ok(reply) if reply.trim().is_empty() => "Done.".to_string(),
+            Ok(reply) => truncate_reply(&reply),
+            Err(e) => {
+                error!(error = %e, "Slash command narrative failed");
+                "Sorry, something went wrong running that command.".to_string()
+            }
+        };
+
+        if let Err(e) = command
+            .edit_response(&ctx.http, EditInteractionResponse::new().content(reply))
+            .await
+        {
+            error!(error = %e, "Failed to edit deferred interaction response");
+        }
+    }
+}
+
+async fn respond_ephemeral(ctx: &Context, command: &CommandInteraction, content: &str) {
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(content)
+            .ephemeral(true),
+    );
+    if let Err(e) = command.create_response(&ctx.http, response).await {
+        error!(error = %e, "Failed to respond to interaction");
+    }
+}
+
+/// Shorten a reply to fit in a single Discord message.
+pub fn truncate_reply(reply: &str) -> String {
+    if reply.chars().count() <= MAX_REPLY_LEN {
+        return reply.to_string();
+    }
+    let mut truncated: String = reply.chars().take(MAX_REPLY_LEN - 1).collect();
+    truncated.push('…');
+    truncated
+}
@@ -19,10 +19,12 @@
 //! ## Integration Layer
 //! - **client**: Serenity client setup and lifecycle management
 //! - **handler**: Event handler implementing Serenity's EventHandler trait
+//! - **events**: Broadcasting gateway events for event-triggered narratives
 //! - **error**: Discord-specific error types
 //!
 //! ## Feature Layer
-//! - **commands**: Slash command implementations
+//! - **commands**: Bot command executor for narratives
+//! - **interactions**: Slash commands backed by narratives
 //! - **poster**: Narrative-to-Discord posting functionality
 //!
 //! # Usage
@@ -36,7 +38,7 @@
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //!     let bot = BotticelliBot::new(
 //!         std::env::var("DISCORD_TOKEN")?,
-//!         std::env::var("DATABASE_URL")?,
+//!         botticelli::create_pool()?,
 //!     ).await?;
 //!
 //!     bot.start().await?;
@@ -48,7 +50,9 @@ mod client;
 mod commands;
 mod conversions;
 mod error;
+mod events;
 mod handler;
+mod interactions;
 mod json_models;
 mod models;
 mod repository;
@@ -58,7 +62,15 @@ pub use client::BotticelliBot;
 pub use commands::DiscordCommandExecutor;
 pub use conversions::{NewMemberRole, parse_channel_type, parse_iso_timestamp};
 pub use error::{DiscordError, DiscordErrorKind, DiscordResult as DiscordErrorResult};
+pub use events::{
+    DEFAULT_EVENT_CAPACITY, DISCORD_PLATFORM, DiscordEventPublisher, member_joined_event,
+    message_event, reaction_added_event,
+};
 pub use handler::BotticelliHandler;
+pub use interactions::{
+    MAX_REPLY_LEN, NarrativeSlashCommandRunner, SlashCommandDispatcher, SlashCommandInvocation,
+    SlashCommandRegistry, SlashCommandRoute, SlashCommandRunner, truncate_reply,
+};
 pub use json_models::{
     DiscordChannelJson, DiscordGuildJson, DiscordGuildMemberJson, DiscordMemberRoleJson,
     DiscordRoleJson, DiscordUserJson,
@@ -15,7 +15,9 @@
 //!
 //! Each platform is feature-gated and lives in its own submodule:
 //! - `discord` - Discord bot integration (requires `discord` feature)
-//! - `telegram` - Telegram bot integration (requires `telegram` feature, not yet implemented)
+//! - `bluesky` - Bluesky (AT Protocol) integration (requires `bluesky` feature)
+//! - `telegram` - Telegram bot integration (requires `telegram` feature)
+//! - `webhook` - Generic webhook/HTTP delivery (requires `webhook` feature)
 //! - `reddit` - Reddit integration (requires `reddit` feature, not yet implemented)
 //!
 //! Platform implementations follow a common pattern:
@@ -36,8 +38,14 @@ mod secure_bot_executor;
 #[cfg(feature = "database")]
 mod secure_executor;
 
+#[cfg(feature = "bluesky")]
+mod bluesky;
 #[cfg(feature = "discord")]
 mod discord;
+#[cfg(feature = "telegram")]
+mod telegram;
+#[cfg(feature = "webhook")]
+mod webhook;
 
 // Export bot command infrastructure (requires database feature)
 #[cfg(feature = "database")]
@@ -54,13 +62,44 @@ pub use secure_bot_executor::SecureBotExecutor;
 #[cfg(feature = "database")]
 pub use secure_executor::{ExecutionResult, SecureBotCommandExecutor};
 
+// Export Bluesky-specific types (feature-gated)
+#[cfg(feature = "bluesky")]
+pub use bluesky::{
+    BLUESKY_DEFAULT_SERVICE, BLUESKY_MAX_GRAPHEMES, BLUESKY_MAX_IMAGES, BlueskyClient,
+    BlueskyCommandExecutor, BlueskyError, BlueskyErrorKind, BlueskyResult, BlueskySession, Facet,
+    FacetFeature, ImageUpload, NewPost, PostRef, ReplyRef, detect_facets, grapheme_len,
+};
+
 // Export Discord-specific types (feature-gated)
 #[cfg(feature = "discord")]
 pub use discord::{
-    BotticelliBot, BotticelliHandler, ChannelRow, ChannelType, DiscordChannelJson,
-    DiscordCommandExecutor, DiscordError, DiscordErrorKind, DiscordErrorResult, DiscordGuildJson,
-    DiscordGuildMemberJson, DiscordMemberRoleJson, DiscordRepository, DiscordResult,
-    DiscordRoleJson, DiscordUserJson, GuildMemberRow, GuildRow, NewChannel, NewGuild,
-    NewGuildBuilder, NewGuildMember, NewMemberRole, NewRole, NewUser, RoleRow, UserRow,
-    parse_channel_type, parse_iso_timestamp,
+    BotticelliBot, BotticelliHandler, ChannelRow, ChannelType, DEFAULT_EVENT_CAPACITY,
+    DISCORD_PLATFORM, DiscordChannelJson, DiscordCommandExecutor, DiscordError, DiscordErrorKind,
+    DiscordErrorResult, DiscordEventPublisher, DiscordGuildJson, DiscordGuildMemberJson,
+    DiscordMemberRoleJson, DiscordRepository, DiscordResult, DiscordRoleJson, DiscordUserJson,
+    GuildMemberRow, GuildRow, MAX_REPLY_LEN, NarrativeSlashCommandRunner, NewChannel, NewGuild,
+    NewGuildBuilder, NewGuildMember, NewMemberRole, NewRole, NewUser, RoleRow,
+    SlashCommandDispatcher, SlashCommandInvocation, SlashCommandRegistry, SlashCommandRoute,
+    SlashCommandRunner, UserRow, member_joined_event, message_event, parse_channel_type,
+    parse_iso_timestamp, reaction_added_event, truncate_reply,
+};
+
+// Export Telegram-specific types (feature-gated)
+#[cfg(feature = "telegram")]
+pub use telegram::{
+    NewTelegramChat, NewTelegramMessage, NewTelegramUser, TELEGRAM_API_URL,
+    TELEGRAM_DEFAULT_POLL_TIMEOUT_SECS, TELEGRAM_MAX_CAPTION_LENGTH, TELEGRAM_MAX_MESSAGE_LENGTH,
+    TelegramChat, TelegramChatRow, TelegramClient, TelegramCommandExecutor, TelegramError,
+    TelegramErrorKind, TelegramFile, TelegramMediaKind, TelegramMessage, TelegramMessageRow,
+    TelegramPhotoSize, TelegramPoll, TelegramPollOption, TelegramPoller, TelegramRepository,
+    TelegramRepositoryResult, TelegramResult, TelegramSendOptions, TelegramUpdate, TelegramUser,
+    TelegramUserRow, chat_id_value,
+};
+
+// Export webhook types (feature-gated)
+#[cfg(feature = "webhook")]
+pub use webhook::{
+    HttpCommandExecutor, WebhookClient, WebhookConfig, WebhookConfigBuilder, WebhookError,
+    WebhookErrorKind, WebhookResponse, WebhookResult, WebhookSigning, WebhookSigningBuilder,
+    default_body_template, hmac_sha256, message_variables, render_template, sign_hex,
 };
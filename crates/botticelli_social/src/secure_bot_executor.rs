@@ -6,14 +6,20 @@
 use crate::{BotCommandError, BotCommandErrorKind, BotCommandExecutor, BotCommandResult};
 use async_trait::async_trait;
 use botticelli_security::{
-    ApprovalWorkflow, CommandValidator, ContentFilter, PermissionChecker, RateLimiter,
-    SecureExecutor, SecurityError,
+    ApprovalWorkflow, CommandValidator, ContentFilter, ContentModerator, PermissionChecker,
+    PlatformSecurityConfig, RateLimiter, SecureExecutor, SecurityConfig, SecurityError,
 };
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 use tokio::sync::Mutex;
-use tracing::{debug, error, info, instrument};
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, instrument, warn};
+
+/// Commands whose `content` argument is sent through the moderation stage.
+const MODERATED_COMMANDS: &[&str] = &["messages.send"];
 
 /// Security-aware bot command executor.
 ///
@@ -23,6 +29,12 @@ use tracing::{debug, error, info, instrument};
 /// 3. Content filtering
 /// 4. Rate limiting
 /// 5. Approval workflow
+///
+/// When a [`ContentModerator`] is attached, `messages.send` content is also
+/// classified after the synchronous checks pass and before the command runs.
+///
+/// Clones share the security state, so a clone registered per narrative run
+/// sees the same rate limit buckets and configuration reloads.
 pub struct SecureBotExecutor<E, V>
 where
     E: BotCommandExecutor,
@@ -30,6 +42,7 @@ where
 {
     inner: E,
     secure_executor: Arc<Mutex<SecureExecutor<V>>>,
+    moderator: Option<Arc<dyn ContentModerator>>,
     narrative_id: String,
 }
 
@@ -59,10 +72,60 @@ where
         Self {
             inner,
             secure_executor: Arc::new(Mutex::new(secure_executor)),
+            moderator: None,
             narrative_id,
         }
     }
 
+    /// Create a secure bot executor from file-based security configuration.
+    ///
+    /// Uses the section matching the inner executor's platform, or an empty
+    /// (deny-by-default) configuration when the platform is not listed.
+    pub fn from_config(
+        inner: E,
+        validator: V,
+        config: &SecurityConfig,
+        narrative_id: String,
+    ) -> BotCommandResult<Self> {
+        let platform_config = config
+            .platform(inner.platform())
+            .cloned()
+            .unwrap_or_default();
+
+        let secure_executor = SecureExecutor::from_config(validator, &platform_config)
+            .map_err(|e| security_error_to_bot_error("security_config", e))?;
+
+        Ok(Self {
+            inner,
+            secure_executor: Arc::new(Mutex::new(secure_executor)),
+            moderator: None,
+            narrative_id,
+        })
+    }
+
+    /// Re-apply security configuration without recreating the executor.
+    #[instrument(skip(self, config), fields(platform = self.inner.platform()))]
+    pub async fn reload_config(&self, config: &SecurityConfig) -> BotCommandResult<()> {
+        let platform_config = config
+            .platform(self.inner.platform())
+            .cloned()
+            .unwrap_or_default();
+
+        apply_platform_config(&self.secure_executor, &platform_config).await
+    }
+
+    /// Record commands under a different narrative ID.
+    pub fn with_narrative_id(mut self, narrative_id: impl Into<String>) -> Self {
+        self.narrative_id = narrative_id.into();
+        self
+    }
+
+    /// Attach an LLM moderation stage for outgoing message content.
+    pub fn with_moderator(mut self, moderator: Arc<dyn ContentModerator>) -> Self {
+        self.moderator = Some(moderator);
+        self
+    }
+
     /// Get reference to inner executor.
     pub fn inner(&self) -> &E {
         &self.inner
@@ -74,6 +137,106 @@ where
     }
 }
 
+impl<E, V> Clone for SecureBotExecutor<E, V>
+where
+    E: BotCommandExecutor + Clone,
+    V: CommandValidator,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            secure_executor: Arc::clone(&self.secure_executor),
+            moderator: self.moderator.clone(),
+            narrative_id: self.narrative_id.clone(),
+        }
+    }
+}
+
+impl<E, V> SecureBotExecutor<E, V>
+where
+    E: BotCommandExecutor,
+    V: CommandValidator + Send + 'static,
+{
+    /// Watch a security configuration file and re-apply it when it changes.
+    ///
+    /// The file's modification time is polled every `poll_interval`. Updates
+    /// that fail to parse or validate are rejected and logged together with a
+    /// diff against the last applied configuration; the executor keeps running
+    /// with the previous settings.
+    pub fn watch_config(
+        &self,
+        path: impl Into<PathBuf>,
+        poll_interval: Duration,
+    ) -> JoinHandle<()> {
+        let path = path.into();
+        let platform = self.inner.platform().to_string();
+        let secure_executor = Arc::clone(&self.secure_executor);
+
+        tokio::spawn(async move {
+            let mut current = match tokio::fs::read_to_string(&path).await {
+                Ok(contents) => SecurityConfig::from_toml_str(&contents).map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            }
+            .unwrap_or_else(|e| {
+                warn!(path = %path.display(), error = %e, "Initial security config unreadable");
+                SecurityConfig::default()
+            });
+            let mut last_modified = modified_time(&path).await;
+            let mut ticker = tokio::time::interval(poll_interval);
+
+            loop {
+                ticker.tick().await;
+
+                let modified = modified_time(&path).await;
+                if modified.is_none() || modified == last_modified {
+                    continue;
+                }
+                last_modified = modified;
+
+                let contents = match tokio::fs::read_to_string(&path).await {
+                    Ok(contents) => contents,
+                    Err(e) => {
+                        error!(path = %path.display(), error = %e, "Failed to read security config");
+                        continue;
+                    }
+                };
+                let (updated, changes) = match current.reload_from_str(&contents) {
+                    Ok(result) => result,
+                    Err(e) => {
+                        error!(path = %path.display(), "{}", e.kind);
+                        continue;
+                    }
+                };
+
+                if changes.is_empty() {
+                    debug!("Security config touched without changes");
+                    continue;
+                }
+
+                let platform_config = updated.platform(&platform).cloned().unwrap_or_default();
+                match apply_platform_config(&secure_executor, &platform_config).await {
+                    Ok(()) => {
+                        info!(
+                            path = %path.display(),
+                            changes = %changes.join("; "),
+                            "Security configuration reloaded"
+                        );
+                        current = updated;
+                    }
+                    Err(e) => {
+                        error!(
+                            path = %path.display(),
+                            error = %e,
+                            changes = %changes.join("; "),
+                            "Rejected security configuration update"
+                        );
+                    }
+                }
+            }
+        })
+    }
+}
+
 #[async_trait]
 impl<E, V> BotCommandExecutor for SecureBotExecutor<E, V>
 where
@@ -111,9 +274,25 @@ where
             }));
         }
 
+        drop(secure_executor); // Release lock before moderation and execution
+
+        // Moderation stage for outgoing message content
+        if let Some(moderator) = &self.moderator
+            && MODERATED_COMMANDS.contains(&command)
+            && let Some(content) = params.get("content")
+        {
+            debug!("Running moderation stage");
+            moderator
+                .check(self.inner.platform(), content)
+                .await
+                .map_err(|e| {
+                    error!("Moderation rejected content: {}", e);
+                    security_error_to_bot_error(command, e)
+                })?;
+        }
+
         // Security checks passed, execute the command
         debug!("Security checks passed, executing command");
-        drop(secure_executor); // Release lock before executing
 
         let result = self.inner.execute(command, args).await?;
 
@@ -217,6 +396,26 @@ where
     }
 }
 
+/// Apply platform configuration to a shared secure executor.
+async fn apply_platform_config<V: CommandValidator>(
+    secure_executor: &Mutex<SecureExecutor<V>>,
+    config: &PlatformSecurityConfig,
+) -> BotCommandResult<()> {
+    secure_executor
+        .lock()
+        .await
+        .apply_config(config)
+        .map_err(|e| security_error_to_bot_error("security_config", e))
+}
+
+/// Last modification time of a file, if it can be read.
+async fn modified_time(path: &Path) -> Option<SystemTime> {
+    tokio::fs::metadata(path)
+        .await
+        .and_then(|m| m.modified())
+        .ok()
+}
+
 /// Convert HashMap<String, JsonValue> to HashMap<String, String> for security checks.
 fn hashmap_to_params(
     args: &HashMap<String, JsonValue>,
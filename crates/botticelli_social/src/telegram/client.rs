@@ -0,0 +1,554 @@
+//! Telegram Bot API client.
+
+use super::{
+    TelegramChat, TelegramError, TelegramErrorKind, TelegramMessage, TelegramPoll, TelegramResult,
+    TelegramUpdate, TelegramUser,
+};
+use reqwest::multipart::{Form, Part};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value as JsonValue, json};
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::{debug, instrument};
+
+/// Default Bot API endpoint.
+pub const TELEGRAM_API_URL: &str = "https://api.telegram.org";
+
+/// Maximum message text length in characters.
+pub const TELEGRAM_MAX_MESSAGE_LENGTH: usize = 4096;
+
+/// Maximum media caption length in characters.
+pub const TELEGRAM_MAX_CAPTION_LENGTH: usize = 1024;
+
+/// Secret holding the bot token.
+const TELEGRAM_TOKEN_SECRET: &str = "telegram_bot_token";
+
+/// Fallback delay when a rate-limited response carries no `retry_after`.
+const DEFAULT_RETRY_AFTER_SECS: u64 = 30;
+
+/// Update types requested when long polling.
+const ALLOWED_UPDATES: [&str; 4] = [
+    "message",
+    "edited_message",
+    "channel_post",
+    "edited_channel_post",
+];
+
+/// Kind of media to send.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, derive_more::Display)]
+pub enum TelegramMediaKind {
+    /// Photo (compressed by Telegram).
+    #[default]
+    #[display("photo")]
+    Photo,
+    /// Video.
+    #[display("video")]
+    Video,
+    /// Animation (GIF or silent MP4).
+    #[display("animation")]
+    Animation,
+    /// Audio file.
+    #[display("audio")]
+    Audio,
+    /// Generic file.
+    #[display("document")]
+    Document,
+}
+
+impl TelegramMediaKind {
+    /// Bot API method that sends this kind of media.
+    pub fn method(self) -> &'static str {
+        match self {
+            Self::Photo => "sendPhoto",
+            Self::Video => "sendVideo",
+            Self::Animation => "sendAnimation",
+            Self::Audio => "sendAudio",
+            Self::Document => "sendDocument",
+        }
+    }
+
+    /// Guess the media kind from a URL or path extension.
+    ///
+    /// Unknown extensions are sent as documents.
+    pub fn from_extension(url: &str) -> Self {
+        let path = url.split(['?', '#']).next().unwrap_or(url);
+        let extension = path
+            .rsplit('.')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match extension.as_str() {
+            "jpg" | "jpeg" | "png" | "webp" => Self::Photo,
+            "gif" => Self::Animation,
+            "mp4" | "mov" | "webm" => Self::Video,
+            "mp3" | "m4a" | "ogg" | "flac" => Self::Audio,
+            _ => Self::Document,
+        }
+    }
+}
+
+impl FromStr for TelegramMediaKind {
+    type Err = TelegramError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "photo" => Ok(Self::Photo),
+            "video" => Ok(Self::Video),
+            "animation" => Ok(Self::Animation),
+            "audio" => Ok(Self::Audio),
+            "document" => Ok(Self::Document),
+            other => Err(TelegramError::new(TelegramErrorKind::InvalidInput(
+                format!(
+                    "Unknown media type '{}' (expected photo, video, animation, audio or document)",
+                    other
+                ),
+            ))),
+        }
+    }
+}
+
+/// Optional settings for sending a message.
+#[derive(Debug, Clone, Default)]
+pub struct TelegramSendOptions {
+    /// "MarkdownV2", "HTML" or "Markdown".
+    pub parse_mode: Option<String>,
+    /// Message to reply to.
+    pub reply_to_message_id: Option<i64>,
+    /// Send silently.
+    pub disable_notification: bool,
+}
+
+impl TelegramSendOptions {
+    fn apply(&self, body: &mut JsonValue) {
+        if let Some(mode) = &self.parse_mode {
+            body["parse_mode"] = json!(mode);
+        }
+        if let Some(reply_to) = self.reply_to_message_id {
+            body["reply_parameters"] = json!({ "message_id": reply_to });
+        }
+        if self.disable_notification {
+            body["disable_notification"] = json!(true);
+        }
+    }
+}
+
+/// Bot API response envelope.
+#[derive(Debug, Deserialize)]
+struct ApiResponse<T> {
+    ok: bool,
+    #[serde(default = "Option::default")]
+    result: Option<T>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    error_code: Option<i64>,
+    #[serde(default)]
+    parameters: Option<ResponseParameters>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseParameters {
+    #[serde(default)]
+    retry_after: Option<u64>,
+    #[serde(default)]
+    migrate_to_chat_id: Option<i64>,
+}
+
+/// Client for the Telegram Bot API.
+#[derive(Clone)]
+pub struct TelegramClient {
+    api_url: String,
+    token: String,
+    http: reqwest::Client,
+}
+
+impl std::fmt::Debug for TelegramClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TelegramClient")
+            .field("api_url", &self.api_url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TelegramClient {
+    /// Create a client for a bot token against the public Bot API.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token is empty.
+    pub fn new(token: impl Into<String>) -> TelegramResult<Self> {
+        Self::with_api_url(TELEGRAM_API_URL, token)
+    }
+
+    /// Create a client against a custom Bot API server (e.g. a local server).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is not http(s) or the token is empty.
+    pub fn with_api_url(
+        api_url: impl Into<String>,
+        token: impl Into<String>,
+    ) -> TelegramResult<Self> {
+        let api_url = api_url.into().trim_end_matches('/').to_string();
+        let token = token.into();
+
+        if !(api_url.starts_with("http://") || api_url.starts_with("https://")) {
+            return Err(TelegramError::new(TelegramErrorKind::ConfigurationError(
+                format!("API URL must be an http(s) URL: {}", api_url),
+            )));
+        }
+        if token.is_empty() {
+            return Err(TelegramError::new(TelegramErrorKind::ConfigurationError(
+                "Bot token cannot be empty".to_string(),
+            )));
+        }
+
+        Ok(Self {
+            api_url,
+            token,
+            http: reqwest::Client::new(),
+        })
+    }
+
+    /// Create a client from the `telegram_bot_token` secret.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the secret is missing.
+    pub fn from_secrets() -> TelegramResult<Self> {
+        let token = botticelli_secrets::get_secret(TELEGRAM_TOKEN_SECRET).map_err(|e| {
+            TelegramError::new(TelegramErrorKind::ConfigurationError(e.to_string()))
+        })?;
+        Self::new(token.into_inner())
+    }
+
+    /// Bot API base URL.
+    pub fn api_url(&self) -> &str {
+        &self.api_url
+    }
+
+    fn method_url(&self, method: &str) -> String {
+        format!("{}/bot{}/{}", self.api_url, self.token, method)
+    }
+
+    /// Call a Bot API method with a JSON body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the Bot API rejects it.
+    #[instrument(skip(self, body))]
+    pub async fn call<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        body: &JsonValue,
+    ) -> TelegramResult<T> {
+        let response = self
+            .http
+            .post(self.method_url(method))
+            .json(body)
+            .send()
+            .await?;
+        parse_response(response).await
+    }
+
+    /// Get the bot's own user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token is invalid.
+    pub async fn get_me(&self) -> TelegramResult<TelegramUser> {
+        self.call("getMe", &json!({})).await
+    }
+
+    /// Send a text message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is too long or the Bot API rejects it.
+    pub async fn send_message(
+        &self,
+        chat_id: &str,
+        text: &str,
+        options: &TelegramSendOptions,
+    ) -> TelegramResult<TelegramMessage> {
+        check_length("Message", text, TELEGRAM_MAX_MESSAGE_LENGTH)?;
+        let mut body = json!({ "chat_id": chat_id_value(chat_id)?, "text": text });
+        options.apply(&mut body);
+        self.call("sendMessage", &body).await
+    }
+
+    /// Replace the text of a message sent by the bot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message cannot be edited.
+    pub async fn edit_message_text(
+        &self,
+        chat_id: &str,
+        message_id: i64,
+        text: &str,
+        parse_mode: Option<&str>,
+    ) -> TelegramResult<TelegramMessage> {
+        check_length("Message", text, TELEGRAM_MAX_MESSAGE_LENGTH)?;
+        let mut body = json!({
+            "chat_id": chat_id_value(chat_id)?,
+            "message_id": message_id,
+            "text": text,
+        });
+        if let Some(mode) = parse_mode {
+            body["parse_mode"] = json!(mode);
+        }
+        self.call("editMessageText", &body).await
+    }
+
+    /// Delete a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message cannot be deleted.
+    pub async fn delete_message(&self, chat_id: &str, message_id: i64) -> TelegramResult<bool> {
+        self.call(
+            "deleteMessage",
+            &json!({ "chat_id": chat_id_value(chat_id)?, "message_id": message_id }),
+        )
+        .await
+    }
+
+    /// Get up-to-date information about a chat.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chat is not accessible to the bot.
+    pub async fn get_chat(&self, chat_id: &str) -> TelegramResult<TelegramChat> {
+        self.call("getChat", &json!({ "chat_id": chat_id_value(chat_id)? }))
+            .await
+    }
+
+    /// Number of members in a chat.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chat is not accessible to the bot.
+    pub async fn get_chat_member_count(&self, chat_id: &str) -> TelegramResult<i64> {
+        self.call(
+            "getChatMemberCount",
+            &json!({ "chat_id": chat_id_value(chat_id)? }),
+        )
+        .await
+    }
+
+    /// Send a native poll.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the poll is invalid (2-10 options) or rejected.
+    pub async fn send_poll(
+        &self,
+        chat_id: &str,
+        question: &str,
+        options: &[String],
+        is_anonymous: bool,
+        allows_multiple_answers: bool,
+    ) -> TelegramResult<TelegramMessage> {
+        if !(2..=10).contains(&options.len()) {
+            return Err(TelegramError::new(TelegramErrorKind::InvalidInput(
+                format!("Polls need 2-10 options, got {}", options.len()),
+            )));
+        }
+        let options: Vec<JsonValue> = options.iter().map(|o| json!({ "text": o })).collect();
+        self.call(
+            "sendPoll",
+            &json!({
+                "chat_id": chat_id_value(chat_id)?,
+                "question": question,
+                "options": options,
+                "is_anonymous": is_anonymous,
+                "allows_multiple_answers": allows_multiple_answers,
+            }),
+        )
+        .await
+    }
+
+    /// Send a photo, video, animation, audio file or document.
+    ///
+    /// `source` is an `http(s)://` URL or Telegram file ID (fetched by
+    /// Telegram), or a local path or `file://` URL (uploaded).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or the Bot API rejects it.
+    #[instrument(skip(self, caption, options))]
+    pub async fn send_media(
+        &self,
+        chat_id: &str,
+        kind: TelegramMediaKind,
+        source: &str,
+        caption: Option<&str>,
+        options: &TelegramSendOptions,
+    ) -> TelegramResult<TelegramMessage> {
+        if let Some(caption) = caption {
+            check_length("Caption", caption, TELEGRAM_MAX_CAPTION_LENGTH)?;
+        }
+        let chat_id = chat_id_value(chat_id)?;
+        let field = kind.to_string();
+
+        let local_path = source
+            .strip_prefix("file://")
+            .or_else(|| (source.starts_with('/') || source.starts_with('.')).then_some(source));
+
+        let Some(path) = local_path else {
+            let mut body = json!({ "chat_id": chat_id, field.as_str(): source });
+            if let Some(caption) = caption {
+                body["caption"] = json!(caption);
+            }
+            options.apply(&mut body);
+            return self.call(kind.method(), &body).await;
+        };
+
+        let bytes = tokio::fs::read(path).await.map_err(|e| {
+            TelegramError::new(TelegramErrorKind::InvalidInput(format!(
+                "Failed to read {}: {}",
+                path, e
+            )))
+        })?;
+        let file_name = path.rsplit('/').next().unwrap_or("file").to_string();
+        debug!(path, size = bytes.len(), "Uploading media");
+
+        // Multipart fields are strings, so nested values are JSON-encoded
+        let mut fields = json!({ "chat_id": chat_id });
+        if let Some(caption) = caption {
+            fields["caption"] = json!(caption);
+        }
+        options.apply(&mut fields);
+
+        let mut form = Form::new().part(field, Part::bytes(bytes).file_name(file_name));
+        if let JsonValue::Object(map) = fields {
+            for (key, value) in map {
+                let text = match value {
+                    JsonValue::String(s) => s,
+                    other => other.to_string(),
+                };
+                form = form.text(key, text);
+            }
+        }
+
+        let response = self
+            .http
+            .post(self.method_url(kind.method()))
+            .multipart(form)
+            .send()
+            .await?;
+        parse_response(response).await
+    }
+
+    /// Stop a poll and return its final results.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the poll cannot be stopped.
+    pub async fn stop_poll(&self, chat_id: &str, message_id: i64) -> TelegramResult<TelegramPoll> {
+        self.call(
+            "stopPoll",
+            &json!({ "chat_id": chat_id_value(chat_id)?, "message_id": message_id }),
+        )
+        .await
+    }
+
+    /// Long-poll for updates after `offset`.
+    ///
+    /// Waits up to `timeout_secs` for new updates. Passing the last seen
+    /// `update_id + 1` as `offset` confirms earlier updates to Telegram.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    #[instrument(skip(self))]
+    pub async fn get_updates(
+        &self,
+        offset: Option<i64>,
+        timeout_secs: u64,
+        limit: u32,
+    ) -> TelegramResult<Vec<TelegramUpdate>> {
+        let mut body = json!({
+            "timeout": timeout_secs,
+            "limit": limit,
+            "allowed_updates": ALLOWED_UPDATES,
+        });
+        if let Some(offset) = offset {
+            body["offset"] = json!(offset);
+        }
+
+        let response = self
+            .http
+            .post(self.method_url("getUpdates"))
+            .json(&body)
+            // Leave room for the server-side long-poll wait
+            .timeout(Duration::from_secs(timeout_secs + 10))
+            .send()
+            .await?;
+        parse_response(response).await
+    }
+}
+
+/// Turn a Bot API response into its result or an error.
+async fn parse_response<T: DeserializeOwned>(response: reqwest::Response) -> TelegramResult<T> {
+    let status = response.status();
+    let body: ApiResponse<T> = response.json().await.map_err(|e| {
+        TelegramError::new(TelegramErrorKind::Http(format!(
+            "Invalid Bot API response ({}): {}",
+            status, e
+        )))
+    })?;
+
+    if body.ok
+        && let Some(result) = body.result
+    {
+        return Ok(result);
+    }
+
+    let description = body.description.unwrap_or_default();
+    let code = body.error_code.unwrap_or(i64::from(status.as_u16()));
+    let parameters = body.parameters;
+
+    let kind = match code {
+        401 => TelegramErrorKind::Unauthorized(description),
+        429 => TelegramErrorKind::RateLimited(
+            parameters
+                .and_then(|p| p.retry_after)
+                .unwrap_or(DEFAULT_RETRY_AFTER_SECS),
+        ),
+        _ => match parameters.and_then(|p| p.migrate_to_chat_id) {
+            Some(chat_id) => TelegramErrorKind::ChatMigrated(chat_id),
+            None => TelegramErrorKind::Api { code, description },
+        },
+    };
+    Err(TelegramError::new(kind))
+}
+
+/// Convert a chat ID argument to the Bot API representation.
+///
+/// Numeric IDs (negative for groups) are sent as integers; `@channelname`
+/// usernames are sent as strings.
+pub fn chat_id_value(chat_id: &str) -> TelegramResult<JsonValue> {
+    let chat_id = chat_id.trim();
+    if let Ok(id) = chat_id.parse::<i64>() {
+        return Ok(json!(id));
+    }
+    if chat_id.starts_with('@') && chat_id.len() > 1 {
+        return Ok(json!(chat_id));
+    }
+    Err(TelegramError::new(TelegramErrorKind::InvalidInput(
+        format!("Chat ID must be numeric or an @username: '{}'", chat_id),
+    )))
+}
+
+fn check_length(what: &str, text: &str, max: usize) -> TelegramResult<()> {
+    let length = text.chars().count();
+    if length > max {
+        return Err(TelegramError::new(TelegramErrorKind::InvalidInput(
+            format!("{} is {} characters, Telegram allows {}", what, length, max),
+        )));
+    }
+    Ok(())
+}
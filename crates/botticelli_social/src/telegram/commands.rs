@@ -0,0 +1,639 @@
+//! Telegram bot command executor.
+
+use super::client::{TELEGRAM_MAX_MESSAGE_LENGTH, TelegramMediaKind, TelegramSendOptions};
+use super::{TelegramClient, TelegramError, TelegramErrorKind, TelegramMessage};
+use crate::{BotCommandError, BotCommandErrorKind, BotCommandExecutor, BotCommandResult};
+use async_trait::async_trait;
+use serde_json::{Value as JsonValue, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+/// Telegram command executor for narrative-driven Telegram operations.
+///
+/// Command and argument names mirror `DiscordCommandExecutor` where Telegram
+/// has an equivalent, so a narrative can switch platforms by changing
+/// `platform = "telegram"`. `channel_id` is the Telegram chat ID (numeric, or
+/// `@channelname`); `chat_id` is accepted as an alias.
+///
+/// Supported commands:
+/// - `messages.send` - Send a message (split if over 4096 characters)
+/// - `messages.edit` - Edit a message sent by the bot
+/// - `messages.delete` - Delete a message
+/// - `chats.get` - Get chat information
+/// - `polls.create` - Send a native poll
+/// - `media.send` - Send a photo, video, animation, audio file or document
+///
+/// # Example
+///
+/// ```toml
+/// [bots.announce]
+/// platform = "telegram"
+/// command = "messages.send"
+/// channel_id = "@botticelli_news"
+/// content = "{{select_best}}"
+/// ```
+#[derive(Debug, Clone)]
+pub struct TelegramCommandExecutor {
+    client: Arc<TelegramClient>,
+}
+
+impl TelegramCommandExecutor {
+    /// Create an executor around a Telegram client.
+    pub fn new(client: TelegramClient) -> Self {
+        Self::with_client(Arc::new(client))
+    }
+
+    /// Create an executor sharing an existing client.
+    pub fn with_client(client: Arc<TelegramClient>) -> Self {
+        Self { client }
+    }
+
+    /// Get the underlying client.
+    pub fn client(&self) -> &Arc<TelegramClient> {
+        &self.client
+    }
+
+    /// Send a message, splitting content over the length limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command arguments:
+    ///   - `channel_id` (required): Chat ID or `@channelname`
+    ///   - `content` (required): Message text
+    ///   - `parse_mode` (optional): "MarkdownV2", "HTML" or "Markdown"
+    ///   - `reply_to_message_id` (optional): Message to reply to
+    ///   - `disable_notification` (optional): Send silently
+    #[instrument(skip(self, args), fields(command = "messages.send"))]
+    async fn messages_send(
+        &self,
+        args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        const COMMAND: &str = "messages.send";
+        let chat_id = chat_id_arg(args, COMMAND)?;
+        let content = required_str(args, COMMAND, "content")?;
+        let mut options = send_options(args, COMMAND)?;
+
+        let chunks = split_message(content, TELEGRAM_MAX_MESSAGE_LENGTH);
+        let mut sent = Vec::with_capacity(chunks.len());
+        for chunk in &chunks {
+            let message = self
+                .client
+                .send_message(chat_id, chunk, &options)
+                .await
+                .map_err(|e| api_error(COMMAND, e))?;
+            // Later chunks continue the first one rather than the original reply target
+            options.reply_to_message_id = None;
+            sent.push(message);
+        }
+
+        info!(chat_id, parts = sent.len(), "Sent Telegram message");
+        let mut result = message_json(&sent[0]);
+        if sent.len() > 1 {
+            result["message_ids"] = json!(
+                sent.iter()
+                    .map(|m| m.message_id.to_string())
+                    .collect::<Vec<_>>()
+            );
+        }
+        Ok(result)
+    }
+
+    /// Edit the text of a message sent by the bot.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command arguments:
+    ///   - `channel_id` (required): Chat ID
+    ///   - `message_id` (required): Message to edit
+    ///   - `content` (required): New text
+    ///   - `parse_mode` (optional): Text formatting mode
+    #[instrument(skip(self, args), fields(command = "messages.edit"))]
+    async fn messages_edit(
+        &self,
+        args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        const COMMAND: &str = "messages.edit";
+        let chat_id = chat_id_arg(args, COMMAND)?;
+        let message_id = id_arg(args, COMMAND, "message_id")?;
+        let content = required_str(args, COMMAND, "content")?;
+        let parse_mode = args.get("parse_mode").and_then(|v| v.as_str());
+
+        let message = self
+            .client
+            .edit_message_text(chat_id, message_id, content, parse_mode)
+            .await
+            .map_err(|e| api_error(COMMAND, e))?;
+
+        info!(chat_id, message_id, "Edited Telegram message");
+        Ok(message_json(&message))
+    }
+
+    /// Delete a message.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command arguments:
+    ///   - `channel_id` (required): Chat ID
+    ///   - `message_id` (required): Message to delete
+    #[instrument(skip(self, args), fields(command = "messages.delete"))]
+    async fn messages_delete(
+        &self,
+        args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        const COMMAND: &str = "messages.delete";
+        let chat_id = chat_id_arg(args, COMMAND)?;
+        let message_id = id_arg(args, COMMAND, "message_id")?;
+
+        self.client
+            .delete_message(chat_id, message_id)
+            .await
+            .map_err(|e| api_error(COMMAND, e))?;
+
+        info!(chat_id, message_id, "Deleted Telegram message");
+        Ok(json!({
+            "deleted": true,
+            "channel_id": chat_id,
+            "message_id": message_id.to_string(),
+        }))
+    }
+
+    /// Get chat information and member count.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command arguments:
+    ///   - `channel_id` (required): Chat ID or `@channelname`
+    #[instrument(skip(self, args), fields(command = "chats.get"))]
+    async fn chats_get(&self, args: &HashMap<String, JsonValue>) -> BotCommandResult<JsonValue> {
+        const COMMAND: &str = "chats.get";
+        let chat_id = chat_id_arg(args, COMMAND)?;
+
+        let chat = self
+            .client
+            .get_chat(chat_id)
+            .await
+            .map_err(|e| api_error(COMMAND, e))?;
+        let member_count = self
+            .client
+            .get_chat_member_count(chat_id)
+            .await
+            .map_err(|e| api_error(COMMAND, e))?;
+
+        Ok(json!({
+            "id": chat.id.to_string(),
+            "type": chat.chat_type,
+            "title": chat.title,
+            "username": chat.username,
+            "description": chat.description,
+            "is_forum": chat.is_forum,
+            "member_count": member_count,
+        }))
+    }
+
+    /// Send a native poll.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command arguments:
+    ///   - `channel_id` (required): Chat ID
+    ///   - `question` (required): Poll question
+    ///   - `options` (required): Array of 2-10 answer strings
+    ///   - `is_anonymous` (optional): Default true
+    ///   - `allows_multiple_answers` (optional): Default false
+    #[instrument(skip(self, args), fields(command = "polls.create"))]
+    async fn polls_create(&self, args: &HashMap<String, JsonValue>) -> BotCommandResult<JsonValue> {
+        const COMMAND: &str = "polls.create";
+        let chat_id = chat_id_arg(args, COMMAND)?;
+        let question = required_str(args, COMMAND, "question")?;
+        let options: Vec<String> = args
+            .get("options")
+            .and_then(|v| v.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|v| v.as_str().map(str::to_string))
+                    .collect()
+            })
+            .ok_or_else(|| {
+                BotCommandError::new(BotCommandErrorKind::MissingArgument {
+                    command: COMMAND.to_string(),
+                    arg_name: "options".to_string(),
+                })
+            })?;
+        if !(2..=10).contains(&options.len()) {
+            return Err(BotCommandError::new(BotCommandErrorKind::InvalidArgument {
+                command: COMMAND.to_string(),
+                arg_name: "options".to_string(),
+                reason: format!("Polls need 2-10 options, got {}", options.len()),
+            }));
+        }
+        let is_anonymous = args
+            .get("is_anonymous")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+        let multiple = args
+            .get("allows_multiple_answers")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let message = self
+            .client
+            .send_poll(chat_id, question, &options, is_anonymous, multiple)
+            .await
+            .map_err(|e| api_error(COMMAND, e))?;
+
+        info!(
+            chat_id,
+            message_id = message.message_id,
+            "Sent Telegram poll"
+        );
+        let mut result = message_json(&message);
+        if let Some(poll) = &message.poll {
+            result["poll_id"] = json!(poll.id);
+        }
+        Ok(result)
+    }
+
+    /// Send media by URL, file ID or local path.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command arguments:
+    ///   - `channel_id` (required): Chat ID
+    ///   - `url` (required): `http(s)://` URL, Telegram file ID, or local path
+    ///   - `media_type` (optional): photo, video, animation, audio or document
+    ///     (guessed from the extension when omitted)
+    ///   - `caption` (optional): Caption, at most 1024 characters
+    ///   - `parse_mode` (optional): Caption formatting mode
+    #[instrument(skip(self, args), fields(command = "media.send"))]
+    async fn media_send(&self, args: &HashMap<String, JsonValue>) -> BotCommandResult<JsonValue> {
+        const COMMAND: &str = "media.send";
+        let chat_id = chat_id_arg(args, COMMAND)?;
+        let url = required_str(args, COMMAND, "url")?;
+        let kind = match args.get("media_type").and_then(|v| v.as_str()) {
+            Some(kind) => kind.parse::<TelegramMediaKind>().map_err(|e| {
+                BotCommandError::new(BotCommandErrorKind::InvalidArgument {
+                    command: COMMAND.to_string(),
+                    arg_name: "media_type".to_string(),
+                    reason: e.kind().to_string(),
+                })
+            })?,
+            None => TelegramMediaKind::from_extension(url),
+        };
+        let caption = args.get("caption").and_then(|v| v.as_str());
+        let options = send_options(args, COMMAND)?;
+
+        let message = self
+            .client
+            .send_media(chat_id, kind, url, caption, &options)
+            .await
+            .map_err(|e| api_error(COMMAND, e))?;
+
+        info!(chat_id, %kind, message_id = message.message_id, "Sent Telegram media");
+        let mut result = message_json(&message);
+        result["media_type"] = json!(kind.to_string());
+        Ok(result)
+    }
+}
+
+/// JSON shape shared with the Discord executor's message results.
+fn message_json(message: &TelegramMessage) -> JsonValue {
+    json!({
+        "id": message.message_id.to_string(),
+        "channel_id": message.chat.id.to_string(),
+        "content": message.text.as_ref().or(message.caption.as_ref()),
+        "timestamp": chrono::DateTime::from_timestamp(message.date, 0).map(|t| t.to_rfc3339()),
+    })
+}
+
+/// Split text into chunks of at most `max` characters, preferring line breaks.
+fn split_message(text: &str, max: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut rest = text;
+    while rest.chars().count() > max {
+        let limit = rest
+            .char_indices()
+            .nth(max)
+            .map(|(i, _)| i)
+            .unwrap_or(rest.len());
+        let split_at = rest[..limit]
+            .rfind('\n')
+            .or_else(|| rest[..limit].rfind(' '))
+            .filter(|&i| i > 0)
+            .unwrap_or(limit);
+        chunks.push(rest[..split_at].to_string());
+        rest = rest[split_at..].trim_start();
+    }
+    if !rest.is_empty() || chunks.is_empty() {
+        chunks.push(rest.to_string());
+    }
+    chunks
+}
+
+fn required_str<'a>(
+    args: &'a HashMap<String, JsonValue>,
+    command: &str,
+    arg_name: &str,
+) -> BotCommandResult<&'a str> {
+    args.get(arg_name).and_then(|v| v.as_str()).ok_or_else(|| {
+        BotCommandError::new(BotCommandErrorKind::MissingArgument {
+            command: command.to_string(),
+            arg_name: arg_name.to_string(),
+        })
+    })
+}
+
+/// Chat ID from `channel_id` (Discord naming) or `chat_id`.
+fn chat_id_arg<'a>(
+    args: &'a HashMap<String, JsonValue>,
+    command: &str,
+) -> BotCommandResult<&'a str> {
+    args.get("channel_id")
+        .or_else(|| args.get("chat_id"))
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| {
+            BotCommandError::new(BotCommandErrorKind::MissingArgument {
+                command: command.to_string(),
+                arg_name: "channel_id".to_string(),
+            })
+        })
+}
+
+/// Numeric ID given as a JSON number or string.
+fn id_arg(
+    args: &HashMap<String, JsonValue>,
+    command: &str,
+    arg_name: &str,
+) -> BotCommandResult<i64> {
+    let value = args.get(arg_name).ok_or_else(|| {
+        BotCommandError::new(BotCommandErrorKind::MissingArgument {
+            command: command.to_string(),
+            arg_name: arg_name.to_string(),
+        })
+    })?;
+    value
+        .as_i64()
+        .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+        .ok_or_else(|| {
+            BotCommandError::new(BotCommandErrorKind::InvalidArgument {
+                command: command.to_string(),
+                arg_name: arg_name.to_string(),
+                reason: format!("Expected a numeric ID, got {}", value),
+            })
+        })
+}
+
+fn send_options(
+    args: &HashMap<String, JsonValue>,
+    command: &str,
+) -> BotCommandResult<TelegramSendOptions> {
+    let reply_to_message_id = match args.get("reply_to_message_id") {
+        Some(_) => Some(id_arg(args, command, "reply_to_message_id")?),
+        None => None,
+    };
+    Ok(TelegramSendOptions {
+        parse_mode: args
+            .get("parse_mode")
+            .and_then(|v| v.as_str())
+            .map(str::to_string),
+        reply_to_message_id,
+        disable_notification: args
+            .get("disable_notification")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+    })
+}
+
+/// Map a client error to the matching bot command error.
+#[track_caller]
+fn api_error(command: &str, error: TelegramError) -> BotCommandError {
+    error!(command, error = %error, "Telegram command failed");
+    let kind = match error.kind() {
+        TelegramErrorKind::Unauthorized(reason) => BotCommandErrorKind::AuthenticationError {
+            platform: "telegram".to_string(),
+            reason: reason.clone(),
+        },
+        TelegramErrorKind::RateLimited(retry_after) => BotCommandErrorKind::RateLimitExceeded {
+            command: command.to_string(),
+            retry_after: *retry_after,
+        },
+        TelegramErrorKind::InvalidInput(reason) => BotCommandErrorKind::InvalidArgument {
+            command: command.to_string(),
+            arg_name: "args".to_string(),
+            reason: reason.clone(),
+        },
+        other => BotCommandErrorKind::ApiError {
+            command: command.to_string(),
+            reason: other.to_string(),
+        },
+    };
+    BotCommandError::new(kind)
+}
+
+#[async_trait]
+impl BotCommandExecutor for TelegramCommandExecutor {
+    fn platform(&self) -> &str {
+        "telegram"
+    }
+
+    fn supports_command(&self, command: &str) -> bool {
+        matches!(
+            command,
+            "messages.send"
+                | "messages.edit"
+                | "messages.delete"
+                | "chats.get"
+                | "polls.create"
+                | "media.send"
+        )
+    }
+
+    fn supported_commands(&self) -> Vec<String> {
+        vec![
+            "messages.send".to_string(),
+            "messages.edit".to_string(),
+            "messages.delete".to_string(),
+            "chats.get".to_string(),
+            "polls.create".to_string(),
+            "media.send".to_string(),
+        ]
+    }
+
+    #[instrument(
+        skip(self, args),
+        fields(
+            platform = "telegram",
+            command = %command,
+            arg_count = args.len()
+        )
+    )]
+    async fn execute(
+        &self,
+        command: &str,
+        args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        info!("Executing Telegram bot command");
+
+        match command {
+            "messages.send" => self.messages_send(args).await,
+            "messages.edit" => self.messages_edit(args).await,
+            "messages.delete" => self.messages_delete(args).await,
+            "chats.get" => self.chats_get(args).await,
+            "polls.create" => self.polls_create(args).await,
+            "media.send" => self.media_send(args).await,
+            _ => Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+                format!("telegram.{}", command),
+            ))),
+        }
+    }
+
+    async fn messages_bulk_delete(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "telegram.messages_bulk_delete".to_string(),
+        )))
+    }
+
+    async fn threads_create(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "telegram.threads_create".to_string(),
+        )))
+    }
+
+    async fn threads_list(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "telegram.threads_list".to_string(),
+        )))
+    }
+
+    async fn threads_get(&self, _args: &HashMap<String, JsonValue>) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "telegram.threads_get".to_string(),
+        )))
+    }
+
+    async fn threads_edit(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "telegram.threads_edit".to_string(),
+        )))
+    }
+
+    async fn threads_delete(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "telegram.threads_delete".to_string(),
+        )))
+    }
+
+    async fn threads_join(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "telegram.threads_join".to_string(),
+        )))
+    }
+
+    async fn threads_leave(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "telegram.threads_leave".to_string(),
+        )))
+    }
+
+    async fn threads_add_member(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "telegram.threads_add_member".to_string(),
+        )))
+    }
+
+    async fn threads_remove_member(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "telegram.threads_remove_member".to_string(),
+        )))
+    }
+
+    async fn reactions_list(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "telegram.reactions_list".to_string(),
+        )))
+    }
+
+    async fn reactions_clear(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "telegram.reactions_clear".to_string(),
+        )))
+    }
+
+    async fn reactions_clear_emoji(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "telegram.reactions_clear_emoji".to_string(),
+        )))
+    }
+
+    fn command_help(&self, command: &str) -> Option<String> {
+        let help = match command {
+            "messages.send" => {
+                "Send a message to a chat (split if over 4096 characters)\n\
+                 Required arguments: channel_id, content\n\
+                 Optional arguments: parse_mode, reply_to_message_id, disable_notification"
+            }
+            "messages.edit" => {
+                "Edit a message sent by the bot\n\
+                 Required arguments: channel_id, message_id, content\n\
+                 Optional arguments: parse_mode"
+            }
+            "messages.delete" => {
+                "Delete a message\n\
+                 Required arguments: channel_id, message_id"
+            }
+            "chats.get" => {
+                "Get chat information and member count\n\
+                 Required arguments: channel_id"
+            }
+            "polls.create" => {
+                "Send a native poll\n\
+                 Required arguments: channel_id, question, options (2-10 strings)\n\
+                 Optional arguments: is_anonymous (default true), allows_multiple_answers (default false)"
+            }
+            "media.send" => {
+                "Send a photo, video, animation, audio file or document\n\
+                 Required arguments: channel_id, url (URL, file ID or local path)\n\
+                 Optional arguments: media_type, caption, parse_mode, reply_to_message_id"
+            }
+            _ => return None,
+        };
+        Some(help.to_string())
+    }
+}
@@ -0,0 +1,85 @@
+//! Telegram-specific error types.
+
+use derive_getters::Getters;
+
+/// Telegram error variants.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
+pub enum TelegramErrorKind {
+    /// HTTP request could not be sent or the response could not be read.
+    #[display("HTTP error: {_0}")]
+    Http(String),
+
+    /// The bot token was rejected.
+    #[display("Unauthorized: {_0}")]
+    Unauthorized(String),
+
+    /// The Bot API is rate limiting requests.
+    #[display("Rate limited: retry after {_0}s")]
+    RateLimited(u64),
+
+    /// The group was upgraded to a supergroup with a new chat ID.
+    #[display("Chat migrated to {_0}")]
+    ChatMigrated(i64),
+
+    /// The Bot API returned an error.
+    #[display("Bot API error {code}: {description}")]
+    Api {
+        /// Error code returned by the Bot API (usually an HTTP status).
+        code: i64,
+        /// Human-readable description.
+        description: String,
+    },
+
+    /// Storing mirrored data failed.
+    #[display("Database error: {_0}")]
+    DatabaseError(String),
+
+    /// Invalid input (chat ID, media, arguments).
+    #[display("Invalid input: {_0}")]
+    InvalidInput(String),
+
+    /// Configuration error (missing token, invalid API URL).
+    #[display("Configuration error: {_0}")]
+    ConfigurationError(String),
+}
+
+/// Telegram error with source location tracking.
+#[derive(Debug, Clone, derive_more::Display, derive_more::Error, Getters)]
+#[display("Telegram Error: {} at line {} in {}", kind, line, file)]
+pub struct TelegramError {
+    kind: TelegramErrorKind,
+    line: u32,
+    file: &'static str,
+}
+
+impl TelegramError {
+    /// Create a new TelegramError with automatic location tracking.
+    #[track_caller]
+    pub fn new(kind: TelegramErrorKind) -> Self {
+        let location = std::panic::Location::caller();
+        Self {
+            kind,
+            line: location.line(),
+            file: location.file(),
+        }
+    }
+}
+
+/// Result type for Telegram operations.
+pub type TelegramResult<T> = Result<T, TelegramError>;
+
+impl From<reqwest::Error> for TelegramError {
+    /// Request URLs embed the bot token (`/bot<token>/<method>`), so the URL
+    /// is stripped before the error is formatted.
+    #[track_caller]
+    fn from(err: reqwest::Error) -> Self {
+        TelegramError::new(TelegramErrorKind::Http(err.without_url().to_string()))
+    }
+}
+
+impl From<botticelli_error::DatabaseError> for TelegramError {
+    #[track_caller]
+    fn from(err: botticelli_error::DatabaseError) -> Self {
+        TelegramError::new(TelegramErrorKind::DatabaseError(err.to_string()))
+    }
+}
@@ -0,0 +1,59 @@
+//! Telegram integration for Botticelli.
+//!
+//! Talks to the Telegram Bot API directly over HTTPS. It enables Botticelli to:
+//! - Send, edit and delete messages, polls and media from narratives
+//! - Post actor content to chats and channels
+//! - Mirror chats, users and messages into Postgres via long polling
+//!
+//! # Architecture
+//!
+//! ## Data Layer
+//! - **models**: Diesel models for Telegram chats, users and messages
+//! - **repository**: Database operations following the repository pattern
+//!
+//! ## Integration Layer
+//! - **client**: Bot API client
+//! - **types**: Bot API types
+//! - **poller**: Long-polling update ingestion
+//! - **error**: Telegram-specific error types
+//!
+//! ## Feature Layer
+//! - **commands**: Bot command executor for narratives
+//!
+//! # Usage
+//!
+//! Available with the `telegram` feature.
+//!
+//! ```rust,ignore
+//! use botticelli_social::{TelegramClient, TelegramPoller, TelegramRepository};
+//! use std::sync::Arc;
+//!
+//! let client = Arc::new(TelegramClient::from_secrets()?);
+//! let repository = TelegramRepository::new(create_pool()?);
+//! tokio::spawn(TelegramPoller::new(client, repository).run());
+//! ```
+
+mod client;
+mod commands;
+mod error;
+mod models;
+mod poller;
+mod repository;
+mod types;
+
+pub use client::{
+    TELEGRAM_API_URL, TELEGRAM_MAX_CAPTION_LENGTH, TELEGRAM_MAX_MESSAGE_LENGTH, TelegramClient,
+    TelegramMediaKind, TelegramSendOptions, chat_id_value,
+};
+pub use commands::TelegramCommandExecutor;
+pub use error::{TelegramError, TelegramErrorKind, TelegramResult};
+pub use models::{
+    NewTelegramChat, NewTelegramMessage, NewTelegramUser, TelegramChatRow, TelegramMessageRow,
+    TelegramUserRow,
+};
+pub use poller::{TELEGRAM_DEFAULT_POLL_TIMEOUT_SECS, TelegramPoller};
+pub use repository::{TelegramRepository, TelegramRepositoryResult};
+pub use types::{
+    TelegramChat, TelegramFile, TelegramMessage, TelegramPhotoSize, TelegramPoll,
+    TelegramPollOption, TelegramUpdate, TelegramUser,
+};
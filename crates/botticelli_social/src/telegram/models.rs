@@ -0,0 +1,182 @@
+//! Diesel models for the Telegram mirror tables.
+//!
+//! Following the Discord models, each entity has a `*Row` (Queryable, for
+//! SELECT queries) and a `New*` (Insertable, for upserts) struct. `New*`
+//! structs are built from Bot API types with `From`.
+
+use super::{TelegramChat, TelegramMessage, TelegramUser};
+use botticelli_database::schema::{telegram_chats, telegram_messages, telegram_users};
+use chrono::{DateTime, NaiveDateTime};
+use diesel::prelude::*;
+
+/// Database row for the telegram_chats table.
+#[derive(Debug, Clone, Queryable, Identifiable, Selectable, derive_getters::Getters)]
+#[diesel(table_name = telegram_chats)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TelegramChatRow {
+    /// Chat ID
+    id: i64,
+    /// Chat type (private, group, supergroup, channel)
+    chat_type: String,
+    /// Title, for groups and channels
+    title: Option<String>,
+    /// Public username
+    username: Option<String>,
+    /// First name, for private chats
+    first_name: Option<String>,
+    /// Last name, for private chats
+    last_name: Option<String>,
+    /// Whether topics are enabled
+    is_forum: Option<bool>,
+
+    // Timestamps
+    first_seen: NaiveDateTime,
+    last_seen: NaiveDateTime,
+    created_at: NaiveDateTime,
+    updated_at: NaiveDateTime,
+}
+
+/// Insertable struct for the telegram_chats table.
+#[derive(Debug, Clone, Insertable, derive_getters::Getters)]
+#[diesel(table_name = telegram_chats)]
+pub struct NewTelegramChat {
+    pub(crate) id: i64,
+    pub(crate) chat_type: String,
+    pub(crate) title: Option<String>,
+    pub(crate) username: Option<String>,
+    pub(crate) first_name: Option<String>,
+    pub(crate) last_name: Option<String>,
+    pub(crate) is_forum: Option<bool>,
+}
+
+impl From<&TelegramChat> for NewTelegramChat {
+    fn from(chat: &TelegramChat) -> Self {
+        Self {
+            id: chat.id,
+            chat_type: chat.chat_type.clone(),
+            title: chat.title.clone(),
+            username: chat.username.clone(),
+            first_name: chat.first_name.clone(),
+            last_name: chat.last_name.clone(),
+            is_forum: chat.is_forum,
+        }
+    }
+}
+
+/// Database row for the telegram_users table.
+#[derive(Debug, Clone, Queryable, Identifiable, Selectable, derive_getters::Getters)]
+#[diesel(table_name = telegram_users)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TelegramUserRow {
+    /// User ID
+    id: i64,
+    /// Whether the user is a bot
+    is_bot: bool,
+    /// First name
+    first_name: String,
+    /// Last name
+    last_name: Option<String>,
+    /// Username
+    username: Option<String>,
+    /// Client language
+    language_code: Option<String>,
+
+    // Timestamps
+    first_seen: NaiveDateTime,
+    last_seen: NaiveDateTime,
+    created_at: NaiveDateTime,
+    updated_at: NaiveDateTime,
+}
+
+/// Insertable struct for the telegram_users table.
+#[derive(Debug, Clone, Insertable, derive_getters::Getters)]
+#[diesel(table_name = telegram_users)]
+pub struct NewTelegramUser {
+    pub(crate) id: i64,
+    pub(crate) is_bot: bool,
+    pub(crate) first_name: String,
+    pub(crate) last_name: Option<String>,
+    pub(crate) username: Option<String>,
+    pub(crate) language_code: Option<String>,
+}
+
+impl From<&TelegramUser> for NewTelegramUser {
+    fn from(user: &TelegramUser) -> Self {
+        Self {
+            id: user.id,
+            is_bot: user.is_bot,
+            first_name: user.first_name.clone(),
+            last_name: user.last_name.clone(),
+            username: user.username.clone(),
+            language_code: user.language_code.clone(),
+        }
+    }
+}
+
+/// Database row for the telegram_messages table.
+#[derive(Debug, Clone, Queryable, Identifiable, Selectable, derive_getters::Getters)]
+#[diesel(table_name = telegram_messages)]
+#[diesel(primary_key(chat_id, message_id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TelegramMessageRow {
+    /// Chat ID
+    chat_id: i64,
+    /// Message ID within the chat
+    message_id: i64,
+    /// Sender, absent for channel posts
+    from_user_id: Option<i64>,
+    /// Message text
+    text: Option<String>,
+    /// Media caption
+    caption: Option<String>,
+    /// Attached media kind
+    media_type: Option<String>,
+    /// Message this one replies to
+    reply_to_message_id: Option<i64>,
+    /// When the message was sent
+    sent_at: NaiveDateTime,
+    /// When the message was last edited
+    edited_at: Option<NaiveDateTime>,
+
+    // Timestamps
+    created_at: NaiveDateTime,
+    updated_at: NaiveDateTime,
+}
+
+/// Insertable struct for the telegram_messages table.
+#[derive(Debug, Clone, Insertable, derive_getters::Getters)]
+#[diesel(table_name = telegram_messages)]
+pub struct NewTelegramMessage {
+    pub(crate) chat_id: i64,
+    pub(crate) message_id: i64,
+    pub(crate) from_user_id: Option<i64>,
+    pub(crate) text: Option<String>,
+    pub(crate) caption: Option<String>,
+    pub(crate) media_type: Option<String>,
+    pub(crate) reply_to_message_id: Option<i64>,
+    pub(crate) sent_at: NaiveDateTime,
+    pub(crate) edited_at: Option<NaiveDateTime>,
+}
+
+impl From<&TelegramMessage> for NewTelegramMessage {
+    fn from(message: &TelegramMessage) -> Self {
+        Self {
+            chat_id: message.chat.id,
+            message_id: message.message_id,
+            from_user_id: message.from.as_ref().map(|u| u.id),
+            text: message.text.clone(),
+            caption: message.caption.clone(),
+            media_type: message.media_type().map(str::to_string),
+            reply_to_message_id: message.reply_to_message.as_ref().map(|m| m.message_id),
+            sent_at: unix_to_naive(message.date),
+            edited_at: message.edit_date.map(unix_to_naive),
+        }
+    }
+}
+
+/// Convert Bot API Unix seconds to a UTC timestamp.
+fn unix_to_naive(seconds: i64) -> NaiveDateTime {
+    DateTime::from_timestamp(seconds, 0)
+        .unwrap_or_default()
+        .naive_utc()
+}
@@ -0,0 +1,134 @@
+//! Long-polling update ingestion into Postgres.
+
+use super::models::{NewTelegramChat, NewTelegramMessage, NewTelegramUser};
+use super::{
+    TelegramClient, TelegramErrorKind, TelegramRepository, TelegramResult, TelegramUpdate,
+};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, instrument, warn};
+
+/// Default long-poll wait in seconds.
+pub const TELEGRAM_DEFAULT_POLL_TIMEOUT_SECS: u64 = 30;
+
+/// Maximum updates fetched per poll (Bot API limit).
+const MAX_UPDATES_PER_POLL: u32 = 100;
+
+/// Delay before retrying after a failed poll.
+const ERROR_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Mirrors Telegram chats, users and messages into Postgres.
+///
+/// Uses `getUpdates` long polling (the bot must not have a webhook set).
+/// The update offset is stored per bot, so a restarted poller picks up
+/// where the previous one stopped.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let client = Arc::new(TelegramClient::from_secrets()?);
+/// let repository = TelegramRepository::new(create_pool()?);
+/// TelegramPoller::new(client, repository).run().await;
+/// ```
+pub struct TelegramPoller {
+    client: Arc<TelegramClient>,
+    repository: TelegramRepository,
+    timeout_secs: u64,
+    bot_id: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl TelegramPoller {
+    /// Create a poller that stores updates through `repository`.
+    pub fn new(client: Arc<TelegramClient>, repository: TelegramRepository) -> Self {
+        Self {
+            client,
+            repository,
+            timeout_secs: TELEGRAM_DEFAULT_POLL_TIMEOUT_SECS,
+            bot_id: None,
+            offset: None,
+        }
+    }
+
+    /// Set how long each poll waits for new updates.
+    pub fn with_timeout_secs(mut self, timeout_secs: u64) -> Self {
+        self.timeout_secs = timeout_secs;
+        self
+    }
+
+    /// Fetch one batch of updates and store them.
+    ///
+    /// Returns the number of updates processed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Bot API or database calls fail. Updates
+    /// stored before the failure are not requested again.
+    #[instrument(skip(self))]
+    pub async fn poll_once(&mut self) -> TelegramResult<usize> {
+        let bot_id = match self.bot_id {
+            Some(id) => id,
+            None => {
+                let me = self.client.get_me().await?;
+                info!(bot_id = me.id, username = ?me.username, "Telegram poller started");
+                self.offset = self.repository.get_update_offset(me.id).await?;
+                self.bot_id = Some(me.id);
+                me.id
+            }
+        };
+
+        let updates = self
+            .client
+            .get_updates(self.offset, self.timeout_secs, MAX_UPDATES_PER_POLL)
+            .await?;
+
+        for update in &updates {
+            store_update(&self.repository, update).await?;
+            let next = update.update_id + 1;
+            self.repository.set_update_offset(bot_id, next).await?;
+            self.offset = Some(next);
+        }
+
+        if !updates.is_empty() {
+            debug!(count = updates.len(), offset = ?self.offset, "Stored Telegram updates");
+        }
+        Ok(updates.len())
+    }
+
+    /// Poll forever, backing off after errors.
+    ///
+    /// Rate limits are honoured; other errors are logged and retried.
+    pub async fn run(mut self) {
+        loop {
+            if let Err(e) = self.poll_once().await {
+                let delay = match e.kind() {
+                    TelegramErrorKind::RateLimited(secs) => Duration::from_secs(*secs),
+                    _ => ERROR_BACKOFF,
+                };
+                warn!(error = %e, delay_secs = delay.as_secs(), "Telegram poll failed");
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+}
+
+/// Store the chat, sender and message carried by an update.
+async fn store_update(
+    repository: &TelegramRepository,
+    update: &TelegramUpdate,
+) -> TelegramResult<()> {
+    let Some(message) = update.any_message() else {
+        return Ok(());
+    };
+
+    repository
+        .store_chat(&NewTelegramChat::from(&message.chat))
+        .await?;
+    if let Some(user) = &message.from {
+        repository.store_user(&NewTelegramUser::from(user)).await?;
+    }
+    repository
+        .store_message(&NewTelegramMessage::from(message))
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,216 @@
+//! PostgreSQL repository for Telegram data.
+//!
+//! Stores chats, users and messages seen by the long-polling ingester, plus
+//! the update offset so polling resumes after a restart.
+
+use super::models::{
+    NewTelegramChat, NewTelegramMessage, NewTelegramUser, TelegramChatRow, TelegramMessageRow,
+    TelegramUserRow,
+};
+use botticelli_database::schema::{
+    telegram_chats, telegram_messages, telegram_update_offsets, telegram_users,
+};
+use botticelli_database::{PgPool, with_pooled_connection};
+use botticelli_error::DatabaseError;
+use diesel::prelude::*;
+use tracing::instrument;
+
+/// Result type for Telegram repository operations.
+pub type TelegramRepositoryResult<T> = Result<T, DatabaseError>;
+
+/// PostgreSQL repository for Telegram data.
+///
+/// # Example
+/// ```no_run
+/// use botticelli_social::TelegramRepository;
+/// use botticelli_database::create_pool;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let pool = create_pool()?;
+///     let repo = TelegramRepository::new(pool);
+///     let recent = repo.list_messages(-1001234567890, 20).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TelegramRepository {
+    /// Connection pool
+    pool: PgPool,
+}
+
+impl TelegramRepository {
+    /// Create a new Telegram repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Store or update a chat.
+    #[instrument(skip(self), fields(chat_id = chat.id))]
+    pub async fn store_chat(
+        &self,
+        chat: &NewTelegramChat,
+    ) -> TelegramRepositoryResult<TelegramChatRow> {
+        let chat = chat.clone();
+        with_pooled_connection(&self.pool, move |conn| {
+            diesel::insert_into(telegram_chats::table)
+                .values(&chat)
+                .on_conflict(telegram_chats::id)
+                .do_update()
+                .set((
+                    telegram_chats::chat_type.eq(chat.chat_type()),
+                    telegram_chats::title.eq(chat.title()),
+                    telegram_chats::username.eq(chat.username()),
+                    telegram_chats::first_name.eq(chat.first_name()),
+                    telegram_chats::last_name.eq(chat.last_name()),
+                    telegram_chats::is_forum.eq(chat.is_forum()),
+                    telegram_chats::last_seen.eq(diesel::dsl::now),
+                    telegram_chats::updated_at.eq(diesel::dsl::now),
+                ))
+                .get_result(conn)
+                .map_err(DatabaseError::from)
+        })
+        .await
+    }
+
+    /// Get a chat by ID.
+    #[instrument(skip(self))]
+    pub async fn get_chat(
+        &self,
+        chat_id: i64,
+    ) -> TelegramRepositoryResult<Option<TelegramChatRow>> {
+        with_pooled_connection(&self.pool, move |conn| {
+            telegram_chats::table
+                .find(chat_id)
+                .first(conn)
+                .optional()
+                .map_err(DatabaseError::from)
+        })
+        .await
+    }
+
+    /// Store or update a user.
+    #[instrument(skip(self), fields(user_id = user.id))]
+    pub async fn store_user(
+        &self,
+        user: &NewTelegramUser,
+    ) -> TelegramRepositoryResult<TelegramUserRow> {
+        let user = user.clone();
+        with_pooled_connection(&self.pool, move |conn| {
+            diesel::insert_into(telegram_users::table)
+                .values(&user)
+                .on_conflict(telegram_users::id)
+                .do_update()
+                .set((
+                    telegram_users::first_name.eq(user.first_name()),
+                    telegram_users::last_name.eq(user.last_name()),
+                    telegram_users::username.eq(user.username()),
+                    telegram_users::language_code.eq(user.language_code()),
+                    telegram_users::last_seen.eq(diesel::dsl::now),
+                    telegram_users::updated_at.eq(diesel::dsl::now),
+                ))
+                .get_result(conn)
+                .map_err(DatabaseError::from)
+        })
+        .await
+    }
+
+    /// Get a user by ID.
+    #[instrument(skip(self))]
+    pub async fn get_user(
+        &self,
+        user_id: i64,
+    ) -> TelegramRepositoryResult<Option<TelegramUserRow>> {
+        with_pooled_connection(&self.pool, move |conn| {
+            telegram_users::table
+                .find(user_id)
+                .first(conn)
+                .optional()
+                .map_err(DatabaseError::from)
+        })
+        .await
+    }
+
+    /// Store a message, updating text and edit time if it was already stored.
+    #[instrument(skip(self), fields(chat_id = message.chat_id, message_id = message.message_id))]
+    pub async fn store_message(
+        &self,
+        message: &NewTelegramMessage,
+    ) -> TelegramRepositoryResult<TelegramMessageRow> {
+        let message = message.clone();
+        with_pooled_connection(&self.pool, move |conn| {
+            diesel::insert_into(telegram_messages::table)
+                .values(&message)
+                .on_conflict((telegram_messages::chat_id, telegram_messages::message_id))
+                .do_update()
+                .set((
+                    telegram_messages::text.eq(message.text()),
+                    telegram_messages::caption.eq(message.caption()),
+                    telegram_messages::edited_at.eq(message.edited_at()),
+                    telegram_messages::updated_at.eq(diesel::dsl::now),
+                ))
+                .get_result(conn)
+                .map_err(DatabaseError::from)
+        })
+        .await
+    }
+
+    /// List the most recent messages in a chat, newest first.
+    #[instrument(skip(self))]
+    pub async fn list_messages(
+        &self,
+        chat_id: i64,
+        limit: i64,
+    ) -> TelegramRepositoryResult<Vec<TelegramMessageRow>> {
+        with_pooled_connection(&self.pool, move |conn| {
+            telegram_messages::table
+                .filter(telegram_messages::chat_id.eq(chat_id))
+                .order(telegram_messages::sent_at.desc())
+                .limit(limit)
+                .load(conn)
+                .map_err(DatabaseError::from)
+        })
+        .await
+    }
+
+    /// Get the next update ID to request for a bot.
+    #[instrument(skip(self))]
+    pub async fn get_update_offset(&self, bot_id: i64) -> TelegramRepositoryResult<Option<i64>> {
+        with_pooled_connection(&self.pool, move |conn| {
+            telegram_update_offsets::table
+                .find(bot_id)
+                .select(telegram_update_offsets::next_update_id)
+                .first(conn)
+                .optional()
+                .map_err(DatabaseError::from)
+        })
+        .await
+    }
+
+    /// Record the next update ID to request for a bot.
+    #[instrument(skip(self))]
+    pub async fn set_update_offset(
+        &self,
+        bot_id: i64,
+        next_update_id: i64,
+    ) -> TelegramRepositoryResult<()> {
+        with_pooled_connection(&self.pool, move |conn| {
+            diesel::insert_into(telegram_update_offsets::table)
+                .values((
+                    telegram_update_offsets::bot_id.eq(bot_id),
+                    telegram_update_offsets::next_update_id.eq(next_update_id),
+                ))
+                .on_conflict(telegram_update_offsets::bot_id)
+                .do_update()
+                .set((
+                    telegram_update_offsets::next_update_id.eq(next_update_id),
+                    telegram_update_offsets::updated_at.eq(diesel::dsl::now),
+                ))
+                .execute(conn)
+                .map_err(DatabaseError::from)?;
+
+            Ok(())
+        })
+        .await
+    }
+}
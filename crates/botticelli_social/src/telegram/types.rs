@@ -0,0 +1,195 @@
+//! Telegram Bot API types (the fields Botticelli uses).
+
+use serde::{Deserialize, Serialize};
+
+/// A Telegram user or bot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelegramUser {
+    /// User ID.
+    pub id: i64,
+    /// Whether this user is a bot.
+    pub is_bot: bool,
+    /// First name.
+    pub first_name: String,
+    /// Last name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Username (without `@`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// IETF language tag of the user's client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+/// A private chat, group, supergroup or channel.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelegramChat {
+    /// Chat ID.
+    pub id: i64,
+    /// Chat type: "private", "group", "supergroup" or "channel".
+    #[serde(rename = "type")]
+    pub chat_type: String,
+    /// Title, for groups and channels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Username, for private chats, supergroups and channels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// First name of the other party in a private chat.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /// Last name of the other party in a private chat.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Whether the supergroup has topics enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_forum: Option<bool>,
+    /// Description (only returned by `getChat`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// One size of a photo.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelegramPhotoSize {
+    /// File identifier for downloading or resending.
+    pub file_id: String,
+    /// Width in pixels.
+    pub width: i64,
+    /// Height in pixels.
+    pub height: i64,
+}
+
+/// A file attachment (video, document, audio, animation).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelegramFile {
+    /// File identifier for downloading or resending.
+    pub file_id: String,
+    /// Original file name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    /// MIME type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+}
+
+/// A poll option with its vote count.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelegramPollOption {
+    /// Option text.
+    pub text: String,
+    /// Number of votes.
+    pub voter_count: i64,
+}
+
+/// A native poll.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelegramPoll {
+    /// Poll ID.
+    pub id: String,
+    /// Poll question.
+    pub question: String,
+    /// Answer options.
+    pub options: Vec<TelegramPollOption>,
+    /// Total number of voters.
+    pub total_voter_count: i64,
+    /// Whether the poll is closed.
+    pub is_closed: bool,
+}
+
+/// A message in a chat.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelegramMessage {
+    /// Message ID, unique within the chat.
+    pub message_id: i64,
+    /// Unix time the message was sent.
+    pub date: i64,
+    /// Chat the message belongs to.
+    pub chat: TelegramChat,
+    /// Sender (absent for channel posts).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<TelegramUser>,
+    /// Message text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Caption of a media message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Photo sizes, for photo messages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub photo: Option<Vec<TelegramPhotoSize>>,
+    /// Video attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub video: Option<TelegramFile>,
+    /// Animation (GIF) attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animation: Option<TelegramFile>,
+    /// Audio attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio: Option<TelegramFile>,
+    /// Document attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document: Option<TelegramFile>,
+    /// Poll, for poll messages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poll: Option<TelegramPoll>,
+    /// Message this one replies to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_to_message: Option<Box<TelegramMessage>>,
+    /// Unix time of the last edit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edit_date: Option<i64>,
+}
+
+impl TelegramMessage {
+    /// Kind of media attached to the message, if any.
+    pub fn media_type(&self) -> Option<&'static str> {
+        if self.photo.is_some() {
+            Some("photo")
+        } else if self.animation.is_some() {
+            // Animations also carry a `document`, so check them first
+            Some("animation")
+        } else if self.video.is_some() {
+            Some("video")
+        } else if self.audio.is_some() {
+            Some("audio")
+        } else if self.document.is_some() {
+            Some("document")
+        } else if self.poll.is_some() {
+            Some("poll")
+        } else {
+            None
+        }
+    }
+}
+
+/// An incoming update from `getUpdates`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelegramUpdate {
+    /// Update ID; the next poll starts after the highest one seen.
+    pub update_id: i64,
+    /// New message in a private chat or group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<TelegramMessage>,
+    /// Edited message in a private chat or group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edited_message: Option<TelegramMessage>,
+    /// New post in a channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_post: Option<TelegramMessage>,
+    /// Edited post in a channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edited_channel_post: Option<TelegramMessage>,
+}
+
+impl TelegramUpdate {
+    /// The message carried by this update, whichever kind it is.
+    pub fn any_message(&self) -> Option<&TelegramMessage> {
+        self.message
+            .as_ref()
+            .or(self.edited_message.as_ref())
+            .or(self.channel_post.as_ref())
+            .or(self.edited_channel_post.as_ref())
+    }
+}
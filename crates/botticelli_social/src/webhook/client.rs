@@ -0,0 +1,255 @@
+//! Webhook HTTP client with retries and signing.
+
+use super::signing::sign_hex;
+use super::template::{default_body_template, render_template};
+use super::{WebhookConfig, WebhookError, WebhookErrorKind, WebhookResult};
+use reqwest::Method;
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue, RETRY_AFTER};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::{debug, info, instrument, warn};
+
+/// Longest response body kept in errors and results.
+const MAX_CAPTURED_BODY: usize = 4096;
+
+/// Response from a webhook destination.
+#[derive(Debug, Clone, PartialEq, derive_getters::Getters)]
+pub struct WebhookResponse {
+    /// HTTP status code.
+    status: u16,
+    /// Response body (truncated).
+    body: String,
+    /// Response body parsed as JSON, if it was JSON.
+    json: Option<JsonValue>,
+    /// Number of attempts made.
+    attempts: u32,
+}
+
+impl WebhookResponse {
+    /// Extract fields by JSON pointer, keyed by the capture names.
+    ///
+    /// Missing fields are skipped; strings are returned without quotes.
+    pub fn capture(&self, fields: &HashMap<String, String>) -> HashMap<String, String> {
+        let Some(json) = &self.json else {
+            return HashMap::new();
+        };
+        fields
+            .iter()
+            .filter_map(|(name, pointer)| {
+                let value = json.pointer(pointer)?;
+                let text = match value {
+                    JsonValue::String(s) => s.clone(),
+                    other => other.to_string(),
+                };
+                Some((name.clone(), text))
+            })
+            .collect()
+    }
+}
+
+/// Client for one configured webhook destination.
+#[derive(Debug, Clone)]
+pub struct WebhookClient {
+    config: WebhookConfig,
+    method: Method,
+    http: reqwest::Client,
+}
+
+impl WebhookClient {
+    /// Create a client for a destination.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is not http(s), the method is unknown, or
+    /// a header name is invalid.
+    pub fn new(config: WebhookConfig) -> WebhookResult<Self> {
+        let url = config.url();
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err(configuration_error(format!(
+                "Webhook URL must be an http(s) URL: {}",
+                url
+            )));
+        }
+        let method = Method::from_str(&config.method().to_ascii_uppercase()).map_err(|e| {
+            configuration_error(format!("Invalid method '{}': {}", config.method(), e))
+        })?;
+        for name in config.headers().keys() {
+            HeaderName::from_str(name)
+                .map_err(|e| configuration_error(format!("Invalid header '{}': {}", name, e)))?;
+        }
+
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(*config.timeout_secs()))
+            .build()?;
+
+        Ok(Self {
+            config,
+            method,
+            http,
+        })
+    }
+
+    /// Get the destination configuration.
+    pub fn config(&self) -> &WebhookConfig {
+        &self.config
+    }
+
+    /// Render the body template with `variables` and send it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails, a secret cannot be resolved, or
+    /// the destination still fails after all retries.
+    pub async fn send(
+        &self,
+        variables: &HashMap<String, JsonValue>,
+    ) -> WebhookResult<WebhookResponse> {
+        let body = match self.config.body() {
+            Some(template) => render_template(template, variables)?,
+            None => render_template(&default_body_template(), variables)?,
+        };
+        self.send_json(Some(&body)).await
+    }
+
+    /// Send a prepared JSON body (or none), with signing and retries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a secret cannot be resolved or the destination
+    /// still fails after all retries.
+    #[instrument(skip(self, body), fields(url = %self.config.url(), method = %self.method))]
+    pub async fn send_json(&self, body: Option<&JsonValue>) -> WebhookResult<WebhookResponse> {
+        let bytes = match body {
+            Some(body) if self.method != Method::GET && self.method != Method::HEAD => {
+                serde_json::to_vec(body)
+                    .map_err(|e| WebhookError::new(WebhookErrorKind::Template(e.to_string())))?
+            }
+            _ => Vec::new(),
+        };
+        let headers = self.headers(&bytes)?;
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            match self.attempt(&headers, &bytes).await {
+                Ok((status, text)) => {
+                    info!(status, attempts = attempt, "Webhook delivered");
+                    let json = serde_json::from_str(&text).ok();
+                    return Ok(WebhookResponse {
+                        status,
+                        body: truncate(text),
+                        json,
+                        attempts: attempt,
+                    });
+                }
+                Err(e) if e.kind().is_retryable() && attempt <= *self.config.max_retries() => {
+                    let delay = self.retry_delay(attempt, e.kind());
+                    warn!(error = %e, attempt, delay_ms = delay.as_millis() as u64, "Webhook failed, retrying");
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// One request; success returns the status and body text.
+    async fn attempt(&self, headers: &HeaderMap, bytes: &[u8]) -> WebhookResult<(u16, String)> {
+        let mut request = self
+            .http
+            .request(self.method.clone(), self.config.url())
+            .headers(headers.clone());
+        if !bytes.is_empty() {
+            request = request.body(bytes.to_vec());
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+        let text = response.text().await?;
+        debug!(status = status.as_u16(), "Webhook responded");
+
+        if status.is_success() {
+            Ok((status.as_u16(), text))
+        } else {
+            Err(WebhookError::new(WebhookErrorKind::Status {
+                status: status.as_u16(),
+                body: truncate(text),
+                retry_after,
+            }))
+        }
+    }
+
+    /// Build headers, resolving `secret:` references and adding the signature.
+    fn headers(&self, body: &[u8]) -> WebhookResult<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        for (name, value) in self.config.headers() {
+            let value = resolve(value)?;
+            let name = HeaderName::from_str(name)
+                .map_err(|e| configuration_error(format!("Invalid header '{}': {}", name, e)))?;
+            let mut value = HeaderValue::from_str(&value).map_err(|e| {
+                configuration_error(format!("Invalid value for header '{}': {}", name, e))
+            })?;
+            value.set_sensitive(true);
+            headers.insert(name, value);
+        }
+
+        if let Some(signing) = self.config.signing() {
+            let key = resolve(signing.secret())?;
+            let signature = format!("{}{}", signing.prefix(), sign_hex(key.as_bytes(), body));
+            let name = HeaderName::from_str(signing.header()).map_err(|e| {
+                configuration_error(format!(
+                    "Invalid signature header '{}': {}",
+                    signing.header(),
+                    e
+                ))
+            })?;
+            let value = HeaderValue::from_str(&signature)
+                .map_err(|e| configuration_error(e.to_string()))?;
+            headers.insert(name, value);
+        }
+
+        Ok(headers)
+    }
+
+    /// Exponential backoff, or the server's `Retry-After` when given.
+    fn retry_delay(&self, attempt: u32, kind: &WebhookErrorKind) -> Duration {
+        if let WebhookErrorKind::Status {
+            retry_after: Some(secs),
+            ..
+        } = kind
+        {
+            return Duration::from_secs(*secs);
+        }
+        let factor = 2u64.saturating_pow(attempt.saturating_sub(1));
+        Duration::from_millis(self.config.retry_backoff_ms().saturating_mul(factor))
+    }
+}
+
+fn resolve(value: &str) -> WebhookResult<String> {
+    botticelli_secrets::resolve_secret_reference(value)
+        .map_err(|e| WebhookError::new(WebhookErrorKind::Secret(e.to_string())))
+}
+
+#[track_caller]
+fn configuration_error(message: String) -> WebhookError {
+    WebhookError::new(WebhookErrorKind::ConfigurationError(message))
+}
+
+fn truncate(mut text: String) -> String {
+    if text.len() > MAX_CAPTURED_BODY {
+        let mut end = MAX_CAPTURED_BODY;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+    }
+    text
+}
@@ -0,0 +1,389 @@
+//! HTTP bot command executor.
+
+use super::template::{default_body_template, render_template};
+use super::{
+    WebhookClient, WebhookConfig, WebhookError, WebhookErrorKind, WebhookResponse, WebhookSigning,
+};
+use crate::{BotCommandError, BotCommandErrorKind, BotCommandExecutor, BotCommandResult};
+use async_trait::async_trait;
+use serde_json::{Value as JsonValue, json};
+use std::collections::HashMap;
+use tracing::{error, info, instrument};
+
+/// HTTP command executor for narrative-driven webhook calls.
+///
+/// Destinations can be registered by name with [`HttpCommandExecutor::with_webhook`]
+/// and called with `webhooks.send`, or called ad hoc with `request`.
+///
+/// Supported commands:
+/// - `request` - Send a request to an arbitrary URL
+/// - `webhooks.send` - Render and send a registered webhook's body template
+///
+/// # Example
+///
+/// ```toml
+/// [bots.notify]
+/// platform = "http"
+/// command = "webhooks.send"
+/// webhook = "slack"
+/// text = "{{select_best}}"
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HttpCommandExecutor {
+    webhooks: HashMap<String, WebhookClient>,
+}
+
+impl HttpCommandExecutor {
+    /// Create an executor with no registered webhooks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a named webhook destination.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn with_webhook(
+        mut self,
+        name: impl Into<String>,
+        config: WebhookConfig,
+    ) -> Result<Self, WebhookError> {
+        self.webhooks
+            .insert(name.into(), WebhookClient::new(config)?);
+        Ok(self)
+    }
+
+    /// Names of registered webhooks.
+    pub fn webhook_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.webhooks.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Send an ad-hoc request.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command arguments:
+    ///   - `url` (required): Destination URL
+    ///   - `method` (optional): HTTP method (default "POST")
+    ///   - `headers` (optional): Object of header values (`secret:` references allowed)
+    ///   - `body` (optional): JSON body
+    ///   - `sign_secret` (optional): HMAC-SHA256 signing key
+    ///   - `max_retries` (optional): Retries on 408/429/5xx (default 3)
+    #[instrument(skip(self, args), fields(command = "request"))]
+    async fn request(&self, args: &HashMap<String, JsonValue>) -> BotCommandResult<JsonValue> {
+        const COMMAND: &str = "request";
+        let url = args.get("url").and_then(|v| v.as_str()).ok_or_else(|| {
+            BotCommandError::new(BotCommandErrorKind::MissingArgument {
+                command: COMMAND.to_string(),
+                arg_name: "url".to_string(),
+            })
+        })?;
+
+        let mut builder = WebhookConfig::builder();
+        builder.url(url.to_string());
+        if let Some(method) = args.get("method").and_then(|v| v.as_str()) {
+            builder.method(method.to_string());
+        }
+        if let Some(headers) = args.get("headers") {
+            let headers: HashMap<String, String> = serde_json::from_value(headers.clone())
+                .map_err(|e| invalid_argument(COMMAND, "headers", e.to_string()))?;
+            builder.headers(headers);
+        }
+        if let Some(secret) = args.get("sign_secret").and_then(|v| v.as_str()) {
+            builder.signing(WebhookSigning::new(secret));
+        }
+        if let Some(retries) = args.get("max_retries") {
+            let retries = retries
+                .as_u64()
+                .and_then(|r| u32::try_from(r).ok())
+                .ok_or_else(|| {
+                    invalid_argument(
+                        COMMAND,
+                        "max_retries",
+                        "Expected a non-negative integer".to_string(),
+                    )
+                })?;
+            builder.max_retries(retries);
+        }
+        let config = builder
+            .build()
+            .map_err(|e| invalid_argument(COMMAND, "url", e.to_string()))?;
+
+        let client = WebhookClient::new(config).map_err(|e| api_error(COMMAND, e))?;
+        let response = client
+            .send_json(args.get("body"))
+            .await
+            .map_err(|e| api_error(COMMAND, e))?;
+        Ok(response_json(&response, &HashMap::new()))
+    }
+
+    /// Send a registered webhook.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command arguments:
+    ///   - `webhook` (required): Registered webhook name
+    ///   - `text` (optional): Message text, available as `{{text}}`
+    ///   - Any other argument is available to the body template by name
+    #[instrument(skip(self, args), fields(command = "webhooks.send"))]
+    async fn webhooks_send(
+        &self,
+        args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        const COMMAND: &str = "webhooks.send";
+        let name = args
+            .get("webhook")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                BotCommandError::new(BotCommandErrorKind::MissingArgument {
+                    command: COMMAND.to_string(),
+                    arg_name: "webhook".to_string(),
+                })
+            })?;
+        let client = self.webhooks.get(name).ok_or_else(|| {
+            BotCommandError::new(BotCommandErrorKind::ResourceNotFound {
+                command: COMMAND.to_string(),
+                resource_type: format!("webhook '{}'", name),
+            })
+        })?;
+
+        let template = client
+            .config()
+            .body()
+            .clone()
+            .unwrap_or_else(default_body_template);
+        let body = render_template(&template, args).map_err(|e| api_error(COMMAND, e))?;
+        let response = client
+            .send_json(Some(&body))
+            .await
+            .map_err(|e| api_error(COMMAND, e))?;
+        Ok(response_json(&response, client.config().capture()))
+    }
+}
+
+fn response_json(response: &WebhookResponse, capture: &HashMap<String, String>) -> JsonValue {
+    json!({
+        "status": response.status(),
+        "attempts": response.attempts(),
+        "body": response.json().clone().unwrap_or_else(|| JsonValue::String(response.body().clone())),
+        "captured": response.capture(capture),
+    })
+}
+
+#[track_caller]
+fn invalid_argument(command: &str, arg_name: &str, reason: String) -> BotCommandError {
+    BotCommandError::new(BotCommandErrorKind::InvalidArgument {
+        command: command.to_string(),
+        arg_name: arg_name.to_string(),
+        reason,
+    })
+}
+
+/// Map a webhook error to the matching bot command error.
+#[track_caller]
+fn api_error(command: &str, error: WebhookError) -> BotCommandError {
+    error!(command, error = %error, "HTTP command failed");
+    let kind = match error.kind() {
+        WebhookErrorKind::Status {
+            status: 401 | 403,
+            body,
+            ..
+        }
+        | WebhookErrorKind::Secret(body) => BotCommandErrorKind::AuthenticationError {
+            platform: "http".to_string(),
+            reason: body.clone(),
+        },
+        WebhookErrorKind::Status {
+            status: 429,
+            retry_after,
+            ..
+        } => BotCommandErrorKind::RateLimitExceeded {
+            command: command.to_string(),
+            retry_after: retry_after.unwrap_or(60),
+        },
+        WebhookErrorKind::Template(reason) | WebhookErrorKind::ConfigurationError(reason) => {
+            BotCommandErrorKind::InvalidArgument {
+                command: command.to_string(),
+                arg_name: "args".to_string(),
+                reason: reason.clone(),
+            }
+        }
+        other => BotCommandErrorKind::ApiError {
+            command: command.to_string(),
+            reason: other.to_string(),
+        },
+    };
+    BotCommandError::new(kind)
+}
+
+#[async_trait]
+impl BotCommandExecutor for HttpCommandExecutor {
+    fn platform(&self) -> &str {
+        "http"
+    }
+
+    fn supports_command(&self, command: &str) -> bool {
+        matches!(command, "request" | "webhooks.send")
+    }
+
+    fn supported_commands(&self) -> Vec<String> {
+        vec!["request".to_string(), "webhooks.send".to_string()]
+    }
+
+    #[instrument(
+        skip(self, args),
+        fields(
+            platform = "http",
+            command = %command,
+            arg_count = args.len()
+        )
+    )]
+    async fn execute(
+        &self,
+        command: &str,
+        args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        info!("Executing HTTP bot command");
+
+        match command {
+            "request" => self.request(args).await,
+            "webhooks.send" => self.webhooks_send(args).await,
+            _ => Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+                format!("http.{}", command),
+            ))),
+        }
+    }
+
+    async fn messages_bulk_delete(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "http.messages_bulk_delete".to_string(),
+        )))
+    }
+
+    async fn threads_create(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "http.threads_create".to_string(),
+        )))
+    }
+
+    async fn threads_list(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "http.threads_list".to_string(),
+        )))
+    }
+
+    async fn threads_get(&self, _args: &HashMap<String, JsonValue>) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "http.threads_get".to_string(),
+        )))
+    }
+
+    async fn threads_edit(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "http.threads_edit".to_string(),
+        )))
+    }
+
+    async fn threads_delete(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "http.threads_delete".to_string(),
+        )))
+    }
+
+    async fn threads_join(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "http.threads_join".to_string(),
+        )))
+    }
+
+    async fn threads_leave(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "http.threads_leave".to_string(),
+        )))
+    }
+
+    async fn threads_add_member(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "http.threads_add_member".to_string(),
+        )))
+    }
+
+    async fn threads_remove_member(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "http.threads_remove_member".to_string(),
+        )))
+    }
+
+    async fn reactions_list(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "http.reactions_list".to_string(),
+        )))
+    }
+
+    async fn reactions_clear(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "http.reactions_clear".to_string(),
+        )))
+    }
+
+    async fn reactions_clear_emoji(
+        &self,
+        _args: &HashMap<String, JsonValue>,
+    ) -> BotCommandResult<JsonValue> {
+        Err(BotCommandError::new(BotCommandErrorKind::CommandNotFound(
+            "http.reactions_clear_emoji".to_string(),
+        )))
+    }
+    fn command_help(&self, command: &str) -> Option<String> {
+        let help = match command {
+            "request" => {
+                "Send an HTTP request to an arbitrary URL\n\
+                 Required arguments: url\n\
+                 Optional arguments: method, headers, body, sign_secret, max_retries"
+            }
+            "webhooks.send" => {
+                "Render and send a registered webhook's body template\n\
+                 Required arguments: webhook\n\
+                 Optional arguments: text, plus any variable used by the template"
+            }
+            _ => return None,
+        };
+        Some(help.to_string())
+    }
+}
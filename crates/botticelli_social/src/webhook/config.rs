@@ -0,0 +1,130 @@
+//! Webhook destination configuration.
+
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+
+/// HMAC-SHA256 signing of the request body.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Getters, derive_builder::Builder)]
+#[builder(setter(into))]
+pub struct WebhookSigning {
+    /// Signing key, usually a `secret:name` reference.
+    secret: String,
+
+    /// Header carrying the signature.
+    #[builder(default = "default_signature_header()")]
+    #[serde(default = "default_signature_header")]
+    header: String,
+
+    /// Prefix before the hex digest.
+    #[builder(default = "default_signature_prefix()")]
+    #[serde(default = "default_signature_prefix")]
+    prefix: String,
+}
+
+impl WebhookSigning {
+    /// Sign with `secret` using the default header and prefix.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secret: secret.into(),
+            header: default_signature_header(),
+            prefix: default_signature_prefix(),
+        }
+    }
+}
+
+fn default_signature_header() -> String {
+    "X-Signature-256".to_string()
+}
+
+fn default_signature_prefix() -> String {
+    "sha256=".to_string()
+}
+
+/// Configuration for one webhook destination.
+///
+/// # Example
+///
+/// ```toml
+/// url = "https://hooks.slack.com/services/T000/B000/XXXX"
+/// method = "POST"
+/// headers = { Authorization = "secret:internal_api_token" }
+/// body = { text = "{{text}}", attachments = "{{media_urls}}" }
+/// max_retries = 3
+///
+/// [signing]
+/// secret = "secret:webhook_signing_key"
+///
+/// [capture]
+/// message_ts = "/ts"
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Getters, derive_builder::Builder)]
+#[builder(setter(into))]
+pub struct WebhookConfig {
+    /// Destination URL.
+    url: String,
+
+    /// HTTP method.
+    #[builder(default = "default_method()")]
+    #[serde(default = "default_method")]
+    method: String,
+
+    /// Extra headers; values may be `secret:name` references.
+    #[builder(default)]
+    #[serde(default)]
+    headers: HashMap<String, String>,
+
+    /// JSON body template (defaults to `{"text": "{{text}}"}`).
+    #[builder(default, setter(strip_option))]
+    #[serde(default)]
+    body: Option<JsonValue>,
+
+    /// Request signing.
+    #[builder(default, setter(strip_option))]
+    #[serde(default)]
+    signing: Option<WebhookSigning>,
+
+    /// Retries after the first attempt for network errors, 408, 429 and 5xx.
+    #[builder(default = "default_max_retries()")]
+    #[serde(default = "default_max_retries")]
+    max_retries: u32,
+
+    /// Initial delay between retries, doubled after each attempt.
+    #[builder(default = "default_retry_backoff_ms()")]
+    #[serde(default = "default_retry_backoff_ms")]
+    retry_backoff_ms: u64,
+
+    /// Per-attempt timeout in seconds.
+    #[builder(default = "default_timeout_secs()")]
+    #[serde(default = "default_timeout_secs")]
+    timeout_secs: u64,
+
+    /// Response fields to capture: result key to JSON pointer (e.g. `"/id"`).
+    #[builder(default)]
+    #[serde(default)]
+    capture: HashMap<String, String>,
+}
+
+fn default_method() -> String {
+    "POST".to_string()
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+fn default_retry_backoff_ms() -> u64 {
+    500
+}
+
+fn default_timeout_secs() -> u64 {
+    30
+}
+
+impl WebhookConfig {
+    /// Get a builder for a webhook configuration.
+    pub fn builder() -> WebhookConfigBuilder {
+        WebhookConfigBuilder::default()
+    }
+}
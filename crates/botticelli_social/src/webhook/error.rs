@@ -0,0 +1,77 @@
+//! Webhook-specific error types.
+
+use derive_getters::Getters;
+
+/// Webhook error variants.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
+pub enum WebhookErrorKind {
+    /// HTTP request could not be sent or the response could not be read.
+    #[display("HTTP error: {_0}")]
+    Http(String),
+
+    /// The destination answered with a non-success status.
+    #[display("Webhook returned {status}: {body}")]
+    Status {
+        /// HTTP status code.
+        status: u16,
+        /// Response body (truncated).
+        body: String,
+        /// Seconds to wait before retrying, from `Retry-After`.
+        retry_after: Option<u64>,
+    },
+
+    /// The body template could not be rendered.
+    #[display("Template error: {_0}")]
+    Template(String),
+
+    /// A `secret:` reference in a header or signing key could not be resolved.
+    #[display("Secret error: {_0}")]
+    Secret(String),
+
+    /// Invalid configuration (URL, method, header names).
+    #[display("Configuration error: {_0}")]
+    ConfigurationError(String),
+}
+
+impl WebhookErrorKind {
+    /// Whether retrying the request may succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Http(_) => true,
+            Self::Status { status, .. } => *status == 408 || *status == 429 || *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+/// Webhook error with source location tracking.
+#[derive(Debug, Clone, derive_more::Display, derive_more::Error, Getters)]
+#[display("Webhook Error: {} at line {} in {}", kind, line, file)]
+pub struct WebhookError {
+    kind: WebhookErrorKind,
+    line: u32,
+    file: &'static str,
+}
+
+impl WebhookError {
+    /// Create a new WebhookError with automatic location tracking.
+    #[track_caller]
+    pub fn new(kind: WebhookErrorKind) -> Self {
+        let location = std::panic::Location::caller();
+        Self {
+            kind,
+            line: location.line(),
+            file: location.file(),
+        }
+    }
+}
+
+/// Result type for webhook operations.
+pub type WebhookResult<T> = Result<T, WebhookError>;
+
+impl From<reqwest::Error> for WebhookError {
+    #[track_caller]
+    fn from(err: reqwest::Error) -> Self {
+        WebhookError::new(WebhookErrorKind::Http(err.to_string()))
+    }
+}
@@ -0,0 +1,45 @@
+//! Generic webhook/HTTP integration for Botticelli.
+//!
+//! Delivers content to any HTTP endpoint: Slack-compatible incoming webhooks,
+//! Matrix hooks, or internal services. It enables Botticelli to:
+//! - Post actor content through a configurable URL, method and headers
+//! - Build request bodies from JSON templates
+//! - Sign request bodies with HMAC-SHA256
+//! - Retry transient failures and capture fields from the response
+//!
+//! # Architecture
+//!
+//! - **config**: Destination configuration (URL, headers, template, signing)
+//! - **template**: `{{variable}}` substitution in JSON bodies
+//! - **signing**: HMAC-SHA256 request signing
+//! - **client**: HTTP client with retries
+//! - **commands**: Bot command executor for narratives
+//! - **error**: Webhook-specific error types
+//!
+//! # Usage
+//!
+//! Available with the `webhook` feature.
+//!
+//! ```rust,ignore
+//! use botticelli_social::{WebhookClient, WebhookConfig, message_variables};
+//!
+//! let config = WebhookConfig::builder()
+//!     .url("https://hooks.slack.com/services/T000/B000/XXXX")
+//!     .build()?;
+//! let client = WebhookClient::new(config)?;
+//! client.send(&message_variables("Hello!", &[])).await?;
+//! ```
+
+mod client;
+mod commands;
+mod config;
+mod error;
+mod signing;
+mod template;
+
+pub use client::{WebhookClient, WebhookResponse};
+pub use commands::HttpCommandExecutor;
+pub use config::{WebhookConfig, WebhookConfigBuilder, WebhookSigning, WebhookSigningBuilder};
+pub use error::{WebhookError, WebhookErrorKind, WebhookResult};
+pub use signing::{hmac_sha256, sign_hex};
+pub use template::{default_body_template, message_variables, render_template};
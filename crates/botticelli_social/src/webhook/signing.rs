@@ -0,0 +1,33 @@
+//! HMAC-SHA256 request signing.
+
+use sha2::{Digest, Sha256};
+
+/// SHA-256 block size in bytes.
+const BLOCK_SIZE: usize = 64;
+
+/// Compute HMAC-SHA256 of `message` with `key` (RFC 2104).
+pub fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    inner.update(message);
+
+    let mut outer = Sha256::new();
+    outer.update(block.map(|b| b ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
+
+/// Lowercase hex HMAC-SHA256 signature, as used by GitHub-style webhooks.
+pub fn sign_hex(key: &[u8], message: &[u8]) -> String {
+    hmac_sha256(key, message)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
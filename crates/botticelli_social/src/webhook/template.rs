@@ -0,0 +1,101 @@
+//! JSON body templates.
+//!
+//! String values in a template may reference variables with `{{name}}`. A
+//! string that is exactly one placeholder is replaced by the variable's JSON
+//! value (so `"{{media_urls}}"` becomes an array); placeholders inside longer
+//! strings are replaced by the variable's text.
+
+use super::{WebhookError, WebhookErrorKind, WebhookResult};
+use serde_json::{Value as JsonValue, json};
+use std::collections::HashMap;
+
+/// Template used when a webhook has no `body` configured.
+///
+/// `text` is the field Slack-compatible incoming webhooks expect.
+pub fn default_body_template() -> JsonValue {
+    json!({ "text": "{{text}}" })
+}
+
+/// Variables describing a post: `text`, `media_urls`, `media_url` (first URL
+/// or null) and `timestamp` (RFC 3339).
+pub fn message_variables(text: &str, media_urls: &[String]) -> HashMap<String, JsonValue> {
+    HashMap::from([
+        ("text".to_string(), json!(text)),
+        ("media_urls".to_string(), json!(media_urls)),
+        ("media_url".to_string(), json!(media_urls.first())),
+        (
+            "timestamp".to_string(),
+            json!(chrono::Utc::now().to_rfc3339()),
+        ),
+    ])
+}
+
+/// Render a JSON template with variables.
+///
+/// # Errors
+///
+/// Returns an error if a placeholder is unterminated or names an unknown variable.
+pub fn render_template(
+    template: &JsonValue,
+    variables: &HashMap<String, JsonValue>,
+) -> WebhookResult<JsonValue> {
+    Ok(match template {
+        JsonValue::String(s) => render_string(s, variables)?,
+        JsonValue::Array(items) => JsonValue::Array(
+            items
+                .iter()
+                .map(|item| render_template(item, variables))
+                .collect::<WebhookResult<_>>()?,
+        ),
+        JsonValue::Object(map) => JsonValue::Object(
+            map.iter()
+                .map(|(k, v)| Ok((k.clone(), render_template(v, variables)?)))
+                .collect::<WebhookResult<_>>()?,
+        ),
+        other => other.clone(),
+    })
+}
+
+fn render_string(s: &str, variables: &HashMap<String, JsonValue>) -> WebhookResult<JsonValue> {
+    // A lone placeholder keeps the variable's JSON type
+    if let Some(name) = s
+        .strip_prefix("{{")
+        .and_then(|rest| rest.strip_suffix("}}"))
+        .filter(|name| !name.contains("{{") && !name.contains("}}"))
+    {
+        return lookup(name.trim(), variables).cloned();
+    }
+
+    let mut rendered = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find("}}").ok_or_else(|| {
+            WebhookError::new(WebhookErrorKind::Template(format!(
+                "Unterminated placeholder in '{}'",
+                s
+            )))
+        })?;
+        match lookup(after[..end].trim(), variables)? {
+            JsonValue::String(text) => rendered.push_str(text),
+            JsonValue::Null => {}
+            other => rendered.push_str(&other.to_string()),
+        }
+        rest = &after[end + 2..];
+    }
+    rendered.push_str(rest);
+    Ok(JsonValue::String(rendered))
+}
+
+fn lookup<'a>(
+    name: &str,
+    variables: &'a HashMap<String, JsonValue>,
+) -> WebhookResult<&'a JsonValue> {
+    variables.get(name).ok_or_else(|| {
+        WebhookError::new(WebhookErrorKind::Template(format!(
+            "Unknown template variable '{}'",
+            name
+        )))
+    })
+}
@@ -0,0 +1,423 @@
+//! Tests for the Bluesky client and command executor against a mock XRPC server.
+
+#![cfg(feature = "bluesky")]
+
+use axum::extract::{Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use botticelli_social::{
+    BlueskyClient, BlueskyCommandExecutor, BlueskyErrorKind, BotCommandErrorKind,
+    BotCommandExecutor, FacetFeature, NewPost, detect_facets, grapheme_len,
+};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+const IDENTIFIER: &str = "bot.test";
+const PASSWORD: &str = "app-password";
+const DID: &str = "did:plc:bot";
+
+/// Requests observed by the mock server.
+#[derive(Default)]
+struct Recorded {
+    logins: usize,
+    refreshes: usize,
+    records: Vec<Value>,
+    /// Reject the next authenticated call with `ExpiredToken`.
+    expire_next: bool,
+}
+
+type Shared = Arc<Mutex<Recorded>>;
+
+fn bearer(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(str::to_string)
+}
+
+fn session(access: &str) -> Value {
+    json!({
+        "accessJwt": access,
+        "refreshJwt": "refresh",
+        "did": DID,
+        "handle": IDENTIFIER,
+    })
+}
+
+/// Check the access token, honouring a pending forced expiry.
+fn authorize(state: &Shared, headers: &HeaderMap) -> Result<(), (StatusCode, Json<Value>)> {
+    let mut recorded = state.lock().unwrap();
+    if recorded.expire_next {
+        recorded.expire_next = false;
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "error": "ExpiredToken", "message": "Token has expired" })),
+        ));
+    }
+    match bearer(headers).as_deref() {
+        Some("access-1") | Some("access-2") => Ok(()),
+        _ => Err((
+            StatusCode::UNAUTHORIZED,
+            Json(json!({ "error": "AuthenticationRequired", "message": "Invalid token" })),
+        )),
+    }
+}
+
+async fn create_session(
+    State(state): State<Shared>,
+    Json(body): Json<Value>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    if body["identifier"] != IDENTIFIER || body["password"] != PASSWORD {
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            Json(
+                json!({ "error": "AuthenticationRequired", "message": "Invalid identifier or password" }),
+            ),
+        ));
+    }
+    state.lock().unwrap().logins += 1;
+    Ok(Json(session("access-1")))
+}
+
+async fn refresh_session(State(state): State<Shared>, headers: HeaderMap) -> Json<Value> {
+    assert_eq!(bearer(&headers).as_deref(), Some("refresh"));
+    state.lock().unwrap().refreshes += 1;
+    Json(session("access-2"))
+}
+
+async fn create_record(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Json(body): Json<Value>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    authorize(&state, &headers)?;
+    let mut recorded = state.lock().unwrap();
+    recorded.records.push(body);
+    let n = recorded.records.len();
+    Ok(Json(json!({
+        "uri": format!("at://{DID}/app.bsky.feed.post/{n}"),
+        "cid": format!("cid{n}"),
+    })))
+}
+
+async fn upload_blob(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    body: axum::body::Bytes,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    authorize(&state, &headers)?;
+    let mime = headers
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    Ok(Json(json!({
+        "blob": { "$type": "blob", "ref": { "$link": "bafyblob" }, "mimeType": mime, "size": body.len() }
+    })))
+}
+
+async fn resolve_handle(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    authorize(&state, &headers)?;
+    match params.get("handle").map(String::as_str) {
+        Some("alice.test") => Ok(Json(json!({ "did": "did:plc:alice" }))),
+        _ => Err((
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "error": "InvalidRequest", "message": "Unable to resolve handle" })),
+        )),
+    }
+}
+
+async fn get_timeline(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    authorize(&state, &headers)?;
+    assert_eq!(params.get("limit").map(String::as_str), Some("2"));
+    Ok(Json(json!({
+        "cursor": "next",
+        "feed": [{
+            "post": {
+                "uri": "at://did:plc:alice/app.bsky.feed.post/1",
+                "cid": "c1",
+                "author": { "handle": "alice.test" },
+                "record": { "text": "hello", "createdAt": "2025-01-01T00:00:00Z" },
+                "likeCount": 3,
+            }
+        }]
+    })))
+}
+
+async fn get_profile(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    authorize(&state, &headers)?;
+    Ok(Json(json!({
+        "did": params.get("actor"),
+        "handle": IDENTIFIER,
+        "displayName": "Bot",
+        "followersCount": 7,
+    })))
+}
+
+async fn rate_limited() -> (StatusCode, [(&'static str, &'static str); 1]) {
+    (StatusCode::TOO_MANY_REQUESTS, [("retry-after", "42")])
+}
+
+/// Start a mock PDS, returning its base URL and recorded requests.
+async fn mock_server() -> (String, Shared) {
+    let state = Shared::default();
+    let app = Router::new()
+        .route(
+            "/xrpc/com.atproto.server.createSession",
+            post(create_session),
+        )
+        .route(
+            "/xrpc/com.atproto.server.refreshSession",
+            post(refresh_session),
+        )
+        .route("/xrpc/com.atproto.repo.createRecord", post(create_record))
+        .route("/xrpc/com.atproto.repo.uploadBlob", post(upload_blob))
+        .route(
+            "/xrpc/com.atproto.identity.resolveHandle",
+            get(resolve_handle),
+        )
+        .route("/xrpc/app.bsky.feed.getTimeline", get(get_timeline))
+        .route("/xrpc/app.bsky.actor.getProfile", get(get_profile))
+        .route(
+            "/xrpc/app.bsky.notification.listNotifications",
+            get(rate_limited),
+        )
+        .route("/images/cat.png", get(|| async { vec![0u8; 32] }))
+        .with_state(state.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    (format!("http://{addr}"), state)
+}
+
+fn args(pairs: &[(&str, Value)]) -> HashMap<String, Value> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.clone()))
+        .collect()
+}
+
+#[test]
+fn test_detect_facets() {
+    let text = "Ask @alice.test about https://example.com/a. #rust #1";
+    let facets = detect_facets(text);
+
+    assert_eq!(facets.len(), 3);
+    assert_eq!(
+        facets[0].feature,
+        FacetFeature::Mention("alice.test".to_string())
+    );
+    assert_eq!(
+        facets[1].feature,
+        FacetFeature::Link("https://example.com/a".to_string())
+    );
+    assert_eq!(facets[2].feature, FacetFeature::Tag("rust".to_string()));
+    assert_eq!(
+        &text[facets[1].byte_start..facets[1].byte_end],
+        "https://example.com/a"
+    );
+}
+
+#[test]
+fn test_facet_offsets_are_utf8_bytes() {
+    let text = "café 👩‍👩‍👧 https://x.test";
+    let facets = detect_facets(text);
+    assert_eq!(
+        &text[facets[0].byte_start..facets[0].byte_end],
+        "https://x.test"
+    );
+    assert_eq!(grapheme_len("café 👩‍👩‍👧"), 6);
+}
+
+#[test]
+fn test_invalid_client_configuration() {
+    assert!(BlueskyClient::new("bsky.social", IDENTIFIER, PASSWORD).is_err());
+    assert!(BlueskyClient::new("https://bsky.social", "", PASSWORD).is_err());
+}
+
+#[tokio::test]
+async fn test_create_post_with_facets_images_and_reply() {
+    let (service, state) = mock_server().await;
+    let client = BlueskyClient::new(&service, IDENTIFIER, PASSWORD).unwrap();
+
+    let mut post = NewPost::text("Hi @alice.test and @nobody.test, see https://example.com #bots");
+    post.images.push(botticelli_social::ImageUpload {
+        bytes: vec![1, 2, 3],
+        mime_type: "image/png".to_string(),
+        alt: "dots".to_string(),
+    });
+    let created = client
+        .create_post(&post)
+        .await
+        .expect("post should succeed");
+    assert_eq!(created.cid, "cid1");
+
+    let recorded = state.lock().unwrap();
+    assert_eq!(recorded.logins, 1);
+    let record = &recorded.records[0]["record"];
+    assert_eq!(recorded.records[0]["repo"], DID);
+    assert_eq!(record["$type"], "app.bsky.feed.post");
+
+    // The unresolvable mention is left as plain text
+    let facets = record["facets"].as_array().unwrap();
+    assert_eq!(facets.len(), 3);
+    assert_eq!(facets[0]["features"][0]["did"], "did:plc:alice");
+    assert_eq!(facets[1]["features"][0]["uri"], "https://example.com");
+    assert_eq!(facets[2]["features"][0]["tag"], "bots");
+
+    let image = &record["embed"]["images"][0];
+    assert_eq!(image["alt"], "dots");
+    assert_eq!(image["image"]["size"], 3);
+}
+
+#[tokio::test]
+async fn test_post_too_long_is_rejected_locally() {
+    let client = BlueskyClient::new("https://bsky.test", IDENTIFIER, PASSWORD).unwrap();
+    let err = client
+        .create_post(&NewPost::text("a".repeat(301)))
+        .await
+        .unwrap_err();
+    assert_eq!(*err.kind(), BlueskyErrorKind::PostTooLong(301));
+}
+
+#[tokio::test]
+async fn test_expired_token_is_refreshed() {
+    let (service, state) = mock_server().await;
+    let client = BlueskyClient::new(&service, IDENTIFIER, PASSWORD).unwrap();
+
+    client.session().await.unwrap();
+    state.lock().unwrap().expire_next = true;
+    client
+        .create_post(&NewPost::text("still here"))
+        .await
+        .unwrap();
+
+    let recorded = state.lock().unwrap();
+    assert_eq!(recorded.logins, 1);
+    assert_eq!(recorded.refreshes, 1);
+    assert_eq!(recorded.records.len(), 1);
+}
+
+#[tokio::test]
+async fn test_bad_password_is_authentication_error() {
+    let (service, _) = mock_server().await;
+    let client = BlueskyClient::new(&service, IDENTIFIER, "wrong").unwrap();
+    let err = client.session().await.unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        BlueskyErrorKind::AuthenticationFailed(_)
+    ));
+}
+
+#[tokio::test]
+async fn test_executor_commands() {
+    let (service, state) = mock_server().await;
+    let executor =
+        BlueskyCommandExecutor::new(BlueskyClient::new(&service, IDENTIFIER, PASSWORD).unwrap());
+
+    assert_eq!(executor.platform(), "bluesky");
+    assert!(executor.supports_command("posts.create"));
+    assert!(executor.command_help("feed.get").is_some());
+
+    let created = executor
+        .execute(
+            "posts.create",
+            &args(&[
+                ("text", json!("Picture time")),
+                ("images", json!([format!("{service}/images/cat.png")])),
+                ("alt", json!("a cat")),
+                (
+                    "reply_to_uri",
+                    json!("at://did:plc:alice/app.bsky.feed.post/1"),
+                ),
+                ("reply_to_cid", json!("c1")),
+            ]),
+        )
+        .await
+        .unwrap();
+    assert_eq!(created["cid"], "cid1");
+    {
+        let recorded = state.lock().unwrap();
+        let record = &recorded.records[0]["record"];
+        assert_eq!(record["reply"]["parent"]["cid"], "c1");
+        assert_eq!(record["embed"]["images"][0]["alt"], "a cat");
+    }
+
+    let feed = executor
+        .execute("feed.get", &args(&[("limit", json!(2))]))
+        .await
+        .unwrap();
+    assert_eq!(feed["cursor"], "next");
+    assert_eq!(feed["posts"][0]["author"], "alice.test");
+    assert_eq!(feed["posts"][0]["text"], "hello");
+
+    let profile = executor
+        .execute("profile.get", &HashMap::new())
+        .await
+        .unwrap();
+    assert_eq!(profile["did"], DID);
+    assert_eq!(profile["followers_count"], 7);
+}
+
+#[tokio::test]
+async fn test_executor_errors() {
+    let (service, _) = mock_server().await;
+    let executor =
+        BlueskyCommandExecutor::new(BlueskyClient::new(&service, IDENTIFIER, PASSWORD).unwrap());
+
+    let err = executor
+        .execute("posts.create", &HashMap::new())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        BotCommandErrorKind::MissingArgument { .. }
+    ));
+
+    let err = executor
+        .execute("posts.create", &args(&[("text", json!("é".repeat(301)))]))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        BotCommandErrorKind::InvalidArgument { .. }
+    ));
+
+    let err = executor
+        .execute("notifications.list", &HashMap::new())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        BotCommandErrorKind::RateLimitExceeded {
+            retry_after: 42,
+            ..
+        }
+    ));
+
+    let err = executor
+        .execute("posts.delete", &HashMap::new())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        BotCommandErrorKind::CommandNotFound(_)
+    ));
+}
@@ -0,0 +1,85 @@
+//! Tests for the database bot command executor, run against a SQLite file.
+
+#![cfg(feature = "sqlite")]
+
+use botticelli_database::{
+    ContentConnection, content_status_history, create_content_table, create_database_pool,
+    get_content_by_id,
+};
+use botticelli_social::{BotCommandExecutor, DatabaseCommandExecutor};
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+
+fn update_args(
+    where_clause: &str,
+    updates: serde_json::Value,
+) -> HashMap<String, serde_json::Value> {
+    HashMap::from([
+        ("table_name".to_string(), json!("guild_ideas")),
+        ("where_clause".to_string(), json!(where_clause)),
+        ("updates".to_string(), updates),
+        ("actor".to_string(), json!("poster")),
+    ])
+}
+
+#[tokio::test]
+async fn test_update_table_on_pooled_connection() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_database_pool(&dir.path().join("content.db").display().to_string()).unwrap();
+    {
+        let mut conn = pool.get().unwrap();
+        create_content_table(&mut *conn, "guild_ideas", "discord_guilds", None, None).unwrap();
+        conn.execute_sql(
+            "INSERT INTO guild_ideas (id, name, owner_id) VALUES (1, 'Lore Lounge', 42), (2, 'Den', 42)",
+            &[],
+        )
+        .unwrap();
+    }
+
+    let executor = DatabaseCommandExecutor::with_allowed_tables(
+        pool.clone(),
+        HashSet::from(["guild_ideas".to_string()]),
+    );
+    let result = executor
+        .execute(
+            "update_table",
+            &update_args(
+                "owner_id = 42",
+                json!({"review_status": "approved", "name": "Renamed"}),
+            ),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result["rows_affected"], 2);
+
+    let mut conn = pool.get().unwrap();
+    let row = get_content_by_id(&mut *conn, "guild_ideas", 1).unwrap();
+    assert_eq!(row["name"], "Renamed");
+    assert_eq!(row["review_status"], "approved");
+    let history = content_status_history(&mut *conn, "guild_ideas", 2).unwrap();
+    assert_eq!(history[0].actor(), "poster");
+
+    // A forbidden transition on one row leaves every row unchanged
+    conn.execute_sql(
+        "UPDATE guild_ideas SET review_status = 'rejected' WHERE id = 2",
+        &[],
+    )
+    .unwrap();
+    drop(conn);
+    let err = executor
+        .execute(
+            "update_table",
+            &update_args(
+                "owner_id = 42",
+                json!({"review_status": "posted", "name": "Posted"}),
+            ),
+        )
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Status change failed"), "{}", err);
+
+    let mut conn = pool.get().unwrap();
+    let row = get_content_by_id(&mut *conn, "guild_ideas", 1).unwrap();
+    assert_eq!(row["name"], "Renamed");
+    assert_eq!(row["review_status"], "approved");
+}
@@ -0,0 +1,36 @@
+//! Tests for broadcasting Discord events to subscribers.
+
+#![cfg(feature = "discord")]
+
+use botticelli_interface::{PlatformEventBuilder, PlatformEventKind};
+use botticelli_social::{DISCORD_PLATFORM, DiscordEventPublisher};
+
+fn joined() -> botticelli_interface::PlatformEvent {
+    PlatformEventBuilder::default()
+        .platform(DISCORD_PLATFORM)
+        .kind(PlatformEventKind::MemberJoined)
+        .guild_id("1")
+        .user_id("42")
+        .build()
+        .expect("valid event")
+}
+
+#[test]
+fn test_publish_without_subscribers() {
+    let publisher = DiscordEventPublisher::default();
+    assert_eq!(publisher.publish(joined()), 0);
+}
+
+#[tokio::test]
+async fn test_subscribers_receive_events() {
+    let publisher = DiscordEventPublisher::new(8);
+    let mut first = publisher.subscribe();
+    let mut second = publisher.subscribe();
+
+    assert_eq!(publisher.publish(joined()), 2);
+
+    let event = first.recv().await.expect("event delivered");
+    assert_eq!(*event.kind(), PlatformEventKind::MemberJoined);
+    assert_eq!(event.user_id().as_deref(), Some("42"));
+    assert_eq!(second.recv().await.expect("event delivered"), event);
+}
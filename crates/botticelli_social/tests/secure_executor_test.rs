@@ -3,17 +3,20 @@
 use async_trait::async_trait;
 use botticelli_cache::CommandCache;
 use botticelli_security::{
-    ApprovalWorkflow, ContentFilter, ContentFilterConfig, DiscordValidator, PermissionChecker,
-    PermissionConfig, RateLimit, RateLimiter, ResourcePermission,
+    ApprovalWorkflow, CommandValidator, ContentFilter, ContentFilterConfig, DiscordValidator,
+    PermissionChecker, PermissionConfig, RateLimit, RateLimiter, ResourcePermission,
+    SecurityConfig, SecurityResult,
 };
 use botticelli_social::{
     BotCommandError, BotCommandErrorKind, BotCommandExecutor, BotCommandRegistryImpl,
-    BotCommandResult, ExecutionResult, SecureBotCommandExecutor,
+    BotCommandResult, ExecutionResult, SecureBotCommandExecutor, SecureBotExecutor,
 };
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
+use std::time::Duration;
 
 // Mock executor for testing
+#[derive(Clone)]
 struct MockExecutor;
 
 #[async_trait]
@@ -324,3 +327,95 @@ async fn test_secure_execution_approval_required() {
         ExecutionResult::Success(_) => panic!("Should require approval"),
     }
 }
+
+/// Validator that accepts every command.
+struct AcceptAll;
+
+impl CommandValidator for AcceptAll {
+    fn validate(&self, _command: &str, _params: &HashMap<String, String>) -> SecurityResult<()> {
+        Ok(())
+    }
+}
+
+fn config_toml(max_length: usize) -> String {
+    format!(
+        "[security.mock.permissions]\nallowed_commands = [\"messages.send\"]\n\n[security.mock.content]\nmax_length = {}\n",
+        max_length
+    )
+}
+
+fn send_args(content: &str) -> HashMap<String, JsonValue> {
+    HashMap::from([("content".to_string(), serde_json::json!(content))])
+}
+
+#[tokio::test]
+async fn test_from_config_applies_platform_section() {
+    let config = SecurityConfig::from_toml_str(&config_toml(10)).unwrap();
+    let executor =
+        SecureBotExecutor::from_config(MockExecutor, AcceptAll, &config, "test".to_string())
+            .unwrap();
+
+    assert!(
+        executor
+            .execute("messages.send", &send_args("short"))
+            .await
+            .is_ok()
+    );
+    let err = executor
+        .execute("messages.send", &send_args("much too long for ten"))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        BotCommandErrorKind::ContentFiltered { .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_watch_config_applies_edits_to_running_executor() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("security.toml");
+    std::fs::write(&path, config_toml(100)).unwrap();
+
+    let config = SecurityConfig::from_file(&path).unwrap();
+    let executor =
+        SecureBotExecutor::from_config(MockExecutor, AcceptAll, &config, "test".to_string())
+            .unwrap();
+    // Clones share the running configuration
+    let clone = executor.clone().with_narrative_id("other");
+    let watcher = executor.watch_config(&path, Duration::from_millis(10));
+
+    let message = send_args("forty characters of perfectly fine text!");
+    assert!(clone.execute("messages.send", &message).await.is_ok());
+
+    // Let the watcher record the file's initial state before editing it
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    std::fs::write(&path, config_toml(20)).unwrap();
+
+    let mut applied = false;
+    for _ in 0..200 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        if clone.execute("messages.send", &message).await.is_err() {
+            applied = true;
+            break;
+        }
+    }
+    assert!(applied, "the new max_length should be applied");
+
+    // An invalid edit is rejected and the last good limits stay in force
+    std::fs::write(
+        &path,
+        "[security.mock.content]\nprohibited_patterns = [\"(\"]\n",
+    )
+    .unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(
+        executor
+            .execute("messages.send", &send_args("short"))
+            .await
+            .is_ok()
+    );
+    assert!(executor.execute("messages.send", &message).await.is_err());
+
+    watcher.abort();
+}
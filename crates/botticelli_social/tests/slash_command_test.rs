@@ -0,0 +1,164 @@
+//! Tests for narrative-backed slash commands.
+#![cfg(feature = "discord")]
+
+use async_trait::async_trait;
+use botticelli_security::{PermissionChecker, PermissionConfig, ResourcePermission};
+use botticelli_social::{
+    DiscordError, DiscordErrorKind, MAX_REPLY_LEN, SlashCommandDispatcher, SlashCommandInvocation,
+    SlashCommandRegistry, SlashCommandRoute, SlashCommandRunner, truncate_reply,
+};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+const COMMANDS: &str = r#"
+[narrative.summarize]
+description = "Summarize a channel"
+toc = ["summarize"]
+
+[narrative.summarize.slash_command]
+description = "Summarize a channel"
+
+[[narrative.summarize.slash_command.options]]
+name = "channel"
+description = "Channel to summarize"
+type = "channel"
+required = true
+
+[narrative.internal]
+toc = ["summarize"]
+
+[acts]
+summarize = "Summarize ${option:channel}"
+"#;
+
+/// Runner that replies with the variables it received.
+struct EchoRunner;
+
+#[async_trait]
+impl SlashCommandRunner for EchoRunner {
+    async fn run(
+        &self,
+        route: &SlashCommandRoute,
+        variables: HashMap<String, String>,
+    ) -> Result<String, DiscordError> {
+        Ok(format!(
+            "{} channel={}",
+            route.narrative_name(),
+            variables["option:channel"]
+        ))
+    }
+}
+
+fn registry() -> (tempfile::TempDir, SlashCommandRegistry) {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("commands.toml"), COMMANDS).unwrap();
+    std::fs::write(dir.path().join("notes.toml"), "not = [valid").unwrap();
+
+    let mut registry = SlashCommandRegistry::new();
+    registry.register_dir(dir.path()).unwrap();
+    (dir, registry)
+}
+
+fn invocation(channel_option: &str) -> SlashCommandInvocation {
+    SlashCommandInvocation::new(
+        "summarize",
+        7,
+        100,
+        Some(1),
+        HashMap::from([("channel".to_string(), channel_option.to_string())]),
+    )
+}
+
+fn allow_all() -> PermissionChecker {
+    PermissionChecker::new(PermissionConfig::default().with_allow_all_by_default(true))
+}
+
+#[test]
+fn test_registry_discovers_commands_from_narratives() {
+    let (_dir, registry) = registry();
+
+    assert_eq!(registry.len(), 1);
+    let route = registry.get("summarize").unwrap();
+    assert_eq!(route.narrative_name(), "summarize");
+    assert_eq!(registry.create_commands().len(), 1);
+}
+
+#[test]
+fn test_registry_rejects_duplicate_command_names() {
+    let (dir, mut registry) = registry();
+    let err = registry
+        .register_file(dir.path().join("commands.toml"))
+        .unwrap_err();
+
+    assert!(matches!(
+        err.kind(),
+        DiscordErrorKind::ConfigurationError(_)
+    ));
+}
+
+#[test]
+fn test_invocation_variables() {
+    let variables = invocation("555").variables();
+
+    assert_eq!(variables["option:channel"], "555");
+    assert_eq!(variables["interaction:user_id"], "7");
+    assert_eq!(variables["interaction:channel_id"], "100");
+    assert_eq!(variables["interaction:guild_id"], "1");
+}
+
+#[test]
+fn test_authorize_checks_command_permission() {
+    let (_dir, registry) = registry();
+    let checker = PermissionChecker::new(
+        PermissionConfig::default()
+            .with_allowed_commands(HashSet::from(["slash.other".to_string()])),
+    );
+    let dispatcher = SlashCommandDispatcher::new(registry, Arc::new(EchoRunner), checker);
+
+    assert!(dispatcher.authorize(&invocation("555")).is_err());
+}
+
+#[test]
+fn test_authorize_checks_channel_options() {
+    let (_dir, registry) = registry();
+    let channels = ResourcePermission::default()
+        .with_allowed_ids(HashSet::from(["100".to_string(), "555".to_string()]));
+    let checker = PermissionChecker::new(
+        PermissionConfig::default()
+            .with_allow_all_by_default(true)
+            .with_resources(HashMap::from([("channel".to_string(), channels)])),
+    );
+    let dispatcher = SlashCommandDispatcher::new(registry, Arc::new(EchoRunner), checker);
+
+    assert!(dispatcher.authorize(&invocation("555")).is_ok());
+    assert!(dispatcher.authorize(&invocation("999")).is_err());
+}
+
+#[tokio::test]
+async fn test_dispatch_runs_narrative_with_options() {
+    let (_dir, registry) = registry();
+    let dispatcher = SlashCommandDispatcher::new(registry, Arc::new(EchoRunner), allow_all());
+
+    let reply = dispatcher.dispatch(&invocation("555")).await.unwrap();
+    assert_eq!(reply, "summarize channel=555");
+}
+
+#[tokio::test]
+async fn test_dispatch_unknown_command() {
+    let (_dir, registry) = registry();
+    let dispatcher = SlashCommandDispatcher::new(registry, Arc::new(EchoRunner), allow_all());
+
+    let unknown = SlashCommandInvocation::new("nope", 1, 1, None, HashMap::new());
+    let err = dispatcher.dispatch(&unknown).await.unwrap_err();
+    assert!(matches!(err.kind(), DiscordErrorKind::InteractionFailed(_)));
+}
+
+#[test]
+fn test_truncate_reply() {
+    assert_eq!(truncate_reply("short"), "short");
+
+    let long = "x".repeat(MAX_REPLY_LEN + 10);
+    let truncated = truncate_reply(&long);
+    assert_eq!(truncated.chars().count(), MAX_REPLY_LEN);
+    assert!(truncated.ends_with('…'));
+}
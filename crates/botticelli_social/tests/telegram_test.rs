@@ -0,0 +1,416 @@
+//! Tests for the Telegram client and command executor against a mock Bot API.
+
+#![cfg(feature = "telegram")]
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::routing::post;
+use axum::{Json, Router};
+use botticelli_social::{
+    BotCommandErrorKind, BotCommandExecutor, NewTelegramMessage, TelegramClient,
+    TelegramCommandExecutor, TelegramErrorKind, TelegramMediaKind, TelegramUpdate,
+};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+const TOKEN: &str = "123:abc";
+
+/// Calls observed by the mock server: (method, JSON body or multipart marker).
+type Shared = Arc<Mutex<Vec<(String, Value)>>>;
+
+fn message(chat_id: i64, message_id: i64, extra: Value) -> Value {
+    let mut message = json!({
+        "message_id": message_id,
+        "date": 1_735_689_600,
+        "chat": { "id": chat_id, "type": "supergroup", "title": "Test group" },
+    });
+    if let (Value::Object(target), Value::Object(extra)) = (&mut message, extra) {
+        target.extend(extra);
+    }
+    message
+}
+
+fn ok(result: Value) -> Json<Value> {
+    Json(json!({ "ok": true, "result": result }))
+}
+
+async fn bot_api(
+    State(state): State<Shared>,
+    Path((token, method)): Path<(String, String)>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Json<Value> {
+    if token != format!("bot{TOKEN}") {
+        return Json(json!({ "ok": false, "error_code": 401, "description": "Unauthorized" }));
+    }
+
+    let is_multipart = headers
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("multipart/form-data"));
+    let body: Value = if is_multipart {
+        json!({ "multipart": String::from_utf8_lossy(&body) })
+    } else {
+        serde_json::from_slice(&body).unwrap_or(Value::Null)
+    };
+
+    let mut calls = state.lock().unwrap();
+    calls.push((method.clone(), body.clone()));
+    let next_id = calls.len() as i64;
+    let chat_id = body["chat_id"].as_i64().unwrap_or(-100);
+
+    match method.as_str() {
+        "getMe" => ok(
+            json!({ "id": 42, "is_bot": true, "first_name": "Botticelli", "username": "botticelli_bot" }),
+        ),
+        "sendMessage" | "editMessageText" => {
+            let id = body["message_id"].as_i64().unwrap_or(next_id);
+            ok(message(chat_id, id, json!({ "text": body["text"] })))
+        }
+        "deleteMessage" => ok(json!(true)),
+        "getChat" => ok(json!({
+            "id": -100,
+            "type": "channel",
+            "title": "News",
+            "username": "botticelli_news",
+            "description": "Daily posts",
+        })),
+        "getChatMemberCount" => ok(json!(1234)),
+        "sendPoll" => ok(message(
+            chat_id,
+            next_id,
+            json!({
+                "poll": {
+                    "id": "poll-1",
+                    "question": body["question"],
+                    "options": [],
+                    "total_voter_count": 0,
+                    "is_closed": false,
+                }
+            }),
+        )),
+        "sendPhoto" | "sendVideo" | "sendDocument" => ok(message(
+            chat_id,
+            next_id,
+            json!({ "caption": body["caption"], "photo": [] }),
+        )),
+        "sendAnimation" => Json(json!({
+            "ok": false,
+            "error_code": 429,
+            "description": "Too Many Requests: retry after 17",
+            "parameters": { "retry_after": 17 },
+        })),
+        _ => Json(json!({ "ok": false, "error_code": 404, "description": "Not Found" })),
+    }
+}
+
+/// Start a mock Bot API server, returning its URL and recorded calls.
+async fn mock_server() -> (String, Shared) {
+    let state = Shared::default();
+    let app = Router::new()
+        .route("/:token/:method", post(bot_api))
+        .with_state(state.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    (format!("http://{addr}"), state)
+}
+
+async fn executor() -> (TelegramCommandExecutor, Shared) {
+    let (url, state) = mock_server().await;
+    let client = TelegramClient::with_api_url(url, TOKEN).unwrap();
+    (TelegramCommandExecutor::new(client), state)
+}
+
+fn args(pairs: &[(&str, Value)]) -> HashMap<String, Value> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.clone()))
+        .collect()
+}
+
+#[test]
+fn test_media_kind_and_chat_ids() {
+    assert_eq!(
+        TelegramMediaKind::from_extension("a/cat.PNG"),
+        TelegramMediaKind::Photo
+    );
+    assert_eq!(
+        TelegramMediaKind::from_extension("clip.mp4?x=1"),
+        TelegramMediaKind::Video
+    );
+    assert_eq!(
+        TelegramMediaKind::from_extension("notes.pdf"),
+        TelegramMediaKind::Document
+    );
+    assert!("sticker".parse::<TelegramMediaKind>().is_err());
+
+    assert_eq!(
+        botticelli_social::chat_id_value("-1001").unwrap(),
+        json!(-1001)
+    );
+    assert_eq!(
+        botticelli_social::chat_id_value("@news").unwrap(),
+        json!("@news")
+    );
+    assert!(botticelli_social::chat_id_value("news").is_err());
+}
+
+#[test]
+fn test_update_to_database_model() {
+    let update: TelegramUpdate = serde_json::from_value(json!({
+        "update_id": 7,
+        "edited_message": message(-100, 5, json!({
+            "from": { "id": 9, "is_bot": false, "first_name": "Ada" },
+            "text": "hello again",
+            "edit_date": 1_735_689_700,
+            "reply_to_message": message(-100, 4, json!({})),
+        })),
+    }))
+    .unwrap();
+
+    let message = update.any_message().expect("edited message");
+    let row = NewTelegramMessage::from(message);
+    assert_eq!(*row.chat_id(), -100);
+    assert_eq!(*row.message_id(), 5);
+    assert_eq!(*row.from_user_id(), Some(9));
+    assert_eq!(row.text().as_deref(), Some("hello again"));
+    assert_eq!(*row.reply_to_message_id(), Some(4));
+    assert_eq!(row.sent_at().and_utc().timestamp(), 1_735_689_600);
+    assert!(row.edited_at().is_some());
+}
+
+#[tokio::test]
+async fn test_messages_send_edit_delete() {
+    let (executor, calls) = executor().await;
+    assert_eq!(executor.platform(), "telegram");
+    assert!(executor.supports_command("media.send"));
+
+    let sent = executor
+        .execute(
+            "messages.send",
+            &args(&[
+                ("channel_id", json!("-100")),
+                ("content", json!("Hello Telegram")),
+                ("parse_mode", json!("HTML")),
+                ("reply_to_message_id", json!("3")),
+            ]),
+        )
+        .await
+        .unwrap();
+    assert_eq!(sent["id"], "1");
+    assert_eq!(sent["channel_id"], "-100");
+    assert_eq!(sent["content"], "Hello Telegram");
+
+    executor
+        .execute(
+            "messages.edit",
+            &args(&[
+                ("chat_id", json!("-100")),
+                ("message_id", json!(1)),
+                ("content", json!("Edited")),
+            ]),
+        )
+        .await
+        .unwrap();
+    let deleted = executor
+        .execute(
+            "messages.delete",
+            &args(&[("channel_id", json!("-100")), ("message_id", json!("1"))]),
+        )
+        .await
+        .unwrap();
+    assert_eq!(deleted["deleted"], true);
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls[0].0, "sendMessage");
+    assert_eq!(calls[0].1["chat_id"], -100);
+    assert_eq!(calls[0].1["parse_mode"], "HTML");
+    assert_eq!(calls[0].1["reply_parameters"]["message_id"], 3);
+    assert_eq!(calls[1].0, "editMessageText");
+    assert_eq!(calls[1].1["text"], "Edited");
+    assert_eq!(calls[2].0, "deleteMessage");
+}
+
+#[tokio::test]
+async fn test_long_message_is_split() {
+    let (executor, calls) = executor().await;
+    let content = "line of text\n".repeat(500);
+
+    let sent = executor
+        .execute(
+            "messages.send",
+            &args(&[("channel_id", json!("@news")), ("content", json!(content))]),
+        )
+        .await
+        .unwrap();
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.len(), 2);
+    assert!(
+        calls
+            .iter()
+            .all(|(_, body)| body["text"].as_str().unwrap().chars().count() <= 4096)
+    );
+    assert_eq!(calls[0].1["chat_id"], "@news");
+    assert_eq!(sent["message_ids"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_chats_get_and_polls_create() {
+    let (executor, calls) = executor().await;
+
+    let chat = executor
+        .execute(
+            "chats.get",
+            &args(&[("channel_id", json!("@botticelli_news"))]),
+        )
+        .await
+        .unwrap();
+    assert_eq!(chat["type"], "channel");
+    assert_eq!(chat["member_count"], 1234);
+
+    let poll = executor
+        .execute(
+            "polls.create",
+            &args(&[
+                ("channel_id", json!("-100")),
+                ("question", json!("Best painter?")),
+                ("options", json!(["Botticelli", "Raphael"])),
+                ("is_anonymous", json!(false)),
+            ]),
+        )
+        .await
+        .unwrap();
+    assert_eq!(poll["poll_id"], "poll-1");
+
+    {
+        let calls = calls.lock().unwrap();
+        let (_, body) = calls.iter().find(|(m, _)| m == "sendPoll").unwrap();
+        assert_eq!(
+            body["options"],
+            json!([{ "text": "Botticelli" }, { "text": "Raphael" }])
+        );
+        assert_eq!(body["is_anonymous"], false);
+    }
+
+    let err = executor
+        .execute(
+            "polls.create",
+            &args(&[
+                ("channel_id", json!("-100")),
+                ("question", json!("?")),
+                ("options", json!(["only one"])),
+            ]),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        BotCommandErrorKind::InvalidArgument { .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_media_send_by_url_and_upload() {
+    let (executor, calls) = executor().await;
+
+    executor
+        .execute(
+            "media.send",
+            &args(&[
+                ("channel_id", json!("-100")),
+                ("url", json!("https://example.com/cat.jpg")),
+                ("caption", json!("A cat")),
+            ]),
+        )
+        .await
+        .unwrap();
+
+    let file = tempfile::Builder::new().suffix(".pdf").tempfile().unwrap();
+    std::fs::write(file.path(), b"%PDF-1.4").unwrap();
+    let sent = executor
+        .execute(
+            "media.send",
+            &args(&[
+                ("channel_id", json!("-100")),
+                ("url", json!(file.path().to_string_lossy())),
+            ]),
+        )
+        .await
+        .unwrap();
+    assert_eq!(sent["media_type"], "document");
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls[0].0, "sendPhoto");
+    assert_eq!(calls[0].1["photo"], "https://example.com/cat.jpg");
+    assert_eq!(calls[0].1["caption"], "A cat");
+    assert_eq!(calls[1].0, "sendDocument");
+    let multipart = calls[1].1["multipart"].as_str().unwrap();
+    assert!(multipart.contains("name=\"document\""));
+    assert!(multipart.contains("%PDF-1.4"));
+}
+
+#[tokio::test]
+async fn test_errors_are_mapped() {
+    let (executor, _) = executor().await;
+
+    let err = executor
+        .execute(
+            "media.send",
+            &args(&[
+                ("channel_id", json!("-100")),
+                ("url", json!("https://x.test/a.gif")),
+            ]),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        BotCommandErrorKind::RateLimitExceeded {
+            retry_after: 17,
+            ..
+        }
+    ));
+
+    let err = executor
+        .execute("messages.send", &args(&[("content", json!("hi"))]))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        BotCommandErrorKind::MissingArgument { .. }
+    ));
+
+    let err = executor
+        .execute("threads.create", &HashMap::new())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        BotCommandErrorKind::CommandNotFound(_)
+    ));
+
+    let (url, _) = mock_server().await;
+    let bad = TelegramClient::with_api_url(url, "wrong").unwrap();
+    let err = bad.get_me().await.unwrap_err();
+    assert!(matches!(err.kind(), TelegramErrorKind::Unauthorized(_)));
+}
+
+#[tokio::test]
+async fn test_request_errors_do_not_leak_token() {
+    // Reserve a port, then close it so the request fails to connect
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+
+    let client = TelegramClient::with_api_url(format!("http://{addr}"), TOKEN).unwrap();
+    let err = client.get_me().await.unwrap_err();
+    assert!(matches!(err.kind(), TelegramErrorKind::Http(_)));
+    assert!(!err.to_string().contains(TOKEN));
+    assert!(!format!("{:?}", err).contains(TOKEN));
+}
@@ -0,0 +1,329 @@
+//! Tests for the webhook client, templates, signing and HTTP executor.
+
+#![cfg(feature = "webhook")]
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::any;
+use axum::{Json, Router};
+use botticelli_social::{
+    BotCommandErrorKind, BotCommandExecutor, HttpCommandExecutor, WebhookClient, WebhookConfig,
+    WebhookErrorKind, WebhookSigning, hmac_sha256, message_variables, render_template, sign_hex,
+};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Requests observed by the mock server: (headers, raw body).
+#[derive(Default)]
+struct Mock {
+    requests: Mutex<Vec<(HeaderMap, Vec<u8>)>>,
+    /// Status codes to answer with before succeeding.
+    failures: Mutex<Vec<u16>>,
+}
+
+async fn hook(State(mock): State<Arc<Mock>>, headers: HeaderMap, body: Bytes) -> Response {
+    mock.requests.lock().unwrap().push((headers, body.to_vec()));
+    let failure = {
+        let mut failures = mock.failures.lock().unwrap();
+        (!failures.is_empty()).then(|| failures.remove(0))
+    };
+    match failure {
+        Some(status) => (
+            StatusCode::from_u16(status).unwrap(),
+            [("retry-after", "0")],
+            "unavailable",
+        )
+            .into_response(),
+        None => Json(json!({ "ok": true, "ts": "1735689600.000100", "channel": { "id": "C1" } }))
+            .into_response(),
+    }
+}
+
+/// Start a mock endpoint that fails with `failures` before succeeding.
+async fn mock_server(failures: Vec<u16>) -> (String, Arc<Mock>) {
+    let mock = Arc::new(Mock {
+        failures: Mutex::new(failures),
+        ..Mock::default()
+    });
+    let app = Router::new()
+        .route("/hook", any(hook))
+        .with_state(mock.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    (format!("http://{addr}/hook"), mock)
+}
+
+fn config(url: &str) -> WebhookConfig {
+    WebhookConfig::builder()
+        .url(url)
+        .retry_backoff_ms(1u64)
+        .build()
+        .unwrap()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[test]
+fn test_hmac_sha256_rfc4231_vectors() {
+    // RFC 4231 test case 2
+    assert_eq!(
+        to_hex(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+    // RFC 4231 test case 6: key longer than the block size
+    assert_eq!(
+        sign_hex(
+            &[0xaa; 131],
+            b"Test Using Larger Than Block-Size Key - Hash Key First"
+        ),
+        "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+    );
+}
+
+#[test]
+fn test_render_template() {
+    let variables = message_variables(
+        "Hello \"world\"",
+        &["https://example.com/a.png".to_string()],
+    );
+    let template = json!({
+        "text": "{{text}}",
+        "summary": "Posted: {{ text }} with {{media_url}}",
+        "attachments": "{{media_urls}}",
+        "nested": [{ "image": "{{media_url}}" }],
+        "count": 3,
+    });
+
+    let body = render_template(&template, &variables).unwrap();
+    assert_eq!(body["text"], "Hello \"world\"");
+    assert_eq!(
+        body["summary"],
+        "Posted: Hello \"world\" with https://example.com/a.png"
+    );
+    assert_eq!(body["attachments"], json!(["https://example.com/a.png"]));
+    assert_eq!(body["nested"][0]["image"], "https://example.com/a.png");
+    assert_eq!(body["count"], 3);
+}
+
+#[test]
+fn test_render_template_errors() {
+    let variables = message_variables("hi", &[]);
+    let err = render_template(&json!({ "x": "{{missing}}" }), &variables).unwrap_err();
+    assert!(matches!(err.kind(), WebhookErrorKind::Template(_)));
+
+    let err = render_template(&json!("open {{text"), &variables).unwrap_err();
+    assert!(matches!(err.kind(), WebhookErrorKind::Template(_)));
+}
+
+#[test]
+fn test_invalid_configuration() {
+    let err = WebhookClient::new(config("ftp://example.com/hook")).unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        WebhookErrorKind::ConfigurationError(_)
+    ));
+
+    let bad_header = WebhookConfig::builder()
+        .url("https://example.com/hook")
+        .headers(HashMap::from([("bad header".to_string(), "x".to_string())]))
+        .build()
+        .unwrap();
+    let err = WebhookClient::new(bad_header).unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        WebhookErrorKind::ConfigurationError(_)
+    ));
+}
+
+#[test]
+fn test_config_deserialize_defaults() {
+    let config: WebhookConfig = serde_json::from_value(json!({
+        "url": "https://hooks.example.com/T000",
+        "headers": { "Authorization": "Bearer token" },
+        "body": { "text": "{{text}}", "icon": ":art:" },
+        "signing": { "secret": "key" },
+        "capture": { "ts": "/ts" },
+    }))
+    .unwrap();
+
+    assert_eq!(config.method(), "POST");
+    assert_eq!(*config.max_retries(), 3);
+    assert_eq!(
+        config.signing().as_ref().unwrap().header(),
+        "X-Signature-256"
+    );
+    assert_eq!(config.capture()["ts"], "/ts");
+}
+
+#[tokio::test]
+async fn test_send_signs_body_and_sets_headers() {
+    let (url, mock) = mock_server(vec![]).await;
+    let config = WebhookConfig::builder()
+        .url(url)
+        .headers(HashMap::from([(
+            "Authorization".to_string(),
+            "Bearer abc".to_string(),
+        )]))
+        .signing(WebhookSigning::new("signing-key"))
+        .build()
+        .unwrap();
+    let client = WebhookClient::new(config).unwrap();
+
+    let response = client.send(&message_variables("Hello", &[])).await.unwrap();
+    assert_eq!(*response.status(), 200);
+    assert_eq!(*response.attempts(), 1);
+
+    let requests = mock.requests.lock().unwrap();
+    let (headers, body) = &requests[0];
+    let sent: Value = serde_json::from_slice(body).unwrap();
+    assert_eq!(sent, json!({ "text": "Hello" }));
+    assert_eq!(headers["authorization"], "Bearer abc");
+    assert_eq!(headers["content-type"], "application/json");
+    assert_eq!(
+        headers["x-signature-256"].to_str().unwrap(),
+        format!("sha256={}", sign_hex(b"signing-key", body))
+    );
+}
+
+#[tokio::test]
+async fn test_send_retries_transient_failures() {
+    let (url, mock) = mock_server(vec![503, 429]).await;
+    let client = WebhookClient::new(config(&url)).unwrap();
+
+    let response = client.send(&message_variables("Hello", &[])).await.unwrap();
+    assert_eq!(*response.attempts(), 3);
+    assert_eq!(mock.requests.lock().unwrap().len(), 3);
+
+    let captured = response.capture(&HashMap::from([
+        ("ts".to_string(), "/ts".to_string()),
+        ("channel".to_string(), "/channel/id".to_string()),
+        ("missing".to_string(), "/nope".to_string()),
+    ]));
+    assert_eq!(captured["ts"], "1735689600.000100");
+    assert_eq!(captured["channel"], "C1");
+    assert!(!captured.contains_key("missing"));
+}
+
+#[tokio::test]
+async fn test_send_gives_up() {
+    // Client errors are not retried
+    let (url, mock) = mock_server(vec![400]).await;
+    let client = WebhookClient::new(config(&url)).unwrap();
+    let err = client.send_json(None).await.unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        WebhookErrorKind::Status { status: 400, .. }
+    ));
+    assert_eq!(mock.requests.lock().unwrap().len(), 1);
+
+    // Server errors stop after max_retries
+    let (url, mock) = mock_server(vec![500; 5]).await;
+    let config = WebhookConfig::builder()
+        .url(url)
+        .max_retries(2u32)
+        .retry_backoff_ms(1u64)
+        .build()
+        .unwrap();
+    let err = WebhookClient::new(config)
+        .unwrap()
+        .send_json(None)
+        .await
+        .unwrap_err();
+    assert!(err.kind().is_retryable());
+    assert_eq!(mock.requests.lock().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_http_executor_commands() {
+    let (url, mock) = mock_server(vec![]).await;
+    let hook = WebhookConfig::builder()
+        .url(url.clone())
+        .body(json!({ "content": "{{text}}", "username": "{{author}}" }))
+        .capture(HashMap::from([("ts".to_string(), "/ts".to_string())]))
+        .build()
+        .unwrap();
+    let executor = HttpCommandExecutor::new()
+        .with_webhook("announce", hook)
+        .unwrap();
+    assert_eq!(executor.platform(), "http");
+    assert_eq!(executor.webhook_names(), vec!["announce".to_string()]);
+
+    let args = |pairs: &[(&str, Value)]| -> HashMap<String, Value> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect()
+    };
+
+    let result = executor
+        .execute(
+            "webhooks.send",
+            &args(&[
+                ("webhook", json!("announce")),
+                ("text", json!("New post")),
+                ("author", json!("Botticelli")),
+            ]),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result["status"], 200);
+    assert_eq!(result["captured"]["ts"], "1735689600.000100");
+
+    let result = executor
+        .execute(
+            "request",
+            &args(&[
+                ("url", json!(url)),
+                ("method", json!("PUT")),
+                ("body", json!({ "raw": true })),
+                ("sign_secret", json!("k")),
+            ]),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result["body"]["ok"], true);
+
+    {
+        let requests = mock.requests.lock().unwrap();
+        let first: Value = serde_json::from_slice(&requests[0].1).unwrap();
+        assert_eq!(
+            first,
+            json!({ "content": "New post", "username": "Botticelli" })
+        );
+        let (headers, body) = &requests[1];
+        assert_eq!(body.as_slice(), br#"{"raw":true}"#);
+        assert_eq!(
+            headers["x-signature-256"].to_str().unwrap(),
+            format!("sha256={}", sign_hex(b"k", body))
+        );
+    }
+
+    let err = executor
+        .execute("webhooks.send", &args(&[("webhook", json!("unknown"))]))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        BotCommandErrorKind::ResourceNotFound { .. }
+    ));
+
+    let err = executor
+        .execute(
+            "webhooks.send",
+            &args(&[("webhook", json!("announce")), ("text", json!("no author"))]),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        BotCommandErrorKind::InvalidArgument { .. }
+    ));
+}
@@ -1,5 +1,9 @@
 //! Application state and core TUI types.
 
+use crate::{ActRow, BodyEditor, BudgetRow, ExecutionRow, ListPage, ListQuery, TaskRow, Triage};
+use serde_json::{Map, Value as JsonValue};
+use std::collections::BTreeSet;
+
 /// Application mode determines which view is displayed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum AppMode {
@@ -9,10 +13,52 @@ pub enum AppMode {
     Detail,
     /// Edit view - edit tags, rating, status
     Edit,
+    /// Body editor - edit the content itself
+    EditBody,
+    /// New item - write a new content item by hand
+    New,
+    /// Search - type to narrow the list
+    Search,
+    /// Filter prompt - edit filter chips
+    Filter,
+    /// Tag prompt - add tags to the marked items
+    Tag,
+    /// Triage - decide on one item at a time
+    Triage,
     /// Compare view - side-by-side comparison
     Compare,
     /// Export view - export options
     Export,
+    /// Executions - recorded narrative runs
+    Executions,
+    /// Execution detail - act-by-act inputs and responses
+    ExecutionDetail,
+    /// Tasks - actor server task health
+    Tasks,
+    /// Rate limits - recent usage against model budgets
+    RateLimits,
+}
+
+impl AppMode {
+    /// Whether this mode is an operator console view rather than content.
+    pub fn is_console(&self) -> bool {
+        matches!(
+            self,
+            AppMode::Executions | AppMode::ExecutionDetail | AppMode::Tasks | AppMode::RateLimits
+        )
+    }
+
+    /// The top-level view after this one when cycling with Tab.
+    ///
+    /// Content modes count as the content list.
+    pub fn next_view(&self) -> Self {
+        match self {
+            AppMode::Executions | AppMode::ExecutionDetail => AppMode::Tasks,
+            AppMode::Tasks => AppMode::RateLimits,
+            AppMode::RateLimits => AppMode::List,
+            _ => AppMode::Executions,
+        }
+    }
 }
 
 /// Content row representation for TUI display.
@@ -20,7 +66,7 @@ pub enum AppMode {
 pub struct ContentRow {
     /// Row ID
     pub id: i64,
-    /// Review status (pending, approved, rejected)
+    /// Lifecycle status (generated, pending_review, approved, ...)
     pub review_status: String,
     /// User rating (1-5)
     pub rating: Option<i32>,
@@ -30,6 +76,8 @@ pub struct ContentRow {
     pub preview: String,
     /// Full content (for detail view)
     pub content: serde_json::Value,
+    /// Editable columns (everything but `id` and metadata)
+    pub body: Map<String, JsonValue>,
     /// Source narrative
     pub source_narrative: Option<String>,
     /// Source act
@@ -66,14 +114,48 @@ pub struct App {
     pub mode: AppMode,
     /// Table name being viewed
     pub table_name: String,
-    /// List of content items
+    /// List of content items on the current page
     pub content_items: Vec<ContentRow>,
+    /// Search, filters, sort and page of the list
+    pub query: ListQuery,
+    /// Items matching the query across all pages
+    pub total_items: i64,
+    /// Filter prompt being edited (when in Filter mode)
+    pub filter_input: String,
     /// Currently selected index in list
     pub selected_index: usize,
     /// Items selected for comparison
     pub compare_selection: Vec<usize>,
+    /// Position in `compare_selection` of the focused variant
+    pub compare_focus: usize,
+    /// IDs of items marked for bulk actions (kept across pages)
+    pub marked: BTreeSet<i64>,
+    /// Tags being typed (when in Tag mode)
+    pub tag_input: String,
+    /// Triage session (when in Triage mode)
+    pub triage: Option<Triage>,
+    /// Whether the next key confirms deleting the marked items
+    pub confirm_delete: bool,
     /// Edit buffer (when in Edit mode)
     pub edit_buffer: Option<EditBuffer>,
+    /// Body editor (when in EditBody or New mode)
+    pub body_editor: Option<BodyEditor>,
+    /// Whether the body should be opened in `$EDITOR` before the next draw
+    pub external_edit: bool,
+    /// Recorded narrative executions (when in Executions mode)
+    pub executions: Vec<ExecutionRow>,
+    /// Acts of the selected execution (when in ExecutionDetail mode)
+    pub acts: Vec<ActRow>,
+    /// Actor server tasks (when in Tasks mode)
+    pub tasks: Vec<TaskRow>,
+    /// Model budgets (when in RateLimits mode)
+    pub budgets: Vec<BudgetRow>,
+    /// Selected row in the current console view
+    pub console_index: usize,
+    /// Lines scrolled in the execution detail
+    pub detail_scroll: u16,
+    /// Ticks since the console view was last refreshed
+    pub ticks_since_refresh: u32,
     /// Status message to display
     pub status_message: String,
     /// Whether to quit the application
@@ -87,9 +169,26 @@ impl App {
             mode: AppMode::List,
             table_name,
             content_items: Vec::new(),
+            query: ListQuery::default(),
+            total_items: 0,
+            filter_input: String::new(),
             selected_index: 0,
             compare_selection: Vec::new(),
+            compare_focus: 0,
+            marked: BTreeSet::new(),
+            tag_input: String::new(),
+            triage: None,
+            confirm_delete: false,
             edit_buffer: None,
+            body_editor: None,
+            external_edit: false,
+            executions: Vec::new(),
+            acts: Vec::new(),
+            tasks: Vec::new(),
+            budgets: Vec::new(),
+            console_index: 0,
+            detail_scroll: 0,
+            ticks_since_refresh: 0,
             status_message: String::from("Press ? for help"),
             should_quit: false,
         }
@@ -103,20 +202,115 @@ impl App {
         }
     }
 
+    /// Show a page of content from the backend.
+    pub fn set_page(&mut self, page: ListPage) {
+        self.total_items = page.total;
+        self.set_content(page.items);
+    }
+
+    /// Start typing a search.
+    pub fn start_search(&mut self) {
+        self.mode = AppMode::Search;
+    }
+
+    /// Open the filter prompt with the current filters.
+    pub fn start_filter(&mut self) {
+        self.filter_input = self.query.filter_text();
+        self.mode = AppMode::Filter;
+    }
+
     /// Move selection up.
+    ///
+    /// In the execution detail this scrolls the acts instead.
     pub fn select_previous(&mut self) {
-        if !self.content_items.is_empty() && self.selected_index > 0 {
-            self.selected_index -= 1;
+        match self.mode {
+            AppMode::ExecutionDetail => self.detail_scroll = self.detail_scroll.saturating_sub(1),
+            mode if mode.is_console() => self.console_index = self.console_index.saturating_sub(1),
+            _ => {
+                if !self.content_items.is_empty() && self.selected_index > 0 {
+                    self.selected_index -= 1;
+                }
+            }
         }
     }
 
     /// Move selection down.
+    ///
+    /// In the execution detail this scrolls the acts instead.
     pub fn select_next(&mut self) {
-        if self.selected_index < self.content_items.len().saturating_sub(1) {
-            self.selected_index += 1;
+        match self.mode {
+            AppMode::ExecutionDetail => self.detail_scroll = self.detail_scroll.saturating_add(1),
+            mode if mode.is_console() => {
+                if self.console_index < self.console_len().saturating_sub(1) {
+                    self.console_index += 1;
+                }
+            }
+            _ => {
+                if self.selected_index < self.content_items.len().saturating_sub(1) {
+                    self.selected_index += 1;
+                }
+            }
+        }
+    }
+
+    /// Switch to the next top-level view, starting at its first row.
+    pub fn next_view(&mut self) {
+        let next = self.mode.next_view();
+        self.return_to_list();
+        self.mode = next;
+        self.console_index = 0;
+        self.ticks_since_refresh = 0;
+    }
+
+    /// Show the executions, keeping the selection within the list.
+    pub fn set_executions(&mut self, executions: Vec<ExecutionRow>) {
+        self.executions = executions;
+        self.clamp_console_index();
+    }
+
+    /// Show the acts of the selected execution.
+    pub fn show_acts(&mut self, acts: Vec<ActRow>) {
+        self.acts = acts;
+        self.detail_scroll = 0;
+        self.mode = AppMode::ExecutionDetail;
+    }
+
+    /// Show the actor tasks, keeping the selection within the list.
+    pub fn set_tasks(&mut self, tasks: Vec<TaskRow>) {
+        self.tasks = tasks;
+        self.clamp_console_index();
+    }
+
+    /// Show the model budgets, keeping the selection within the list.
+    pub fn set_budgets(&mut self, budgets: Vec<BudgetRow>) {
+        self.budgets = budgets;
+        self.clamp_console_index();
+    }
+
+    /// Execution under the cursor in the Executions view.
+    pub fn selected_execution(&self) -> Option<&ExecutionRow> {
+        self.executions.get(self.console_index)
+    }
+
+    /// Task under the cursor in the Tasks view.
+    pub fn selected_task(&self) -> Option<&TaskRow> {
+        self.tasks.get(self.console_index)
+    }
+
+    /// Rows in the current console view.
+    fn console_len(&self) -> usize {
+        match self.mode {
+            AppMode::Executions => self.executions.len(),
+            AppMode::Tasks => self.tasks.len(),
+            AppMode::RateLimits => self.budgets.len(),
+            _ => 0,
         }
     }
 
+    fn clamp_console_index(&mut self) {
+        self.console_index = self.console_index.min(self.console_len().saturating_sub(1));
+    }
+
     /// Enter detail view for selected item.
     pub fn enter_detail(&mut self) {
         if !self.content_items.is_empty() {
@@ -128,7 +322,10 @@ impl App {
     pub fn return_to_list(&mut self) {
         self.mode = AppMode::List;
         self.edit_buffer = None;
+        self.body_editor = None;
         self.compare_selection.clear();
+        self.compare_focus = 0;
+        self.triage = None;
     }
 
     /// Enter edit mode for selected item.
@@ -160,6 +357,48 @@ impl App {
         }
     }
 
+    /// Enter the body editor for selected item.
+    pub fn enter_body_edit(&mut self) {
+        if let Some(item) = self.content_items.get(self.selected_index) {
+            self.body_editor = Some(BodyEditor::new(&item.body));
+            self.mode = AppMode::EditBody;
+        }
+    }
+
+    /// Start writing a new item from a template of its columns.
+    pub fn enter_new(&mut self, template: &Map<String, JsonValue>) {
+        self.body_editor = Some(BodyEditor::new(template));
+        self.mode = AppMode::New;
+    }
+
+    /// Get the edited body for saving.
+    ///
+    /// When editing an existing item only the fields that changed are
+    /// returned; a new item gets every field that isn't null.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message for the status bar if the body is invalid.
+    pub fn get_body_data(&self) -> Option<Result<Map<String, JsonValue>, String>> {
+        let editor = self.body_editor.as_ref()?;
+        let values = match editor.values() {
+            Ok(values) => values,
+            Err(message) => return Some(Err(message)),
+        };
+
+        let values = match self.mode {
+            AppMode::New => values.into_iter().filter(|(_, v)| !v.is_null()).collect(),
+            _ => {
+                let body = &self.content_items.get(self.selected_index)?.body;
+                values
+                    .into_iter()
+                    .filter(|(name, value)| body.get(name) != Some(value))
+                    .collect()
+            }
+        };
+        Some(Ok(values))
+    }
+
     /// Toggle item in comparison selection.
     pub fn toggle_compare(&mut self) {
         if let Some(pos) = self
@@ -177,6 +416,104 @@ impl App {
         }
     }
 
+    /// Compare the marked items on this page, if there are at least two.
+    ///
+    /// Returns false, leaving the mode unchanged, otherwise.
+    pub fn compare_marked(&mut self) -> bool {
+        let marked: Vec<usize> = self
+            .content_items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| self.marked.contains(&item.id))
+            .map(|(i, _)| i)
+            .collect();
+        if marked.len() < 2 {
+            return false;
+        }
+        self.compare_selection = marked;
+        self.compare_focus = 0;
+        self.mode = AppMode::Compare;
+        true
+    }
+
+    /// Focus the previous variant in the compare view.
+    pub fn compare_focus_previous(&mut self) {
+        self.compare_focus = self.compare_focus.saturating_sub(1);
+    }
+
+    /// Focus the next variant in the compare view.
+    pub fn compare_focus_next(&mut self) {
+        if self.compare_focus + 1 < self.compare_selection.len() {
+            self.compare_focus += 1;
+        }
+    }
+
+    /// The focused variant's ID and the IDs of the others being compared.
+    pub fn compare_winner(&self) -> Option<(i64, Vec<i64>)> {
+        let ids: Vec<i64> = self
+            .compare_selection
+            .iter()
+            .filter_map(|&i| self.content_items.get(i).map(|item| item.id))
+            .collect();
+        let winner = *ids.get(self.compare_focus)?;
+        let losers = ids.into_iter().filter(|&id| id != winner).collect();
+        Some((winner, losers))
+    }
+
+    /// Mark or unmark the selected item for bulk actions.
+    pub fn toggle_mark(&mut self) {
+        if let Some(id) = self.get_selected_id()
+            && !self.marked.remove(&id)
+        {
+            self.marked.insert(id);
+        }
+    }
+
+    /// Mark every item on this page, or unmark them if all are marked.
+    pub fn toggle_mark_page(&mut self) {
+        let ids: Vec<i64> = self.content_items.iter().map(|item| item.id).collect();
+        if ids.iter().all(|id| self.marked.contains(id)) {
+            for id in &ids {
+                self.marked.remove(id);
+            }
+        } else {
+            self.marked.extend(ids);
+        }
+    }
+
+    /// Items a bulk action applies to: the marked items, or else the
+    /// selected one.
+    pub fn target_ids(&self) -> Vec<i64> {
+        if self.marked.is_empty() {
+            self.get_selected_id().into_iter().collect()
+        } else {
+            self.marked.iter().copied().collect()
+        }
+    }
+
+    /// Open the prompt for tagging the target items.
+    pub fn start_tagging(&mut self) {
+        self.tag_input.clear();
+        self.mode = AppMode::Tag;
+    }
+
+    /// Tags typed in the tag prompt, split on commas.
+    pub fn get_tag_input(&self) -> Vec<String> {
+        self.tag_input
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+
+    /// Start triaging the list from its first item.
+    pub fn start_triage(&mut self) {
+        self.return_to_list();
+        self.triage = Some(Triage::default());
+        self.selected_index = 0;
+        self.mode = AppMode::Triage;
+    }
+
     /// Get selected item ID for deletion.
     pub fn get_selected_id(&self) -> Option<i64> {
         self.content_items
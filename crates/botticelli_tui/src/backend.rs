@@ -4,7 +4,8 @@
 //! different data sources (database, mock data, etc.) without coupling to
 //! specific implementations.
 
-use crate::{ContentRow, TuiResult};
+use crate::{ActRow, BudgetRow, BulkAction, ExecutionRow, ListPage, ListQuery, TaskRow, TuiResult};
+use serde_json::{Map, Value as JsonValue};
 
 /// Backend trait for TUI data operations.
 ///
@@ -15,20 +16,22 @@ use crate::{ContentRow, TuiResult};
 /// Note: Only requires `Send` (not `Sync`) since the TUI is single-threaded.
 /// Database connections are not thread-safe and don't need to be.
 pub trait TuiBackend: Send {
-    /// List content items from the data source.
+    /// List a page of content items from the data source.
     ///
     /// # Arguments
     ///
     /// * `table_name` - Name of the table/collection to query
-    /// * `limit` - Maximum number of items to return
+    /// * `query` - Search text, filters, sort order and page to load
     ///
     /// # Returns
     ///
-    /// Vector of content rows
-    fn list_content(&mut self, table_name: &str, limit: i64) -> TuiResult<Vec<ContentRow>>;
+    /// The page's content rows and the number of matches across all pages
+    fn list_content(&mut self, table_name: &str, query: &ListQuery) -> TuiResult<ListPage>;
 
     /// Update metadata for a content item.
     ///
+    /// Tags, rating and status are saved together; if any fails, none is.
+    ///
     /// # Arguments
     ///
     /// * `table_name` - Name of the table/collection
@@ -45,6 +48,39 @@ pub trait TuiBackend: Send {
         status: &str,
     ) -> TuiResult<()>;
 
+    /// Replace fields of a content item's body.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - Name of the table/collection
+    /// * `id` - Item ID to update
+    /// * `values` - New values by column name
+    fn update_body(
+        &mut self,
+        table_name: &str,
+        id: i64,
+        values: &Map<String, JsonValue>,
+    ) -> TuiResult<()>;
+
+    /// Columns a new item can be written with, all set to null.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - Name of the table/collection
+    fn new_item_template(&mut self, table_name: &str) -> TuiResult<Map<String, JsonValue>>;
+
+    /// Insert a hand-written content item.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - Name of the table/collection
+    /// * `values` - Column values for the new item
+    ///
+    /// # Returns
+    ///
+    /// ID of the new item
+    fn insert_item(&mut self, table_name: &str, values: &Map<String, JsonValue>) -> TuiResult<i64>;
+
     /// Delete a content item.
     ///
     /// # Arguments
@@ -53,6 +89,35 @@ pub trait TuiBackend: Send {
     /// * `id` - Item ID to delete
     fn delete_item(&mut self, table_name: &str, id: i64) -> TuiResult<()>;
 
+    /// Apply one change to several content items.
+    ///
+    /// Either every item is changed or, if any change fails, none are.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - Name of the table/collection
+    /// * `ids` - Items to change
+    /// * `action` - Change to apply
+    fn apply_bulk(&mut self, table_name: &str, ids: &[i64], action: &BulkAction) -> TuiResult<()>;
+
+    /// Put an item back in the status it had before, to undo a decision.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - Name of the table/collection
+    /// * `id` - Item to restore
+    /// * `status` - Status to go back to
+    ///
+    /// # Returns
+    ///
+    /// The status the item ended up in
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `status` isn't a known status or the lifecycle
+    /// can't move the item back to it, such as to `generated`.
+    fn restore_status(&mut self, table_name: &str, id: i64, status: &str) -> TuiResult<String>;
+
     /// Export content items to JSON.
     ///
     /// # Arguments
@@ -64,4 +129,32 @@ pub trait TuiBackend: Send {
     ///
     /// JSON string containing exported items
     fn export_items(&mut self, table_name: &str, ids: &[i64]) -> TuiResult<String>;
+
+    /// List recent narrative executions, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of executions to return
+    fn list_executions(&mut self, limit: i64) -> TuiResult<Vec<ExecutionRow>>;
+
+    /// Load the acts of an execution, in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `execution_id` - Execution to load
+    fn execution_acts(&mut self, execution_id: i64) -> TuiResult<Vec<ActRow>>;
+
+    /// List actor server tasks, soonest due first.
+    fn list_tasks(&mut self) -> TuiResult<Vec<TaskRow>>;
+
+    /// Pause or resume an actor server task.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - Task to change
+    /// * `paused` - Whether the task should be paused
+    fn set_task_paused(&mut self, task_id: &str, paused: bool) -> TuiResult<()>;
+
+    /// Recent usage of each model against its configured budget.
+    fn rate_limit_usage(&mut self) -> TuiResult<Vec<BudgetRow>>;
 }
@@ -0,0 +1,107 @@
+//! Operator console rows: narrative executions, actor tasks and rate-limit
+//! budgets.
+
+/// A recorded narrative execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionRow {
+    /// Execution ID
+    pub id: i64,
+    /// Narrative that ran
+    pub narrative_name: String,
+    /// Execution status (running, completed, failed)
+    pub status: String,
+    /// When the execution started
+    pub started_at: String,
+    /// When the execution finished, if it has
+    pub completed_at: Option<String>,
+    /// Why the execution failed
+    pub error_message: Option<String>,
+    /// Acts recorded for the execution
+    pub act_count: i64,
+}
+
+/// One act of a recorded execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActRow {
+    /// Position of the act in the narrative, from 0
+    pub sequence_number: i64,
+    /// Act name
+    pub act_name: String,
+    /// Model the act ran on, if it overrode the default
+    pub model: Option<String>,
+    /// Inputs sent to the model; media is shown as a summary
+    pub inputs: Vec<String>,
+    /// Model response
+    pub response: String,
+}
+
+/// Scheduling state of an actor server task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskRow {
+    /// Task ID
+    pub task_id: String,
+    /// Actor that runs the task
+    pub actor_name: String,
+    /// When the task last ran
+    pub last_run: Option<String>,
+    /// When the task is next due
+    pub next_run: String,
+    /// Failed runs since the last success
+    pub consecutive_failures: i64,
+    /// Whether the task is paused
+    pub is_paused: bool,
+}
+
+/// Recent usage of one model against its configured budget.
+///
+/// Limits are `None` when the model has no configured tier or the tier
+/// sets no limit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetRow {
+    /// Model name
+    pub model: String,
+    /// Requests in the last minute
+    pub requests_minute: i64,
+    /// Requests per minute allowed
+    pub rpm: Option<u64>,
+    /// Requests in the last 24 hours
+    pub requests_day: i64,
+    /// Requests per day allowed
+    pub rpd: Option<u64>,
+    /// Estimated tokens in the last minute
+    pub tokens_minute: i64,
+    /// Tokens per minute allowed
+    pub tpm: Option<u64>,
+}
+
+impl BudgetRow {
+    /// Usage against each limit: (label, used, limit).
+    pub fn usage(&self) -> [(&'static str, i64, Option<u64>); 3] {
+        [
+            ("requests/min", self.requests_minute, self.rpm),
+            ("requests/day", self.requests_day, self.rpd),
+            ("tokens/min", self.tokens_minute, self.tpm),
+        ]
+    }
+
+    /// Largest share of any limit used, from 0.0; `None` without limits.
+    pub fn peak(&self) -> Option<f64> {
+        self.usage()
+            .into_iter()
+            .filter_map(|(_, used, limit)| fraction(used, limit?))
+            .reduce(f64::max)
+    }
+}
+
+/// Usage as `used/limit (pct%)`, or just `used` without a limit.
+pub fn format_usage(used: i64, limit: Option<u64>) -> String {
+    match limit.and_then(|limit| Some((limit, fraction(used, limit)?))) {
+        Some((limit, share)) => format!("{}/{} ({:.0}%)", used, limit, share * 100.0),
+        None => used.to_string(),
+    }
+}
+
+/// Share of `limit` that `used` is; `None` for a zero limit.
+fn fraction(used: i64, limit: u64) -> Option<f64> {
+    (limit > 0).then(|| used.max(0) as f64 / limit as f64)
+}
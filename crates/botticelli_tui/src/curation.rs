@@ -0,0 +1,92 @@
+//! Bulk actions and one-at-a-time triage for content curation.
+
+use crate::ContentRow;
+use std::collections::HashSet;
+
+/// A change applied to several content items at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BulkAction {
+    /// Move items to a lifecycle status
+    SetStatus(String),
+    /// Add tags, keeping the tags items already have
+    AddTags(Vec<String>),
+    /// Set the rating (1-5)
+    Rate(i32),
+    /// Delete items
+    Delete,
+}
+
+impl BulkAction {
+    /// Status bar summary of the action applied to `count` items.
+    pub fn describe(&self, count: usize) -> String {
+        let items = if count == 1 { "item" } else { "items" };
+        match self {
+            BulkAction::SetStatus(status) => {
+                format!("Moved {} {} to {}", count, items, status)
+            }
+            BulkAction::AddTags(tags) => {
+                format!("Tagged {} {} with {}", count, items, tags.join(", "))
+            }
+            BulkAction::Rate(rating) => format!("Rated {} {} {}", count, items, rating),
+            BulkAction::Delete => format!("Deleted {} {}", count, items),
+        }
+    }
+}
+
+/// One decision made in triage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TriageStep {
+    /// Item the decision was about
+    pub id: i64,
+    /// List offset of the page the item was on
+    pub offset: i64,
+    /// Status before the decision; `None` if the item was skipped
+    pub previous_status: Option<String>,
+    /// Status the decision set; `None` if the item was skipped
+    pub new_status: Option<String>,
+}
+
+/// A triage session: which items have been decided and how to undo them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Triage {
+    /// Items decided or skipped this session
+    seen: HashSet<i64>,
+    /// Decisions, oldest first
+    history: Vec<TriageStep>,
+    /// Whether every item matching the list's query has been seen
+    pub finished: bool,
+}
+
+impl Triage {
+    /// Record a decision about an item.
+    pub fn record(&mut self, step: TriageStep) {
+        self.seen.insert(step.id);
+        self.history.push(step);
+    }
+
+    /// Take back the last decision so its item comes up again.
+    pub fn undo(&mut self) -> Option<TriageStep> {
+        let step = self.history.pop()?;
+        self.seen.remove(&step.id);
+        self.finished = false;
+        Some(step)
+    }
+
+    /// Index of the first item on the page not yet seen this session.
+    pub fn next_unseen(&self, items: &[ContentRow]) -> Option<usize> {
+        items.iter().position(|item| !self.seen.contains(&item.id))
+    }
+
+    /// Decisions made, by the status they set (`"skipped"` for skips).
+    pub fn tally(&self) -> Vec<(String, usize)> {
+        let mut tally: Vec<(String, usize)> = Vec::new();
+        for step in &self.history {
+            let outcome = step.new_status.as_deref().unwrap_or("skipped");
+            match tally.iter_mut().find(|(status, _)| status == outcome) {
+                Some((_, count)) => *count += 1,
+                None => tally.push((outcome.to_string(), 1)),
+            }
+        }
+        tally
+    }
+}
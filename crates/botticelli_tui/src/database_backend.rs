@@ -1,53 +1,103 @@
 //! Database backend implementation for TUI.
 //!
-//! This module implements the TuiBackend trait using PostgreSQL via Diesel.
+//! This module implements the TuiBackend trait using PostgreSQL or SQLite
+//! via Diesel.
 
-use crate::{ContentRow, TuiError, TuiErrorKind, TuiResult, backend::TuiBackend};
+use crate::{
+    ActRow, BudgetRow, BulkAction, ContentRow, ExecutionRow, ListPage, ListQuery, TaskRow,
+    TuiError, TuiErrorKind, TuiResult, backend::TuiBackend,
+};
+use botticelli_core::{FilterCondition, FilterOperators, FilterValue, TableFilter};
 use botticelli_database::{
-    delete_content, establish_connection, get_content_by_id, list_content, update_content_metadata,
-    update_review_status,
+    ActInputRecord, CONTENT_METADATA_COLUMNS, ContentConnection, ContentQuery, ContentQueryBuilder,
+    ContentStatus, DatabaseConnection, delete_content, edit_content, get_content_by_id,
+    insert_content, list_actor_tasks, list_narrative_executions, model_usage,
+    narrative_execution_acts, query_content, reflect_table_schema, set_actor_task_paused,
+    transition_content_status, update_content_metadata, update_review_status,
 };
-use diesel::PgConnection;
+use botticelli_error::BotticelliResult;
+use botticelli_rate_limit::BotticelliConfig;
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value as JsonValue};
+
+/// Editor credited with TUI changes when no reviewer name is known.
+const TUI_EDITOR: &str = "tui";
 
-/// Database backend using PostgreSQL via Diesel.
+/// Database backend using PostgreSQL or SQLite via Diesel.
 pub struct DatabaseBackend {
-    connection: PgConnection,
+    connection: DatabaseConnection,
+    /// Rate limits from `botticelli.toml`, if it could be loaded
+    config: Option<BotticelliConfig>,
+    /// Reviewer recorded in status history and revisions
+    editor: String,
 }
 
 impl DatabaseBackend {
     /// Create a new database backend.
     ///
-    /// Connects to PostgreSQL using DATABASE_URL environment variable.
+    /// Connects to the database named by the DATABASE_URL environment
+    /// variable; SQLite file paths need the `sqlite` feature. Rate limits
+    /// for the console are read from `botticelli.toml`.
     pub fn new() -> TuiResult<Self> {
-        let connection = establish_connection().map_err(|e| {
+        let connection = DatabaseConnection::from_env().map_err(|e| {
             TuiError::new(TuiErrorKind::Database(format!(
                 "Failed to connect to database: {}",
                 e
             )))
         })?;
 
-        Ok(Self { connection })
+        let config = BotticelliConfig::load()
+            .inspect_err(|e| tracing::warn!(error = %e, "Rate limits unavailable"))
+            .ok();
+
+        Ok(Self {
+            connection,
+            config,
+            editor: default_editor(),
+        })
+    }
+
+    /// Record changes under `editor` instead of the login name.
+    pub fn with_editor(mut self, editor: impl Into<String>) -> Self {
+        self.editor = editor.into();
+        self
+    }
+
+    /// Reviewer recorded in status history and revisions.
+    pub fn editor(&self) -> &str {
+        &self.editor
     }
 }
 
+/// Login name of the person running the TUI, from `$USER` (or
+/// `%USERNAME%` on Windows), falling back to `"tui"`.
+fn default_editor() -> String {
+    ["USER", "USERNAME"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| TUI_EDITOR.to_string())
+}
+
 impl TuiBackend for DatabaseBackend {
-    fn list_content(&mut self, table_name: &str, limit: i64) -> TuiResult<Vec<ContentRow>> {
-        let rows =
-            list_content(&mut self.connection, table_name, None, limit as usize).map_err(|e| {
+    fn list_content(&mut self, table_name: &str, query: &ListQuery) -> TuiResult<ListPage> {
+        let page = query_content(&mut self.connection, table_name, &content_query(query)?)
+            .map_err(|e| {
                 TuiError::new(TuiErrorKind::Database(format!(
                     "Failed to list content: {}",
                     e
                 )))
             })?;
 
-        let content_rows = rows
-            .into_iter()
+        let items = page
+            .rows()
+            .iter()
             .map(|row| {
                 let id = row.get("id").and_then(|v| v.as_i64()).unwrap_or(0);
                 let review_status = row
                     .get("review_status")
                     .and_then(|v| v.as_str())
-                    .unwrap_or("pending")
+                    .unwrap_or("generated")
                     .to_string();
                 let rating = row.get("rating").and_then(|v| v.as_i64()).map(|v| v as i32);
                 let tags = row
@@ -78,6 +128,17 @@ impl TuiBackend for DatabaseBackend {
                     .and_then(|v| v.as_str())
                     .map(String::from);
 
+                let body = row
+                    .as_object()
+                    .map(|fields| {
+                        fields
+                            .iter()
+                            .filter(|(name, _)| is_body_column(name))
+                            .map(|(name, value)| (name.clone(), value.clone()))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
                 ContentRow {
                     id,
                     review_status,
@@ -85,13 +146,17 @@ impl TuiBackend for DatabaseBackend {
                     tags,
                     preview,
                     content,
+                    body,
                     source_narrative,
                     source_act,
                 }
             })
             .collect();
 
-        Ok(content_rows)
+        Ok(ListPage {
+            items,
+            total: *page.total(),
+        })
     }
 
     fn update_metadata(
@@ -102,20 +167,53 @@ impl TuiBackend for DatabaseBackend {
         rating: Option<i32>,
         status: &str,
     ) -> TuiResult<()> {
-        // Update tags and rating
-        update_content_metadata(&mut self.connection, table_name, id, Some(tags), rating).map_err(
-            |e| {
-                TuiError::new(TuiErrorKind::Database(format!(
-                    "Failed to update metadata: {}",
+        let transaction_error = |e| {
+            TuiError::new(TuiErrorKind::Database(format!(
+                "Failed to update metadata: {}",
+                e
+            )))
+        };
+
+        // Tags, rating and status are saved together or not at all
+        self.connection
+            .begin_transaction()
+            .map_err(transaction_error)?;
+        let updated = update_content_metadata(
+            &mut self.connection,
+            table_name,
+            id,
+            Some(tags),
+            rating,
+            &self.editor,
+        )
+        .and_then(|()| {
+            update_review_status(&mut self.connection, table_name, id, status, &self.editor)
+        });
+
+        match updated {
+            Ok(()) => self
+                .connection
+                .commit_transaction()
+                .map_err(transaction_error),
+            Err(e) => {
+                let _ = self.connection.rollback_transaction();
+                Err(TuiError::new(TuiErrorKind::Database(format!(
+                    "Failed to update metadata, nothing was changed: {}",
                     e
-                )))
-            },
-        )?;
+                ))))
+            }
+        }
+    }
 
-        // Update review status separately
-        update_review_status(&mut self.connection, table_name, id, status).map_err(|e| {
+    fn update_body(
+        &mut self,
+        table_name: &str,
+        id: i64,
+        values: &Map<String, JsonValue>,
+    ) -> TuiResult<()> {
+        edit_content(&mut self.connection, table_name, id, values, &self.editor).map_err(|e| {
             TuiError::new(TuiErrorKind::Database(format!(
-                "Failed to update review status: {}",
+                "Failed to update content: {}",
                 e
             )))
         })?;
@@ -123,6 +221,31 @@ impl TuiBackend for DatabaseBackend {
         Ok(())
     }
 
+    fn new_item_template(&mut self, table_name: &str) -> TuiResult<Map<String, JsonValue>> {
+        let schema = reflect_table_schema(&mut self.connection, table_name).map_err(|e| {
+            TuiError::new(TuiErrorKind::Database(format!(
+                "Failed to read table schema: {}",
+                e
+            )))
+        })?;
+
+        Ok(schema
+            .columns
+            .into_iter()
+            .filter(|column| is_body_column(&column.name))
+            .map(|column| (column.name, JsonValue::Null))
+            .collect())
+    }
+
+    fn insert_item(&mut self, table_name: &str, values: &Map<String, JsonValue>) -> TuiResult<i64> {
+        insert_content(&mut self.connection, table_name, values, &self.editor).map_err(|e| {
+            TuiError::new(TuiErrorKind::Database(format!(
+                "Failed to insert item: {}",
+                e
+            )))
+        })
+    }
+
     fn delete_item(&mut self, table_name: &str, id: i64) -> TuiResult<()> {
         delete_content(&mut self.connection, table_name, id).map_err(|e| {
             TuiError::new(TuiErrorKind::Database(format!(
@@ -134,6 +257,54 @@ impl TuiBackend for DatabaseBackend {
         Ok(())
     }
 
+    fn apply_bulk(&mut self, table_name: &str, ids: &[i64], action: &BulkAction) -> TuiResult<()> {
+        let transaction_error = |e| {
+            TuiError::new(TuiErrorKind::Database(format!(
+                "Failed to apply bulk change: {}",
+                e
+            )))
+        };
+
+        self.connection
+            .begin_transaction()
+            .map_err(transaction_error)?;
+        let applied = ids.iter().try_for_each(|&id| {
+            apply_to_item(&mut self.connection, table_name, id, action, &self.editor)
+        });
+
+        match applied {
+            Ok(()) => self
+                .connection
+                .commit_transaction()
+                .map_err(transaction_error),
+            Err(e) => {
+                let _ = self.connection.rollback_transaction();
+                Err(TuiError::new(TuiErrorKind::Database(format!(
+                    "Failed to apply bulk change, nothing was changed: {}",
+                    e
+                ))))
+            }
+        }
+    }
+
+    fn restore_status(&mut self, table_name: &str, id: i64, status: &str) -> TuiResult<String> {
+        let editor = self.editor.as_str();
+        let restore = |conn: &mut DatabaseConnection| -> BotticelliResult<ContentStatus> {
+            let previous: ContentStatus = status.parse()?;
+            transition_content_status(conn, table_name, id, previous, editor, Some("undo"))?;
+            Ok(previous)
+        };
+
+        restore(&mut self.connection)
+            .map(|status| status.to_string())
+            .map_err(|e| {
+                TuiError::new(TuiErrorKind::Database(format!(
+                    "Failed to restore status: {}",
+                    e
+                )))
+            })
+    }
+
     fn export_items(&mut self, table_name: &str, ids: &[i64]) -> TuiResult<String> {
         // Fetch items and convert to JSON
         let items: Result<Vec<_>, _> = ids
@@ -155,4 +326,237 @@ impl TuiBackend for DatabaseBackend {
             )))
         })
     }
+
+    fn list_executions(&mut self, limit: i64) -> TuiResult<Vec<ExecutionRow>> {
+        let executions = list_narrative_executions(&mut self.connection, limit).map_err(|e| {
+            TuiError::new(TuiErrorKind::Database(format!(
+                "Failed to list executions: {}",
+                e
+            )))
+        })?;
+
+        Ok(executions
+            .into_iter()
+            .map(|execution| ExecutionRow {
+                id: *execution.id(),
+                narrative_name: execution.narrative_name().clone(),
+                status: execution.status().clone(),
+                started_at: timestamp(execution.started_at()),
+                completed_at: execution.completed_at().as_ref().map(timestamp),
+                error_message: execution.error_message().clone(),
+                act_count: *execution.act_count(),
+            })
+            .collect())
+    }
+
+    fn execution_acts(&mut self, execution_id: i64) -> TuiResult<Vec<ActRow>> {
+        let acts = narrative_execution_acts(&mut self.connection, execution_id).map_err(|e| {
+            TuiError::new(TuiErrorKind::Database(format!(
+                "Failed to load execution {}: {}",
+                execution_id, e
+            )))
+        })?;
+
+        Ok(acts
+            .into_iter()
+            .map(|act| ActRow {
+                sequence_number: *act.sequence_number(),
+                act_name: act.act_name().clone(),
+                model: act.model().clone(),
+                inputs: act.inputs().iter().map(input_summary).collect(),
+                response: act.response().clone(),
+            })
+            .collect())
+    }
+
+    fn list_tasks(&mut self) -> TuiResult<Vec<TaskRow>> {
+        let tasks = list_actor_tasks(&mut self.connection).map_err(|e| {
+            TuiError::new(TuiErrorKind::Database(format!(
+                "Failed to list actor tasks: {}",
+                e
+            )))
+        })?;
+
+        Ok(tasks
+            .into_iter()
+            .map(|task| TaskRow {
+                task_id: task.task_id().clone(),
+                actor_name: task.actor_name().clone(),
+                last_run: task.last_run().as_ref().map(timestamp),
+                next_run: timestamp(task.next_run()),
+                consecutive_failures: *task.consecutive_failures(),
+                is_paused: *task.is_paused(),
+            })
+            .collect())
+    }
+
+    fn set_task_paused(&mut self, task_id: &str, paused: bool) -> TuiResult<()> {
+        set_actor_task_paused(&mut self.connection, task_id, paused).map_err(|e| {
+            TuiError::new(TuiErrorKind::Database(format!(
+                "Failed to update task '{}': {}",
+                task_id, e
+            )))
+        })
+    }
+
+    fn rate_limit_usage(&mut self) -> TuiResult<Vec<BudgetRow>> {
+        let usage = model_usage(&mut self.connection).map_err(|e| {
+            TuiError::new(TuiErrorKind::Database(format!(
+                "Failed to read model usage: {}",
+                e
+            )))
+        })?;
+
+        Ok(usage
+            .into_iter()
+            .map(|usage| {
+                let tier = usage
+                    .model()
+                    .as_deref()
+                    .zip(self.config.as_ref())
+                    .and_then(|(model, config)| config.tier_for_model(model));
+                BudgetRow {
+                    model: usage
+                        .model()
+                        .clone()
+                        .unwrap_or_else(|| "(default)".to_string()),
+                    requests_minute: *usage.requests_last_minute(),
+                    rpm: tier.as_ref().and_then(|t| t.rpm).map(u64::from),
+                    requests_day: *usage.requests_last_day(),
+                    rpd: tier.as_ref().and_then(|t| t.rpd).map(u64::from),
+                    tokens_minute: *usage.tokens_last_minute(),
+                    tpm: tier.as_ref().and_then(|t| t.tpm),
+                }
+            })
+            .collect())
+    }
+}
+
+/// Apply a bulk action to one item.
+fn apply_to_item(
+    conn: &mut DatabaseConnection,
+    table_name: &str,
+    id: i64,
+    action: &BulkAction,
+    editor: &str,
+) -> BotticelliResult<()> {
+    match action {
+        BulkAction::SetStatus(status) => update_review_status(conn, table_name, id, status, editor),
+        BulkAction::AddTags(new_tags) => {
+            let item = get_content_by_id(conn, table_name, id)?;
+            let mut tags: Vec<String> = item
+                .get("tags")
+                .and_then(|v| v.as_array())
+                .map(|tags| {
+                    tags.iter()
+                        .filter_map(|v| v.as_str().map(String::from))
+                        .collect()
+                })
+                .unwrap_or_default();
+            for tag in new_tags {
+                if !tags.contains(tag) {
+                    tags.push(tag.clone());
+                }
+            }
+            update_content_metadata(conn, table_name, id, Some(&tags), None, editor)
+        }
+        BulkAction::Rate(rating) => {
+            update_content_metadata(conn, table_name, id, None, Some(*rating), editor)
+        }
+        BulkAction::Delete => delete_content(conn, table_name, id),
+    }
+}
+
+/// Format a timestamp for display.
+fn timestamp(ts: &DateTime<Utc>) -> String {
+    ts.format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+/// One line describing an act input: its text, or the kind of media.
+fn input_summary(input: &ActInputRecord) -> String {
+    match input.text_content() {
+        Some(text) => text.clone(),
+        None => {
+            let mut summary = input.input_type().clone();
+            for detail in [input.mime_type(), input.filename()].into_iter().flatten() {
+                summary.push(' ');
+                summary.push_str(detail);
+            }
+            format!("[{}]", summary)
+        }
+    }
+}
+
+/// Whether a column belongs to the editable body of a content row.
+fn is_body_column(name: &str) -> bool {
+    name != "id" && !CONTENT_METADATA_COLUMNS.contains(&name)
+}
+
+/// Translate the list's search, filters and sort into a content query.
+fn content_query(query: &ListQuery) -> TuiResult<ContentQuery> {
+    let operators = |operators: FilterOperators| FilterCondition::Operators(Box::new(operators));
+
+    let mut filter = TableFilter::new();
+    if let Some(status) = &query.status {
+        filter = filter.with(
+            "review_status",
+            FilterCondition::Equals(status.as_str().into()),
+        );
+    }
+    if let Some(rating) = query.min_rating {
+        filter = filter.with(
+            "rating",
+            operators(FilterOperators {
+                gte: Some(FilterValue::Integer(rating.into())),
+                ..Default::default()
+            }),
+        );
+    }
+    if let Some(tag) = &query.tag {
+        filter = filter.with(
+            "tags",
+            operators(FilterOperators {
+                contains: Some(tag.as_str().into()),
+                ..Default::default()
+            }),
+        );
+    }
+    if let Some(narrative) = &query.narrative {
+        filter = filter.with(
+            "source_narrative",
+            FilterCondition::Equals(narrative.as_str().into()),
+        );
+    }
+    if query.since.is_some() || query.until.is_some() {
+        filter = filter.with(
+            "generated_at",
+            operators(FilterOperators {
+                gte: query.since.as_deref().map(Into::into),
+                // Dates are inclusive, so take in the whole last day
+                lte: query
+                    .until
+                    .as_ref()
+                    .map(|until| format!("{} 23:59:59.999999", until).into()),
+                ..Default::default()
+            }),
+        );
+    }
+
+    let mut builder = ContentQueryBuilder::default();
+    builder
+        .filter(filter)
+        .sort_by(query.sort.column_name())
+        .descending(query.descending)
+        .offset(query.offset)
+        .limit(query.limit);
+    if !query.search.is_empty() {
+        builder.search(query.search.as_str());
+    }
+
+    builder.build().map_err(|e| {
+        TuiError::new(TuiErrorKind::Database(format!(
+            "Failed to build content query: {}",
+            e
+        )))
+    })
 }
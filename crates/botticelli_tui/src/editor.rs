@@ -0,0 +1,228 @@
+//! Multi-line editor for content bodies.
+//!
+//! A body is the set of editable columns of a content row. Rows whose body
+//! is a single text column are edited as plain text; structured rows are
+//! edited as a JSON object and validated before saving.
+
+use serde_json::{Map, Value as JsonValue};
+
+/// How an edited body maps back onto table columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BodyFormat {
+    /// Plain text written to the named column
+    Text(String),
+    /// JSON object of column values
+    Json,
+}
+
+/// Text buffer with a cursor for editing a content body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BodyEditor {
+    /// Buffer contents, one entry per line
+    lines: Vec<String>,
+    /// Cursor line
+    row: usize,
+    /// Cursor position within the line, in characters
+    col: usize,
+    /// How the buffer is saved
+    format: BodyFormat,
+}
+
+impl BodyEditor {
+    /// Create an editor for a content body.
+    ///
+    /// A body with a single text (or empty) column is edited as plain text;
+    /// anything else is edited as pretty-printed JSON.
+    pub fn new(body: &Map<String, JsonValue>) -> Self {
+        let mut fields = body.iter();
+        let (format, text) = match (fields.next(), fields.next()) {
+            (Some((column, JsonValue::String(text))), None) => {
+                (BodyFormat::Text(column.clone()), text.clone())
+            }
+            (Some((column, JsonValue::Null)), None) => {
+                (BodyFormat::Text(column.clone()), String::new())
+            }
+            _ => (
+                BodyFormat::Json,
+                serde_json::to_string_pretty(body).unwrap_or_default(),
+            ),
+        };
+
+        let mut editor = Self {
+            lines: Vec::new(),
+            row: 0,
+            col: 0,
+            format,
+        };
+        editor.set_text(&text);
+        editor
+    }
+
+    /// How the buffer is saved.
+    pub fn format(&self) -> &BodyFormat {
+        &self.format
+    }
+
+    /// Buffer contents, one entry per line.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Cursor position as (line, character).
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    /// Full buffer contents.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Replace the buffer contents, keeping the cursor where it still fits.
+    pub fn set_text(&mut self, text: &str) {
+        self.lines = text.split('\n').map(str::to_string).collect();
+        self.row = self.row.min(self.lines.len() - 1);
+        self.col = self.col.min(self.line_len());
+    }
+
+    /// File extension for the buffer when opened in an external editor.
+    pub fn file_extension(&self) -> &'static str {
+        match self.format {
+            BodyFormat::Text(_) => "txt",
+            BodyFormat::Json => "json",
+        }
+    }
+
+    /// Column values the buffer describes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message for the status bar if a JSON body doesn't parse or
+    /// isn't an object.
+    pub fn values(&self) -> Result<Map<String, JsonValue>, String> {
+        match &self.format {
+            BodyFormat::Text(column) => {
+                let mut values = Map::new();
+                values.insert(column.clone(), JsonValue::String(self.text()));
+                Ok(values)
+            }
+            BodyFormat::Json => match serde_json::from_str(&self.text()) {
+                Ok(JsonValue::Object(values)) => Ok(values),
+                Ok(_) => Err("Body must be a JSON object of column values".to_string()),
+                Err(e) => Err(format!("Invalid JSON: {}", e)),
+            },
+        }
+    }
+
+    /// Insert a character at the cursor.
+    pub fn insert_char(&mut self, c: char) {
+        let at = self.byte_offset();
+        self.lines[self.row].insert(at, c);
+        self.col += 1;
+    }
+
+    /// Insert text at the cursor; newlines split the line.
+    pub fn insert_str(&mut self, text: &str) {
+        for c in text.chars() {
+            match c {
+                '\n' => self.insert_newline(),
+                '\r' => {}
+                c => self.insert_char(c),
+            }
+        }
+    }
+
+    /// Split the line at the cursor.
+    pub fn insert_newline(&mut self) {
+        let at = self.byte_offset();
+        let rest = self.lines[self.row].split_off(at);
+        self.row += 1;
+        self.col = 0;
+        self.lines.insert(self.row, rest);
+    }
+
+    /// Delete the character before the cursor, joining lines at line start.
+    pub fn backspace(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+            let at = self.byte_offset();
+            self.lines[self.row].remove(at);
+        } else if self.row > 0 {
+            let line = self.lines.remove(self.row);
+            self.row -= 1;
+            self.col = self.line_len();
+            self.lines[self.row].push_str(&line);
+        }
+    }
+
+    /// Delete the character under the cursor, joining lines at line end.
+    pub fn delete(&mut self) {
+        if self.col < self.line_len() {
+            let at = self.byte_offset();
+            self.lines[self.row].remove(at);
+        } else if self.row + 1 < self.lines.len() {
+            let line = self.lines.remove(self.row + 1);
+            self.lines[self.row].push_str(&line);
+        }
+    }
+
+    /// Move the cursor one character left, wrapping to the previous line.
+    pub fn move_left(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+        } else if self.row > 0 {
+            self.row -= 1;
+            self.col = self.line_len();
+        }
+    }
+
+    /// Move the cursor one character right, wrapping to the next line.
+    pub fn move_right(&mut self) {
+        if self.col < self.line_len() {
+            self.col += 1;
+        } else if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.col = 0;
+        }
+    }
+
+    /// Move the cursor up one line.
+    pub fn move_up(&mut self) {
+        if self.row > 0 {
+            self.row -= 1;
+            self.col = self.col.min(self.line_len());
+        }
+    }
+
+    /// Move the cursor down one line.
+    pub fn move_down(&mut self) {
+        if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.col = self.col.min(self.line_len());
+        }
+    }
+
+    /// Move the cursor to the start of the line.
+    pub fn move_home(&mut self) {
+        self.col = 0;
+    }
+
+    /// Move the cursor to the end of the line.
+    pub fn move_end(&mut self) {
+        self.col = self.line_len();
+    }
+
+    /// Length of the cursor line in characters.
+    fn line_len(&self) -> usize {
+        self.lines[self.row].chars().count()
+    }
+
+    /// Byte offset of the cursor within its line.
+    fn byte_offset(&self) -> usize {
+        let line = &self.lines[self.row];
+        line.char_indices()
+            .nth(self.col)
+            .map(|(i, _)| i)
+            .unwrap_or(line.len())
+    }
+}
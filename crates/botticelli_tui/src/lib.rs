@@ -1,14 +1,20 @@
 //! Terminal User Interface for content review.
 //!
 //! Provides an interactive TUI for reviewing, editing, and managing generated content
-//! stored in custom tables. Built with ratatui for terminal rendering.
+//! stored in custom tables, plus an operator console for narrative executions,
+//! actor task health and rate-limit budgets. Built with ratatui for terminal
+//! rendering.
 
 mod app;
 mod backend;
+mod console;
+mod curation;
 #[cfg(feature = "database")]
 mod database_backend;
+mod editor;
 mod error;
 mod events;
+mod query;
 #[cfg(feature = "database")]
 mod runner;
 #[cfg(feature = "database")]
@@ -17,9 +23,13 @@ mod views;
 
 pub use app::{App, AppMode, ContentRow, EditBuffer, EditField};
 pub use backend::TuiBackend;
+pub use console::{ActRow, BudgetRow, ExecutionRow, TaskRow, format_usage};
+pub use curation::{BulkAction, Triage, TriageStep};
 #[cfg(feature = "database")]
 pub use database_backend::DatabaseBackend;
+pub use editor::{BodyEditor, BodyFormat};
 pub use error::{TuiError, TuiErrorKind, TuiResult};
 pub use events::{Event, EventHandler};
+pub use query::{ListPage, ListQuery, PAGE_SIZE, SortColumn};
 #[cfg(feature = "database")]
 pub use runner::run_tui;
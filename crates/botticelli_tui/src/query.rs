@@ -0,0 +1,269 @@
+//! Search, filter, sort and paging state for the content list.
+
+use crate::ContentRow;
+
+/// Rows loaded per page.
+pub const PAGE_SIZE: i64 = 100;
+
+/// Keys accepted in the filter prompt.
+const FILTER_KEYS: [&str; 6] = ["status", "rating", "tag", "narrative", "since", "until"];
+
+/// Column the content list is sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SortColumn {
+    /// When the item was generated
+    Generated,
+    /// Row ID
+    Id,
+    /// Lifecycle status
+    Status,
+    /// User rating
+    Rating,
+}
+
+impl SortColumn {
+    /// Table column sorted on.
+    pub fn column_name(&self) -> &'static str {
+        match self {
+            SortColumn::Generated => "generated_at",
+            SortColumn::Id => "id",
+            SortColumn::Status => "review_status",
+            SortColumn::Rating => "rating",
+        }
+    }
+
+    /// Short name for the status bar.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SortColumn::Generated => "generated",
+            SortColumn::Id => "id",
+            SortColumn::Status => "status",
+            SortColumn::Rating => "rating",
+        }
+    }
+
+    /// The column after this one when cycling.
+    pub fn next(&self) -> Self {
+        match self {
+            SortColumn::Generated => SortColumn::Id,
+            SortColumn::Id => SortColumn::Status,
+            SortColumn::Status => SortColumn::Rating,
+            SortColumn::Rating => SortColumn::Generated,
+        }
+    }
+}
+
+/// What the content list shows: search text, filter chips, sort and page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListQuery {
+    /// Text searched for in the content (empty for none)
+    pub search: String,
+    /// Only items with this lifecycle status
+    pub status: Option<String>,
+    /// Only items rated at least this
+    pub min_rating: Option<i32>,
+    /// Only items with this tag
+    pub tag: Option<String>,
+    /// Only items from this narrative
+    pub narrative: Option<String>,
+    /// Only items generated on or after this date (YYYY-MM-DD)
+    pub since: Option<String>,
+    /// Only items generated on or before this date (YYYY-MM-DD)
+    pub until: Option<String>,
+    /// Column to sort by
+    pub sort: SortColumn,
+    /// Sort in descending order
+    pub descending: bool,
+    /// Rows skipped before this page
+    pub offset: i64,
+    /// Rows per page
+    pub limit: i64,
+}
+
+impl Default for ListQuery {
+    fn default() -> Self {
+        Self {
+            search: String::new(),
+            status: None,
+            min_rating: None,
+            tag: None,
+            narrative: None,
+            since: None,
+            until: None,
+            sort: SortColumn::Generated,
+            descending: true,
+            offset: 0,
+            limit: PAGE_SIZE,
+        }
+    }
+}
+
+impl ListQuery {
+    /// Replace the filters from prompt input such as
+    /// `status:approved rating:4 tag:lore since:2025-01-01`.
+    ///
+    /// Empty input clears every filter. Search, sort and page size are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message for the status bar if a filter is malformed; the
+    /// current filters are left unchanged.
+    pub fn set_filters(&mut self, input: &str) -> Result<(), String> {
+        let mut filters = Self {
+            search: self.search.clone(),
+            sort: self.sort,
+            descending: self.descending,
+            limit: self.limit,
+            ..Self::default()
+        };
+
+        for token in input.split_whitespace() {
+            let (key, value) = token
+                .split_once(':')
+                .filter(|(_, value)| !value.is_empty())
+                .ok_or_else(|| format!("Expected key:value, got '{}'", token))?;
+            match key {
+                "status" => filters.status = Some(value.to_string()),
+                "rating" => {
+                    filters.min_rating = Some(
+                        value
+                            .parse()
+                            .ok()
+                            .filter(|r| (1..=5).contains(r))
+                            .ok_or_else(|| format!("Rating must be 1-5, got '{}'", value))?,
+                    )
+                }
+                "tag" => filters.tag = Some(value.to_string()),
+                "narrative" => filters.narrative = Some(value.to_string()),
+                "since" => filters.since = Some(parse_date(value)?),
+                "until" => filters.until = Some(parse_date(value)?),
+                _ => {
+                    return Err(format!(
+                        "Unknown filter '{}' (use {})",
+                        key,
+                        FILTER_KEYS.join(", ")
+                    ));
+                }
+            }
+        }
+
+        *self = filters;
+        Ok(())
+    }
+
+    /// Current filters in prompt syntax, for editing.
+    pub fn filter_text(&self) -> String {
+        self.filters()
+            .into_iter()
+            .map(|(key, value)| format!("{}:{}", key, value))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Current search and filters as short labels.
+    pub fn chips(&self) -> Vec<String> {
+        let mut chips: Vec<String> = self
+            .filters()
+            .into_iter()
+            .map(|(key, value)| match key {
+                "rating" => format!("rating ≥ {}", value),
+                _ => format!("{}: {}", key, value),
+            })
+            .collect();
+        if !self.search.is_empty() {
+            chips.insert(0, format!("/{}", self.search));
+        }
+        chips
+    }
+
+    /// Drop every filter and the search text.
+    pub fn clear_filters(&mut self) {
+        *self = Self {
+            sort: self.sort,
+            descending: self.descending,
+            limit: self.limit,
+            ..Self::default()
+        };
+    }
+
+    /// Sort by the next column, starting from the first page.
+    pub fn cycle_sort(&mut self) {
+        self.sort = self.sort.next();
+        self.offset = 0;
+    }
+
+    /// Flip the sort direction, starting from the first page.
+    pub fn toggle_direction(&mut self) {
+        self.descending = !self.descending;
+        self.offset = 0;
+    }
+
+    /// Move to the next page; returns false on the last page.
+    pub fn next_page(&mut self, total: i64) -> bool {
+        if self.offset + self.limit < total {
+            self.offset += self.limit;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Move to the previous page; returns false on the first page.
+    pub fn previous_page(&mut self) -> bool {
+        if self.offset > 0 {
+            self.offset = (self.offset - self.limit).max(0);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Current page, starting at 1.
+    pub fn page(&self) -> i64 {
+        self.offset / self.limit + 1
+    }
+
+    /// Pages needed for `total` rows (at least 1).
+    pub fn page_count(&self, total: i64) -> i64 {
+        ((total + self.limit - 1) / self.limit).max(1)
+    }
+
+    /// Set filters as (key, value) pairs, in prompt order.
+    fn filters(&self) -> Vec<(&'static str, String)> {
+        [
+            ("status", self.status.clone()),
+            ("rating", self.min_rating.map(|r| r.to_string())),
+            ("tag", self.tag.clone()),
+            ("narrative", self.narrative.clone()),
+            ("since", self.since.clone()),
+            ("until", self.until.clone()),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| Some((key, value?)))
+        .collect()
+    }
+}
+
+/// One page of content for the list view.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListPage {
+    /// Items on this page
+    pub items: Vec<ContentRow>,
+    /// Items matching the query across all pages
+    pub total: i64,
+}
+
+/// Check a YYYY-MM-DD date.
+fn parse_date(value: &str) -> Result<String, String> {
+    let parts: Vec<&str> = value.split('-').collect();
+    let valid = matches!(parts.as_slice(), [y, m, d]
+        if y.len() == 4 && m.len() == 2 && d.len() == 2
+            && parts.iter().all(|p| p.chars().all(|c| c.is_ascii_digit()))
+            && (1..=12).contains(&m.parse::<u32>().unwrap_or(0))
+            && (1..=31).contains(&d.parse::<u32>().unwrap_or(0)));
+    if valid {
+        Ok(value.to_string())
+    } else {
+        Err(format!("Dates must be YYYY-MM-DD, got '{}'", value))
+    }
+}
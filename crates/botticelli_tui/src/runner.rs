@@ -3,14 +3,21 @@
 //! This module contains the main TUI loop that works with any backend
 //! implementing the TuiBackend trait.
 
-use crate::{App, Event, EventHandler, TuiBackend, TuiError, TuiErrorKind, TuiResult};
+use crate::{
+    App, AppMode, BulkAction, Event, EventHandler, PAGE_SIZE, TriageStep, TuiBackend, TuiError,
+    TuiErrorKind, TuiResult,
+};
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{DisableMouseCapture, EnableMouseCapture, KeyEvent},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
-use std::io;
+use std::io::{self, Stdout};
+use std::process::Command;
+
+/// Ticks between refreshes of the console views (about five seconds).
+const REFRESH_TICKS: u32 = 20;
 
 /// Run the TUI with the provided backend.
 ///
@@ -48,8 +55,7 @@ pub fn run_tui(backend: &mut dyn TuiBackend, table_name: String) -> TuiResult<()
     let events = EventHandler::new(250);
 
     // Initial load
-    let items = backend.list_content(&table_name, 100)?;
-    app.set_content(items);
+    reload(&mut app, backend, &table_name)?;
 
     // Main loop
     while !app.should_quit {
@@ -60,6 +66,11 @@ pub fn run_tui(backend: &mut dyn TuiBackend, table_name: String) -> TuiResult<()
         if let Ok(Some(event)) = events.next() {
             handle_event(&mut app, backend, &table_name, event)?;
         }
+
+        if app.external_edit {
+            app.external_edit = false;
+            edit_externally(&mut terminal, &mut app)?;
+        }
     }
 
     // Cleanup terminal
@@ -100,6 +111,27 @@ fn handle_event(
     use crossterm::event::{KeyCode, KeyModifiers};
 
     match event {
+        Event::Key(key) if matches!(app.mode, AppMode::EditBody | AppMode::New) => {
+            handle_editor_key(app, backend, table_name, key)?
+        }
+        Event::Key(key) if matches!(app.mode, AppMode::Search | AppMode::Filter | AppMode::Tag) => {
+            handle_prompt_key(app, backend, table_name, key)?
+        }
+        Event::Key(key) if app.mode == AppMode::Triage => {
+            handle_triage_key(app, backend, table_name, key)?
+        }
+        Event::Key(key) if app.mode == AppMode::Compare => {
+            handle_compare_key(app, backend, table_name, key)?
+        }
+        Event::Key(key) if app.confirm_delete => {
+            app.confirm_delete = false;
+            if key.code == KeyCode::Char('y') {
+                bulk(app, backend, table_name, BulkAction::Delete)?;
+            } else {
+                app.status_message = "Delete cancelled".to_string();
+            }
+        }
+        Event::Key(key) if app.mode.is_console() => handle_console_key(app, backend, key)?,
         Event::Key(key) => match key.code {
             KeyCode::Char('q') | KeyCode::Esc => app.quit(),
             KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => app.quit(),
@@ -107,31 +139,663 @@ fn handle_event(
             KeyCode::Down | KeyCode::Char('j') => app.select_next(),
             KeyCode::Enter => app.enter_detail(),
             KeyCode::Char('e') => app.enter_edit(),
-            KeyCode::Char('c') => app.toggle_compare(),
+            KeyCode::Char('b') => app.enter_body_edit(),
+            KeyCode::Char('n') => {
+                let template = backend.new_item_template(table_name)?;
+                app.enter_new(&template);
+            }
+            KeyCode::Char('c') if !app.compare_marked() => app.toggle_compare(),
+            KeyCode::Char(' ') if app.mode == AppMode::List => app.toggle_mark(),
+            KeyCode::Char('*') if app.mode == AppMode::List => app.toggle_mark_page(),
+            KeyCode::Char('U') if app.mode == AppMode::List => {
+                app.marked.clear();
+                app.status_message = "Marks cleared".to_string();
+            }
+            KeyCode::Char('a') if app.mode == AppMode::List => {
+                bulk(app, backend, table_name, approve())?;
+            }
+            KeyCode::Char('x') if app.mode == AppMode::List => {
+                bulk(app, backend, table_name, reject())?;
+            }
+            KeyCode::Char(c @ '1'..='5') if app.mode == AppMode::List => {
+                let rating = c.to_digit(10).map(|d| d as i32).unwrap_or(1);
+                bulk(app, backend, table_name, BulkAction::Rate(rating))?;
+            }
+            KeyCode::Char('#') if app.mode == AppMode::List => app.start_tagging(),
+            KeyCode::Char('T') if app.mode == AppMode::List => {
+                app.start_triage();
+                app.query.offset = 0;
+                reload(app, backend, table_name)?;
+                advance_triage(app, backend, table_name)?;
+            }
+            KeyCode::Char('d') if !app.marked.is_empty() => {
+                app.confirm_delete = true;
+                app.status_message =
+                    format!("Delete {} marked items? (y to confirm)", app.marked.len());
+            }
             KeyCode::Char('d') => {
                 if let Some(id) = app.get_selected_id() {
                     backend.delete_item(table_name, id)?;
-                    // Reload content
-                    let items = backend.list_content(table_name, 100)?;
-                    app.set_content(items);
+                    reload(app, backend, table_name)?;
                     app.status_message = "Item deleted".to_string();
                 }
             }
-            KeyCode::Char('s') if app.mode == crate::AppMode::Edit => {
+            KeyCode::Char('s') if app.mode == AppMode::Edit => {
                 if let Some((id, tags, rating, status)) = app.get_edit_data() {
                     backend.update_metadata(table_name, id, &tags, rating, &status)?;
-                    // Reload content
-                    let items = backend.list_content(table_name, 100)?;
-                    app.set_content(items);
+                    reload(app, backend, table_name)?;
                     app.return_to_list();
                     app.status_message = "Changes saved".to_string();
                 }
             }
-            KeyCode::Backspace if app.mode == crate::AppMode::List => app.return_to_list(),
+            KeyCode::Char('/') => app.start_search(),
+            KeyCode::Char('f') => app.start_filter(),
+            KeyCode::Char('F') => {
+                app.query.clear_filters();
+                requery(app, backend, table_name)?;
+                app.status_message = "Filters cleared".to_string();
+            }
+            KeyCode::Char('o') => {
+                app.query.cycle_sort();
+                requery(app, backend, table_name)?;
+            }
+            KeyCode::Char('O') => {
+                app.query.toggle_direction();
+                requery(app, backend, table_name)?;
+            }
+            KeyCode::PageDown if app.query.next_page(app.total_items) => {
+                requery(app, backend, table_name)?;
+            }
+            KeyCode::PageUp if app.query.previous_page() => {
+                requery(app, backend, table_name)?;
+            }
+            KeyCode::Char('r') => {
+                reload(app, backend, table_name)?;
+                app.status_message = "Reloaded".to_string();
+            }
+            KeyCode::Tab if app.mode == AppMode::List => {
+                app.next_view();
+                refresh_console(app, backend);
+            }
+            KeyCode::Backspace if app.mode == AppMode::List => app.return_to_list(),
             _ => {}
         },
+        Event::Tick if app.mode.is_console() => {
+            app.ticks_since_refresh += 1;
+            if app.ticks_since_refresh >= REFRESH_TICKS && app.mode != AppMode::ExecutionDetail {
+                refresh_console(app, backend);
+            }
+        }
         Event::Tick => {}
     }
 
     Ok(())
 }
+
+/// Handle a key press in the operator console views.
+fn handle_console_key(app: &mut App, backend: &mut dyn TuiBackend, key: KeyEvent) -> TuiResult<()> {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    match key.code {
+        KeyCode::Char('q') => app.quit(),
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => app.quit(),
+        KeyCode::Esc if app.mode == AppMode::ExecutionDetail => app.mode = AppMode::Executions,
+        KeyCode::Esc => app.return_to_list(),
+        KeyCode::Tab => {
+            app.next_view();
+            if app.mode.is_console() {
+                refresh_console(app, backend);
+            }
+        }
+        KeyCode::Up | KeyCode::Char('k') => app.select_previous(),
+        KeyCode::Down | KeyCode::Char('j') => app.select_next(),
+        KeyCode::PageUp if app.mode == AppMode::ExecutionDetail => {
+            app.detail_scroll = app.detail_scroll.saturating_sub(10);
+        }
+        KeyCode::PageDown if app.mode == AppMode::ExecutionDetail => {
+            app.detail_scroll = app.detail_scroll.saturating_add(10);
+        }
+        KeyCode::Enter if app.mode == AppMode::Executions => {
+            if let Some(id) = app.selected_execution().map(|execution| execution.id) {
+                match backend.execution_acts(id) {
+                    Ok(acts) => {
+                        app.status_message = format!("Execution {}: {} acts", id, acts.len());
+                        app.show_acts(acts);
+                    }
+                    Err(e) => app.status_message = e.kind.to_string(),
+                }
+            }
+        }
+        KeyCode::Char(c @ ('p' | 'u')) if app.mode == AppMode::Tasks => {
+            if let Some(task_id) = app.selected_task().map(|task| task.task_id.clone()) {
+                let paused = c == 'p';
+                match backend.set_task_paused(&task_id, paused) {
+                    Ok(()) => {
+                        refresh_console(app, backend);
+                        let action = if paused { "Paused" } else { "Resumed" };
+                        app.status_message = format!("{} task {}", action, task_id);
+                    }
+                    Err(e) => app.status_message = e.kind.to_string(),
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            refresh_console(app, backend);
+            app.status_message = "Reloaded".to_string();
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Reload the current console view.
+///
+/// Failures, such as a database without the narrative or actor tables, are
+/// shown in the status bar so the console stays open.
+fn refresh_console(app: &mut App, backend: &mut dyn TuiBackend) {
+    app.ticks_since_refresh = 0;
+    let loaded = match app.mode {
+        AppMode::Executions => backend
+            .list_executions(PAGE_SIZE)
+            .map(|executions| app.set_executions(executions)),
+        AppMode::ExecutionDetail => match app.selected_execution().map(|execution| execution.id) {
+            Some(id) => backend.execution_acts(id).map(|acts| app.acts = acts),
+            None => Ok(()),
+        },
+        AppMode::Tasks => backend.list_tasks().map(|tasks| app.set_tasks(tasks)),
+        AppMode::RateLimits => backend
+            .rate_limit_usage()
+            .map(|budgets| app.set_budgets(budgets)),
+        _ => Ok(()),
+    };
+
+    if let Err(e) = loaded {
+        app.status_message = e.kind.to_string();
+    }
+}
+
+/// Load the current page of the list.
+fn reload(app: &mut App, backend: &mut dyn TuiBackend, table_name: &str) -> TuiResult<()> {
+    let page = backend.list_content(table_name, &app.query)?;
+    app.set_page(page);
+    Ok(())
+}
+
+/// Load the list after its query changed, selecting the first item.
+fn requery(app: &mut App, backend: &mut dyn TuiBackend, table_name: &str) -> TuiResult<()> {
+    app.selected_index = 0;
+    reload(app, backend, table_name)
+}
+
+/// Handle a key press while typing a search or filters.
+///
+/// Searches update the list on every key; filters apply on Enter.
+fn handle_prompt_key(
+    app: &mut App,
+    backend: &mut dyn TuiBackend,
+    table_name: &str,
+    key: KeyEvent,
+) -> TuiResult<()> {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        if key.code == KeyCode::Char('c') {
+            app.quit();
+        }
+        return Ok(());
+    }
+
+    match (app.mode, key.code) {
+        (_, KeyCode::Up) => app.select_previous(),
+        (_, KeyCode::Down) => app.select_next(),
+        (AppMode::Search, KeyCode::Char(c)) => {
+            app.query.search.push(c);
+            app.query.offset = 0;
+            requery(app, backend, table_name)?;
+        }
+        (AppMode::Search, KeyCode::Backspace) => {
+            app.query.search.pop();
+            app.query.offset = 0;
+            requery(app, backend, table_name)?;
+        }
+        (AppMode::Search, KeyCode::Esc) => {
+            app.query.search.clear();
+            app.query.offset = 0;
+            requery(app, backend, table_name)?;
+            app.mode = AppMode::List;
+        }
+        (AppMode::Filter, KeyCode::Char(c)) => app.filter_input.push(c),
+        (AppMode::Filter, KeyCode::Backspace) => {
+            app.filter_input.pop();
+        }
+        (AppMode::Filter, KeyCode::Enter) => {
+            let previous = app.query.clone();
+            if let Err(message) = app.query.set_filters(&app.filter_input) {
+                app.status_message = message;
+                return Ok(());
+            }
+            match requery(app, backend, table_name) {
+                Ok(()) => {
+                    app.mode = AppMode::List;
+                    app.status_message = format!("{} matching items", app.total_items);
+                }
+                Err(e) => {
+                    app.query = previous;
+                    app.status_message = e.kind.to_string();
+                }
+            }
+        }
+        (AppMode::Tag, KeyCode::Char(c)) => app.tag_input.push(c),
+        (AppMode::Tag, KeyCode::Backspace) => {
+            app.tag_input.pop();
+        }
+        (AppMode::Tag, KeyCode::Enter) => {
+            let tags = app.get_tag_input();
+            app.mode = AppMode::List;
+            if tags.is_empty() {
+                app.status_message = "No tags entered".to_string();
+            } else {
+                bulk(app, backend, table_name, BulkAction::AddTags(tags))?;
+            }
+        }
+        (_, KeyCode::Enter | KeyCode::Esc) => app.mode = AppMode::List,
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn approve() -> BulkAction {
+    BulkAction::SetStatus("approved".to_string())
+}
+
+fn reject() -> BulkAction {
+    BulkAction::SetStatus("rejected".to_string())
+}
+
+/// Apply a bulk action to the marked items, or the selected one.
+///
+/// On success the marks are cleared and the list reloaded; failures leave
+/// every item as it was and are shown in the status bar.
+fn bulk(
+    app: &mut App,
+    backend: &mut dyn TuiBackend,
+    table_name: &str,
+    action: BulkAction,
+) -> TuiResult<()> {
+    let ids = app.target_ids();
+    if ids.is_empty() {
+        return Ok(());
+    }
+
+    match backend.apply_bulk(table_name, &ids, &action) {
+        Ok(()) => {
+            app.marked.clear();
+            reload(app, backend, table_name)?;
+            app.status_message = action.describe(ids.len());
+        }
+        Err(e) => app.status_message = e.kind.to_string(),
+    }
+
+    Ok(())
+}
+
+/// Handle a key press in the compare view.
+///
+/// The focused variant can be picked as the winner, which approves it and
+/// rejects the others.
+fn handle_compare_key(
+    app: &mut App,
+    backend: &mut dyn TuiBackend,
+    table_name: &str,
+    key: KeyEvent,
+) -> TuiResult<()> {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    match key.code {
+        KeyCode::Char('q') => app.quit(),
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => app.quit(),
+        KeyCode::Esc | KeyCode::Backspace => app.return_to_list(),
+        KeyCode::Left | KeyCode::Char('h') => app.compare_focus_previous(),
+        KeyCode::Right | KeyCode::Char('l') => app.compare_focus_next(),
+        KeyCode::Enter | KeyCode::Char('w') => {
+            let Some((winner, losers)) = app.compare_winner() else {
+                return Ok(());
+            };
+            let picked = backend
+                .apply_bulk(table_name, &[winner], &approve())
+                .and_then(|()| backend.apply_bulk(table_name, &losers, &reject()));
+            match picked {
+                Ok(()) => {
+                    app.marked.clear();
+                    app.return_to_list();
+                    reload(app, backend, table_name)?;
+                    app.status_message =
+                        format!("Approved item {}, rejected {} others", winner, losers.len());
+                }
+                Err(e) => app.status_message = e.kind.to_string(),
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Handle a key press in triage.
+fn handle_triage_key(
+    app: &mut App,
+    backend: &mut dyn TuiBackend,
+    table_name: &str,
+    key: KeyEvent,
+) -> TuiResult<()> {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    match key.code {
+        KeyCode::Char('q') => app.quit(),
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => app.quit(),
+        KeyCode::Esc => {
+            app.return_to_list();
+            reload(app, backend, table_name)?;
+            app.status_message = "Triage ended".to_string();
+        }
+        KeyCode::Char('u') => triage_undo(app, backend, table_name)?,
+        _ if app.triage.as_ref().is_some_and(|triage| triage.finished) => {}
+        KeyCode::Char('a') => triage_decide(app, backend, table_name, Some("approved"))?,
+        KeyCode::Char('x') => triage_decide(app, backend, table_name, Some("rejected"))?,
+        KeyCode::Char('s') | KeyCode::Char(' ') => triage_decide(app, backend, table_name, None)?,
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Approve, reject or skip (`None`) the item in triage and move on.
+fn triage_decide(
+    app: &mut App,
+    backend: &mut dyn TuiBackend,
+    table_name: &str,
+    status: Option<&str>,
+) -> TuiResult<()> {
+    let Some(item) = app.content_items.get(app.selected_index) else {
+        return Ok(());
+    };
+    let id = item.id;
+    let previous = item.review_status.clone();
+
+    if let Some(status) = status
+        && let Err(e) = backend.apply_bulk(
+            table_name,
+            &[id],
+            &BulkAction::SetStatus(status.to_string()),
+        )
+    {
+        app.status_message = e.kind.to_string();
+        return Ok(());
+    }
+
+    if let Some(triage) = app.triage.as_mut() {
+        triage.record(TriageStep {
+            id,
+            offset: app.query.offset,
+            previous_status: status.map(|_| previous),
+            new_status: status.map(String::from),
+        });
+    }
+    if let (Some(status), Some(item)) = (status, app.content_items.get_mut(app.selected_index)) {
+        item.review_status = status.to_string();
+    }
+    app.status_message = match status {
+        Some(status) => format!("Item {} {}", id, status),
+        None => format!("Skipped item {}", id),
+    };
+
+    advance_triage(app, backend, table_name)
+}
+
+/// Select the next item not yet seen in triage, paging through the list.
+///
+/// Decided items may drop out of a filtered list, so the current page is
+/// reloaded before moving on to the next one.
+fn advance_triage(app: &mut App, backend: &mut dyn TuiBackend, table_name: &str) -> TuiResult<()> {
+    let mut reloaded = false;
+    loop {
+        let Some(triage) = app.triage.as_mut() else {
+            return Ok(());
+        };
+        if let Some(index) = triage.next_unseen(&app.content_items) {
+            app.selected_index = index;
+            return Ok(());
+        }
+        if !reloaded {
+            reloaded = true;
+            reload(app, backend, table_name)?;
+        } else if app.query.next_page(app.total_items) {
+            reload(app, backend, table_name)?;
+        } else {
+            triage.finished = true;
+            return Ok(());
+        }
+    }
+}
+
+/// Take back the last triage decision and bring its item up again.
+fn triage_undo(app: &mut App, backend: &mut dyn TuiBackend, table_name: &str) -> TuiResult<()> {
+    let Some(step) = app.triage.as_mut().and_then(|triage| triage.undo()) else {
+        app.status_message = "Nothing to undo".to_string();
+        return Ok(());
+    };
+
+    let mut message = format!("Undid skip of item {}", step.id);
+    if let Some(previous) = &step.previous_status {
+        match backend.restore_status(table_name, step.id, previous) {
+            Ok(status) => message = format!("Item {} back to {}", step.id, status),
+            Err(e) => {
+                app.status_message = e.kind.to_string();
+                return Ok(());
+            }
+        }
+    }
+
+    app.query.offset = step.offset;
+    reload(app, backend, table_name)?;
+    match app.content_items.iter().position(|item| item.id == step.id) {
+        Some(index) => app.selected_index = index,
+        None => advance_triage(app, backend, table_name)?,
+    }
+    app.status_message = message;
+
+    Ok(())
+}
+
+/// Handle a key press in the body editor.
+fn handle_editor_key(
+    app: &mut App,
+    backend: &mut dyn TuiBackend,
+    table_name: &str,
+    key: KeyEvent,
+) -> TuiResult<()> {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        match key.code {
+            KeyCode::Char('s') => save_body(app, backend, table_name)?,
+            KeyCode::Char('e') => app.external_edit = true,
+            KeyCode::Char('c') => app.quit(),
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    if key.code == KeyCode::Esc {
+        app.return_to_list();
+        app.status_message = "Edit cancelled".to_string();
+        return Ok(());
+    }
+
+    let Some(editor) = app.body_editor.as_mut() else {
+        return Ok(());
+    };
+    match key.code {
+        KeyCode::Char(c) => editor.insert_char(c),
+        KeyCode::Enter => editor.insert_newline(),
+        KeyCode::Tab => editor.insert_str("  "),
+        KeyCode::Backspace => editor.backspace(),
+        KeyCode::Delete => editor.delete(),
+        KeyCode::Left => editor.move_left(),
+        KeyCode::Right => editor.move_right(),
+        KeyCode::Up => editor.move_up(),
+        KeyCode::Down => editor.move_down(),
+        KeyCode::Home => editor.move_home(),
+        KeyCode::End => editor.move_end(),
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Save the body editor, updating the selected item or inserting a new one.
+///
+/// Invalid bodies and rejected writes are reported in the status bar and
+/// leave the editor open.
+fn save_body(app: &mut App, backend: &mut dyn TuiBackend, table_name: &str) -> TuiResult<()> {
+    let values = match app.get_body_data() {
+        Some(Ok(values)) => values,
+        Some(Err(message)) => {
+            app.status_message = message;
+            return Ok(());
+        }
+        None => return Ok(()),
+    };
+
+    let saved = if app.mode == AppMode::New {
+        backend
+            .insert_item(table_name, &values)
+            .map(|id| format!("Created item {}", id))
+    } else {
+        let Some(id) = app.get_selected_id() else {
+            return Ok(());
+        };
+        if values.is_empty() {
+            app.status_message = "No changes to save".to_string();
+            return Ok(());
+        }
+        backend
+            .update_body(table_name, id, &values)
+            .map(|()| format!("Saved item {}", id))
+    };
+
+    match saved {
+        Ok(message) => {
+            reload(app, backend, table_name)?;
+            app.return_to_list();
+            app.status_message = message;
+        }
+        Err(e) => app.status_message = e.kind.to_string(),
+    }
+
+    Ok(())
+}
+
+/// Open the body editor's contents in `$VISUAL` or `$EDITOR`.
+///
+/// The terminal is handed to the editor while it runs, and the buffer is
+/// replaced with the saved file when it exits successfully.
+fn edit_externally(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    app: &mut App,
+) -> TuiResult<()> {
+    let Some(editor) = &app.body_editor else {
+        return Ok(());
+    };
+    let path = std::env::temp_dir().join(format!(
+        "botticelli-{}-{}.{}",
+        app.table_name,
+        std::process::id(),
+        editor.file_extension()
+    ));
+    let text = editor.text();
+
+    disable_raw_mode().map_err(|e| {
+        TuiError::new(TuiErrorKind::TerminalRestore(format!(
+            "Failed to disable raw mode: {}",
+            e
+        )))
+    })?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )
+    .map_err(|e| {
+        TuiError::new(TuiErrorKind::TerminalRestore(format!(
+            "Failed to leave alternate screen: {}",
+            e
+        )))
+    })?;
+
+    let edited = run_external_editor(&path, &text);
+    let _ = std::fs::remove_file(&path);
+
+    enable_raw_mode().map_err(|e| {
+        TuiError::new(TuiErrorKind::TerminalSetup(format!(
+            "Failed to enable raw mode: {}",
+            e
+        )))
+    })?;
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableMouseCapture
+    )
+    .map_err(|e| {
+        TuiError::new(TuiErrorKind::TerminalSetup(format!(
+            "Failed to enter alternate screen: {}",
+            e
+        )))
+    })?;
+    terminal.clear().map_err(|e| {
+        TuiError::new(TuiErrorKind::Rendering(format!(
+            "Failed to clear terminal: {}",
+            e
+        )))
+    })?;
+
+    match (edited, app.body_editor.as_mut()) {
+        (Ok(text), Some(editor)) => {
+            // Editors usually end files with a newline the body didn't have
+            editor.set_text(text.strip_suffix('\n').unwrap_or(&text));
+            app.status_message = "Body updated from editor".to_string();
+        }
+        (Err(message), _) => app.status_message = message,
+        (Ok(_), None) => {}
+    }
+
+    Ok(())
+}
+
+/// Write `text` to `path`, run the user's editor on it, and read it back.
+fn run_external_editor(path: &std::path::Path, text: &str) -> Result<String, String> {
+    let command = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let mut args = command.split_whitespace();
+    let program = args.next().unwrap_or("vi");
+
+    std::fs::write(path, text).map_err(|e| format!("Failed to write temp file: {}", e))?;
+
+    let status = Command::new(program)
+        .args(args)
+        .arg(path)
+        .status()
+        .map_err(|e| format!("Failed to run {}: {}", program, e))?;
+    if !status.success() {
+        return Err(format!(
+            "{} exited with {}; body unchanged",
+            program, status
+        ));
+    }
+
+    std::fs::read_to_string(path).map_err(|e| format!("Failed to read temp file: {}", e))
+}
@@ -5,11 +5,12 @@ use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Paragraph, Row, Table},
+    text::Line,
+    widgets::{Block, Borders, Paragraph, Row, Table, Tabs},
 };
 
 #[cfg(feature = "database")]
-use crate::{App, app::AppMode};
+use crate::{App, BodyFormat, SortColumn, app::AppMode, format_usage};
 
 /// Draw the main UI.
 #[cfg(feature = "database")]
@@ -19,6 +20,7 @@ pub fn draw(f: &mut Frame, app: &App) {
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Header
+            Constraint::Length(3), // Search and filters
             Constraint::Min(0),    // Main content
             Constraint::Length(3), // Status bar
         ])
@@ -27,24 +29,45 @@ pub fn draw(f: &mut Frame, app: &App) {
     // Draw header
     draw_header(f, app, chunks[0]);
 
+    if app.mode.is_console() {
+        draw_view_tabs(f, app, chunks[1]);
+    } else {
+        draw_filter_bar(f, app, chunks[1]);
+    }
+
     // Draw main content based on mode
     match app.mode {
-        AppMode::List => draw_list_view(f, app, chunks[1]),
-        AppMode::Detail => draw_detail_view(f, app, chunks[1]),
-        AppMode::Edit => draw_edit_view(f, app, chunks[1]),
-        AppMode::Compare => draw_compare_view(f, app, chunks[1]),
-        AppMode::Export => draw_export_view(f, app, chunks[1]),
+        AppMode::List | AppMode::Search | AppMode::Filter | AppMode::Tag => {
+            draw_list_view(f, app, chunks[2])
+        }
+        AppMode::Triage => draw_triage_view(f, app, chunks[2]),
+        AppMode::Detail => draw_detail_view(f, app, chunks[2]),
+        AppMode::Edit => draw_edit_view(f, app, chunks[2]),
+        AppMode::EditBody | AppMode::New => draw_body_editor(f, app, chunks[2]),
+        AppMode::Compare => draw_compare_view(f, app, chunks[2]),
+        AppMode::Export => draw_export_view(f, app, chunks[2]),
+        AppMode::Executions => draw_executions_view(f, app, chunks[2]),
+        AppMode::ExecutionDetail => draw_execution_detail(f, app, chunks[2]),
+        AppMode::Tasks => draw_tasks_view(f, app, chunks[2]),
+        AppMode::RateLimits => draw_rate_limits_view(f, app, chunks[2]),
     }
 
     // Draw status bar
-    draw_status_bar(f, app, chunks[2]);
+    draw_status_bar(f, app, chunks[3]);
 }
 
 /// Draw the header.
 #[cfg(feature = "database")]
 #[tracing::instrument(skip_all)]
 fn draw_header(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
-    let title = format!("Botticelli Content Review - {}", app.table_name);
+    let title = match app.mode {
+        AppMode::Executions | AppMode::ExecutionDetail => {
+            "Botticelli Operator Console - Narrative Executions".to_string()
+        }
+        AppMode::Tasks => "Botticelli Operator Console - Actor Tasks".to_string(),
+        AppMode::RateLimits => "Botticelli Operator Console - Rate Limits".to_string(),
+        _ => format!("Botticelli Content Review - {}", app.table_name),
+    };
     let header = Paragraph::new(title)
         .block(Block::default().borders(Borders::ALL))
         .style(
@@ -56,18 +79,119 @@ fn draw_header(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     f.render_widget(header, area);
 }
 
+/// Draw the search prompt, filter prompt, or active filters and page.
+#[cfg(feature = "database")]
+#[tracing::instrument(skip_all)]
+fn draw_filter_bar(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let (text, title) = match app.mode {
+        AppMode::Search => (format!("/{}", app.query.search), "Search"),
+        AppMode::Filter => (
+            app.filter_input.clone(),
+            "Filter (status: rating: tag: narrative: since: until:)",
+        ),
+        AppMode::Tag => (app.tag_input.clone(), "Add tags (comma-separated)"),
+        _ => {
+            let chips = app.query.chips();
+            let chips = if chips.is_empty() {
+                "No filters".to_string()
+            } else {
+                chips
+                    .iter()
+                    .map(|chip| format!("[{}]", chip))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            let direction = if app.query.descending { "↓" } else { "↑" };
+            let mut text = format!(
+                "{} | Sort: {} {} | Page {}/{} ({} items)",
+                chips,
+                app.query.sort.label(),
+                direction,
+                app.query.page(),
+                app.query.page_count(app.total_items),
+                app.total_items
+            );
+            if !app.marked.is_empty() {
+                text.push_str(&format!(" | {} marked", app.marked.len()));
+            }
+            (text, "Filters")
+        }
+    };
+
+    let editing = matches!(app.mode, AppMode::Search | AppMode::Filter | AppMode::Tag);
+    let style = if editing {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+    let bar = Paragraph::new(text.as_str())
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .style(style);
+    f.render_widget(bar, area);
+
+    if editing {
+        let width = text.chars().count() as u16;
+        f.set_cursor_position((
+            (area.x + 1 + width).min(area.right().saturating_sub(2)),
+            area.y + 1,
+        ));
+    }
+}
+
+/// Draw the tabs for switching between content and the console views.
+#[cfg(feature = "database")]
+#[tracing::instrument(skip_all)]
+fn draw_view_tabs(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let selected = match app.mode {
+        AppMode::Executions | AppMode::ExecutionDetail => 1,
+        AppMode::Tasks => 2,
+        AppMode::RateLimits => 3,
+        _ => 0,
+    };
+    let tabs = Tabs::new(vec![
+        Line::from(app.table_name.as_str()),
+        Line::from("Executions"),
+        Line::from("Tasks"),
+        Line::from("Rate limits"),
+    ])
+    .select(selected)
+    .block(Block::default().borders(Borders::ALL).title("Views (Tab)"))
+    .style(Style::default().fg(Color::Gray))
+    .highlight_style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    );
+    f.render_widget(tabs, area);
+}
+
 /// Draw the status bar with help text.
 #[cfg(feature = "database")]
 #[tracing::instrument(skip_all)]
 fn draw_status_bar(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     let help_text = match app.mode {
         AppMode::List => {
-            "↑↓: Navigate | Enter: Detail | E: Edit | C: Compare | D: Delete | R: Reload | Q: Quit"
+            "↑↓: Navigate | Enter: Detail | Space/*: Mark | A/X: Approve/Reject | 1-5: Rate | #: Tag | T: Triage | /: Search | F: Filter | O: Sort | PgUp/PgDn: Page | E: Edit | B: Edit body | N: New | C: Compare | D: Delete | R: Reload | Tab: Console | Q: Quit"
+        }
+        AppMode::Tag => "Enter: Add tags | Esc: Cancel",
+        AppMode::Triage => {
+            "A: Approve | X: Reject | S/Space: Skip | U: Undo | Esc: End triage | Q: Quit"
         }
-        AppMode::Detail => "Esc: Back | E: Edit | Q: Quit",
+        AppMode::Search => "Type to search | Enter: Done | Esc: Clear search",
+        AppMode::Filter => "Enter: Apply | Esc: Cancel | Empty clears all filters",
+        AppMode::Detail => "Esc: Back | E: Edit | B: Edit body | Q: Quit",
         AppMode::Edit => "Ctrl+Enter: Save | Esc: Cancel",
-        AppMode::Compare => "Esc: Back | Q: Quit",
+        AppMode::EditBody | AppMode::New => "Ctrl+S: Save | Ctrl+E: Open in $EDITOR | Esc: Cancel",
+        AppMode::Compare => "←→: Focus | Enter/W: Pick winner | Esc: Back | Q: Quit",
         AppMode::Export => "Esc: Back | Q: Quit",
+        AppMode::Executions => {
+            "↑↓: Navigate | Enter: Acts | R: Reload | Tab: Next view | Esc: Content | Q: Quit"
+        }
+        AppMode::ExecutionDetail => "↑↓/PgUp/PgDn: Scroll | Esc: Executions | Q: Quit",
+        AppMode::Tasks => {
+            "↑↓: Navigate | P: Pause | U: Resume | R: Reload | Tab: Next view | Esc: Content | Q: Quit"
+        }
+        AppMode::RateLimits => "R: Reload | Tab: Next view | Esc: Content | Q: Quit",
     };
 
     let status_text = format!("{} | {}", app.status_message, help_text);
@@ -81,13 +205,28 @@ fn draw_status_bar(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
 #[cfg(feature = "database")]
 #[tracing::instrument(skip_all)]
 fn draw_list_view(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
-    let header = Row::new(vec!["ID", "Status", "Rating", "Tags", "Preview"])
-        .style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        )
-        .bottom_margin(1);
+    let direction = if app.query.descending { " ↓" } else { " ↑" };
+    let heading = |label: &str, column: SortColumn| {
+        if app.query.sort == column {
+            format!("{}{}", label, direction)
+        } else {
+            label.to_string()
+        }
+    };
+    let header = Row::new(vec![
+        String::new(),
+        heading("ID", SortColumn::Id),
+        heading("Status", SortColumn::Status),
+        heading("Rating", SortColumn::Rating),
+        "Tags".to_string(),
+        heading("Preview", SortColumn::Generated),
+    ])
+    .style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )
+    .bottom_margin(1);
 
     let rows: Vec<Row> = app
         .content_items
@@ -102,11 +241,14 @@ fn draw_list_view(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
 
             let tags_str = item.tags.join(", ");
 
+            let marked = app.marked.contains(&item.id);
             let style = if i == app.selected_index {
                 Style::default()
                     .fg(Color::Black)
                     .bg(Color::Cyan)
                     .add_modifier(Modifier::BOLD)
+            } else if marked {
+                Style::default().fg(Color::Magenta)
             } else if app.compare_selection.contains(&i) {
                 Style::default().fg(Color::Green)
             } else {
@@ -114,6 +256,7 @@ fn draw_list_view(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
             };
 
             Row::new(vec![
+                if marked { "●" } else { " " }.to_string(),
                 item.id.to_string(),
                 item.review_status.clone(),
                 rating_str,
@@ -127,6 +270,7 @@ fn draw_list_view(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     let table = Table::new(
         rows,
         [
+            Constraint::Length(1),
             Constraint::Length(8),
             Constraint::Length(10),
             Constraint::Length(10),
@@ -220,59 +364,183 @@ fn draw_edit_view(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     }
 }
 
-/// Draw the compare view (side-by-side comparison).
+/// Draw the body editor for an existing or new item.
+#[tracing::instrument(skip_all)]
+fn draw_body_editor(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let Some(editor) = &app.body_editor else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(area);
+
+    let item = match app.mode {
+        AppMode::New => "New item".to_string(),
+        _ => app
+            .get_selected_id()
+            .map(|id| format!("Item {}", id))
+            .unwrap_or_default(),
+    };
+    let title = match editor.format() {
+        BodyFormat::Text(column) => format!("{} - {}", item, column),
+        BodyFormat::Json => format!("{} - JSON", item),
+    };
+
+    // Scroll so the cursor stays inside the borders
+    let (row, col) = editor.cursor();
+    let height = usize::from(chunks[0].height.saturating_sub(2)).max(1);
+    let width = usize::from(chunks[0].width.saturating_sub(2)).max(1);
+    let scroll_y = row.saturating_sub(height - 1);
+    let scroll_x = col.saturating_sub(width - 1);
+
+    let body = Paragraph::new(editor.text())
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .scroll((scroll_y as u16, scroll_x as u16));
+    f.render_widget(body, chunks[0]);
+    f.set_cursor_position((
+        chunks[0].x + 1 + (col - scroll_x) as u16,
+        chunks[0].y + 1 + (row - scroll_y) as u16,
+    ));
+
+    let (validation, color) = match editor.values() {
+        Ok(_) if *editor.format() == BodyFormat::Json => ("Valid JSON".to_string(), Color::Green),
+        Ok(_) => (format!("{} lines", editor.lines().len()), Color::Gray),
+        Err(message) => (message, Color::Red),
+    };
+    let validation = Paragraph::new(validation)
+        .block(Block::default().borders(Borders::ALL))
+        .style(Style::default().fg(color));
+    f.render_widget(validation, chunks[1]);
+}
+
+/// Draw the compare view (variants side by side).
+///
+/// The focused variant is highlighted; it's the one picked as the winner.
 #[tracing::instrument(skip_all)]
 fn draw_compare_view(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
-    if app.compare_selection.len() >= 2 {
-        let chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(area);
+    let count = app.compare_selection.len();
+    if count < 2 {
+        return;
+    }
 
-        // Left panel
-        if let Some(&idx) = app.compare_selection.first()
-            && let Some(item) = app.content_items.get(idx)
-        {
-            let content_json = serde_json::to_string_pretty(&item.content).unwrap_or_default();
-            let details = [
-                format!("ID: {}", item.id),
-                format!("Status: {}", item.review_status),
-                format!("Rating: {:?}", item.rating),
-                format!("Tags: {}", item.tags.join(", ")),
-                String::new(),
-                content_json,
-            ];
-
-            let left = Paragraph::new(details.join("\n"))
-                .block(Block::default().borders(Borders::ALL).title("Item 1"))
-                .wrap(ratatui::widgets::Wrap { trim: true });
-
-            f.render_widget(left, chunks[0]);
-        }
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, count as u32); count])
+        .split(area);
+
+    for (panel, &idx) in app.compare_selection.iter().enumerate() {
+        let Some(item) = app.content_items.get(idx) else {
+            continue;
+        };
+        let content_json = serde_json::to_string_pretty(&item.content).unwrap_or_default();
+        let details = [
+            format!("ID: {}", item.id),
+            format!("Status: {}", item.review_status),
+            format!("Rating: {:?}", item.rating),
+            format!("Tags: {}", item.tags.join(", ")),
+            String::new(),
+            content_json,
+        ];
 
-        // Right panel
-        if let Some(&idx) = app.compare_selection.get(1)
-            && let Some(item) = app.content_items.get(idx)
-        {
-            let content_json = serde_json::to_string_pretty(&item.content).unwrap_or_default();
-            let details = [
-                format!("ID: {}", item.id),
-                format!("Status: {}", item.review_status),
-                format!("Rating: {:?}", item.rating),
-                format!("Tags: {}", item.tags.join(", ")),
-                String::new(),
-                content_json,
-            ];
-
-            let right = Paragraph::new(details.join("\n"))
-                .block(Block::default().borders(Borders::ALL).title("Item 2"))
-                .wrap(ratatui::widgets::Wrap { trim: true });
-
-            f.render_widget(right, chunks[1]);
-        }
+        let focused = panel == app.compare_focus;
+        let border_style = if focused {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        let title = if focused {
+            format!("Item {} (winner?)", panel + 1)
+        } else {
+            format!("Item {}", panel + 1)
+        };
+
+        let variant = Paragraph::new(details.join("\n"))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(border_style)
+                    .title(title),
+            )
+            .wrap(ratatui::widgets::Wrap { trim: true });
+
+        f.render_widget(variant, chunks[panel]);
     }
 }
 
+/// Draw triage: the item being decided, or a summary once all are done.
+#[cfg(feature = "database")]
+#[tracing::instrument(skip_all)]
+fn draw_triage_view(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let Some(triage) = &app.triage else {
+        return;
+    };
+    let tally = triage
+        .tally()
+        .iter()
+        .map(|(status, count)| format!("{} {}", count, status))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    if triage.finished {
+        let summary = format!(
+            "Triage complete\n\n{}\n\nU: Undo the last decision | Esc: Back to the list",
+            if tally.is_empty() {
+                "No items to triage".to_string()
+            } else {
+                tally
+            }
+        );
+        let done = Paragraph::new(summary)
+            .block(Block::default().borders(Borders::ALL).title("Triage"))
+            .alignment(Alignment::Center);
+        f.render_widget(done, area);
+        return;
+    }
+
+    let Some(item) = app.content_items.get(app.selected_index) else {
+        return;
+    };
+    let position = app.query.offset + app.selected_index as i64 + 1;
+    let title = format!(
+        "Triage - item {} of {}{}",
+        position,
+        app.total_items,
+        if tally.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", tally)
+        }
+    );
+
+    let content_json = serde_json::to_string_pretty(&item.content).unwrap_or_default();
+    let details = [
+        format!("ID: {}", item.id),
+        format!("Status: {}", item.review_status),
+        format!(
+            "Rating: {}",
+            item.rating
+                .map(|r| "★".repeat(r as usize))
+                .unwrap_or_else(|| "---".to_string())
+        ),
+        format!("Tags: {}", item.tags.join(", ")),
+        format!(
+            "Narrative: {}",
+            item.source_narrative.as_deref().unwrap_or("N/A")
+        ),
+        String::new(),
+        content_json,
+    ];
+
+    let detail = Paragraph::new(details.join("\n"))
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .wrap(ratatui::widgets::Wrap { trim: true });
+    f.render_widget(detail, area);
+}
+
 /// Draw the export view.
 #[tracing::instrument(skip_all)]
 fn draw_export_view(f: &mut Frame, _app: &App, area: ratatui::layout::Rect) {
@@ -282,3 +550,267 @@ fn draw_export_view(f: &mut Frame, _app: &App, area: ratatui::layout::Rect) {
         .alignment(Alignment::Center);
     f.render_widget(export, area);
 }
+
+/// Style for the selected row of a console table.
+#[cfg(feature = "database")]
+fn selected_style() -> Style {
+    Style::default()
+        .fg(Color::Black)
+        .bg(Color::Cyan)
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Header row style for console tables.
+#[cfg(feature = "database")]
+fn header_style() -> Style {
+    Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Draw the recorded narrative executions.
+#[cfg(feature = "database")]
+#[tracing::instrument(skip_all)]
+fn draw_executions_view(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let header = Row::new(vec![
+        "ID",
+        "Narrative",
+        "Status",
+        "Started",
+        "Acts",
+        "Error",
+    ])
+    .style(header_style())
+    .bottom_margin(1);
+
+    let rows: Vec<Row> = app
+        .executions
+        .iter()
+        .enumerate()
+        .map(|(i, execution)| {
+            let status_color = match execution.status.as_str() {
+                "completed" => Color::Green,
+                "failed" => Color::Red,
+                _ => Color::Yellow,
+            };
+            let style = if i == app.console_index {
+                selected_style()
+            } else {
+                Style::default().fg(status_color)
+            };
+            Row::new(vec![
+                execution.id.to_string(),
+                execution.narrative_name.clone(),
+                execution.status.clone(),
+                execution.started_at.clone(),
+                execution.act_count.to_string(),
+                execution.error_message.clone().unwrap_or_default(),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Length(24),
+            Constraint::Length(10),
+            Constraint::Length(20),
+            Constraint::Length(6),
+            Constraint::Min(20),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Narrative Executions ({})", app.executions.len())),
+    );
+
+    f.render_widget(table, area);
+}
+
+/// Draw the acts of the selected execution with their inputs and responses.
+#[cfg(feature = "database")]
+#[tracing::instrument(skip_all)]
+fn draw_execution_detail(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let mut lines: Vec<Line> = Vec::new();
+    if let Some(execution) = app.selected_execution() {
+        lines.push(Line::from(format!(
+            "{} - {} (started {}{})",
+            execution.narrative_name,
+            execution.status,
+            execution.started_at,
+            execution
+                .completed_at
+                .as_ref()
+                .map(|completed| format!(", finished {}", completed))
+                .unwrap_or_default()
+        )));
+        if let Some(error) = &execution.error_message {
+            lines.push(Line::styled(
+                format!("Error: {}", error),
+                Style::default().fg(Color::Red),
+            ));
+        }
+    }
+
+    for act in &app.acts {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            format!(
+                "Act {}: {} [{}]",
+                act.sequence_number + 1,
+                act.act_name,
+                act.model.as_deref().unwrap_or("default model")
+            ),
+            header_style(),
+        ));
+        for input in &act.inputs {
+            for (i, line) in input.lines().enumerate() {
+                let prefix = if i == 0 { "> " } else { "  " };
+                lines.push(Line::styled(
+                    format!("{}{}", prefix, line),
+                    Style::default().fg(Color::Cyan),
+                ));
+            }
+        }
+        lines.extend(
+            act.response
+                .lines()
+                .map(|line| Line::from(line.to_string())),
+        );
+    }
+
+    let detail = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Acts ({})", app.acts.len())),
+        )
+        .wrap(ratatui::widgets::Wrap { trim: false })
+        .scroll((app.detail_scroll, 0));
+
+    f.render_widget(detail, area);
+}
+
+/// Draw actor server task health.
+#[cfg(feature = "database")]
+#[tracing::instrument(skip_all)]
+fn draw_tasks_view(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let header = Row::new(vec![
+        "Task", "Actor", "State", "Failures", "Last run", "Next run",
+    ])
+    .style(header_style())
+    .bottom_margin(1);
+
+    let rows: Vec<Row> = app
+        .tasks
+        .iter()
+        .enumerate()
+        .map(|(i, task)| {
+            let color = if task.is_paused {
+                Color::DarkGray
+            } else if task.consecutive_failures > 0 {
+                Color::Red
+            } else {
+                Color::Green
+            };
+            let style = if i == app.console_index {
+                selected_style()
+            } else {
+                Style::default().fg(color)
+            };
+            Row::new(vec![
+                task.task_id.clone(),
+                task.actor_name.clone(),
+                if task.is_paused { "paused" } else { "active" }.to_string(),
+                task.consecutive_failures.to_string(),
+                task.last_run.clone().unwrap_or_else(|| "never".to_string()),
+                task.next_run.clone(),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let paused = app.tasks.iter().filter(|task| task.is_paused).count();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(16),
+            Constraint::Length(16),
+            Constraint::Length(8),
+            Constraint::Length(9),
+            Constraint::Length(20),
+            Constraint::Length(20),
+        ],
+    )
+    .header(header)
+    .block(Block::default().borders(Borders::ALL).title(format!(
+        "Actor Tasks ({}, {} paused)",
+        app.tasks.len(),
+        paused
+    )));
+
+    f.render_widget(table, area);
+}
+
+/// Draw recent model usage against configured budgets.
+#[cfg(feature = "database")]
+#[tracing::instrument(skip_all)]
+fn draw_rate_limits_view(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let header = Row::new(vec![
+        "Model",
+        "Requests/min",
+        "Requests/day",
+        "Tokens/min (est.)",
+    ])
+    .style(header_style())
+    .bottom_margin(1);
+
+    let rows: Vec<Row> = app
+        .budgets
+        .iter()
+        .enumerate()
+        .map(|(i, budget)| {
+            let color = match budget.peak() {
+                Some(peak) if peak >= 0.8 => Color::Red,
+                Some(peak) if peak >= 0.5 => Color::Yellow,
+                Some(_) => Color::Green,
+                None => Color::Gray,
+            };
+            let style = if i == app.console_index {
+                selected_style()
+            } else {
+                Style::default().fg(color)
+            };
+            let mut cells = vec![budget.model.clone()];
+            cells.extend(
+                budget
+                    .usage()
+                    .into_iter()
+                    .map(|(_, used, limit)| format_usage(used, limit)),
+            );
+            Row::new(cells).style(style)
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(24),
+            Constraint::Length(16),
+            Constraint::Length(18),
+            Constraint::Length(22),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Rate Limits (recorded acts, last minute and 24 hours)"),
+    );
+
+    f.render_widget(table, area);
+}
@@ -0,0 +1,73 @@
+//! Tests for the content body editor.
+
+use botticelli_tui::{BodyEditor, BodyFormat};
+use serde_json::{Map, Value as JsonValue, json};
+
+fn body(value: JsonValue) -> Map<String, JsonValue> {
+    value.as_object().unwrap().clone()
+}
+
+#[test]
+fn test_text_body_edits() {
+    let mut editor = BodyEditor::new(&body(json!({"content": "Helo world"})));
+    assert_eq!(editor.format(), &BodyFormat::Text("content".to_string()));
+
+    // Fix the typo, then add a second line
+    for _ in 0..3 {
+        editor.move_right();
+    }
+    editor.insert_char('l');
+    editor.move_end();
+    editor.insert_newline();
+    editor.insert_str("— signed ✓");
+    assert_eq!(editor.text(), "Hello world\n— signed ✓");
+    assert_eq!(editor.cursor(), (1, 10));
+
+    // Backspace at line start joins the lines
+    editor.move_home();
+    editor.backspace();
+    assert_eq!(editor.text(), "Hello world— signed ✓");
+    assert_eq!(editor.cursor(), (0, 11));
+
+    editor.delete();
+    editor.move_up();
+    assert_eq!(editor.text(), "Hello world signed ✓");
+    assert_eq!(
+        editor.values().unwrap(),
+        body(json!({"content": "Hello world signed ✓"}))
+    );
+}
+
+#[test]
+fn test_structured_body_is_validated() {
+    let original = body(json!({"name": "Lore Lounge", "owner_id": 42}));
+    let mut editor = BodyEditor::new(&original);
+    assert_eq!(editor.format(), &BodyFormat::Json);
+    assert_eq!(editor.file_extension(), "json");
+    assert_eq!(editor.values().unwrap(), original);
+
+    editor.set_text(r#"{"name": "Lore Lounge", "owner_id": 42"#);
+    assert!(editor.values().unwrap_err().starts_with("Invalid JSON"));
+
+    editor.set_text("[1, 2]");
+    assert!(editor.values().is_err());
+
+    editor.set_text(r#"{"name": "Lore Lounge!", "owner_id": 42}"#);
+    assert_eq!(editor.values().unwrap()["name"], "Lore Lounge!");
+    assert_eq!(editor.lines().len(), 1);
+}
+
+#[test]
+fn test_new_item_template() {
+    // A single empty column is written as plain text
+    let editor = BodyEditor::new(&body(json!({"content": null})));
+    assert_eq!(editor.format(), &BodyFormat::Text("content".to_string()));
+    assert_eq!(editor.text(), "");
+
+    let editor = BodyEditor::new(&body(json!({"name": null, "description": null})));
+    assert_eq!(editor.format(), &BodyFormat::Json);
+    assert_eq!(
+        editor.values().unwrap(),
+        body(json!({"name": null, "description": null}))
+    );
+}
@@ -0,0 +1,74 @@
+//! Tests for the operator console views and budget gauges.
+
+use botticelli_tui::{App, AppMode, BudgetRow, TaskRow, format_usage};
+
+fn task(task_id: &str, is_paused: bool) -> TaskRow {
+    TaskRow {
+        task_id: task_id.to_string(),
+        actor_name: "poster".to_string(),
+        last_run: None,
+        next_run: "2025-01-01 10:00:00".to_string(),
+        consecutive_failures: 0,
+        is_paused,
+    }
+}
+
+#[test]
+fn test_views_cycle_and_keep_selection_in_range() {
+    let mut app = App::new("guild_ideas".to_string());
+
+    app.next_view();
+    assert_eq!(app.mode, AppMode::Executions);
+    app.next_view();
+    assert_eq!(app.mode, AppMode::Tasks);
+
+    app.set_tasks(vec![
+        task("post", false),
+        task("curate", true),
+        task("reply", false),
+    ]);
+    app.select_next();
+    app.select_next();
+    app.select_next();
+    assert_eq!(app.selected_task().unwrap().task_id, "reply");
+
+    // The selection follows the list when it shrinks
+    app.set_tasks(vec![task("post", false)]);
+    assert_eq!(app.selected_task().unwrap().task_id, "post");
+
+    // Content selection is untouched by console navigation
+    assert_eq!(app.selected_index, 0);
+
+    app.next_view();
+    assert_eq!(app.mode, AppMode::RateLimits);
+    assert_eq!(app.console_index, 0);
+    app.next_view();
+    assert_eq!(app.mode, AppMode::List);
+    assert!(AppMode::ExecutionDetail.is_console());
+    assert!(!AppMode::List.is_console());
+}
+
+#[test]
+fn test_budget_usage() {
+    let budget = BudgetRow {
+        model: "gemini-2.5-pro".to_string(),
+        requests_minute: 1,
+        rpm: Some(2),
+        requests_day: 45,
+        rpd: Some(50),
+        tokens_minute: 1_000,
+        tpm: Some(125_000),
+    };
+
+    assert_eq!(budget.peak(), Some(0.9));
+    assert_eq!(format_usage(1, Some(2)), "1/2 (50%)");
+    assert_eq!(format_usage(7, None), "7");
+
+    let unlimited = BudgetRow {
+        rpm: None,
+        rpd: None,
+        tpm: None,
+        ..budget
+    };
+    assert_eq!(unlimited.peak(), None);
+}
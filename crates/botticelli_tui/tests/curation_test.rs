@@ -0,0 +1,128 @@
+//! Tests for bulk marks, triage sessions and picking a compare winner.
+
+use botticelli_tui::{App, AppMode, BulkAction, ContentRow, Triage, TriageStep};
+use serde_json::{Map, Value as JsonValue};
+
+fn row(id: i64) -> ContentRow {
+    ContentRow {
+        id,
+        review_status: "generated".to_string(),
+        rating: None,
+        tags: Vec::new(),
+        preview: format!("item {}", id),
+        content: JsonValue::Null,
+        body: Map::new(),
+        source_narrative: None,
+        source_act: None,
+    }
+}
+
+fn decided(id: i64, status: Option<&str>) -> TriageStep {
+    TriageStep {
+        id,
+        offset: 0,
+        previous_status: status.map(|_| "generated".to_string()),
+        new_status: status.map(String::from),
+    }
+}
+
+#[test]
+fn test_triage_skips_seen_items_and_undoes() {
+    let items = vec![row(1), row(2), row(3)];
+    let mut triage = Triage::default();
+    assert_eq!(triage.next_unseen(&items), Some(0));
+
+    triage.record(decided(1, Some("approved")));
+    triage.record(decided(2, None));
+    triage.record(decided(3, Some("rejected")));
+    assert_eq!(triage.next_unseen(&items), None);
+    assert_eq!(
+        triage.tally(),
+        vec![
+            ("approved".to_string(), 1),
+            ("skipped".to_string(), 1),
+            ("rejected".to_string(), 1),
+        ]
+    );
+
+    triage.finished = true;
+    let step = triage.undo().unwrap();
+    assert_eq!(step.id, 3);
+    assert_eq!(step.previous_status.as_deref(), Some("generated"));
+    assert!(!triage.finished);
+    assert_eq!(triage.next_unseen(&items), Some(2));
+
+    assert_eq!(triage.undo().unwrap().new_status, None);
+    assert_eq!(triage.undo().unwrap().id, 1);
+    assert!(triage.undo().is_none());
+}
+
+#[test]
+fn test_bulk_action_descriptions() {
+    assert_eq!(
+        BulkAction::SetStatus("approved".to_string()).describe(3),
+        "Moved 3 items to approved"
+    );
+    assert_eq!(
+        BulkAction::AddTags(vec!["funny".to_string(), "short".to_string()]).describe(1),
+        "Tagged 1 item with funny, short"
+    );
+    assert_eq!(BulkAction::Rate(4).describe(2), "Rated 2 items 4");
+    assert_eq!(BulkAction::Delete.describe(1), "Deleted 1 item");
+}
+
+#[test]
+fn test_marks_choose_bulk_targets() {
+    let mut app = App::new("guild_ideas".to_string());
+    app.set_content(vec![row(10), row(11), row(12)]);
+
+    // Without marks the selected item is the target
+    app.selected_index = 1;
+    assert_eq!(app.target_ids(), vec![11]);
+
+    app.toggle_mark();
+    app.selected_index = 2;
+    app.toggle_mark();
+    assert_eq!(app.target_ids(), vec![11, 12]);
+    app.toggle_mark();
+    assert_eq!(app.target_ids(), vec![11]);
+
+    app.toggle_mark_page();
+    assert_eq!(app.target_ids(), vec![10, 11, 12]);
+    app.toggle_mark_page();
+    assert!(app.marked.is_empty());
+
+    app.start_tagging();
+    app.tag_input = "funny, , short ".to_string();
+    assert_eq!(app.mode, AppMode::Tag);
+    assert_eq!(app.get_tag_input(), vec!["funny", "short"]);
+}
+
+#[test]
+fn test_compare_winner_among_marked_variants() {
+    let mut app = App::new("guild_ideas".to_string());
+    app.set_content(vec![row(10), row(11), row(12), row(13)]);
+
+    app.toggle_mark();
+    assert!(!app.compare_marked(), "one marked item can't be compared");
+    assert_eq!(app.mode, AppMode::List);
+
+    for index in [2, 3] {
+        app.selected_index = index;
+        app.toggle_mark();
+    }
+    assert!(app.compare_marked());
+    assert_eq!(app.mode, AppMode::Compare);
+    assert_eq!(app.compare_winner(), Some((10, vec![12, 13])));
+
+    app.compare_focus_next();
+    app.compare_focus_next();
+    app.compare_focus_next();
+    assert_eq!(app.compare_winner(), Some((13, vec![10, 12])));
+    app.compare_focus_previous();
+    assert_eq!(app.compare_winner(), Some((12, vec![10, 13])));
+
+    app.return_to_list();
+    assert_eq!(app.compare_focus, 0);
+    assert!(app.compare_selection.is_empty());
+}
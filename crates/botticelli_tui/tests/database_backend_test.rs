@@ -0,0 +1,78 @@
+//! Tests for the database backend, run against a SQLite file.
+
+#![cfg(feature = "sqlite")]
+
+use botticelli_database::{
+    ContentConnection, ContentStatus, content_status, content_status_history, create_content_table,
+    establish_sqlite_connection, get_content_by_id,
+};
+use botticelli_tui::{BulkAction, DatabaseBackend, TuiBackend};
+
+#[test]
+fn test_changes_recorded_under_reviewer() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("content.db").display().to_string();
+    let mut conn = establish_sqlite_connection(&path).unwrap();
+    create_content_table(&mut conn, "guild_ideas", "discord_guilds", None, None).unwrap();
+    conn.execute_sql(
+        "INSERT INTO guild_ideas (id, name, owner_id) VALUES (1, 'Lore Lounge', 42), (2, 'Den', 42)",
+        &[],
+    )
+    .unwrap();
+
+    // Only test in this binary, so nothing else reads these concurrently
+    unsafe {
+        std::env::set_var("DATABASE_URL", &path);
+        std::env::set_var("USER", "carol");
+    }
+
+    let mut backend = DatabaseBackend::new().unwrap();
+    assert_eq!(backend.editor(), "carol");
+    backend
+        .update_metadata("guild_ideas", 1, &[], None, "approved")
+        .unwrap();
+
+    let mut backend = backend.with_editor("alice");
+    backend
+        .apply_bulk(
+            "guild_ideas",
+            &[2],
+            &BulkAction::SetStatus("rejected".to_string()),
+        )
+        .unwrap();
+
+    let history = content_status_history(&mut conn, "guild_ideas", 1).unwrap();
+    assert_eq!(history[0].actor(), "carol");
+    let history = content_status_history(&mut conn, "guild_ideas", 2).unwrap();
+    assert_eq!(history[0].actor(), "alice");
+
+    // A status the item can't move to rolls back the tags and rating too
+    assert!(
+        backend
+            .update_metadata(
+                "guild_ideas",
+                1,
+                &["lore".to_string()],
+                Some(4),
+                "generated"
+            )
+            .is_err()
+    );
+    let item = get_content_by_id(&mut conn, "guild_ideas", 1).unwrap();
+    assert!(item["tags"].is_null() && item["rating"].is_null());
+
+    // Undo can't send an item back to a status the lifecycle won't allow
+    for status in ["generated", "bogus"] {
+        assert!(backend.restore_status("guild_ideas", 1, status).is_err());
+    }
+    assert_eq!(
+        content_status(&mut conn, "guild_ideas", 1).unwrap(),
+        ContentStatus::Approved
+    );
+    assert_eq!(
+        backend
+            .restore_status("guild_ideas", 2, "pending_review")
+            .unwrap(),
+        "pending_review"
+    );
+}
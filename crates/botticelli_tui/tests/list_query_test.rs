@@ -0,0 +1,65 @@
+//! Tests for the content list's search, filter, sort and paging state.
+
+use botticelli_tui::{ListQuery, SortColumn};
+
+#[test]
+fn test_filter_prompt_round_trip() {
+    let mut query = ListQuery {
+        search: "lore".to_string(),
+        ..Default::default()
+    };
+
+    query
+        .set_filters("status:approved rating:4 tag:lore since:2025-01-01 until:2025-01-31")
+        .unwrap();
+    assert_eq!(query.status.as_deref(), Some("approved"));
+    assert_eq!(query.min_rating, Some(4));
+    assert_eq!(query.until.as_deref(), Some("2025-01-31"));
+    assert_eq!(
+        query.filter_text(),
+        "status:approved rating:4 tag:lore since:2025-01-01 until:2025-01-31"
+    );
+    assert_eq!(
+        query.chips()[..3],
+        ["/lore", "status: approved", "rating ≥ 4"]
+    );
+
+    // Bad input leaves the filters alone
+    for input in ["rating:9", "since:2025-1-1", "color:red", "status"] {
+        assert!(query.set_filters(input).is_err(), "{input}");
+    }
+    assert_eq!(query.tag.as_deref(), Some("lore"));
+
+    // Empty input clears filters but keeps the search
+    query.set_filters("").unwrap();
+    assert_eq!(query.chips(), ["/lore"]);
+    query.clear_filters();
+    assert!(query.chips().is_empty());
+}
+
+#[test]
+fn test_sort_and_pages() {
+    let mut query = ListQuery {
+        limit: 50,
+        ..Default::default()
+    };
+    assert_eq!(query.sort, SortColumn::Generated);
+    assert!(query.descending);
+
+    assert!(query.next_page(120));
+    assert!(query.next_page(120));
+    assert!(!query.next_page(120));
+    assert_eq!((query.page(), query.page_count(120)), (3, 3));
+    assert_eq!(query.page_count(0), 1);
+
+    // Changing the sort starts over from the first page
+    query.cycle_sort();
+    assert_eq!(query.sort.column_name(), "id");
+    assert_eq!(query.page(), 1);
+    assert!(!query.previous_page());
+
+    query.next_page(120);
+    query.toggle_direction();
+    assert!(!query.descending);
+    assert_eq!(query.offset, 0);
+}
@@ -0,0 +1,5 @@
+-- Drop Telegram mirror tables
+DROP TABLE telegram_update_offsets;
+DROP TABLE telegram_messages;
+DROP TABLE telegram_users;
+DROP TABLE telegram_chats;
@@ -0,0 +1,62 @@
+-- Telegram mirror tables populated by long-polling update ingestion
+
+CREATE TABLE telegram_chats (
+    id BIGINT PRIMARY KEY,
+    chat_type VARCHAR(16) NOT NULL,  -- private, group, supergroup, channel
+    title VARCHAR(255),
+    username VARCHAR(64),
+    first_name VARCHAR(64),
+    last_name VARCHAR(64),
+    is_forum BOOLEAN,
+
+    -- Timestamps
+    first_seen TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_seen TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_telegram_chats_type ON telegram_chats(chat_type);
+CREATE INDEX idx_telegram_chats_username ON telegram_chats(username);
+
+CREATE TABLE telegram_users (
+    id BIGINT PRIMARY KEY,
+    is_bot BOOLEAN NOT NULL DEFAULT FALSE,
+    first_name VARCHAR(64) NOT NULL,
+    last_name VARCHAR(64),
+    username VARCHAR(64),
+    language_code VARCHAR(16),
+
+    -- Timestamps
+    first_seen TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_seen TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_telegram_users_username ON telegram_users(username);
+
+CREATE TABLE telegram_messages (
+    chat_id BIGINT NOT NULL REFERENCES telegram_chats(id) ON DELETE CASCADE,
+    message_id BIGINT NOT NULL,
+    from_user_id BIGINT REFERENCES telegram_users(id) ON DELETE SET NULL,
+    text TEXT,
+    caption TEXT,
+    media_type VARCHAR(16),  -- photo, video, animation, audio, document, poll
+    reply_to_message_id BIGINT,
+    sent_at TIMESTAMP NOT NULL,
+    edited_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (chat_id, message_id)
+);
+
+CREATE INDEX idx_telegram_messages_sent_at ON telegram_messages(chat_id, sent_at DESC);
+CREATE INDEX idx_telegram_messages_from_user ON telegram_messages(from_user_id);
+
+-- Long-polling offset per bot, so restarts resume where they stopped
+CREATE TABLE telegram_update_offsets (
+    bot_id BIGINT PRIMARY KEY,
+    next_update_id BIGINT NOT NULL,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
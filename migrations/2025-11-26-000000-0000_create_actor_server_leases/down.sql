@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_actor_server_leases_expires;
+DROP INDEX IF EXISTS idx_actor_server_leases_owner;
+
+DROP TABLE IF EXISTS actor_server_leases;
@@ -0,0 +1,12 @@
+-- Task leases so several actor-server replicas can share one database.
+-- A replica runs a task only while it holds an unexpired lease on it;
+-- when a replica dies its leases expire and another replica takes over.
+CREATE TABLE actor_server_leases (
+    task_id VARCHAR(255) PRIMARY KEY,
+    owner VARCHAR(255) NOT NULL,
+    acquired_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_actor_server_leases_owner ON actor_server_leases(owner);
+CREATE INDEX idx_actor_server_leases_expires ON actor_server_leases(expires_at);
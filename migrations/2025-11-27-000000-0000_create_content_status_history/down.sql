@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_content_status_history_changed_at;
+DROP INDEX IF EXISTS idx_content_status_history_content;
+
+DROP TABLE IF EXISTS content_status_history;
@@ -0,0 +1,16 @@
+-- Audit trail of content lifecycle transitions.
+-- Every review_status change made through content_management records
+-- who made it, why, and when, so posted content can be traced back.
+CREATE TABLE content_status_history (
+    id SERIAL PRIMARY KEY,
+    table_name TEXT NOT NULL,
+    content_id BIGINT NOT NULL,
+    from_status TEXT NOT NULL,
+    to_status TEXT NOT NULL,
+    actor TEXT NOT NULL,
+    reason TEXT,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_content_status_history_content ON content_status_history(table_name, content_id);
+CREATE INDEX idx_content_status_history_changed_at ON content_status_history(changed_at DESC);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_content_revisions_created_at;
+
+DROP TABLE IF EXISTS content_revisions;
@@ -0,0 +1,17 @@
+-- Revisions of content items in generated content tables.
+-- Revision 1 keeps the row as the model wrote it; every later edit stores
+-- the full row, who made the edit, and the fields it changed.
+CREATE TABLE content_revisions (
+    id SERIAL PRIMARY KEY,
+    table_name TEXT NOT NULL,
+    content_id BIGINT NOT NULL,
+    revision INTEGER NOT NULL,
+    content JSONB NOT NULL,
+    editor TEXT NOT NULL,
+    diff JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT content_revisions_item_revision_key UNIQUE (table_name, content_id, revision)
+);
+
+CREATE INDEX idx_content_revisions_created_at ON content_revisions(created_at DESC);
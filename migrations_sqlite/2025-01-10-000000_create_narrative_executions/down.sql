@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS act_inputs;
+DROP TABLE IF EXISTS act_executions;
+DROP TABLE IF EXISTS narrative_executions;
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_act_inputs_media_ref;
+ALTER TABLE act_inputs DROP COLUMN media_ref_id;
+DROP TABLE IF EXISTS media_references;
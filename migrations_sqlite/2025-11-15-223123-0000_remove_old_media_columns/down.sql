@@ -0,0 +1,8 @@
+ALTER TABLE act_inputs ADD COLUMN source_type TEXT;
+ALTER TABLE act_inputs ADD COLUMN source_url TEXT;
+ALTER TABLE act_inputs ADD COLUMN source_base64 TEXT;
+ALTER TABLE act_inputs ADD COLUMN source_binary BLOB;
+ALTER TABLE act_inputs ADD COLUMN source_size_bytes BIGINT;
+ALTER TABLE act_inputs ADD COLUMN content_hash TEXT;
+
+CREATE INDEX idx_act_inputs_content_hash ON act_inputs(content_hash);
@@ -0,0 +1,9 @@
+-- Remove old media storage columns from act_inputs
+DROP INDEX IF EXISTS idx_act_inputs_content_hash;
+
+ALTER TABLE act_inputs DROP COLUMN source_type;
+ALTER TABLE act_inputs DROP COLUMN source_url;
+ALTER TABLE act_inputs DROP COLUMN source_base64;
+ALTER TABLE act_inputs DROP COLUMN source_binary;
+ALTER TABLE act_inputs DROP COLUMN source_size_bytes;
+ALTER TABLE act_inputs DROP COLUMN content_hash;
@@ -0,0 +1,2 @@
+-- Drop discord_guilds table
+DROP TABLE discord_guilds;
@@ -0,0 +1,2 @@
+-- Drop discord_users table
+DROP TABLE discord_users;
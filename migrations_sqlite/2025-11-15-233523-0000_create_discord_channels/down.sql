@@ -0,0 +1,2 @@
+-- Drop discord_channels table
+DROP TABLE discord_channels;
@@ -0,0 +1,2 @@
+-- Drop discord_guild_members table
+DROP TABLE discord_guild_members;
@@ -0,0 +1,2 @@
+-- Drop discord_roles table
+DROP TABLE discord_roles;
@@ -0,0 +1,2 @@
+-- Drop discord_member_roles table
+DROP TABLE discord_member_roles;
@@ -0,0 +1,2 @@
+-- Drop content generation metadata table
+DROP TABLE IF EXISTS content_generation_tables;
@@ -0,0 +1,4 @@
+-- Drop actor content tables
+DROP TABLE IF EXISTS actor_preferences;
+DROP TABLE IF EXISTS post_history;
+DROP TABLE IF EXISTS content;
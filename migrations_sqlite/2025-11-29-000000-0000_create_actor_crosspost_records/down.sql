@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_actor_crosspost_records_updated;
+
+DROP TABLE IF EXISTS actor_crosspost_records;
@@ -0,0 +1,12 @@
+-- Cross-post delivery records, one row per actor and content row.
+-- Deliveries per destination are kept as JSON so retries after a partial
+-- failure only repost where the last attempt failed.
+CREATE TABLE actor_crosspost_records (
+    actor_name VARCHAR(255) NOT NULL,
+    content_id VARCHAR(255) NOT NULL,
+    deliveries JSONB NOT NULL DEFAULT '{}',
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (actor_name, content_id)
+);
+
+CREATE INDEX idx_actor_crosspost_records_updated ON actor_crosspost_records(updated_at);